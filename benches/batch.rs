@@ -0,0 +1,128 @@
+//! Benchmarking of the batch resolution
+//!
+//! Comparison on 1000 models `x^2 = c` (`Dyn(1)`) sharing the same configuration between:
+//! - a naive loop creating a solver for each model
+//! - `solve_batch()`, reusing a single solver
+//! - `solve_batch_parallel()`, only with the `rayon` feature
+//!
+//! Reference results, on a single core machine (not the one of RESULTS.md):
+//!
+//! NewtonRaphson-FD:
+//! - naive_loop:           [4.8978 ms 5.0733 ms 5.2446 ms]
+//! - solve_batch:          [4.3654 ms 4.5366 ms 4.7070 ms]
+//! - solve_batch_parallel: [4.9796 ms 5.1216 ms 5.2541 ms]
+
+use std::convert::Infallible;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+
+const BATCH_SIZE: usize = 1000;
+
+struct SquareModel {
+    constant: f64,
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for SquareModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.left[0] = self.iteratives[0] * self.iteratives[0];
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Dyn> {
+        nrf::residuals::ResidualsValues::new(
+            self.left.clone(),
+            nalgebra::DVector::from_element(1, self.constant),
+        )
+    }
+}
+
+fn models() -> Vec<SquareModel> {
+    (0..BATCH_SIZE)
+        .map(|i| SquareModel {
+            constant: 1.0 + i as f64 / BATCH_SIZE as f64,
+            iteratives: nalgebra::DVector::zeros(1),
+            left: nalgebra::DVector::zeros(1),
+        })
+        .collect()
+}
+
+fn run(c: &mut Criterion) {
+    let problem_size = 1;
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let initial_guesses = vec![nalgebra::DVector::from_element(1, 2.0); BATCH_SIZE];
+    let mut user_models = models();
+
+    let mut group = c.benchmark_group("NewtonRaphson-FD");
+    group.bench_function("naive_loop", |b| {
+        b.iter(|| {
+            for (initial_guess, user_model) in initial_guesses.iter().zip(user_models.iter_mut()) {
+                let mut rf = nrf::solver::RootFinder::new(
+                    parameters.clone(),
+                    initial_guess.clone(),
+                    &iter_params,
+                    &res_config,
+                );
+                rf.solve(user_model).unwrap();
+            }
+        })
+    });
+    group.bench_function("solve_batch", |b| {
+        b.iter(|| {
+            nrf::solver::solve_batch(
+                parameters.clone(),
+                &iter_params,
+                &res_config,
+                &initial_guesses,
+                &mut user_models,
+            )
+        })
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("solve_batch_parallel", |b| {
+        b.iter(|| {
+            nrf::solver::solve_batch_parallel(
+                parameters.clone(),
+                &iter_params,
+                &res_config,
+                &initial_guesses,
+                &mut user_models,
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
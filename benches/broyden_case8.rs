@@ -24,215 +24,49 @@ use newton_rootfinder as nrf;
 use util::test_cases::broyden1965::{broyden1965_case8, init_broyden1965_case8};
 
 fn solvers_comparison(c: &mut Criterion) {
-    const FILEPATH_NR: &'static str = "./benches/data/broyden_case8_NR.xml";
-    const FILEPATH_SN: &'static str = "./benches/data/broyden_case8_SN.xml";
-    const FILEPATH_BROY1_JAC: &'static str = "./benches/data/broyden_case8_BROY1.xml";
-    const FILEPATH_BROY2_JAC: &'static str = "./benches/data/broyden_case8_BROY2.xml";
-    const FILEPATH_BROY1_INV: &'static str = "./benches/data/broyden_case8_BROY1_INV.xml";
-    const FILEPATH_BROY2_INV: &'static str = "./benches/data/broyden_case8_BROY2_INV.xml";
-    const FILEPATH_GRST1_JAC: &'static str = "./benches/data/broyden_case8_GRST1.xml";
-    const FILEPATH_GRST2_JAC: &'static str = "./benches/data/broyden_case8_GRST2.xml";
-    const FILEPATH_GRST1_INV: &'static str = "./benches/data/broyden_case8_GRST1_INV.xml";
-    const FILEPATH_GRST2_INV: &'static str = "./benches/data/broyden_case8_GRST2_INV.xml";
+    const FILEPATH_NR: &str = "./benches/data/broyden_case8_NR.xml";
+    const FILEPATH_SN: &str = "./benches/data/broyden_case8_SN.xml";
+    const FILEPATH_BROY1_JAC: &str = "./benches/data/broyden_case8_BROY1.xml";
+    const FILEPATH_BROY2_JAC: &str = "./benches/data/broyden_case8_BROY2.xml";
+    const FILEPATH_BROY1_INV: &str = "./benches/data/broyden_case8_BROY1_INV.xml";
+    const FILEPATH_BROY2_INV: &str = "./benches/data/broyden_case8_BROY2_INV.xml";
+    const FILEPATH_GRST1_JAC: &str = "./benches/data/broyden_case8_GRST1.xml";
+    const FILEPATH_GRST2_JAC: &str = "./benches/data/broyden_case8_GRST2.xml";
+    const FILEPATH_GRST1_INV: &str = "./benches/data/broyden_case8_GRST1_INV.xml";
+    const FILEPATH_GRST2_INV: &str = "./benches/data/broyden_case8_GRST2_INV.xml";
 
     let mut group_function = c.benchmark_group("Solver parsing");
     group_function.bench_function("NR", |b| {
-        b.iter(|| nrf::xml_parser::from_xml_finite_diff(&FILEPATH_NR))
+        b.iter(|| nrf::xml_parser::from_xml_finite_diff(FILEPATH_NR))
     });
 
     group_function.finish();
 
     let mut group_function = c.benchmark_group("Advanced solver on Broyden test case 8");
 
-    // Newton Raphson method
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_NR);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("NR", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // Stationary Newton method
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_SN);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("SN", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // First Broyden method on jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_BROY1_JAC);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("BROY1_jac", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // Second Broyden method on jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_BROY2_JAC);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("BROY2_jac", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // First Broyden method on inverse jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_BROY1_INV);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("BROY1_inv", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // Second Broyden method on inverse jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_BROY2_INV);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("BROY2_inv", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // First Greenstad method on jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_GRST1_JAC);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("GRST1", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // Second Greenstad method on jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_GRST2_JAC);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("GRST2", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // First Greenstad method on inverse jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_GRST1_INV);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("GRST1_inv", |b| b.iter(|| rf.solve(&mut user_model)));
-
-    // Second Greenstad method on inverse jacobian
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH_GRST2_INV);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case8(),
-        &iteratives,
-        &residuals_config,
-    );
-
-    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
-    group_function.bench_function("GRST2_inv", |b| b.iter(|| rf.solve(&mut user_model)));
+    let configurations = [
+        ("NR", FILEPATH_NR),
+        ("SN", FILEPATH_SN),
+        ("BROY1_jac", FILEPATH_BROY1_JAC),
+        ("BROY2_jac", FILEPATH_BROY2_JAC),
+        ("BROY1_inv", FILEPATH_BROY1_INV),
+        ("BROY2_inv", FILEPATH_BROY2_INV),
+        ("GRST1", FILEPATH_GRST1_JAC),
+        ("GRST2", FILEPATH_GRST2_JAC),
+        ("GRST1_inv", FILEPATH_GRST1_INV),
+        ("GRST2_inv", FILEPATH_GRST2_INV),
+    ];
+
+    for (name, filepath) in configurations {
+        let config = nrf::xml_parser::load_xml_fd(filepath);
+        let problem_size = config.get_parameters().get_problem_size().unwrap();
+        let mut user_model =
+            nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case8);
+
+        config.with_rootfinder(init_broyden1965_case8(), |rf| {
+            group_function.bench_function(name, |b| b.iter(|| rf.solve(&mut user_model)));
+        });
+    }
 
     group_function.finish();
 }
@@ -0,0 +1,31 @@
+//! Benchmarking of the display of the iteratives parameters for a large problem
+//!
+//! Comparison on 1000 iteratives with finite differences between:
+//! - the full table, as written in the debug log with `LogLevel::Full`
+//! - the default display, truncated to the first and last 10 iteratives
+//!
+//! Reference results (see RESULTS.md for the machine used):
+//!
+//! Iteratives-1000:
+//! - format_in_full:    [2.4813 ms 2.5805 ms 2.6754 ms]
+//! - display_truncated: [55.944 us 59.583 us 62.854 us]
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+
+fn run(c: &mut Criterion) {
+    let problem_size = 1000;
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+
+    let mut group = c.benchmark_group("Iteratives-1000");
+    group.bench_function("format_in_full", |b| {
+        b.iter(|| iter_params.format_with(None))
+    });
+    group.bench_function("display_truncated", |b| b.iter(|| iter_params.to_string()));
+    group.finish();
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+//! Benchmarking of the inversion of the jacobian of one-dimensional problems
+//!
+//! Comparison between:
+//! - the update of a `JacobianMatrix`, inverting a jacobian of size 1 with a division
+//! - the inversion through the LU decomposition of nalgebra, the generic path for larger problems
+//!
+//! Both give the same inverse, see the unit tests of the `JacobianMatrix`
+//!
+//! Reference results (Linux x86_64, not the machine of RESULTS.md):
+//!
+//! Const<1>:
+//! - Scalar inversion: [784.41 ps 802.65 ps 820.76 ps]
+//! - LU inversion:     [4.1673 ns 4.2579 ns 4.3385 ns]
+//!
+//! Dyn(1):
+//! - Scalar inversion: [37.982 ns 38.560 ns 39.234 ns]
+//! - LU inversion:     [89.569 ns 94.808 ns 99.856 ns]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::solver::JacobianMatrix;
+
+fn bench_inversion<D>(c: &mut Criterion, shape: D, group_name: &str)
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let jac = nalgebra::OMatrix::from_element_generic(shape, shape, 2.5);
+    let mut jacobian = JacobianMatrix::new();
+
+    let mut group = c.benchmark_group(group_name);
+
+    group.bench_function("Scalar inversion", |b| {
+        b.iter(|| {
+            jacobian
+                .update_jacobian_with_exact_value(black_box(jac.clone()))
+                .unwrap()
+        })
+    });
+
+    group.bench_function("LU inversion", |b| {
+        b.iter(|| black_box(jac.clone()).lu().try_inverse().unwrap())
+    });
+
+    group.finish();
+}
+
+fn jacobian_inverse(c: &mut Criterion) {
+    bench_inversion(c, nalgebra::Const::<1>, "Jacobian inversion Const<1>");
+    bench_inversion(c, nalgebra::Dyn(1), "Jacobian inversion Dyn(1)");
+}
+
+criterion_group!(benches, jacobian_inverse);
+criterion_main!(benches);
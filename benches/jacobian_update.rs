@@ -0,0 +1,82 @@
+//! Benchmarking of the jacobian update of quasi-Newton methods
+//!
+//! Comparison between:
+//! - the pure update function, allocating a new jacobian and a new inverse at each call
+//! - the in place update performed by `approximate_jacobian()`, reusing the `JacobianMatrix` buffers
+//!
+//! Reference results (see RESULTS.md for the machine used):
+//!
+//! Const<1>:
+//! - Pure update and inversion: [14.063 ns 14.261 ns 14.461 ns]
+//! - In place:                  [14.202 ns 14.444 ns 14.859 ns]
+//!
+//! Dyn(20):
+//! - Pure update and inversion: [12.104 us 12.356 us 12.615 us]
+//! - In place:                  [10.039 us 10.279 us 10.539 us]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::solver::{JacobianMatrix, UpdateQuasiNewtonMethod};
+
+fn bench_broyden_first_method<D>(c: &mut Criterion, shape: D, group_name: &str)
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let jac = nalgebra::OMatrix::from_fn_generic(shape, shape, |i, j| {
+        if i == j {
+            4.0 + i as f64
+        } else {
+            1.0 / (1.0 + i as f64 + 2.0 * j as f64)
+        }
+    });
+    let s = nalgebra::OVector::from_element_generic(shape, nalgebra::U1, 1e-2);
+    let y = nalgebra::OVector::from_fn_generic(shape, nalgebra::U1, |i, _| 1e-2 * (1.0 + i as f64));
+
+    // After the first update, the secant equation is satisfied:
+    // the following updates with the same steps leave the jacobian unchanged
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jac.clone())
+        .unwrap();
+
+    let mut group = c.benchmark_group(group_name);
+
+    group.bench_function("In place", |b| {
+        b.iter(|| {
+            nrf::solver::approximate_jacobian(
+                &mut jacobian,
+                UpdateQuasiNewtonMethod::BroydenFirstMethod,
+                &s,
+                &y,
+                &y,
+            )
+            .unwrap()
+        })
+    });
+
+    // Behavior of the previous implementation:
+    // the updated jacobian and its inverse are new matrices
+    let mut jac_current = jac.clone();
+    let mut inverse_current = jac.clone().lu().try_inverse();
+    group.bench_function("Pure update and inversion", |b| {
+        b.iter(|| {
+            jac_current = nrf::solver::broyden_first_method_udpate_jac(&jac_current, &s, &y);
+            inverse_current = jac_current.clone().lu().try_inverse();
+        })
+    });
+    black_box(inverse_current);
+
+    group.finish();
+}
+
+fn jacobian_update(c: &mut Criterion) {
+    bench_broyden_first_method(c, nalgebra::Const::<1>, "Broyden first method Const<1>");
+    bench_broyden_first_method(c, nalgebra::Dyn(20), "Broyden first method Dyn(20)");
+}
+
+criterion_group!(benches, jacobian_update);
+criterion_main!(benches);
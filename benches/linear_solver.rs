@@ -0,0 +1,89 @@
+//! Benchmarking of the linear solvers on a large problem
+//!
+//! Resolution with the Newton-Raphson method and the analytical jacobian
+//! of a synthetic tridiagonal system of 500 equations, comparing:
+//! - the direct inverse of the jacobian
+//! - the restarted GMRES, only performing matrix-vector products with the jacobian
+//!
+//! Reference results (see RESULTS.md for the machine used):
+//!
+//! Banded-500:
+//! - DirectInverse: [285.13 ms 299.44 ms 316.06 ms]
+//! - Gmres:         [19.778 ms 20.507 ms 21.329 ms]
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::solver::{Damping, LinearSolver, MaxIterations, ProblemSize, ResolutionMethod};
+
+const PROBLEM_SIZE: usize = 500;
+
+/// 4 x_i + x_i^3 / 10 - x_{i-1} - x_{i+1} = 1, with x_{-1} = x_{n} = 0
+fn banded(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let n = x.len();
+    nalgebra::DVector::from_fn(n, |i, _| {
+        let previous = if i > 0 { x[i - 1] } else { 0.0 };
+        let next = if i + 1 < n { x[i + 1] } else { 0.0 };
+        4.0 * x[i] + x[i].powi(3) / 10.0 - previous - next - 1.0
+    })
+}
+
+fn banded_jac(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    let n = x.len();
+    nalgebra::DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            4.0 + 3.0 * x[i].powi(2) / 10.0
+        } else if i.abs_diff(j) == 1 {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+fn solve(linear_solver: LinearSolver) {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives(PROBLEM_SIZE);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![nrf::residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-8,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_linear_solver(linear_solver);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(PROBLEM_SIZE),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model =
+        nrf::model::UserModelFromFunctionAndJacobian::new(PROBLEM_SIZE, banded, banded_jac);
+
+    rf.solve(&mut user_model).unwrap();
+}
+
+fn run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Banded-500");
+    group.sample_size(10);
+    group.bench_function("DirectInverse", |b| {
+        b.iter(|| solve(LinearSolver::DirectInverse))
+    });
+    group.bench_function("Gmres", |b| {
+        b.iter(|| {
+            solve(LinearSolver::Gmres {
+                restart: 30,
+                max_iter: 300,
+                tol: 1e-10,
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
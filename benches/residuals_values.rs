@@ -0,0 +1,109 @@
+//! Benchmarking of the access to the residuals values by the solver
+//!
+//! Comparison on the Broyden case 8 (`Dyn(20)`) between:
+//! - a model relying on the default `write_residuals()`, allocating new residuals values at each call
+//! - a model overriding `write_residuals()`, reusing the solver placeholder
+//!
+//! Reference results (see RESULTS.md for the machine used):
+//!
+//! NewtonRaphson-FD:
+//! - get_residuals:   [76.209 us 78.206 us 80.309 us]
+//! - write_residuals: [80.151 us 82.161 us 84.250 us]
+//!
+//! BroydenFirstMethod-FD:
+//! - get_residuals:   [110.52 us 113.23 us 115.96 us]
+//! - write_residuals: [104.76 us 106.03 us 107.48 us]
+
+use std::convert::Infallible;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+use util::test_cases::broyden1965::*;
+
+struct Case8Model {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    right: nalgebra::DVector<f64>,
+    reuse_residuals: bool,
+}
+
+impl Model<nalgebra::Dyn> for Case8Model {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.left = broyden1965_case8(&self.iteratives);
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Dyn> {
+        nrf::residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
+    }
+
+    fn write_residuals(&self, residuals: &mut nrf::residuals::ResidualsValues<nalgebra::Dyn>) {
+        if self.reuse_residuals {
+            residuals.update_from(&self.left, &self.right);
+        } else {
+            *residuals = self.get_residuals();
+        }
+    }
+}
+
+fn bench_resolution(c: &mut Criterion, resolution_method: ResolutionMethod, group_name: &str) {
+    let problem_size = 20;
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let damping = Damping::Enabled;
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+        resolution_method,
+        damping,
+    );
+
+    let mut group = c.benchmark_group(group_name);
+    for (name, reuse_residuals) in [("get_residuals", false), ("write_residuals", true)] {
+        let mut user_model = Case8Model {
+            iteratives: nalgebra::DVector::zeros(problem_size),
+            left: nalgebra::DVector::zeros(problem_size),
+            right: nalgebra::DVector::zeros(problem_size),
+            reuse_residuals,
+        };
+        group.bench_function(name, |b| b.iter(|| rf.solve(&mut user_model).unwrap()));
+    }
+    group.finish();
+}
+
+fn run(c: &mut Criterion) {
+    bench_resolution(c, ResolutionMethod::NewtonRaphson, "NewtonRaphson-FD");
+    bench_resolution(
+        c,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+        "BroydenFirstMethod-FD",
+    );
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
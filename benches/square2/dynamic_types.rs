@@ -30,7 +30,7 @@ fn run(c: &mut Criterion) {
     let stopping_residuals = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
     let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
     let res_config = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     let mut nrf = nrf::solver::default_with_guess(
         init_guess.clone(),
         &iter_params,
@@ -79,6 +79,36 @@ fn run(c: &mut Criterion) {
         b.iter(|| nrf_jac.solve(&mut user_model_jac))
     });
     group_solver.finish();
+
+    // Requires the `debug_log` feature, enabled by default
+    #[cfg(feature = "debug_log")]
+    {
+        use std::time::{Duration, Instant};
+
+        // The log file is recreated before each resolution, outside of the measured time
+        let mut group_debug = c.benchmark_group("Advanced solver with debug log");
+        for (name, write_mode) in [
+            ("Streaming", nrf::solver::DebugWriteMode::Streaming),
+            ("Buffered", nrf::solver::DebugWriteMode::Buffered),
+        ] {
+            let log_path = std::env::temp_dir().join(format!("nrf_bench_log_{}.txt", name));
+            let log_path = log_path.to_str().unwrap();
+            group_debug.bench_function(name, |b| {
+                b.iter_custom(|iters| {
+                    let mut elapsed = Duration::ZERO;
+                    for _ in 0..iters {
+                        nrf.activate_debug_with_mode(log_path, write_mode);
+                        let start = Instant::now();
+                        let _ = nrf.solve(&mut user_model);
+                        elapsed += start.elapsed();
+                    }
+                    elapsed
+                })
+            });
+            std::fs::remove_file(log_path).unwrap();
+        }
+        group_debug.finish();
+    }
 }
 
 criterion_group!(benches, run);
@@ -4,6 +4,7 @@ use std::fmt;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use newton_rootfinder as nrf;
+use nrf::solver::{MaxIterations, ProblemSize};
 
 use nrf::{
     model::{Model, ModelError},
@@ -13,8 +14,7 @@ use nrf::{
 /// x**2 - 2 = 0
 /// Root: x = 2.sqrt() approx 1.4142
 pub fn square2(x: &nalgebra::SVector<f64, 1>) -> nalgebra::SVector<f64, 1> {
-    let y = nalgebra::SVector::<f64, 1>::new(x[0] * x[0] - 2.0);
-    y
+    nalgebra::SVector::<f64, 1>::new(x[0] * x[0] - 2.0)
 }
 
 pub struct UserModel {
@@ -35,7 +35,7 @@ impl UserModel {
 pub struct MyCustomErrors;
 impl fmt::Display for MyCustomErrors {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", "Not a good value")
+        write!(f, "Not a good value")
     }
 }
 
@@ -86,11 +86,11 @@ fn static_types(c: &mut Criterion) {
     let mut user_model = UserModel::new(1.0);
 
     let solver_parameters = nrf::solver::SolverParameters::new(
-        1,
+        ProblemSize::new(1),
         1e-6,
-        50,
+        MaxIterations::new(50),
         nrf::solver::ResolutionMethod::NewtonRaphson,
-        false,
+        nrf::solver::Damping::Disabled,
     );
 
     let iterative_param = nrf::iteratives::IterativeParamsFD::default();
@@ -0,0 +1,108 @@
+//! Benchmarking of the iterations on a problem of moderate size with cheap iterations
+//!
+//! Resolution with the stationary Newton method of a synthetic tridiagonal system of 100 equations,
+//! the jacobian being computed by finite differences at the first iteration only.
+//! With a null tolerance, the 200 iterations are always performed,
+//! each of them evaluating the model once and computing the stopping errors with the adaptative normalization.
+//!
+//! The stopping errors of the current iterate are reused by the next iteration,
+//! instead of being computed again from the residuals of the model.
+//!
+//! Reference results (see RESULTS.md for the machine used):
+//!
+//! - Tridiagonal-100, Stationary Newton: [4.6746 ms 4.7424 ms 4.8041 ms]
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+use nrf::model::{Model, ModelError};
+use nrf::solver::{Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod};
+
+const PROBLEM_SIZE: usize = 100;
+const MAX_ITER: usize = 200;
+
+/// 4 x_i + x_i^3 / 10 - x_{i-1} - x_{i+1} = i + 1, with x_{-1} = x_{n} = 0
+struct Tridiagonal {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    right: nalgebra::DVector<f64>,
+}
+
+impl Tridiagonal {
+    fn new() -> Self {
+        Tridiagonal {
+            iteratives: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            left: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            right: nalgebra::DVector::from_fn(PROBLEM_SIZE, |i, _| i as f64 + 1.0),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for Tridiagonal {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        PROBLEM_SIZE
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        let x = &self.iteratives;
+        for i in 0..PROBLEM_SIZE {
+            let previous = if i > 0 { x[i - 1] } else { 0.0 };
+            let next = if i + 1 < PROBLEM_SIZE { x[i + 1] } else { 0.0 };
+            self.left[i] = 4.0 * x[i] + x[i].powi(3) / 10.0 - previous - next;
+        }
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Dyn> {
+        nrf::residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
+    }
+
+    fn write_residuals(&self, residuals: &mut nrf::residuals::ResidualsValues<nalgebra::Dyn>) {
+        residuals.update_from(&self.left, &self.right);
+    }
+}
+
+fn solve() {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let normalizations = vec![nrf::residuals::NormalizationMethod::Adapt; PROBLEM_SIZE];
+    let res_config = nrf::residuals::ResidualsConfig::new(&normalizations, &normalizations);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        0.0,
+        MaxIterations::new(MAX_ITER),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(PROBLEM_SIZE),
+        &iter_params,
+        &res_config,
+    );
+    let mut model = Tridiagonal::new();
+
+    // the null tolerance is never reached
+    let _ = rf.solve(&mut model);
+}
+
+fn run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Tridiagonal-100");
+    group.sample_size(20);
+    group.bench_function("Stationary Newton", |b| b.iter(solve));
+    group.finish();
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Benchmarking of the parsing of xml configurations
+//!
+//! The configurations are parsed from strings, without reading files:
+//! - the configuration of the Broyden test case 8, with 3 iteratives
+//! - a generated configuration with 200 iteratives and residuals, each node having its attributes
+//!
+//! The description of the nodes used in the error messages is only formatted when an error occurs.
+//!
+//! Reference results, on a single core Intel Xeon virtual machine (noisy, see RESULTS.md):
+//!
+//! Xml parsing, before and after the lazy formatting of the node descriptions:
+//! - broyden_case8: [77.526 us 80.363 us 83.360 us] -> [56.125 us 58.123 us 60.519 us]
+//! - generated_200: [1.4521 ms 1.5120 ms 1.5690 ms] -> [1.4062 ms 1.4632 ms 1.5184 ms]
+//!
+//! Most of the time is spent by minidom to build the xml tree.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use newton_rootfinder as nrf;
+
+fn generated_configuration(problem_size: usize) -> String {
+    let mut content = format!(
+        "<nrf>\n  <solver problem_size=\"{}\" max_iter=\"60\" tolerance=\"1e-6\" damping=\"true\" resolution_method=\"NR\"/>\n",
+        problem_size
+    );
+    content.push_str("  <iteratives min_value=\"-inf\" max_value=\"inf\" max_step_abs=\"inf\" max_step_rel=\"inf\" dx_abs=\"5e-8\" dx_rel=\"5e-8\" perturbation_method=\"Max\">\n");
+    for id in 0..problem_size {
+        content.push_str(&format!(
+            "    <iterative id=\"{}\" min_value=\"0\" max_value=\"100\" max_step_abs=\"10\" max_step_rel=\"0.5\" dx_abs=\"1e-7\"/>\n",
+            id
+        ));
+    }
+    content.push_str(
+        "  </iteratives>\n  <residuals stopping_criteria=\"Abs\" update_method=\"Abs\">\n",
+    );
+    for id in 0..problem_size {
+        content.push_str(&format!(
+            "    <residual id=\"{}\" stopping_criteria=\"Adapt\" update_method=\"Rel\"/>\n",
+            id
+        ));
+    }
+    content.push_str("  </residuals>\n</nrf>\n");
+    content
+}
+
+fn run(c: &mut Criterion) {
+    let broyden_case8 = std::fs::read_to_string("./benches/data/broyden_case8_NR.xml").unwrap();
+    let generated_200 = generated_configuration(200);
+
+    let mut group = c.benchmark_group("Xml parsing");
+    group.bench_function("broyden_case8", |b| {
+        b.iter(|| nrf::xml_parser::load_xml_fd_str(&broyden_case8).unwrap())
+    });
+    group.bench_function("generated_200", |b| {
+        b.iter(|| nrf::xml_parser::load_xml_fd_str(&generated_200).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, run);
+criterion_main!(benches);
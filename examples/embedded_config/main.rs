@@ -0,0 +1,44 @@
+//! Solver configuration embedded in the binary with `include_str!`
+//!
+//! The configuration is checked by the test of this example, run with:
+//! `cargo test --example embedded_config --features xml_config_file`
+//!
+//! It could also be checked by a build script, see the documentation of the `xml_parser` module.
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+
+const CONFIGURATION: &str = include_str!("config.xml");
+
+/// Intersection of the circle of radius 2 with the line y = x
+fn intersection(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] + x[1] * x[1] - 4.0, x[1] - x[0]])
+}
+
+fn main() {
+    let config = nrf::xml_parser::load_xml_fd_str(CONFIGURATION)
+        .unwrap_or_else(|error| panic!("Invalid embedded configuration: {}", error));
+
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
+    let init = nalgebra::DVector::from_element(problem_size, 1.0);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, intersection);
+
+    config
+        .with_rootfinder(init, |rf| rf.solve(&mut user_model))
+        .unwrap();
+    println!("Solution: {}", user_model.get_iteratives());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nrf::solver::IterativesKind;
+
+    #[test]
+    fn embedded_configuration_is_valid() {
+        let summary =
+            nrf::xml_parser::validate(CONFIGURATION, IterativesKind::WithFiniteDifference).unwrap();
+        assert_eq!(summary.get_problem_size(), 2);
+        assert!(summary.get_finite_difference());
+    }
+}
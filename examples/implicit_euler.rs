@@ -0,0 +1,184 @@
+//! Integration of a stiff ordinary differential equation with the implicit Euler method
+//!
+//! The Robertson chemical kinetics problem involves three species with reaction rates of very different magnitudes:
+//!
+//! ```text
+//! y1' = -0.04 y1 + 1e4 y2 y3
+//! y2' =  0.04 y1 - 1e4 y2 y3 - 3e7 y2^2
+//! y3' =                        3e7 y2^2
+//! ```
+//!
+//! At each time step, the implicit Euler method solves `y - dt f(y) = y_previous`.
+//! The model holds the previous state and the time step,
+//! the solver is created once and reused for all the time steps:
+//! - the initial guess of each time step is the previous state, see `RootFinder::set_initial_guess()`
+//! - the jacobian is kept from one time step to the next one, see `RootFinder::set_jacobian_reuse()`
+//! - the jacobian is computed again when the time step changes, see `RootFinder::invalidate_jacobian()`
+//!
+//! Run with: `cargo run --example implicit_euler`
+
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianSource, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    RootFinder,
+};
+
+/// Right-hand side of the Robertson problem
+fn robertson(y: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![
+        -0.04 * y[0] + 1e4 * y[1] * y[2],
+        0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1] * y[1],
+        3e7 * y[1] * y[1],
+    ])
+}
+
+/// Jacobian of the right-hand side of the Robertson problem
+fn robertson_jacobian(y: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_row_slice(
+        3,
+        3,
+        &[
+            -0.04,
+            1e4 * y[2],
+            1e4 * y[1],
+            0.04,
+            -1e4 * y[2] - 6e7 * y[1],
+            -1e4 * y[1],
+            0.0,
+            6e7 * y[1],
+            0.0,
+        ],
+    )
+}
+
+/// Time step of the implicit Euler method: `y - dt f(y) = y_previous`
+struct ImplicitEulerStep {
+    previous_state: nalgebra::DVector<f64>,
+    time_step: f64,
+    state: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl ImplicitEulerStep {
+    fn new(initial_state: nalgebra::DVector<f64>, time_step: f64) -> Self {
+        ImplicitEulerStep {
+            previous_state: initial_state.clone(),
+            time_step,
+            left: initial_state.clone(),
+            state: initial_state,
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for ImplicitEulerStep {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        3
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.state.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.state.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = &self.state - robertson(&self.state) * self.time_step;
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), self.previous_state.clone())
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        let jacobian_left =
+            nalgebra::DMatrix::identity(3, 3) - robertson_jacobian(&self.state) * self.time_step;
+        Ok(residuals::JacobianValues::new(
+            jacobian_left,
+            nalgebra::DMatrix::zeros(3, 3),
+        ))
+    }
+}
+
+fn main() {
+    let problem_size = 3;
+    let initial_state = nalgebra::DVector::from_vec(vec![1.0, 0.0, 0.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-12,
+        MaxIterations::new(30),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Damping::Disabled,
+    );
+
+    let mut rf = RootFinder::new(parameters, initial_state.clone(), &iter_params, &res_config);
+    rf.set_jacobian_reuse(true);
+
+    let mut time_step = 1e-4;
+    let mut model = ImplicitEulerStep::new(initial_state, time_step);
+    let mut time = 0.0;
+    let mut jacobian_computations = 0;
+
+    println!(
+        "{:>12} {:>12} {:>12} {:>12} {:>10}",
+        "time", "y1", "y2", "y3", "iterations"
+    );
+    // the time step is doubled every 10 steps
+    for step in 0..160 {
+        if step > 0 && step % 10 == 0 {
+            time_step *= 2.0;
+            model.time_step = time_step;
+            rf.invalidate_jacobian();
+        }
+
+        rf.set_initial_guess(&model.previous_state);
+        if rf.solve(&mut model).is_err() {
+            // the jacobian of the previous time step was too far from the current one
+            rf.invalidate_jacobian();
+            rf.solve(&mut model).unwrap();
+        }
+        jacobian_computations += rf
+            .get_jacobian_sources()
+            .iter()
+            .filter(|&&source| source == JacobianSource::AnalyticalModel)
+            .count();
+
+        time += time_step;
+        model.previous_state = model.get_iteratives();
+        println!(
+            "{:>12.4e} {:>12.6} {:>12.4e} {:>12.6} {:>10}",
+            time,
+            model.previous_state[0],
+            model.previous_state[1],
+            model.previous_state[2],
+            rf.get_iter()
+        );
+    }
+
+    println!(
+        "\n{} jacobian computations for 160 time steps",
+        jacobian_computations
+    );
+}
@@ -80,6 +80,7 @@
 //! use newton_rootfinder as nrf;
 //! use nrf::model::Model; // trait import
 //! # use std::convert::Infallible;
+//! # use nrf::solver::{MaxIterations, ProblemSize};
 //! # use nalgebra;
 //!
 //! struct UserModel {
@@ -141,7 +142,7 @@
 //! #    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; problem_size];
 //! #    let residuals_configuration = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
 //! #
-//! #    let solver_parameters = nrf::solver::SolverParameters::new(1, 1e-6, 60, nrf::solver::ResolutionMethod::NewtonRaphson, true);
+//! #    let solver_parameters = nrf::solver::SolverParameters::new(ProblemSize::new(1), 1e-6, MaxIterations::new(60), nrf::solver::ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Enabled);
 //! #    let inital_guess = nalgebra::DVector::from_vec(vec![1.0]);
 //! #
 //!     // ...
@@ -192,12 +193,50 @@
 //! If defined in the user model, the solver can react to specific errors and propage them, without any panic.
 //! Check the [errors] module for more details
 //!
+//! ## Batch resolution
+//!
+//! Several models sharing the same configuration, for example the same equations with different constants,
+//! can be solved with a single solver, check the [solver::solve_batch] function.
+//!
+//! With the optional feature `rayon`, the models can be solved in parallel with `solver::solve_batch_parallel`:
+//! ```toml
+//! [dependencies]
+//! newton_rootfinder = { version = your_version, features = ["rayon"] }
+//! ```
+//!
+//! For a multi-start resolution, reproducible initial guesses are drawn from a seed with [solver::uniform_guesses]
+//! and the roots found are given by [solver::distinct_roots].
+//! The roots are given in the order of the initial guesses, as the results of [solver::solve_batch].
+//!
+//! ## Checkpointing
+//!
+//! A long resolution stopped by its budget can be continued later, by another process for example,
+//! with [solver::RootFinder::snapshot] and [solver::RootFinder::resume].
+//!
+//! With the optional feature `serde`, the [solver::SolverSnapshot] can be serialized:
+//! ```toml
+//! [dependencies]
+//! newton_rootfinder = { version = your_version, features = ["serde"] }
+//! ```
+//!
 //! ## Debugging
 //!
 //! In order to be able to debug more easily the resolution process, it is possible to generate a simulation log.
 //!
 //! Check the [solver::RootFinder::activate_debug] method.
 //!
+//! By default, the log is written to its file as the resolution progresses.
+//! To avoid the input/output operations during the iterations,
+//! it can be kept in memory and written at the end of the resolution,
+//! check the [solver::RootFinder::activate_debug_with_mode] method.
+//!
+//! The log is available with the `debug_log` feature, enabled by default.
+//! For minimal builds, it can be disabled to compile only the numerical core:
+//! ```toml
+//! [dependencies]
+//! newton_rootfinder = { version = your_version, default-features = false, features = ["std"] }
+//! ```
+//!
 //! The optional feature `additional_log_info` allows to add in the log informations such as:
 //! - the time of the computation (UTC and local time)
 //! - user information such as plateform, id, ...
@@ -209,6 +248,9 @@
 //! newton_rootfinder = { version = your_version, features = ["additional_log_info"] }
 //! ```
 //!
+//! Metadata specific to the application, such as the identifier of a job or the version of the model,
+//! can be added to the header of the log with `RootFinder::set_log_metadata()`, without this feature.
+//!
 //! ## User interface
 //!
 //! To ease the parametrization of the solver, it is possible to set up the parametrization through an external `.xml` configuration file.
@@ -228,6 +270,27 @@
 //! even if the user intend to not use the xml configuration feature.
 //!
 //! ## Examples
+//!
+//! For the common case of solving `f(x) = 0` with the default configuration,
+//! [quick_solver](crate::solver::quick_solver) owns the parametrization of the iteratives and of the residuals:
+//! ```
+//! use newton_rootfinder as nrf;
+//!
+//! // Function to optimize: x**2 = 2
+//! pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+//!     x.map(|x| x * x - 2.0)
+//! }
+//!
+//! fn main() {
+//!     let mut solver = nrf::solver::quick_solver(1, nrf::solver::ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+//!     let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+//!     let report = solver.solve(&mut user_model).unwrap();
+//!
+//!     println!("{}", report.get_final_iteratives()[0]); // 1.4142135623747443
+//! }
+//! ```
+//!
+//! The same resolution with the parametrization defined explicitly:
 //! ```
 //! use newton_rootfinder as nrf;
 //! use nrf::model::Model; // trait import
@@ -256,7 +319,7 @@
 //!     // Parametrization of the solver
 //!     let init = nalgebra::DVector::from_vec(vec![1.0]);
 //!     let resolution_method = nrf::solver::ResolutionMethod::NewtonRaphson;
-//!     let damping = false;
+//!     let damping = nrf::solver::Damping::Disabled;
 //!     let mut rf = nrf::solver::default_with_guess(
 //!         init,
 //!         &iter_params,
@@ -344,11 +407,11 @@
 //!
 //! fn main() {
 //!     let solver_parameters = nrf::solver::SolverParameters::new(
-//!         1,
+//!         nrf::solver::ProblemSize::new(1),
 //!         1e-6,
-//!         50,
+//!         nrf::solver::MaxIterations::new(50),
 //!         nrf::solver::ResolutionMethod::NewtonRaphson,
-//!         false,
+//!         nrf::solver::Damping::Disabled,
 //!    );
 //!
 //!     let iterative_param = nrf::iteratives::IterativeParamsFD::default();
@@ -379,6 +442,26 @@
 //! }
 //! ```
 //!
+//! ## Without the standard library
+//!
+//! The crate relies on the standard library through the `std` feature, enabled by default.
+//! Without it, the crate is `no_std` and only requires an allocator:
+//! the numerical core (models, iteratives, residuals, jacobian and solver) is available,
+//! typically with static types for an embedded target.
+//! The floating point functions are then provided by the `libm` feature, one of `std` or `libm` being required:
+//! ```toml
+//! [dependencies]
+//! newton_rootfinder = { version = your_version, default-features = false, features = ["libm"] }
+//! ```
+//!
+//! Hence `cargo build --no-default-features` alone fails with a compilation error
+//! asking for one of the two features, `--no-default-features --features libm` must be used instead.
+//!
+//! The features performing input/output operations require `std`:
+//! `debug_log`, `additional_log_info`, `xml_config_file` and `rayon`.
+//! The errors of the model are then only required to implement `core::fmt::Display` and `core::fmt::Debug`,
+//! and the errors of the crate do not implement `std::error::Error`.
+//!
 //! ## Benchmark static vs dynamic:
 //!
 //! The use of static types provide a 30 times improvement versus dynamic type on 1D problems.
@@ -391,6 +474,15 @@
 //!
 //! The values returned by a user model must be such vectors and matrix
 
+// The examples keep an explicit `main` to show where the user code goes
+#![allow(clippy::needless_doctest_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("newton_rootfinder requires either the `std` feature or the `libm` feature");
+
+extern crate alloc;
+
 pub use solver_n_dimensional::model;
 
 pub use solver_n_dimensional::iteratives;
@@ -403,4 +495,6 @@ pub use solver_n_dimensional::xml_parser;
 
 pub use solver_n_dimensional::errors;
 
+pub use solver_n_dimensional::ovector_from_slice_generic;
+
 mod solver_n_dimensional;
@@ -2,6 +2,9 @@
 //!
 //! The error API exposed to the end user is represented by the enum [SolverError]
 //!
+//! Its [SolverErrorKind], given by [SolverError::kind], allows to handle the errors
+//! of different models with the same code.
+//!
 //! However, to have optimal integration between solver and model,
 //! it is expected to define the potential errors raised by the model
 //! through the associated types:
@@ -96,7 +99,7 @@
 //!     let mut init = nalgebra::DVector::zeros(problem_size);
 //!     init[0] = 1.0;
 //!
-//!     let damping = false;
+//!     let damping = nrf::solver::Damping::Disabled;
 //!
 //!     let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
 //!     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
@@ -126,8 +129,11 @@
 //! }
 //! ```
 
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
 /// Errors for solver control flow
 ///
@@ -141,6 +147,18 @@ where
 {
     InvalidJacobianError(crate::model::ModelError<M, D>),
     InvalidJacobianInverseError,
+    StructurallySingularJacobian {
+        zero_rows: Vec<usize>,
+        zero_cols: Vec<usize>,
+    },
+    LinearSolverNonConvergenceError,
+    MissingLinearSystemMatrixError,
+    NonFiniteQuasiNewtonUpdatesError,
+    ResidualMagnitudeExceeded {
+        id: usize,
+        value: f64,
+        limit: f64,
+    },
 }
 
 impl<M, D> fmt::Display for SolverInternalError<M, D>
@@ -154,10 +172,43 @@ where
         match self {
             Self::InvalidJacobianError(error) => write!(f, "Invalid jacobian: {}", error),
             Self::InvalidJacobianInverseError => write!(f, "Non invertible jacobian"),
+            Self::StructurallySingularJacobian {
+                zero_rows,
+                zero_cols,
+            } => write_structural_zeros(f, zero_rows, zero_cols),
+            Self::LinearSolverNonConvergenceError => {
+                write!(f, "The linear solver did not reach its tolerance")
+            }
+            Self::MissingLinearSystemMatrixError => write!(
+                f,
+                "The jacobian does not hold the matrix required by the linear solver"
+            ),
+            Self::NonFiniteQuasiNewtonUpdatesError => write!(
+                f,
+                "Consecutive quasi-Newton updates produced non-finite values"
+            ),
+            Self::ResidualMagnitudeExceeded { id, value, limit } => write!(
+                f,
+                "Magnitude of the residual {} exceeded at a perturbed point: {:e} is above the limit {:e}",
+                id, value, limit
+            ),
         }
     }
 }
 
+/// Null rows and columns of a structurally singular jacobian
+fn write_structural_zeros(
+    f: &mut fmt::Formatter,
+    zero_rows: &[usize],
+    zero_cols: &[usize],
+) -> fmt::Result {
+    write!(
+        f,
+        "Structurally singular jacobian: the residuals {:?} depend on no iterative and the iteratives {:?} affect no residual",
+        zero_rows, zero_cols
+    )
+}
+
 impl<M, D> fmt::Debug for SolverInternalError<M, D>
 where
     M: crate::model::Model<D>,
@@ -170,16 +221,107 @@ where
     }
 }
 
+#[derive(Debug)]
 pub struct NonInvertibleJacobian;
 
+#[derive(Debug)]
+pub struct LinearSolverNonConvergence;
+
+/// Residuals above their tolerance when a resolution stops without reaching the convergence,
+/// carried by [SolverError::NonConvergenceError]
+///
+/// The residuals are given with their stopping error, sorted by decreasing error.
+/// If the convergence is checked on the groups of residuals (see [crate::residuals::ResidualsConfig::set_group_convergence]),
+/// they are the residuals above the tolerance of the groups not converged.
+/// The groups are the ones not converged in that case, or the ones of the residuals listed otherwise.
+///
+/// Without any residual or group, the convergence failed on the steps of the iteratives,
+/// see the `convergence_step_tolerance()` method of the [crate::iteratives::Iterative] trait.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnconvergedResiduals {
+    residuals: Vec<(usize, f64)>,
+    groups: Vec<String>,
+}
+
+impl UnconvergedResiduals {
+    pub fn new(mut residuals: Vec<(usize, f64)>, groups: Vec<String>) -> Self {
+        residuals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        UnconvergedResiduals { residuals, groups }
+    }
+
+    /// Index and stopping error of the residuals, sorted by decreasing error
+    pub fn get_residuals(&self) -> &[(usize, f64)] {
+        &self.residuals
+    }
+
+    /// Names of the groups of residuals
+    pub fn get_groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// Residual with the largest error
+    pub fn get_worst(&self) -> Option<(usize, f64)> {
+        self.residuals.first().copied()
+    }
+
+    /// No residual nor group is blocking the convergence
+    pub fn is_empty(&self) -> bool {
+        self.residuals.is_empty() && self.groups.is_empty()
+    }
+}
+
+impl fmt::Display for UnconvergedResiduals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((index, error)) = self.get_worst() {
+            write!(
+                f,
+                "{} residual(s) above the tolerance, the largest error being {} for the residual {}",
+                self.residuals.len(),
+                error,
+                index
+            )?;
+            if !self.groups.is_empty() {
+                write!(f, ", ")?;
+            }
+        }
+        if !self.groups.is_empty() {
+            write!(f, "groups not converged: {}", self.groups.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 /// Error returned by the [crate::solver::RootFinder::solve] method
 ///
 /// Exit status:
-/// - [SolverError::NonConvergenceError] : finished all the iterations but didn't find a root
+/// - [SolverError::NonConvergenceError] : finished all the iterations but didn't find a root,
+///   the residuals blocking the convergence being given by [UnconvergedResiduals]
 /// - [SolverError::ModelInitialEvaluationError] : the algorithm must be able to evaluate the model correctly at the begin of the resolution process, it failed in that case
 /// - [SolverError::ModelEvaluationError] : during the iterative process, while performing an update, a model error occured
-/// - [SolverError::JacobianError] : during the jacobian evaluation, an error occured
+/// - [SolverError::JacobianEvaluationError] : the model returned an error during the evaluation of the jacobian,
+///   for example at a perturbed point of the finite differences,
+///   once the retries of [crate::solver::InaccurateJacobianPolicy::RetryColumnWithReducedStep] are exhausted,
+///   for the inaccurate as well as the unusable values
+/// - [SolverError::JacobianInversionError] : the jacobian is singular at the `iteration`,
+///   the inversion failing on a null pivot.
+///   The `condition_estimate` is the ratio of the largest to the smallest absolute pivot of the LU decomposition of the jacobian,
+///   see [crate::solver::JacobianMatrix::get_condition_estimate], `None` when unknown.
+///   The solver does not regularize the jacobian to recover from it
+/// - [SolverError::StructurallySingularJacobian] : the computed jacobian has null rows, the residuals `zero_rows` depending on no iterative,
+///   or null columns, the iteratives `zero_cols` affecting no residual.
+///   The check is done before the inversion, see [crate::solver::StructuralSingularityPolicy] to remove them from the system instead
+/// - [SolverError::JacobianError] : the linear solver did not reach its tolerance
+///   or the quasi-Newton updates produced non-finite values
 /// - [SolverError::FinalEvaluationError] : the algorithm managed to converged but the model returned an error at convergence
+/// - [SolverError::ConvergedOnBoundsError] : the algorithm managed to converged but some iteratives are on their bounds,
+///   only returned if [crate::solver::SolverParameters::set_reject_solution_on_bounds] is activated
+/// - [SolverError::ResidualMagnitudeExceeded] : a member of the residual `id` exceeded its maximum magnitude `limit`
+///   after the evaluation of the model at the `iteration`, `0` being the initial guess,
+///   or at a perturbed point of the finite difference jacobian without previous column to keep,
+///   see [crate::residuals::ResidualsConfig::set_max_magnitudes]
+/// - [SolverError::InitialGuessOutOfBounds] : the `value` of the initial guess of the `iterative`
+///   is outside of its bounds `[min_value, max_value]`,
+///   only returned with [crate::solver::BoundPolicy::Fail]
 pub enum SolverError<M, D>
 where
     M: crate::model::Model<D>,
@@ -187,11 +329,33 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
 {
-    NonConvergenceError,
+    NonConvergenceError(UnconvergedResiduals),
     ModelInitialEvaluationError(String),
     ModelEvaluationError(crate::model::ModelError<M, D>),
+    JacobianEvaluationError(crate::model::ModelError<M, D>),
+    JacobianInversionError {
+        iteration: usize,
+        condition_estimate: Option<f64>,
+    },
+    StructurallySingularJacobian {
+        zero_rows: Vec<usize>,
+        zero_cols: Vec<usize>,
+    },
     JacobianError(SolverInternalError<M, D>),
     FinalEvaluationError,
+    ConvergedOnBoundsError,
+    ResidualMagnitudeExceeded {
+        id: usize,
+        value: f64,
+        limit: f64,
+        iteration: usize,
+    },
+    InitialGuessOutOfBounds {
+        iterative: usize,
+        value: f64,
+        min_value: f64,
+        max_value: f64,
+    },
 }
 
 impl<M, D> fmt::Display for SolverError<M, D>
@@ -203,19 +367,71 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::NonConvergenceError => write!(f, "Convergence not reached"),
+            Self::NonConvergenceError(unconverged_residuals) => {
+                if unconverged_residuals.is_empty() {
+                    write!(f, "Convergence not reached")
+                } else {
+                    write!(f, "Convergence not reached: {}", unconverged_residuals)
+                }
+            }
             Self::ModelInitialEvaluationError(error) => {
                 write!(f, "Initial model evaluation failed: {}", error)
             }
             Self::ModelEvaluationError(error) => {
                 write!(f, "Model evaluation failed: {}", error)
             }
+            Self::JacobianEvaluationError(error) => {
+                write!(f, "Jacobian evaluation failed: {}", error)
+            }
+            Self::JacobianInversionError {
+                iteration,
+                condition_estimate,
+            } => {
+                write!(f, "Jacobian inversion failed at iteration {}", iteration)?;
+                match condition_estimate {
+                    Some(condition_estimate) => {
+                        write!(f, ", condition number estimate: {:e}", condition_estimate)
+                    }
+                    None => Ok(()),
+                }
+            }
+            Self::StructurallySingularJacobian {
+                zero_rows,
+                zero_cols,
+            } => write_structural_zeros(f, zero_rows, zero_cols),
             Self::JacobianError(error) => {
                 write!(f, "Jacobian error: {}", error)
             }
             Self::FinalEvaluationError => {
                 write!(f, "Final model evaluation failed")
             }
+            Self::ConvergedOnBoundsError => {
+                write!(f, "Convergence reached with iteratives on their bounds")
+            }
+            Self::ResidualMagnitudeExceeded {
+                id,
+                value,
+                limit,
+                iteration,
+            } => {
+                write!(
+                    f,
+                    "Magnitude of the residual {} exceeded at iteration {}: {:e} is above the limit {:e}",
+                    id, iteration, value, limit
+                )
+            }
+            Self::InitialGuessOutOfBounds {
+                iterative,
+                value,
+                min_value,
+                max_value,
+            } => {
+                write!(
+                    f,
+                    "Initial guess of the iterative {} out of its bounds: {} is outside of [{}, {}]",
+                    iterative, value, min_value, max_value
+                )
+            }
         }
     }
 }
@@ -232,6 +448,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<M, D> Error for SolverError<M, D>
 where
     M: crate::model::Model<D>,
@@ -240,3 +457,428 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
 {
 }
+
+impl<M, D> SolverError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    /// Kind of the error, without the type parameters of the model, see [SolverErrorKind]
+    pub fn kind(&self) -> SolverErrorKind {
+        match self {
+            Self::NonConvergenceError(_) => SolverErrorKind::NonConvergence,
+            Self::ModelInitialEvaluationError(error) => {
+                SolverErrorKind::ModelInitialEvaluation(error.clone())
+            }
+            Self::ModelEvaluationError(error) => {
+                SolverErrorKind::ModelEvaluation(error.to_string())
+            }
+            Self::JacobianEvaluationError(error) => {
+                SolverErrorKind::JacobianEvaluation(error.to_string())
+            }
+            Self::JacobianInversionError { .. } => SolverErrorKind::JacobianInversion,
+            Self::StructurallySingularJacobian { .. } => {
+                SolverErrorKind::StructurallySingularJacobian
+            }
+            Self::JacobianError(error) => SolverErrorKind::Jacobian(error.to_string()),
+            Self::FinalEvaluationError => SolverErrorKind::FinalEvaluation,
+            Self::ConvergedOnBoundsError => SolverErrorKind::ConvergedOnBounds,
+            Self::ResidualMagnitudeExceeded { id, .. } => {
+                SolverErrorKind::ResidualMagnitudeExceeded(*id)
+            }
+            Self::InitialGuessOutOfBounds { iterative, .. } => {
+                SolverErrorKind::InitialGuessOutOfBounds(*iterative)
+            }
+        }
+    }
+
+    /// Context of the error of the model, if any, see [crate::model::ModelError::get_context]
+    ///
+    /// The context of an error of the initial evaluation is only given by its message
+    pub fn get_model_error_context(&self) -> Option<crate::model::ErrorContext> {
+        match self {
+            Self::ModelEvaluationError(error) => error.get_context(),
+            Self::JacobianEvaluationError(error)
+            | Self::JacobianError(SolverInternalError::InvalidJacobianError(error)) => {
+                error.get_context()
+            }
+            _ => None,
+        }
+    }
+
+    /// Public error of a jacobian error of the solver control flow
+    ///
+    /// The failures of the evaluation and of the inversion of the jacobian get their own variant,
+    /// the inversion failing at the `iteration` with the `condition_estimate` of the jacobian.
+    /// A residual exceeding its maximum magnitude at a perturbed point is reported at the `iteration`
+    pub(crate) fn from_internal(
+        error: SolverInternalError<M, D>,
+        iteration: usize,
+        condition_estimate: Option<f64>,
+    ) -> Self {
+        match error {
+            SolverInternalError::InvalidJacobianError(error) => {
+                Self::JacobianEvaluationError(error)
+            }
+            SolverInternalError::InvalidJacobianInverseError => Self::JacobianInversionError {
+                iteration,
+                condition_estimate,
+            },
+            SolverInternalError::StructurallySingularJacobian {
+                zero_rows,
+                zero_cols,
+            } => Self::StructurallySingularJacobian {
+                zero_rows,
+                zero_cols,
+            },
+            SolverInternalError::ResidualMagnitudeExceeded { id, value, limit } => {
+                Self::ResidualMagnitudeExceeded {
+                    id,
+                    value,
+                    limit,
+                    iteration,
+                }
+            }
+            error => Self::JacobianError(error),
+        }
+    }
+}
+
+/// Kind of a [SolverError], independent of the model
+///
+/// A [SolverError] depends on the type of the model, through the errors it can return.
+/// Its kind allows to handle the errors of different models with the same code,
+/// for example to count the failures of the resolutions:
+/// the errors of the model are given by their message.
+///
+/// The [UnconvergedResiduals] of a non convergence remain available on the [SolverError].
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::errors::{SolverError, SolverErrorKind};
+/// use nrf::model::UserModelFromFunction;
+///
+/// fn is_retryable(kind: &SolverErrorKind) -> bool {
+///     matches!(kind, SolverErrorKind::NonConvergence | SolverErrorKind::JacobianInversion)
+/// }
+///
+/// let error: SolverError<UserModelFromFunction, nalgebra::Dyn> =
+///     SolverError::FinalEvaluationError;
+/// assert_eq!(error.kind(), SolverErrorKind::FinalEvaluation);
+/// assert!(!is_retryable(&error.kind()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SolverErrorKind {
+    /// See [SolverError::NonConvergenceError]
+    NonConvergence,
+    /// See [SolverError::ModelInitialEvaluationError], with the message of the model error
+    ModelInitialEvaluation(String),
+    /// See [SolverError::ModelEvaluationError], with the message of the model error
+    ModelEvaluation(String),
+    /// See [SolverError::JacobianEvaluationError], with the message of the model error
+    JacobianEvaluation(String),
+    /// See [SolverError::JacobianInversionError]
+    JacobianInversion,
+    /// See [SolverError::StructurallySingularJacobian]
+    StructurallySingularJacobian,
+    /// See [SolverError::JacobianError], with the message of the jacobian error
+    Jacobian(String),
+    /// See [SolverError::FinalEvaluationError]
+    FinalEvaluation,
+    /// See [SolverError::ConvergedOnBoundsError]
+    ConvergedOnBounds,
+    /// See [SolverError::ResidualMagnitudeExceeded], with the index of the residual
+    ResidualMagnitudeExceeded(usize),
+    /// See [SolverError::InitialGuessOutOfBounds], with the index of the iterative
+    InitialGuessOutOfBounds(usize),
+}
+
+/// Error returned by the [crate::solver::RootFinder::solve_with_fallback] method
+///
+/// It gathers the failure of each attempted resolution method, in the order of the attempts
+pub struct FallbackError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    attempts: Vec<(crate::solver::ResolutionMethod, SolverError<M, D>)>,
+}
+
+impl<M, D> FallbackError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    pub fn new(attempts: Vec<(crate::solver::ResolutionMethod, SolverError<M, D>)>) -> Self {
+        FallbackError { attempts }
+    }
+
+    pub fn get_attempts(&self) -> &[(crate::solver::ResolutionMethod, SolverError<M, D>)] {
+        &self.attempts
+    }
+}
+
+impl<M, D> fmt::Display for FallbackError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "All {} resolution methods failed:", self.attempts.len())?;
+        for (i, (resolution_method, error)) in self.attempts.iter().enumerate() {
+            write!(
+                f,
+                "\n- attempt {} with {}: {}",
+                i + 1,
+                resolution_method,
+                error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<M, D> fmt::Debug for FallbackError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M, D> Error for FallbackError<M, D>
+where
+    M: crate::model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+}
+
+/// Error returned by [crate::residuals::JacobianValues::normalize_checked]
+///
+/// It names the dimension that does not match the size of the jacobian
+#[derive(Debug, Clone, PartialEq)]
+pub enum JacobianDimensionError {
+    /// The number of residuals values differs from the size of the jacobian
+    ResidualsValues { jacobian: usize, residuals: usize },
+    /// The number of normalization methods differs from the size of the jacobian
+    NormalizationMethods {
+        jacobian: usize,
+        normalization_methods: usize,
+    },
+}
+
+impl fmt::Display for JacobianDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ResidualsValues {
+                jacobian,
+                residuals,
+            } => write!(
+                f,
+                "The jacobian has {} rows but {} residuals values are provided",
+                jacobian, residuals
+            ),
+            Self::NormalizationMethods {
+                jacobian,
+                normalization_methods,
+            } => write!(
+                f,
+                "The jacobian has {} rows but {} normalization methods are provided",
+                jacobian, normalization_methods
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for JacobianDimensionError {}
+
+/// Error returned by [crate::solver::RootFinder::try_new]
+///
+/// It names the input whose size does not match the problem size of the solver parameters
+#[derive(Debug, Clone, PartialEq)]
+pub enum RootFinderConstructionError {
+    /// The initial guess given to [crate::solver::try_default_with_guess] is empty,
+    /// or the one given to [crate::solver::RootFinder::try_new] when the problem size is inferred
+    EmptyProblem,
+    /// The size of the residuals configuration differs from the problem size
+    ResidualsConfigSizeMismatch {
+        residuals_config: usize,
+        problem_size: usize,
+    },
+    /// The size of the initial guess differs from the problem size
+    InitialGuessSizeMismatch {
+        initial_guess: usize,
+        problem_size: usize,
+    },
+    /// The number of iteratives differs from the problem size
+    IterativesSizeMismatch {
+        iteratives: usize,
+        problem_size: usize,
+    },
+    /// The tolerance is negative or not finite
+    InvalidTolerance { tolerance: f64 },
+    /// The tolerance is below the floor [crate::solver::MIN_TOLERANCE],
+    /// see [crate::solver::SolverParameters::allow_extreme_tolerance]
+    ToleranceBelowFloor { tolerance: f64, floor: f64 },
+    /// The number of ignored residuals differs from the number of fixed iteratives,
+    /// see the `fixed_value()` method of the [crate::iteratives::Iterative] trait
+    FixedIterativesMismatch {
+        fixed_iteratives: usize,
+        ignored_residuals: usize,
+    },
+    /// The storage estimated for the jacobian is above the memory limit,
+    /// see [crate::solver::SolverParameters::set_memory_limit_bytes]
+    MemoryLimitExceeded {
+        estimated_bytes: usize,
+        limit_bytes: usize,
+    },
+    /// The parameters and the iteratives prevent the resolution whatever the model,
+    /// see [crate::solver::CompatibilityWarning::prevents_resolution]
+    IncompatibleConfiguration(crate::solver::CompatibilityWarning),
+}
+
+impl fmt::Display for RootFinderConstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyProblem => write!(f, "The problem size must be strictly positive, got 0"),
+            Self::ResidualsConfigSizeMismatch {
+                residuals_config,
+                problem_size,
+            } => write!(
+                f,
+                "Dimension mismatch :\n residuals_config.len() = {} and problem_size = {}",
+                residuals_config, problem_size
+            ),
+            Self::InitialGuessSizeMismatch {
+                initial_guess,
+                problem_size,
+            } => write!(
+                f,
+                "Dimension mismatch :\n initial_guess.len() = {} and problem_size = {}",
+                initial_guess, problem_size
+            ),
+            Self::IterativesSizeMismatch {
+                iteratives,
+                problem_size,
+            } => write!(
+                f,
+                "Dimension mismatch :\n iters_params.len() = {} and problem_size = {}",
+                iteratives, problem_size
+            ),
+            Self::InvalidTolerance { tolerance } => write!(
+                f,
+                "The tolerance must be finite and non-negative, provided value was {}",
+                tolerance
+            ),
+            Self::ToleranceBelowFloor { tolerance, floor } => write!(
+                f,
+                "The tolerance {:e} is below the floor {:e}: the residuals would never reach it. \
+                 Use `SolverParameters::allow_extreme_tolerance()` to accept it",
+                tolerance, floor
+            ),
+            Self::FixedIterativesMismatch {
+                fixed_iteratives,
+                ignored_residuals,
+            } => write!(
+                f,
+                "The number of ignored residuals must be the number of fixed iteratives to keep the system square, got {} fixed iteratives and {} ignored residuals",
+                fixed_iteratives, ignored_residuals
+            ),
+            Self::MemoryLimitExceeded {
+                estimated_bytes,
+                limit_bytes,
+            } => write!(
+                f,
+                "The storage estimated for the jacobian is {} bytes, above the memory limit of {} bytes. \
+                 Use the `QuasiNewtonMethod::LimitedMemoryBroyden` method or the `LinearSolver::Gmres` linear solver to reduce it",
+                estimated_bytes, limit_bytes
+            ),
+            Self::IncompatibleConfiguration(warning) => write!(f, "{}", warning),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RootFinderConstructionError {}
+
+/// Error returned when accessing the iteratives of a model by index or from a slice,
+/// see [crate::model::IterativesAccess]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IterativesAccessError {
+    /// The index is not lower than the problem size
+    IndexOutOfRange { index: usize, problem_size: usize },
+    /// The length of the slice differs from the problem size
+    LengthMismatch { length: usize, problem_size: usize },
+}
+
+impl fmt::Display for IterativesAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IndexOutOfRange {
+                index,
+                problem_size,
+            } => write!(
+                f,
+                "The iterative index {} is out of range, the problem size is {}",
+                index, problem_size
+            ),
+            Self::LengthMismatch {
+                length,
+                problem_size,
+            } => write!(
+                f,
+                "Dimension mismatch :\n iteratives.len() = {} and problem_size = {}",
+                length, problem_size
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for IterativesAccessError {}
+
+/// Error returned when solving the linear system of a Newton iteration,
+/// see [crate::solver::solve_newton_system]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewtonSystemError {
+    /// The jacobian does not hold the matrix required by the linear solver:
+    /// the inverse for the direct inverse, the jacobian itself for GMRES
+    MissingMatrix,
+    /// The linear solver did not reach its tolerance
+    LinearSolverNonConvergence,
+}
+
+impl fmt::Display for NewtonSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingMatrix => write!(
+                f,
+                "The jacobian does not hold the matrix required by the linear solver"
+            ),
+            Self::LinearSolverNonConvergence => {
+                write!(f, "The linear solver did not reach its tolerance")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NewtonSystemError {}
@@ -1,3 +1,6 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 /// Constructor with default values for iteratives parameters
 ///
 /// # Examples
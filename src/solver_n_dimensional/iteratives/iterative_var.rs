@@ -1,15 +1,33 @@
 use super::Iterative;
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 /// The parameters of an iterative variable
 ///
 /// This parameters are used by the `step_limitation()` method from the `Iterative` trait to reduce the size of a step
+///
+/// The steps increasing and decreasing the iterative can be limited differently
+/// with `set_max_step_abs_up()`, `set_max_step_abs_down()`, `set_max_step_rel_up()` and `set_max_step_rel_down()`,
+/// the unset directional limits being the symmetric `max_step_abs` and `max_step_rel`
+///
+/// A convergence step tolerance can also be set with `set_convergence_step_tolerance()`,
+/// see the `convergence_step_tolerance()` method of the `Iterative` trait
+///
+/// The iterative can be fixed at a value with `set_fixed()`,
+/// see the `fixed_value()` method of the `Iterative` trait
 #[derive(Debug, Clone, PartialEq)]
 pub struct IterativeParams {
     max_step_abs: f64,
     max_step_rel: f64,
     min_value: f64,
     max_value: f64,
+    max_step_abs_up: Option<f64>,
+    max_step_abs_down: Option<f64>,
+    max_step_rel_up: Option<f64>,
+    max_step_rel_down: Option<f64>,
+    convergence_step_tolerance: Option<f64>,
+    fixed: Option<f64>,
 }
 
 impl IterativeParams {
@@ -19,31 +37,60 @@ impl IterativeParams {
     ///
     /// The `min_value` must be lower than the `max_value`
     pub fn new(max_step_abs: f64, max_step_rel: f64, min_value: f64, max_value: f64) -> Self {
-        if max_step_abs <= 0.0 {
-            panic!(
-                "max_step_abs must be strictly positive, provided value was {}",
-                max_step_abs
-            );
-        }
-        if max_step_rel <= 0.0 {
-            panic!(
-                "max_step_rel must be strictly positive, provided value was {}",
-                max_step_rel
-            );
+        if let Err(message) = Self::check_values(max_step_abs, max_step_rel, min_value, max_value) {
+            panic!("{}", message);
         }
 
-        if min_value >= max_value {
-            panic!(
-                "min_value must be strictly inferior to max_value, provided values are {} > {}",
-                min_value, max_value
-            );
+        IterativeParams {
+            max_step_abs,
+            max_step_rel,
+            min_value,
+            max_value,
+            max_step_abs_up: None,
+            max_step_abs_down: None,
+            max_step_rel_up: None,
+            max_step_rel_down: None,
+            convergence_step_tolerance: None,
+            fixed: None,
         }
+    }
 
+    /// Constant constructor, usable to define the parameters in a `const` or a `static`
+    ///
+    /// Unlike [IterativeParams::new], the values are **not validated**:
+    /// floating point comparisons are not available in constant functions with the minimal supported `rustc`.
+    /// The caller is responsible for `max_step_abs` and `max_step_rel` being strictly positive
+    /// and `min_value` being lower than `max_value`, otherwise the step limitations are meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::IterativeParams;
+    ///
+    /// static ITERATIVES: [IterativeParams; 2] = [
+    ///     IterativeParams::new_unchecked_const(1.0, 0.5, 0.0, f64::INFINITY),
+    ///     IterativeParams::new_unchecked_const(10.0, f64::INFINITY, -5.0, 5.0),
+    /// ];
+    ///
+    /// assert_eq!(ITERATIVES[0], IterativeParams::new(1.0, 0.5, 0.0, f64::INFINITY));
+    /// ```
+    pub const fn new_unchecked_const(
+        max_step_abs: f64,
+        max_step_rel: f64,
+        min_value: f64,
+        max_value: f64,
+    ) -> Self {
         IterativeParams {
             max_step_abs,
             max_step_rel,
             min_value,
             max_value,
+            max_step_abs_up: None,
+            max_step_abs_down: None,
+            max_step_rel_up: None,
+            max_step_rel_down: None,
+            convergence_step_tolerance: None,
+            fixed: None,
         }
     }
 
@@ -62,6 +109,180 @@ impl IterativeParams {
     pub fn get_max_step_rel(&self) -> f64 {
         self.max_step_rel
     }
+
+    pub fn get_max_step_abs_up(&self) -> Option<f64> {
+        self.max_step_abs_up
+    }
+
+    pub fn get_max_step_abs_down(&self) -> Option<f64> {
+        self.max_step_abs_down
+    }
+
+    pub fn get_max_step_rel_up(&self) -> Option<f64> {
+        self.max_step_rel_up
+    }
+
+    pub fn get_max_step_rel_down(&self) -> Option<f64> {
+        self.max_step_rel_down
+    }
+
+    /// Limit the absolute size of the steps increasing the iterative, instead of `max_step_abs`
+    ///
+    /// The limit must be strictly positive, `None` restores the symmetric `max_step_abs`
+    pub fn set_max_step_abs_up(&mut self, max_step: Option<f64>) {
+        if let Err(message) = Self::check_directional_max_step("max_step_abs_up", max_step) {
+            panic!("{}", message);
+        }
+        self.max_step_abs_up = max_step;
+    }
+
+    /// Limit the absolute size of the steps decreasing the iterative, instead of `max_step_abs`
+    ///
+    /// The limit must be strictly positive, `None` restores the symmetric `max_step_abs`
+    pub fn set_max_step_abs_down(&mut self, max_step: Option<f64>) {
+        if let Err(message) = Self::check_directional_max_step("max_step_abs_down", max_step) {
+            panic!("{}", message);
+        }
+        self.max_step_abs_down = max_step;
+    }
+
+    /// Limit the relative size of the steps increasing the iterative, instead of `max_step_rel`
+    ///
+    /// The limit must be strictly positive, `None` restores the symmetric `max_step_rel`
+    pub fn set_max_step_rel_up(&mut self, max_step: Option<f64>) {
+        if let Err(message) = Self::check_directional_max_step("max_step_rel_up", max_step) {
+            panic!("{}", message);
+        }
+        self.max_step_rel_up = max_step;
+    }
+
+    /// Limit the relative size of the steps decreasing the iterative, instead of `max_step_rel`
+    ///
+    /// The limit must be strictly positive, `None` restores the symmetric `max_step_rel`
+    pub fn set_max_step_rel_down(&mut self, max_step: Option<f64>) {
+        if let Err(message) = Self::check_directional_max_step("max_step_rel_down", max_step) {
+            panic!("{}", message);
+        }
+        self.max_step_rel_down = max_step;
+    }
+
+    /// Limits of the absolute and relative size of a step of the given sign,
+    /// the directional limits falling back to the symmetric ones
+    fn max_steps(&self, raw_step: f64) -> (f64, f64) {
+        if raw_step >= 0.0 {
+            (
+                self.max_step_abs_up.unwrap_or(self.max_step_abs),
+                self.max_step_rel_up.unwrap_or(self.max_step_rel),
+            )
+        } else {
+            (
+                self.max_step_abs_down.unwrap_or(self.max_step_abs),
+                self.max_step_rel_down.unwrap_or(self.max_step_rel),
+            )
+        }
+    }
+
+    pub fn get_convergence_step_tolerance(&self) -> Option<f64> {
+        self.convergence_step_tolerance
+    }
+
+    /// Require the absolute value of the last step of the iterative to be below `step_tolerance`
+    /// for the solver to declare the convergence, in addition to the residuals criteria
+    ///
+    /// It is useful for variables whose residual converges before the variable itself stabilizes.
+    ///
+    /// The tolerance must be strictly positive, `None` removes the requirement
+    pub fn set_convergence_step_tolerance(&mut self, step_tolerance: Option<f64>) {
+        if let Err(message) = Self::check_convergence_step_tolerance(step_tolerance) {
+            panic!("{}", message);
+        }
+        self.convergence_step_tolerance = step_tolerance;
+    }
+
+    pub fn get_fixed(&self) -> Option<f64> {
+        self.fixed
+    }
+
+    /// Fix the iterative at `value` for the resolution, `None` releasing it
+    ///
+    /// The iterative is set to this value in the initial guess and is never updated by the solver.
+    /// It removes a variable of the system: as many residuals must be ignored,
+    /// see `ResidualsConfig::set_ignored_residuals()`.
+    ///
+    /// The value must be finite
+    pub fn set_fixed(&mut self, value: Option<f64>) {
+        if let Err(message) = Self::check_fixed(value) {
+            panic!("{}", message);
+        }
+        self.fixed = value;
+    }
+
+    /// Check the values expected by [IterativeParams::new], returning the error message
+    pub(crate) fn check_values(
+        max_step_abs: f64,
+        max_step_rel: f64,
+        min_value: f64,
+        max_value: f64,
+    ) -> Result<(), String> {
+        if max_step_abs <= 0.0 {
+            return Err(format!(
+                "max_step_abs must be strictly positive, provided value was {}",
+                max_step_abs
+            ));
+        }
+        if max_step_rel <= 0.0 {
+            return Err(format!(
+                "max_step_rel must be strictly positive, provided value was {}",
+                max_step_rel
+            ));
+        }
+        if min_value >= max_value {
+            return Err(format!(
+                "min_value must be strictly inferior to max_value, provided values are {} > {}",
+                min_value, max_value
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the value expected by the setters of the directional limits,
+    /// such as [IterativeParams::set_max_step_abs_up], returning the error message
+    pub(crate) fn check_directional_max_step(
+        name: &str,
+        max_step: Option<f64>,
+    ) -> Result<(), String> {
+        match max_step {
+            Some(max_step) if max_step <= 0.0 => Err(format!(
+                "{} must be strictly positive, provided value was {}",
+                name, max_step
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the value expected by [IterativeParams::set_convergence_step_tolerance], returning the error message
+    pub(crate) fn check_convergence_step_tolerance(
+        step_tolerance: Option<f64>,
+    ) -> Result<(), String> {
+        match step_tolerance {
+            Some(tolerance) if tolerance <= 0.0 => Err(format!(
+                "convergence_step_tolerance must be strictly positive, provided value was {}",
+                tolerance
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the value expected by [IterativeParams::set_fixed], returning the error message
+    pub(crate) fn check_fixed(value: Option<f64>) -> Result<(), String> {
+        match value {
+            Some(value) if !value.is_finite() => Err(format!(
+                "The fixed value must be finite, provided value was {}",
+                value
+            )),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Default for IterativeParams {
@@ -71,6 +292,12 @@ impl Default for IterativeParams {
             max_step_rel: f64::INFINITY,
             min_value: f64::NEG_INFINITY,
             max_value: f64::INFINITY,
+            max_step_abs_up: None,
+            max_step_abs_down: None,
+            max_step_rel_up: None,
+            max_step_rel_down: None,
+            convergence_step_tolerance: None,
+            fixed: None,
         }
     }
 }
@@ -85,6 +312,13 @@ impl Iterative for IterativeParams {
     ///```
     /// Also, the step must not violated the constraints on the `min_value` and `max_value` of the iterative variable.
     ///
+    /// A positive raw step is limited by `max_step_abs_up` and `max_step_rel_up` if they are set,
+    /// a negative one by `max_step_abs_down` and `max_step_rel_down`.
+    ///
+    /// The new value is always within `[min_value, max_value]`, even if the current value is not:
+    /// the step is limited and applied from the current value, the result being brought back onto the nearest bound.
+    /// A current value out of the bounds then gives the bound itself for any step not reaching the inside of the bounds.
+    ///
     /// **Warning**:
     /// setting the parameters max_step_rel to a value different from infinity
     /// might lead to very reduced step size if the iterative value is near zero.
@@ -103,11 +337,35 @@ impl Iterative for IterativeParams {
     /// let mut iterative_var = IterativeParams::new(max_step_abs, max_step_rel, min_value, max_value);
     /// assert_eq!(iterative_var.step_limitation(1.5, 0.5), 1.6);
     /// assert_eq!(iterative_var.step_limitation(0.1, 3.0), 0.15000000000000002);
+    ///
+    /// iterative_var.set_max_step_abs_up(Some(0.01));
+    /// assert_eq!(iterative_var.step_limitation(1.5, 0.5), 1.51);
+    /// assert_eq!(iterative_var.step_limitation(1.5, -0.5), 1.4);
+    ///
+    /// let iterative_var = IterativeParams::new(1.0, f64::INFINITY, 0.0, 10.0);
+    /// assert_eq!(iterative_var.step_limitation(-0.5, 0.75), 0.25);
+    /// assert_eq!(iterative_var.step_limitation(-0.5, 0.25), 0.0);
+    /// assert_eq!(iterative_var.step_limitation(-0.5, -0.5), 0.0);
     /// ```
     fn step_limitation(&self, value_current: f64, raw_step: f64) -> f64 {
-        let max_step = self
-            .max_step_abs
-            .min(self.max_step_rel * value_current.abs());
+        self.step_limitation_with_scale(value_current, raw_step, 1.0)
+    }
+
+    /// Compute a limited update step, with `max_step_abs` and `max_step_rel` multiplied by `scale`
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::*;
+    ///
+    /// let (max_step_abs, max_step_rel, min_value, max_value) = (0.1, 0.5, f64::NEG_INFINITY, 2.0);
+    /// let mut iterative_var = IterativeParams::new(max_step_abs, max_step_rel, min_value, max_value);
+    /// assert_eq!(iterative_var.step_limitation_with_scale(1.5, 0.3, 2.0), 1.7);
+    /// assert_eq!(iterative_var.step_limitation_with_scale(1.5, 3.0, f64::INFINITY), 2.0);
+    /// ```
+    fn step_limitation_with_scale(&self, value_current: f64, raw_step: f64, scale: f64) -> f64 {
+        let (max_step_abs, max_step_rel) = self.max_steps(raw_step);
+        let max_step = (scale * max_step_abs).min(scale * max_step_rel * value_current.abs());
 
         let abs_step = raw_step.abs();
         let sign_step = raw_step.signum();
@@ -119,6 +377,88 @@ impl Iterative for IterativeParams {
         // limitation by min_value and max_value
         (value_next_lim.max(self.min_value)).min(self.max_value)
     }
+
+    fn is_within_bounds(&self, value: f64) -> bool {
+        self.min_value <= value && value <= self.max_value
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        (self.min_value, self.max_value)
+    }
+
+    /// Check if a value is on one of the bounds
+    ///
+    /// Only the finite bounds are considered
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::*;
+    ///
+    /// let iterative_var = IterativeParams::new(1.0, 1.0, 0.0, f64::INFINITY);
+    /// assert!(iterative_var.is_on_bounds(0.0));
+    /// assert!(iterative_var.is_on_bounds(1e-12));
+    /// assert!(!iterative_var.is_on_bounds(1e-3));
+    /// ```
+    fn is_on_bounds(&self, value: f64) -> bool {
+        [self.min_value, self.max_value].iter().any(|bound| {
+            bound.is_finite()
+                && (value - bound).abs() <= super::ACTIVE_BOUND_TOLERANCE * bound.abs().max(1.0)
+        })
+    }
+
+    /// Compute an initial guess from the bounds of the iterative
+    ///
+    /// - the midpoint of `[min_value, max_value]` if both bounds are finite
+    /// - `min_value + 1.0` or `max_value - 1.0` if only one bound is finite
+    /// - `1.0` if the iterative is unbounded,
+    ///   as the relative step limitation and perturbation are null for `0.0`
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::*;
+    ///
+    /// let iterative_var = IterativeParams::new(1.0, 1.0, 2.0, 4.0);
+    /// assert_eq!(iterative_var.default_guess(), 3.0);
+    ///
+    /// let iterative_var = IterativeParams::new(1.0, 1.0, 2.0, f64::INFINITY);
+    /// assert_eq!(iterative_var.default_guess(), 3.0);
+    ///
+    /// let iterative_var = IterativeParams::new(1.0, 1.0, f64::NEG_INFINITY, 4.0);
+    /// assert_eq!(iterative_var.default_guess(), 3.0);
+    ///
+    /// let iterative_var = IterativeParams::default();
+    /// assert_eq!(iterative_var.default_guess(), 1.0);
+    /// ```
+    fn default_guess(&self) -> f64 {
+        match (self.min_value.is_finite(), self.max_value.is_finite()) {
+            (true, true) => self.min_value + (self.max_value - self.min_value) / 2.0,
+            (true, false) => self.min_value + 1.0,
+            (false, true) => self.max_value - 1.0,
+            (false, false) => 1.0,
+        }
+    }
+
+    fn convergence_step_tolerance(&self) -> Option<f64> {
+        self.convergence_step_tolerance
+    }
+
+    fn fixed_value(&self) -> Option<f64> {
+        self.fixed
+    }
+}
+
+/// Display of a step limit, as `+up/-down` if one of the directional limits is set
+fn format_max_step(max_step: f64, max_step_up: Option<f64>, max_step_down: Option<f64>) -> String {
+    match (max_step_up, max_step_down) {
+        (None, None) => max_step.to_string(),
+        _ => format!(
+            "+{}/-{}",
+            max_step_up.unwrap_or(max_step),
+            max_step_down.unwrap_or(max_step)
+        ),
+    }
 }
 
 impl fmt::Display for IterativeParams {
@@ -126,12 +466,20 @@ impl fmt::Display for IterativeParams {
         let mut content = String::new();
         content.push_str(&format!(
             " {:width$}|",
-            &self.max_step_abs.to_string(),
+            &format_max_step(
+                self.max_step_abs,
+                self.max_step_abs_up,
+                self.max_step_abs_down
+            ),
             width = 13
         ));
         content.push_str(&format!(
             " {:width$}|",
-            &self.max_step_rel.to_string(),
+            &format_max_step(
+                self.max_step_rel,
+                self.max_step_rel_up,
+                self.max_step_rel_down
+            ),
             width = 13
         ));
         content.push_str(&format!(
@@ -1,12 +1,22 @@
 use super::Iterative;
 use super::IterativeParams;
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
 
 /// Perturbation method used for the `compute_perturbation()` method from the `Iterative` trait by the `IterativeParamsFD` struct
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PerturbationMethod {
     Max,
     Sum,
+    /// Square root of the sum of the squares of the absolute and relative perturbations
+    ///
+    /// It transitions smoothly between the absolute and relative perturbations,
+    /// without the kink of `Max` when the value of the iterative is around `dx_abs / dx_rel`
+    Quadrature,
 }
 
 impl fmt::Display for PerturbationMethod {
@@ -14,6 +24,37 @@ impl fmt::Display for PerturbationMethod {
         let result = match self {
             PerturbationMethod::Max => &"Max",
             PerturbationMethod::Sum => &"Sum",
+            PerturbationMethod::Quadrature => &"Quadrature",
+        };
+
+        write!(f, "{}", result)
+    }
+}
+
+/// Direction of the perturbation used by the `IterativeParamsFD` struct
+///
+/// A model only defined on one side of a bound (a physical quantity that must stay positive for example)
+/// can fail to be evaluated, or give meaningless values, when perturbed outside of its domain.
+/// The sign of the perturbation allows to perturb toward the valid side.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum PerturbationSign {
+    /// Forward difference: dx > 0
+    #[default]
+    Positive,
+    /// Backward difference: dx < 0
+    Negative,
+    /// Perturbation toward the interior of the bounds, from the bound closest to the current value
+    ///
+    /// An infinite bound is never the closest one: without finite bounds, the perturbation is positive.
+    AwayFromNearestBound,
+}
+
+impl fmt::Display for PerturbationSign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let result = match self {
+            PerturbationSign::Positive => &"Positive",
+            PerturbationSign::Negative => &"Negative",
+            PerturbationSign::AwayFromNearestBound => &"Away from nearest bound",
         };
 
         write!(f, "{}", result)
@@ -27,10 +68,14 @@ impl fmt::Display for PerturbationMethod {
 /// leads to the necessity to parametrize the way the perturbation on the iteratives are made.
 ///
 /// This functionality is provided thourgh the `compute_perturbation()` method of the `Iterative` trait
+///
+/// The perturbation is positive by default,
+/// its direction can be changed with [IterativeParamsFD::set_perturbation_sign]
 #[derive(Debug, Clone, PartialEq)]
 pub struct IterativeParamsFD {
     iterative_params: IterativeParams,
     perturbation_method: PerturbationMethod,
+    perturbation_sign: PerturbationSign,
     dx_abs: f64,
     dx_rel: f64,
 }
@@ -40,6 +85,7 @@ impl Default for IterativeParamsFD {
         IterativeParamsFD {
             iterative_params: IterativeParams::default(),
             perturbation_method: PerturbationMethod::Max,
+            perturbation_sign: PerturbationSign::Positive,
             dx_abs: 5.0e-8,
             dx_rel: 5.0e-8,
         }
@@ -56,17 +102,8 @@ impl IterativeParamsFD {
         dx_rel: f64,
         perturbation_method: PerturbationMethod,
     ) -> Self {
-        if dx_abs <= 0.0 {
-            panic!(
-                "dx_abs must be strictly positive, provided value was {}",
-                dx_abs
-            );
-        }
-        if dx_rel <= 0.0 {
-            panic!(
-                "dx_rel must be strictly positive, provided value was {}",
-                dx_rel
-            );
+        if let Err(message) = Self::check_perturbations(dx_abs, dx_rel) {
+            panic!("{}", message);
         }
 
         IterativeParamsFD {
@@ -77,11 +114,79 @@ impl IterativeParamsFD {
                 max_value,
             ),
             perturbation_method,
+            perturbation_sign: PerturbationSign::Positive,
             dx_abs,
             dx_rel,
         }
     }
 
+    /// Constant constructor, usable to define the parameters in a `const` or a `static`
+    ///
+    /// As with [IterativeParams::new_unchecked_const], the values are **not validated**:
+    /// in addition to the requirements of [IterativeParams::new],
+    /// the caller is responsible for `dx_abs` and `dx_rel` being strictly positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::{IterativeParamsFD, PerturbationMethod};
+    ///
+    /// static ITERATIVES: [IterativeParamsFD; 1] = [IterativeParamsFD::new_unchecked_const(
+    ///     1.0,
+    ///     0.5,
+    ///     0.0,
+    ///     f64::INFINITY,
+    ///     5e-8,
+    ///     5e-8,
+    ///     PerturbationMethod::Max,
+    /// )];
+    ///
+    /// assert_eq!(
+    ///     ITERATIVES[0],
+    ///     IterativeParamsFD::new(1.0, 0.5, 0.0, f64::INFINITY, 5e-8, 5e-8, PerturbationMethod::Max)
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new_unchecked_const(
+        max_step_abs: f64,
+        max_step_rel: f64,
+        min_value: f64,
+        max_value: f64,
+        dx_abs: f64,
+        dx_rel: f64,
+        perturbation_method: PerturbationMethod,
+    ) -> Self {
+        IterativeParamsFD {
+            iterative_params: IterativeParams::new_unchecked_const(
+                max_step_abs,
+                max_step_rel,
+                min_value,
+                max_value,
+            ),
+            perturbation_method,
+            perturbation_sign: PerturbationSign::Positive,
+            dx_abs,
+            dx_rel,
+        }
+    }
+
+    /// Check the perturbations expected by [IterativeParamsFD::new], returning the error message
+    pub(crate) fn check_perturbations(dx_abs: f64, dx_rel: f64) -> Result<(), String> {
+        if dx_abs <= 0.0 {
+            return Err(format!(
+                "dx_abs must be strictly positive, provided value was {}",
+                dx_abs
+            ));
+        }
+        if dx_rel <= 0.0 {
+            return Err(format!(
+                "dx_rel must be strictly positive, provided value was {}",
+                dx_rel
+            ));
+        }
+        Ok(())
+    }
+
     pub fn get_min_value(&self) -> f64 {
         self.iterative_params.get_min_value()
     }
@@ -98,6 +203,61 @@ impl IterativeParamsFD {
         self.iterative_params.get_max_step_rel()
     }
 
+    pub fn get_max_step_abs_up(&self) -> Option<f64> {
+        self.iterative_params.get_max_step_abs_up()
+    }
+
+    pub fn get_max_step_abs_down(&self) -> Option<f64> {
+        self.iterative_params.get_max_step_abs_down()
+    }
+
+    pub fn get_max_step_rel_up(&self) -> Option<f64> {
+        self.iterative_params.get_max_step_rel_up()
+    }
+
+    pub fn get_max_step_rel_down(&self) -> Option<f64> {
+        self.iterative_params.get_max_step_rel_down()
+    }
+
+    /// See [IterativeParams::set_max_step_abs_up]
+    pub fn set_max_step_abs_up(&mut self, max_step: Option<f64>) {
+        self.iterative_params.set_max_step_abs_up(max_step);
+    }
+
+    /// See [IterativeParams::set_max_step_abs_down]
+    pub fn set_max_step_abs_down(&mut self, max_step: Option<f64>) {
+        self.iterative_params.set_max_step_abs_down(max_step);
+    }
+
+    /// See [IterativeParams::set_max_step_rel_up]
+    pub fn set_max_step_rel_up(&mut self, max_step: Option<f64>) {
+        self.iterative_params.set_max_step_rel_up(max_step);
+    }
+
+    /// See [IterativeParams::set_max_step_rel_down]
+    pub fn set_max_step_rel_down(&mut self, max_step: Option<f64>) {
+        self.iterative_params.set_max_step_rel_down(max_step);
+    }
+
+    pub fn get_convergence_step_tolerance(&self) -> Option<f64> {
+        self.iterative_params.get_convergence_step_tolerance()
+    }
+
+    /// See [IterativeParams::set_convergence_step_tolerance]
+    pub fn set_convergence_step_tolerance(&mut self, step_tolerance: Option<f64>) {
+        self.iterative_params
+            .set_convergence_step_tolerance(step_tolerance);
+    }
+
+    pub fn get_fixed(&self) -> Option<f64> {
+        self.iterative_params.get_fixed()
+    }
+
+    /// See [IterativeParams::set_fixed]
+    pub fn set_fixed(&mut self, value: Option<f64>) {
+        self.iterative_params.set_fixed(value);
+    }
+
     pub fn get_dx_abs(&self) -> f64 {
         self.dx_abs
     }
@@ -110,6 +270,15 @@ impl IterativeParamsFD {
         self.perturbation_method
     }
 
+    pub fn get_perturbation_sign(&self) -> PerturbationSign {
+        self.perturbation_sign
+    }
+
+    /// Set the direction of the perturbation, see [PerturbationSign]
+    pub fn set_perturbation_sign(&mut self, perturbation_sign: PerturbationSign) {
+        self.perturbation_sign = perturbation_sign;
+    }
+
     pub fn get_iterative_params(&self) -> &IterativeParams {
         &self.iterative_params
     }
@@ -121,22 +290,14 @@ impl IterativeParamsFD {
         dx_rel: f64,
         perturbation_method: PerturbationMethod,
     ) -> Self {
-        if dx_abs <= 0.0 {
-            panic!(
-                "dx_abs must be strictly positive, provided value was {}",
-                dx_abs
-            );
-        }
-        if dx_rel <= 0.0 {
-            panic!(
-                "dx_rel must be strictly positive, provided value was {}",
-                dx_rel
-            );
+        if let Err(message) = Self::check_perturbations(dx_abs, dx_rel) {
+            panic!("{}", message);
         }
 
         IterativeParamsFD {
             iterative_params,
             perturbation_method,
+            perturbation_sign: PerturbationSign::Positive,
             dx_abs,
             dx_rel,
         }
@@ -153,6 +314,11 @@ impl Iterative for IterativeParamsFD {
             .step_limitation(value_current, value_next)
     }
 
+    fn step_limitation_with_scale(&self, value_current: f64, raw_step: f64, scale: f64) -> f64 {
+        self.iterative_params
+            .step_limitation_with_scale(value_current, raw_step, scale)
+    }
+
     /// Compute the perturbation for finite differences evaluation.
     ///
     /// For a given f(x), this method compute the dx to use in the formula:
@@ -171,22 +337,72 @@ impl Iterative for IterativeParamsFD {
     /// The implementation here allows you to choose and combine the formulas:
     /// - dx = max(dx_abs, dx_rel*abs(x))
     /// - dx = dx_abs + dx_rel*abs(x)
+    /// - dx = sqrt(dx_abs^2 + (dx_rel*abs(x))^2)
     ///
     /// This is achieved through the `perturbation_method` field.
     ///
     /// It is also possible to get one of the two basic cases by setting the other to 0:
     /// - dx_abs = 0 implies dx = dx_rel*abs(x)
     /// - dx_rel = 0 implies dx = dx_abs
+    ///
+    /// The sign of dx is then given by the `perturbation_sign` field:
+    /// with a negative dx, the formula above is a backward difference.
     fn compute_perturbation(&self, x: f64) -> f64 {
-        match self.perturbation_method {
+        let dx = match self.perturbation_method {
             PerturbationMethod::Max => (self.dx_abs).max(x.abs() * self.dx_rel),
             PerturbationMethod::Sum => self.dx_abs + x.abs() * self.dx_rel,
+            PerturbationMethod::Quadrature => {
+                let dx_rel = x.abs() * self.dx_rel;
+                (self.dx_abs * self.dx_abs + dx_rel * dx_rel).sqrt()
+            }
+        };
+
+        match self.perturbation_sign {
+            PerturbationSign::Positive => dx,
+            PerturbationSign::Negative => -dx,
+            PerturbationSign::AwayFromNearestBound => {
+                let distance_to_max = self.get_max_value() - x;
+                let distance_to_min = x - self.get_min_value();
+                if distance_to_max < distance_to_min {
+                    -dx
+                } else {
+                    dx
+                }
+            }
         }
     }
 
     fn with_finite_diff(&self) -> bool {
         true
     }
+
+    fn is_within_bounds(&self, value: f64) -> bool {
+        self.iterative_params.is_within_bounds(value)
+    }
+
+    fn is_on_bounds(&self, value: f64) -> bool {
+        self.iterative_params.is_on_bounds(value)
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        self.iterative_params.bounds()
+    }
+
+    /// Compute an initial guess from the bounds of the iterative
+    ///
+    /// This method is a wrapper around the method of `IterativeParams`.
+    /// Check its documentation for more details
+    fn default_guess(&self) -> f64 {
+        self.iterative_params.default_guess()
+    }
+
+    fn convergence_step_tolerance(&self) -> Option<f64> {
+        self.iterative_params.convergence_step_tolerance()
+    }
+
+    fn fixed_value(&self) -> Option<f64> {
+        self.iterative_params.fixed_value()
+    }
 }
 
 impl fmt::Display for IterativeParamsFD {
@@ -194,10 +410,17 @@ impl fmt::Display for IterativeParamsFD {
         let column_float = String::from("--------------+");
         let width = column_float.len() - 2;
         let mut content = self.iterative_params.to_string();
+        let perturbation = match self.perturbation_sign {
+            PerturbationSign::Positive => self.perturbation_method.to_string(),
+            PerturbationSign::Negative => format!("{} (-)", self.perturbation_method),
+            PerturbationSign::AwayFromNearestBound => {
+                format!("{} (away)", self.perturbation_method)
+            }
+        };
         content.push_str(&format!(
             " {:width$}|",
-            &self.perturbation_method.to_string(),
-            width = "-----------------+".len() - 2
+            &perturbation,
+            width = "-------------------+".len() - 2
         ));
         content.push_str(&format!(
             " {:width$}|",
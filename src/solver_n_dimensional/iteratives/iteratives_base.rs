@@ -1,4 +1,13 @@
-use std::fmt;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Relative tolerance used to consider that a value is on a bound of an iterative
+///
+/// A value `x` is on the bound `b` if `abs(x - b) <= ACTIVE_BOUND_TOLERANCE * max(1, abs(b))`
+pub const ACTIVE_BOUND_TOLERANCE: f64 = 1e-10;
 
 /// Iterative definition
 ///
@@ -13,7 +22,25 @@ pub trait Iterative {
     /// Compute the new value based on the current value and the step size proposed
     ///
     /// The iteratives variables implement a way to reduce this step according to the parametrization
+    ///
+    /// The returned value is expected to respect `is_within_bounds()`,
+    /// including when the current value does not, for example at a point perturbed by the finite difference:
+    /// the implementations provided bring the value obtained back onto the nearest bound
     fn step_limitation(&self, value_current: f64, raw_step: f64) -> f64;
+    /// Compute the new value as `step_limitation()`, with the maximum step sizes multiplied by `scale`
+    ///
+    /// The constraints on the range of values are not relaxed.
+    /// Used by the solver to relax the step limitations at the first iteration, see `SolverParameters`.
+    ///
+    /// The default implementation ignores the `scale` and calls `step_limitation()`
+    fn step_limitation_with_scale(
+        &self,
+        value_current: f64,
+        raw_step: f64,
+        #[allow(unused_variables)] scale: f64,
+    ) -> f64 {
+        self.step_limitation(value_current, raw_step)
+    }
     /// Compute the perturbation (only valid if it is working with finite differences)
     ///
     /// according to the parametrization
@@ -21,9 +48,51 @@ pub trait Iterative {
         unimplemented!();
     }
     /// Method to differente without panicking if it is working with finite differences
+    ///
+    /// The solver requires it to be `true` if the model does not provide its jacobian,
+    /// as `compute_perturbation()` is then used to compute the jacobian with finite differences
     fn with_finite_diff(&self) -> bool {
         false
     }
+    /// Check if a value respects the `min_value` and `max_value` constraints
+    fn is_within_bounds(&self, #[allow(unused_variables)] value: f64) -> bool {
+        true
+    }
+    /// Check if a value is on the `min_value` or `max_value` bound,
+    /// within the relative tolerance [ACTIVE_BOUND_TOLERANCE]
+    fn is_on_bounds(&self, #[allow(unused_variables)] value: f64) -> bool {
+        false
+    }
+    /// Range of values of the iterative, as `(min_value, max_value)`
+    ///
+    /// The default implementation returns `(f64::NEG_INFINITY, f64::INFINITY)`, as for an unbounded iterative
+    fn bounds(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+    /// Value to use as initial guess when none is provided by the user
+    ///
+    /// The default implementation returns `1.0`, as for an unbounded iterative
+    fn default_guess(&self) -> f64 {
+        1.0
+    }
+    /// Tolerance on the absolute value of the step of the iterative required to reach the convergence,
+    /// in addition to the residuals being below the solver tolerance
+    ///
+    /// The default implementation returns `None`, the step not being checked
+    fn convergence_step_tolerance(&self) -> Option<f64> {
+        None
+    }
+    /// Value at which the iterative is fixed for the resolution
+    ///
+    /// A fixed iterative takes this value in the initial guess and is never updated by the solver,
+    /// its step being null: its column of the jacobian is excluded from the linear systems.
+    /// As many residuals must be ignored for the system to stay square,
+    /// see `ResidualsConfig::set_ignored_residuals()`.
+    ///
+    /// The default implementation returns `None`, the iterative being free
+    fn fixed_value(&self) -> Option<f64> {
+        None
+    }
 }
 
 /// A slice of iteratives
@@ -33,6 +102,10 @@ pub trait Iterative {
 /// It provides the same method as the `Iterative` trait with the plural suffix:
 /// - `step_limitations`
 /// - `compute_perturbations`
+///
+/// The parameters of each iterative can be inspected with `iter()` and `get()`,
+/// two configurations being equal if their parameters are equal.
+#[derive(PartialEq)]
 pub struct Iteratives<'a, T: Iterative> {
     iteratives_params: &'a [T],
 }
@@ -41,18 +114,90 @@ impl<'a, T> Iteratives<'a, T>
 where
     T: Iterative,
 {
-    pub fn new(iteratives_params: &'a [T]) -> Self {
+    /// Wrap a slice of iteratives parameters
+    ///
+    /// The constructor is `const`: with the constant constructors of the parameters,
+    /// such as [super::IterativeParamsFD::new_unchecked_const],
+    /// the whole configuration can be defined without any runtime setup.
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::{IterativeParams, Iteratives};
+    ///
+    /// static PARAMS: [IterativeParams; 2] = [
+    ///     IterativeParams::new_unchecked_const(1.0, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY),
+    ///     IterativeParams::new_unchecked_const(1.0, f64::INFINITY, 0.0, f64::INFINITY),
+    /// ];
+    /// static ITERATIVES: Iteratives<'static, IterativeParams> = Iteratives::new(&PARAMS);
+    ///
+    /// let current = nalgebra::DVector::from_vec(vec![0.0, 0.5]);
+    /// let raw_step = nalgebra::DVector::from_vec(vec![3.0, -3.0]);
+    /// let next = ITERATIVES.step_limitations(&current, &raw_step);
+    /// assert_eq!(next, nalgebra::DVector::from_vec(vec![1.0, 0.0]));
+    /// ```
+    pub const fn new(iteratives_params: &'a [T]) -> Self {
         Iteratives { iteratives_params }
     }
 
     pub fn len(&self) -> usize {
         self.iteratives_params.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.iteratives_params.is_empty()
+    }
+
+    /// Iterate over the parameters of the iteratives, in the order of the iteratives
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives::{IterativeParams, Iteratives};
+    ///
+    /// let params = [
+    ///     IterativeParams::new(1.0, f64::INFINITY, 0.0, 10.0),
+    ///     IterativeParams::new(2.0, f64::INFINITY, -5.0, 5.0),
+    /// ];
+    /// let iteratives = Iteratives::new(&params);
+    /// let max_steps: Vec<f64> = iteratives.iter().map(|params| params.get_max_step_abs()).collect();
+    /// assert_eq!(max_steps, vec![1.0, 2.0]);
+    /// assert_eq!(iteratives.get(1), Some(&params[1]));
+    /// assert_eq!(iteratives.get(2), None);
+    /// assert_eq!(iteratives.bounds_summary(), (-5.0, 10.0));
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'a, T> {
+        self.iteratives_params.iter()
+    }
+
+    /// Parameters of the iterative `index`, `None` if it is out of range
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.iteratives_params.get(index)
+    }
+
+    /// Smallest `min_value` and largest `max_value` of the iteratives, as `(min, max)`
+    ///
+    /// See the `bounds()` method of the `Iterative` trait.
+    /// Without any iterative, `(f64::INFINITY, f64::NEG_INFINITY)` is returned.
+    pub fn bounds_summary(&self) -> (f64, f64) {
+        self.iteratives_params.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(global_min, global_max), iterative_var| {
+                let (min_value, max_value) = iterative_var.bounds();
+                (global_min.min(min_value), global_max.max(max_value))
+            },
+        )
+    }
+
     /// Compute a limited step for several iteratives
     ///
     /// Return the new value after the application of the step limitation (and not the step).
     ///
     /// This is required as it can be limited by an interval for the iteratives.
+    /// The current values outside of their bounds are handled as described by the `step_limitation()` method
+    /// of the `Iterative` trait.
+    ///
+    /// The fixed iteratives keep their fixed value, see the `fixed_value()` method of the `Iterative` trait
     pub fn step_limitations<D>(
         &self,
         values: &nalgebra::OVector<f64, D>,
@@ -65,7 +210,34 @@ where
         let mut step_lim: nalgebra::OVector<f64, D> = super::super::ovector_zeros_like(values);
 
         for (i, iterative_params) in (self.iteratives_params).iter().enumerate() {
-            step_lim[i] = iterative_params.step_limitation(values[i], raw_step[i]);
+            step_lim[i] = match iterative_params.fixed_value() {
+                Some(value) => value,
+                None => iterative_params.step_limitation(values[i], raw_step[i]),
+            };
+        }
+        step_lim
+    }
+
+    /// Compute a limited step for several iteratives, with the maximum step sizes multiplied by `scale`
+    ///
+    /// See the `step_limitation_with_scale()` method of the `Iterative` trait
+    pub fn step_limitations_with_scale<D>(
+        &self,
+        values: &nalgebra::OVector<f64, D>,
+        raw_step: &nalgebra::OVector<f64, D>,
+        scale: f64,
+    ) -> nalgebra::OVector<f64, D>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let mut step_lim: nalgebra::OVector<f64, D> = super::super::ovector_zeros_like(values);
+
+        for (i, iterative_params) in (self.iteratives_params).iter().enumerate() {
+            step_lim[i] = match iterative_params.fixed_value() {
+                Some(value) => value,
+                None => iterative_params.step_limitation_with_scale(values[i], raw_step[i], scale),
+            };
         }
         step_lim
     }
@@ -87,13 +259,169 @@ where
         }
         perturbations
     }
+
+    /// Find the iteratives whose value is on one of their bounds
+    ///
+    /// At the end of a resolution,
+    /// it indicates that the solution might have been limited by the bounds of the iteratives
+    pub fn active_bounds<D>(&self, iterative_values: &nalgebra::OVector<f64, D>) -> Vec<usize>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        (self.iteratives_params)
+            .iter()
+            .enumerate()
+            .filter(|(i, iterative_var)| iterative_var.is_on_bounds(iterative_values[*i]))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Compute an initial guess from the parametrization of each iterative
+    ///
+    /// See the `default_guess()` method of the `Iterative` trait.
+    ///
+    /// The `shape` is the dimension of the guess, as used by the solver.
+    pub fn default_guess<D>(&self, shape: D) -> nalgebra::OVector<f64, D>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        if shape.value() != self.len() {
+            panic!(
+                "Dimension mismatch :\n shape = {} and iteratives.len() = {}",
+                shape.value(),
+                self.len()
+            );
+        }
+
+        let mut guess: nalgebra::OVector<f64, D> = super::super::ovector_zeros_from_shape(shape);
+
+        for (i, iterative_var) in (self.iteratives_params).iter().enumerate() {
+            guess[i] = iterative_var.default_guess();
+        }
+        guess
+    }
+
+    /// Whether all the iteratives are working with finite differences
+    ///
+    /// See the `with_finite_diff()` method of the `Iterative` trait
+    pub fn with_finite_diff(&self) -> bool {
+        (self.iteratives_params)
+            .iter()
+            .all(|iterative_var| iterative_var.with_finite_diff())
+    }
+
+    /// Indices of the fixed iteratives, see the `fixed_value()` method of the `Iterative` trait
+    pub fn fixed_iteratives(&self) -> Vec<usize> {
+        (self.iteratives_params)
+            .iter()
+            .enumerate()
+            .filter(|(_, iterative_var)| iterative_var.fixed_value().is_some())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Set the fixed iteratives to their fixed value, the others being left unchanged
+    pub fn apply_fixed_values<D>(&self, values: &mut nalgebra::OVector<f64, D>)
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        for (i, iterative_var) in (self.iteratives_params).iter().enumerate() {
+            if let Some(value) = iterative_var.fixed_value() {
+                values[i] = value;
+            }
+        }
+    }
+
+    /// Whether at least one iterative has a convergence step tolerance
+    pub fn has_step_tolerances(&self) -> bool {
+        (self.iteratives_params)
+            .iter()
+            .any(|iterative_var| iterative_var.convergence_step_tolerance().is_some())
+    }
+
+    /// Check if the steps of the iteratives are below their convergence step tolerance
+    ///
+    /// The iteratives without convergence step tolerance are not considered
+    pub fn are_steps_converged<D>(&self, steps: &nalgebra::OVector<f64, D>) -> bool
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        (self.iteratives_params)
+            .iter()
+            .zip(steps.iter())
+            .all(
+                |(iterative_var, step)| match iterative_var.convergence_step_tolerance() {
+                    Some(tolerance) => step.abs() < tolerance,
+                    None => true,
+                },
+            )
+    }
+
+    /// Find the iteratives whose perturbed value is out of their bounds
+    ///
+    /// The model is then evaluated outside of the validity domain defined for the iterative,
+    /// which can degrade the accuracy of the finite difference
+    pub fn perturbations_out_of_bounds<D>(
+        &self,
+        iterative_values: &nalgebra::OVector<f64, D>,
+        perturbations: &nalgebra::OVector<f64, D>,
+    ) -> Vec<usize>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        (self.iteratives_params)
+            .iter()
+            .enumerate()
+            .filter(|(i, iterative_var)| {
+                !iterative_var.is_within_bounds(iterative_values[*i] + perturbations[*i])
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
 }
 
-impl<'a, T> fmt::Display for Iteratives<'a, T>
+impl<'a, T> IntoIterator for Iteratives<'a, T>
+where
+    T: Iterative,
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iteratives_params.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &Iteratives<'a, T>
+where
+    T: Iterative,
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> Iteratives<'a, T>
 where
     T: Iterative + fmt::Display,
 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// Format the table of the iteratives parameters
+    ///
+    /// With `Some(n)`, only the first `n` and the last `n` iteratives are displayed,
+    /// the other ones being replaced by a line with their number.
+    /// With `None`, all the iteratives are displayed.
+    ///
+    /// The [fmt::Display] implementation displays the first and last 10 iteratives,
+    /// this number can be changed through the width of the formatter: `format!("{:5}", iteratives)`
+    pub fn format_with(&self, displayed_entries: Option<usize>) -> String {
         let finite_diff = self.iteratives_params[0].with_finite_diff();
 
         let mut content = String::from("Iteratives parameters\n");
@@ -107,7 +435,7 @@ where
                 + &column_float
                 + &column_float
                 + &column_float
-                + "-----------------+"
+                + "-------------------+"
                 + &column_float
                 + &column_float
                 + "\n"
@@ -132,7 +460,7 @@ where
             content.push_str(&format!(
                 "| {:width$}",
                 &"perturbation",
-                width = "-----------------+".len() - 2
+                width = "-------------------+".len() - 2
             ));
             content.push_str(&format!("| {:width$}", &"dx_abs", width = width));
             content.push_str(&format!("| {:width$}|", &"dx_rel", width = width));
@@ -143,7 +471,23 @@ where
         content.push('\n');
         content.push_str(&separation_line);
 
+        let hidden = super::super::hidden_entries(self.len(), displayed_entries);
+        let displayed_rows = match &hidden {
+            Some(hidden) => self.len() - hidden.len() + 1,
+            None => self.len(),
+        };
+        content.reserve(2 * separation_line.len() * displayed_rows + 1);
+
         for (i, elt) in self.iteratives_params.iter().enumerate() {
+            if let Some(hidden) = &hidden {
+                if i == hidden.start {
+                    content.push_str(&super::super::hidden_entries_marker(hidden));
+                    content.push_str(&separation_line);
+                }
+                if hidden.contains(&i) {
+                    continue;
+                }
+            }
             content.push_str(&format!("| {:width$}|", &i.to_string(), width = 10));
             content.push_str(&elt.to_string());
             content.push('\n');
@@ -151,7 +495,17 @@ where
         }
 
         content.push('\n');
-        write!(f, "{}", content)
+        content
+    }
+}
+
+impl<'a, T> fmt::Display for Iteratives<'a, T>
+where
+    T: Iterative + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let displayed_entries = f.width().unwrap_or(super::super::DEFAULT_DISPLAYED_ENTRIES);
+        write!(f, "{}", self.format_with(Some(displayed_entries)))
     }
 }
 
@@ -165,3 +519,77 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::{IterativeParams, IterativeParamsFD};
+    use super::*;
+    use alloc::vec;
+
+    fn params() -> Vec<IterativeParams> {
+        vec![
+            IterativeParams::new(1.0, f64::INFINITY, 0.0, 10.0),
+            IterativeParams::new(2.0, f64::INFINITY, -5.0, 5.0),
+            IterativeParams::new(3.0, f64::INFINITY, f64::NEG_INFINITY, 1.0),
+        ]
+    }
+
+    #[test]
+    fn iteration_order() {
+        let params = params();
+        let iteratives = Iteratives::new(&params);
+        let max_steps: Vec<f64> = iteratives.iter().map(|p| p.get_max_step_abs()).collect();
+        assert_eq!(max_steps, vec![1.0, 2.0, 3.0]);
+
+        let mut count = 0;
+        for (i, iterative_var) in (&iteratives).into_iter().enumerate() {
+            assert_eq!(iterative_var, &params[i]);
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        assert_eq!(iteratives.into_iter().count(), 3);
+    }
+
+    #[test]
+    fn get_bounds() {
+        let params = params();
+        let iteratives = Iteratives::new(&params);
+        assert_eq!(iteratives.get(0), Some(&params[0]));
+        assert_eq!(iteratives.get(2), Some(&params[2]));
+        assert_eq!(iteratives.get(3), None);
+    }
+
+    #[test]
+    fn equality() {
+        let params = params();
+        let other_params = params.clone();
+        assert_eq!(Iteratives::new(&params), Iteratives::new(&other_params));
+        assert_ne!(Iteratives::new(&params), Iteratives::new(&params[..2]));
+
+        let mut modified = params.clone();
+        modified[1].set_max_step_abs_up(Some(0.5));
+        assert_ne!(Iteratives::new(&params), Iteratives::new(&modified));
+    }
+
+    #[test]
+    fn bounds_summary() {
+        let params = params();
+        assert_eq!(
+            Iteratives::new(&params).bounds_summary(),
+            (f64::NEG_INFINITY, 10.0)
+        );
+        assert_eq!(Iteratives::new(&params[..2]).bounds_summary(), (-5.0, 10.0));
+
+        let params_fd = vec![IterativeParamsFD::default(); 2];
+        assert_eq!(
+            Iteratives::new(&params_fd).bounds_summary(),
+            (f64::NEG_INFINITY, f64::INFINITY)
+        );
+
+        let empty: [IterativeParams; 0] = [];
+        assert_eq!(
+            Iteratives::new(&empty).bounds_summary(),
+            (f64::INFINITY, f64::NEG_INFINITY)
+        );
+    }
+}
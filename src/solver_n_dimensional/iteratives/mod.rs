@@ -30,7 +30,9 @@ mod iteratives_base;
 pub use iterative_var::IterativeParams; // struct re-export
 pub use iterative_var_fd::IterativeParamsFD; // struct re-export
 pub use iterative_var_fd::PerturbationMethod; // enum re-export
+pub use iterative_var_fd::PerturbationSign; // enum re-export
 pub use iteratives_base::Iterative; // trait re-export
 pub use iteratives_base::Iteratives; // struct re-export
+pub use iteratives_base::ACTIVE_BOUND_TOLERANCE; // const re-export
 
 pub use default::{default_vec_iteratives, default_vec_iteratives_fd};
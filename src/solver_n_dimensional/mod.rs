@@ -3,14 +3,17 @@
 pub mod errors;
 pub mod iteratives;
 pub mod model;
+mod prelude;
 pub mod residuals;
 pub mod solver;
+mod util_display;
 mod util_nalgebra;
 
 #[cfg(feature = "xml_config_file")]
 pub mod xml_parser;
 
+pub(crate) use util_display::{hidden_entries, hidden_entries_marker, DEFAULT_DISPLAYED_ENTRIES};
 pub use util_nalgebra::{
-    omatrix_zeros_from_shape, omatrix_zeros_like, omatrix_zeros_like_ovector,
-    ovector_zeros_from_shape, ovector_zeros_like,
+    omatrix_from_slice_generic, omatrix_zeros_from_shape, omatrix_zeros_like_ovector,
+    ovector_from_slice_generic, ovector_zeros_from_shape, ovector_zeros_like,
 };
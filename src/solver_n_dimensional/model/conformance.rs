@@ -0,0 +1,364 @@
+//! Check of the contract of the [Model] trait
+//!
+//! The solver relies on implicit invariants of a model that the compiler cannot verify,
+//! such as the iteratives being returned as they were set.
+//! A model violating them leads to resolutions failing or converging to wrong values
+//! without any error pointing at the model.
+//!
+//! [check_model] exercises the methods of the trait in the order used by the solver
+//! at each of the sample points provided and lists the invariants violated:
+//! - the sample points, the iteratives and the residuals have the length given by [Model::len_problem]
+//! - [Model::get_iteratives] returns the values given to [Model::set_iteratives],
+//!   which are not modified by [Model::evaluate]
+//! - [Model::write_residuals] writes the values returned by [Model::get_residuals]
+//! - two evaluations at the same point give the same residuals
+//! - the jacobian, if provided, is a square matrix of the size of the problem
+//!
+//! # Examples
+//!
+//! ```
+//! use newton_rootfinder as nrf;
+//! use nrf::model::conformance::check_model;
+//!
+//! fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+//!     x.map(|x| x * x - 2.0)
+//! }
+//!
+//! let mut model = nrf::model::UserModelFromFunction::new(1, square2);
+//! let sample_points = [
+//!     nalgebra::DVector::from_vec(vec![1.0]),
+//!     nalgebra::DVector::from_vec(vec![-3.0]),
+//! ];
+//! assert_eq!(check_model(&mut model, &sample_points), Ok(()));
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::Model;
+use crate::residuals::ResidualsValues;
+
+/// Invariant of the [Model] trait violated by a model, found by [check_model]
+///
+/// `point` is the index of the sample point at which the violation has been found.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConformanceViolation {
+    /// The length of the sample point differs from [Model::len_problem], the point is not evaluated
+    SamplePointLength {
+        point: usize,
+        length: usize,
+        problem_size: usize,
+    },
+    /// The length of the iteratives returned by [Model::get_iteratives] differs from [Model::len_problem]
+    IterativesLength {
+        point: usize,
+        length: usize,
+        problem_size: usize,
+    },
+    /// [Model::get_iteratives] does not return the values given to [Model::set_iteratives]
+    IterativesRoundtrip { point: usize },
+    /// The iteratives have been modified by [Model::evaluate]
+    IterativesModifiedByEvaluation { point: usize },
+    /// [Model::evaluate] returned an error, the other invariants are not checked at this point
+    EvaluationFailed { point: usize, error: String },
+    /// The length of the residuals returned by [Model::get_residuals] differs from [Model::len_problem]
+    ResidualsLength {
+        point: usize,
+        length: usize,
+        problem_size: usize,
+    },
+    /// [Model::write_residuals] does not write the values returned by [Model::get_residuals]
+    WriteResidualsMismatch { point: usize },
+    /// A second evaluation at the same point gave different residuals
+    NonDeterministicEvaluation { point: usize },
+    /// The jacobian provided by the model returned an error
+    JacobianFailed { point: usize, error: String },
+    /// The dimensions of the jacobian provided by the model differ from the problem size
+    JacobianDimensions {
+        point: usize,
+        rows: usize,
+        columns: usize,
+        problem_size: usize,
+    },
+    /// The length of a column of the jacobian provided by [Model::get_jacobian_column] differs from the problem size
+    JacobianColumnLength {
+        point: usize,
+        column: usize,
+        length: usize,
+        problem_size: usize,
+    },
+}
+
+impl fmt::Display for ConformanceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SamplePointLength {
+                point,
+                length,
+                problem_size,
+            } => write!(
+                f,
+                "Sample point {}: its length is {}, the problem size is {}",
+                point, length, problem_size
+            ),
+            Self::IterativesLength {
+                point,
+                length,
+                problem_size,
+            } => write!(
+                f,
+                "Sample point {}: get_iteratives() returned {} values, the problem size is {}",
+                point, length, problem_size
+            ),
+            Self::IterativesRoundtrip { point } => write!(
+                f,
+                "Sample point {}: get_iteratives() does not return the values given to set_iteratives()",
+                point
+            ),
+            Self::IterativesModifiedByEvaluation { point } => write!(
+                f,
+                "Sample point {}: the iteratives have been modified by evaluate()",
+                point
+            ),
+            Self::EvaluationFailed { point, error } => write!(
+                f,
+                "Sample point {}: evaluate() failed with {}",
+                point, error
+            ),
+            Self::ResidualsLength {
+                point,
+                length,
+                problem_size,
+            } => write!(
+                f,
+                "Sample point {}: get_residuals() returned {} values, the problem size is {}",
+                point, length, problem_size
+            ),
+            Self::WriteResidualsMismatch { point } => write!(
+                f,
+                "Sample point {}: write_residuals() does not write the values returned by get_residuals()",
+                point
+            ),
+            Self::NonDeterministicEvaluation { point } => write!(
+                f,
+                "Sample point {}: two evaluations at the same point gave different residuals",
+                point
+            ),
+            Self::JacobianFailed { point, error } => write!(
+                f,
+                "Sample point {}: the jacobian failed with {}",
+                point, error
+            ),
+            Self::JacobianDimensions {
+                point,
+                rows,
+                columns,
+                problem_size,
+            } => write!(
+                f,
+                "Sample point {}: the jacobian is {}x{}, the problem size is {}",
+                point, rows, columns, problem_size
+            ),
+            Self::JacobianColumnLength {
+                point,
+                column,
+                length,
+                problem_size,
+            } => write!(
+                f,
+                "Sample point {}: the column {} of the jacobian has {} values, the problem size is {}",
+                point, column, length, problem_size
+            ),
+        }
+    }
+}
+
+/// Check the invariants of the [Model] trait at each of the sample points, see the [module documentation](self)
+///
+/// All the violations found are returned, in the order of the sample points.
+/// The model is left at the last sample point evaluated.
+pub fn check_model<M, D>(
+    model: &mut M,
+    sample_points: &[nalgebra::OVector<f64, D>],
+) -> Result<(), Vec<ConformanceViolation>>
+where
+    M: Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let mut violations = Vec::new();
+    for (point, sample_point) in sample_points.iter().enumerate() {
+        check_point(model, point, sample_point, &mut violations);
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+fn check_point<M, D>(
+    model: &mut M,
+    point: usize,
+    sample_point: &nalgebra::OVector<f64, D>,
+    violations: &mut Vec<ConformanceViolation>,
+) where
+    M: Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let problem_size = model.len_problem();
+    if sample_point.len() != problem_size {
+        violations.push(ConformanceViolation::SamplePointLength {
+            point,
+            length: sample_point.len(),
+            problem_size,
+        });
+        return;
+    }
+
+    model.set_iteratives(sample_point);
+    let iteratives = model.get_iteratives();
+    if iteratives.len() != problem_size {
+        violations.push(ConformanceViolation::IterativesLength {
+            point,
+            length: iteratives.len(),
+            problem_size,
+        });
+    } else if !same_values(iteratives.iter(), sample_point.iter()) {
+        violations.push(ConformanceViolation::IterativesRoundtrip { point });
+    }
+
+    if let Err(error) = model.evaluate() {
+        violations.push(ConformanceViolation::EvaluationFailed {
+            point,
+            error: error.to_string(),
+        });
+        return;
+    }
+    if !same_values(model.get_iteratives().iter(), iteratives.iter()) {
+        violations.push(ConformanceViolation::IterativesModifiedByEvaluation { point });
+    }
+
+    let residuals = model.get_residuals();
+    if residuals.len() != problem_size {
+        violations.push(ConformanceViolation::ResidualsLength {
+            point,
+            length: residuals.len(),
+            problem_size,
+        });
+    }
+
+    if residuals.len() == problem_size && iteratives.len() == problem_size {
+        // the placeholder holds other values, as when reused by the solver
+        let mut left = iteratives.clone();
+        let mut right = iteratives.clone();
+        for (i, (left_value, right_value)) in residuals.iter().enumerate() {
+            left[i] = left_value + 1.0 + left_value.abs();
+            right[i] = right_value + 1.0 + right_value.abs();
+        }
+        let mut written = ResidualsValues::new(left, right);
+        model.write_residuals(&mut written);
+        if !same_residuals(&written, &residuals) {
+            violations.push(ConformanceViolation::WriteResidualsMismatch { point });
+        }
+    }
+
+    match model.evaluate() {
+        Ok(()) => {
+            if !same_residuals(&model.get_residuals(), &residuals) {
+                violations.push(ConformanceViolation::NonDeterministicEvaluation { point });
+            }
+        }
+        Err(_) => violations.push(ConformanceViolation::NonDeterministicEvaluation { point }),
+    }
+
+    if model.jacobian_provided() {
+        check_jacobian(model, point, problem_size, violations);
+    }
+}
+
+fn check_jacobian<M, D>(
+    model: &mut M,
+    point: usize,
+    problem_size: usize,
+    violations: &mut Vec<ConformanceViolation>,
+) where
+    M: Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    if model.provides_jacobian_by_column() {
+        for j in 0..problem_size {
+            match model.get_jacobian_column(j) {
+                Ok((left, right)) => {
+                    for length in [left.len(), right.len()] {
+                        if length != problem_size {
+                            violations.push(ConformanceViolation::JacobianColumnLength {
+                                point,
+                                column: j,
+                                length,
+                                problem_size,
+                            });
+                            return;
+                        }
+                    }
+                }
+                Err(error) => {
+                    violations.push(ConformanceViolation::JacobianFailed {
+                        point,
+                        error: error.to_string(),
+                    });
+                    return;
+                }
+            }
+        }
+    } else {
+        match model.get_jacobian() {
+            Ok(jacobians) => {
+                let (rows, columns) = jacobians.get_left_matrix().shape();
+                if rows != problem_size || columns != problem_size {
+                    violations.push(ConformanceViolation::JacobianDimensions {
+                        point,
+                        rows,
+                        columns,
+                        problem_size,
+                    });
+                }
+            }
+            Err(error) => violations.push(ConformanceViolation::JacobianFailed {
+                point,
+                error: error.to_string(),
+            }),
+        }
+    }
+}
+
+fn same_residuals<D>(left: &ResidualsValues<D>, right: &ResidualsValues<D>) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .all(|(a, b)| same_value(a.0, b.0) && same_value(a.1, b.1))
+}
+
+fn same_values<'a>(
+    left: impl ExactSizeIterator<Item = &'a f64>,
+    right: impl ExactSizeIterator<Item = &'a f64>,
+) -> bool {
+    left.len() == right.len() && left.zip(right).all(|(a, b)| same_value(*a, *b))
+}
+
+/// Equality of two values, NaN being equal to itself
+fn same_value(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
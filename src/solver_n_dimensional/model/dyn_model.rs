@@ -0,0 +1,342 @@
+use std::any::Any;
+use std::boxed::Box;
+use std::error::Error;
+use std::fmt;
+use std::vec::Vec;
+
+use super::{ErrorContext, Model, ModelError};
+
+use crate::residuals;
+
+/// Error of a model wrapped in a [DynModel], with its type erased
+///
+/// `Box<dyn Error>` does not implement the `Error` trait required by the [Model] trait,
+/// hence this wrapper, which displays the original error and gives it as its source.
+///
+/// The [ErrorContext] given by the wrapped model is kept with the error.
+#[derive(Debug)]
+pub struct DynModelError(Box<dyn Error + Send + Sync>, Option<ErrorContext>);
+
+impl DynModelError {
+    pub fn new(error: Box<dyn Error + Send + Sync>) -> Self {
+        DynModelError(error, None)
+    }
+
+    /// Error with the context given by the model, see [super::ModelError::get_context]
+    pub fn with_context(
+        error: Box<dyn Error + Send + Sync>,
+        context: Option<ErrorContext>,
+    ) -> Self {
+        DynModelError(error, context)
+    }
+
+    pub fn get_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.0.as_ref()
+    }
+
+    pub fn get_context(&self) -> Option<&ErrorContext> {
+        self.1.as_ref()
+    }
+
+    pub fn into_error(self) -> Box<dyn Error + Send + Sync> {
+        self.0
+    }
+}
+
+impl fmt::Display for DynModelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for DynModelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
+
+/// Object-safe counterpart of the [Model] trait, implemented by all the models whose errors can be erased
+trait ErasedModel<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    fn len_problem(&self) -> usize;
+    fn set_iteratives(&mut self, iteratives: &nalgebra::OVector<f64, D>);
+    fn get_iteratives(&self) -> nalgebra::OVector<f64, D>;
+    fn evaluate(&mut self) -> Result<(), ModelError<DynModel<D>, D>>;
+    fn get_residuals(&self) -> residuals::ResidualsValues<D>;
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<D>);
+    fn jacobian_provided(&self) -> bool;
+    fn get_jacobian(&mut self) -> Result<residuals::JacobianValues<D>, ModelError<DynModel<D>, D>>;
+    fn provides_jacobian_by_column(&self) -> bool;
+    #[allow(clippy::type_complexity)]
+    fn get_jacobian_column(
+        &mut self,
+        j: usize,
+    ) -> Result<(nalgebra::OVector<f64, D>, nalgebra::OVector<f64, D>), ModelError<DynModel<D>, D>>;
+    fn provides_batch_evaluation(&self) -> bool;
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::OVector<f64, D>],
+    ) -> Result<Vec<residuals::ResidualsValues<D>>, ModelError<DynModel<D>, D>>;
+    fn get_memory(&self) -> nalgebra::DVector<f64>;
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+fn erase_error<M, D>(error: ModelError<M, D>) -> ModelError<DynModel<D>, D>
+where
+    M: Model<D>,
+    M::InaccurateValuesError: Send + Sync + 'static,
+    M::UnusableValuesError: Send + Sync + 'static,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let context = error.get_context();
+    match error {
+        ModelError::InaccurateValuesError(error) => {
+            ModelError::InaccurateValuesError(DynModelError::with_context(Box::new(error), context))
+        }
+        ModelError::UnusableValuesError(error) => {
+            ModelError::UnusableValuesError(DynModelError::with_context(Box::new(error), context))
+        }
+    }
+}
+
+impl<M, D> ErasedModel<D> for M
+where
+    M: Model<D> + 'static,
+    M::InaccurateValuesError: Send + Sync + 'static,
+    M::UnusableValuesError: Send + Sync + 'static,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    fn len_problem(&self) -> usize {
+        Model::len_problem(self)
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::OVector<f64, D>) {
+        Model::set_iteratives(self, iteratives)
+    }
+
+    fn get_iteratives(&self) -> nalgebra::OVector<f64, D> {
+        Model::get_iteratives(self)
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<DynModel<D>, D>> {
+        Model::evaluate(self).map_err(erase_error)
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<D> {
+        Model::get_residuals(self)
+    }
+
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<D>) {
+        Model::write_residuals(self, residuals)
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        Model::jacobian_provided(self)
+    }
+
+    fn get_jacobian(&mut self) -> Result<residuals::JacobianValues<D>, ModelError<DynModel<D>, D>> {
+        Model::get_jacobian(self).map_err(erase_error)
+    }
+
+    fn provides_jacobian_by_column(&self) -> bool {
+        Model::provides_jacobian_by_column(self)
+    }
+
+    fn get_jacobian_column(
+        &mut self,
+        j: usize,
+    ) -> Result<(nalgebra::OVector<f64, D>, nalgebra::OVector<f64, D>), ModelError<DynModel<D>, D>>
+    {
+        Model::get_jacobian_column(self, j).map_err(erase_error)
+    }
+
+    fn provides_batch_evaluation(&self) -> bool {
+        Model::provides_batch_evaluation(self)
+    }
+
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::OVector<f64, D>],
+    ) -> Result<Vec<residuals::ResidualsValues<D>>, ModelError<DynModel<D>, D>> {
+        Model::evaluate_batch(self, inputs).map_err(erase_error)
+    }
+
+    fn get_memory(&self) -> nalgebra::DVector<f64> {
+        Model::get_memory(self)
+    }
+
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>) {
+        Model::set_memory(self, memory)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Type-erased model, to instantiate the solver only once per kind of dimension
+///
+/// The resolution functions such as `RootFinder::solve()` are generic over the model:
+/// they are compiled for each type of model they are used with.
+/// Wrapping the models in a `DynModel` compiles them only once for all the models working with the dimension `D`,
+/// reducing the compilation time and the size of the binary when many types of models are solved.
+///
+/// The calls of the solver to the model go through a virtual dispatch,
+/// and the errors of the model are boxed in a [DynModelError] when they occur.
+/// This cost is small compared to the evaluation of a model, hence with a negligible impact on the resolution,
+/// except for a trivial model evaluated in a tight loop.
+///
+/// The wrapped model is accessed with [DynModel::downcast_ref] and [DynModel::downcast_mut],
+/// for example to read its outputs after the resolution.
+///
+/// Available with the `std` feature, enabled by default.
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::model::Model; // trait import required
+///
+/// pub fn square(x: &nalgebra::DVector::<f64>) -> nalgebra::DVector::<f64> {
+///     x*x
+/// }
+///
+/// let mut user_model = nrf::model::UserModelFromFunction::new(1, square).into_dyn();
+///
+/// user_model.set_iteratives(&nalgebra::DVector::from_vec(vec![2.0]));
+/// user_model.evaluate().unwrap();
+///
+/// assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
+/// assert!(user_model
+///     .downcast_ref::<nrf::model::UserModelFromFunction>()
+///     .is_some());
+/// ```
+pub struct DynModel<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    model: Box<dyn ErasedModel<D>>,
+}
+
+impl<D> DynModel<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    pub fn new<M>(model: M) -> Self
+    where
+        M: Model<D> + 'static,
+        M::InaccurateValuesError: Send + Sync + 'static,
+        M::UnusableValuesError: Send + Sync + 'static,
+    {
+        DynModel {
+            model: Box::new(model),
+        }
+    }
+
+    /// The wrapped model, if it is of type `M`
+    pub fn downcast_ref<M: 'static>(&self) -> Option<&M> {
+        self.model.as_any().downcast_ref::<M>()
+    }
+
+    /// The wrapped model, if it is of type `M`
+    pub fn downcast_mut<M: 'static>(&mut self) -> Option<&mut M> {
+        self.model.as_any_mut().downcast_mut::<M>()
+    }
+}
+
+impl<D> Model<D> for DynModel<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    type InaccurateValuesError = DynModelError;
+    type UnusableValuesError = DynModelError;
+
+    fn len_problem(&self) -> usize {
+        self.model.len_problem()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::OVector<f64, D>) {
+        self.model.set_iteratives(iteratives)
+    }
+
+    fn get_iteratives(&self) -> nalgebra::OVector<f64, D> {
+        self.model.get_iteratives()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, D>> {
+        self.model.evaluate()
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<D> {
+        self.model.get_residuals()
+    }
+
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<D>) {
+        self.model.write_residuals(residuals)
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.model.jacobian_provided()
+    }
+
+    fn get_jacobian(&mut self) -> Result<residuals::JacobianValues<D>, ModelError<Self, D>> {
+        self.model.get_jacobian()
+    }
+
+    fn provides_jacobian_by_column(&self) -> bool {
+        self.model.provides_jacobian_by_column()
+    }
+
+    fn get_jacobian_column(
+        &mut self,
+        j: usize,
+    ) -> Result<(nalgebra::OVector<f64, D>, nalgebra::OVector<f64, D>), ModelError<Self, D>> {
+        self.model.get_jacobian_column(j)
+    }
+
+    fn provides_batch_evaluation(&self) -> bool {
+        self.model.provides_batch_evaluation()
+    }
+
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::OVector<f64, D>],
+    ) -> Result<Vec<residuals::ResidualsValues<D>>, ModelError<Self, D>> {
+        self.model.evaluate_batch(inputs)
+    }
+
+    fn get_memory(&self) -> nalgebra::DVector<f64> {
+        self.model.get_memory()
+    }
+
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>) {
+        self.model.set_memory(memory)
+    }
+
+    fn inaccurate_values_error_context(error: &DynModelError) -> Option<ErrorContext> {
+        error.get_context().cloned()
+    }
+
+    fn unusable_values_error_context(error: &DynModelError) -> Option<ErrorContext> {
+        error.get_context().cloned()
+    }
+}
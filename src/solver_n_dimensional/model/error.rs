@@ -1,5 +1,39 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+
+/// Structured context of a [ModelError], see [ModelError::get_context]
+///
+/// It names the residuals and the iteratives concerned by the error, such as:
+/// - the residual 7 produced NaN: `residual_indices = vec![7]`
+/// - the input 3 is outside the validity domain: `iterative_indices = vec![3]`
+///
+/// The solver reacts to the `iterative_indices` of unusable values returned at the iterate proposed by an iteration:
+/// the step is reduced on these iteratives only, see [crate::solver::MAX_CONTEXT_STEP_REDUCTIONS].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ErrorContext {
+    pub residual_indices: Vec<usize>,
+    pub iterative_indices: Vec<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.message.is_empty() {
+            parts.push(self.message.clone());
+        }
+        if !self.residual_indices.is_empty() {
+            parts.push(alloc::format!("residuals {:?}", self.residual_indices));
+        }
+        if !self.iterative_indices.is_empty() {
+            parts.push(alloc::format!("iteratives {:?}", self.iterative_indices));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
 /// User model error definition
 ///
 /// If the user model raise an error,
@@ -15,7 +49,7 @@ use std::fmt;
 ///
 /// - numerical values exist but are inaccurate:
 ///   - recoverable except if it happens in the final evaluation,
-///      in this case the algorithm can continue with hope it will recover before the final evaluation
+///     in this case the algorithm can continue with hope it will recover before the final evaluation
 ///   - the source of such an error could be an out of validity range on a component of the user model
 /// - unusable numerical values such as NaN, None, defaults values or random values.
 ///   - recoverable if it happens during the iterations phase,
@@ -29,6 +63,61 @@ use std::fmt;
 ///
 /// The rootfinder algorithm will not act on the subclassification,
 /// but the information can be reported in errors logs.
+///
+/// An error can give an [ErrorContext] naming the residuals and iteratives concerned,
+/// the model taking it from its errors with [super::Model::inaccurate_values_error_context]
+/// and [super::Model::unusable_values_error_context]:
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::model::{ErrorContext, Model, ModelError};
+/// # use std::fmt;
+/// # use nrf::residuals;
+///
+/// /// Error naming the inputs outside of their validity domain
+/// #[derive(Debug)]
+/// struct OutOfDomain(Vec<usize>);
+/// # impl fmt::Display for OutOfDomain {
+/// #     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// #         write!(f, "Out of the validity domain")
+/// #     }
+/// # }
+/// # impl std::error::Error for OutOfDomain {}
+///
+/// struct SquareRoot {
+///     x: nalgebra::DVector<f64>,
+/// }
+///
+/// impl Model<nalgebra::Dyn> for SquareRoot {
+///     type InaccurateValuesError = std::convert::Infallible;
+///     type UnusableValuesError = OutOfDomain;
+/// #   fn len_problem(&self) -> usize { 1 }
+/// #   fn set_iteratives(&mut self, x: &nalgebra::DVector<f64>) { self.x.copy_from(x); }
+/// #   fn get_iteratives(&self) -> nalgebra::DVector<f64> { self.x.clone() }
+/// #   fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+/// #       residuals::ResidualsValues::new(self.x.map(f64::sqrt), nalgebra::DVector::from_element(1, 2.0))
+/// #   }
+///
+///     fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+///         if self.x[0] < 0.0 {
+///             return Err(ModelError::UnusableValuesError(OutOfDomain(vec![0])));
+///         }
+///         Ok(())
+///     }
+///
+///     fn unusable_values_error_context(error: &OutOfDomain) -> Option<ErrorContext> {
+///         Some(ErrorContext {
+///             iterative_indices: error.0.clone(),
+///             message: "input outside of the validity domain".to_string(),
+///             ..ErrorContext::default()
+///         })
+///     }
+/// }
+///
+/// let error: ModelError<SquareRoot, nalgebra::Dyn> =
+///     ModelError::UnusableValuesError(OutOfDomain(vec![0]));
+/// assert_eq!(error.get_context().unwrap().iterative_indices, vec![0]);
+/// ```
 pub enum ModelError<M, D>
 where
     M: super::Model<D>,
@@ -40,6 +129,63 @@ where
     UnusableValuesError(M::UnusableValuesError),
 }
 
+impl<M, D> ModelError<M, D>
+where
+    M: super::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    /// Context of the error given by the model, see [super::Model::inaccurate_values_error_context]
+    /// and [super::Model::unusable_values_error_context]
+    pub fn get_context(&self) -> Option<ErrorContext> {
+        match self {
+            Self::InaccurateValuesError(error) => M::inaccurate_values_error_context(error),
+            Self::UnusableValuesError(error) => M::unusable_values_error_context(error),
+        }
+    }
+
+    /// The error is an `InaccurateValuesError`
+    pub fn is_inaccurate_values_error(&self) -> bool {
+        matches!(self, Self::InaccurateValuesError(_))
+    }
+
+    /// The error is an `UnusableValuesError`
+    pub fn is_unusable_values_error(&self) -> bool {
+        matches!(self, Self::UnusableValuesError(_))
+    }
+
+    /// Message of the error of the model followed by its context, without the variant
+    pub(crate) fn message(&self) -> String {
+        let message = match self {
+            Self::InaccurateValuesError(error) => error.to_string(),
+            Self::UnusableValuesError(error) => error.to_string(),
+        };
+        match self.get_context() {
+            Some(context) => alloc::format!("{} ({})", message, context),
+            None => message,
+        }
+    }
+
+    /// Convert the error for another model with the given conversions of the errors of the model
+    pub(crate) fn map<N, E, F, G>(self, inaccurate: F, unusable: G) -> ModelError<N, E>
+    where
+        N: super::Model<E>,
+        E: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<E>,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<E, E>,
+        F: FnOnce(M::InaccurateValuesError) -> N::InaccurateValuesError,
+        G: FnOnce(M::UnusableValuesError) -> N::UnusableValuesError,
+    {
+        match self {
+            Self::InaccurateValuesError(error) => {
+                ModelError::InaccurateValuesError(inaccurate(error))
+            }
+            Self::UnusableValuesError(error) => ModelError::UnusableValuesError(unusable(error)),
+        }
+    }
+}
+
 impl<M, D> fmt::Display for ModelError<M, D>
 where
     M: super::Model<D>,
@@ -49,8 +195,12 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::InaccurateValuesError(error) => write!(f, "InaccurateValues Error: {}", error),
-            Self::UnusableValuesError(error) => write!(f, "UnusableValuesError Error: {}", error),
+            Self::InaccurateValuesError(error) => write!(f, "InaccurateValues Error: {}", error)?,
+            Self::UnusableValuesError(error) => write!(f, "UnusableValuesError Error: {}", error)?,
+        }
+        match self.get_context() {
+            Some(context) => write!(f, " ({})", context),
+            None => Ok(()),
         }
     }
 }
@@ -67,6 +217,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<M, D> Error for ModelError<M, D>
 where
     M: super::Model<D>,
@@ -0,0 +1,178 @@
+use super::Model;
+use crate::errors::IterativesAccessError;
+
+/// Access to the iteratives of a model one by one, or from a slice
+///
+/// It eases the scripting-style use of a model,
+/// for example to nudge one component of the initial guess between two resolutions.
+/// The methods are provided from the ones of the [Model] trait:
+/// a hand-written model opts in with an empty implementation.
+///
+/// It is implemented by the adapters such as [super::UserModelFromFunction].
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::errors::IterativesAccessError;
+/// use nrf::model::{IterativesAccess, Model};
+///
+/// struct Line {
+///     inputs: nalgebra::DVector<f64>,
+/// }
+///
+/// impl Model<nalgebra::Dyn> for Line {
+///     type InaccurateValuesError = std::convert::Infallible;
+///     type UnusableValuesError = std::convert::Infallible;
+///
+///     fn len_problem(&self) -> usize {
+///         2
+///     }
+///     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+///         self.inputs.clone()
+///     }
+///     fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+///         self.inputs.copy_from(iteratives);
+///     }
+///     fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+///         Ok(())
+///     }
+///     fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Dyn> {
+///         nrf::residuals::ResidualsValues::new(self.inputs.clone(), nalgebra::DVector::zeros(2))
+///     }
+/// }
+///
+/// impl IterativesAccess<nalgebra::Dyn> for Line {}
+///
+/// let mut model = Line { inputs: nalgebra::DVector::zeros(2) };
+/// model.set_iteratives_from_slice(&[1.0, 2.0]).unwrap();
+/// model.set_iterative(1, 3.0).unwrap();
+/// assert_eq!(model.get_iterative(1), Ok(3.0));
+/// assert_eq!(
+///     model.get_iterative(2),
+///     Err(IterativesAccessError::IndexOutOfRange { index: 2, problem_size: 2 })
+/// );
+/// ```
+pub trait IterativesAccess<D>: Model<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    /// Set the value of the iterative `index`, the other ones being unchanged
+    fn set_iterative(&mut self, index: usize, value: f64) -> Result<(), IterativesAccessError> {
+        let mut iteratives = self.get_iteratives();
+        set_iterative_in(iteratives.as_mut_slice(), index, value)?;
+        self.set_iteratives(&iteratives);
+        Ok(())
+    }
+
+    /// Value of the iterative `index`
+    fn get_iterative(&self, index: usize) -> Result<f64, IterativesAccessError> {
+        get_iterative_in(self.get_iteratives().as_slice(), index)
+    }
+
+    /// Set all the iteratives from a slice, whose length must be the problem size
+    fn set_iteratives_from_slice(&mut self, values: &[f64]) -> Result<(), IterativesAccessError> {
+        let mut iteratives = self.get_iteratives();
+        set_iteratives_in(iteratives.as_mut_slice(), values)?;
+        self.set_iteratives(&iteratives);
+        Ok(())
+    }
+}
+
+pub(crate) fn set_iterative_in(
+    iteratives: &mut [f64],
+    index: usize,
+    value: f64,
+) -> Result<(), IterativesAccessError> {
+    match iteratives.get_mut(index) {
+        Some(iterative) => {
+            *iterative = value;
+            Ok(())
+        }
+        None => Err(IterativesAccessError::IndexOutOfRange {
+            index,
+            problem_size: iteratives.len(),
+        }),
+    }
+}
+
+pub(crate) fn get_iterative_in(
+    iteratives: &[f64],
+    index: usize,
+) -> Result<f64, IterativesAccessError> {
+    iteratives
+        .get(index)
+        .copied()
+        .ok_or(IterativesAccessError::IndexOutOfRange {
+            index,
+            problem_size: iteratives.len(),
+        })
+}
+
+pub(crate) fn set_iteratives_in(
+    iteratives: &mut [f64],
+    values: &[f64],
+) -> Result<(), IterativesAccessError> {
+    if values.len() != iteratives.len() {
+        return Err(IterativesAccessError::LengthMismatch {
+            length: values.len(),
+            problem_size: iteratives.len(),
+        });
+    }
+    iteratives.copy_from_slice(values);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn index_out_of_range() {
+        let mut iteratives = [1.0, 2.0];
+        assert_eq!(
+            set_iterative_in(&mut iteratives, 2, 0.0),
+            Err(IterativesAccessError::IndexOutOfRange {
+                index: 2,
+                problem_size: 2
+            })
+        );
+        assert_eq!(
+            get_iterative_in(&iteratives, 3),
+            Err(IterativesAccessError::IndexOutOfRange {
+                index: 3,
+                problem_size: 2
+            })
+        );
+        assert_eq!(iteratives, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let mut iteratives = [1.0, 2.0];
+        assert_eq!(
+            set_iteratives_in(&mut iteratives, &[3.0]),
+            Err(IterativesAccessError::LengthMismatch {
+                length: 1,
+                problem_size: 2
+            })
+        );
+        assert_eq!(iteratives, [1.0, 2.0]);
+        set_iteratives_in(&mut iteratives, &[3.0, 4.0]).unwrap();
+        assert_eq!(iteratives, [3.0, 4.0]);
+    }
+
+    #[test]
+    fn error_messages() {
+        let error = IterativesAccessError::IndexOutOfRange {
+            index: 2,
+            problem_size: 2,
+        };
+        assert_eq!(
+            error.to_string(),
+            "The iterative index 2 is out of range, the problem size is 2"
+        );
+    }
+}
@@ -5,8 +5,12 @@
 //!
 //! Don't hesitate to check the [Model] trait documentation !
 //!
+//! The first step to debug a model is to check that it fulfills the contract of the trait
+//! with [conformance::check_model], which lists the invariants violated at a few sample points.
+//!
 //! If you want your model to raise errors and the rootfinding algorithm to react properly to it,
 //! check the [error::ModelError] enum documentation.
+//! An error can name the residuals and iteratives concerned with an [ErrorContext].
 //!
 //! # Motivations
 //!
@@ -54,13 +58,46 @@
 //! - [UserModelFromFunctionAndJacobian]: to work with two functions, one for the model and one for the jacobian
 //! - [UserModelFromClosure]: to work with a closure defining the problem, finite-difference will be used
 //! - [UserModelFromClosureAndJacobian]: to work with two closures, one for the model and one for the jacobian
+//!
+//! Their iteratives can be set one by one between two resolutions with the methods of [IterativesAccess],
+//! which a hand-written model can implement as well.
+//!
+//! # Batch evaluation
+//!
+//! For a model with a high cost per call, such as a model called through FFI,
+//! the perturbed points of the finite-difference jacobian can be evaluated in one call,
+//! see [Model::provides_batch_evaluation] and [Model::evaluate_batch].
+//!
+//! # Switching between dynamic and static dimensions
+//!
+//! A model written for one kind of dimension can be used by a solver working with the other one:
+//! - [StaticToDynModel]: to use a model implementing `Model<nalgebra::Const<N>>` as a `Model<nalgebra::Dyn>`
+//! - [DynToStaticModel]: to use a model implementing `Model<nalgebra::Dyn>` as a `Model<nalgebra::Const<N>>`
+//!
+//! The values are copied at each exchange between the solver and the model,
+//! this is intended to prototype or test a model, not for performance critical code.
+//!
+//! # Type erasure
+//!
+//! The solver is compiled for each type of model it is used with.
+//! With many types of models, [DynModel] erases their types to compile it only once per kind of dimension,
+//! at the cost of a virtual dispatch, see [Model::into_dyn].
 
+pub mod conformance;
+#[cfg(feature = "std")]
+mod dyn_model;
 mod error;
+mod iteratives_access;
+mod model_adapters;
 mod model_definition;
 mod model_from_closure;
 mod model_from_func;
 
-pub use error::ModelError;
+#[cfg(feature = "std")]
+pub use dyn_model::{DynModel, DynModelError};
+pub use error::{ErrorContext, ModelError};
+pub use iteratives_access::IterativesAccess;
+pub use model_adapters::{DynToStaticModel, StaticToDynModel};
 pub use model_definition::Model;
 pub use model_from_closure::{UserModelFromClosure, UserModelFromClosureAndJacobian};
 pub use model_from_func::{UserModelFromFunction, UserModelFromFunctionAndJacobian};
@@ -0,0 +1,313 @@
+use super::{ErrorContext, Model, ModelError};
+
+use crate::residuals;
+
+/// Adapter to use a model defined with static dimensions (`nalgebra::Const<N>`)
+/// with a solver operating on dynamic dimensions (`nalgebra::Dyn`)
+///
+/// The vectors and matrices are copied between the static and dynamic representations
+/// at each exchange with the solver.
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::model::Model; // trait import required
+///
+/// struct Square {
+///     iteratives: nalgebra::Vector1<f64>,
+///     output: nalgebra::Vector1<f64>,
+/// }
+///
+/// impl Model<nalgebra::Const<1>> for Square {
+///     type InaccurateValuesError = std::convert::Infallible;
+///     type UnusableValuesError = std::convert::Infallible;
+///
+///     fn len_problem(&self) -> usize {
+///         1
+///     }
+///     fn set_iteratives(&mut self, iteratives: &nalgebra::Vector1<f64>) {
+///         self.iteratives = *iteratives;
+///     }
+///     fn get_iteratives(&self) -> nalgebra::Vector1<f64> {
+///         self.iteratives
+///     }
+///     fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Const<1>>> {
+///         self.output = self.iteratives.component_mul(&self.iteratives);
+///         Ok(())
+///     }
+///     fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Const<1>> {
+///         nrf::residuals::ResidualsValues::new(self.output, nalgebra::Vector1::new(0.0))
+///     }
+/// }
+///
+/// let static_model = Square {
+///     iteratives: nalgebra::Vector1::new(0.0),
+///     output: nalgebra::Vector1::new(0.0),
+/// };
+/// let mut user_model = nrf::model::StaticToDynModel::new(static_model);
+///
+/// user_model.set_iteratives(&nalgebra::DVector::from_vec(vec![2.0]));
+/// user_model.evaluate().unwrap();
+///
+/// assert_eq!(user_model.len_problem(), 1);
+/// assert_eq!(user_model.get_iteratives(), nalgebra::DVector::from_vec(vec![2.0]));
+/// assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
+/// ```
+pub struct StaticToDynModel<M, const N: usize>
+where
+    M: Model<nalgebra::Const<N>>,
+{
+    model: M,
+}
+
+impl<M, const N: usize> StaticToDynModel<M, N>
+where
+    M: Model<nalgebra::Const<N>>,
+{
+    pub fn new(model: M) -> Self {
+        StaticToDynModel { model }
+    }
+
+    pub fn get_model(&self) -> &M {
+        &self.model
+    }
+
+    pub fn get_model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
+    pub fn into_model(self) -> M {
+        self.model
+    }
+}
+
+impl<M, const N: usize> Model<nalgebra::Dyn> for StaticToDynModel<M, N>
+where
+    M: Model<nalgebra::Const<N>>,
+{
+    type InaccurateValuesError = M::InaccurateValuesError;
+    type UnusableValuesError = M::UnusableValuesError;
+
+    fn len_problem(&self) -> usize {
+        N
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.model
+            .set_iteratives(&super::super::ovector_from_slice_generic(
+                nalgebra::Const::<N>,
+                iteratives.as_slice(),
+            ));
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        nalgebra::DVector::from_column_slice(self.model.get_iteratives().as_slice())
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.model
+            .evaluate()
+            .map_err(|error| error.map(|error| error, |error| error))
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        convert_residuals(&self.model.get_residuals(), nalgebra::Dyn(N))
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.model.jacobian_provided()
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        match self.model.get_jacobian() {
+            Ok(jacobians) => Ok(convert_jacobians(&jacobians, nalgebra::Dyn(N))),
+            Err(error) => Err(error.map(|error| error, |error| error)),
+        }
+    }
+
+    fn get_memory(&self) -> nalgebra::DVector<f64> {
+        self.model.get_memory()
+    }
+
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>) {
+        self.model.set_memory(memory)
+    }
+
+    fn inaccurate_values_error_context(error: &M::InaccurateValuesError) -> Option<ErrorContext> {
+        M::inaccurate_values_error_context(error)
+    }
+
+    fn unusable_values_error_context(error: &M::UnusableValuesError) -> Option<ErrorContext> {
+        M::unusable_values_error_context(error)
+    }
+}
+
+/// Adapter to use a model defined with dynamic dimensions (`nalgebra::Dyn`)
+/// with a solver operating on static dimensions (`nalgebra::Const<N>`)
+///
+/// The vectors and matrices are copied between the dynamic and static representations
+/// at each exchange with the solver.
+///
+/// # Panics
+/// The constructor panics if the size of the problem of the model is not `N`
+///
+/// # Examples
+/// ```
+/// pub fn square(x: &nalgebra::DVector::<f64>) -> nalgebra::DVector::<f64> {
+///     x*x
+/// }
+///
+/// use newton_rootfinder as nrf;
+/// use nrf::model::Model; // trait import required
+///
+/// let dyn_model = nrf::model::UserModelFromFunction::new(1, square);
+/// let mut user_model = nrf::model::DynToStaticModel::<_, 1>::new(dyn_model);
+///
+/// user_model.set_iteratives(&nalgebra::Vector1::new(2.0));
+/// user_model.evaluate().unwrap();
+///
+/// assert_eq!(user_model.len_problem(), 1);
+/// assert_eq!(user_model.get_iteratives(), nalgebra::Vector1::new(2.0));
+/// assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
+/// ```
+pub struct DynToStaticModel<M, const N: usize>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    model: M,
+}
+
+impl<M, const N: usize> DynToStaticModel<M, N>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    pub fn new(model: M) -> Self {
+        if model.len_problem() != N {
+            panic!(
+                "Dimension mismatch between the model and the static dimension {} != {}",
+                model.len_problem(),
+                N
+            );
+        }
+
+        DynToStaticModel { model }
+    }
+
+    pub fn get_model(&self) -> &M {
+        &self.model
+    }
+
+    pub fn get_model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
+    pub fn into_model(self) -> M {
+        self.model
+    }
+}
+
+impl<M, const N: usize> Model<nalgebra::Const<N>> for DynToStaticModel<M, N>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    type InaccurateValuesError = M::InaccurateValuesError;
+    type UnusableValuesError = M::UnusableValuesError;
+
+    fn len_problem(&self) -> usize {
+        N
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::SVector<f64, N>) {
+        self.model
+            .set_iteratives(&nalgebra::DVector::from_column_slice(iteratives.as_slice()));
+    }
+
+    fn get_iteratives(&self) -> nalgebra::SVector<f64, N> {
+        super::super::ovector_from_slice_generic(
+            nalgebra::Const::<N>,
+            self.model.get_iteratives().as_slice(),
+        )
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Const<N>>> {
+        self.model
+            .evaluate()
+            .map_err(|error| error.map(|error| error, |error| error))
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Const<N>> {
+        convert_residuals(&self.model.get_residuals(), nalgebra::Const::<N>)
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.model.jacobian_provided()
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Const<N>>, ModelError<Self, nalgebra::Const<N>>>
+    {
+        match self.model.get_jacobian() {
+            Ok(jacobians) => Ok(convert_jacobians(&jacobians, nalgebra::Const::<N>)),
+            Err(error) => Err(error.map(|error| error, |error| error)),
+        }
+    }
+
+    fn get_memory(&self) -> nalgebra::DVector<f64> {
+        self.model.get_memory()
+    }
+
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>) {
+        self.model.set_memory(memory)
+    }
+
+    fn inaccurate_values_error_context(error: &M::InaccurateValuesError) -> Option<ErrorContext> {
+        M::inaccurate_values_error_context(error)
+    }
+
+    fn unusable_values_error_context(error: &M::UnusableValuesError) -> Option<ErrorContext> {
+        M::unusable_values_error_context(error)
+    }
+}
+
+fn convert_residuals<D1, D2>(
+    residuals_values: &residuals::ResidualsValues<D1>,
+    nrows: D2,
+) -> residuals::ResidualsValues<D2>
+where
+    D1: nalgebra::Dim,
+    D2: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D1>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D2>,
+{
+    let mut left = super::super::ovector_zeros_from_shape(nrows);
+    let mut right = super::super::ovector_zeros_from_shape(nrows);
+
+    for i in 0..residuals_values.len() {
+        (left[i], right[i]) = residuals_values.get_values(i);
+    }
+
+    residuals::ResidualsValues::new(left, right)
+}
+
+fn convert_jacobians<D1, D2>(
+    jacobians_values: &residuals::JacobianValues<D1>,
+    nrows: D2,
+) -> residuals::JacobianValues<D2>
+where
+    D1: nalgebra::Dim,
+    D2: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D1>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D1, D1>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D2>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D2, D2>,
+{
+    let (left, right) = jacobians_values.get_jacobians();
+
+    residuals::JacobianValues::new(
+        super::super::omatrix_from_slice_generic(nrows, left.as_slice()),
+        super::super::omatrix_from_slice_generic(nrows, right.as_slice()),
+    )
+}
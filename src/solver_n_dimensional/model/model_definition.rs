@@ -1,3 +1,8 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
 
 use crate::residuals;
@@ -43,8 +48,15 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
 {
+    #[cfg(feature = "std")]
     type InaccurateValuesError: Error;
+    #[cfg(feature = "std")]
     type UnusableValuesError: Error;
+    /// Without the `std` feature, the errors of the model are only required to be displayed
+    #[cfg(not(feature = "std"))]
+    type InaccurateValuesError: fmt::Display + fmt::Debug;
+    #[cfg(not(feature = "std"))]
+    type UnusableValuesError: fmt::Display + fmt::Debug;
 
     /// This method defines the dimension of the problem.
     ///
@@ -84,6 +96,18 @@ where
     ///
     fn get_residuals(&self) -> residuals::ResidualsValues<D>;
 
+    /// This method writes the values of the output into an existing [residuals::ResidualsValues].
+    ///
+    /// The solver calls it several times per iteration, reusing the same placeholder.
+    /// If overriden with [residuals::ResidualsValues::update_from],
+    /// the residuals are provided without allocating at each call.
+    /// The values written must be the same as the ones returned by [Model::get_residuals].
+    ///
+    /// The default implementation replaces the placeholder with the output of [Model::get_residuals].
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<D>) {
+        *residuals = self.get_residuals();
+    }
+
     /// This method allows the solver to know if the jacobian is provided by the user or not
     ///
     /// The default implementation returns `false` which would lead to using finite-differences for evaluating the jacobian
@@ -108,6 +132,79 @@ where
         Ok(residuals::JacobianValues::new(left, right))
     }
 
+    /// This method allows the solver to know if the jacobian is provided column by column
+    /// through the [Model::get_jacobian_column] method instead of the [Model::get_jacobian] one.
+    ///
+    /// It is only considered if [Model::jacobian_provided] returns `true`.
+    ///
+    /// The default implementation returns `false`.
+    fn provides_jacobian_by_column(&self) -> bool {
+        false
+    }
+
+    /// Method allowing to access the j-th column of the jacobian matrix,
+    /// as a tuple `(left, right)` of the derivatives of the left and right members of the equations.
+    ///
+    /// It allows models that naturally compute the derivatives with respect to one iterative at a time
+    /// to provide them without having to assemble the full matrices.
+    ///
+    /// Like [Model::get_jacobian], this method takes a *mutable* model and can fail.
+    /// If overriden, the [Model::provides_jacobian_by_column] must also be overriden to return `true`.
+    ///
+    /// The default implementation extracts the column from [Model::get_jacobian].
+    #[allow(clippy::type_complexity)]
+    fn get_jacobian_column(
+        &mut self,
+        j: usize,
+    ) -> Result<(nalgebra::OVector<f64, D>, nalgebra::OVector<f64, D>), super::ModelError<Self, D>>
+    {
+        let jacobians = self.get_jacobian()?;
+        let (left, right) = jacobians.get_jacobians();
+        Ok((left.column(j).into_owned(), right.column(j).into_owned()))
+    }
+
+    /// This method allows the solver to know if the perturbed points of the finite-difference jacobian
+    /// can be evaluated in one call to [Model::evaluate_batch] instead of one call to [Model::evaluate] per column.
+    ///
+    /// It is intended for models whose evaluation has a high fixed cost per call,
+    /// such as a model implemented in another language and called through FFI.
+    ///
+    /// The default implementation returns `false`.
+    fn provides_batch_evaluation(&self) -> bool {
+        false
+    }
+
+    /// Method evaluating the model at each of the `inputs`, returning the residuals in the same order:
+    /// the i-th element of the output holds the residuals of the i-th input.
+    ///
+    /// For the finite-difference jacobian, the solver submits the perturbed points of all the evaluated columns,
+    /// in increasing order of the columns, each of them with one iterative perturbed.
+    /// The columns declared constant and reused by the solver are not submitted.
+    ///
+    /// As with the evaluation column by column, the solver does not evaluate the reference point again after the call:
+    /// the model can be left either at the reference point or at one of the inputs.
+    /// If inaccurate values are returned, the columns are evaluated one by one with [Model::evaluate]
+    /// to apply the [crate::solver::InaccurateJacobianPolicy].
+    /// If overriden, the [Model::provides_batch_evaluation] must also be overriden to return `true`.
+    ///
+    /// The default implementation evaluates the inputs one after the other,
+    /// and restores the memory of the model between two of them (see [Model::set_memory]).
+    /// The model is left with the last input.
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::OVector<f64, D>],
+    ) -> Result<Vec<residuals::ResidualsValues<D>>, super::ModelError<Self, D>> {
+        let memory = self.get_memory();
+        let mut outputs = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            self.set_iteratives(input);
+            self.evaluate()?;
+            outputs.push(self.get_residuals());
+            self.set_memory(&memory);
+        }
+        Ok(outputs)
+    }
+
     /// This method allow the solver to memorize information after calculating the reference point
     /// and before the jacobian evaluation by finite-difference.
     ///
@@ -121,4 +218,45 @@ where
     ///
     /// The default implementation is empty.
     fn set_memory(&mut self, #[allow(unused_variables)] memory: &nalgebra::DVector<f64>) {}
+
+    /// Context of an `InaccurateValuesError` returned by the model, see [super::ErrorContext]
+    ///
+    /// The context is taken from the error, for example from a field of the error type.
+    ///
+    /// The default implementation gives no context.
+    fn inaccurate_values_error_context(
+        #[allow(unused_variables)] error: &Self::InaccurateValuesError,
+    ) -> Option<super::ErrorContext>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Context of an `UnusableValuesError` returned by the model, see [super::ErrorContext]
+    ///
+    /// The context is taken from the error, for example from a field of the error type.
+    ///
+    /// The default implementation gives no context.
+    fn unusable_values_error_context(
+        #[allow(unused_variables)] error: &Self::UnusableValuesError,
+    ) -> Option<super::ErrorContext>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Wrap the model in a [super::DynModel], erasing its type
+    ///
+    /// Available with the `std` feature, enabled by default.
+    #[cfg(feature = "std")]
+    fn into_dyn(self) -> super::DynModel<D>
+    where
+        Self: 'static,
+        Self::InaccurateValuesError: Send + Sync + 'static,
+        Self::UnusableValuesError: Send + Sync + 'static,
+    {
+        super::DynModel::new(self)
+    }
 }
@@ -1,6 +1,7 @@
-use std::convert::Infallible;
+use alloc::vec;
+use core::convert::Infallible;
 
-use super::Model;
+use super::{IterativesAccess, Model};
 use crate::residuals;
 
 /// Blanket implementation to easily adapt user closure to the [Model](super::Model) trait required by the solver to work with finite-differences
@@ -54,6 +55,8 @@ impl<'a> UserModelFromClosure<'a> {
     }
 }
 
+impl<'a> IterativesAccess<nalgebra::Dyn> for UserModelFromClosure<'a> {}
+
 impl<'a> Model<nalgebra::Dyn> for UserModelFromClosure<'a> {
     type InaccurateValuesError = Infallible;
     type UnusableValuesError = Infallible;
@@ -67,6 +70,10 @@ impl<'a> Model<nalgebra::Dyn> for UserModelFromClosure<'a> {
         residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
     }
 
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {
+        residuals.update_from(&self.left, &self.right);
+    }
+
     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
         self.inputs.clone()
     }
@@ -145,6 +152,8 @@ impl<'a, 'b> UserModelFromClosureAndJacobian<'a, 'b> {
     }
 }
 
+impl<'a, 'b> IterativesAccess<nalgebra::Dyn> for UserModelFromClosureAndJacobian<'a, 'b> {}
+
 impl<'a, 'b> Model<nalgebra::Dyn> for UserModelFromClosureAndJacobian<'a, 'b> {
     type InaccurateValuesError = Infallible;
     type UnusableValuesError = Infallible;
@@ -158,6 +167,10 @@ impl<'a, 'b> Model<nalgebra::Dyn> for UserModelFromClosureAndJacobian<'a, 'b> {
         residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
     }
 
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {
+        residuals.update_from(&self.left, &self.right);
+    }
+
     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
         self.inputs.clone()
     }
@@ -203,7 +216,7 @@ mod tests {
             user_model.get_iteratives(),
             nalgebra::DVector::from_vec(vec!(2.0))
         );
-        assert_eq!(user_model.jacobian_provided(), false);
+        assert!(!user_model.jacobian_provided());
         assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
     }
 
@@ -230,7 +243,7 @@ mod tests {
             user_model.get_iteratives(),
             nalgebra::DVector::from_vec(vec!(2.0))
         );
-        assert_eq!(user_model.jacobian_provided(), true);
+        assert!(user_model.jacobian_provided());
         assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
     }
 }
@@ -1,6 +1,7 @@
-use std::convert::Infallible;
+use alloc::vec;
+use core::convert::Infallible;
 
-use super::Model;
+use super::{IterativesAccess, Model};
 
 use crate::residuals;
 
@@ -55,6 +56,8 @@ impl UserModelFromFunction {
     }
 }
 
+impl IterativesAccess<nalgebra::Dyn> for UserModelFromFunction {}
+
 impl Model<nalgebra::Dyn> for UserModelFromFunction {
     type InaccurateValuesError = Infallible;
     type UnusableValuesError = Infallible;
@@ -68,6 +71,10 @@ impl Model<nalgebra::Dyn> for UserModelFromFunction {
         residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
     }
 
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {
+        residuals.update_from(&self.left, &self.right);
+    }
+
     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
         self.inputs.clone()
     }
@@ -145,6 +152,8 @@ impl UserModelFromFunctionAndJacobian {
     }
 }
 
+impl IterativesAccess<nalgebra::Dyn> for UserModelFromFunctionAndJacobian {}
+
 impl Model<nalgebra::Dyn> for UserModelFromFunctionAndJacobian {
     type InaccurateValuesError = Infallible;
     type UnusableValuesError = Infallible;
@@ -158,6 +167,10 @@ impl Model<nalgebra::Dyn> for UserModelFromFunctionAndJacobian {
         residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
     }
 
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {
+        residuals.update_from(&self.left, &self.right);
+    }
+
     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
         self.inputs.clone()
     }
@@ -209,7 +222,7 @@ mod tests {
             user_model.get_iteratives(),
             nalgebra::DVector::from_vec(vec!(2.0))
         );
-        assert_eq!(user_model.jacobian_provided(), false);
+        assert!(!user_model.jacobian_provided());
         assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
     }
     #[test]
@@ -224,7 +237,20 @@ mod tests {
             user_model.get_iteratives(),
             nalgebra::DVector::from_vec(vec!(2.0))
         );
-        assert_eq!(user_model.jacobian_provided(), true);
+        assert!(user_model.jacobian_provided());
         assert_eq!(user_model.get_residuals().get_values(0), (4.0, 0.0));
     }
+
+    #[test]
+    fn write_residuals_in_placeholder() {
+        let iteratives = nalgebra::DVector::from_vec(vec![2.0]);
+        let mut user_model = UserModelFromFunction::new(1, square);
+        let mut residuals = user_model.get_residuals();
+
+        user_model.set_iteratives(&iteratives);
+        user_model.evaluate().unwrap();
+        user_model.write_residuals(&mut residuals);
+
+        assert_eq!(residuals.get_values(0), (4.0, 0.0));
+    }
 }
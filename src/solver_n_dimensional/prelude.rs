@@ -0,0 +1,10 @@
+//! Imports shared by the modules of the crate
+//!
+//! It is glob imported with `#[allow(unused_imports)]`,
+//! as its items are only used by some configurations of the features.
+
+// floating point functions of the standard library, provided by libm without it
+// (unused if the standard library is linked by another crate, such as the test harness)
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+pub(crate) use nalgebra::ComplexField;
@@ -1,15 +1,36 @@
-use super::{normalization, NormalizationMethod, ResidualsValues};
-use std::fmt;
+use super::{
+    normalization, GroupAggregation, NormalizationMethod, ResidualsGroup, ResidualsValues,
+};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Default threshold of the safeguard of the relative normalization,
+/// see `ResidualsConfig::set_rel_safeguard_epsilon()`
+pub const DEFAULT_REL_SAFEGUARD_EPSILON: f64 = 1e-8;
 
 /// Single residual configuration
 ///
 /// A residual is constituded of two elements:
 /// - the way of computing the `stopping_critera` from the left and right part of a residual
 /// - the way of computing the error for the update (`update_method`) used by the rootfinder
+///
+/// The `rel_floor` bounds below the denominator of the `Rel` methods of the residual,
+/// see `set_rel_floor()`
+///
+/// The optional `max_magnitude` bounds the left and right members of the residual,
+/// see `set_max_magnitude()`
+///
+/// The residual can be excluded from the system with `set_ignored()`
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct ResidualConfig {
     stopping_critera: NormalizationMethod,
     update_method: NormalizationMethod,
+    rel_floor: f64,
+    max_magnitude: Option<f64>,
+    ignored: bool,
 }
 
 impl Default for ResidualConfig {
@@ -17,15 +38,85 @@ impl Default for ResidualConfig {
         ResidualConfig {
             stopping_critera: NormalizationMethod::Abs,
             update_method: NormalizationMethod::Abs,
+            rel_floor: 0.0,
+            max_magnitude: None,
+            ignored: false,
         }
     }
 }
 
 impl ResidualConfig {
-    pub fn new(stopping_critera: NormalizationMethod, update_method: NormalizationMethod) -> Self {
+    /// The constructor is `const`, allowing to define the configurations in a `const` or a `static`
+    pub const fn new(
+        stopping_critera: NormalizationMethod,
+        update_method: NormalizationMethod,
+    ) -> Self {
         ResidualConfig {
             stopping_critera,
             update_method,
+            rel_floor: 0.0,
+            max_magnitude: None,
+            ignored: false,
+        }
+    }
+
+    /// Set the floor of the denominator of the `Rel` normalization, `0.0` by default
+    ///
+    /// With a positive floor, the `Rel` stopping criteria and update method of the residual
+    /// are replaced by `RelFloored(rel_floor)` by `ResidualsConfig::convert_into_vecs()`.
+    /// The default value keeps the `Rel` normalization unchanged.
+    pub fn set_rel_floor(&mut self, rel_floor: f64) {
+        if !(rel_floor >= 0.0 && rel_floor.is_finite()) {
+            panic!(
+                "The floor of the relative normalization must be positive and finite, got {}",
+                rel_floor
+            );
+        }
+        self.rel_floor = rel_floor;
+    }
+
+    pub fn get_rel_floor(self) -> f64 {
+        self.rel_floor
+    }
+
+    /// Set the maximum magnitude of the left and right members of the residual, `None` by default
+    ///
+    /// A member exceeding it in absolute value means that the model is in a broken regime:
+    /// the resolution is stopped with `SolverError::ResidualMagnitudeExceeded`,
+    /// see `ResidualsConfig::set_max_magnitudes()`
+    pub fn set_max_magnitude(&mut self, max_magnitude: Option<f64>) {
+        if let Some(magnitude) = max_magnitude {
+            if magnitude.is_nan() || magnitude <= 0.0 {
+                panic!(
+                    "The maximum magnitude of a residual must be strictly positive, got {}",
+                    magnitude
+                );
+            }
+        }
+        self.max_magnitude = max_magnitude;
+    }
+
+    pub fn get_max_magnitude(self) -> Option<f64> {
+        self.max_magnitude
+    }
+
+    /// Exclude the residual from the system, `false` by default
+    ///
+    /// See `ResidualsConfig::set_ignored_residuals()`
+    pub fn set_ignored(&mut self, ignored: bool) {
+        self.ignored = ignored;
+    }
+
+    pub fn get_ignored(self) -> bool {
+        self.ignored
+    }
+
+    fn floored(self, method: NormalizationMethod) -> NormalizationMethod {
+        match method {
+            NormalizationMethod::Rel if self.rel_floor > 0.0 => {
+                NormalizationMethod::RelFloored(self.rel_floor)
+            }
+            method => method,
         }
     }
 
@@ -37,6 +128,74 @@ impl ResidualConfig {
     }
 }
 
+/// Configuration of a residual inside a `ResidualsConfig`, as given by `ResidualsConfig::iter()`
+///
+/// The `Rel` methods of a `ResidualConfig` with a `rel_floor` are given as `RelFloored`,
+/// as converted by `ResidualsConfig::convert_into_vecs()`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ResidualEntry {
+    stopping_criteria: NormalizationMethod,
+    update_method: NormalizationMethod,
+    rel_safeguard_epsilon: f64,
+    max_magnitude: Option<f64>,
+    ignored: bool,
+}
+
+impl ResidualEntry {
+    pub fn get_stopping_criteria(self) -> NormalizationMethod {
+        self.stopping_criteria
+    }
+
+    pub fn get_update_method(self) -> NormalizationMethod {
+        self.update_method
+    }
+
+    /// Threshold of the safeguard of the relative normalization,
+    /// see `ResidualsConfig::set_rel_safeguard_epsilons()`
+    pub fn get_rel_safeguard_epsilon(self) -> f64 {
+        self.rel_safeguard_epsilon
+    }
+
+    /// Maximum magnitude of the members, see `ResidualsConfig::set_max_magnitudes()`
+    pub fn get_max_magnitude(self) -> Option<f64> {
+        self.max_magnitude
+    }
+
+    /// Whether the residual is excluded from the system, see `ResidualsConfig::set_ignored_residuals()`
+    pub fn get_ignored(self) -> bool {
+        self.ignored
+    }
+}
+
+/// Iterator over the residuals of a `ResidualsConfig`, created by `ResidualsConfig::iter()`
+#[derive(Debug, Clone)]
+pub struct ResidualsIter<'b, 'a> {
+    residuals_config: &'b ResidualsConfig<'a>,
+    indices: core::ops::Range<usize>,
+}
+
+impl<'b, 'a> Iterator for ResidualsIter<'b, 'a> {
+    type Item = ResidualEntry;
+
+    fn next(&mut self) -> Option<ResidualEntry> {
+        let index = self.indices.next()?;
+        self.residuals_config.get(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'b, 'a> DoubleEndedIterator for ResidualsIter<'b, 'a> {
+    fn next_back(&mut self) -> Option<ResidualEntry> {
+        let index = self.indices.next_back()?;
+        self.residuals_config.get(index)
+    }
+}
+
+impl<'b, 'a> ExactSizeIterator for ResidualsIter<'b, 'a> {}
+
 /// Residuals configuration used by the solver
 ///
 /// The solver is using directly two slices to perform its calculation
@@ -48,11 +207,64 @@ impl ResidualConfig {
 /// However, if the performance is critical for the user,
 /// it should create is own arrays to feed to the `new()` constructor
 /// and not use `ResidualConfig` (singular)
+///
+/// ## Groups
+///
+/// The residuals can optionally be gathered into named groups with `set_groups()`,
+/// for example the components of a vector equation.
+/// The error of a group aggregates the stopping residuals of its members (see [GroupAggregation]),
+/// it is written in the debug log at each iteration.
+/// With `set_group_convergence()`, the convergence is checked on the errors of the groups
+/// instead of the stopping residuals of each residual.
+///
+/// The normalization methods remain defined for each residual,
+/// `convert_groups_into_vecs()` giving them from a configuration per group.
+///
+/// ## Safeguard of the relative normalization
+///
+/// With the `Rel` update method, the update residual is divided by the mean of the left and right members.
+/// If an intermediate iterate makes this mean cross zero, the update residual blows up,
+/// and so does the step computed from it.
+///
+/// To prevent it, a residual whose mean `abs(left+right)/2` is below its `rel_safeguard_epsilon`
+/// is normalized with the `Adapt` method for the update residuals and the jacobian,
+/// its stopping criteria being unchanged.
+/// The threshold is [DEFAULT_REL_SAFEGUARD_EPSILON] by default,
+/// it can be set for all the residuals with `set_rel_safeguard_epsilon()`
+/// or for each one with `set_rel_safeguard_epsilons()`, a value of `0.0` disabling the safeguard.
+///
+/// The `RelFloored` method is not concerned by the safeguard:
+/// its denominator is bounded below by the floor given by the user, see `ResidualConfig::set_rel_floor()`.
+///
+/// ## Maximum magnitude of the members
+///
+/// Optional bounds on the left and right members of each residual can be set with `set_max_magnitudes()`,
+/// to detect early that the model is in a broken regime, for example with values around `1e30`.
+/// They are checked after the evaluation of the model at the initial guess and at each iterate proposed by the solver,
+/// the resolution being stopped with `SolverError::ResidualMagnitudeExceeded`.
+///
+/// During the finite difference evaluation of the jacobian, a perturbed point exceeding them
+/// does not stop the resolution: the column is treated as one with inaccurate values
+/// (see [crate::solver::InaccurateJacobianPolicy]), the column of the previous jacobian being kept if it is not recovered.
+///
+/// ## Ignored residuals
+///
+/// The residuals declared with `set_ignored_residuals()` are excluded from the system:
+/// their update and stopping residuals are always null, they do not take part in the convergence.
+/// Each of them makes room for an iterative fixed at a given value
+/// (see the `fixed_value()` method of the [crate::iteratives::Iterative] trait),
+/// the solver requiring as many ignored residuals as fixed iteratives.
 #[derive(Debug, PartialEq)]
 pub struct ResidualsConfig<'a> {
     stopping_criterias: &'a [NormalizationMethod],
     update_methods: &'a [NormalizationMethod],
     length: usize,
+    groups: Vec<ResidualsGroup>,
+    group_aggregation: GroupAggregation,
+    group_convergence: bool,
+    rel_safeguard_epsilons: Vec<f64>,
+    max_magnitudes: Vec<Option<f64>>,
+    ignored_residuals: Vec<usize>,
 }
 
 impl<'a> ResidualsConfig<'a> {
@@ -69,14 +281,56 @@ impl<'a> ResidualsConfig<'a> {
             );
         }
 
+        for (i, method) in stopping_criterias
+            .iter()
+            .chain(update_methods.iter())
+            .enumerate()
+        {
+            if let NormalizationMethod::RelToReference(reference) = method {
+                if !(*reference > 0.0 && reference.is_finite()) {
+                    panic!(
+                        "The reference of the residual {} must be strictly positive and finite, got {}",
+                        i % length,
+                        reference
+                    );
+                }
+            }
+            if let NormalizationMethod::AdaptScaled(scale) = method {
+                if !(*scale > 0.0 && scale.is_finite()) {
+                    panic!(
+                        "The scale of the residual {} must be strictly positive and finite, got {}",
+                        i % length,
+                        scale
+                    );
+                }
+            }
+            if let NormalizationMethod::RelFloored(floor) = method {
+                if !(*floor >= 0.0 && floor.is_finite()) {
+                    panic!(
+                        "The floor of the residual {} must be positive and finite, got {}",
+                        i % length,
+                        floor
+                    );
+                }
+            }
+        }
+
         ResidualsConfig {
             stopping_criterias,
             update_methods,
             length,
+            groups: Vec::new(),
+            group_aggregation: GroupAggregation::default(),
+            group_convergence: false,
+            rel_safeguard_epsilons: vec![DEFAULT_REL_SAFEGUARD_EPSILON; length],
+            max_magnitudes: vec![None; length],
+            ignored_residuals: Vec::new(),
         }
     }
 
     /// Method to generate the vector of `stopping_criteras` and `update_methods` from a vector of `ResidualConfig`
+    ///
+    /// The `Rel` methods of a residual with a positive `rel_floor` are given as `RelFloored`
     pub fn convert_into_vecs(
         residuals_config: Vec<ResidualConfig>,
     ) -> (Vec<NormalizationMethod>, Vec<NormalizationMethod>) {
@@ -85,17 +339,325 @@ impl<'a> ResidualsConfig<'a> {
         let mut update_methods = Vec::with_capacity(length);
 
         for elt in residuals_config {
-            stopping_criterias.push(elt.get_stopping_criteria());
-            update_methods.push(elt.get_update_method());
+            stopping_criterias.push(elt.floored(elt.get_stopping_criteria()));
+            update_methods.push(elt.floored(elt.get_update_method()));
         }
 
         (stopping_criterias, update_methods)
     }
 
+    /// Method to generate the vector of the maximum magnitudes from a slice of `ResidualConfig`,
+    /// to be given to `set_max_magnitudes()`
+    pub fn convert_into_max_magnitudes(residuals_config: &[ResidualConfig]) -> Vec<Option<f64>> {
+        residuals_config
+            .iter()
+            .map(|elt| elt.get_max_magnitude())
+            .collect()
+    }
+
+    /// Method to generate the indices of the ignored residuals from a slice of `ResidualConfig`,
+    /// to be given to `set_ignored_residuals()`
+    pub fn convert_into_ignored_residuals(residuals_config: &[ResidualConfig]) -> Vec<usize> {
+        residuals_config
+            .iter()
+            .enumerate()
+            .filter(|(_, elt)| elt.get_ignored())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Method to generate the vector of `stopping_criteras` and `update_methods` from a configuration per group
+    ///
+    /// The `ResidualConfig` of each group is applied to all its members,
+    /// the groups must form a partition of the `length` residuals.
+    pub fn convert_groups_into_vecs(
+        groups: &[(ResidualsGroup, ResidualConfig)],
+        length: usize,
+    ) -> (Vec<NormalizationMethod>, Vec<NormalizationMethod>) {
+        let groups_only: Vec<ResidualsGroup> =
+            groups.iter().map(|(group, _)| group.clone()).collect();
+        check_partition(&groups_only, length);
+
+        let mut residuals_config = vec![ResidualConfig::default(); length];
+        for (group, config) in groups {
+            for &i in group.get_indices() {
+                residuals_config[i] = *config;
+            }
+        }
+        ResidualsConfig::convert_into_vecs(residuals_config)
+    }
+
+    /// Set the groups of residuals
+    ///
+    /// The groups must form a partition of the residuals:
+    /// each residual must belong to exactly one group
+    pub fn set_groups(&mut self, groups: Vec<ResidualsGroup>) {
+        check_partition(&groups, self.length);
+        self.groups = groups;
+    }
+
+    pub fn get_groups(&self) -> &[ResidualsGroup] {
+        &self.groups
+    }
+
+    pub fn set_group_aggregation(&mut self, group_aggregation: GroupAggregation) {
+        self.group_aggregation = group_aggregation;
+    }
+
+    pub fn get_group_aggregation(&self) -> GroupAggregation {
+        self.group_aggregation
+    }
+
+    /// Check the convergence on the errors of the groups, the default being `false`
+    ///
+    /// Each group error must then be below the tolerance of the group, if any,
+    /// or the tolerance of the solver otherwise
+    pub fn set_group_convergence(&mut self, group_convergence: bool) {
+        if group_convergence && self.groups.is_empty() {
+            panic!("The convergence on the groups of residuals requires groups, see set_groups()");
+        }
+        self.group_convergence = group_convergence;
+    }
+
+    pub fn get_group_convergence(&self) -> bool {
+        self.group_convergence
+    }
+
+    /// Errors of the groups, in the order of the groups, computed from the stopping residuals
+    pub fn evaluate_group_errors<D>(
+        &self,
+        stopping_residuals: &nalgebra::OVector<f64, D>,
+    ) -> Vec<f64>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        self.groups
+            .iter()
+            .map(|group| group.aggregate(stopping_residuals, self.group_aggregation))
+            .collect()
+    }
+
+    /// Check if the error of each group is below its tolerance,
+    /// `tolerance` being used for the groups without one
+    pub fn are_groups_converged<D>(
+        &self,
+        stopping_residuals: &nalgebra::OVector<f64, D>,
+        tolerance: f64,
+    ) -> bool
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        self.groups.iter().all(|group| {
+            group.aggregate(stopping_residuals, self.group_aggregation)
+                <= group.get_tolerance().unwrap_or(tolerance)
+        })
+    }
+
+    /// Tolerance of each residual
+    ///
+    /// With the convergence on the groups, it is the tolerance of the group of the residual, if any,
+    /// `tolerance` being used otherwise
+    pub fn residual_tolerances(&self, tolerance: f64) -> Vec<f64> {
+        let mut tolerances = vec![tolerance; self.length];
+        if self.group_convergence {
+            for group in &self.groups {
+                let group_tolerance = group.get_tolerance().unwrap_or(tolerance);
+                for &i in group.get_indices() {
+                    tolerances[i] = group_tolerance;
+                }
+            }
+        }
+        tolerances
+    }
+
+    /// Stopping residuals expressed in units of the tolerance of each residual, see [ResidualsConfig::residual_tolerances]
+    ///
+    /// A value above `1.0` is a residual above its tolerance
+    pub fn evaluate_errors_over_tolerance<D>(
+        &self,
+        stopping_residuals: &nalgebra::OVector<f64, D>,
+        tolerance: f64,
+    ) -> nalgebra::OVector<f64, D>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let mut errors_over_tolerance = stopping_residuals.clone();
+        for (error, tolerance) in errors_over_tolerance
+            .iter_mut()
+            .zip(self.residual_tolerances(tolerance))
+        {
+            *error /= tolerance;
+        }
+        errors_over_tolerance
+    }
+
+    /// Set the threshold of the safeguard of the relative normalization for all the residuals
+    pub fn set_rel_safeguard_epsilon(&mut self, epsilon: f64) {
+        self.set_rel_safeguard_epsilons(vec![epsilon; self.length]);
+    }
+
+    /// Set the threshold of the safeguard of the relative normalization of each residual
+    pub fn set_rel_safeguard_epsilons(&mut self, epsilons: Vec<f64>) {
+        if epsilons.len() != self.length {
+            panic!(
+                "Dimension mismatch between the residuals and the safeguard thresholds {} != {}",
+                self.length,
+                epsilons.len()
+            );
+        }
+        for (i, epsilon) in epsilons.iter().enumerate() {
+            if !(*epsilon >= 0.0 && epsilon.is_finite()) {
+                panic!(
+                    "The safeguard threshold of the residual {} must be positive and finite, got {}",
+                    i, epsilon
+                );
+            }
+        }
+        self.rel_safeguard_epsilons = epsilons;
+    }
+
+    pub fn get_rel_safeguard_epsilons(&self) -> &[f64] {
+        &self.rel_safeguard_epsilons
+    }
+
+    /// Set the maximum magnitude of the left and right members of each residual, `None` disabling the check
+    pub fn set_max_magnitudes(&mut self, max_magnitudes: Vec<Option<f64>>) {
+        if max_magnitudes.len() != self.length {
+            panic!(
+                "Dimension mismatch between the residuals and the maximum magnitudes {} != {}",
+                self.length,
+                max_magnitudes.len()
+            );
+        }
+        for (i, max_magnitude) in max_magnitudes.iter().enumerate() {
+            if let Some(magnitude) = max_magnitude {
+                if magnitude.is_nan() || *magnitude <= 0.0 {
+                    panic!(
+                        "The maximum magnitude of the residual {} must be strictly positive, got {}",
+                        i, magnitude
+                    );
+                }
+            }
+        }
+        self.max_magnitudes = max_magnitudes;
+    }
+
+    pub fn get_max_magnitudes(&self) -> &[Option<f64>] {
+        &self.max_magnitudes
+    }
+
+    /// Exclude residuals from the system, see the section on the ignored residuals
+    ///
+    /// The indices can be given in any order, they are stored sorted
+    pub fn set_ignored_residuals(&mut self, mut ignored_residuals: Vec<usize>) {
+        ignored_residuals.sort_unstable();
+        ignored_residuals.dedup();
+        if let Some(&index) = ignored_residuals.last() {
+            if index >= self.length {
+                panic!(
+                    "The ignored residual {} is out of range, the number of residuals is {}",
+                    index, self.length
+                );
+            }
+        }
+        self.ignored_residuals = ignored_residuals;
+    }
+
+    /// Indices of the ignored residuals, sorted
+    pub fn get_ignored_residuals(&self) -> &[usize] {
+        &self.ignored_residuals
+    }
+
+    /// Set the ignored residuals to zero
+    fn clear_ignored<D>(&self, residuals: &mut nalgebra::OVector<f64, D>)
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        for &i in &self.ignored_residuals {
+            residuals[i] = 0.0;
+        }
+    }
+
+    /// First residual with a member exceeding its maximum magnitude, see `set_max_magnitudes()`
+    ///
+    /// The index of the residual is returned with the value of the member and the maximum magnitude.
+    /// A `NaN` member is not considered as exceeding the maximum magnitude.
+    pub fn find_exceeded_magnitude<D>(
+        &self,
+        values: &ResidualsValues<D>,
+    ) -> Option<(usize, f64, f64)>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        self.max_magnitudes
+            .iter()
+            .enumerate()
+            .find_map(|(i, max_magnitude)| {
+                let limit = (*max_magnitude)?;
+                let (left, right) = values.get_values(i);
+                [left, right]
+                    .into_iter()
+                    .find(|value| value.abs() > limit)
+                    .map(|value| (i, value, limit))
+            })
+    }
+
     pub fn len(&self) -> usize {
         self.length
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Configuration of the residual `index`, `None` if it is out of range
+    pub fn get(&self, index: usize) -> Option<ResidualEntry> {
+        if index >= self.length {
+            return None;
+        }
+        Some(ResidualEntry {
+            stopping_criteria: self.stopping_criterias[index],
+            update_method: self.update_methods[index],
+            rel_safeguard_epsilon: self.rel_safeguard_epsilons[index],
+            max_magnitude: self.max_magnitudes[index],
+            ignored: self.ignored_residuals.binary_search(&index).is_ok(),
+        })
+    }
+
+    /// Iterate over the configuration of the residuals, in the order of the residuals
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::residuals::{NormalizationMethod, ResidualsConfig};
+    ///
+    /// let stopping_criterias = [NormalizationMethod::Abs, NormalizationMethod::Rel];
+    /// let update_methods = [NormalizationMethod::Abs, NormalizationMethod::Abs];
+    /// let mut residuals_config = ResidualsConfig::new(&stopping_criterias, &update_methods);
+    /// residuals_config.set_max_magnitudes(vec![None, Some(1e10)]);
+    ///
+    /// let relative: Vec<usize> = residuals_config
+    ///     .iter()
+    ///     .enumerate()
+    ///     .filter(|(_, entry)| entry.get_stopping_criteria() == NormalizationMethod::Rel)
+    ///     .map(|(i, _)| i)
+    ///     .collect();
+    /// assert_eq!(relative, vec![1]);
+    /// assert_eq!(residuals_config.get(1).unwrap().get_max_magnitude(), Some(1e10));
+    /// assert!(residuals_config.get(2).is_none());
+    /// ```
+    pub fn iter(&self) -> ResidualsIter<'_, 'a> {
+        ResidualsIter {
+            residuals_config: self,
+            indices: 0..self.length,
+        }
+    }
+
     /// Evaluation of the value of the update residuals thanks to the `normalization()` function
     pub fn evaluate_update_residuals<D>(
         &self,
@@ -108,13 +670,89 @@ impl<'a> ResidualsConfig<'a> {
         let mut update_residuals: nalgebra::OVector<f64, D> =
             super::super::ovector_zeros_from_shape(values.shape_generic());
 
-        for (i, &update_method) in self.update_methods.iter().enumerate() {
+        for i in 0..self.length {
+            let (left, right) = values.get_values(i);
+            update_residuals[i] =
+                normalization(left, right, self.safeguarded_update_method(i, left, right));
+        }
+        self.clear_ignored(&mut update_residuals);
+        update_residuals
+    }
+
+    /// Evaluation of the value of the update residuals with the given update methods
+    ///
+    /// It allows to keep the methods given by `get_safeguarded_update_methods()` at a reference point,
+    /// for example for the columns of a finite difference jacobian
+    pub fn evaluate_update_residuals_with<D>(
+        &self,
+        values: &ResidualsValues<D>,
+        update_methods: &[NormalizationMethod],
+    ) -> nalgebra::OVector<f64, D>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let mut update_residuals: nalgebra::OVector<f64, D> =
+            super::super::ovector_zeros_from_shape(values.shape_generic());
+
+        for (i, &update_method) in update_methods.iter().enumerate() {
             let (left, right) = values.get_values(i);
             update_residuals[i] = normalization(left, right, update_method);
         }
+        self.clear_ignored(&mut update_residuals);
         update_residuals
     }
 
+    /// Update method of the residual `index`, the `Rel` method falling back to `Adapt`
+    /// if the mean of the members is below the safeguard threshold
+    fn safeguarded_update_method(
+        &self,
+        index: usize,
+        left: f64,
+        right: f64,
+    ) -> NormalizationMethod {
+        match self.update_methods[index] {
+            NormalizationMethod::Rel
+                if (left + right).abs() / 2.0 < self.rel_safeguard_epsilons[index] =>
+            {
+                NormalizationMethod::Adapt
+            }
+            update_method => update_method,
+        }
+    }
+
+    /// Update methods actually used with the given residuals values,
+    /// taking into account the safeguard of the relative normalization
+    pub fn get_safeguarded_update_methods<D>(
+        &self,
+        values: &ResidualsValues<D>,
+    ) -> Vec<NormalizationMethod>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        (0..self.length)
+            .map(|i| {
+                let (left, right) = values.get_values(i);
+                self.safeguarded_update_method(i, left, right)
+            })
+            .collect()
+    }
+
+    /// Residuals for which the safeguard of the relative normalization is active with the given residuals values
+    pub fn get_safeguarded_residuals<D>(&self, values: &ResidualsValues<D>) -> Vec<usize>
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        (0..self.length)
+            .filter(|&i| {
+                let (left, right) = values.get_values(i);
+                self.safeguarded_update_method(i, left, right) != self.update_methods[i]
+            })
+            .collect()
+    }
+
     /// Evaluation of the value of the stopping residuals thanks to the `normalization()` function
     pub fn evaluate_stopping_residuals<D>(
         &self,
@@ -131,6 +769,7 @@ impl<'a> ResidualsConfig<'a> {
             let (left, right) = values.get_values(i);
             stopping_residuals[i] = normalization(left, right, stopping_criteria).abs();
         }
+        self.clear_ignored(&mut stopping_residuals);
         stopping_residuals
     }
 
@@ -141,10 +780,16 @@ impl<'a> ResidualsConfig<'a> {
     pub fn get_stopping_criterias(&self) -> &'a [NormalizationMethod] {
         self.stopping_criterias
     }
-}
 
-impl<'a> fmt::Display for ResidualsConfig<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// Format the table of the residuals configuration
+    ///
+    /// With `Some(n)`, only the first `n` and the last `n` residuals are displayed,
+    /// the other ones being replaced by a line with their number.
+    /// With `None`, all the residuals are displayed.
+    ///
+    /// The [fmt::Display] implementation displays the first and last 10 residuals,
+    /// this number can be changed through the width of the formatter: `format!("{:5}", residuals_config)`
+    pub fn format_with(&self, displayed_entries: Option<usize>) -> String {
         let separation_line = String::from(
             "+-------------------+--------------------------+--------------------------+\n",
         );
@@ -162,7 +807,22 @@ impl<'a> fmt::Display for ResidualsConfig<'a> {
 
         content.push_str(&separation_line);
 
+        let hidden = super::super::hidden_entries(self.len(), displayed_entries);
+        let displayed_rows = match &hidden {
+            Some(hidden) => self.len() - hidden.len() + 1,
+            None => self.len(),
+        };
+        content.reserve(separation_line.len() * (displayed_rows + 2));
+
         for i in 0..self.len() {
+            if let Some(hidden) = &hidden {
+                if i == hidden.start {
+                    content.push_str(&super::super::hidden_entries_marker(hidden));
+                }
+                if hidden.contains(&i) {
+                    continue;
+                }
+            }
             content.push_str(&format!("| {:width$}", &i.to_string(), width = 18));
             content.push_str("| ");
             content.push_str(&format!(
@@ -180,6 +840,188 @@ impl<'a> fmt::Display for ResidualsConfig<'a> {
         }
         content.push_str(&separation_line);
         content.push('\n');
-        write!(f, "{}", content)
+
+        if !self.groups.is_empty() {
+            content.push_str(&self.format_groups());
+        }
+        content
+    }
+
+    /// Format the table of the groups of residuals
+    fn format_groups(&self) -> String {
+        let separation_line = String::from(
+            "+-------------------+--------------------------+--------------------------+\n",
+        );
+
+        let mut content = String::from("Residuals groups\n");
+        content.push_str("================\n\n");
+        content.push_str(&format!(
+            "Aggregation: {}, convergence checked on the groups: {}\n",
+            self.group_aggregation, self.group_convergence
+        ));
+        content.push_str(&separation_line);
+        content.push_str(&format!(
+            "| {:18}| {:25}| {:25}|\n",
+            "Group", "Residuals", "Tolerance"
+        ));
+        content.push_str(&separation_line);
+        for group in &self.groups {
+            let tolerance = match group.get_tolerance() {
+                Some(tolerance) => tolerance.to_string(),
+                None => "Solver tolerance".to_string(),
+            };
+            content.push_str(&format!(
+                "| {:18}| {:25}| {:25}|\n",
+                group.get_name(),
+                group.format_indices(),
+                tolerance
+            ));
+        }
+        content.push_str(&separation_line);
+        content.push('\n');
+        content
+    }
+
+    /// Format the table of the errors of the groups, computed from the stopping residuals
+    pub fn format_group_errors<D>(&self, stopping_residuals: &nalgebra::OVector<f64, D>) -> String
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let separation_line = String::from("+-------------------+--------------------------+\n");
+
+        let mut content = separation_line.clone();
+        content.push_str(&format!("| {:18}| {:25}|\n", "Group", "Error"));
+        content.push_str(&separation_line);
+        for (group, error) in self
+            .groups
+            .iter()
+            .zip(self.evaluate_group_errors(stopping_residuals))
+        {
+            content.push_str(&format!("| {:18}| {:25}|\n", group.get_name(), error));
+        }
+        content.push_str(&separation_line);
+        content.push('\n');
+        content
+    }
+}
+
+/// Check that the groups form a partition of the `length` residuals
+fn check_partition(groups: &[ResidualsGroup], length: usize) {
+    let mut owners: Vec<Option<&str>> = vec![None; length];
+    for group in groups {
+        for &i in group.get_indices() {
+            if i >= length {
+                panic!(
+                    "The residual {} of the group {} is out of range, the number of residuals is {}",
+                    i,
+                    group.get_name(),
+                    length
+                );
+            }
+            if let Some(owner) = owners[i] {
+                panic!(
+                    "The residual {} belongs to both the groups {} and {}",
+                    i,
+                    owner,
+                    group.get_name()
+                );
+            }
+            owners[i] = Some(group.get_name());
+        }
+    }
+    if let Some(i) = owners.iter().position(|owner| owner.is_none()) {
+        panic!("The residual {} does not belong to any group", i);
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b ResidualsConfig<'a> {
+    type Item = ResidualEntry;
+    type IntoIter = ResidualsIter<'b, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> fmt::Display for ResidualsConfig<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let displayed_entries = f.width().unwrap_or(super::super::DEFAULT_DISPLAYED_ENTRIES);
+        write!(f, "{}", self.format_with(Some(displayed_entries)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STOPPING_CRITERIAS: [NormalizationMethod; 3] = [
+        NormalizationMethod::Abs,
+        NormalizationMethod::Rel,
+        NormalizationMethod::Adapt,
+    ];
+    const UPDATE_METHODS: [NormalizationMethod; 3] = [
+        NormalizationMethod::Rel,
+        NormalizationMethod::Abs,
+        NormalizationMethod::Abs,
+    ];
+
+    #[test]
+    fn iteration_order() {
+        let mut residuals_config = ResidualsConfig::new(&STOPPING_CRITERIAS, &UPDATE_METHODS);
+        residuals_config.set_max_magnitudes(vec![None, Some(1e10), None]);
+        residuals_config.set_rel_safeguard_epsilons(vec![0.0, 1e-6, 1e-8]);
+        residuals_config.set_ignored_residuals(vec![2]);
+
+        let entries: Vec<ResidualEntry> = residuals_config.iter().collect();
+        assert_eq!(entries.len(), 3);
+        for (i, entry) in entries.iter().enumerate() {
+            assert_eq!(entry.get_stopping_criteria(), STOPPING_CRITERIAS[i]);
+            assert_eq!(entry.get_update_method(), UPDATE_METHODS[i]);
+        }
+        assert_eq!(entries[1].get_max_magnitude(), Some(1e10));
+        assert_eq!(entries[1].get_rel_safeguard_epsilon(), 1e-6);
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.get_ignored())
+                .collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+
+        assert_eq!((&residuals_config).into_iter().collect::<Vec<_>>(), entries);
+        let mut reversed: Vec<ResidualEntry> = residuals_config.iter().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, entries);
+        assert_eq!(residuals_config.iter().len(), 3);
+    }
+
+    #[test]
+    fn get_bounds() {
+        let residuals_config = ResidualsConfig::new(&STOPPING_CRITERIAS, &UPDATE_METHODS);
+        assert_eq!(
+            residuals_config
+                .get(2)
+                .map(|entry| entry.get_stopping_criteria()),
+            Some(NormalizationMethod::Adapt)
+        );
+        assert_eq!(residuals_config.get(3), None);
+        assert_eq!(ResidualsConfig::new(&[], &[]).iter().next(), None);
+    }
+
+    #[test]
+    fn equality() {
+        let residuals_config = ResidualsConfig::new(&STOPPING_CRITERIAS, &UPDATE_METHODS);
+        let stopping_criterias = STOPPING_CRITERIAS.to_vec();
+        let mut other = ResidualsConfig::new(&stopping_criterias, &UPDATE_METHODS);
+        assert_eq!(residuals_config, other);
+
+        other.set_ignored_residuals(vec![0]);
+        assert_ne!(residuals_config, other);
+        other.set_ignored_residuals(Vec::new());
+        assert_eq!(residuals_config, other);
+
+        let swapped = ResidualsConfig::new(&UPDATE_METHODS, &STOPPING_CRITERIAS);
+        assert_ne!(residuals_config, swapped);
     }
 }
@@ -0,0 +1,128 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+/// Aggregation of the stopping residuals of the members of a group into the error of the group
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum GroupAggregation {
+    /// Maximum of the stopping residuals
+    #[default]
+    Max,
+    /// Euclidean norm of the stopping residuals
+    L2,
+}
+
+impl fmt::Display for GroupAggregation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GroupAggregation::Max => write!(f, "Maximum"),
+            GroupAggregation::L2 => write!(f, "L2 norm"),
+        }
+    }
+}
+
+/// Named group of residuals, for example the components of a vector equation
+///
+/// The groups are set on the residuals configuration with `ResidualsConfig::set_groups()`.
+///
+/// A group can have its own tolerance, used instead of the solver one
+/// if the convergence is checked on the groups (see `ResidualsConfig::set_group_convergence()`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResidualsGroup {
+    name: String,
+    indices: Vec<usize>,
+    tolerance: Option<f64>,
+}
+
+impl ResidualsGroup {
+    pub fn new(name: &str, indices: Vec<usize>) -> Self {
+        if indices.is_empty() {
+            panic!("The group of residuals {} must not be empty", name);
+        }
+        ResidualsGroup {
+            name: name.to_string(),
+            indices,
+            tolerance: None,
+        }
+    }
+
+    /// Group of the contiguous residuals of the `range`
+    pub fn from_range(name: &str, range: Range<usize>) -> Self {
+        ResidualsGroup::new(name, range.collect())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn get_tolerance(&self) -> Option<f64> {
+        self.tolerance
+    }
+
+    pub fn set_tolerance(&mut self, tolerance: Option<f64>) {
+        if let Some(value) = tolerance {
+            if value <= 0.0 {
+                panic!(
+                    "The tolerance of the group of residuals {} must be strictly positive, provided value was {}",
+                    self.name, value
+                );
+            }
+        }
+        self.tolerance = tolerance;
+    }
+
+    /// Error of the group, computed from the stopping residuals of all the residuals
+    ///
+    /// As for the convergence check of the solver,
+    /// a non-finite stopping residual gives an infinite error
+    pub fn aggregate<D>(
+        &self,
+        stopping_residuals: &nalgebra::OVector<f64, D>,
+        aggregation: GroupAggregation,
+    ) -> f64
+    where
+        D: nalgebra::Dim,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let mut members = self.indices.iter().map(|&i| stopping_residuals[i]);
+        if !members.all(|value| value.is_finite()) {
+            return f64::INFINITY;
+        }
+
+        let members = self.indices.iter().map(|&i| stopping_residuals[i].abs());
+        match aggregation {
+            GroupAggregation::Max => members.fold(0.0, f64::max),
+            GroupAggregation::L2 => members.map(|value| value * value).sum::<f64>().sqrt(),
+        }
+    }
+
+    /// Indices of the group, the contiguous ones being written as a range, e.g. `0-2, 5`
+    pub(crate) fn format_indices(&self) -> String {
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        for &index in &self.indices {
+            match runs.last_mut() {
+                Some((_, end)) if *end + 1 == index => *end = index,
+                _ => runs.push((index, index)),
+            }
+        }
+        runs.iter()
+            .map(|&(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
@@ -7,22 +7,48 @@
 //! In addition to this two base struct, the following one are introduced:
 //! - `ResidualConfig` to make easier to create the `ResidualsConfig`from a slice of the ladder
 //! - `JacobianValues` to manipulate the jacobian outputs of a model when it is provided (non applicable for finite-differences)
+//! - `ResidualsGroup` to gather the residuals of a vector equation, see `ResidualsConfig::set_groups()`
+//! - `ResidualEntry` to inspect the configuration of each residual, see `ResidualsConfig::iter()`
+//! - `RightMemberOverride` to compute a right member from the iteratives instead of the model, see `RootFinder::set_right_overrides()`
 
 mod config;
+mod groups;
+mod overrides;
 mod values;
 pub use config::ResidualConfig;
 pub use config::ResidualsConfig;
+pub use config::DEFAULT_REL_SAFEGUARD_EPSILON;
+pub use config::{ResidualEntry, ResidualsIter};
+pub use groups::{GroupAggregation, ResidualsGroup};
+pub use overrides::RightMemberOverride;
+pub(crate) use overrides::RightOverrides;
 pub use values::JacobianValues;
 pub use values::ResidualsValues;
 
-use std::fmt;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
 
 /// Normalization method used by the `normalization` function.
+///
+/// The `RelToReference` variant carries the reference magnitude of the residual,
+/// and the `AdaptScaled` variant the magnitude at which the `Adapt` normalization
+/// transitions from absolute to relative, both must be strictly positive.
+/// The `RelFloored` variant carries the lower bound of the denominator of the `Rel` normalization,
+/// it must be positive, a floor of `0.0` giving the `Rel` normalization.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NormalizationMethod {
     Abs,
     Rel,
     Adapt,
+    RelToReference(f64),
+    AdaptScaled(f64),
+    RelFloored(f64),
 }
 
 /// Not used yet
@@ -35,9 +61,18 @@ pub enum StoppingCriteria {
 impl fmt::Display for NormalizationMethod {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let result = match self {
-            NormalizationMethod::Abs => &"Absolute Normalization",
-            NormalizationMethod::Rel => &"Relative Normalization",
-            NormalizationMethod::Adapt => &"Adaptative Normalization",
+            NormalizationMethod::Abs => "Absolute Normalization".to_string(),
+            NormalizationMethod::Rel => "Relative Normalization".to_string(),
+            NormalizationMethod::Adapt => "Adaptative Normalization".to_string(),
+            NormalizationMethod::RelToReference(reference) => {
+                format!("Relative Normalization to {:e}", reference)
+            }
+            NormalizationMethod::AdaptScaled(scale) => {
+                format!("Adaptative Normalization with scale {:e}", scale)
+            }
+            NormalizationMethod::RelFloored(floor) => {
+                format!("Relative Normalization with floor {:e}", floor)
+            }
         };
 
         write!(f, "{}", result)
@@ -49,16 +84,25 @@ impl fmt::Display for NormalizationMethod {
 /// - Abs (absolute) is the plain difference evaluation
 /// - Rel (relative) is the relative value evaluation
 /// - Adapt (adaptative) is designed to behave like Abs for near zero values and like Rel for big values
+/// - RelToReference (relative to a reference) is the relative value evaluation with respect to a magnitude known by the user
+/// - AdaptScaled (adaptative with a scale) is the Adapt method transitioning from Abs to Rel around a magnitude given by the user
+/// - RelFloored (relative with a floor) is the Rel method whose denominator is bounded below by a magnitude given by the user
 ///
 /// The formula are:
 /// - Abs: left - right
 /// - Rel: (left - right)/(abs(left+right)/2)
 /// - Adapt: (left - right)/(1+abs(left+right)/2)
+/// - RelToReference: (left - right)/reference
+/// - AdaptScaled: (left - right)/(scale+abs(left+right)/2)
+/// - RelFloored: (left - right)/max(abs(left+right)/2, floor)
+///
+/// Adapt is AdaptScaled with a scale of 1, hence only suited to residuals of order of magnitude 1.
 ///
 /// Default of each formula:
 /// - Abs: does not take into account the order of magnitude of the residuals
 /// - Rel: behave poorly if the residual is close to zero
 /// - Adapt: behave poorly if one member of the residual is close to zero and the other one is big, as the value will be close to either -2 or 2.
+/// - RelToReference: requires the order of magnitude of the residual to be known before the resolution
 ///
 /// # Examples
 /// ```
@@ -83,18 +127,36 @@ impl fmt::Display for NormalizationMethod {
 ///
 /// let big_values_adapt = normalization(101.1, 101.25, NormalizationMethod::Adapt);
 /// assert!(approx_eq!(f64, big_values_adapt, -0.0014680694886225172, ulps = 2));
+///
+/// let small_values_rel_to_reference = normalization(0.1, -0.15, NormalizationMethod::RelToReference(1e5));
+/// assert!(approx_eq!(f64, small_values_rel_to_reference, 2.5e-6, ulps = 2));
+///
+/// let big_values_adapt_scaled = normalization(1.011e7, 1.0125e7, NormalizationMethod::AdaptScaled(1e5));
+/// assert!(approx_eq!(f64, big_values_adapt_scaled, big_values_adapt, epsilon = 1e-15));
+///
+/// let small_values_rel_floored = normalization(0.1, -0.15, NormalizationMethod::RelFloored(1.0));
+/// assert!(approx_eq!(f64, small_values_rel_floored, 0.25, ulps = 2));
+///
+/// let big_values_rel_floored = normalization(101.1, 101.25, NormalizationMethod::RelFloored(1.0));
+/// assert!(approx_eq!(f64, big_values_rel_floored, big_values_rel, ulps = 2));
 /// ```
 pub fn normalization(x: f64, y: f64, normalization_method: NormalizationMethod) -> f64 {
     match normalization_method {
         NormalizationMethod::Abs => x - y,
         NormalizationMethod::Rel => (x - y) / ((x + y).abs() / 2.0),
         NormalizationMethod::Adapt => (x - y) / (1.0 + (x + y).abs() / 2.0),
+        NormalizationMethod::RelToReference(reference) => (x - y) / reference,
+        NormalizationMethod::AdaptScaled(scale) => (x - y) / (scale + (x + y).abs() / 2.0),
+        NormalizationMethod::RelFloored(floor) => (x - y) / ((x + y).abs() / 2.0).max(floor),
     }
 }
 
 /// Derivation of the normalization method
 ///
 /// This method is used when the jacobian is provided by the model and not calculated through finite-difference
+///
+/// For the `RelFloored` method, the denominator is constant where the floor is active,
+/// the derivative being then the one of `(left - right)/floor`
 pub fn deriv_normalization(
     x: f64,
     y: f64,
@@ -104,27 +166,40 @@ pub fn deriv_normalization(
 ) -> f64 {
     match normalization_method {
         NormalizationMethod::Abs => dx - dy,
-        NormalizationMethod::Rel => {
-            let diff = x - y;
-            let deriv_diff = dx - dy;
-            let sum = x + y;
-            let deriv_sum = dx + dy;
-
-            2.0 * ((deriv_diff) * sum.abs() - deriv_sum * diff * sum.signum()) / (sum.powi(2))
-        }
-        NormalizationMethod::Adapt => {
-            let diff = x - y;
-            let deriv_diff = dx - dy;
-            let avg = (x + y) / 2.0;
-            let deriv_avg = (dx + dy) / 2.0;
-            let denominator = 1.0 + avg.abs();
-            let deriv_denominator = deriv_avg * avg.signum();
-
-            (deriv_diff * denominator - deriv_denominator * diff) / (denominator.powi(2))
+        NormalizationMethod::Rel => deriv_rel_normalization(x, y, dx, dy),
+        NormalizationMethod::Adapt => deriv_adapt_normalization(x, y, dx, dy, 1.0),
+        NormalizationMethod::RelToReference(reference) => (dx - dy) / reference,
+        NormalizationMethod::AdaptScaled(scale) => deriv_adapt_normalization(x, y, dx, dy, scale),
+        NormalizationMethod::RelFloored(floor) => {
+            if (x + y).abs() / 2.0 < floor {
+                (dx - dy) / floor
+            } else {
+                deriv_rel_normalization(x, y, dx, dy)
+            }
         }
     }
 }
 
+fn deriv_rel_normalization(x: f64, y: f64, dx: f64, dy: f64) -> f64 {
+    let diff = x - y;
+    let deriv_diff = dx - dy;
+    let sum = x + y;
+    let deriv_sum = dx + dy;
+
+    2.0 * ((deriv_diff) * sum.abs() - deriv_sum * diff * sum.signum()) / (sum.powi(2))
+}
+
+fn deriv_adapt_normalization(x: f64, y: f64, dx: f64, dy: f64, scale: f64) -> f64 {
+    let diff = x - y;
+    let deriv_diff = dx - dy;
+    let avg = (x + y) / 2.0;
+    let deriv_avg = (dx + dy) / 2.0;
+    let denominator = scale + avg.abs();
+    let deriv_denominator = deriv_avg * avg.signum();
+
+    (deriv_diff * denominator - deriv_denominator * diff) / (denominator.powi(2))
+}
+
 /// Default method to construct a residual with Abs values
 ///
 /// # Examples
@@ -0,0 +1,80 @@
+use super::ResidualsValues;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Right member of a residual computed from the iteratives instead of the model,
+/// see `RootFinder::set_right_overrides()`
+pub type RightMemberOverride<D> = Box<dyn Fn(&nalgebra::OVector<f64, D>) -> f64>;
+
+/// Right members of the residuals replaced by functions of the iteratives
+///
+/// The residuals without override keep the right member given by the model.
+pub(crate) struct RightOverrides<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    overrides: Vec<Option<RightMemberOverride<D>>>,
+}
+
+impl<D> Default for RightOverrides<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    fn default() -> Self {
+        RightOverrides {
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl<D> RightOverrides<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    pub(crate) fn new(overrides: Vec<Option<RightMemberOverride<D>>>) -> Self {
+        RightOverrides { overrides }
+    }
+
+    /// No right member is overridden
+    pub(crate) fn is_empty(&self) -> bool {
+        self.overrides.iter().all(Option::is_none)
+    }
+
+    /// Indices of the residuals whose right member is overridden
+    pub(crate) fn get_overridden_residuals(&self) -> Vec<usize> {
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter_map(|(i, right_override)| right_override.as_ref().map(|_| i))
+            .collect()
+    }
+
+    /// Replace the right members of the `values` by the overrides evaluated at the `iteratives`
+    pub(crate) fn apply(
+        &self,
+        iteratives: &nalgebra::OVector<f64, D>,
+        values: &mut ResidualsValues<D>,
+    ) {
+        for (i, right_override) in self.overrides.iter().enumerate() {
+            if let Some(right_override) = right_override {
+                values.set_right(i, right_override(iteratives));
+            }
+        }
+    }
+}
+
+impl<D> fmt::Debug for RightOverrides<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RightOverrides")
+            .field("overridden_residuals", &self.get_overridden_residuals())
+            .finish()
+    }
+}
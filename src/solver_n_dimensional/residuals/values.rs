@@ -1,5 +1,8 @@
 use super::{deriv_normalization, NormalizationMethod};
-use std::fmt;
+use crate::errors::JacobianDimensionError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 /// Residuals values outputs of the model
 ///
@@ -10,7 +13,7 @@ use std::fmt;
 /// - the right members of the equations
 ///
 /// Once converged, one should have left = right (with a tolerance)
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResidualsValues<D>
 where
     D: nalgebra::Dim,
@@ -44,14 +47,87 @@ where
         }
     }
 
+    /// Update the values from the left and right members of the equations
+    ///
+    /// The storage is reused if the dimension is unchanged:
+    /// it allows a model to provide its residuals without allocating,
+    /// see [crate::model::Model::write_residuals]
+    pub fn update_from(
+        &mut self,
+        left: &nalgebra::OVector<f64, D>,
+        right: &nalgebra::OVector<f64, D>,
+    ) {
+        if left.len() != right.len() {
+            panic!(
+                "Dimension mismatch in the residuals values {} != {} ",
+                left.len(),
+                right.len()
+            );
+        }
+
+        if left.len() == self.problem_size {
+            self.left.copy_from(left);
+            self.right.copy_from(right);
+        } else {
+            self.left = left.clone();
+            self.right = right.clone();
+            self.problem_size = left.len();
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.problem_size
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.problem_size == 0
+    }
+
+    /// Left and right members of the residual `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range, see `get_left()` and `get_right()` for the checked accessors
     pub fn get_values(&self, index: usize) -> (f64, f64) {
+        if index >= self.problem_size {
+            panic!(
+                "The residual {} is out of range, the number of residuals is {}",
+                index, self.problem_size
+            );
+        }
         (self.left[index], self.right[index])
     }
 
+    /// Left member of the residual `index`, `None` if out of range
+    pub fn get_left(&self, index: usize) -> Option<f64> {
+        self.left.get(index).copied()
+    }
+
+    /// Right member of the residual `index`, `None` if out of range
+    pub fn get_right(&self, index: usize) -> Option<f64> {
+        self.right.get(index).copied()
+    }
+
+    /// Replace the right member of the residual `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range
+    pub fn set_right(&mut self, index: usize, value: f64) {
+        if index >= self.problem_size {
+            panic!(
+                "The residual {} is out of range, the number of residuals is {}",
+                index, self.problem_size
+            );
+        }
+        self.right[index] = value;
+    }
+
+    /// Iterator over the `(left, right)` members of the residuals
+    pub fn iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.left.iter().copied().zip(self.right.iter().copied())
+    }
+
     pub fn shape(&self) -> usize {
         let (nrows, _ncols) = self.left.shape();
         nrows
@@ -122,11 +198,42 @@ where
         }
     }
 
+    /// Normalized jacobian, computed with the residuals values and the normalization method of each residual
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions do not match, see `normalize_checked()`
     pub fn normalize(
         &self,
         res_values: &ResidualsValues<D>,
         norm_methods: &[NormalizationMethod],
     ) -> nalgebra::OMatrix<f64, D, D> {
+        match self.normalize_checked(res_values, norm_methods) {
+            Ok(jac) => jac,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Normalized jacobian, the dimensions of the residuals values and of the normalization methods
+    /// being checked against the size of the jacobian
+    pub fn normalize_checked(
+        &self,
+        res_values: &ResidualsValues<D>,
+        norm_methods: &[NormalizationMethod],
+    ) -> Result<nalgebra::OMatrix<f64, D, D>, JacobianDimensionError> {
+        if res_values.len() != self.problem_size {
+            return Err(JacobianDimensionError::ResidualsValues {
+                jacobian: self.problem_size,
+                residuals: res_values.len(),
+            });
+        }
+        if norm_methods.len() != self.problem_size {
+            return Err(JacobianDimensionError::NormalizationMethods {
+                jacobian: self.problem_size,
+                normalization_methods: norm_methods.len(),
+            });
+        }
+
         let mut jac: nalgebra::OMatrix<f64, D, D> =
             super::super::omatrix_zeros_from_shape(res_values.shape_generic());
 
@@ -144,12 +251,30 @@ where
                 );
             }
         }
-        jac
+        Ok(jac)
+    }
+
+    pub fn len(&self) -> usize {
+        self.problem_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.problem_size == 0
     }
 
     pub fn get_jacobians(&self) -> (&nalgebra::OMatrix<f64, D, D>, &nalgebra::OMatrix<f64, D, D>) {
         (&self.left, &self.right)
     }
+
+    /// Jacobian of the left members of the equations
+    pub fn get_left_matrix(&self) -> &nalgebra::OMatrix<f64, D, D> {
+        &self.left
+    }
+
+    /// Jacobian of the right members of the equations
+    pub fn get_right_matrix(&self) -> &nalgebra::OMatrix<f64, D, D> {
+        &self.right
+    }
 }
 
 impl<D> fmt::Display for ResidualsValues<D>
@@ -168,3 +293,104 @@ where
         write!(f, "{}", result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn residuals_values() -> ResidualsValues<nalgebra::Dyn> {
+        ResidualsValues::new(
+            nalgebra::DVector::from_vec(vec![1.0, 2.0]),
+            nalgebra::DVector::from_vec(vec![3.0, 4.0]),
+        )
+    }
+
+    fn jacobian_values(size: usize) -> JacobianValues<nalgebra::Dyn> {
+        JacobianValues::new(
+            nalgebra::DMatrix::identity(size, size),
+            nalgebra::DMatrix::zeros(size, size),
+        )
+    }
+
+    #[test]
+    fn residuals_accessors() {
+        let values = residuals_values();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.get_left(1), Some(2.0));
+        assert_eq!(values.get_right(1), Some(4.0));
+        assert_eq!(values.get_left(2), None);
+        assert_eq!(values.get_right(2), None);
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            vec![(1.0, 3.0), (2.0, 4.0)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The residual 2 is out of range, the number of residuals is 2")]
+    fn residuals_values_out_of_range() {
+        residuals_values().get_values(2);
+    }
+
+    #[test]
+    fn jacobian_accessors() {
+        let jacobian = jacobian_values(2);
+        assert_eq!(jacobian.len(), 2);
+        assert_eq!(
+            jacobian.get_left_matrix(),
+            &nalgebra::DMatrix::<f64>::identity(2, 2)
+        );
+        assert_eq!(
+            jacobian.get_right_matrix(),
+            &nalgebra::DMatrix::<f64>::zeros(2, 2)
+        );
+    }
+
+    #[test]
+    fn normalize_checked() {
+        let norm_methods = [NormalizationMethod::Abs; 2];
+        assert_eq!(
+            jacobian_values(2).normalize_checked(&residuals_values(), &norm_methods),
+            Ok(nalgebra::DMatrix::identity(2, 2))
+        );
+    }
+
+    #[test]
+    fn normalize_checked_residuals_mismatch() {
+        let norm_methods = [NormalizationMethod::Abs; 3];
+        assert_eq!(
+            jacobian_values(3).normalize_checked(&residuals_values(), &norm_methods),
+            Err(JacobianDimensionError::ResidualsValues {
+                jacobian: 3,
+                residuals: 2
+            })
+        );
+    }
+
+    #[test]
+    fn normalize_checked_normalization_methods_mismatch() {
+        let norm_methods = [NormalizationMethod::Abs; 1];
+        let error = jacobian_values(2)
+            .normalize_checked(&residuals_values(), &norm_methods)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            JacobianDimensionError::NormalizationMethods {
+                jacobian: 2,
+                normalization_methods: 1
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "The jacobian has 2 rows but 1 normalization methods are provided"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The jacobian has 3 rows but 2 residuals values are provided")]
+    fn normalize_mismatch() {
+        jacobian_values(3).normalize(&residuals_values(), &[NormalizationMethod::Abs; 3]);
+    }
+}
@@ -0,0 +1,364 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+use crate::errors::SolverError;
+use crate::iteratives;
+use crate::iteratives::Iterative;
+use crate::model::Model;
+use crate::residuals;
+
+use super::{ConvergenceCriterion, ConvergenceRate, JacobianSource, RootFinder, SolverParameters};
+
+/// Outcome of a successful resolution, see [RootFinder::solve_with_report] and [solve_batch]
+///
+/// Along with the statistics of the resolution, it keeps the state of the model at the solution:
+/// its iteratives, its residuals and their stopping errors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverReport<D = nalgebra::Dyn>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    iter: usize,
+    model_evaluations: usize,
+    last_satisfied_criterion: Option<ConvergenceCriterion>,
+    active_bound_variables: Vec<usize>,
+    jacobian_source_counts: Vec<(JacobianSource, usize)>,
+    initial_max_error: f64,
+    final_max_error: f64,
+    convergence_rate: Option<ConvergenceRate>,
+    suspicious_jacobian: bool,
+    final_iteratives: nalgebra::OVector<f64, D>,
+    final_residuals: residuals::ResidualsValues<D>,
+    final_errors: nalgebra::OVector<f64, D>,
+    errors_over_tolerance: nalgebra::OVector<f64, D>,
+}
+
+impl<D> SolverReport<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    /// Report of the last resolution of `rf`, which must have succeeded, the model being at the solution
+    pub(super) fn new<T>(
+        rf: &RootFinder<T, D>,
+        final_iteratives: nalgebra::OVector<f64, D>,
+        final_residuals: residuals::ResidualsValues<D>,
+        final_errors: nalgebra::OVector<f64, D>,
+    ) -> Self
+    where
+        T: Iterative + fmt::Display + fmt::Debug,
+    {
+        SolverReport {
+            iter: rf.get_iter(),
+            model_evaluations: rf.get_model_evaluations(),
+            last_satisfied_criterion: rf.get_last_satisfied_criterion(),
+            active_bound_variables: rf.get_active_bound_variables().to_vec(),
+            jacobian_source_counts: rf.get_jacobian_source_counts(),
+            initial_max_error: rf.get_initial_max_error().unwrap(),
+            final_max_error: rf.get_last_max_error().unwrap(),
+            convergence_rate: rf.get_convergence_rate(),
+            suspicious_jacobian: rf.get_suspicious_jacobian(),
+            final_iteratives,
+            final_residuals,
+            final_errors,
+            errors_over_tolerance: rf.get_errors_over_tolerance().unwrap().clone(),
+        }
+    }
+
+    /// See [RootFinder::get_iter]
+    pub fn get_iter(&self) -> usize {
+        self.iter
+    }
+
+    /// See [RootFinder::get_model_evaluations]
+    pub fn get_model_evaluations(&self) -> usize {
+        self.model_evaluations
+    }
+
+    /// See [RootFinder::get_last_satisfied_criterion]
+    pub fn get_last_satisfied_criterion(&self) -> Option<ConvergenceCriterion> {
+        self.last_satisfied_criterion
+    }
+
+    /// See [RootFinder::get_active_bound_variables]
+    pub fn get_active_bound_variables(&self) -> &[usize] {
+        &self.active_bound_variables
+    }
+
+    /// See [RootFinder::get_jacobian_source_counts]
+    pub fn get_jacobian_source_counts(&self) -> &[(JacobianSource, usize)] {
+        &self.jacobian_source_counts
+    }
+
+    /// See [RootFinder::get_initial_max_error]
+    pub fn get_initial_max_error(&self) -> f64 {
+        self.initial_max_error
+    }
+
+    /// See [RootFinder::get_last_max_error]
+    pub fn get_final_max_error(&self) -> f64 {
+        self.final_max_error
+    }
+
+    /// Reduction of the maximum error during the resolution, in orders of magnitude
+    ///
+    /// It is `log10(initial_max_error / final_max_error)`,
+    /// zero if the initial guess had no error and infinite if the final error is zero.
+    pub fn get_orders_of_magnitude_gained(&self) -> f64 {
+        if self.initial_max_error == 0.0 {
+            0.0
+        } else {
+            (self.initial_max_error / self.final_max_error).log10()
+        }
+    }
+
+    /// See [RootFinder::get_convergence_rate]
+    pub fn get_convergence_rate(&self) -> Option<ConvergenceRate> {
+        self.convergence_rate
+    }
+
+    /// See [RootFinder::get_suspicious_jacobian]
+    pub fn get_suspicious_jacobian(&self) -> bool {
+        self.suspicious_jacobian
+    }
+
+    /// Iteratives of the model at the solution
+    pub fn get_final_iteratives(&self) -> &nalgebra::OVector<f64, D> {
+        &self.final_iteratives
+    }
+
+    /// Left and right members of the residuals at the solution, before normalization
+    ///
+    /// The right members overridden with [RootFinder::set_right_overrides] are the overridden values.
+    pub fn get_final_residuals(&self) -> &residuals::ResidualsValues<D> {
+        &self.final_residuals
+    }
+
+    /// Normalized stopping errors at the solution, see [crate::residuals::ResidualsConfig::evaluate_stopping_residuals]
+    pub fn get_final_errors(&self) -> &nalgebra::OVector<f64, D> {
+        &self.final_errors
+    }
+
+    /// See [RootFinder::get_errors_over_tolerance]
+    pub fn get_errors_over_tolerance(&self) -> &nalgebra::OVector<f64, D> {
+        &self.errors_over_tolerance
+    }
+
+    /// See [RootFinder::get_residuals_above_tolerance]
+    pub fn get_residuals_above_tolerance(&self) -> usize {
+        count_above_tolerance(&self.errors_over_tolerance)
+    }
+
+    /// See [RootFinder::get_worst_residual]
+    pub fn get_worst_residual(&self) -> Option<(usize, f64)> {
+        worst_residual(&self.errors_over_tolerance)
+    }
+}
+
+/// Number of errors expressed in units of tolerance above `1.0`, a `NaN` error being above
+pub(super) fn count_above_tolerance<D>(errors_over_tolerance: &nalgebra::OVector<f64, D>) -> usize
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    errors_over_tolerance
+        .iter()
+        .filter(|error| error.is_nan() || **error > 1.0)
+        .count()
+}
+
+/// Index and value of the largest error expressed in units of tolerance, a `NaN` error being the largest
+pub(super) fn worst_residual<D>(
+    errors_over_tolerance: &nalgebra::OVector<f64, D>,
+) -> Option<(usize, f64)>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    let sort_key = |error: f64| if error.is_nan() { f64::INFINITY } else { error };
+    errors_over_tolerance
+        .iter()
+        .copied()
+        .enumerate()
+        .fold(None, |worst, (i, error)| match worst {
+            Some((_, worst_error)) if sort_key(error) <= sort_key(worst_error) => worst,
+            _ => Some((i, error)),
+        })
+}
+
+/// Solve several models sharing the same configuration
+///
+/// The model `models[i]` is solved from the initial guess `initial_guesses[i]`,
+/// the result of each resolution being returned in the same order.
+///
+/// A single [RootFinder] is created and reused for all the models:
+/// its state is reset at the beginning of each resolution,
+/// while the storage of the jacobian and of the other placeholders is kept.
+/// Hence, the results are the same as the ones of a loop creating a solver for each model,
+/// the failure of a resolution having no effect on the following ones.
+///
+/// With the `rayon` feature, the models can be solved in parallel with [solve_batch_parallel]
+///
+/// # Panics
+///
+/// If the number of initial guesses differs from the number of models,
+/// or if the dimensions of the configuration are not consistent, as with [RootFinder::new]
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::iteratives;
+/// use nrf::residuals;
+/// use nrf::solver::{MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+///
+/// fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 2.0)
+/// }
+///
+/// fn square3(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 3.0)
+/// }
+///
+/// let problem_size = 1;
+/// let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+/// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+/// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+/// let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+/// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+/// let parameters =
+///     SolverParameters::new(ProblemSize::new(problem_size), 1e-6, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+///
+/// let initial_guesses = vec![nalgebra::DVector::from_vec(vec![1.0]); 2];
+/// let mut models = vec![
+///     nrf::model::UserModelFromFunction::new(problem_size, square2),
+///     nrf::model::UserModelFromFunction::new(problem_size, square3),
+/// ];
+///
+/// let results = nrf::solver::solve_batch(
+///     parameters,
+///     &iter_params,
+///     &res_config,
+///     &initial_guesses,
+///     &mut models,
+/// );
+/// assert!(results.iter().all(|result| result.is_ok()));
+/// ```
+pub fn solve_batch<T, D, M>(
+    parameters: SolverParameters,
+    iteratives: &iteratives::Iteratives<T>,
+    residuals_config: &residuals::ResidualsConfig,
+    initial_guesses: &[nalgebra::OVector<f64, D>],
+    models: &mut [M],
+) -> Vec<Result<SolverReport<D>, SolverError<M, D>>>
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+    M: Model<D>,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    check_batch_dimensions(initial_guesses.len(), models.len());
+    let Some(first_guess) = initial_guesses.first() else {
+        return Vec::new();
+    };
+
+    let mut rf = RootFinder::new(
+        parameters,
+        first_guess.clone(),
+        iteratives,
+        residuals_config,
+    );
+
+    initial_guesses
+        .iter()
+        .zip(models.iter_mut())
+        .map(|(initial_guess, model)| solve_item(&mut rf, initial_guess, model))
+        .collect()
+}
+
+/// Parallel version of [solve_batch], available with the `rayon` feature
+///
+/// The models are split between the threads of the rayon thread pool,
+/// each piece of work creating its own [RootFinder], which is reused for all its models.
+/// The results are the same as the ones of [solve_batch], in the same order:
+/// the result of each model is at the index of its initial guess, whatever the order in which the resolutions finish.
+#[cfg(feature = "rayon")]
+pub fn solve_batch_parallel<T, D, M>(
+    parameters: SolverParameters,
+    iteratives: &iteratives::Iteratives<T>,
+    residuals_config: &residuals::ResidualsConfig,
+    initial_guesses: &[nalgebra::OVector<f64, D>],
+    models: &mut [M],
+) -> Vec<Result<SolverReport<D>, SolverError<M, D>>>
+where
+    T: Iterative + fmt::Display + fmt::Debug + Sync,
+    M: Model<D> + Send,
+    SolverError<M, D>: Send,
+    SolverReport<D>: Send,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::OVector<f64, D>: Sync,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    use rayon::prelude::*;
+
+    check_batch_dimensions(initial_guesses.len(), models.len());
+    let Some(first_guess) = initial_guesses.first() else {
+        return Vec::new();
+    };
+
+    initial_guesses
+        .par_iter()
+        .zip(models.par_iter_mut())
+        .map_init(
+            || {
+                RootFinder::new(
+                    parameters.clone(),
+                    first_guess.clone(),
+                    iteratives,
+                    residuals_config,
+                )
+            },
+            |rf, (initial_guess, model)| solve_item(rf, initial_guess, model),
+        )
+        .collect()
+}
+
+fn check_batch_dimensions(initial_guesses_number: usize, models_number: usize) {
+    if initial_guesses_number != models_number {
+        panic!(
+            "Dimension mismatch :\n initial_guesses.len() = {} and models.len() = {}",
+            initial_guesses_number, models_number
+        );
+    }
+}
+
+fn solve_item<T, D, M>(
+    rf: &mut RootFinder<T, D>,
+    initial_guess: &nalgebra::OVector<f64, D>,
+    model: &mut M,
+) -> Result<SolverReport<D>, SolverError<M, D>>
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+    M: Model<D>,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    rf.set_initial_guess(initial_guess);
+    rf.solve_with_report(model)
+}
@@ -0,0 +1,284 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{
+    LinearSolver, QuasiNewtonMethod, ResolutionMethod, SolverParameters, UpdateQuasiNewtonMethod,
+};
+use crate::iteratives::{Iterative, Iteratives};
+
+/// Use of the finite difference parameters of the iteratives by a resolution method
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FiniteDifferenceUse {
+    /// The derivatives are always computed with finite differences, even if the model provides its jacobian
+    Always,
+    /// The finite differences are only used if the model does not provide its jacobian
+    WithoutProvidedJacobian,
+}
+
+/// What a resolution method needs from the solver and from the model, see [ResolutionMethod::requirements]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MethodRequirements {
+    pub(crate) jacobian_storage: bool,
+    pub(crate) inverse_storage: bool,
+    pub(crate) linear_solver: bool,
+    pub(crate) finite_differences: FiniteDifferenceUse,
+}
+
+impl MethodRequirements {
+    /// The jacobian is computed and stored, at least at the first iteration
+    pub fn needs_jacobian_storage(&self) -> bool {
+        self.jacobian_storage
+    }
+
+    /// The method works with the inverse of the jacobian, whatever the linear solver
+    pub fn needs_inverse_storage(&self) -> bool {
+        self.inverse_storage
+    }
+
+    /// A linear system is solved at each iteration, see [LinearSolver]
+    pub fn uses_linear_solver(&self) -> bool {
+        self.linear_solver
+    }
+
+    pub fn get_finite_differences(&self) -> FiniteDifferenceUse {
+        self.finite_differences
+    }
+
+    /// The linear solver can be used with the method
+    ///
+    /// GMRES never computes the inverse of the jacobian,
+    /// the methods requiring it are not compatible with it.
+    pub fn supports_linear_solver(&self, linear_solver: LinearSolver) -> bool {
+        match linear_solver {
+            LinearSolver::DirectInverse => true,
+            LinearSolver::Gmres { .. } => !self.inverse_storage,
+        }
+    }
+
+    /// The finite difference parameters of the iteratives are used,
+    /// depending on the model providing its jacobian or not
+    pub fn benefits_from_fd_params(&self, jacobian_provided: bool) -> bool {
+        match self.finite_differences {
+            FiniteDifferenceUse::Always => true,
+            FiniteDifferenceUse::WithoutProvidedJacobian => !jacobian_provided,
+        }
+    }
+}
+
+/// Kind of the iteratives of a configuration, see [check_compatibility]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IterativesKind {
+    /// Iteratives providing finite difference parameters, such as `IterativeParamsFD`
+    WithFiniteDifference,
+    /// Iteratives without finite difference parameters, such as `IterativeParams`
+    WithoutFiniteDifference,
+}
+
+impl<'a, T: Iterative> From<&Iteratives<'a, T>> for IterativesKind {
+    fn from(iteratives: &Iteratives<'a, T>) -> Self {
+        if iteratives.with_finite_diff() {
+            IterativesKind::WithFiniteDifference
+        } else {
+            IterativesKind::WithoutFiniteDifference
+        }
+    }
+}
+
+/// Issue of a configuration found by [check_compatibility]
+///
+/// Some of them make `RootFinder::solve()` panic, see [CompatibilityWarning::prevents_resolution],
+/// the others are noted in the debug log.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompatibilityWarning {
+    /// The model does not provide its jacobian and the iteratives cannot compute it with finite differences
+    NoJacobianComputation,
+    /// The nonlinear Gauss-Seidel method requires iteratives with finite difference parameters
+    GaussSeidelWithoutFiniteDifference,
+    /// The model provides its jacobian, the finite difference parameters of the iteratives are not used
+    UnusedFiniteDifferenceParameters,
+    /// The resolution method does not perform Newton warmup iterations
+    UnusedNewtonWarmup(ResolutionMethod),
+    /// The Aitken acceleration only applies to the stationary Newton method
+    UnusedAitkenAcceleration(ResolutionMethod),
+    /// The resolution method does not solve linear systems
+    UnusedLinearSolver(LinearSolver),
+    /// The Schubert method requires a sparsity pattern, see `SolverParameters::set_sparsity_pattern()`
+    SchubertWithoutSparsityPattern,
+    /// The Schubert method only applies to the jacobian, not to its inverse
+    InverseSchubertUpdate,
+    /// The sparsity pattern is only used by the Schubert method
+    UnusedSparsityPattern(ResolutionMethod),
+    /// The scalar safeguard only applies to the problems of size 1, see `SolverParameters::set_scalar_safeguard()`
+    UnusedScalarSafeguard(usize),
+}
+
+impl CompatibilityWarning {
+    /// The resolution cannot be performed with this configuration
+    pub fn prevents_resolution(&self) -> bool {
+        matches!(
+            self,
+            CompatibilityWarning::NoJacobianComputation
+                | CompatibilityWarning::GaussSeidelWithoutFiniteDifference
+                | CompatibilityWarning::SchubertWithoutSparsityPattern
+                | CompatibilityWarning::InverseSchubertUpdate
+        )
+    }
+}
+
+impl fmt::Display for CompatibilityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompatibilityWarning::NoJacobianComputation => write!(
+                f,
+                "The model does not provide its jacobian and the iteratives are not working with finite differences: use iteratives such as `IterativeParamsFD` to compute the jacobian with finite differences"
+            ),
+            CompatibilityWarning::GaussSeidelWithoutFiniteDifference => write!(
+                f,
+                "The nonlinear Gauss-Seidel method computes the derivatives with finite differences: use iteratives such as `IterativeParamsFD`"
+            ),
+            CompatibilityWarning::UnusedFiniteDifferenceParameters => write!(
+                f,
+                "The jacobian is provided by the model, the finite difference parameters of the iteratives are not used"
+            ),
+            CompatibilityWarning::UnusedNewtonWarmup(resolution_method) => write!(
+                f,
+                "The Newton warmup iterations have no effect with the {} method",
+                resolution_method
+            ),
+            CompatibilityWarning::UnusedAitkenAcceleration(resolution_method) => write!(
+                f,
+                "The Aitken acceleration has no effect with the {} method, only with the stationary Newton method",
+                resolution_method
+            ),
+            CompatibilityWarning::UnusedLinearSolver(linear_solver) => write!(
+                f,
+                "The resolution method does not solve linear systems, the linear solver {} is not used",
+                linear_solver
+            ),
+            CompatibilityWarning::SchubertWithoutSparsityPattern => write!(
+                f,
+                "The Schubert method preserves the sparsity of the jacobian: declare its sparsity pattern with `SolverParameters::set_sparsity_pattern()`"
+            ),
+            CompatibilityWarning::InverseSchubertUpdate => write!(
+                f,
+                "The Schubert method preserves the sparsity of the jacobian, it cannot be applied to its inverse: use `QuasiNewtonMethod::JacobianUpdate`"
+            ),
+            CompatibilityWarning::UnusedSparsityPattern(resolution_method) => write!(
+                f,
+                "The sparsity pattern has no effect with the {} method, only with the Schubert method",
+                resolution_method
+            ),
+            CompatibilityWarning::UnusedScalarSafeguard(problem_size) => write!(
+                f,
+                "The scalar safeguard only applies to the problems of size 1, it is ignored for the problem of size {}",
+                problem_size
+            ),
+        }
+    }
+}
+
+/// Check if the parameters make sense for a model and its iteratives, before running the resolution
+///
+/// `jacobian_provided` is the value of `Model::jacobian_provided()` for the model to solve.
+/// The combinations rejected by the parameters themselves,
+/// such as a linear solver not supported by the resolution method, are not reported.
+///
+/// `RootFinder::solve()` performs this check, panicking on the warnings preventing the resolution.
+/// `RootFinder::try_new()` already rejects the warnings preventing the resolution whatever the model,
+/// with `RootFinderConstructionError::IncompatibleConfiguration`.
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::{
+///     check_compatibility, CompatibilityWarning, IterativesKind, MaxIterations, ProblemSize,
+///     ResolutionMethod, SolverParameters,
+/// };
+///
+/// let mut parameters =
+///     SolverParameters::new(ProblemSize::new(2), 1e-6, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+/// assert_eq!(
+///     check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+///     Ok(())
+/// );
+///
+/// parameters.set_newton_warmup_iterations(2);
+/// assert_eq!(
+///     check_compatibility(&parameters, true, IterativesKind::WithFiniteDifference),
+///     Err(vec![
+///         CompatibilityWarning::UnusedFiniteDifferenceParameters,
+///         CompatibilityWarning::UnusedNewtonWarmup(ResolutionMethod::NewtonRaphson),
+///     ])
+/// );
+/// ```
+pub fn check_compatibility(
+    parameters: &SolverParameters,
+    jacobian_provided: bool,
+    iteratives_kind: IterativesKind,
+) -> Result<(), Vec<CompatibilityWarning>> {
+    let resolution_method = parameters.get_resolution_method();
+    let requirements = resolution_method.requirements();
+    let finite_difference = iteratives_kind == IterativesKind::WithFiniteDifference;
+    let mut warnings = Vec::new();
+
+    if !jacobian_provided && !finite_difference {
+        warnings.push(CompatibilityWarning::NoJacobianComputation);
+    }
+    if requirements.get_finite_differences() == FiniteDifferenceUse::Always && !finite_difference {
+        warnings.push(CompatibilityWarning::GaussSeidelWithoutFiniteDifference);
+    }
+    if finite_difference && !requirements.benefits_from_fd_params(jacobian_provided) {
+        warnings.push(CompatibilityWarning::UnusedFiniteDifferenceParameters);
+    }
+    if parameters.get_newton_warmup_iterations() > 0
+        && !matches!(resolution_method, ResolutionMethod::QuasiNewton(_))
+    {
+        warnings.push(CompatibilityWarning::UnusedNewtonWarmup(resolution_method));
+    }
+    if parameters.get_aitken_acceleration().is_some()
+        && resolution_method != ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+    {
+        warnings.push(CompatibilityWarning::UnusedAitkenAcceleration(
+            resolution_method,
+        ));
+    }
+    let schubert_update = |method| method == UpdateQuasiNewtonMethod::SchubertSparse;
+    match resolution_method {
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(method))
+            if schubert_update(method) =>
+        {
+            if parameters.get_sparsity_pattern().is_none() {
+                warnings.push(CompatibilityWarning::SchubertWithoutSparsityPattern);
+            }
+        }
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(method))
+            if schubert_update(method) =>
+        {
+            warnings.push(CompatibilityWarning::InverseSchubertUpdate);
+        }
+        _ => {
+            if parameters.get_sparsity_pattern().is_some() {
+                warnings.push(CompatibilityWarning::UnusedSparsityPattern(
+                    resolution_method,
+                ));
+            }
+        }
+    }
+    let linear_solver = parameters.get_linear_solver();
+    if !requirements.uses_linear_solver() && linear_solver != LinearSolver::default() {
+        warnings.push(CompatibilityWarning::UnusedLinearSolver(linear_solver));
+    }
+    match parameters.get_problem_size() {
+        Some(problem_size) if parameters.get_scalar_safeguard() && problem_size > 1 => {
+            warnings.push(CompatibilityWarning::UnusedScalarSafeguard(problem_size));
+        }
+        _ => (),
+    }
+
+    if warnings.is_empty() {
+        Ok(())
+    } else {
+        Err(warnings)
+    }
+}
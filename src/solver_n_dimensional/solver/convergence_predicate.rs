@@ -0,0 +1,81 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+/// Combination of the custom convergence predicate with the tolerance check of the residuals,
+/// see [super::RootFinder::set_convergence_predicate]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum PredicateMode {
+    /// The predicate replaces the tolerance check
+    #[default]
+    Replace,
+    /// The residuals must be below the tolerance and the predicate satisfied
+    And,
+    /// Either the residuals are below the tolerance or the predicate is satisfied
+    Or,
+}
+
+impl fmt::Display for PredicateMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PredicateMode::Replace => write!(f, "replacing the tolerance check"),
+            PredicateMode::And => write!(f, "required with the tolerance check"),
+            PredicateMode::Or => write!(f, "alternative to the tolerance check"),
+        }
+    }
+}
+
+/// Custom convergence predicate, see [super::RootFinder::set_convergence_predicate]
+pub type ConvergencePredicate<'a, D> = Box<dyn Fn(&ConvergenceContext<D>) -> bool + 'a>;
+
+/// State of the resolution given to a custom convergence predicate
+///
+/// The predicate is called after the evaluation of the initial guess and after each iteration,
+/// with shared references only: it cannot modify the state of the solver.
+pub struct ConvergenceContext<'c, D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    pub(super) iter: usize,
+    pub(super) stopping_errors: &'c nalgebra::OVector<f64, D>,
+    pub(super) last_step: Option<&'c nalgebra::OVector<f64, D>>,
+    pub(super) iteratives: &'c nalgebra::OVector<f64, D>,
+    pub(super) model_evaluations: usize,
+    pub(super) tolerance: f64,
+}
+
+impl<'c, D> ConvergenceContext<'c, D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    /// Number of iterations performed, `0` at the initial guess
+    pub fn get_iter(&self) -> usize {
+        self.iter
+    }
+
+    /// Stopping errors of the current iterate, i.e. the residuals normalized with the stopping criteria
+    pub fn get_stopping_errors(&self) -> &nalgebra::OVector<f64, D> {
+        self.stopping_errors
+    }
+
+    /// Step of the iteratives of the last iteration, `None` at the initial guess
+    pub fn get_last_step(&self) -> Option<&nalgebra::OVector<f64, D>> {
+        self.last_step
+    }
+
+    /// Iteratives of the current iterate
+    pub fn get_iteratives(&self) -> &nalgebra::OVector<f64, D> {
+        self.iteratives
+    }
+
+    /// Number of model evaluations performed since the start of the resolution
+    pub fn get_model_evaluations(&self) -> usize {
+        self.model_evaluations
+    }
+
+    /// Tolerance of the solver
+    pub fn get_tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
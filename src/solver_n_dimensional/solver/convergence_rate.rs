@@ -0,0 +1,110 @@
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+/// Minimum number of iterations to estimate the convergence rate, see [ConvergenceRate]
+pub const MIN_ITERATIONS_CONVERGENCE_RATE: usize = 4;
+
+/// Estimated order below which the convergence of the Newton-Raphson method with an analytical jacobian is suspicious,
+/// see [crate::solver::RootFinder::get_suspicious_jacobian]
+pub const SUSPICIOUS_NEWTON_ORDER: f64 = 1.5;
+
+/// Convergence order and asymptotic rate observed on the maximum errors of the last iterations
+///
+/// With the maximum errors `e(k)` of the last three iterations,
+/// the order is `p = ln(e(k) / e(k-1)) / ln(e(k-1) / e(k-2))`
+/// and the asymptotic rate is `e(k) / e(k-1)^p`, such that `e(k) = rate * e(k-1)^p`.
+///
+/// The estimation requires at least [MIN_ITERATIONS_CONVERGENCE_RATE] iterations,
+/// and the last three errors to be strictly decreasing and not null.
+/// The order is expected to be close to 2 for the Newton-Raphson method,
+/// between 1 and 2 for the quasi-Newton updates and close to 1 for the stationary Newton method.
+///
+/// It is only an estimation: the errors of the last iterations can be polluted by the noise of the model,
+/// or the resolution can stop before reaching the asymptotic regime.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ConvergenceRate {
+    order: f64,
+    rate: f64,
+}
+
+impl ConvergenceRate {
+    /// Estimated convergence order
+    pub fn get_order(&self) -> f64 {
+        self.order
+    }
+
+    /// Estimated asymptotic rate, the ratio of the errors for a linear convergence
+    pub fn get_rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl fmt::Display for ConvergenceRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "order {:.2}, asymptotic rate {:.3e}",
+            self.order, self.rate
+        )
+    }
+}
+
+/// Estimation from the maximum errors of the initial guess and of each iteration, see [ConvergenceRate]
+pub(crate) fn estimate_convergence_rate(max_errors: &[f64]) -> Option<ConvergenceRate> {
+    if max_errors.len() < MIN_ITERATIONS_CONVERGENCE_RATE + 1 {
+        return None;
+    }
+    let [e0, e1, e2] = max_errors[max_errors.len() - 3..] else {
+        return None;
+    };
+    if !(e2 > 0.0 && e1 > e2 && e0 > e1 && e0.is_finite()) {
+        return None;
+    }
+
+    let order = (e2 / e1).ln() / (e1 / e0).ln();
+    let rate = e2 / e1.powf(order);
+    if order.is_finite() && rate.is_finite() {
+        Some(ConvergenceRate { order, rate })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quadratic() {
+        let errors = [1.0, 1e-1, 1e-2, 1e-4, 1e-8];
+        let convergence_rate = estimate_convergence_rate(&errors).unwrap();
+        assert!((convergence_rate.get_order() - 2.0).abs() < 1e-12);
+        assert!((convergence_rate.get_rate() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn linear() {
+        let errors = [1.0, 0.5, 0.25, 0.125, 0.0625];
+        let convergence_rate = estimate_convergence_rate(&errors).unwrap();
+        assert!((convergence_rate.get_order() - 1.0).abs() < 1e-12);
+        assert!((convergence_rate.get_rate() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn not_estimated() {
+        // too few iterations
+        assert_eq!(estimate_convergence_rate(&[1.0, 1e-1, 1e-2, 1e-4]), None);
+        // null error
+        assert_eq!(
+            estimate_convergence_rate(&[1.0, 1e-1, 1e-2, 1e-4, 0.0]),
+            None
+        );
+        // increasing error
+        assert_eq!(
+            estimate_convergence_rate(&[1.0, 1e-1, 1e-2, 1e-4, 1e-3]),
+            None
+        );
+    }
+}
@@ -1,10 +1,15 @@
-use std::fmt;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
+use super::Damping;
 use super::ResolutionMethod;
 use super::RootFinder;
 use super::SolverParameters;
+use super::{MaxIterations, ProblemSize};
 use crate::iteratives::Iterative;
 
+use crate::errors;
 use crate::iteratives;
 use crate::residuals;
 
@@ -14,7 +19,7 @@ use crate::residuals;
 /// - max_iter = 50
 /// - tolerance = 1e-6
 /// - resolution_method = NewtonRaphson
-/// - damping = false
+/// - damping = Damping::Disabled
 ///
 /// This function works either for finite difference or not.
 ///
@@ -29,6 +34,9 @@ use crate::residuals;
 /// - at compile-time (through an array)
 /// - at run-time (through the generation of a vector while parsing a configuration file)
 ///
+/// It panics if the residuals configuration or the iteratives do not have the size of the initial guess,
+/// see [try_default_with_guess] to get an error instead.
+///
 ///
 /// ## Examples
 ///
@@ -54,7 +62,7 @@ use crate::residuals;
 ///   let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
 ///   let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
 ///   let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
-///   let damping = false;
+///   let damping = nrf::solver::Damping::Disabled;
 ///
 ///   let mut rf_fd = nrf::solver::default_with_guess(init_guess_fd, &iter_params_fd, &res_config_fd, ResolutionMethod::NewtonRaphson, damping);
 ///   let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
@@ -65,7 +73,7 @@ pub fn default_with_guess<'a, T, D>(
     iters_params: &'a iteratives::Iteratives<'a, T>,
     residuals_config: &'a residuals::ResidualsConfig<'a>,
     resolution_method: ResolutionMethod,
-    damping: bool,
+    damping: Damping,
 ) -> RootFinder<'a, T, D>
 where
     T: Iterative + fmt::Display + fmt::Debug,
@@ -75,16 +83,226 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
+    match try_default_with_guess(
+        initial_guess,
+        iters_params,
+        residuals_config,
+        resolution_method,
+        damping,
+    ) {
+        Ok(rootfinder) => rootfinder,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+/// Create a solver with default parameters, returning an error if the sizes of the inputs do not match
+///
+/// The problem size is the length of the `initial_guess`:
+/// the error reports the residuals configuration or the iteratives that differ from it,
+/// or an empty initial guess.
+/// See [default_with_guess] for the default parameters and [RootFinder::try_new] for the errors.
+pub fn try_default_with_guess<'a, T, D>(
+    initial_guess: nalgebra::OVector<f64, D>,
+    iters_params: &'a iteratives::Iteratives<'a, T>,
+    residuals_config: &'a residuals::ResidualsConfig<'a>,
+    resolution_method: ResolutionMethod,
+    damping: Damping,
+) -> Result<RootFinder<'a, T, D>, errors::RootFinderConstructionError>
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    let tolerance: f64 = 1e-6;
+    let max_iter: usize = 50;
+    // the problem size is inferred from the initial guess, an empty one being rejected
+    let parameters = SolverParameters::with_inferred_size(
+        tolerance,
+        MaxIterations::new(max_iter),
+        resolution_method,
+        damping,
+    );
+
+    RootFinder::try_new(parameters, initial_guess, iters_params, residuals_config)
+}
+
+/// Starting point of a [QuickSolver], see [quick_solver]
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuickStart<D = nalgebra::Dyn>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    /// Problem size, the initial guess being the default guess of the iteratives, equal to `1.0`
+    ProblemSize(usize),
+    /// Initial guess, whose length is the problem size
+    InitialGuess(nalgebra::OVector<f64, D>),
+}
+
+impl From<usize> for QuickStart<nalgebra::Dyn> {
+    fn from(problem_size: usize) -> Self {
+        QuickStart::ProblemSize(problem_size)
+    }
+}
+
+impl<D> From<nalgebra::OVector<f64, D>> for QuickStart<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    fn from(initial_guess: nalgebra::OVector<f64, D>) -> Self {
+        QuickStart::InitialGuess(initial_guess)
+    }
+}
+
+/// Create a solver owning its configuration, to solve a problem with the default parameters in one call
+///
+/// It is intended for the common case of solving `f(x) = 0` without any specific configuration:
+/// the solver has the default parameters of [default_with_guess],
+/// the default iteratives with finite differences of [iteratives::default_vec_iteratives_fd]
+/// and the `Abs` normalization for both the stopping criteria and the update methods of the residuals.
+///
+/// The starting point is either the problem size or the initial guess, see [QuickStart].
+///
+/// There is no configuration per iterative or per residual, nor analytical jacobian:
+/// for these, build a [RootFinder] with [default_with_guess] or [RootFinder::new].
+///
+/// # Panics
+///
+/// If the problem size is zero
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+///
+/// fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 2.0)
+/// }
+///
+/// let mut solver = nrf::solver::quick_solver(1, nrf::solver::ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+/// let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+/// let report = solver.solve(&mut user_model).unwrap();
+///
+/// assert!((report.get_final_iteratives()[0] - 2.0_f64.sqrt()).abs() < 1e-6);
+/// ```
+pub fn quick_solver<D>(
+    problem_size_or_guess: impl Into<QuickStart<D>>,
+    resolution_method: ResolutionMethod,
+    damping: Damping,
+) -> QuickSolver<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    let initial_guess = match problem_size_or_guess.into() {
+        QuickStart::ProblemSize(problem_size) => nalgebra::OVector::<f64, D>::from_element_generic(
+            D::from_usize(problem_size),
+            nalgebra::U1,
+            iteratives::IterativeParamsFD::default().default_guess(),
+        ),
+        QuickStart::InitialGuess(initial_guess) => initial_guess,
+    };
     let problem_size = initial_guess.len();
+    if problem_size == 0 {
+        panic!("The problem size of the quick solver must be strictly positive, got 0");
+    }
+
     let tolerance: f64 = 1e-6;
     let max_iter: usize = 50;
     let parameters = SolverParameters::new(
-        problem_size,
+        ProblemSize::new(problem_size),
         tolerance,
-        max_iter,
+        MaxIterations::new(max_iter),
         resolution_method,
         damping,
     );
 
-    RootFinder::new(parameters, initial_guess, iters_params, residuals_config)
+    QuickSolver {
+        parameters,
+        initial_guess,
+        iteratives: iteratives::default_vec_iteratives_fd(problem_size),
+        normalization_methods: vec![residuals::NormalizationMethod::Abs; problem_size],
+    }
+}
+
+/// Solver owning its parameters, iteratives and residuals configuration, created by [quick_solver]
+///
+/// The [RootFinder] borrowing them is built and dropped within each call to [QuickSolver::solve].
+#[derive(Debug, Clone)]
+pub struct QuickSolver<D = nalgebra::Dyn>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    parameters: SolverParameters,
+    initial_guess: nalgebra::OVector<f64, D>,
+    iteratives: Vec<iteratives::IterativeParamsFD>,
+    normalization_methods: Vec<residuals::NormalizationMethod>,
+}
+
+impl<D> QuickSolver<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    pub fn get_parameters(&self) -> &SolverParameters {
+        &self.parameters
+    }
+
+    pub fn get_initial_guess(&self) -> &nalgebra::OVector<f64, D> {
+        &self.initial_guess
+    }
+
+    /// # Panics
+    ///
+    /// If the length of the initial guess is not the problem size
+    pub fn set_initial_guess(&mut self, initial_guess: nalgebra::OVector<f64, D>) {
+        if initial_guess.len() != self.initial_guess.len() {
+            panic!(
+                "The initial guess has a length of {}, the problem size of the quick solver being {}",
+                initial_guess.len(),
+                self.initial_guess.len()
+            );
+        }
+        self.initial_guess = initial_guess;
+    }
+
+    /// Solve the problem of `model`, returning the report of the resolution
+    ///
+    /// After a successful resolution, the solution is the initial guess of the next one,
+    /// as for a sequence of slowly varying models.
+    ///
+    /// # Panics
+    ///
+    /// If the problem size of the model is not the one of the solver, as with [RootFinder::solve]
+    pub fn solve<M>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<super::SolverReport<D>, errors::SolverError<M, D>>
+    where
+        M: crate::model::Model<D>,
+    {
+        let iteratives = iteratives::Iteratives::new(&self.iteratives);
+        let residuals_config = residuals::ResidualsConfig::new(
+            &self.normalization_methods,
+            &self.normalization_methods,
+        );
+        let mut rf = RootFinder::new(
+            self.parameters.clone(),
+            self.initial_guess.clone(),
+            &iteratives,
+            &residuals_config,
+        );
+
+        let report = rf.solve_with_report(model)?;
+        self.initial_guess = report.get_final_iteratives().clone();
+        Ok(report)
+    }
 }
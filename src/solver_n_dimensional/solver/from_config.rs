@@ -0,0 +1,77 @@
+use core::fmt;
+
+use crate::errors::SolverError;
+use crate::iteratives::Iterative;
+use crate::model::Model;
+use crate::xml_parser::SolverConfig;
+
+use super::SolverReport;
+
+/// Solve the problem of `model` with a solver built from `config`, starting from `initial_guess`
+///
+/// The iteratives, the residuals configuration and the [super::RootFinder] are built and dropped within this call,
+/// see [SolverConfig::with_rootfinder] to perform several resolutions with the same solver.
+/// It works with the configurations of both kinds of iteratives,
+/// parsed with [crate::xml_parser::load_xml_fd] or [crate::xml_parser::load_xml_jac].
+///
+/// # Panics
+///
+/// If the problem size of the model or the length of `initial_guess` is not the problem size of the configuration
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::model::Model;
+///
+/// fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 2.0)
+/// }
+///
+/// const CONFIGURATION: &str = r#"
+///     <nrf>
+///         <solver problem_size="1" max_iter="50" tolerance="1e-6" damping="false" resolution_method="NR"/>
+///         <iteratives min_value="-inf" max_value="inf" max_step_abs="inf" max_step_rel="inf" dx_abs="5e-8" dx_rel="5e-8" perturbation_method="Max">
+///             <iterative id="0"/>
+///         </iteratives>
+///         <residuals stopping_criteria="Abs" update_method="Abs">
+///             <residual id="0"/>
+///         </residuals>
+///     </nrf>"#;
+///
+/// let config = nrf::xml_parser::load_xml_fd_str(CONFIGURATION).unwrap();
+/// let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+///
+/// let report = nrf::solver::solve_from_config(
+///     &config,
+///     &mut user_model,
+///     nalgebra::DVector::from_vec(vec![1.0]),
+/// )
+/// .unwrap();
+/// assert!((report.get_final_iteratives()[0] - 2.0_f64.sqrt()).abs() < 1e-6);
+/// ```
+pub fn solve_from_config<T, D, M>(
+    config: &SolverConfig<T>,
+    model: &mut M,
+    initial_guess: nalgebra::OVector<f64, D>,
+) -> Result<SolverReport<D>, SolverError<M, D>>
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+    M: Model<D>,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    match config.get_parameters().get_problem_size() {
+        Some(problem_size) if model.len_problem() != problem_size => panic!(
+            "Dimension mismatch :\n model.len_problem() = {} and problem_size = {}",
+            model.len_problem(),
+            problem_size
+        ),
+        _ => (),
+    }
+
+    config.with_rootfinder(initial_guess, |rf| rf.solve_with_report(model))
+}
@@ -0,0 +1,136 @@
+use alloc::format;
+use alloc::string::String;
+use std::fs::File;
+use std::io::Write;
+use std::time::Duration;
+
+/// Record of an iteration of the solver, see [crate::solver::RootFinder::set_history]
+///
+/// The durations are measured with [std::time::Instant], unaffected by the changes of the system clock.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IterationRecord {
+    pub(crate) iteration: usize,
+    pub(crate) max_error: f64,
+    pub(crate) model_evaluations: usize,
+    pub(crate) duration: Duration,
+    pub(crate) timestamp_offset: Duration,
+    pub(crate) update_metrics: Option<QuasiNewtonUpdateMetrics>,
+}
+
+impl IterationRecord {
+    /// Number of the iteration, starting at 1
+    pub fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// Maximum stopping error of the iterate accepted at the end of the iteration
+    pub fn get_max_error(&self) -> f64 {
+        self.max_error
+    }
+
+    /// Number of model evaluations of the resolution at the end of the iteration
+    pub fn get_model_evaluations(&self) -> usize {
+        self.model_evaluations
+    }
+
+    /// Duration of the iteration, from the start of the computation of its step to the acceptance of the iterate
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Duration from the start of the resolution to the acceptance of the iterate
+    pub fn get_timestamp_offset(&self) -> Duration {
+        self.timestamp_offset
+    }
+
+    /// Quality of the quasi-Newton update performed by the iteration
+    ///
+    /// `None` if the jacobian has been computed or reused as is,
+    /// or if the limited memory Broyden method is used, as it does not build the matrix
+    pub fn get_update_metrics(&self) -> Option<QuasiNewtonUpdateMetrics> {
+        self.update_metrics
+    }
+}
+
+/// Quality of a quasi-Newton update of the jacobian or of its inverse, see [IterationRecord]
+///
+/// With the step `s` of the iteratives and the step `y` of the residuals of the previous iteration,
+/// the updated jacobian `J` should satisfy the secant equation `J s = y`, and its inverse `H` the equation `H y = s`.
+/// A skipped update keeps the previous matrix, which usually does not satisfy it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuasiNewtonUpdateMetrics {
+    pub(crate) secant_residual: f64,
+    pub(crate) update_norm: f64,
+}
+
+impl QuasiNewtonUpdateMetrics {
+    /// Euclidean norm of `J s - y` for an update of the jacobian, of `H y - s` for an update of its inverse
+    pub fn get_secant_residual(&self) -> f64 {
+        self.secant_residual
+    }
+
+    /// Frobenius norm of the difference between the updated matrix and the previous one, `0` if the update has been skipped
+    pub fn get_update_norm(&self) -> f64 {
+        self.update_norm
+    }
+}
+
+/// Metrics of the update of the matrix `previous` into `updated`,
+/// `inverse` telling if they are inverses of the jacobian, see [QuasiNewtonUpdateMetrics]
+pub(crate) fn quasi_newton_update_metrics<D>(
+    previous: &nalgebra::OMatrix<f64, D, D>,
+    updated: &nalgebra::OMatrix<f64, D, D>,
+    iteratives_step_size: &nalgebra::OVector<f64, D>,
+    residuals_step_size: &nalgebra::OVector<f64, D>,
+    inverse: bool,
+) -> QuasiNewtonUpdateMetrics
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let secant_residual = if inverse {
+        (updated * residuals_step_size - iteratives_step_size).norm()
+    } else {
+        (updated * iteratives_step_size - residuals_step_size).norm()
+    };
+    QuasiNewtonUpdateMetrics {
+        secant_residual,
+        update_norm: (updated - previous).norm(),
+    }
+}
+
+/// Content of the CSV file of the history, one line per iteration, the durations being in seconds
+///
+/// The metrics of the quasi-Newton updates are empty for the iterations without update
+pub(crate) fn format_history_file(history: &[IterationRecord]) -> String {
+    let mut content = String::from(
+        "iteration,max_error,model_evaluations,duration_s,timestamp_offset_s,secant_residual,update_norm\n",
+    );
+    for record in history {
+        let update_metrics = match record.update_metrics {
+            Some(metrics) => format!("{:e},{:e}", metrics.secant_residual, metrics.update_norm),
+            None => String::from(","),
+        };
+        content.push_str(&format!(
+            "{},{:e},{},{:e},{:e},{}\n",
+            record.iteration,
+            record.max_error,
+            record.model_evaluations,
+            record.duration.as_secs_f64(),
+            record.timestamp_offset.as_secs_f64(),
+            update_metrics
+        ));
+    }
+    content
+}
+
+pub(crate) fn write_history_file(history: &[IterationRecord], path: &str) {
+    let content = format_history_file(history);
+    let mut file = File::create(path)
+        .unwrap_or_else(|error| panic!("Cannot create the history file {}: {}", path, error));
+    file.write_all(content.as_bytes())
+        .unwrap_or_else(|error| panic!("Cannot write the history file {}: {}", path, error));
+}
@@ -17,19 +17,20 @@ where
 {
     let residuals_values = model.get_residuals();
 
-    let jacobians = model.get_jacobian();
+    let jacobians = if model.provides_jacobian_by_column() {
+        assemble_jacobian_from_columns(model)
+    } else {
+        model.get_jacobian()
+    };
+
     match jacobians {
         Ok(valid_jacobians) => {
-            let normalization_method = residuals_config.get_update_methods();
-
-            match jacobian_matrix.update_jacobian_with_exact_value(
-                valid_jacobians.normalize(&residuals_values, normalization_method),
-            ) {
-                Ok(()) => Ok(()),
-                Err(errors::NonInvertibleJacobian) => {
-                    Err(errors::SolverInternalError::InvalidJacobianInverseError)
-                }
-            }
+            let normalization_method =
+                residuals_config.get_safeguarded_update_methods(&residuals_values);
+
+            jacobian_matrix.update_jacobian_with_computed_value(
+                valid_jacobians.normalize(&residuals_values, &normalization_method),
+            )
         }
         Err(error) => {
             jacobian_matrix.invalidate_jacobian();
@@ -37,3 +38,72 @@ where
         }
     }
 }
+
+fn assemble_jacobian_from_columns<M, D>(
+    model: &mut M,
+) -> Result<residuals::JacobianValues<D>, model::ModelError<M, D>>
+where
+    M: model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let iteratives = model.get_iteratives();
+    let mut left = super::super::super::omatrix_zeros_like_ovector(&iteratives);
+    let mut right = super::super::super::omatrix_zeros_like_ovector(&iteratives);
+
+    for j in 0..model.len_problem() {
+        let (left_column, right_column) = model.get_jacobian_column(j)?;
+        left.set_column(j, &left_column);
+        right.set_column(j, &right_column);
+    }
+
+    Ok(residuals::JacobianValues::new(left, right))
+}
+
+/// Diagonal of the normalized jacobian provided by the model,
+/// used as seed by [crate::solver::QuasiNewtonMethod::LimitedMemoryBroyden]
+///
+/// If the model provides its jacobian by column, only the diagonal values of the columns are kept,
+/// otherwise the matrices returned by the model are dropped once their diagonal is extracted.
+pub(crate) fn evaluate_jacobian_diagonal_from_analytical_function<'a, M, D>(
+    model: &mut M,
+    residuals_config: &'a residuals::ResidualsConfig<'a>,
+) -> Result<nalgebra::OVector<f64, D>, model::ModelError<M, D>>
+where
+    M: model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let residuals_values = model.get_residuals();
+    let normalization_method = residuals_config.get_safeguarded_update_methods(&residuals_values);
+    let mut left_diagonal = model.get_iteratives();
+    let mut right_diagonal = left_diagonal.clone();
+
+    if model.provides_jacobian_by_column() {
+        for j in 0..model.len_problem() {
+            let (left_column, right_column) = model.get_jacobian_column(j)?;
+            left_diagonal[j] = left_column[j];
+            right_diagonal[j] = right_column[j];
+        }
+    } else {
+        let jacobians = model.get_jacobian()?;
+        let (left, right) = jacobians.get_jacobians();
+        left_diagonal.copy_from(&left.diagonal());
+        right_diagonal.copy_from(&right.diagonal());
+    }
+
+    // the left diagonal is replaced by the diagonal of the normalized jacobian
+    for i in 0..left_diagonal.len() {
+        let (left_value, right_value) = residuals_values.get_values(i);
+        left_diagonal[i] = residuals::deriv_normalization(
+            left_value,
+            right_value,
+            left_diagonal[i],
+            right_diagonal[i],
+            normalization_method[i],
+        );
+    }
+    Ok(left_diagonal)
+}
@@ -2,17 +2,40 @@ use super::JacobianMatrix;
 
 use super::super::UpdateQuasiNewtonMethod;
 use super::super::{
-    broyden_first_method_udpate_jac, broyden_second_method_udpate_jac,
-    greenstadt_second_method_udpate_jac, quasi_method_update_jac,
+    broyden_first_method_update_jac_in_place, broyden_second_method_update_jac_in_place,
+    greenstadt_second_method_update_jac_in_place, quasi_method_update_jac_in_place,
+    schubert_update_jac_in_place,
 };
 
+/// Outcome of a quasi-Newton update of the jacobian or of its inverse
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum QuasiNewtonUpdateStatus {
+    /// The update has been performed
+    Updated,
+    /// The update has been skipped because of a degenerate denominator,
+    /// the previous matrix being kept
+    Skipped,
+    /// The update produced non-finite values:
+    /// the computation of the jacobian is forced at the next iteration.
+    ///
+    /// Within a resolution, two consecutive non-finite updates stop it
+    /// with the [crate::errors::SolverInternalError::NonFiniteQuasiNewtonUpdatesError]
+    NonFinite,
+}
+
+/// Update in place the jacobian with a quasi-Newton method and recompute its inverse
+///
+/// The jacobian buffers are reused: no allocation is performed
+///
+/// If the update is skipped or produces non-finite values, the inverse is not recomputed,
+/// see [QuasiNewtonUpdateStatus].
 pub fn approximate_jacobian<D>(
     jacobian: &mut JacobianMatrix<D>,
     method: UpdateQuasiNewtonMethod,
     iteratives_step_size: &nalgebra::OVector<f64, D>,
     residuals_step_size: &nalgebra::OVector<f64, D>,
     residuals_values_current: &nalgebra::OVector<f64, D>,
-) -> Result<(), crate::errors::NonInvertibleJacobian>
+) -> Result<QuasiNewtonUpdateStatus, crate::errors::NonInvertibleJacobian>
 where
     D: nalgebra::DimMin<D, Output = D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
@@ -20,33 +43,67 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
-    let jac_next = match method {
-        UpdateQuasiNewtonMethod::BroydenFirstMethod => broyden_first_method_udpate_jac(
-            jacobian.get_jacobian().as_ref().unwrap(),
+    let (jac, inverse, scratch, c, sparsity_pattern) = jacobian.jacobian_workspace();
+
+    let updated = match method {
+        UpdateQuasiNewtonMethod::BroydenFirstMethod => broyden_first_method_update_jac_in_place(
+            jac,
             iteratives_step_size,
             residuals_step_size,
+            scratch,
         ),
-        UpdateQuasiNewtonMethod::BroydenSecondMethod => broyden_second_method_udpate_jac(
-            jacobian.get_jacobian().as_ref().unwrap(),
+        UpdateQuasiNewtonMethod::BroydenSecondMethod => broyden_second_method_update_jac_in_place(
+            jac,
             iteratives_step_size,
             residuals_step_size,
+            scratch,
         ),
-        UpdateQuasiNewtonMethod::GreenstadtFirstMethod => quasi_method_update_jac(
-            jacobian.get_jacobian().as_ref().unwrap(),
+        UpdateQuasiNewtonMethod::GreenstadtFirstMethod => quasi_method_update_jac_in_place(
+            jac,
             iteratives_step_size,
             residuals_step_size,
             residuals_values_current,
+            scratch,
         ),
         UpdateQuasiNewtonMethod::GreenstadtSecondMethod => {
-            let c = jacobian.get_inverse().as_ref().unwrap() * residuals_step_size;
-            greenstadt_second_method_udpate_jac(
-                jacobian.get_jacobian().as_ref().unwrap(),
+            // see LinearSolver::is_compatible_with()
+            inverse
+                .expect("The second method of Greenstadt requires the inverse of the jacobian")
+                .mul_to(residuals_step_size, c);
+            greenstadt_second_method_update_jac_in_place(
+                jac,
                 iteratives_step_size,
                 residuals_step_size,
-                &c,
+                c,
+                scratch,
             )
         }
+        UpdateQuasiNewtonMethod::SchubertSparse => schubert_update_jac_in_place(
+            jac,
+            iteratives_step_size,
+            residuals_step_size,
+            // see check_compatibility()
+            sparsity_pattern.expect("The Schubert method requires a sparsity pattern"),
+            scratch,
+        ),
     };
 
-    jacobian.update_jacobian_with_approximated_value(jac_next)
+    if !updated {
+        return Ok(QuasiNewtonUpdateStatus::Skipped);
+    }
+
+    if !jacobian
+        .get_jacobian()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .all(|value| value.is_finite())
+    {
+        jacobian.force_jacobian_computation();
+        return Ok(QuasiNewtonUpdateStatus::NonFinite);
+    }
+
+    jacobian
+        .update_inverse_from_approximated_jacobian()
+        .map(|()| QuasiNewtonUpdateStatus::Updated)
 }
@@ -0,0 +1,133 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::fs::File;
+use std::io::Write;
+
+use super::JacobianMatrix;
+
+/// Format of the files written by [JacobianMatrix::write_jacobian_file]
+///
+/// Both formats start with a comment giving the iteration at which the jacobian has been computed.
+/// The values are written with the shortest representation parsed back to the same float.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MatrixFileFormat {
+    /// One line per row, the values being separated by commas
+    ///
+    /// The header comment starts with `#`, as expected by `numpy.loadtxt(path, delimiter=",")`.
+    Csv,
+    /// Coordinate format of the Matrix Market exchange format, with 1-based indices
+    ///
+    /// Only the nonzero entries are written,
+    /// the file can be read with `scipy.io.mmread` or the `mmread` function of Matlab.
+    MatrixMarket,
+}
+
+/// Content of a matrix file, see [MatrixFileFormat]
+pub(crate) fn format_matrix_file<D>(
+    matrix: &nalgebra::OMatrix<f64, D, D>,
+    format: MatrixFileFormat,
+    iteration: usize,
+) -> String
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let mut content = String::new();
+    match format {
+        MatrixFileFormat::Csv => {
+            content.push_str(&format!("# Jacobian at iteration {}\n", iteration));
+            for row in matrix.row_iter() {
+                let values: Vec<String> = row.iter().map(|value| format!("{:e}", value)).collect();
+                content.push_str(&values.join(","));
+                content.push('\n');
+            }
+        }
+        MatrixFileFormat::MatrixMarket => {
+            content.push_str("%%MatrixMarket matrix coordinate real general\n");
+            content.push_str(&format!("% Jacobian at iteration {}\n", iteration));
+            let nonzeros = matrix.iter().filter(|&&value| value != 0.0).count();
+            content.push_str(&format!(
+                "{} {} {}\n",
+                matrix.nrows(),
+                matrix.ncols(),
+                nonzeros
+            ));
+            for j in 0..matrix.ncols() {
+                for i in 0..matrix.nrows() {
+                    let value = matrix[(i, j)];
+                    if value != 0.0 {
+                        content.push_str(&format!("{} {} {:e}\n", i + 1, j + 1, value));
+                    }
+                }
+            }
+        }
+    }
+    content
+}
+
+/// Path of a file written by a jacobian dump, the `{iter}` placeholder being replaced by the iteration
+pub(crate) fn dump_path(path_template: &str, iteration: usize) -> String {
+    path_template.replace("{iter}", &iteration.to_string())
+}
+
+/// Writing of the jacobian computed by the solver, see [crate::solver::RootFinder::dump_jacobian_on_next_computation]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JacobianDump {
+    pub(crate) path_template: String,
+    pub(crate) format: MatrixFileFormat,
+    pub(crate) every_computation: bool,
+}
+
+impl<D> JacobianMatrix<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    /// Write the jacobian to a file, to be analyzed with external tools
+    ///
+    /// Available with the `std` feature, enabled by default.
+    ///
+    /// `iteration` is written in the header comment of the file, see [MatrixFileFormat].
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created or written
+    ///
+    /// # Panics
+    ///
+    /// If the jacobian is not up to date with its inverse, see [JacobianMatrix::get_jacobian]
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use newton_rootfinder as nrf;
+    /// use nrf::solver::MatrixFileFormat;
+    ///
+    /// let mut jacobian = nrf::solver::JacobianMatrix::new();
+    /// jacobian
+    ///     .update_jacobian_with_exact_value(nalgebra::DMatrix::from_vec(2, 2, vec![2.0, 0.0, 1.0, 4.0]))
+    ///     .unwrap();
+    ///
+    /// jacobian
+    ///     .write_jacobian_file("jacobian.mtx", MatrixFileFormat::MatrixMarket, 1)
+    ///     .unwrap();
+    /// ```
+    pub fn write_jacobian_file(
+        &self,
+        path: &str,
+        format: MatrixFileFormat,
+        iteration: usize,
+    ) -> std::io::Result<()> {
+        let matrix = match self.get_jacobian() {
+            Some(matrix) => matrix,
+            None => panic!(
+                "The jacobian cannot be written to {}: it is not up to date with its inverse",
+                path
+            ),
+        };
+        let content = format_matrix_file(matrix, format, iteration);
+        let mut file = File::create(path)?;
+        file.write_all(content.as_bytes())
+    }
+}
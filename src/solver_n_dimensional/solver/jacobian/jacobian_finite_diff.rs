@@ -1,21 +1,110 @@
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
 
+use super::super::{perturbation_jitter_factor, InaccurateJacobianPolicy};
 use super::JacobianMatrix;
 use crate::errors;
 use crate::iteratives;
 use crate::iteratives::Iterative;
 use crate::model;
-use crate::model::ModelError;
 use crate::residuals;
 
+/// Column of a jacobian evaluated per finite difference for which the model returned inaccurate values,
+/// or residuals exceeding their maximum magnitude.
+/// With [crate::solver::InaccurateJacobianPolicy::RetryColumnWithReducedStep],
+/// the columns retried after unusable values are reported as well.
+///
+/// See [crate::solver::InaccurateJacobianPolicy] and [crate::residuals::ResidualsConfig::set_max_magnitudes]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InaccurateColumn {
+    column: usize,
+    retries: usize,
+    recovered: bool,
+}
+
+impl InaccurateColumn {
+    /// Index of the column, which is the one of the perturbed iterative
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// Number of evaluations of the column performed again with a reduced perturbation
+    pub fn get_retries(&self) -> usize {
+        self.retries
+    }
+
+    /// The values were accurate and usable with the last perturbation
+    pub fn get_recovered(&self) -> bool {
+        self.recovered
+    }
+}
+
 /// Evaluate a jacobian per forward finite difference when perturbation step eps is provided
 ///
+/// The inaccurate values returned by the model are accepted,
+/// see [crate::solver::InaccurateJacobianPolicy::Accept],
+/// whereas a perturbed point with residuals exceeding their maximum magnitude is an error
+///
 /// This function has been made public for testing purpose only
 pub fn compute_jacobian_from_finite_difference<M, D>(
     model: &mut M,
     perturbations: &nalgebra::OVector<f64, D>,
     update_residuals: &residuals::ResidualsConfig,
-) -> Result<nalgebra::OMatrix<f64, D, D>, ModelError<M, D>>
+) -> Result<nalgebra::OMatrix<f64, D, D>, errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let mut perturbations = perturbations.clone();
+    let mut inaccurate_columns = Vec::new();
+    let mut evaluations = 0;
+    compute_jacobian_with_policy(
+        model,
+        &mut perturbations,
+        update_residuals,
+        &residuals::RightOverrides::default(),
+        InaccurateJacobianPolicy::Accept,
+        &mut inaccurate_columns,
+        None,
+        &[],
+        None,
+        &mut evaluations,
+    )
+}
+
+/// Evaluate a jacobian per forward finite difference, treating the inaccurate values according to the `policy`
+///
+/// The right members of the residuals are replaced by the `right_overrides` at each evaluated point.
+/// The `perturbations` reduced by the policy are updated,
+/// the columns with inaccurate values are pushed in `inaccurate_columns`.
+///
+/// The `constant_columns` are copied from the given matrix instead of being evaluated,
+/// the `excluded_columns` are left null without evaluating the model,
+/// the number of points evaluated by the model is added to `evaluations`.
+///
+/// A perturbed point with unusable values, or with residuals exceeding their maximum magnitude,
+/// is retried as inaccurate values with the `RetryColumnWithReducedStep` policy.
+/// If the magnitude is still exceeded, the column of the `previous_jacobian` is kept unchanged whatever the policy.
+/// Without previous jacobian, the computation fails on the exceeded magnitude.
+///
+/// If the model provides a batch evaluation, all the perturbed points are evaluated in one call,
+/// falling back on the evaluation column by column to apply the `policy` to the errors of the model
+/// or to residuals exceeding their maximum magnitude.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn compute_jacobian_with_policy<M, D>(
+    model: &mut M,
+    perturbations: &mut nalgebra::OVector<f64, D>,
+    update_residuals: &residuals::ResidualsConfig,
+    right_overrides: &residuals::RightOverrides<D>,
+    policy: InaccurateJacobianPolicy,
+    inaccurate_columns: &mut Vec<InaccurateColumn>,
+    constant_columns: Option<(&[usize], &nalgebra::OMatrix<f64, D, D>)>,
+    excluded_columns: &[usize],
+    previous_jacobian: Option<&nalgebra::OMatrix<f64, D, D>>,
+    evaluations: &mut usize,
+) -> Result<nalgebra::OMatrix<f64, D, D>, errors::SolverInternalError<M, D>>
 where
     M: model::Model<D>,
     D: nalgebra::Dim,
@@ -27,27 +116,181 @@ where
         super::super::super::omatrix_zeros_like_ovector(perturbations);
     let memory_ref = model.get_memory();
     let iteratives_ref = model.get_iteratives();
-    let residuals_ref = update_residuals.evaluate_update_residuals(&model.get_residuals());
+    // the residuals values are written in the same placeholder for each column
+    let mut residuals_values = model.get_residuals();
+    right_overrides.apply(&iteratives_ref, &mut residuals_values);
+    // the update methods of the reference point are kept for all the columns
+    let update_methods = update_residuals.get_safeguarded_update_methods(&residuals_values);
+    let residuals_ref =
+        update_residuals.evaluate_update_residuals_with(&residuals_values, &update_methods);
 
+    let mut evaluated_columns = Vec::with_capacity(problem_size);
     for i in 0..problem_size {
-        // Finite-difference column evaluation
-        let mut iteratives_perturbations = iteratives_ref.clone();
-        iteratives_perturbations[i] += perturbations[i];
+        match constant_columns {
+            _ if excluded_columns.contains(&i) => (),
+            Some((columns, values)) if columns.contains(&i) => {
+                jacobian.set_column(i, &values.column(i))
+            }
+            _ => evaluated_columns.push(i),
+        }
+    }
 
-        model.set_iteratives(&iteratives_perturbations);
-        match model.evaluate() {
-            // recovers from inaccurate values
-            Ok(()) | Err(ModelError::InaccurateValuesError(_)) => (),
-            Err(model_error) => return Err(model_error),
+    if model.provides_batch_evaluation() && !evaluated_columns.is_empty() {
+        let inputs: Vec<nalgebra::OVector<f64, D>> = evaluated_columns
+            .iter()
+            .map(|&i| {
+                let mut iteratives_perturbations = iteratives_ref.clone();
+                iteratives_perturbations[i] += perturbations[i];
+                iteratives_perturbations
+            })
+            .collect();
+
+        *evaluations += inputs.len();
+        let outputs = model.evaluate_batch(&inputs);
+        model.set_memory(&memory_ref); // restart from reference state
+
+        match outputs {
+            Ok(mut outputs) => {
+                if outputs.len() != inputs.len() {
+                    panic!(
+                        "The batch evaluation returned {} residuals for {} inputs",
+                        outputs.len(),
+                        inputs.len()
+                    );
+                }
+                for (input, residuals_values) in inputs.iter().zip(outputs.iter_mut()) {
+                    right_overrides.apply(input, residuals_values);
+                }
+                // the columns are evaluated one by one to retry the exceeding points
+                let exceeded = outputs.iter().any(|residuals_values| {
+                    update_residuals
+                        .find_exceeded_magnitude(residuals_values)
+                        .is_some()
+                });
+                if !exceeded {
+                    for (&i, residuals_values) in evaluated_columns.iter().zip(outputs) {
+                        let residuals_perturbation = update_residuals
+                            .evaluate_update_residuals_with(&residuals_values, &update_methods);
+                        let col = (residuals_perturbation - &residuals_ref) / perturbations[i];
+                        jacobian.set_column(i, &col);
+                    }
+                    return Ok(jacobian);
+                }
+            }
+            // the columns are evaluated one by one to apply the policy
+            Err(model_error)
+                if model_error.is_inaccurate_values_error()
+                    || matches!(
+                        policy,
+                        InaccurateJacobianPolicy::RetryColumnWithReducedStep { .. }
+                    ) => {}
+            Err(model_error) => {
+                return Err(errors::SolverInternalError::InvalidJacobianError(
+                    model_error,
+                ))
+            }
         }
+    }
 
-        let residuals_perturbation =
-            update_residuals.evaluate_update_residuals(&model.get_residuals());
+    for i in evaluated_columns {
+        let mut retries = 0;
+        let mut exceeded = None;
+        loop {
+            // Finite-difference column evaluation
+            let mut iteratives_perturbations = iteratives_ref.clone();
+            iteratives_perturbations[i] += perturbations[i];
 
-        // First order forward difference
-        let col = (residuals_perturbation - &residuals_ref) / perturbations[i];
+            model.set_iteratives(&iteratives_perturbations);
+            *evaluations += 1;
+            match model.evaluate() {
+                Ok(()) => {
+                    write_perturbed_residuals(model, right_overrides, &mut residuals_values);
+                    exceeded = update_residuals.find_exceeded_magnitude(&residuals_values);
+                    if exceeded.is_none() {
+                        if retries > 0 {
+                            inaccurate_columns.push(InaccurateColumn {
+                                column: i,
+                                retries,
+                                recovered: true,
+                            });
+                        }
+                        break;
+                    }
+                    // the perturbed point is in a broken regime of the model
+                    match policy {
+                        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries }
+                            if retries < max_retries =>
+                        {
+                            retries += 1;
+                            perturbations[i] /= 2.0;
+                            model.set_memory(&memory_ref);
+                        }
+                        _ => {
+                            inaccurate_columns.push(InaccurateColumn {
+                                column: i,
+                                retries,
+                                recovered: false,
+                            });
+                            break;
+                        }
+                    }
+                }
+                Err(model_error) => match policy {
+                    // recovers from inaccurate values
+                    InaccurateJacobianPolicy::Accept
+                        if model_error.is_inaccurate_values_error() =>
+                    {
+                        inaccurate_columns.push(InaccurateColumn {
+                            column: i,
+                            retries,
+                            recovered: false,
+                        });
+                        write_perturbed_residuals(model, right_overrides, &mut residuals_values);
+                        break;
+                    }
+                    // the unusable values are retried as well, the model failing at the perturbed point only
+                    InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries }
+                        if retries < max_retries =>
+                    {
+                        retries += 1;
+                        perturbations[i] /= 2.0;
+                        model.set_memory(&memory_ref);
+                    }
+                    _ => {
+                        if model_error.is_inaccurate_values_error() || retries > 0 {
+                            inaccurate_columns.push(InaccurateColumn {
+                                column: i,
+                                retries,
+                                recovered: false,
+                            });
+                        }
+                        return Err(errors::SolverInternalError::InvalidJacobianError(
+                            model_error,
+                        ));
+                    }
+                },
+            }
+        }
 
-        jacobian.set_column(i, &col);
+        match (exceeded, previous_jacobian) {
+            (Some(_), Some(previous)) => jacobian.set_column(i, &previous.column(i)),
+            (Some((id, value, limit)), None) => {
+                return Err(errors::SolverInternalError::ResidualMagnitudeExceeded {
+                    id,
+                    value,
+                    limit,
+                });
+            }
+            (None, _) => {
+                let residuals_perturbation = update_residuals
+                    .evaluate_update_residuals_with(&residuals_values, &update_methods);
+
+                // First order difference: forward with a positive perturbation, backward with a negative one
+                let col = (residuals_perturbation - &residuals_ref) / perturbations[i];
+
+                jacobian.set_column(i, &col);
+            }
+        }
 
         // Restart from reference state, needed for :
         // - next iteration of the loop :
@@ -61,11 +304,87 @@ where
     Ok(jacobian)
 }
 
+/// Write the residuals values of the perturbed point evaluated by the model, with the right members overridden
+fn write_perturbed_residuals<M, D>(
+    model: &M,
+    right_overrides: &residuals::RightOverrides<D>,
+    residuals_values: &mut residuals::ResidualsValues<D>,
+) where
+    M: model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    model.write_residuals(residuals_values);
+    if !right_overrides.is_empty() {
+        // the perturbation may have been reduced by the policy
+        right_overrides.apply(&model.get_iteratives(), residuals_values);
+    }
+}
+
+/// Evaluate the jacobian per forward finite difference at the current iteratives of the model
+///
+/// The perturbations used are stored in the `jacobian`,
+/// along with the iteratives whose perturbed value is out of their bounds
+/// and the columns for which the model returned inaccurate values, treated according to the `policy`.
+///
+/// The column of a perturbed point with residuals exceeding their maximum magnitude
+/// is kept from the jacobian previously stored, the evaluation failing without it
 pub fn evaluate_jacobian_from_finite_difference<'a, M, D, T>(
     jacobian: &mut JacobianMatrix<D>,
     model: &mut M,
     iters_params: &'a iteratives::Iteratives<'a, T>,
     residuals_config: &'a residuals::ResidualsConfig<'a>,
+    policy: InaccurateJacobianPolicy,
+) -> Result<(), crate::errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    T: Iterative + fmt::Display,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    evaluate_jacobian_with_constant_columns(
+        jacobian,
+        model,
+        iters_params,
+        residuals_config,
+        &residuals::RightOverrides::default(),
+        policy,
+        None,
+        &[],
+        &mut None,
+        &mut 0,
+    )
+}
+
+/// Evaluate the jacobian per forward finite difference, reusing the columns known to be constant
+///
+/// If `constant_values` holds a previous jacobian, the `constant_columns` are copied from it without evaluating the model.
+/// Otherwise, all the columns are evaluated and the jacobian is kept in `constant_values`,
+/// unless one of the `constant_columns` has inaccurate values.
+///
+/// The `perturbation_jitter` gives the relative amplitude and the iteration of the jitter of the perturbations,
+/// see [jitter_perturbations].
+///
+/// The columns of the fixed iteratives are not evaluated: their step being null,
+/// they are excluded from the linear systems, see [JacobianMatrix::set_fixed_rows].
+/// Neither are the columns removed by a structural reduction, see [JacobianMatrix::set_structural_reduction].
+///
+/// The number of points evaluated by the model is added to `evaluations`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn evaluate_jacobian_with_constant_columns<'a, M, D, T>(
+    jacobian: &mut JacobianMatrix<D>,
+    model: &mut M,
+    iters_params: &'a iteratives::Iteratives<'a, T>,
+    residuals_config: &'a residuals::ResidualsConfig<'a>,
+    right_overrides: &residuals::RightOverrides<D>,
+    policy: InaccurateJacobianPolicy,
+    perturbation_jitter: Option<(f64, usize)>,
+    constant_columns: &[usize],
+    constant_values: &mut Option<nalgebra::OMatrix<f64, D, D>>,
+    evaluations: &mut usize,
 ) -> Result<(), crate::errors::SolverInternalError<M, D>>
 where
     M: model::Model<D>,
@@ -77,18 +396,178 @@ where
 {
     let iters_values = model.get_iteratives();
 
-    let perturbations = iters_params.compute_perturbations(&iters_values);
+    let mut perturbations = iters_params.compute_perturbations(&iters_values);
+    jitter_perturbations(&mut perturbations, perturbation_jitter);
+    let mut inaccurate_columns = Vec::new();
+    let mut fixed_columns = iters_params.fixed_iteratives();
+    fixed_columns.extend(
+        jacobian
+            .get_reduced_rows()
+            .iter()
+            .map(|&(_, iterative)| iterative),
+    );
+
+    let matrix = compute_jacobian_with_policy(
+        model,
+        &mut perturbations,
+        residuals_config,
+        right_overrides,
+        policy,
+        &mut inaccurate_columns,
+        constant_values
+            .as_ref()
+            .map(|values| (constant_columns, values)),
+        &fixed_columns,
+        jacobian.get_jacobian().as_ref(),
+        evaluations,
+    );
+    let reused_columns = if constant_values.is_some() {
+        constant_columns
+    } else {
+        &[]
+    };
+    let mut perturbations_out_of_bounds =
+        iters_params.perturbations_out_of_bounds(&iters_values, &perturbations);
+    // the model is not evaluated with the perturbations of the reused and fixed columns
+    perturbations_out_of_bounds
+        .retain(|i| !reused_columns.contains(i) && !fixed_columns.contains(i));
+
+    if let Ok(valid_jacobian) = &matrix {
+        let accurate_constant_columns = inaccurate_columns
+            .iter()
+            .all(|column| column.recovered || !constant_columns.contains(&column.column));
+        if !constant_columns.is_empty() && constant_values.is_none() && accurate_constant_columns {
+            *constant_values = Some(valid_jacobian.clone());
+        }
+    }
+
+    jacobian.set_perturbations(
+        perturbations,
+        perturbations_out_of_bounds,
+        inaccurate_columns,
+    );
+    jacobian.update_jacobian_with_computed_value(matrix?)
+}
+
+/// Multiply each perturbation by its jitter factor, see [crate::solver::perturbation_jitter_factor]
+///
+/// The `perturbation_jitter` gives the relative amplitude and the iteration, `None` leaving the perturbations unchanged
+fn jitter_perturbations<D>(
+    perturbations: &mut nalgebra::OVector<f64, D>,
+    perturbation_jitter: Option<(f64, usize)>,
+) where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    if let Some((amplitude, iteration)) = perturbation_jitter {
+        for (index, perturbation) in perturbations.iter_mut().enumerate() {
+            *perturbation *= perturbation_jitter_factor(amplitude, iteration, index);
+        }
+    }
+}
+
+/// Diagonal of the jacobian evaluated per forward finite difference at the current iteratives of the model,
+/// used as seed by [crate::solver::QuasiNewtonMethod::LimitedMemoryBroyden]
+///
+/// Each iterative is perturbed in turn, only the residual of the same index being kept:
+/// it takes as many model evaluations as the full jacobian, without its storage.
+/// The perturbed points are evaluated one by one, the inaccurate values being treated according to the `policy`,
+/// the unusable values and the residuals exceeding their maximum magnitude being retried as inaccurate values.
+/// Without previous diagonal to keep, a perturbed point still exceeding the maximum magnitude is an error.
+/// The iteratives of the model are set back to the reference point afterwards.
+///
+/// The perturbations are jittered according to `perturbation_jitter`, see [jitter_perturbations],
+/// the number of points evaluated by the model is added to `evaluations`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn evaluate_jacobian_diagonal_from_finite_difference<'a, M, D, T>(
+    model: &mut M,
+    iters_params: &'a iteratives::Iteratives<'a, T>,
+    residuals_config: &'a residuals::ResidualsConfig<'a>,
+    right_overrides: &residuals::RightOverrides<D>,
+    policy: InaccurateJacobianPolicy,
+    perturbation_jitter: Option<(f64, usize)>,
+    evaluations: &mut usize,
+) -> Result<nalgebra::OVector<f64, D>, errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    T: Iterative + fmt::Display,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let memory_ref = model.get_memory();
+    let iteratives_ref = model.get_iteratives();
+    let mut perturbations = iters_params.compute_perturbations(&iteratives_ref);
+    jitter_perturbations(&mut perturbations, perturbation_jitter);
+    let mut residuals_values = model.get_residuals();
+    right_overrides.apply(&iteratives_ref, &mut residuals_values);
+    let update_methods = residuals_config.get_safeguarded_update_methods(&residuals_values);
+    let residuals_ref =
+        residuals_config.evaluate_update_residuals_with(&residuals_values, &update_methods);
 
-    let matrix = compute_jacobian_from_finite_difference(model, &perturbations, residuals_config);
-    match matrix {
-        Ok(valid_jacobian) => match jacobian.update_jacobian_with_exact_value(valid_jacobian) {
-            Ok(()) => Ok(()),
-            Err(errors::NonInvertibleJacobian) => {
-                Err(errors::SolverInternalError::InvalidJacobianInverseError)
+    let mut diagonal = iteratives_ref.clone();
+    for i in 0..model.len_problem() {
+        let mut retries = 0;
+        let mut iteratives_perturbations = iteratives_ref.clone();
+        loop {
+            iteratives_perturbations[i] = iteratives_ref[i] + perturbations[i];
+            model.set_iteratives(&iteratives_perturbations);
+            *evaluations += 1;
+            match model.evaluate() {
+                Ok(()) => {
+                    model.write_residuals(&mut residuals_values);
+                    right_overrides.apply(&iteratives_perturbations, &mut residuals_values);
+                    let exceeded = residuals_config.find_exceeded_magnitude(&residuals_values);
+                    match (exceeded, policy) {
+                        (None, _) => break,
+                        (
+                            Some(_),
+                            InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries },
+                        ) if retries < max_retries => {
+                            retries += 1;
+                            perturbations[i] /= 2.0;
+                            model.set_memory(&memory_ref);
+                        }
+                        (Some((id, value, limit)), _) => {
+                            return Err(errors::SolverInternalError::ResidualMagnitudeExceeded {
+                                id,
+                                value,
+                                limit,
+                            });
+                        }
+                    }
+                }
+                Err(model_error) => match policy {
+                    InaccurateJacobianPolicy::Accept
+                        if model_error.is_inaccurate_values_error() =>
+                    {
+                        model.write_residuals(&mut residuals_values);
+                        right_overrides.apply(&iteratives_perturbations, &mut residuals_values);
+                        break;
+                    }
+                    InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries }
+                        if retries < max_retries =>
+                    {
+                        retries += 1;
+                        perturbations[i] /= 2.0;
+                        model.set_memory(&memory_ref);
+                    }
+                    _ => {
+                        return Err(errors::SolverInternalError::InvalidJacobianError(
+                            model_error,
+                        ))
+                    }
+                },
             }
-        },
-        Err(model_error) => Err(errors::SolverInternalError::InvalidJacobianError(
-            model_error,
-        )),
+        }
+
+        let residuals_perturbation =
+            residuals_config.evaluate_update_residuals_with(&residuals_values, &update_methods);
+        diagonal[i] = (residuals_perturbation[i] - residuals_ref[i]) / perturbations[i];
+
+        model.set_memory(&memory_ref); // restart from reference state
     }
+    model.set_iteratives(&iteratives_ref);
+
+    Ok(diagonal)
 }
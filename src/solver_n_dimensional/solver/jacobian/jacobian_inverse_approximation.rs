@@ -1,51 +1,90 @@
 use super::JacobianMatrix;
+use super::QuasiNewtonUpdateStatus;
 
-use super::super::quasi_method_update_inv_jac;
+use super::super::quasi_method_update_inv_jac_in_place;
 use super::super::UpdateQuasiNewtonMethod;
-use super::super::{broyden_first_method_udpate_inv_jac, broyden_second_method_udpate_inv_jac};
+use super::super::{
+    broyden_first_method_update_inv_jac_in_place, broyden_second_method_update_inv_jac_in_place,
+};
 
+/// Update in place the inverse of the jacobian with a quasi-Newton method
+///
+/// The jacobian buffers are reused: no allocation is performed
+///
+/// See [QuasiNewtonUpdateStatus] for the handling of degenerate updates.
 pub fn approximate_inv_jacobian<D>(
     jacobian: &mut JacobianMatrix<D>,
     method: UpdateQuasiNewtonMethod,
     iteratives_step_size: &nalgebra::OVector<f64, D>,
     residuals_step_size: &nalgebra::OVector<f64, D>,
     residuals_values_current: &nalgebra::OVector<f64, D>,
-) where
+) -> QuasiNewtonUpdateStatus
+where
     D: nalgebra::DimMin<D, Output = D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
-    let inv_jac_next = match method {
-        UpdateQuasiNewtonMethod::BroydenFirstMethod => broyden_first_method_udpate_inv_jac(
-            jacobian.get_inverse().as_ref().unwrap(),
-            iteratives_step_size,
-            residuals_step_size,
-        ),
-        UpdateQuasiNewtonMethod::BroydenSecondMethod => broyden_second_method_udpate_inv_jac(
-            jacobian.get_inverse().as_ref().unwrap(),
-            iteratives_step_size,
-            residuals_step_size,
-        ),
-        UpdateQuasiNewtonMethod::GreenstadtFirstMethod => quasi_method_update_inv_jac(
-            jacobian.get_inverse().as_ref().unwrap(),
+    let (inv_jac, scratch, c) = jacobian.inverse_workspace();
+
+    let updated = match method {
+        UpdateQuasiNewtonMethod::BroydenFirstMethod => {
+            broyden_first_method_update_inv_jac_in_place(
+                inv_jac,
+                iteratives_step_size,
+                residuals_step_size,
+                scratch,
+            )
+        }
+        UpdateQuasiNewtonMethod::BroydenSecondMethod => {
+            broyden_second_method_update_inv_jac_in_place(
+                inv_jac,
+                iteratives_step_size,
+                residuals_step_size,
+                scratch,
+            )
+        }
+        UpdateQuasiNewtonMethod::GreenstadtFirstMethod => quasi_method_update_inv_jac_in_place(
+            inv_jac,
             iteratives_step_size,
             residuals_step_size,
             residuals_values_current,
+            scratch,
         ),
         UpdateQuasiNewtonMethod::GreenstadtSecondMethod => {
-            let c = jacobian.get_inverse().as_ref().unwrap().transpose()
-                * jacobian.get_inverse().as_ref().unwrap()
-                * residuals_step_size;
-            quasi_method_update_inv_jac(
-                jacobian.get_inverse().as_ref().unwrap(),
+            // c = H^T * H * y
+            let [hy, _] = scratch;
+            inv_jac.mul_to(residuals_step_size, hy);
+            inv_jac.tr_mul_to(hy, c);
+            quasi_method_update_inv_jac_in_place(
+                inv_jac,
                 iteratives_step_size,
                 residuals_step_size,
-                &c,
+                c,
+                scratch,
             )
         }
+        // see check_compatibility()
+        UpdateQuasiNewtonMethod::SchubertSparse => {
+            panic!("The Schubert method preserves the sparsity of the jacobian, it cannot be applied to its inverse")
+        }
     };
 
-    jacobian.update_inverse(inv_jac_next);
+    if !updated {
+        return QuasiNewtonUpdateStatus::Skipped;
+    }
+
+    if !jacobian
+        .get_inverse()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .all(|value| value.is_finite())
+    {
+        jacobian.force_jacobian_computation();
+        return QuasiNewtonUpdateStatus::NonFinite;
+    }
+
+    QuasiNewtonUpdateStatus::Updated
 }
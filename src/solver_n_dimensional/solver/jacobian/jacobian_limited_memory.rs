@@ -0,0 +1,189 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use super::QuasiNewtonUpdateStatus;
+use crate::errors;
+
+/// Steps `(s_j, y_j)` of the iteratives and of the residuals kept by a [LimitedMemoryInverse], from the oldest one
+type Steps<D> = VecDeque<(nalgebra::OVector<f64, D>, nalgebra::OVector<f64, D>)>;
+
+/// Inverse of the jacobian approximated by the second method of Broyden with a limited memory,
+/// see [crate::solver::QuasiNewtonMethod::LimitedMemoryBroyden]
+///
+/// The matrix is never formed: it is the inverse of a diagonal seed `H_0`
+/// corrected by the rank one updates of the last `memory` steps `(s_j, y_j)`:
+///
+/// H = H_0 + sum_j u_j * y_j^T,
+///
+/// with u_j = (s_j - H_{j} * y_j) / (y_j^T * y_j), `H_{j}` being built from `H_0` and the steps kept before `j`.
+///
+/// Once the memory is full, the oldest step is dropped before the new one is added,
+/// the corrections of the remaining steps being computed again from the seed.
+/// As long as no step has been dropped, the action of `H` is the one of the inverse jacobian
+/// updated by [crate::solver::broyden_second_method_udpate_inv_jac] from the same seed.
+///
+/// The storage is `O(memory * n)` and the application to a vector `O(memory * n)`.
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+///
+/// let diagonal = nalgebra::DVector::from_vec(vec![2.0, 4.0]);
+/// let mut limited_memory = nrf::solver::LimitedMemoryInverse::new(5, &diagonal).unwrap();
+///
+/// let s = nalgebra::DVector::from_vec(vec![0.1, -0.2]);
+/// let y = nalgebra::DVector::from_vec(vec![0.3, 0.5]);
+/// limited_memory.update(&s, &y);
+///
+/// let seed = nalgebra::DMatrix::from_diagonal(&diagonal.map(|value| 1.0 / value));
+/// let expected = nrf::solver::broyden_second_method_udpate_inv_jac(&seed, &s, &y);
+///
+/// let v = nalgebra::DVector::from_vec(vec![1.0, 3.0]);
+/// assert!((limited_memory.apply(&v) - expected * v).amax() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LimitedMemoryInverse<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    memory: usize,
+    inverse_diagonal: nalgebra::OVector<f64, D>,
+    steps: Steps<D>,
+    corrections: Vec<nalgebra::OVector<f64, D>>,
+}
+
+impl<D> LimitedMemoryInverse<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    /// Create the approximation seeded with the diagonal of the jacobian
+    ///
+    /// Returns an error if one of the values of the `diagonal` is zero or not finite
+    pub fn new(
+        memory: usize,
+        diagonal: &nalgebra::OVector<f64, D>,
+    ) -> Result<Self, errors::NonInvertibleJacobian> {
+        if !diagonal
+            .iter()
+            .all(|value| *value != 0.0 && value.is_finite())
+        {
+            return Err(errors::NonInvertibleJacobian);
+        }
+
+        Ok(LimitedMemoryInverse {
+            memory,
+            inverse_diagonal: diagonal.map(|value| 1.0 / value),
+            steps: VecDeque::with_capacity(memory),
+            corrections: Vec::with_capacity(memory),
+        })
+    }
+
+    /// Approximation with the inverse of its diagonal seed and the steps kept,
+    /// as given by [LimitedMemoryInverse::get_inverse_diagonal] and [LimitedMemoryInverse::get_steps]
+    ///
+    /// The corrections are computed again from the steps, with the same values as the updates.
+    pub(crate) fn from_steps(
+        memory: usize,
+        inverse_diagonal: nalgebra::OVector<f64, D>,
+        steps: Steps<D>,
+    ) -> Self {
+        let mut limited_memory = LimitedMemoryInverse {
+            memory,
+            inverse_diagonal,
+            corrections: Vec::with_capacity(memory),
+            steps,
+        };
+        for j in 0..limited_memory.steps.len() {
+            let correction = limited_memory.correction(j);
+            limited_memory.corrections.push(correction);
+        }
+        limited_memory
+    }
+
+    pub fn get_memory(&self) -> usize {
+        self.memory
+    }
+
+    pub(crate) fn get_inverse_diagonal(&self) -> &nalgebra::OVector<f64, D> {
+        &self.inverse_diagonal
+    }
+
+    pub(crate) fn get_steps(&self) -> &Steps<D> {
+        &self.steps
+    }
+
+    /// Number of steps kept
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// No step is kept, the approximation being the inverse of the diagonal seed
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Product of the approximated inverse of the jacobian with the vector `v`
+    pub fn apply(&self, v: &nalgebra::OVector<f64, D>) -> nalgebra::OVector<f64, D> {
+        self.apply_with_steps(v, self.corrections.len())
+    }
+
+    /// Product with the approximation built from the seed and the first `steps` steps kept
+    fn apply_with_steps(
+        &self,
+        v: &nalgebra::OVector<f64, D>,
+        steps: usize,
+    ) -> nalgebra::OVector<f64, D> {
+        let mut product = self.inverse_diagonal.component_mul(v);
+        for ((_, y), u) in self.steps.iter().zip(self.corrections.iter()).take(steps) {
+            product.axpy(y.dot(v), u, 1.0);
+        }
+        product
+    }
+
+    /// Correction of the step `j`, the previous ones being up to date
+    fn correction(&self, j: usize) -> nalgebra::OVector<f64, D> {
+        let (s, y) = &self.steps[j];
+        (s - self.apply_with_steps(y, j)) / y.norm_squared()
+    }
+
+    /// Add the step `s` of the iteratives and the step `y` of the residuals
+    ///
+    /// A zero residuals step leads to a skipped update.
+    /// If a correction is not finite, the approximation is no longer usable:
+    /// it is expected to be created again from a new seed.
+    pub fn update(
+        &mut self,
+        s: &nalgebra::OVector<f64, D>,
+        y: &nalgebra::OVector<f64, D>,
+    ) -> QuasiNewtonUpdateStatus {
+        // written to be false if any of the terms is NaN
+        let valid_denominator = y.norm_squared() > 0.0;
+        if !valid_denominator || self.memory == 0 {
+            return QuasiNewtonUpdateStatus::Skipped;
+        }
+
+        if self.steps.len() == self.memory {
+            self.steps.pop_front();
+            // the corrections depend on the previous steps
+            self.corrections.clear();
+        }
+        self.steps.push_back((s.clone(), y.clone()));
+        for j in self.corrections.len()..self.steps.len() {
+            let correction = self.correction(j);
+            self.corrections.push(correction);
+        }
+
+        if !self
+            .corrections
+            .iter()
+            .all(|correction| correction.iter().all(|value| value.is_finite()))
+        {
+            return QuasiNewtonUpdateStatus::NonFinite;
+        }
+
+        QuasiNewtonUpdateStatus::Updated
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::super::UpdateQuasiNewtonMethod;
+use super::QuasiNewtonUpdateStatus;
+
+/// Origin of the jacobian used by an iteration, see [crate::solver::RootFinder::get_jacobian_sources]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JacobianSource {
+    /// The jacobian has been provided by the model, see [crate::model::Model::get_jacobian]
+    AnalyticalModel,
+    /// The jacobian has been computed with finite differences
+    FiniteDifference,
+    /// The jacobian, or its inverse, has been updated with a quasi-Newton method
+    QuasiNewtonUpdate { method: UpdateQuasiNewtonMethod },
+    /// The jacobian of the previous iteration has been used again,
+    /// either by the `StationaryNewton` method or because the quasi-Newton update was skipped
+    Reused,
+    /// No jacobian has been used: the sweep of the nonlinear Gauss-Seidel method updates each iterative
+    /// from the derivative of its own residual, computed with finite differences
+    ScalarFiniteDifference,
+}
+
+impl fmt::Display for JacobianSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JacobianSource::AnalyticalModel => write!(f, "Analytical (model)"),
+            JacobianSource::FiniteDifference => write!(f, "Finite difference"),
+            JacobianSource::QuasiNewtonUpdate { method } => {
+                write!(f, "Quasi-Newton update ({})", method)
+            }
+            JacobianSource::Reused => write!(f, "Reused"),
+            JacobianSource::ScalarFiniteDifference => {
+                write!(f, "Scalar finite difference (Gauss-Seidel)")
+            }
+        }
+    }
+}
+
+/// State of the resolution method regarding the jacobian, written in the debug log at each iteration
+///
+/// Several states can follow each other within an iteration:
+/// a non-finite quasi-Newton update schedules the computation of the jacobian, which is performed right away.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MethodState {
+    /// The jacobian has been fully computed, by the model or with finite differences
+    FullComputation { source: JacobianSource },
+    /// A quasi-Newton update has been applied to the jacobian or to its inverse
+    UpdateApplied { method: UpdateQuasiNewtonMethod },
+    /// The quasi-Newton update has been skipped because of a degenerate denominator,
+    /// the previous matrix being kept
+    UpdateSkipped { method: UpdateQuasiNewtonMethod },
+    /// The quasi-Newton update produced non-finite values:
+    /// the computation of the jacobian is forced
+    RecomputationScheduled { method: UpdateQuasiNewtonMethod },
+    /// The jacobian of the previous iteration is used again without any update
+    Reused,
+}
+
+impl MethodState {
+    pub(crate) fn from_update_status(
+        method: UpdateQuasiNewtonMethod,
+        status: QuasiNewtonUpdateStatus,
+    ) -> Self {
+        match status {
+            QuasiNewtonUpdateStatus::Updated => MethodState::UpdateApplied { method },
+            QuasiNewtonUpdateStatus::Skipped => MethodState::UpdateSkipped { method },
+            QuasiNewtonUpdateStatus::NonFinite => MethodState::RecomputationScheduled { method },
+        }
+    }
+
+    /// Origin of the jacobian used by the step following this state
+    ///
+    /// A scheduled recomputation is followed by a [MethodState::FullComputation] within the same iteration
+    pub fn jacobian_source(&self) -> JacobianSource {
+        match self {
+            MethodState::FullComputation { source } => *source,
+            MethodState::UpdateApplied { method } => {
+                JacobianSource::QuasiNewtonUpdate { method: *method }
+            }
+            MethodState::UpdateSkipped { .. }
+            | MethodState::RecomputationScheduled { .. }
+            | MethodState::Reused => JacobianSource::Reused,
+        }
+    }
+}
+
+impl fmt::Display for MethodState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MethodState::FullComputation { source } => write!(f, "Full computation ({})", source),
+            MethodState::UpdateApplied { method } => write!(f, "Update applied ({})", method),
+            MethodState::UpdateSkipped { method } => write!(
+                f,
+                "Update skipped ({}), its denominator is degenerate compared to the jacobian norm",
+                method
+            ),
+            MethodState::RecomputationScheduled { method } => write!(
+                f,
+                "Recomputation scheduled, the update ({}) produced non-finite values",
+                method
+            ),
+            MethodState::Reused => write!(f, "Jacobian reused"),
+        }
+    }
+}
+
+/// Number of iterations per jacobian source, in the order of their first use
+pub(crate) fn count_jacobian_sources(sources: &[JacobianSource]) -> Vec<(JacobianSource, usize)> {
+    let mut counts: Vec<(JacobianSource, usize)> = Vec::new();
+    for source in sources {
+        match counts.iter_mut().find(|(counted, _)| counted == source) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((*source, 1)),
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn method_state_from_update_status() {
+        let method = UpdateQuasiNewtonMethod::BroydenFirstMethod;
+        let cases = [
+            (
+                QuasiNewtonUpdateStatus::Updated,
+                MethodState::UpdateApplied { method },
+                JacobianSource::QuasiNewtonUpdate { method },
+            ),
+            (
+                QuasiNewtonUpdateStatus::Skipped,
+                MethodState::UpdateSkipped { method },
+                JacobianSource::Reused,
+            ),
+            (
+                QuasiNewtonUpdateStatus::NonFinite,
+                MethodState::RecomputationScheduled { method },
+                JacobianSource::Reused,
+            ),
+        ];
+
+        for (status, method_state, source) in cases {
+            assert_eq!(
+                MethodState::from_update_status(method, status),
+                method_state
+            );
+            assert_eq!(method_state.jacobian_source(), source);
+        }
+    }
+
+    #[test]
+    fn method_state_display() {
+        let method = UpdateQuasiNewtonMethod::BroydenFirstMethod;
+        assert_eq!(
+            MethodState::FullComputation {
+                source: JacobianSource::FiniteDifference
+            }
+            .to_string(),
+            "Full computation (Finite difference)"
+        );
+        assert_eq!(
+            MethodState::UpdateSkipped { method }.to_string(),
+            "Update skipped (Broyden First Method), its denominator is degenerate compared to the jacobian norm"
+        );
+        assert_eq!(
+            MethodState::RecomputationScheduled { method }.to_string(),
+            "Recomputation scheduled, the update (Broyden First Method) produced non-finite values"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Structurally nonzero entries of the jacobian
+///
+/// The entries outside of the pattern are declared zero whatever the values of the iteratives:
+/// the residual `i` does not depend on the iterative `j`.
+/// The pattern is used by the [crate::solver::UpdateQuasiNewtonMethod::SchubertSparse] update,
+/// see [crate::solver::SolverParameters::set_sparsity_pattern].
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::SparsityPattern;
+///
+/// let tridiagonal = SparsityPattern::banded(4, 1, 1);
+/// assert_eq!(tridiagonal.get_row(0), &[0, 1]);
+/// assert_eq!(tridiagonal.get_row(2), &[1, 2, 3]);
+/// assert!(!tridiagonal.contains(0, 2));
+///
+/// let pattern = SparsityPattern::from_entries(2, &[(1, 0), (0, 0), (1, 1)]);
+/// assert_eq!(pattern.get_row(1), &[0, 1]);
+/// assert_eq!(pattern.get_nonzeros(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsityPattern {
+    // sorted columns of the structurally nonzero entries of each row
+    rows: Vec<Vec<usize>>,
+}
+
+impl SparsityPattern {
+    /// Pattern of a square jacobian of size `size` from its `(row, column)` structurally nonzero entries
+    ///
+    /// The entries can be given in any order, the duplicates being ignored.
+    ///
+    /// # Panics
+    ///
+    /// If a row or a column is not lower than the size
+    pub fn from_entries(size: usize, entries: &[(usize, usize)]) -> Self {
+        let mut rows = vec![Vec::new(); size];
+        for &(row, column) in entries {
+            if row >= size || column >= size {
+                panic!(
+                    "The entry ({}, {}) of the sparsity pattern is out of range, the size is {}",
+                    row, column, size
+                );
+            }
+            rows[row].push(column);
+        }
+        for columns in rows.iter_mut() {
+            columns.sort_unstable();
+            columns.dedup();
+        }
+        SparsityPattern { rows }
+    }
+
+    /// Banded pattern of a square jacobian of size `size`,
+    /// with `lower` diagonals below the main diagonal and `upper` diagonals above it
+    ///
+    /// A tridiagonal jacobian is given by `SparsityPattern::banded(size, 1, 1)`
+    pub fn banded(size: usize, lower: usize, upper: usize) -> Self {
+        let rows = (0..size)
+            .map(|row| (row.saturating_sub(lower)..size.min(row + upper + 1)).collect())
+            .collect();
+        SparsityPattern { rows }
+    }
+
+    /// Size of the jacobian described by the pattern
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Sorted columns of the structurally nonzero entries of the `row`
+    pub fn get_row(&self, row: usize) -> &[usize] {
+        &self.rows[row]
+    }
+
+    /// The entry is structurally nonzero
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        self.rows[row].binary_search(&column).is_ok()
+    }
+
+    /// Number of structurally nonzero entries
+    pub fn get_nonzeros(&self) -> usize {
+        self.rows.iter().map(|columns| columns.len()).sum()
+    }
+}
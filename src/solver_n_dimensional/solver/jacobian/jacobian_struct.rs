@@ -1,18 +1,290 @@
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
-fn compute_inverse<D>(
+use super::InaccurateColumn;
+use super::SparsityPattern;
+
+/// Inversion of `matrix` into `inverse` without any allocation
+///
+/// A matrix of size 1 is inverted with a division,
+/// giving the same result as the LU decomposition of [compute_inverse_lu_in_place]:
+/// the LU decomposition of a scalar is the scalar itself and the triangular solves reduce to its reciprocal.
+fn compute_inverse_in_place<D>(
+    matrix: &nalgebra::OMatrix<f64, D, D>,
+    lu: &mut nalgebra::OMatrix<f64, D, D>,
+    inverse: &mut nalgebra::OMatrix<f64, D, D>,
+) -> Result<(), crate::errors::NonInvertibleJacobian>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    if matrix.nrows() == 1 {
+        compute_scalar_inverse(matrix, inverse)
+    } else {
+        compute_inverse_lu_in_place(matrix, lu, inverse)
+    }
+}
+
+/// Inversion of a matrix of size 1
+fn compute_scalar_inverse<D>(
+    matrix: &nalgebra::OMatrix<f64, D, D>,
+    inverse: &mut nalgebra::OMatrix<f64, D, D>,
+) -> Result<(), crate::errors::NonInvertibleJacobian>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let value = matrix[(0, 0)];
+    if value == 0.0 {
+        return Err(crate::errors::NonInvertibleJacobian);
+    }
+    inverse[(0, 0)] = 1.0 / value;
+    Ok(())
+}
+
+/// Inversion of `matrix` into `inverse` without any allocation
+///
+/// The `lu` matrix is used as workspace, its values are overwritten.
+/// The algorithm is the one of `nalgebra::linalg::try_invert_to`
+/// (LU decomposition with partial pivoting),
+/// giving the same results as `matrix.lu().try_inverse()`
+fn compute_inverse_lu_in_place<D>(
     matrix: &nalgebra::OMatrix<f64, D, D>,
-) -> Result<nalgebra::OMatrix<f64, D, D>, crate::errors::NonInvertibleJacobian>
+    lu: &mut nalgebra::OMatrix<f64, D, D>,
+    inverse: &mut nalgebra::OMatrix<f64, D, D>,
+) -> Result<(), crate::errors::NonInvertibleJacobian>
 where
     D: nalgebra::DimMin<D, Output = D>,
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
-    let lu_jac = matrix.to_owned().lu();
+    lu.copy_from(matrix);
+    inverse.fill_with_identity();
+
+    for i in 0..lu.nrows() {
+        let piv = lu.view_range(i.., i).icamax() + i;
+        let diag = lu[(piv, i)];
+
+        if diag == 0.0 {
+            return Err(crate::errors::NonInvertibleJacobian);
+        }
 
-    match lu_jac.try_inverse() {
-        Some(inv_jac) => Ok(inv_jac),
-        None => Err(crate::errors::NonInvertibleJacobian),
+        if piv != i {
+            inverse.swap_rows(i, piv);
+            lu.columns_range_mut(..i).swap_rows(i, piv);
+            nalgebra::linalg::gauss_step_swap(lu, diag, i, piv);
+        } else {
+            nalgebra::linalg::gauss_step(lu, diag, i);
+        }
+    }
+
+    let _ = lu.solve_lower_triangular_with_diag_mut(inverse, 1.0);
+    if lu.solve_upper_triangular_mut(inverse) {
+        Ok(())
+    } else {
+        Err(crate::errors::NonInvertibleJacobian)
+    }
+}
+
+/// Estimate of the condition number of `matrix`,
+/// as the ratio of the largest to the smallest absolute pivot of its LU decomposition with partial pivoting
+///
+/// It is infinite if one of the pivots is null, the matrix being singular
+fn estimate_condition<D>(matrix: &nalgebra::OMatrix<f64, D, D>) -> f64
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    let pivots = matrix.clone().lu().u().diagonal();
+    let smallest_pivot = pivots.amin();
+    if smallest_pivot == 0.0 {
+        f64::INFINITY
+    } else {
+        pivots.amax() / smallest_pivot
+    }
+}
+
+/// Reuse the storage of `buffer` if it has the expected size,
+/// otherwise allocate a new matrix
+fn matrix_from_buffer<D>(
+    buffer: Option<nalgebra::OMatrix<f64, D, D>>,
+    shape: D,
+) -> nalgebra::OMatrix<f64, D, D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    match buffer {
+        Some(matrix) if matrix.nrows() == shape.value() => matrix,
+        _ => super::super::super::omatrix_zeros_from_shape(shape),
+    }
+}
+
+/// Default number of significant digits of the display of the jacobian
+pub(crate) const DEFAULT_SIGNIFICANT_DIGITS: usize = 3;
+/// Default maximum size of a jacobian displayed in full
+pub(crate) const DEFAULT_MAX_FULL_SIZE: usize = 10;
+/// Default number of off-diagonal entries displayed for a jacobian too large to be displayed in full
+pub(crate) const DEFAULT_LARGEST_ENTRIES: usize = 10;
+
+const MIN_INDEX_WIDTH: usize = 3;
+
+/// Value in scientific notation with a fixed width
+fn format_value(value: f64, significant_digits: usize) -> String {
+    // sign, mantissa, exponent with its sign and up to 3 digits, and a leading space
+    let width = significant_digits + 8;
+    format!(
+        "{:>width$.precision$e}",
+        value,
+        width = width,
+        precision = significant_digits - 1
+    )
+}
+
+fn index_width(size: usize) -> usize {
+    size.saturating_sub(1)
+        .to_string()
+        .len()
+        .max(MIN_INDEX_WIDTH)
+}
+
+/// Matrix with aligned columns and row and column indices,
+/// or its diagonal and its largest off-diagonal entries if it has more than `max_full_size` rows
+fn format_matrix<D>(
+    matrix: &nalgebra::OMatrix<f64, D, D>,
+    significant_digits: usize,
+    max_full_size: usize,
+    largest_entries: usize,
+) -> String
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let size = matrix.nrows();
+    let iw = index_width(size);
+    let vw = significant_digits + 8;
+    let mut content = String::from("\n");
+
+    if size <= max_full_size {
+        content.push_str(&format!("{:>iw$} |", "", iw = iw));
+        for j in 0..size {
+            content.push_str(&format!("{:>vw$}", j, vw = vw));
+        }
+        content.push('\n');
+        content.push_str(&format!(
+            "{}+{}\n",
+            "-".repeat(iw + 1),
+            "-".repeat(size * vw)
+        ));
+        for i in 0..size {
+            content.push_str(&format!("{:>iw$} |", i, iw = iw));
+            for j in 0..size {
+                content.push_str(&format_value(matrix[(i, j)], significant_digits));
+            }
+            content.push('\n');
+        }
+    } else {
+        content.push_str(&format!(
+            "Matrix of size {}x{}, only its diagonal and its {} largest off-diagonal entries are displayed\n\n",
+            size, size, largest_entries
+        ));
+
+        content.push_str("Diagonal:\n");
+        content.push_str(&format!(
+            "{:>iw$} |{:>vw$}\n",
+            "Id",
+            "Value",
+            iw = iw,
+            vw = vw
+        ));
+        content.push_str(&format!("{}+{}\n", "-".repeat(iw + 1), "-".repeat(vw)));
+        for i in 0..size {
+            content.push_str(&format!(
+                "{:>iw$} |{}\n",
+                i,
+                format_value(matrix[(i, i)], significant_digits),
+                iw = iw
+            ));
+        }
+
+        let mut off_diagonal: Vec<(usize, usize)> = (0..size)
+            .flat_map(|i| (0..size).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j)
+            .collect();
+        // stable sort: the ties are kept in row-major order
+        off_diagonal.sort_by(|a, b| matrix[*b].abs().total_cmp(&matrix[*a].abs()));
+
+        content.push_str("\nLargest off-diagonal entries:\n");
+        content.push_str(&format!(
+            "{:>iw$} | {:>iw$} |{:>vw$}\n",
+            "Row",
+            "Col",
+            "Value",
+            iw = iw,
+            vw = vw
+        ));
+        content.push_str(&format!(
+            "{}+{}+{}\n",
+            "-".repeat(iw + 1),
+            "-".repeat(iw + 2),
+            "-".repeat(vw)
+        ));
+        for (i, j) in off_diagonal.into_iter().take(largest_entries) {
+            content.push_str(&format!(
+                "{:>iw$} | {:>iw$} |{}\n",
+                i,
+                j,
+                format_value(matrix[(i, j)], significant_digits),
+                iw = iw
+            ));
+        }
+    }
+
+    content.push('\n');
+    content
+}
+
+/// Buffers reused across iterations and resolutions
+///
+/// They are allocated at first use
+/// and only reallocated if the problem size changes
+struct JacobianBuffers<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    lu: nalgebra::OMatrix<f64, D, D>,
+    vectors: [nalgebra::OVector<f64, D>; 2],
+    c: nalgebra::OVector<f64, D>,
+    // storage of an invalidated inverse
+    inverse: Option<nalgebra::OMatrix<f64, D, D>>,
+}
+
+impl<D> JacobianBuffers<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    fn new(shape: D) -> Self {
+        JacobianBuffers {
+            lu: super::super::super::omatrix_zeros_from_shape(shape),
+            vectors: [
+                super::super::super::ovector_zeros_from_shape(shape),
+                super::super::super::ovector_zeros_from_shape(shape),
+            ],
+            c: super::super::super::ovector_zeros_from_shape(shape),
+            inverse: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.c.len()
     }
 }
 
@@ -26,6 +298,19 @@ where
     inverse: Option<nalgebra::OMatrix<f64, D, D>>,
     compute_jacobian_at_next_iteration: bool,
     is_current_jacobian_approximated: bool,
+    inverse_required: bool,
+    buffers: Option<JacobianBuffers<D>>,
+    perturbations: Option<nalgebra::OVector<f64, D>>,
+    perturbations_out_of_bounds: Vec<usize>,
+    inaccurate_columns: Vec<InaccurateColumn>,
+    sparsity_pattern: Option<SparsityPattern>,
+    // (ignored residual, fixed iterative) pairs, see `set_fixed_rows()`
+    fixed_rows: Vec<(usize, usize)>,
+    structural_reduction: bool,
+    // (null row, null column) pairs removed from the system, see `set_structural_reduction()`
+    reduced_rows: Vec<(usize, usize)>,
+    // of the last jacobian whose inversion failed, see `get_condition_estimate()`
+    condition_estimate: Option<f64>,
 }
 
 impl<D> Default for JacobianMatrix<D>
@@ -51,7 +336,51 @@ where
             inverse: None,
             compute_jacobian_at_next_iteration: true,
             is_current_jacobian_approximated: false,
+            inverse_required: true,
+            buffers: None,
+            perturbations: None,
+            perturbations_out_of_bounds: Vec::new(),
+            inaccurate_columns: Vec::new(),
+            sparsity_pattern: None,
+            fixed_rows: Vec::new(),
+            structural_reduction: false,
+            reduced_rows: Vec::new(),
+            condition_estimate: None,
+        }
+    }
+
+    /// Reset the jacobian to its initial state, before a new resolution
+    ///
+    /// The allocated storage is kept to be reused by the next resolution
+    pub fn reset(&mut self) {
+        self.invalidate_jacobian();
+        self.compute_jacobian_at_next_iteration = true;
+        self.is_current_jacobian_approximated = false;
+        self.perturbations = None;
+        self.perturbations_out_of_bounds.clear();
+        self.inaccurate_columns.clear();
+        self.reduced_rows.clear();
+        self.condition_estimate = None;
+    }
+
+    /// Keep the current jacobian for a new resolution, resetting the other elements
+    ///
+    /// The jacobian is kept if it has been computed or approximated, is not to be computed again,
+    /// and has its inverse if required. It is then considered as approximated.
+    /// Otherwise, the jacobian is reset and `false` is returned.
+    pub fn reuse(&mut self) -> bool {
+        let reusable = self.matrix.is_some()
+            && !self.compute_jacobian_at_next_iteration
+            && (!self.inverse_required || self.inverse.is_some());
+        if !reusable {
+            self.reset();
+            return false;
         }
+        self.is_current_jacobian_approximated = true;
+        self.perturbations = None;
+        self.perturbations_out_of_bounds.clear();
+        self.inaccurate_columns.clear();
+        true
     }
 
     pub fn force_jacobian_computation(&mut self) {
@@ -66,20 +395,190 @@ where
         self.is_current_jacobian_approximated
     }
 
+    /// Set if the inverse has to be computed when the jacobian is updated, the default being `true`
+    ///
+    /// Without the inverse, the jacobian is only used through matrix-vector products,
+    /// see [crate::solver::LinearSolver].
+    /// Its invertibility is then not checked
+    pub fn set_inverse_required(&mut self, inverse_required: bool) {
+        self.inverse_required = inverse_required;
+    }
+
+    pub fn is_inverse_required(&self) -> bool {
+        self.inverse_required
+    }
+
+    /// Set the structurally nonzero entries of the jacobian, the default being `None`
+    ///
+    /// The pattern is required by the [crate::solver::UpdateQuasiNewtonMethod::SchubertSparse] update,
+    /// it is kept by `reset()`
+    pub fn set_sparsity_pattern(&mut self, sparsity_pattern: Option<SparsityPattern>) {
+        self.sparsity_pattern = sparsity_pattern;
+    }
+
+    pub fn get_sparsity_pattern(&self) -> Option<&SparsityPattern> {
+        self.sparsity_pattern.as_ref()
+    }
+
+    /// Set the rows of the ignored residuals to be replaced by the unit rows of the fixed iteratives
+    ///
+    /// Each pair `(residual, iterative)` replaces the row of the ignored residual, null with the update residuals,
+    /// by the row selecting the fixed iterative: the linear systems then give a null step to the fixed iterative
+    /// and are, for the other iteratives, the systems reduced to the other residuals.
+    /// The quasi-Newton updates preserve these rows, the ignored residual and the step of the fixed iterative being null.
+    ///
+    /// The pairs are kept by `reset()`
+    pub fn set_fixed_rows(&mut self, fixed_rows: Vec<(usize, usize)>) {
+        self.fixed_rows = fixed_rows;
+    }
+
+    pub fn get_fixed_rows(&self) -> &[(usize, usize)] {
+        &self.fixed_rows
+    }
+
+    /// Set if the null rows and columns of a computed jacobian are removed from the system,
+    /// the default being `false`, see [crate::solver::StructuralSingularityPolicy]
+    ///
+    /// Each null row is replaced by the unit row selecting a null column, as the rows of `set_fixed_rows()`,
+    /// if there are as many null rows as null columns.
+    /// The pairs are given by `get_reduced_rows()` and cleared by `reset()`
+    pub fn set_structural_reduction(&mut self, structural_reduction: bool) {
+        self.structural_reduction = structural_reduction;
+    }
+
+    /// (null row, null column) pairs removed from the system, see `set_structural_reduction()`
+    pub fn get_reduced_rows(&self) -> &[(usize, usize)] {
+        &self.reduced_rows
+    }
+
+    /// Null rows and null columns of a computed jacobian, in increasing order
+    ///
+    /// The rows replaced by `set_fixed_rows()` or by a structural reduction are not considered,
+    /// neither are the columns they select
+    fn structural_zeros(&self, matrix: &nalgebra::OMatrix<f64, D, D>) -> (Vec<usize>, Vec<usize>) {
+        let replaced_rows: Vec<usize> = self
+            .fixed_rows
+            .iter()
+            .chain(&self.reduced_rows)
+            .map(|&(residual, _)| residual)
+            .collect();
+        let replaced_columns: Vec<usize> = self
+            .fixed_rows
+            .iter()
+            .chain(&self.reduced_rows)
+            .map(|&(_, iterative)| iterative)
+            .collect();
+        let kept_rows: Vec<usize> = (0..matrix.nrows())
+            .filter(|i| !replaced_rows.contains(i))
+            .collect();
+
+        let zero_rows = kept_rows
+            .iter()
+            .copied()
+            .filter(|&i| matrix.row(i).iter().all(|&value| value == 0.0))
+            .collect();
+        let zero_columns = (0..matrix.ncols())
+            .filter(|j| !replaced_columns.contains(j))
+            .filter(|&j| kept_rows.iter().all(|&i| matrix[(i, j)] == 0.0))
+            .collect();
+        (zero_rows, zero_columns)
+    }
+
+    /// Update the jacobian with a computed value, after checking its structure
+    ///
+    /// A jacobian with a null row or a null column is singular:
+    /// it is removed from the system if required by `set_structural_reduction()`,
+    /// otherwise the jacobian is invalidated and the error gives the null rows and columns.
+    pub(super) fn update_jacobian_with_computed_value<M>(
+        &mut self,
+        matrix: nalgebra::OMatrix<f64, D, D>,
+    ) -> Result<(), crate::errors::SolverInternalError<M, D>>
+    where
+        M: crate::model::Model<D>,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    {
+        let (zero_rows, zero_cols) = self.structural_zeros(&matrix);
+        if !(zero_rows.is_empty() && zero_cols.is_empty()) {
+            if self.structural_reduction && zero_rows.len() == zero_cols.len() {
+                self.reduced_rows
+                    .extend(zero_rows.into_iter().zip(zero_cols));
+            } else {
+                self.invalidate_jacobian();
+                return Err(
+                    crate::errors::SolverInternalError::StructurallySingularJacobian {
+                        zero_rows,
+                        zero_cols,
+                    },
+                );
+            }
+        }
+        self.update_jacobian_with_exact_value(matrix).map_err(
+            |crate::errors::NonInvertibleJacobian| {
+                crate::errors::SolverInternalError::InvalidJacobianInverseError
+            },
+        )
+    }
+
+    /// Replace the rows of the ignored residuals and of the structural reduction,
+    /// see `set_fixed_rows()` and `set_structural_reduction()`
+    fn replace_fixed_rows(&mut self) {
+        if let Some(matrix) = self.matrix.as_mut() {
+            for &(residual, iterative) in self.fixed_rows.iter().chain(&self.reduced_rows) {
+                matrix.row_mut(residual).fill(0.0);
+                matrix[(residual, iterative)] = 1.0;
+            }
+        }
+    }
+
+    /// Get the buffers, allocating them if required for a problem of size `shape`
+    fn buffers(&mut self, shape: D) -> &mut JacobianBuffers<D> {
+        let reusable = matches!(&self.buffers, Some(buffers) if buffers.len() == shape.value());
+        if !reusable {
+            self.buffers = Some(JacobianBuffers::new(shape));
+        }
+        self.buffers.as_mut().unwrap()
+    }
+
     /// When updating the jacobian,
     /// the inverse has to be recomputed
     fn update_jacobian(
         &mut self,
         matrix: nalgebra::OMatrix<f64, D, D>,
     ) -> Result<(), crate::errors::NonInvertibleJacobian> {
-        match compute_inverse(&matrix) {
-            Ok(inverse_matrix) => {
-                self.inverse = Some(inverse_matrix);
-                self.matrix = Some(matrix);
+        let shape = matrix.shape_generic().0;
+        self.matrix = Some(matrix);
+        self.replace_fixed_rows();
+
+        if !self.inverse_required {
+            self.buffers(shape);
+            self.compute_jacobian_at_next_iteration = false;
+            return Ok(());
+        }
+
+        let previous_inverse = self.inverse.take();
+
+        let buffers = self.buffers(shape);
+        let inverse = previous_inverse.or_else(|| buffers.inverse.take());
+        self.inverse = Some(matrix_from_buffer(inverse, shape));
+
+        self.update_inverse_from_jacobian()
+    }
+
+    /// Recompute the inverse from the current jacobian, in place
+    fn update_inverse_from_jacobian(&mut self) -> Result<(), crate::errors::NonInvertibleJacobian> {
+        let buffers = self.buffers.as_mut().unwrap();
+        match compute_inverse_in_place(
+            self.matrix.as_ref().unwrap(),
+            &mut buffers.lu,
+            self.inverse.as_mut().unwrap(),
+        ) {
+            Ok(()) => {
                 self.compute_jacobian_at_next_iteration = false;
+                self.condition_estimate = None;
                 Ok(())
             }
             Err(_) => {
+                self.condition_estimate = Some(estimate_condition(self.matrix.as_ref().unwrap()));
                 self.invalidate_jacobian();
                 Err(crate::errors::NonInvertibleJacobian)
             }
@@ -111,6 +610,69 @@ where
         self.is_current_jacobian_approximated = true;
     }
 
+    /// Give access to the jacobian and its inverse, if computed, along with workspace vectors
+    /// and the sparsity pattern, if declared, to update the jacobian in place
+    ///
+    /// The inverse must be recomputed afterwards
+    /// with `update_inverse_from_approximated_jacobian()`
+    #[allow(clippy::type_complexity)]
+    pub(super) fn jacobian_workspace(
+        &mut self,
+    ) -> (
+        &mut nalgebra::OMatrix<f64, D, D>,
+        Option<&nalgebra::OMatrix<f64, D, D>>,
+        &mut [nalgebra::OVector<f64, D>; 2],
+        &mut nalgebra::OVector<f64, D>,
+        Option<&SparsityPattern>,
+    ) {
+        let buffers = self.buffers.as_mut().unwrap();
+        (
+            self.matrix.as_mut().unwrap(),
+            self.inverse.as_ref(),
+            &mut buffers.vectors,
+            &mut buffers.c,
+            self.sparsity_pattern.as_ref(),
+        )
+    }
+
+    /// Recompute the inverse once the jacobian has been approximated in place
+    pub(super) fn update_inverse_from_approximated_jacobian(
+        &mut self,
+    ) -> Result<(), crate::errors::NonInvertibleJacobian> {
+        self.is_current_jacobian_approximated = true;
+        self.replace_fixed_rows();
+        if !self.inverse_required {
+            self.compute_jacobian_at_next_iteration = false;
+            return Ok(());
+        }
+        self.update_inverse_from_jacobian()
+    }
+
+    /// Give access to the inverse along with workspace vectors,
+    /// to update the inverse in place
+    ///
+    /// The jacobian is not up to date anymore with its inverse
+    #[allow(clippy::type_complexity)]
+    pub(super) fn inverse_workspace(
+        &mut self,
+    ) -> (
+        &mut nalgebra::OMatrix<f64, D, D>,
+        &mut [nalgebra::OVector<f64, D>; 2],
+        &mut nalgebra::OVector<f64, D>,
+    ) {
+        self.matrix = None;
+        self.is_current_jacobian_approximated = true;
+
+        let shape = self.inverse.as_ref().unwrap().shape_generic().0;
+        self.buffers(shape);
+        let buffers = self.buffers.as_mut().unwrap();
+        (
+            self.inverse.as_mut().unwrap(),
+            &mut buffers.vectors,
+            &mut buffers.c,
+        )
+    }
+
     /// Need to have Some and None for the inverse ?
     /// it is always valid !
     pub fn get_inverse(&self) -> &Option<nalgebra::OMatrix<f64, D, D>> {
@@ -120,29 +682,93 @@ where
     pub fn get_jacobian(&self) -> &Option<nalgebra::OMatrix<f64, D, D>> {
         &self.matrix
     }
-    /// Invalidate a jacobian
-    /// For example, if there is an error computing it
-    pub fn invalidate_jacobian(&mut self) {
-        self.matrix = None;
-        self.inverse = None;
+
+    /// Estimate of the condition number of the last jacobian whose inversion failed,
+    /// as the ratio of the largest to the smallest absolute pivot of its LU decomposition
+    ///
+    /// It is infinite if one of the pivots is null, `None` if no inversion has failed since the last success
+    pub fn get_condition_estimate(&self) -> Option<f64> {
+        self.condition_estimate
     }
-}
 
-impl<D> fmt::Display for JacobianMatrix<D>
-where
-    D: nalgebra::DimMin<D, Output = D>,
-    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
-    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// Perturbations used by the last evaluation of the jacobian per finite difference
+    pub fn get_perturbations(&self) -> &Option<nalgebra::OVector<f64, D>> {
+        &self.perturbations
+    }
+
+    /// Iteratives whose perturbed value was out of their bounds
+    /// during the last evaluation of the jacobian per finite difference
+    pub fn get_perturbations_out_of_bounds(&self) -> &[usize] {
+        &self.perturbations_out_of_bounds
+    }
+
+    /// Columns for which the model returned inaccurate values
+    /// during the last evaluation of the jacobian per finite difference,
+    /// see [crate::solver::InaccurateJacobianPolicy]
+    pub fn get_inaccurate_columns(&self) -> &[InaccurateColumn] {
+        &self.inaccurate_columns
+    }
+
+    pub(super) fn set_perturbations(
+        &mut self,
+        perturbations: nalgebra::OVector<f64, D>,
+        perturbations_out_of_bounds: Vec<usize>,
+        inaccurate_columns: Vec<InaccurateColumn>,
+    ) {
+        self.perturbations = Some(perturbations);
+        self.perturbations_out_of_bounds = perturbations_out_of_bounds;
+        self.inaccurate_columns = inaccurate_columns;
+    }
+
+    /// Format the jacobian and its inverse for display
+    ///
+    /// The values are written in scientific notation with `significant_digits` significant digits,
+    /// with the row and column indices.
+    ///
+    /// For matrices with more than `max_full_size` rows,
+    /// only the diagonal and the `largest_entries` off-diagonal entries
+    /// of largest absolute values are written, along with their indices.
+    ///
+    /// # Examples
+    /// ```
+    /// use newton_rootfinder as nrf;
+    ///
+    /// let mut jacobian = nrf::solver::JacobianMatrix::new();
+    /// jacobian
+    ///     .update_jacobian_with_exact_value(nalgebra::DMatrix::from_vec(2, 2, vec![2.0, 0.0, 1.0, 4.0]))
+    ///     .unwrap();
+    ///
+    /// let content = jacobian.format_with(3, 10, 5);
+    /// assert!(content.contains("  0 |     2.00e0     1.00e0"));
+    /// ```
+    pub fn format_with(
+        &self,
+        significant_digits: usize,
+        max_full_size: usize,
+        largest_entries: usize,
+    ) -> String {
+        if significant_digits == 0 {
+            panic!("The number of significant digits must be strictly positive");
+        }
+
         let mut content = String::from("Jacobian matrix\n");
         content.push_str("=================\n\n");
 
         match &self.matrix {
             Some(jac) => {
-                content.push_str("Jacobian up to date with its inverse\n\n");
+                if self.inverse_required {
+                    content.push_str("Jacobian up to date with its inverse\n\n");
+                } else {
+                    content
+                        .push_str("Inverse of the jacobian not required by the linear solver\n\n");
+                }
                 content.push_str("Jacobian:\n");
-                content.push_str(&jac.to_string());
+                content.push_str(&format_matrix(
+                    jac,
+                    significant_digits,
+                    max_full_size,
+                    largest_entries,
+                ));
             }
             None => {
                 content.push_str("Jacobian not up to date with its inverse.\n");
@@ -150,16 +776,77 @@ where
             }
         }
 
-        content.push_str("Inverse of the jacobian Matrix:\n");
+        if self.inverse_required {
+            content.push_str("Inverse of the jacobian Matrix:\n");
+
+            match &self.inverse {
+                Some(inv) => content.push_str(&format_matrix(
+                    inv,
+                    significant_digits,
+                    max_full_size,
+                    largest_entries,
+                )),
+                None => content.push_str("Inverse jacobian matrix not yet computed"),
+            }
 
-        match &self.inverse {
-            Some(inv) => content.push_str(&inv.to_string()),
-            None => content.push_str("Inverse jacobian matrix not yet computed"),
+            content.push('\n');
         }
 
-        content.push('\n');
+        content
+    }
+
+    /// Restore the state of a jacobian, see [crate::solver::SolverSnapshot]
+    pub(crate) fn restore(
+        &mut self,
+        matrix: Option<nalgebra::OMatrix<f64, D, D>>,
+        inverse: Option<nalgebra::OMatrix<f64, D, D>>,
+        compute_jacobian_at_next_iteration: bool,
+        is_current_jacobian_approximated: bool,
+    ) {
+        if let Some(shape) = matrix
+            .as_ref()
+            .or(inverse.as_ref())
+            .map(|matrix| matrix.shape_generic().0)
+        {
+            self.buffers(shape);
+        }
+        self.matrix = matrix;
+        self.inverse = inverse;
+        self.compute_jacobian_at_next_iteration = compute_jacobian_at_next_iteration;
+        self.is_current_jacobian_approximated = is_current_jacobian_approximated;
+    }
 
-        write!(f, "{}", content)
+    /// Invalidate a jacobian
+    /// For example, if there is an error computing it
+    pub fn invalidate_jacobian(&mut self) {
+        self.matrix = None;
+        if let Some(inverse) = self.inverse.take() {
+            if let Some(buffers) = self.buffers.as_mut() {
+                buffers.inverse = Some(inverse);
+            }
+        }
+    }
+}
+
+impl<D> fmt::Display for JacobianMatrix<D>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    /// The number of significant digits can be set with the precision of the formatter,
+    /// for example `format!("{:.5}", jacobian)`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let significant_digits = f.precision().unwrap_or(DEFAULT_SIGNIFICANT_DIGITS);
+        write!(
+            f,
+            "{}",
+            self.format_with(
+                significant_digits,
+                DEFAULT_MAX_FULL_SIZE,
+                DEFAULT_LARGEST_ENTRIES
+            )
+        )
     }
 }
 
@@ -173,6 +860,12 @@ where
         f.debug_struct("Jacobian matrix")
             .field("Matrix", &self.matrix)
             .field("Matrix Inverse", &self.inverse)
+            .field("Finite difference perturbations", &self.perturbations)
+            .field(
+                "Perturbations out of bounds",
+                &self.perturbations_out_of_bounds,
+            )
+            .field("Inaccurate columns", &self.inaccurate_columns)
             .field(
                 "Compute jacobian at next iteration: ",
                 &self.compute_jacobian_at_next_iteration,
@@ -181,6 +874,136 @@ where
                 "Is current jacobian approximated: ",
                 &self.is_current_jacobian_approximated,
             )
+            .field("Is inverse required: ", &self.inverse_required)
+            .field("Fixed rows", &self.fixed_rows)
+            .field("Structural reduction", &self.structural_reduction)
+            .field("Reduced rows", &self.reduced_rows)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn generic_inverse(value: f64) -> Option<f64> {
+        let matrix = nalgebra::Matrix1::new(value);
+        let mut lu = nalgebra::Matrix1::zeros();
+        let mut inverse = nalgebra::Matrix1::zeros();
+        compute_inverse_lu_in_place(&matrix, &mut lu, &mut inverse)
+            .ok()
+            .map(|_| inverse[(0, 0)])
+    }
+
+    fn scalar_inverse(value: f64) -> Option<f64> {
+        let matrix = nalgebra::DMatrix::from_element(1, 1, value);
+        let mut lu = nalgebra::DMatrix::zeros(1, 1);
+        let mut inverse = nalgebra::DMatrix::zeros(1, 1);
+        compute_inverse_in_place(&matrix, &mut lu, &mut inverse)
+            .ok()
+            .map(|_| inverse[(0, 0)])
+    }
+
+    #[test]
+    fn scalar_inverse_identical_to_lu_inverse() {
+        let values = [
+            1.0,
+            -1.0,
+            3.0,
+            -7.3e-5,
+            2.5e12,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE / 4.0,
+            f64::MAX,
+            f64::INFINITY,
+            0.0,
+            -0.0,
+        ];
+        for value in values {
+            assert_eq!(
+                scalar_inverse(value).map(f64::to_bits),
+                generic_inverse(value).map(f64::to_bits),
+                "{}",
+                value
+            );
+        }
+        assert!(scalar_inverse(f64::NAN).unwrap().is_nan());
+        assert!(generic_inverse(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn condition_estimate() {
+        let matrix = nalgebra::Matrix2::new(2.0, 0.0, 0.0, 1e-3);
+        assert_eq!(estimate_condition(&matrix), 2e3);
+        let singular = nalgebra::Matrix2::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(estimate_condition(&singular), f64::INFINITY);
+
+        let mut jacobian = JacobianMatrix::new();
+        assert!(jacobian
+            .update_jacobian_with_exact_value(nalgebra::DMatrix::from_element(2, 2, 1.0))
+            .is_err());
+        assert_eq!(jacobian.get_condition_estimate(), Some(f64::INFINITY));
+        jacobian
+            .update_jacobian_with_exact_value(nalgebra::DMatrix::identity(2, 2))
+            .unwrap();
+        assert_eq!(jacobian.get_condition_estimate(), None);
+    }
+
+    #[test]
+    fn fixed_rows_replaced() {
+        let mut jacobian = JacobianMatrix::new();
+        jacobian.set_fixed_rows(vec![(2, 1)]);
+        let matrix = nalgebra::DMatrix::from_row_slice(
+            3,
+            3,
+            &[2.0, 1.0, 1.0, 1.0, 3.0, -1.0, 0.0, 0.0, 0.0],
+        );
+        jacobian.update_jacobian_with_exact_value(matrix).unwrap();
+
+        let expected = nalgebra::DMatrix::from_row_slice(
+            3,
+            3,
+            &[2.0, 1.0, 1.0, 1.0, 3.0, -1.0, 0.0, 1.0, 0.0],
+        );
+        assert_eq!(jacobian.get_jacobian().as_ref().unwrap(), &expected);
+
+        // null step for the fixed iterative with a null ignored residual
+        let residuals = nalgebra::DVector::from_vec(vec![1.0, 2.0, 0.0]);
+        let step = jacobian.get_inverse().as_ref().unwrap() * residuals;
+        assert!(step[1].abs() < 1e-15);
+
+        jacobian.reset();
+        assert_eq!(jacobian.get_fixed_rows(), &[(2, 1)]);
+    }
+
+    #[test]
+    fn structural_zeros() {
+        let mut jacobian = JacobianMatrix::new();
+        jacobian.set_fixed_rows(vec![(3, 0)]);
+        // the row 1 and the column 2 are null, the row 3 being replaced
+        let matrix = nalgebra::DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                2.0, 1.0, 0.0, 1.0, //
+                0.0, 0.0, 0.0, 0.0, //
+                1.0, 3.0, 0.0, -1.0, //
+                0.0, 0.0, 5.0, 0.0,
+            ],
+        );
+        assert_eq!(jacobian.structural_zeros(&matrix), (vec![1], vec![2]));
+
+        jacobian.set_structural_reduction(true);
+        let result: Result<
+            (),
+            crate::errors::SolverInternalError<crate::model::UserModelFromFunction, nalgebra::Dyn>,
+        > = jacobian.update_jacobian_with_computed_value(matrix.clone());
+        assert!(result.is_ok());
+        assert_eq!(jacobian.get_reduced_rows(), &[(1, 2)]);
+        assert_eq!(jacobian.structural_zeros(&matrix), (vec![], vec![]));
+
+        jacobian.reset();
+        assert!(jacobian.get_reduced_rows().is_empty());
+    }
+}
@@ -1,14 +1,36 @@
 mod jacobian_analytic;
 mod jacobian_approximation;
+#[cfg(feature = "std")]
+mod jacobian_file;
 mod jacobian_finite_diff;
 mod jacobian_inverse_approximation;
+mod jacobian_limited_memory;
+mod jacobian_source;
+mod jacobian_sparsity;
 mod jacobian_struct;
 
+pub(crate) use jacobian_analytic::evaluate_jacobian_diagonal_from_analytical_function;
 pub use jacobian_analytic::evaluate_jacobian_from_analytical_function;
+#[cfg(feature = "std")]
+pub use jacobian_file::MatrixFileFormat;
+#[cfg(feature = "std")]
+pub(crate) use jacobian_file::{dump_path, JacobianDump};
 pub use jacobian_finite_diff::{
     compute_jacobian_from_finite_difference, evaluate_jacobian_from_finite_difference,
+    InaccurateColumn,
 };
+pub(crate) use jacobian_finite_diff::{
+    evaluate_jacobian_diagonal_from_finite_difference, evaluate_jacobian_with_constant_columns,
+};
+pub(crate) use jacobian_source::count_jacobian_sources;
+pub use jacobian_source::{JacobianSource, MethodState};
+pub use jacobian_sparsity::SparsityPattern;
 pub use jacobian_struct::JacobianMatrix;
+#[cfg(feature = "debug_log")]
+pub(crate) use jacobian_struct::{
+    DEFAULT_LARGEST_ENTRIES, DEFAULT_MAX_FULL_SIZE, DEFAULT_SIGNIFICANT_DIGITS,
+};
 
-pub use jacobian_approximation::approximate_jacobian;
+pub use jacobian_approximation::{approximate_jacobian, QuasiNewtonUpdateStatus};
 pub use jacobian_inverse_approximation::approximate_inv_jacobian;
+pub use jacobian_limited_memory::LimitedMemoryInverse;
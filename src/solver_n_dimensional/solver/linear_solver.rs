@@ -0,0 +1,185 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+use super::ResolutionMethod;
+
+/// Choice of the method solving the linear system `J * dx = -F` at each iteration
+///
+/// The default is [LinearSolver::DirectInverse].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum LinearSolver {
+    /// The inverse of the jacobian is computed with a LU decomposition
+    /// and the step is obtained by a matrix-vector product
+    #[default]
+    DirectInverse,
+    /// Restarted GMRES, an iterative Krylov method
+    ///
+    /// Only matrix-vector products with the jacobian are performed:
+    /// the jacobian is never inverted, which is cheaper for very large systems.
+    ///
+    /// - `restart`: size of the Krylov subspace before restarting the method
+    /// - `max_iter`: maximum number of inner iterations, over all the restarts
+    /// - `tol`: tolerance on the residual of the linear system, relative to the norm of `F`
+    ///
+    /// The methods updating the inverse of the jacobian cannot be used with this solver,
+    /// see [LinearSolver::is_compatible_with]
+    ///
+    /// See Y. Saad and M. H. Schultz (1986),
+    /// GMRES: A Generalized Minimal Residual Algorithm for Solving Nonsymmetric Linear Systems,
+    /// SIAM Journal on Scientific and Statistical Computing 7 (3), p 856–869,
+    /// doi:10.1137/0907058
+    Gmres {
+        restart: usize,
+        max_iter: usize,
+        tol: f64,
+    },
+}
+
+impl LinearSolver {
+    /// Check if the resolution method can be used with the linear solver
+    ///
+    /// GMRES does not compute the inverse of the jacobian:
+    /// the methods updating the inverse, and the second method of Greenstadt
+    /// that uses the inverse to update the jacobian, are not compatible with it.
+    pub fn is_compatible_with(&self, resolution_method: ResolutionMethod) -> bool {
+        resolution_method
+            .requirements()
+            .supports_linear_solver(*self)
+    }
+
+    pub(crate) fn requires_inverse(&self) -> bool {
+        matches!(self, LinearSolver::DirectInverse)
+    }
+}
+
+impl fmt::Display for LinearSolver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LinearSolver::DirectInverse => write!(f, "Direct inverse"),
+            LinearSolver::Gmres {
+                restart,
+                max_iter,
+                tol,
+            } => write!(
+                f,
+                "GMRES (restart: {}, max iterations: {}, tolerance: {})",
+                restart, max_iter, tol
+            ),
+        }
+    }
+}
+
+/// Solve `matrix * x = rhs` with the restarted GMRES method, starting from `x = 0`
+///
+/// The resolution is successful once `|rhs - matrix * x| <= tol * |rhs|`.
+/// The Krylov basis is orthogonalized with the modified Gram-Schmidt process
+/// and the least-squares problem is solved with Givens rotations.
+pub(crate) fn gmres<D>(
+    matrix: &nalgebra::OMatrix<f64, D, D>,
+    rhs: &nalgebra::OVector<f64, D>,
+    restart: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<nalgebra::OVector<f64, D>, crate::errors::LinearSolverNonConvergence>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let mut solution = super::super::ovector_zeros_like(rhs);
+    let threshold = tol * rhs.norm();
+    if threshold == 0.0 {
+        return if rhs.norm() == 0.0 {
+            Ok(solution)
+        } else {
+            Err(crate::errors::LinearSolverNonConvergence)
+        };
+    }
+
+    let restart = restart.max(1);
+    let mut iterations = 0;
+    let mut hessenberg = nalgebra::DMatrix::zeros(restart + 1, restart);
+    let mut cosines = vec![0.0; restart];
+    let mut sines = vec![0.0; restart];
+    let mut projected_residuals = vec![0.0; restart + 1];
+    let mut basis: Vec<nalgebra::OVector<f64, D>> = Vec::with_capacity(restart + 1);
+
+    loop {
+        let residual = rhs - matrix * &solution;
+        let residual_norm = residual.norm();
+        if residual_norm <= threshold {
+            return Ok(solution);
+        }
+        if iterations >= max_iter || !residual_norm.is_finite() {
+            return Err(crate::errors::LinearSolverNonConvergence);
+        }
+
+        basis.clear();
+        basis.push(residual / residual_norm);
+        hessenberg.fill(0.0);
+        projected_residuals.fill(0.0);
+        projected_residuals[0] = residual_norm;
+
+        let mut k = 0;
+        while k < restart && iterations < max_iter {
+            let mut w = matrix * &basis[k];
+            for (i, vector) in basis.iter().enumerate() {
+                let h = w.dot(vector);
+                hessenberg[(i, k)] = h;
+                w.axpy(-h, vector, 1.0);
+            }
+            let w_norm = w.norm();
+            hessenberg[(k + 1, k)] = w_norm;
+
+            for i in 0..k {
+                let temp = cosines[i] * hessenberg[(i, k)] + sines[i] * hessenberg[(i + 1, k)];
+                hessenberg[(i + 1, k)] =
+                    -sines[i] * hessenberg[(i, k)] + cosines[i] * hessenberg[(i + 1, k)];
+                hessenberg[(i, k)] = temp;
+            }
+
+            let denominator = hessenberg[(k, k)].hypot(hessenberg[(k + 1, k)]);
+            if denominator == 0.0 {
+                cosines[k] = 1.0;
+                sines[k] = 0.0;
+            } else {
+                cosines[k] = hessenberg[(k, k)] / denominator;
+                sines[k] = hessenberg[(k + 1, k)] / denominator;
+            }
+            hessenberg[(k, k)] = denominator;
+            hessenberg[(k + 1, k)] = 0.0;
+            projected_residuals[k + 1] = -sines[k] * projected_residuals[k];
+            projected_residuals[k] *= cosines[k];
+
+            iterations += 1;
+            k += 1;
+
+            // the Krylov subspace is invariant: the solution is exact within it
+            if w_norm == 0.0 || projected_residuals[k].abs() <= threshold {
+                break;
+            }
+            basis.push(w / w_norm);
+        }
+
+        // back substitution of the upper triangular system
+        let mut coefficients = vec![0.0; k];
+        for i in (0..k).rev() {
+            let mut value = projected_residuals[i];
+            for (j, coefficient) in coefficients.iter().enumerate().skip(i + 1) {
+                value -= hessenberg[(i, j)] * coefficient;
+            }
+            if hessenberg[(i, i)] == 0.0 {
+                return Err(crate::errors::LinearSolverNonConvergence);
+            }
+            coefficients[i] = value / hessenberg[(i, i)];
+        }
+
+        for (coefficient, vector) in coefficients.iter().zip(basis.iter()) {
+            solution.axpy(*coefficient, vector, 1.0);
+        }
+    }
+}
@@ -1,20 +1,186 @@
+use std::cell::RefCell;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
 
 use crate::residuals::ResidualsValues;
+use crate::solver::{ConvergenceRate, JacobianSource, SUSPICIOUS_NEWTON_ORDER};
+use crate::solver_n_dimensional::{hidden_entries, hidden_entries_marker};
 
 #[cfg(feature = "additional_log_info")]
 use chrono::prelude::*;
 
 const SEPARATION_ITER: &str = "=========================\n\n";
-const SEPARATION_LINE: &str =  "+-------+-------------------------------+----------------------------------------------------------------+---------------------------------+\n";
-const TITLE_LINE: &str =       "| Id    |           Iteratives          |        Left                    =                Right          |         Stopping criteria       |\n";
+const SEPARATION_LINE: &str = "+-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+\n";
+const TITLE_LINE: &str =      "| Id    |           Iteratives          |              Left             |             Right             |        Update residual        |       Stopping residual       |\n";
+const NORMALIZED_SEPARATION_LINE: &str = "+-------+-------------------------------+-------------------------------+-------------------------------+\n";
+const NORMALIZED_TITLE_LINE: &str =      "| Id    |           Iteratives          |        Update residual        |       Stopping residual       |\n";
+const PERTURBATION_SEPARATION_LINE: &str = "+-------+-------------------------------+\n";
+const PERTURBATION_TITLE_LINE: &str = "| Id    |          Perturbation         |\n";
 const FLOAT_WIDTH: usize = 30;
 const INT_WIDTH: usize = 6;
 
+/// Writing strategy of the debug log to its file
+///
+/// The content of the file is the same with both modes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugWriteMode {
+    /// The log is written to the file as the resolution progresses
+    ///
+    /// If the process is killed, the file contains the log up to that point.
+    Streaming,
+    /// The log is kept in memory during the resolution and written at its end in one go
+    ///
+    /// This avoids the input/output operations inside the iterations.
+    Buffered,
+}
+
+/// Writing of the debug log when the solver performs several resolutions
+///
+/// Each call to `solve()` writes a fresh block of parameters and restarts the numbering of the iterations,
+/// the resolutions being numbered from 1 since the activation of the debug log.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum DebugFilePolicy {
+    /// All the resolutions are written to the given file, each of them starting with a `=== solve #N ===` line
+    #[default]
+    Append,
+    /// Each resolution is written to its own file, the number of the resolution being appended to the file name:
+    /// `log.txt` gives `log_001.txt`, `log_002.txt`, ...
+    ///
+    /// Each file starts with the runner informations, as a log of a single resolution.
+    NumberedFiles,
+}
+
+/// Path of the log of the resolution `solve` with [DebugFilePolicy::NumberedFiles]
+fn numbered_path(path: &str, solve: usize) -> String {
+    let path = Path::new(path);
+    let stem = path
+        .file_stem()
+        .map_or(String::new(), |stem| stem.to_string_lossy().into_owned());
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}_{:03}.{}", stem, solve, extension.to_string_lossy()),
+        None => format!("{}_{:03}", stem, solve),
+    };
+    path.with_file_name(file_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Level of detail of the debug log
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LogLevel {
+    /// Same as [LogLevel::Summary], the tables of the iterations only containing
+    /// the normalized values of the residuals and not the left and right members of the equations
+    Iterations,
+    /// Only the first and last entries of the long tables are written,
+    /// and large jacobians are summarized according to the [LogOptions]
+    Summary,
+    /// All the entries of the tables and of the jacobians are written
+    Full,
+}
+
+/// Options of the content of the debug log
+///
+/// - `jacobian_significant_digits`: number of significant digits of the values of the jacobian
+/// - `jacobian_max_full_size`: maximum size of a jacobian written in full,
+///   only the diagonal and the largest off-diagonal entries of larger jacobians are written
+/// - `jacobian_largest_entries`: number of off-diagonal entries written for larger jacobians
+///
+/// The log level defaults to [LogLevel::Summary] and can be changed with [LogOptions::set_log_level].
+/// With [LogLevel::Summary], only the first and last 10 entries of the iteratives tables are written.
+///
+/// For each residual, the tables of the iterations contain the left and right members of the equation,
+/// and its values normalized with the update method and the stopping criteria.
+/// With [LogLevel::Iterations], only the normalized values are written.
+///
+/// See [crate::solver::JacobianMatrix::format_with]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LogOptions {
+    jacobian_significant_digits: usize,
+    jacobian_max_full_size: usize,
+    jacobian_largest_entries: usize,
+    log_level: LogLevel,
+}
+
+impl LogOptions {
+    pub fn new(
+        jacobian_significant_digits: usize,
+        jacobian_max_full_size: usize,
+        jacobian_largest_entries: usize,
+    ) -> Self {
+        if jacobian_significant_digits == 0 {
+            panic!("The number of significant digits must be strictly positive");
+        }
+        LogOptions {
+            jacobian_significant_digits,
+            jacobian_max_full_size,
+            jacobian_largest_entries,
+            log_level: LogLevel::Summary,
+        }
+    }
+
+    pub fn set_log_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+
+    pub fn get_log_level(&self) -> LogLevel {
+        self.log_level
+    }
+
+    pub fn get_jacobian_significant_digits(&self) -> usize {
+        self.jacobian_significant_digits
+    }
+
+    pub fn get_jacobian_max_full_size(&self) -> usize {
+        match self.log_level {
+            LogLevel::Iterations | LogLevel::Summary => self.jacobian_max_full_size,
+            LogLevel::Full => usize::MAX,
+        }
+    }
+
+    pub fn get_jacobian_largest_entries(&self) -> usize {
+        self.jacobian_largest_entries
+    }
+
+    /// Number of entries written at the beginning and at the end of the tables,
+    /// `None` if all the entries are written
+    pub(crate) fn get_displayed_entries(&self) -> Option<usize> {
+        match self.log_level {
+            LogLevel::Iterations | LogLevel::Summary => {
+                Some(crate::solver_n_dimensional::DEFAULT_DISPLAYED_ENTRIES)
+            }
+            LogLevel::Full => None,
+        }
+    }
+
+    /// Whether the left and right members of the equations are written in the tables of the iterations
+    pub(crate) fn get_residuals_members(&self) -> bool {
+        self.log_level != LogLevel::Iterations
+    }
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        LogOptions::new(
+            super::jacobian::DEFAULT_SIGNIFICANT_DIGITS,
+            super::jacobian::DEFAULT_MAX_FULL_SIZE,
+            super::jacobian::DEFAULT_LARGEST_ENTRIES,
+        )
+    }
+}
+
 pub struct SolverLog {
     path: String,
+    // Runner informations, written at the beginning of each file
+    header: String,
+    file_policy: DebugFilePolicy,
+    // Number of resolutions started, and file of the current one
+    solves: usize,
+    current_path: Option<String>,
+    // Only used in buffered mode
+    buffer: Option<RefCell<String>>,
 }
 
 #[cfg(feature = "additional_log_info")]
@@ -24,41 +190,46 @@ fn write_time(content: &mut String) {
 
     content.push_str("Simulation start:\n");
 
-    content.push_str(&"  - UTC:   ");
+    content.push_str("  - UTC:   ");
     content.push_str(&utc.to_rfc2822());
-    content.push_str(&"\n");
-    content.push_str(&"  - Local: ");
+    content.push('\n');
+    content.push_str("  - Local: ");
     content.push_str(&local.to_rfc2822());
-    content.push_str(&"\n");
+    content.push('\n');
 }
 
 #[cfg(feature = "additional_log_info")]
 fn write_user_infos(content: &mut String) {
-    content.push_str(&"OS: ");
+    content.push_str("OS: ");
     content.push_str(&whoami::distro());
-    content.push_str(&"\n");
-    content.push_str(&"Host: ");
+    content.push('\n');
+    content.push_str("Host: ");
     content.push_str(&whoami::devicename());
-    content.push_str(&"\n");
-    content.push_str(&"Username: ");
+    content.push('\n');
+    content.push_str("Username: ");
     content.push_str(&whoami::username());
-    content.push_str(&"\n");
+    content.push('\n');
 }
 
 #[cfg(feature = "additional_log_info")]
 fn write_rustc_info(content: &mut String) {
     content.push_str("Rust version: ");
     content.push_str(&rustc_version_runtime::version().to_string());
-    content.push_str(&"\n");
+    content.push('\n');
 }
 
 /// Log for debugging information
 ///
 /// This object defines the format and concatenate the debugging informations
 impl SolverLog {
-    pub fn new(path: &str) -> Self {
-        let mut file = File::create(path).unwrap();
-
+    /// With [DebugFilePolicy::NumberedFiles], the files are only created at the start of the resolutions,
+    /// see [SolverLog::start_solve]
+    pub fn new(
+        path: &str,
+        write_mode: DebugWriteMode,
+        file_policy: DebugFilePolicy,
+        metadata: &[(String, String)],
+    ) -> Self {
         let mut content = String::new();
         content.push_str("Runner informations\n");
         content.push_str("===================\n\n");
@@ -74,21 +245,91 @@ impl SolverLog {
         content.push_str(VERSION);
         content.push('\n');
 
+        for (key, value) in metadata {
+            content.push_str(&format!("{}: {}\n", key, value));
+        }
+
         #[cfg(feature = "additional_log_info")]
         write_time(&mut content);
 
         content.push('\n');
 
-        write!(file, "{}", content).unwrap();
+        let buffer = match write_mode {
+            DebugWriteMode::Streaming => None,
+            DebugWriteMode::Buffered => Some(RefCell::new(String::new())),
+        };
 
-        SolverLog {
+        let mut solver_log = SolverLog {
             path: path.to_string(),
+            header: content,
+            file_policy,
+            solves: 0,
+            current_path: None,
+            buffer,
+        };
+        if file_policy == DebugFilePolicy::Append {
+            solver_log.create_file(path.to_string());
+        }
+        solver_log
+    }
+
+    /// Create the file of the log, starting with the runner informations
+    fn create_file(&mut self, path: String) {
+        File::create(&path).unwrap();
+        self.current_path = Some(path);
+        self.add_content(&self.header);
+    }
+
+    /// Start the log of a new resolution, in the file given by the [DebugFilePolicy]
+    pub fn start_solve(&mut self) {
+        self.flush();
+        self.solves += 1;
+
+        match self.file_policy {
+            DebugFilePolicy::Append => {
+                self.add_content(&format!("=== solve #{} ===\n\n", self.solves));
+            }
+            DebugFilePolicy::NumberedFiles => {
+                self.create_file(numbered_path(&self.path, self.solves));
+            }
+        }
+    }
+
+    /// Start a resolution if none has been started since the activation of the log
+    pub fn ensure_started(&mut self) {
+        if self.solves == 0 {
+            self.start_solve();
         }
     }
 
     pub fn add_content(&self, new_content: &str) {
-        let mut file = OpenOptions::new().append(true).open(&self.path).unwrap();
-        write!(file, "{}", new_content).unwrap();
+        match &self.buffer {
+            Some(buffer) => buffer.borrow_mut().push_str(new_content),
+            None => self.write_to_file(new_content).unwrap(),
+        }
+    }
+
+    /// Write the content kept in memory to the file (buffered mode only)
+    pub fn flush(&self) {
+        self.write_buffer().unwrap();
+    }
+
+    fn write_buffer(&self) -> std::io::Result<()> {
+        if let Some(buffer) = &self.buffer {
+            let mut buffer = buffer.borrow_mut();
+            if !buffer.is_empty() {
+                self.write_to_file(&buffer)?;
+                buffer.clear();
+            }
+        }
+        Ok(())
+    }
+
+    fn write_to_file(&self, new_content: &str) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(self.current_path.as_ref().unwrap())?;
+        write!(file, "{}", new_content)
     }
 
     pub fn add_parameters(
@@ -106,70 +347,244 @@ impl SolverLog {
         &self,
         iteratives: &nalgebra::OVector<f64, D>,
         residuals: &ResidualsValues<D>,
+        update_residuals: &nalgebra::OVector<f64, D>,
         errors: &nalgebra::OVector<f64, D>,
+        log_options: &LogOptions,
     ) where
         D: nalgebra::Dim,
         nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     {
-        let mut iteration_log_header = String::new();
-        iteration_log_header.push_str(&"Damping activated !\n\n".to_string());
-        self.add_content(&iteration_log_header);
-        self.add_iteration(iteratives, residuals, errors);
+        let mut content = String::from("Damping activated !\n\n");
+        write_iteration(
+            &mut content,
+            iteratives,
+            residuals,
+            update_residuals,
+            errors,
+            log_options,
+        );
+        self.add_content(&content);
     }
-    pub fn add_new_iteration<D>(
+
+    pub fn add_perturbations<D>(
         &self,
-        iteratives: &nalgebra::OVector<f64, D>,
-        residuals: &ResidualsValues<D>,
-        errors: &nalgebra::OVector<f64, D>,
-        iter: usize,
+        perturbations: &nalgebra::OVector<f64, D>,
+        perturbations_out_of_bounds: &[usize],
+        displayed_entries: Option<usize>,
     ) where
         D: nalgebra::Dim,
         nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     {
-        let mut iteration_log_header = String::new();
-        iteration_log_header.push_str(SEPARATION_ITER);
-        iteration_log_header.push_str(&format!("Iteration: {}\n\n", iter.to_string()));
-        self.add_content(&iteration_log_header);
-        self.add_iteration(iteratives, residuals, errors);
+        let hidden = hidden_entries(perturbations.len(), displayed_entries);
+        let mut content = String::with_capacity(
+            2 * PERTURBATION_SEPARATION_LINE.len()
+                * (displayed_rows(perturbations.len(), &hidden) + 2),
+        );
+        content.push_str("Finite difference perturbations\n\n");
+        content.push_str(PERTURBATION_SEPARATION_LINE);
+        content.push_str(PERTURBATION_TITLE_LINE);
+        content.push_str(PERTURBATION_SEPARATION_LINE);
+        for (i, perturbation) in perturbations.iter().enumerate() {
+            if let Some(hidden) = &hidden {
+                if i == hidden.start {
+                    content.push_str(&hidden_entries_marker(hidden));
+                    content.push_str(PERTURBATION_SEPARATION_LINE);
+                }
+                if hidden.contains(&i) {
+                    continue;
+                }
+            }
+            content.push_str(&format!("| {:width$}", i.to_string(), width = INT_WIDTH));
+            content.push_str(&format!(
+                "| {:width$}|\n",
+                perturbation.to_string(),
+                width = FLOAT_WIDTH
+            ));
+            content.push_str(PERTURBATION_SEPARATION_LINE);
+        }
+        content.push('\n');
+
+        if !perturbations_out_of_bounds.is_empty() {
+            content.push_str(&format!(
+                "Warning: the perturbed values of the iteratives {:?} are out of their bounds, the finite difference may be inaccurate\n\n",
+                perturbations_out_of_bounds
+            ));
+        }
+
+        self.add_content(&content);
     }
 
-    fn add_iteration<D>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_new_iteration<D>(
         &self,
         iteratives: &nalgebra::OVector<f64, D>,
         residuals: &ResidualsValues<D>,
+        update_residuals: &nalgebra::OVector<f64, D>,
         errors: &nalgebra::OVector<f64, D>,
+        iter: usize,
+        jacobian_source: Option<JacobianSource>,
+        log_options: &LogOptions,
     ) where
         D: nalgebra::Dim,
         nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
     {
-        let mut iteration_log_header = String::new();
-        iteration_log_header.push_str(&format!("Max error: {}\n\n", errors.amax()));
-        self.add_content(&iteration_log_header);
-        self.add_content(SEPARATION_LINE);
-        self.add_content(TITLE_LINE);
-        self.add_content(SEPARATION_LINE);
-        for (i, (iterative, error)) in iteratives.iter().zip(errors.iter()).enumerate() {
-            let mut entry = String::new();
-            entry.push_str(&format!("| {:width$}", i.to_string(), width = INT_WIDTH));
-            entry.push_str(&format!(
-                "| {:width$}",
-                iterative.to_string(),
-                width = FLOAT_WIDTH
+        let mut content = String::from(SEPARATION_ITER);
+        content.push_str(&format!("Iteration: {}\n", iter));
+        if let Some(source) = jacobian_source {
+            content.push_str(&format!("Jacobian source: {}\n", source));
+        }
+        content.push('\n');
+        write_iteration(
+            &mut content,
+            iteratives,
+            residuals,
+            update_residuals,
+            errors,
+            log_options,
+        );
+        self.add_content(&content);
+    }
+
+    /// Write the outcome of the resolution and flush the log
+    ///
+    /// It is written at the end of every resolution, whether it succeeded or not,
+    /// with the maximum error at the initial guess, the iteration at which the resolution stopped
+    /// and the maximum error of the last iterate evaluated, if the model could be evaluated.
+    /// The estimated convergence rate follows, if any, with a warning if the jacobian is suspicious,
+    /// see [super::RootFinder::get_suspicious_jacobian].
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_termination(
+        &self,
+        outcome: &str,
+        iter: usize,
+        initial_max_error: Option<f64>,
+        max_error: Option<f64>,
+        convergence_rate: Option<ConvergenceRate>,
+        suspicious_jacobian: bool,
+        residuals_above_tolerance: usize,
+        worst_residual: Option<(usize, f64)>,
+    ) {
+        let mut content = String::from(SEPARATION_ITER);
+        content.push_str("Termination\n\n");
+        content.push_str(&format!("Outcome: {}\n", outcome));
+        match initial_max_error {
+            Some(initial_max_error) => {
+                content.push_str(&format!("Initial max error: {}\n", initial_max_error))
+            }
+            None => content.push_str("Initial max error: not evaluated\n"),
+        }
+        content.push_str(&format!("Last iteration: {}\n", iter));
+        match max_error {
+            Some(max_error) => content.push_str(&format!("Last max error: {}\n", max_error)),
+            None => content.push_str("Last max error: not evaluated\n"),
+        }
+        if let Some((worst_index, worst_error)) = worst_residual {
+            content.push_str(&format!(
+                "Residuals above their tolerance: {}\nWorst residual: {} at {} times its tolerance\n",
+                residuals_above_tolerance, worst_index, worst_error
             ));
-            entry.push_str(&format!(
+        }
+        content.push('\n');
+        if let Some(convergence_rate) = convergence_rate {
+            content.push_str(&format!("Estimated convergence: {}\n\n", convergence_rate));
+        }
+        if suspicious_jacobian {
+            content.push_str(&format!(
+                "WARNING: the Newton-Raphson method converged with an order below {}, the jacobian of the model may be wrong: \
+                 compare it with `evaluate_jacobian_from_finite_difference()`\n\n",
+                SUSPICIOUS_NEWTON_ORDER
+            ));
+        }
+        self.add_content(&content);
+        self.flush();
+    }
+}
+
+/// Number of rows of a table once the hidden entries are replaced by a single line
+fn displayed_rows(len: usize, hidden: &Option<Range<usize>>) -> usize {
+    match hidden {
+        Some(hidden) => len - hidden.len() + 1,
+        None => len,
+    }
+}
+
+/// Write the table of the values of an iteration
+///
+/// The errors are the residuals normalized with the stopping criteria.
+/// The whole table is built in memory to be added to the log in one go
+fn write_iteration<D>(
+    content: &mut String,
+    iteratives: &nalgebra::OVector<f64, D>,
+    residuals: &ResidualsValues<D>,
+    update_residuals: &nalgebra::OVector<f64, D>,
+    errors: &nalgebra::OVector<f64, D>,
+    log_options: &LogOptions,
+) where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    let residuals_members = log_options.get_residuals_members();
+    let (separation_line, title_line) = if residuals_members {
+        (SEPARATION_LINE, TITLE_LINE)
+    } else {
+        (NORMALIZED_SEPARATION_LINE, NORMALIZED_TITLE_LINE)
+    };
+
+    let hidden = hidden_entries(iteratives.len(), log_options.get_displayed_entries());
+    content.reserve(2 * separation_line.len() * (displayed_rows(iteratives.len(), &hidden) + 2));
+
+    content.push_str(&format!("Max error: {}\n\n", errors.amax()));
+    content.push_str(separation_line);
+    content.push_str(title_line);
+    content.push_str(separation_line);
+    for (i, iterative) in iteratives.iter().enumerate() {
+        if let Some(hidden) = &hidden {
+            if i == hidden.start {
+                content.push_str(&hidden_entries_marker(hidden));
+                content.push_str(separation_line);
+            }
+            if hidden.contains(&i) {
+                continue;
+            }
+        }
+        content.push_str(&format!("| {:width$}", i.to_string(), width = INT_WIDTH));
+        content.push_str(&format!(
+            "| {:width$}",
+            iterative.to_string(),
+            width = FLOAT_WIDTH
+        ));
+        if residuals_members {
+            let (left, right) = residuals.get_values(i);
+            content.push_str(&format!(
                 "| {:width$}",
-                residuals.get_values_str_eq(i, FLOAT_WIDTH),
+                left.to_string(),
                 width = FLOAT_WIDTH
             ));
-            entry.push_str(&format!(
-                "| {:width$}  |",
-                error.to_string(),
+            content.push_str(&format!(
+                "| {:width$}",
+                right.to_string(),
                 width = FLOAT_WIDTH
             ));
-            entry.push('\n');
-            entry.push_str(SEPARATION_LINE);
-            self.add_content(&entry);
         }
-        self.add_content("\n");
+        content.push_str(&format!(
+            "| {:width$}",
+            update_residuals[i].to_string(),
+            width = FLOAT_WIDTH
+        ));
+        content.push_str(&format!(
+            "| {:width$}|",
+            errors[i].to_string(),
+            width = FLOAT_WIDTH
+        ));
+        content.push('\n');
+        content.push_str(separation_line);
+    }
+    content.push('\n');
+}
+
+impl Drop for SolverLog {
+    // Safety net if the resolution has been interrupted before flushing the log
+    fn drop(&mut self) {
+        let _ = self.write_buffer();
     }
 }
@@ -0,0 +1,59 @@
+use super::{LinearSolver, QuasiNewtonMethod, ResolutionMethod};
+
+/// Size in bytes of the values stored by the solver
+const VALUE_BYTES: usize = core::mem::size_of::<f64>();
+
+/// Estimate of the storage in bytes required by the jacobian for a problem of size `problem_size`,
+/// see the memory limit of [super::SolverParameters]
+///
+/// The estimate covers the storage depending on the resolution method and the linear solver,
+/// which dominates for large problems, the vectors of the size of the problem being neglected:
+/// - with [LinearSolver::DirectInverse], the jacobian, its inverse and the workspace of its LU decomposition:
+///   `3 * n^2` values.
+///   It includes the methods updating the inverse, such as [QuasiNewtonMethod::InverseJacobianUpdate]:
+///   the jacobian is dropped between its computations, but is still required to compute the inverse
+/// - with [LinearSolver::Gmres], the jacobian and the workspace of the decomposition, allocated in any case,
+///   along with the Krylov basis and the Hessenberg matrix: `2 * n^2 + (restart + 1) * (n + restart)` values
+/// - with [QuasiNewtonMethod::LimitedMemoryBroyden], the inverse of the diagonal seed
+///   and the steps and corrections kept: `(1 + 3 * memory) * n` values
+/// - with [ResolutionMethod::NonlinearGaussSeidel], no jacobian is stored
+///
+/// The copy of the jacobian kept for the constant columns,
+/// see `RootFinder::declare_constant_jacobian_columns()`, is not included.
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::{estimate_memory_bytes, LinearSolver, ResolutionMethod};
+///
+/// let estimate =
+///     estimate_memory_bytes(3000, ResolutionMethod::NewtonRaphson, LinearSolver::DirectInverse);
+/// assert_eq!(estimate, 3 * 3000 * 3000 * 8);
+/// ```
+pub fn estimate_memory_bytes(
+    problem_size: usize,
+    resolution_method: ResolutionMethod,
+    linear_solver: LinearSolver,
+) -> usize {
+    let values = match resolution_method {
+        ResolutionMethod::NonlinearGaussSeidel { .. } => 0,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory }) => memory
+            .saturating_mul(3)
+            .saturating_add(1)
+            .saturating_mul(problem_size),
+        ResolutionMethod::NewtonRaphson | ResolutionMethod::QuasiNewton(_) => {
+            let matrix = problem_size.saturating_mul(problem_size);
+            match linear_solver {
+                LinearSolver::DirectInverse => matrix.saturating_mul(3),
+                LinearSolver::Gmres { restart, .. } => {
+                    let restart = restart.max(1);
+                    let krylov = restart
+                        .saturating_add(1)
+                        .saturating_mul(problem_size.saturating_add(restart));
+                    matrix.saturating_mul(2).saturating_add(krylov)
+                }
+            }
+        }
+    };
+    values.saturating_mul(VALUE_BYTES)
+}
@@ -8,9 +8,21 @@
 //! - The residuals configuration through a reference to a slice of `ResidualsConfig`
 //! - The initial guess to use by the solver
 //!
+//! Their sizes must match the problem size of the parameters:
+//! `RootFinder::new()` and `default_with_guess()` panic otherwise.
+//! `RootFinder::try_new()` and `try_default_with_guess()` return a `RootFinderConstructionError` instead,
+//! and are recommended when these elements are built at runtime.
+//!
+//! For the common case of solving a problem with the default configuration,
+//! `quick_solver()` creates a `QuickSolver` owning all of them.
+//!
+//! With the `xml_config_file` feature, `solve_from_config()` builds all of them from a parsed configuration
+//! and solves the problem in one call.
+//!
 //! # Features
 //! 1. Simulation log available for debugging, check the `set_debug()` method
 //! 2. Damping, check the `set_damping()` method
+//! 3. Parameters tuned for common classes of problems, check the [presets] module
 //!
 //!
 //! ## Examples
@@ -37,7 +49,7 @@
 //!   let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
 //!   let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
 //!   let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
-//!   let damping = false;
+//!   let damping = nrf::solver::Damping::Disabled;
 //!   let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
 //!   let mut user_model =
 //!       nrf::model::UserModelFromFunction::new(problem_size, square2);
@@ -49,26 +61,83 @@
 //! }
 //! ```
 
+mod batch;
+mod compatibility;
+mod convergence_predicate;
+mod convergence_rate;
 mod default;
+#[cfg(feature = "xml_config_file")]
+mod from_config;
+#[cfg(feature = "std")]
+mod history;
 mod jacobian;
+mod linear_solver;
+#[cfg(feature = "debug_log")]
 mod log;
+mod memory;
+mod multi_start;
+mod newton_step;
 mod parameters;
+pub mod presets;
 mod resolution_method;
 mod rootfinder;
+mod sizes;
+mod snapshot;
 
-pub use default::default_with_guess;
+#[cfg(feature = "rayon")]
+pub use batch::solve_batch_parallel;
+pub use batch::{solve_batch, SolverReport};
+pub use compatibility::{
+    check_compatibility, CompatibilityWarning, FiniteDifferenceUse, IterativesKind,
+    MethodRequirements,
+};
+pub use convergence_predicate::{ConvergenceContext, ConvergencePredicate, PredicateMode};
+pub use convergence_rate::{
+    ConvergenceRate, MIN_ITERATIONS_CONVERGENCE_RATE, SUSPICIOUS_NEWTON_ORDER,
+};
+pub use default::{
+    default_with_guess, quick_solver, try_default_with_guess, QuickSolver, QuickStart,
+};
+#[cfg(feature = "xml_config_file")]
+pub use from_config::solve_from_config;
+#[cfg(feature = "std")]
+pub use history::{IterationRecord, QuasiNewtonUpdateMetrics};
 pub use jacobian::evaluate_jacobian_from_analytical_function;
 pub use jacobian::JacobianMatrix;
+#[cfg(feature = "std")]
+pub use jacobian::MatrixFileFormat;
 pub use jacobian::{
     approximate_inv_jacobian, approximate_jacobian, compute_jacobian_from_finite_difference,
-    evaluate_jacobian_from_finite_difference,
+    evaluate_jacobian_from_finite_difference, InaccurateColumn, JacobianSource,
+    LimitedMemoryInverse, MethodState, QuasiNewtonUpdateStatus, SparsityPattern,
+};
+pub use linear_solver::LinearSolver;
+#[cfg(feature = "debug_log")]
+pub use log::{DebugFilePolicy, DebugWriteMode, LogLevel, LogOptions};
+pub use memory::estimate_memory_bytes;
+pub use multi_start::{distinct_roots, uniform_guesses};
+pub(crate) use newton_step::{apply_inaccurate_jacobian_policy, compute_raw_newton_step};
+pub use newton_step::{compute_newton_step, solve_newton_system};
+pub use parameters::{
+    perturbation_jitter_factor, BoundPolicy, ConvergenceCriterion, CycleBreakingAction, Damping,
+    ExhaustedBudget, InaccurateJacobianPolicy, SolverParameters, StepAcceptanceNorm,
+    StructuralSingularityPolicy, AITKEN_DENOMINATOR_THRESHOLD, CYCLE_TOLERANCE,
+    MAX_CONTEXT_STEP_REDUCTIONS, MIN_TOLERANCE,
 };
-pub use parameters::SolverParameters;
 pub use resolution_method::greenstadt_second_method_udpate_jac;
+pub use resolution_method::DEGENERATE_UPDATE_THRESHOLD;
 pub use resolution_method::{
     broyden_first_method_udpate_inv_jac, broyden_second_method_udpate_inv_jac,
 };
 pub use resolution_method::{broyden_first_method_udpate_jac, broyden_second_method_udpate_jac};
+pub use resolution_method::{
+    broyden_first_method_update_inv_jac_in_place, broyden_first_method_update_jac_in_place,
+    broyden_second_method_update_inv_jac_in_place, broyden_second_method_update_jac_in_place,
+    greenstadt_second_method_update_jac_in_place, quasi_method_update_inv_jac_in_place,
+    quasi_method_update_jac_in_place, schubert_update_jac_in_place,
+};
 pub use resolution_method::{quasi_method_update_inv_jac, quasi_method_update_jac};
 pub use resolution_method::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
 pub use rootfinder::RootFinder;
+pub use sizes::{MaxIterations, ProblemSize};
+pub use snapshot::SolverSnapshot;
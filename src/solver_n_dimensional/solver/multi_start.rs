@@ -0,0 +1,171 @@
+use alloc::vec::Vec;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+use super::SolverReport;
+
+/// Pseudorandom generator of the initial guesses, see [uniform_guesses]
+///
+/// It is the SplitMix64 generator: small, without dependency,
+/// and giving the same sequence on every platform for a given seed.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Value in `[0, 1)`, from the 53 most significant bits of the next integer
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// Initial guesses drawn uniformly between `lower` and `upper`, for a multi-start resolution
+///
+/// The guesses are reproducible: the same `seed` gives the same guesses, on every platform.
+/// They are drawn one after the other, the components of each guess in their order.
+///
+/// The guesses can be solved with [super::solve_batch] or `solve_batch_parallel()`,
+/// the roots found being given by [distinct_roots].
+///
+/// # Panics
+///
+/// If `lower` and `upper` have different dimensions, or if a component of `lower` is above the one of `upper`
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::iteratives;
+/// use nrf::residuals;
+/// use nrf::solver::{
+///     distinct_roots, uniform_guesses, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters,
+/// };
+///
+/// fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 2.0)
+/// }
+///
+/// let lower = nalgebra::DVector::from_vec(vec![-3.0]);
+/// let upper = nalgebra::DVector::from_vec(vec![3.0]);
+/// let initial_guesses = uniform_guesses(&lower, &upper, 8, 42);
+/// assert_eq!(initial_guesses, uniform_guesses(&lower, &upper, 8, 42));
+///
+/// let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+/// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+/// let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+/// let update_methods = vec![residuals::NormalizationMethod::Abs];
+/// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+/// let parameters =
+///     SolverParameters::new(ProblemSize::new(1), 1e-9, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+///
+/// let mut models: Vec<_> = (0..initial_guesses.len())
+///     .map(|_| nrf::model::UserModelFromFunction::new(1, square2))
+///     .collect();
+/// let results = nrf::solver::solve_batch(
+///     parameters,
+///     &iter_params,
+///     &res_config,
+///     &initial_guesses,
+///     &mut models,
+/// );
+///
+/// // the two roots, each given by the first guess converging to it
+/// assert_eq!(distinct_roots(&results, 1e-6).len(), 2);
+/// ```
+pub fn uniform_guesses<D>(
+    lower: &nalgebra::OVector<f64, D>,
+    upper: &nalgebra::OVector<f64, D>,
+    number: usize,
+    seed: u64,
+) -> Vec<nalgebra::OVector<f64, D>>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    if lower.len() != upper.len() {
+        panic!(
+            "Dimension mismatch :\n lower.len() = {} and upper.len() = {}",
+            lower.len(),
+            upper.len()
+        );
+    }
+    if let Some(i) = (0..lower.len()).find(|&i| lower[i] > upper[i]) {
+        panic!(
+            "The lower bound {} of the component {} is above its upper bound {}",
+            lower[i], i, upper[i]
+        );
+    }
+
+    let mut generator = SplitMix64::new(seed);
+    (0..number)
+        .map(|_| lower.zip_map(upper, |min, max| min + (max - min) * generator.next_f64()))
+        .collect()
+}
+
+/// Indices of the successful resolutions giving distinct roots, in the order of `results`
+///
+/// Two roots are the same if their iteratives differ by at most `tolerance` for each component.
+/// A root is given by the index of its first resolution in `results`:
+/// the indices only depend on the order of `results`, not on the order in which the resolutions finished.
+/// Both [super::solve_batch] and `solve_batch_parallel()` return the results in the order of their initial guesses,
+/// hence the roots found with the parallel version are the same as with the sequential one.
+///
+/// See [uniform_guesses] for an example
+pub fn distinct_roots<D, E>(results: &[Result<SolverReport<D>, E>], tolerance: f64) -> Vec<usize>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    let mut roots: Vec<(usize, &nalgebra::OVector<f64, D>)> = Vec::new();
+    for (i, result) in results.iter().enumerate() {
+        let Ok(report) = result else {
+            continue;
+        };
+        let iteratives = report.get_final_iteratives();
+        let known = roots.iter().any(|(_, root_iteratives)| {
+            iteratives
+                .iter()
+                .zip(root_iteratives.iter())
+                .all(|(x, y)| (x - y).abs() <= tolerance)
+        });
+        if !known {
+            roots.push((i, iteratives));
+        }
+    }
+    roots.into_iter().map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_sequence() {
+        let mut generator = SplitMix64::new(1234567);
+        let values: Vec<u64> = (0..3).map(|_| generator.next_u64()).collect();
+        assert_eq!(
+            values,
+            [
+                6457827717110365317,
+                3203168211198807973,
+                9817491932198370423
+            ]
+        );
+    }
+}
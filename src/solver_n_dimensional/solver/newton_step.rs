@@ -0,0 +1,162 @@
+//! Building blocks of a Newton-Raphson iteration
+//!
+//! They are used by the [super::RootFinder] and can be called by a custom outer loop,
+//! such as a continuation method, without the convergence checks or the damping of the solver.
+
+use core::fmt;
+
+use super::linear_solver::gmres;
+use super::{
+    evaluate_jacobian_from_analytical_function, evaluate_jacobian_from_finite_difference,
+    InaccurateJacobianPolicy, JacobianMatrix, LinearSolver,
+};
+use crate::errors;
+use crate::iteratives;
+use crate::iteratives::Iterative;
+use crate::model;
+use crate::residuals;
+
+/// Next iterate of the Newton-Raphson method for the model at its current state
+///
+/// The jacobian is evaluated if required by `jacobian`, see [JacobianMatrix::compute_jacobian],
+/// with the model if it provides it or with finite differences otherwise,
+/// the inaccurate values being accepted (see [InaccurateJacobianPolicy::Accept]).
+/// Otherwise, the jacobian already held is reused:
+/// call [JacobianMatrix::force_jacobian_computation] to evaluate it again.
+///
+/// The step solves `J * dx = -F` with the inverse of the jacobian,
+/// `F` being the residuals normalized with their update method,
+/// and is limited by the step limitations of the iteratives.
+/// As the limitations give the next iterate directly, the step is its difference with the current iteratives.
+///
+/// The model must have been evaluated at its current state.
+/// It is evaluated at other points to compute the jacobian with finite differences.
+///
+/// This is the step performed by the [super::RootFinder] with the Newton-Raphson method,
+/// without damping, right members overrides or first iteration step scale.
+pub fn compute_newton_step<'a, M, D, T>(
+    model: &mut M,
+    iters_params: &'a iteratives::Iteratives<'a, T>,
+    residuals_config: &'a residuals::ResidualsConfig<'a>,
+    jacobian: &mut JacobianMatrix<D>,
+) -> Result<nalgebra::OVector<f64, D>, errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    T: Iterative + fmt::Display,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    if jacobian.compute_jacobian() {
+        let policy = InaccurateJacobianPolicy::Accept;
+        let result = if model.jacobian_provided() {
+            evaluate_jacobian_from_analytical_function(jacobian, model, residuals_config)
+        } else {
+            evaluate_jacobian_from_finite_difference(
+                jacobian,
+                model,
+                iters_params,
+                residuals_config,
+                policy,
+            )
+        };
+        apply_inaccurate_jacobian_policy(result, policy)?;
+    }
+
+    let residuals = residuals_config.evaluate_update_residuals(&model.get_residuals());
+    let raw_step = compute_raw_newton_step(jacobian, &residuals, LinearSolver::DirectInverse)?;
+
+    Ok(iters_params.step_limitations(&model.get_iteratives(), &raw_step))
+}
+
+/// Step of a Newton iteration before the step limitations,
+/// shared by [compute_newton_step] and the [super::RootFinder]
+///
+/// The errors of [solve_newton_system] are returned as errors of the solver control flow.
+pub(crate) fn compute_raw_newton_step<M, D>(
+    jacobian: &JacobianMatrix<D>,
+    update_residuals: &nalgebra::OVector<f64, D>,
+    linear_solver: LinearSolver,
+) -> Result<nalgebra::OVector<f64, D>, errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    solve_newton_system(jacobian, update_residuals, linear_solver).map_err(|error| match error {
+        errors::NewtonSystemError::MissingMatrix => {
+            errors::SolverInternalError::MissingLinearSystemMatrixError
+        }
+        errors::NewtonSystemError::LinearSolverNonConvergence => {
+            errors::SolverInternalError::LinearSolverNonConvergenceError
+        }
+    })
+}
+
+/// Solve the linear system `J * dx = -F` of a Newton iteration, without any step limitation
+///
+/// `update_residuals` are the residuals `F` normalized with their update method,
+/// see [residuals::ResidualsConfig::evaluate_update_residuals].
+///
+/// With [LinearSolver::DirectInverse], the inverse of the jacobian must be available,
+/// see [JacobianMatrix::set_inverse_required].
+/// With [LinearSolver::Gmres], the jacobian itself must be available
+/// and an error is returned if the linear solver does not converge.
+/// A missing matrix is returned as [errors::NewtonSystemError::MissingMatrix].
+pub fn solve_newton_system<D>(
+    jacobian: &JacobianMatrix<D>,
+    update_residuals: &nalgebra::OVector<f64, D>,
+    linear_solver: LinearSolver,
+) -> Result<nalgebra::OVector<f64, D>, errors::NewtonSystemError>
+where
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    match (
+        linear_solver,
+        jacobian.get_inverse(),
+        jacobian.get_jacobian(),
+    ) {
+        (LinearSolver::DirectInverse, Some(inverse), _) => Ok(-inverse * update_residuals),
+        (
+            LinearSolver::Gmres {
+                restart,
+                max_iter,
+                tol,
+            },
+            _,
+            Some(matrix),
+        ) => gmres(matrix, &-update_residuals, restart, max_iter, tol).map_err(
+            |errors::LinearSolverNonConvergence| {
+                errors::NewtonSystemError::LinearSolverNonConvergence
+            },
+        ),
+        _ => Err(errors::NewtonSystemError::MissingMatrix),
+    }
+}
+
+/// With [InaccurateJacobianPolicy::Accept], the inaccurate values of a jacobian evaluation are not an error
+pub(crate) fn apply_inaccurate_jacobian_policy<M, D>(
+    result: Result<(), errors::SolverInternalError<M, D>>,
+    policy: InaccurateJacobianPolicy,
+) -> Result<(), errors::SolverInternalError<M, D>>
+where
+    M: model::Model<D>,
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    match result {
+        Err(errors::SolverInternalError::InvalidJacobianError(error))
+            if error.is_inaccurate_values_error() && policy == InaccurateJacobianPolicy::Accept =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
@@ -1,11 +1,16 @@
-use super::ResolutionMethod;
-use std::fmt;
+use super::{LinearSolver, MaxIterations, ProblemSize, ResolutionMethod, SparsityPattern};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::errors::RootFinderConstructionError;
 
 /// A minimal struct holding the resolution parameters
 ///
 /// # Parameters
 /// ## Damping
-/// Activate the damping to improve convergence
+/// Activate the damping to improve convergence, the default being `Damping::Disabled`
+/// (see the enum `Damping`)
 ///
 /// Plain resolution according to Newton is made through the formula
 /// X = X - J^-1*F(X)
@@ -29,65 +34,1053 @@ use std::fmt;
 /// In the case of the jacobian has been recomputed at the current iteration,
 /// damping will be performed
 ///
+/// Whenever the jacobian is computed, the steps of the previous iterations are discarded:
+/// the quasi-Newton updates restart from the computed jacobian,
+/// the first one only using the step made with it.
+///
+/// ## Step acceptance norm
+/// The errors compared to decide if a step deteriorates the solution for the damping,
+/// the default being `StepAcceptanceNorm::Stopping`.
+///
+/// The step is computed from the residuals normalized with the update methods,
+/// while the convergence is checked on the residuals normalized with the stopping criterias.
+/// When they differ, a step decreasing the update residuals can increase the stopping errors and be damped,
+/// the jacobian of a quasi-Newton method being recomputed needlessly.
+///
+/// See the enum `StepAcceptanceNorm` for the options available and their trade-offs.
+///
 /// ## Tolerance
 /// The tolerance values used by the solver to check for convergence.
 ///
 /// Each residuals must be below this threshold
 ///
+/// It must be finite and at least [MIN_TOLERANCE]: the floating point residuals would never reach a lower one,
+/// the solver performing all its iterations in vain.
+/// `RootFinder::try_new()` returns an error otherwise, unless `SolverParameters::allow_extreme_tolerance()` has been called,
+/// which still requires a non-negative tolerance.
+///
+/// The iteratives with a convergence step tolerance (see `IterativeParams::set_convergence_step_tolerance()`)
+/// must also have their last step below their tolerance.
+/// The criterion satisfied last is given by `RootFinder::get_last_satisfied_criterion()`
+///
+/// If the initial guess is already converged, no iteration is performed.
+/// Its evaluation is then the final one: if the model flagged its values as inaccurate,
+/// the resolution fails with `SolverError::FinalEvaluationError`.
+///
 /// ## Max iteration
 /// The maximum number of iterations the solver is allowed to make
 ///
 /// This is required to avoid to have an infinte loop
 ///
+/// An iteration is a pass of the resolution loop: it proposes a new guess and evaluates the model with it.
+/// The first evaluation of the model, with the initial guess, is not an iteration:
+/// with `max_iter = 0`, the model is only evaluated at the initial guess.
+///
+/// The number of model evaluations performed by an iteration depends on the resolution:
+/// - one evaluation with the proposed guess
+/// - one additional evaluation if the damping is performed
+/// - `problem_size` additional evaluations if the jacobian is computed with finite differences
+///
+/// ## Max model evaluations
+/// An optional maximum number of evaluations of the model, the default being `None` (no limit).
+///
+/// It allows to define the computational budget of the resolution,
+/// whatever the resolution method and the way the jacobian is computed.
+/// All the evaluations are counted, including the initial one,
+/// the ones for the finite differences and the ones for the damping.
+///
+/// The budget is checked before each iteration: an iteration is never interrupted,
+/// hence the budget can be exceeded by the evaluations of the last iteration.
+///
+/// The budget that stopped a resolution is given by `RootFinder::get_exhausted_budget()`
+///
+/// ## Reject solution on bounds
+/// Reject a solution having iteratives on their bounds, the default being `false`.
+///
+/// When an iterative is on one of its bounds at convergence,
+/// the solution might only be the best point within the bounds and not a true root:
+/// its residuals are below the tolerance without being null,
+/// which usually means that the problem is outside of the modeled domain.
+///
+/// Such iteratives are given by `RootFinder::get_active_bound_variables()`.
+/// If this parameter is activated, the resolution returns `SolverError::ConvergedOnBoundsError` in this case.
+///
+/// ## Bound policy
+/// The treatment of an initial guess with iteratives outside of their bounds,
+/// the default being `BoundPolicy::Clamp`.
+///
+/// The initial guess can be out of the bounds after a modification by the user between two resolutions,
+/// for example when setting a new operating point.
+/// It is checked at the start of each resolution, before the first evaluation of the model,
+/// the fixed iteratives being not concerned.
+/// See the enum `BoundPolicy` for the options available.
+///
+/// ## Return best on failure
+/// Evaluate the model at the best iterate when the resolution fails, the default being `false`.
+///
+/// During the resolution, the iterate with the lowest maximum stopping error is kept,
+/// it is given by `RootFinder::get_best_iterate()`.
+/// If the resolution fails with `SolverError::NonConvergenceError`, `SolverError::ModelEvaluationError`,
+/// `SolverError::JacobianEvaluationError`, `SolverError::JacobianInversionError`, `SolverError::JacobianError`
+/// or `SolverError::ResidualMagnitudeExceeded`,
+/// the last iterate can be worse than a previous one.
+/// With this parameter activated, the model is evaluated again at the best iterate before the error is returned:
+/// the outputs of the model are then consistent with it, for example to restart the resolution from it.
+///
 /// ## Resolution Method
-/// See the enum `ResolutionMethod` for the options available
+/// See the enum `ResolutionMethod` for the options available,
+/// `SolverParameters::new()` and `set_resolution_method()` panicking for a nonlinear Gauss-Seidel method without scalar iteration
+///
+/// ## Newton warmup iterations
+/// Number of Newton-Raphson iterations performed before the quasi-Newton method, the default being `0`.
+///
+/// With a quasi-Newton method, the first iterations are performed with the Newton-Raphson method,
+/// the jacobian being computed at each of them, before switching to the configured method.
+/// It improves the quality of the jacobian used by the quasi-Newton method,
+/// which is of the most importance for the `StationaryNewton` method: its jacobian is then the one of the last warmup iteration.
+/// The switch is marked in the debug log.
+///
+/// It has no effect with the `NewtonRaphson` and `NonlinearGaussSeidel` methods.
+///
+/// ## Aitken acceleration
+/// An optional period, in iterations, of the Aitken extrapolation of the iterates of the `StationaryNewton` method,
+/// the default being `None` (no extrapolation).
+///
+/// The `StationaryNewton` method converges linearly, slowly if its rate is close to 1.
+/// Every `aitken_every` iterations, the iterate is replaced by the extrapolation of the last three iterates
+/// computed with the same jacobian, with the vector form of the Aitken delta-squared process:
+/// x* = x2 - (d2 . (d2 - d1)) / |d2 - d1|^2 * d2, with d1 = x1 - x0 and d2 = x2 - x1.
+/// The extrapolated step is subject to the step limitations and to the bounds of the iteratives.
+///
+/// The plain iterate is kept if the differences of the iterates are almost identical, see [AITKEN_DENOMINATOR_THRESHOLD],
+/// or if the extrapolated point does not decrease the maximum error:
+/// the model is then evaluated again at the plain iterate.
+/// Each extrapolation costs one evaluation of the model, two if it is rejected,
+/// and is noted in the debug log. The extrapolations kept are counted by `RootFinder::get_aitken_extrapolations()`.
+///
+/// It has no effect with the other methods, nor during the Newton warmup iterations.
+///
+/// ## First iteration step scale
+/// Factor applied to the maximum step sizes of the iteratives at the first iteration, the default being `1.0`.
+///
+/// The step limitations tuned for the end of the resolution can prevent the first step
+/// from moving far enough from a crude initial guess.
+/// At the first iteration, the `max_step_abs` and `max_step_rel` limitations are multiplied by this scale,
+/// `f64::INFINITY` removing them: the bounds of the iteratives are always enforced.
+/// The relaxation is noted in the debug log.
+///
+/// The iteratives implementing the `Iterative` trait apply the scale through its `step_limitation_with_scale()` method.
+///
+/// ## Linear solver
+/// The method solving the linear system of each iteration, the default being `LinearSolver::DirectInverse`.
+///
+/// For very large systems, `LinearSolver::Gmres` avoids the inversion of the jacobian.
+/// It cannot be used with the methods updating the inverse of the jacobian:
+/// setting an incompatible combination panics, see `LinearSolver::is_compatible_with()`
+///
+/// ## Inaccurate jacobian policy
+/// The treatment of the `InaccurateValuesError` raised by the model during the evaluation of the jacobian,
+/// the default being `InaccurateJacobianPolicy::Accept`.
+///
+/// See the enum `InaccurateJacobianPolicy` for the options available, the actions taken are noted in the debug log.
+///
+/// ## Structural singularity policy
+/// The treatment of the null rows and columns of a computed jacobian,
+/// the default being `StructuralSingularityPolicy::Fail`.
+///
+/// A residual depending on no iterative, or an iterative affecting no residual, makes the jacobian singular.
+/// See the enum `StructuralSingularityPolicy` for the options available.
+///
+/// ## Perturbation jitter
+/// An optional relative amplitude of the jitter of the finite difference perturbations, the default being `None` (no jitter).
+///
+/// With piecewise-linear lookups, the perturbations can land exactly on table breakpoints,
+/// producing a systematically wrong jacobian.
+/// Each perturbation is then multiplied by the factor given by [perturbation_jitter_factor]
+/// from the iteration and the index of the iterative, in `[1 - amplitude, 1 + amplitude)`:
+/// the factors are pseudo-random but deterministic, the resolutions being reproducible.
+/// The amplitude must be in `]0, 1[`, for example `0.1` for a jitter of ±10%.
+///
+/// The jittered perturbations are given by `RootFinder::get_perturbations()`, the jitter being noted in the debug log.
+/// It has no effect with a jacobian provided by the model.
+///
+/// ## Sparsity pattern
+/// The optional structurally nonzero entries of the jacobian, the default being `None`.
+///
+/// It is required by the [crate::solver::UpdateQuasiNewtonMethod::SchubertSparse] update,
+/// which preserves the zeros of the pattern, and has no effect with the other methods.
+/// Its size must be the problem size, see [SparsityPattern].
+///
+/// ## Cycle detection
+/// An optional action breaking the period-2 cycles of the iterates, the default being `None` (no detection).
+///
+/// With the step limitation, the iterates can bounce between two points with the same errors,
+/// until the maximum number of iterations is reached: the damping is not triggered, as the error does not increase.
+/// A cycle is detected when the proposed iterate is the one of two iterations before,
+/// within the relative tolerance [CYCLE_TOLERANCE], while the error has not decreased since then.
+///
+/// See the enum `CycleBreakingAction` for the options available.
+/// The number of cycles detected is given by `RootFinder::get_cycles_detected()` and each detection is noted in the debug log.
 ///
 /// ## Problem size
-/// The dimension of the problem for the resolution
+/// The dimension of the problem for the resolution, given by a [ProblemSize] which cannot be zero.
+///
+/// It is distinct from the [MaxIterations] given to `SolverParameters::new()`,
+/// to prevent swapping them silently: the integers are wrapped with [ProblemSize::new] and [MaxIterations::new]
+///
+/// The problem size is already given by the lengths of the initial guess, the iteratives and the residuals configuration:
+/// with `SolverParameters::with_inferred_size()`, it is omitted and inferred by `RootFinder::new()` from the initial guess.
+/// When it is provided, it is only a cross-check of these lengths.
+///
+/// ## Memory limit
+/// An optional limit in bytes of the storage of the jacobian, the default being `None` (no limit).
+///
+/// For large problems, the dense jacobian and its inverse can exhaust the memory in the middle of a resolution:
+/// with a limit, `RootFinder::try_new()` returns `RootFinderConstructionError::MemoryLimitExceeded`
+/// if the storage estimated by [super::estimate_memory_bytes] for the problem size, the resolution method
+/// and the linear solver is above it.
+/// The [super::QuasiNewtonMethod::LimitedMemoryBroyden] method and the [LinearSolver::Gmres] linear solver
+/// require less storage.
+///
+/// The estimate is given by `RootFinder::estimated_memory_bytes()`.
+///
+/// ## Error smoothing
+/// An optional coefficient of the exponential moving average of the stopping errors, the default being `None` (no smoothing).
+///
+/// With a model including stochastic parts, such as a Monte-Carlo computation,
+/// the errors fluctuate between evaluations at the same iteratives
+/// and the convergence can be randomly declared or missed when they are close to the tolerance.
+/// With a coefficient `a` in `]0, 1]`, the convergence is checked on the smoothed errors `s_k = a * e_k + (1 - a) * s_{k-1}`,
+/// computed per residual from the stopping errors `e_k` of the iterations of the resolution, starting with the initial ones.
+/// The raw errors are still the ones written in the debug log and used by the resolution method and the damping.
+/// A non-finite error replaces the smoothed one.
+///
+/// ## Consecutive convergences
+/// The number of consecutive iterations with the errors, smoothed if required, below the tolerance
+/// to declare the convergence, the default being `1`.
+///
+/// Both the smoothed errors and the count of iterations are noted in the debug log when they are used.
+/// They start again from the current errors when a resolution is resumed from a snapshot.
+///
+/// ## Scalar safeguard
+/// Refine the overshooting steps of the problems of size 1 by bracketing the root, the default being `false`.
+///
+/// With an oscillatory function, a Newton step can jump over the root to a point where the slope drives the next steps away.
+/// For a problem with a single iterative, the root is bracketed when the sign of the update residual
+/// flips between the current and the proposed iterates:
+/// the step is then refined with regula falsi iterations in the bracket (Illinois variant),
+/// the last point evaluated being the new iterate from which the resolution method continues.
+/// The number of refinements, the default being `10`, is set with `set_scalar_safeguard_refinements()`,
+/// they stop earlier if the update residual vanishes.
+///
+/// Each refinement is an evaluation of the model, noted in the debug log.
+/// The safeguard is ignored for larger problems, a [super::CompatibilityWarning] being noted in the debug log.
+#[derive(Clone)]
 pub struct SolverParameters {
-    problem_size: usize,
+    problem_size: Option<usize>,
     tolerance: f64,
+    extreme_tolerance_allowed: bool,
     max_iter: usize,
+    max_model_evaluations: Option<usize>,
+    reject_solution_on_bounds: bool,
+    bound_policy: BoundPolicy,
+    return_best_on_failure: bool,
     resolution_method: ResolutionMethod,
-    damping: bool,
+    newton_warmup_iterations: usize,
+    aitken_acceleration: Option<usize>,
+    first_iteration_step_scale: f64,
+    linear_solver: LinearSolver,
+    inaccurate_jacobian_policy: InaccurateJacobianPolicy,
+    structural_singularity_policy: StructuralSingularityPolicy,
+    perturbation_jitter: Option<f64>,
+    sparsity_pattern: Option<SparsityPattern>,
+    cycle_detection: Option<CycleBreakingAction>,
+    step_acceptance_norm: StepAcceptanceNorm,
+    memory_limit_bytes: Option<usize>,
+    error_smoothing: Option<f64>,
+    consecutive_convergences: usize,
+    scalar_safeguard: bool,
+    scalar_safeguard_refinements: usize,
+    damping: Damping,
+}
+
+/// Damping of the steps deteriorating the solution, see [SolverParameters]
+///
+/// A boolean is converted with `into()`, `true` giving [Damping::Enabled]:
+/// the enum is non exhaustive, other damping strategies being possibly added.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub enum Damping {
+    /// The steps are never damped
+    #[default]
+    Disabled,
+    /// A step increasing the error is damped, as described in [SolverParameters]
+    Enabled,
+}
+
+impl Damping {
+    /// Check if the steps can be damped
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Damping::Disabled => false,
+            Damping::Enabled => true,
+        }
+    }
+}
+
+impl From<bool> for Damping {
+    fn from(damping: bool) -> Self {
+        if damping {
+            Damping::Enabled
+        } else {
+            Damping::Disabled
+        }
+    }
+}
+
+impl fmt::Display for Damping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Damping::Disabled => write!(f, "Disabled"),
+            Damping::Enabled => write!(f, "Enabled"),
+        }
+    }
+}
+
+/// Budget of the resolution that has been exhausted, see [SolverParameters]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ExhaustedBudget {
+    /// The maximum number of iterations has been reached
+    Iterations,
+    /// The maximum number of model evaluations has been reached
+    ModelEvaluations,
+}
+
+impl fmt::Display for ExhaustedBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExhaustedBudget::Iterations => write!(f, "maximum number of iterations"),
+            ExhaustedBudget::ModelEvaluations => write!(f, "maximum number of model evaluations"),
+        }
+    }
+}
+
+/// Convergence criterion, see [SolverParameters]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConvergenceCriterion {
+    /// The residuals are below the tolerance of the solver
+    Residuals,
+    /// The steps of the iteratives are below their convergence step tolerance
+    Step,
+    /// The residuals are below the tolerance at the initial guess, no iteration being performed
+    InitialGuess,
+    /// The custom convergence predicate decided the convergence,
+    /// see [super::RootFinder::set_convergence_predicate]
+    CustomPredicate,
+}
+
+impl fmt::Display for ConvergenceCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConvergenceCriterion::Residuals => write!(f, "residuals tolerance"),
+            ConvergenceCriterion::Step => write!(f, "iteratives step tolerance"),
+            ConvergenceCriterion::InitialGuess => write!(f, "initial guess converged"),
+            ConvergenceCriterion::CustomPredicate => write!(f, "custom predicate satisfied"),
+        }
+    }
+}
+
+/// Treatment of the inaccurate values during the evaluation of the jacobian, see [SolverParameters]
+///
+/// The model flags inaccurate values by returning `ModelError::InaccurateValuesError`.
+/// With finite differences, this can happen for the evaluation of a perturbed column,
+/// with an analytical jacobian, for the call to `Model::get_jacobian()` or `Model::get_jacobian_column()`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum InaccurateJacobianPolicy {
+    /// The inaccurate values are used as if they were accurate
+    #[default]
+    Accept,
+    /// The column is evaluated again with its perturbation divided by two, at most `max_retries` times,
+    /// the resolution failing with `SolverError::JacobianEvaluationError` if the values are still inaccurate.
+    /// The finite differences retry the unusable values of a perturbed point the same way,
+    /// the model failing possibly at the perturbed point only.
+    ///
+    /// With an analytical jacobian, there is no perturbation to reduce: it behaves as [InaccurateJacobianPolicy::Fail]
+    RetryColumnWithReducedStep { max_retries: usize },
+    /// The resolution fails with `SolverError::JacobianEvaluationError`
+    Fail,
+}
+
+impl fmt::Display for InaccurateJacobianPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InaccurateJacobianPolicy::Accept => write!(f, "Accept"),
+            InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries } => {
+                write!(
+                    f,
+                    "Retry column with reduced step ({} retries)",
+                    max_retries
+                )
+            }
+            InaccurateJacobianPolicy::Fail => write!(f, "Fail"),
+        }
+    }
+}
+
+/// Treatment of the null rows and columns of a computed jacobian, see [SolverParameters]
+///
+/// They are checked after each computation of the full jacobian, analytical or by finite differences,
+/// before its inversion.
+/// The rows of the ignored residuals and the columns of the fixed iteratives are not considered.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum StructuralSingularityPolicy {
+    /// The resolution fails with `SolverError::StructurallySingularJacobian`,
+    /// giving the indices of the null rows and columns
+    #[default]
+    Fail,
+    /// The k-th null row and the k-th null column are removed from the system,
+    /// as an ignored residual and a fixed iterative, and the resolution goes on with the reduced system.
+    ///
+    /// The removed residuals do not take part in the convergence anymore, and the removed iteratives are not updated,
+    /// until the end of the resolution.
+    /// The pairs are given by `RootFinder::get_structural_reductions()` and noted in the debug log with a warning.
+    ///
+    /// The resolution fails as with [StructuralSingularityPolicy::Fail] if the numbers of null rows and null columns differ.
+    Reduce,
+}
+
+impl fmt::Display for StructuralSingularityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StructuralSingularityPolicy::Fail => write!(f, "Fail"),
+            StructuralSingularityPolicy::Reduce => write!(f, "Reduce"),
+        }
+    }
+}
+
+/// Treatment of the iteratives of the initial guess outside of their bounds, see [SolverParameters]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum BoundPolicy {
+    /// The iteratives are projected onto their nearest bound, each adjustment being noted in the debug log
+    #[default]
+    Clamp,
+    /// The resolution fails with `SolverError::InitialGuessOutOfBounds`,
+    /// giving the first iterative outside of its bounds
+    Fail,
+    /// The initial guess is used as given, the step limitations bringing the iteratives back within their bounds
+    Ignore,
+}
+
+impl fmt::Display for BoundPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoundPolicy::Clamp => write!(f, "Clamp"),
+            BoundPolicy::Fail => write!(f, "Fail"),
+            BoundPolicy::Ignore => write!(f, "Ignore"),
+        }
+    }
+}
+
+/// Smallest tolerance accepted by the solver, see [SolverParameters]
+///
+/// It is a few times the machine epsilon: a residual of order one cannot be computed more accurately.
+pub const MIN_TOLERANCE: f64 = 1e-15;
+
+/// Relative tolerance used to detect a cycle, see [SolverParameters]
+///
+/// The proposed iterate `x` is the iterate `p` of two iterations before if `abs(x - p) <= CYCLE_TOLERANCE * max(1, abs(p))`
+/// for all the iteratives
+pub const CYCLE_TOLERANCE: f64 = 1e-6;
+
+/// Relative threshold under which the Aitken extrapolation is skipped, see [SolverParameters]
+///
+/// With the last differences of the iterates `d1` and `d2`,
+/// the plain iterate is kept if `|d2 - d1|^2 <= AITKEN_DENOMINATOR_THRESHOLD * |d2|^2`:
+/// the extrapolated step would be arbitrarily large.
+pub const AITKEN_DENOMINATOR_THRESHOLD: f64 = 1e-12;
+
+/// Maximum number of step reductions after a model error naming iteratives, see [crate::model::ErrorContext]
+///
+/// If the evaluation of the model at the iterate proposed by an iteration returns unusable values
+/// whose context gives `iterative_indices`, the step of these iteratives is halved,
+/// the other ones keeping theirs, and the model is evaluated again.
+/// Once the reductions are exhausted, the error stops the resolution as without context.
+///
+/// Inaccurate values are accepted at the proposed iterate, whatever their context.
+pub const MAX_CONTEXT_STEP_REDUCTIONS: usize = 10;
+
+/// Action taken when a cycle of the iterates is detected, see [SolverParameters]
+///
+/// The model is evaluated at the new guess, replacing the proposed one, without any damping.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum CycleBreakingAction {
+    /// The new guess is the middle of the two points of the cycle
+    #[default]
+    DampedAverage,
+    /// The new guess is obtained with half of the proposed step, from the current iterate
+    DampedHalfStep,
+    /// The proposed guess is kept, the jacobian being recomputed at the next iteration.
+    ///
+    /// It has no effect with the `NewtonRaphson` method, which computes the jacobian at each iteration
+    RecomputeJacobian,
+}
+
+impl fmt::Display for CycleBreakingAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CycleBreakingAction::DampedAverage => write!(f, "Damped average"),
+            CycleBreakingAction::DampedHalfStep => write!(f, "Damped half-step"),
+            CycleBreakingAction::RecomputeJacobian => write!(f, "Recompute jacobian"),
+        }
+    }
+}
+
+/// Errors compared to accept a step, see [SolverParameters]
+///
+/// A step is rejected if the maximum of the errors at the proposed guess is above the one at the current guess,
+/// the damping being then performed.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum StepAcceptanceNorm {
+    /// The residuals normalized with the stopping criterias
+    ///
+    /// The steps are accepted when they improve the convergence criterion,
+    /// but a step of the resolution method can be rejected if the stopping and update methods differ.
+    #[default]
+    Stopping,
+    /// The residuals normalized with the update methods
+    ///
+    /// The steps are accepted when they improve the residuals on which the resolution method works,
+    /// even if the stopping errors increase.
+    /// The update residuals are computed again for the comparison at each iteration.
+    Update,
+    /// Both residuals, the step being rejected if either of the errors increases
+    ///
+    /// It is the most conservative option, performing the most damping.
+    Both,
+}
+
+impl fmt::Display for StepAcceptanceNorm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StepAcceptanceNorm::Stopping => write!(f, "Stopping"),
+            StepAcceptanceNorm::Update => write!(f, "Update"),
+            StepAcceptanceNorm::Both => write!(f, "Both"),
+        }
+    }
 }
 
 impl SolverParameters {
     pub fn new(
+        problem_size: ProblemSize,
+        tolerance: f64,
+        max_iter: MaxIterations,
+        resolution_method: ResolutionMethod,
+        damping: Damping,
+    ) -> Self {
+        let mut parameters =
+            SolverParameters::with_inferred_size(tolerance, max_iter, resolution_method, damping);
+        parameters.set_problem_size(problem_size);
+        parameters
+    }
+
+    /// Constructor without the problem size, inferred when building the [super::RootFinder]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::{MaxIterations, ResolutionMethod, SolverParameters};
+    ///
+    /// let parameters =
+    ///     SolverParameters::with_inferred_size(1e-6, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+    /// assert_eq!(parameters.get_problem_size(), None);
+    ///
+    /// let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    /// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    /// let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    /// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// let rf = nrf::solver::RootFinder::new(
+    ///     parameters,
+    ///     nalgebra::DVector::zeros(2),
+    ///     &iter_params,
+    ///     &res_config,
+    /// );
+    /// assert_eq!(rf.get_parameters().get_problem_size(), Some(2));
+    /// ```
+    pub fn with_inferred_size(
+        tolerance: f64,
+        max_iter: MaxIterations,
+        resolution_method: ResolutionMethod,
+        damping: Damping,
+    ) -> Self {
+        check_resolution_method(resolution_method);
+
+        SolverParameters {
+            problem_size: None,
+            tolerance,
+            extreme_tolerance_allowed: false,
+            max_iter: max_iter.get(),
+            max_model_evaluations: None,
+            reject_solution_on_bounds: false,
+            bound_policy: BoundPolicy::default(),
+            return_best_on_failure: false,
+            resolution_method,
+            newton_warmup_iterations: 0,
+            aitken_acceleration: None,
+            first_iteration_step_scale: 1.0,
+            linear_solver: LinearSolver::default(),
+            inaccurate_jacobian_policy: InaccurateJacobianPolicy::default(),
+            structural_singularity_policy: StructuralSingularityPolicy::default(),
+            perturbation_jitter: None,
+            sparsity_pattern: None,
+            cycle_detection: None,
+            step_acceptance_norm: StepAcceptanceNorm::default(),
+            memory_limit_bytes: None,
+            error_smoothing: None,
+            consecutive_convergences: 1,
+            scalar_safeguard: false,
+            scalar_safeguard_refinements: 10,
+            damping,
+        }
+    }
+
+    /// Constructor taking the problem size and the maximum number of iterations as raw integers
+    ///
+    /// It panics if the problem size is zero, as [ProblemSize::new]
+    #[deprecated(
+        since = "0.12.0",
+        note = "use `SolverParameters::new()` with `ProblemSize::new()` and `MaxIterations::new()`"
+    )]
+    pub fn from_usizes(
         problem_size: usize,
         tolerance: f64,
         max_iter: usize,
         resolution_method: ResolutionMethod,
         damping: bool,
     ) -> Self {
-        SolverParameters {
+        SolverParameters::new(
+            ProblemSize::new(problem_size),
+            tolerance,
+            MaxIterations::new(max_iter),
+            resolution_method,
+            damping.into(),
+        )
+    }
+
+    /// Constructor taking the damping as a boolean, `true` giving [Damping::Enabled]
+    #[deprecated(
+        since = "0.12.0",
+        note = "use `SolverParameters::new()` with `Damping::Enabled` or `Damping::Disabled`"
+    )]
+    pub fn from_bool_damping(
+        problem_size: ProblemSize,
+        tolerance: f64,
+        max_iter: MaxIterations,
+        resolution_method: ResolutionMethod,
+        damping: bool,
+    ) -> Self {
+        SolverParameters::new(
             problem_size,
             tolerance,
             max_iter,
             resolution_method,
-            damping,
-        }
+            damping.into(),
+        )
     }
 
-    pub fn get_problem_size(&self) -> usize {
+    /// Problem size, either provided or inferred by the [super::RootFinder] holding the parameters
+    ///
+    /// `None` if the parameters have been built with `SolverParameters::with_inferred_size()`
+    /// and have not been given to a [super::RootFinder] yet
+    pub fn get_problem_size(&self) -> Option<usize> {
         self.problem_size
     }
 
+    pub fn set_problem_size(&mut self, problem_size: ProblemSize) {
+        self.problem_size = Some(problem_size.get());
+    }
+
     pub fn get_tolerance(&self) -> f64 {
         self.tolerance
     }
 
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Accept a tolerance below [MIN_TOLERANCE], down to zero
+    ///
+    /// The residuals are then unlikely to reach the tolerance: the resolution usually stops
+    /// when the budget is exhausted, see [SolverParameters::set_max_model_evaluations].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::errors::RootFinderConstructionError;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::{MaxIterations, ProblemSize, ResolutionMethod, SolverParameters, MIN_TOLERANCE};
+    ///
+    /// let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    /// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    /// let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    /// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// let mut parameters =
+    ///     SolverParameters::new(ProblemSize::new(1), 0.0, MaxIterations::new(10), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+    ///
+    /// let result = nrf::solver::RootFinder::try_new(
+    ///     parameters.clone(),
+    ///     nalgebra::DVector::from_vec(vec![1.0]),
+    ///     &iter_params,
+    ///     &res_config,
+    /// );
+    /// assert_eq!(
+    ///     result.err(),
+    ///     Some(RootFinderConstructionError::ToleranceBelowFloor {
+    ///         tolerance: 0.0,
+    ///         floor: MIN_TOLERANCE
+    ///     })
+    /// );
+    ///
+    /// parameters.allow_extreme_tolerance();
+    /// let result = nrf::solver::RootFinder::try_new(
+    ///     parameters,
+    ///     nalgebra::DVector::from_vec(vec![1.0]),
+    ///     &iter_params,
+    ///     &res_config,
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn allow_extreme_tolerance(&mut self) {
+        self.extreme_tolerance_allowed = true;
+    }
+
+    pub fn get_extreme_tolerance_allowed(&self) -> bool {
+        self.extreme_tolerance_allowed
+    }
+
+    /// Check that the tolerance is finite and at least [MIN_TOLERANCE], see [SolverParameters]
+    pub fn check_tolerance(&self) -> Result<(), RootFinderConstructionError> {
+        if !self.tolerance.is_finite() || self.tolerance < 0.0 {
+            Err(RootFinderConstructionError::InvalidTolerance {
+                tolerance: self.tolerance,
+            })
+        } else if self.tolerance < MIN_TOLERANCE && !self.extreme_tolerance_allowed {
+            Err(RootFinderConstructionError::ToleranceBelowFloor {
+                tolerance: self.tolerance,
+                floor: MIN_TOLERANCE,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn get_max_iter(&self) -> usize {
         self.max_iter
     }
 
+    pub fn set_max_iter(&mut self, max_iter: usize) {
+        self.max_iter = max_iter;
+    }
+
+    pub fn get_max_model_evaluations(&self) -> Option<usize> {
+        self.max_model_evaluations
+    }
+
+    pub fn set_max_model_evaluations(&mut self, max_model_evaluations: Option<usize>) {
+        self.max_model_evaluations = max_model_evaluations;
+    }
+
+    pub fn get_reject_solution_on_bounds(&self) -> bool {
+        self.reject_solution_on_bounds
+    }
+
+    pub fn set_reject_solution_on_bounds(&mut self, reject_solution_on_bounds: bool) {
+        self.reject_solution_on_bounds = reject_solution_on_bounds;
+    }
+
+    pub fn get_return_best_on_failure(&self) -> bool {
+        self.return_best_on_failure
+    }
+
+    pub fn set_return_best_on_failure(&mut self, return_best_on_failure: bool) {
+        self.return_best_on_failure = return_best_on_failure;
+    }
+
     pub fn get_resolution_method(&self) -> ResolutionMethod {
         self.resolution_method
     }
 
-    pub fn get_damping(&self) -> bool {
+    pub fn get_newton_warmup_iterations(&self) -> usize {
+        self.newton_warmup_iterations
+    }
+
+    pub fn set_newton_warmup_iterations(&mut self, newton_warmup_iterations: usize) {
+        self.newton_warmup_iterations = newton_warmup_iterations;
+    }
+
+    pub fn get_aitken_acceleration(&self) -> Option<usize> {
+        self.aitken_acceleration
+    }
+
+    /// # Panics
+    ///
+    /// If the period of the extrapolations is zero
+    pub fn set_aitken_acceleration(&mut self, aitken_every: Option<usize>) {
+        if aitken_every == Some(0) {
+            panic!("The period of the Aitken acceleration must be strictly positive, got 0");
+        }
+        self.aitken_acceleration = aitken_every;
+    }
+
+    pub fn get_first_iteration_step_scale(&self) -> f64 {
+        self.first_iteration_step_scale
+    }
+
+    /// # Panics
+    ///
+    /// If the scale is not strictly positive
+    pub fn set_first_iteration_step_scale(&mut self, first_iteration_step_scale: f64) {
+        if first_iteration_step_scale.is_nan() || first_iteration_step_scale <= 0.0 {
+            panic!(
+                "The first iteration step scale must be strictly positive, provided value was {}",
+                first_iteration_step_scale
+            );
+        }
+        self.first_iteration_step_scale = first_iteration_step_scale;
+    }
+
+    pub fn get_linear_solver(&self) -> LinearSolver {
+        self.linear_solver
+    }
+
+    pub fn set_linear_solver(&mut self, linear_solver: LinearSolver) {
+        check_compatibility(linear_solver, self.resolution_method);
+        self.linear_solver = linear_solver;
+    }
+
+    pub fn get_inaccurate_jacobian_policy(&self) -> InaccurateJacobianPolicy {
+        self.inaccurate_jacobian_policy
+    }
+
+    pub fn set_inaccurate_jacobian_policy(
+        &mut self,
+        inaccurate_jacobian_policy: InaccurateJacobianPolicy,
+    ) {
+        self.inaccurate_jacobian_policy = inaccurate_jacobian_policy;
+    }
+
+    pub fn get_bound_policy(&self) -> BoundPolicy {
+        self.bound_policy
+    }
+
+    pub fn set_bound_policy(&mut self, bound_policy: BoundPolicy) {
+        self.bound_policy = bound_policy;
+    }
+
+    pub fn get_structural_singularity_policy(&self) -> StructuralSingularityPolicy {
+        self.structural_singularity_policy
+    }
+
+    pub fn set_structural_singularity_policy(
+        &mut self,
+        structural_singularity_policy: StructuralSingularityPolicy,
+    ) {
+        self.structural_singularity_policy = structural_singularity_policy;
+    }
+
+    pub fn get_perturbation_jitter(&self) -> Option<f64> {
+        self.perturbation_jitter
+    }
+
+    /// # Panics
+    ///
+    /// If the amplitude is not strictly between `0` and `1`
+    pub fn set_perturbation_jitter(&mut self, perturbation_jitter: Option<f64>) {
+        if let Some(amplitude) = perturbation_jitter {
+            if amplitude.is_nan() || amplitude <= 0.0 || amplitude >= 1.0 {
+                panic!(
+                    "The perturbation jitter must be strictly between 0 and 1, provided value was {}",
+                    amplitude
+                );
+            }
+        }
+        self.perturbation_jitter = perturbation_jitter;
+    }
+
+    pub fn get_sparsity_pattern(&self) -> Option<&SparsityPattern> {
+        self.sparsity_pattern.as_ref()
+    }
+
+    pub fn set_sparsity_pattern(&mut self, sparsity_pattern: Option<SparsityPattern>) {
+        self.sparsity_pattern = sparsity_pattern;
+    }
+
+    pub fn get_cycle_detection(&self) -> Option<CycleBreakingAction> {
+        self.cycle_detection
+    }
+
+    pub fn set_cycle_detection(&mut self, cycle_detection: Option<CycleBreakingAction>) {
+        self.cycle_detection = cycle_detection;
+    }
+
+    pub fn get_step_acceptance_norm(&self) -> StepAcceptanceNorm {
+        self.step_acceptance_norm
+    }
+
+    pub fn set_step_acceptance_norm(&mut self, step_acceptance_norm: StepAcceptanceNorm) {
+        self.step_acceptance_norm = step_acceptance_norm;
+    }
+
+    pub fn get_memory_limit_bytes(&self) -> Option<usize> {
+        self.memory_limit_bytes
+    }
+
+    pub fn set_memory_limit_bytes(&mut self, memory_limit_bytes: Option<usize>) {
+        self.memory_limit_bytes = memory_limit_bytes;
+    }
+
+    /// Check that the storage estimated by [super::estimate_memory_bytes] is below the memory limit, see [SolverParameters]
+    ///
+    /// Without problem size, the check is left to the [super::RootFinder] inferring it, see `get_problem_size()`
+    pub fn check_memory_limit(&self) -> Result<(), RootFinderConstructionError> {
+        let (limit_bytes, problem_size) = match (self.memory_limit_bytes, self.problem_size) {
+            (Some(limit_bytes), Some(problem_size)) => (limit_bytes, problem_size),
+            _ => return Ok(()),
+        };
+        let estimated_bytes =
+            super::estimate_memory_bytes(problem_size, self.resolution_method, self.linear_solver);
+        if estimated_bytes > limit_bytes {
+            Err(RootFinderConstructionError::MemoryLimitExceeded {
+                estimated_bytes,
+                limit_bytes,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn get_error_smoothing(&self) -> Option<f64> {
+        self.error_smoothing
+    }
+
+    /// # Panics
+    ///
+    /// If the coefficient is not in `]0, 1]`
+    pub fn set_error_smoothing(&mut self, error_smoothing: Option<f64>) {
+        if let Some(coefficient) = error_smoothing {
+            if coefficient.is_nan() || coefficient <= 0.0 || coefficient > 1.0 {
+                panic!(
+                    "The error smoothing coefficient must be in ]0, 1], provided value was {}",
+                    coefficient
+                );
+            }
+        }
+        self.error_smoothing = error_smoothing;
+    }
+
+    pub fn get_consecutive_convergences(&self) -> usize {
+        self.consecutive_convergences
+    }
+
+    /// # Panics
+    ///
+    /// If the number of consecutive convergences is zero
+    pub fn set_consecutive_convergences(&mut self, consecutive_convergences: usize) {
+        if consecutive_convergences == 0 {
+            panic!("The number of consecutive convergences must be strictly positive, got 0");
+        }
+        self.consecutive_convergences = consecutive_convergences;
+    }
+
+    pub fn get_scalar_safeguard(&self) -> bool {
+        self.scalar_safeguard
+    }
+
+    pub fn set_scalar_safeguard(&mut self, scalar_safeguard: bool) {
+        self.scalar_safeguard = scalar_safeguard;
+    }
+
+    pub fn get_scalar_safeguard_refinements(&self) -> usize {
+        self.scalar_safeguard_refinements
+    }
+
+    /// # Panics
+    ///
+    /// If the number of refinements is zero
+    pub fn set_scalar_safeguard_refinements(&mut self, refinements: usize) {
+        if refinements == 0 {
+            panic!("The number of refinements of the scalar safeguard must be strictly positive, got 0");
+        }
+        self.scalar_safeguard_refinements = refinements;
+    }
+
+    pub fn get_damping(&self) -> Damping {
         self.damping
     }
+
+    pub fn set_damping(&mut self, damping: Damping) {
+        self.damping = damping;
+    }
+
+    pub fn set_resolution_method(&mut self, resolution_method: ResolutionMethod) {
+        check_resolution_method(resolution_method);
+        check_compatibility(self.linear_solver, resolution_method);
+        self.resolution_method = resolution_method;
+    }
+}
+
+/// Factor applied to the finite difference perturbation of the iterative `index` at the `iteration`,
+/// see the perturbation jitter of [SolverParameters]
+///
+/// The generator is fixed, to keep the resolutions reproducible across runs and platforms:
+/// the state `(iteration << 32 ^ index) * 0x9E3779B97F4A7C15 | 1` (64 bits, wrapping)
+/// goes through four xorshift64 steps (shifts 13, 7 and 17),
+/// its 53 upper bits giving the uniform value `u` in `[0, 1)`.
+/// The factor is `1 + amplitude * (2 * u - 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+///
+/// let factor = nrf::solver::perturbation_jitter_factor(0.1, 3, 0);
+/// assert!(0.9 <= factor && factor < 1.1);
+/// assert_eq!(factor, nrf::solver::perturbation_jitter_factor(0.1, 3, 0));
+/// ```
+pub fn perturbation_jitter_factor(amplitude: f64, iteration: usize, index: usize) -> f64 {
+    let mut state =
+        (((iteration as u64) << 32) ^ index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    for _ in 0..4 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+    }
+    let uniform = (state >> 11) as f64 / (1_u64 << 53) as f64;
+    1.0 + amplitude * (2.0 * uniform - 1.0)
+}
+
+fn check_resolution_method(resolution_method: ResolutionMethod) {
+    if let ResolutionMethod::NonlinearGaussSeidel {
+        inner_scalar_iterations: 0,
+    } = resolution_method
+    {
+        panic!("The nonlinear Gauss-Seidel method requires at least one scalar iteration per iterative");
+    }
+}
+
+fn check_compatibility(linear_solver: LinearSolver, resolution_method: ResolutionMethod) {
+    if !linear_solver.is_compatible_with(resolution_method) {
+        panic!(
+            "The linear solver {} cannot be used with the resolution method {}, as it does not compute the inverse of the jacobian",
+            linear_solver, resolution_method
+        );
+    }
 }
 
 impl fmt::Display for SolverParameters {
@@ -100,11 +1093,11 @@ impl fmt::Display for SolverParameters {
         content.push_str(separation_line);
         content.push_str(header);
         content.push_str(separation_line);
-        content.push_str(&format!(
-            "| {:width$}",
-            self.problem_size.to_string(),
-            width = 15
-        ));
+        let problem_size = match self.problem_size {
+            Some(problem_size) => problem_size.to_string(),
+            None => "inferred".to_string(),
+        };
+        content.push_str(&format!("| {:width$}", problem_size, width = 15));
         content.push_str(&format!(
             "| {:width$}",
             self.max_iter.to_string(),
@@ -117,7 +1110,7 @@ impl fmt::Display for SolverParameters {
         ));
         content.push_str(&format!(
             "| {:width$}",
-            self.damping.to_string(),
+            self.damping.is_enabled().to_string(),
             width = 19
         ));
         content.push_str(&format!(
@@ -127,6 +1120,93 @@ impl fmt::Display for SolverParameters {
         ));
         content.push_str("|\n");
         content.push_str(separation_line);
+        if self.extreme_tolerance_allowed {
+            content.push_str("Extreme tolerance allowed\n");
+        }
+        if let Some(max_model_evaluations) = self.max_model_evaluations {
+            content.push_str(&format!(
+                "Max model evaluations: {}\n",
+                max_model_evaluations
+            ));
+        }
+        if self.reject_solution_on_bounds {
+            content.push_str("Solution on bounds rejected\n");
+        }
+        if self.bound_policy != BoundPolicy::Clamp {
+            content.push_str(&format!("Bound policy: {}\n", self.bound_policy));
+        }
+        if self.return_best_on_failure {
+            content.push_str("Best iterate returned on failure\n");
+        }
+        if self.newton_warmup_iterations > 0 {
+            content.push_str(&format!(
+                "Newton warmup iterations: {}\n",
+                self.newton_warmup_iterations
+            ));
+        }
+        if let Some(aitken_every) = self.aitken_acceleration {
+            content.push_str(&format!(
+                "Aitken acceleration every {} iterations\n",
+                aitken_every
+            ));
+        }
+        if self.first_iteration_step_scale != 1.0 {
+            content.push_str(&format!(
+                "First iteration step scale: {}\n",
+                self.first_iteration_step_scale
+            ));
+        }
+        if self.linear_solver != LinearSolver::DirectInverse {
+            content.push_str(&format!("Linear solver: {}\n", self.linear_solver));
+        }
+        if self.inaccurate_jacobian_policy != InaccurateJacobianPolicy::Accept {
+            content.push_str(&format!(
+                "Inaccurate jacobian policy: {}\n",
+                self.inaccurate_jacobian_policy
+            ));
+        }
+        if self.structural_singularity_policy != StructuralSingularityPolicy::Fail {
+            content.push_str(&format!(
+                "Structural singularity policy: {}\n",
+                self.structural_singularity_policy
+            ));
+        }
+        if let Some(amplitude) = self.perturbation_jitter {
+            content.push_str(&format!("Perturbation jitter: {}\n", amplitude));
+        }
+        if let Some(sparsity_pattern) = &self.sparsity_pattern {
+            content.push_str(&format!(
+                "Sparsity pattern: {} nonzero entries\n",
+                sparsity_pattern.get_nonzeros()
+            ));
+        }
+        if let Some(action) = self.cycle_detection {
+            content.push_str(&format!("Cycle detection: {}\n", action));
+        }
+        if self.step_acceptance_norm != StepAcceptanceNorm::Stopping {
+            content.push_str(&format!(
+                "Step acceptance norm: {}\n",
+                self.step_acceptance_norm
+            ));
+        }
+        if let Some(limit_bytes) = self.memory_limit_bytes {
+            content.push_str(&format!("Memory limit: {} bytes\n", limit_bytes));
+        }
+        if let Some(coefficient) = self.error_smoothing {
+            content.push_str(&format!("Error smoothing: {}\n", coefficient));
+        }
+        if self.consecutive_convergences > 1 {
+            content.push_str(&format!(
+                "Consecutive convergences: {}\n",
+                self.consecutive_convergences
+            ));
+        }
+        if self.scalar_safeguard {
+            content.push_str(&format!(
+                "Scalar safeguard: {} refinements\n",
+                self.scalar_safeguard_refinements
+            ));
+        }
         content.push('\n');
 
         write!(f, "{}", content)
@@ -138,8 +1218,40 @@ impl fmt::Debug for SolverParameters {
         f.debug_struct("Solver parameters")
             .field("Problem size", &self.problem_size)
             .field("Max iteration", &self.max_iter)
+            .field("Max model evaluations", &self.max_model_evaluations)
+            .field("Reject solution on bounds", &self.reject_solution_on_bounds)
+            .field("Bound policy", &self.bound_policy)
+            .field("Return best on failure", &self.return_best_on_failure)
             .field("Solver tolerance", &self.tolerance)
+            .field("Extreme tolerance allowed", &self.extreme_tolerance_allowed)
             .field("Resolution method", &self.resolution_method)
+            .field("Newton warmup iterations", &self.newton_warmup_iterations)
+            .field("Aitken acceleration", &self.aitken_acceleration)
+            .field(
+                "First iteration step scale",
+                &self.first_iteration_step_scale,
+            )
+            .field("Linear solver", &self.linear_solver)
+            .field(
+                "Inaccurate jacobian policy",
+                &self.inaccurate_jacobian_policy,
+            )
+            .field(
+                "Structural singularity policy",
+                &self.structural_singularity_policy,
+            )
+            .field("Perturbation jitter", &self.perturbation_jitter)
+            .field("Sparsity pattern", &self.sparsity_pattern)
+            .field("Cycle detection", &self.cycle_detection)
+            .field("Step acceptance norm", &self.step_acceptance_norm)
+            .field("Memory limit in bytes", &self.memory_limit_bytes)
+            .field("Error smoothing", &self.error_smoothing)
+            .field("Consecutive convergences", &self.consecutive_convergences)
+            .field("Scalar safeguard", &self.scalar_safeguard)
+            .field(
+                "Scalar safeguard refinements",
+                &self.scalar_safeguard_refinements,
+            )
             .field("Damping activated", &self.damping)
             .finish()
     }
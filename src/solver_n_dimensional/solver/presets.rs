@@ -0,0 +1,218 @@
+//! Solver parameters tuned for common classes of problems
+//!
+//! Each preset gives the [SolverParameters] of a problem size,
+//! which can then be adjusted with their setters:
+//!
+//! | Preset              | Resolution method      | Damping | Max iterations |
+//! |---------------------|------------------------|---------|----------------|
+//! | [robust]            | Newton-Raphson         | yes     | 100            |
+//! | [fast_smooth]       | BROY2_INV              | no      | 50             |
+//! | [expensive_model]   | Stationary Newton      | yes     | 100            |
+//!
+//! All of them use a tolerance of `1e-6`,
+//! the quasi-Newton methods starting with 2 Newton-Raphson warmup iterations.
+//! The companion functions [recommended_iteratives], [recommended_iteratives_fd],
+//! [recommended_stopping_criterias] and [recommended_update_methods]
+//! give the iteratives and residuals configuration to use with them.
+//!
+//! The presets are also available in the xml configuration file,
+//! with the `preset` attribute of the solver node, see [crate::xml_parser].
+//!
+//! ## Examples
+//!
+//! ```
+//! use newton_rootfinder as nrf;
+//! use nrf::iteratives;
+//! use nrf::model::Model;
+//! use nrf::residuals;
+//! use nrf::solver::presets;
+//!
+//! /// Equation : x**2 - 2 = 0
+//! fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+//!     let mut y = x * x;
+//!     y[0] -= 2.0;
+//!     y
+//! }
+//!
+//! let problem_size = 1;
+//! let parameters = presets::robust(problem_size);
+//! let vec_iter_params = presets::recommended_iteratives_fd(problem_size);
+//! let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+//! let stopping_residuals = presets::recommended_stopping_criterias(problem_size);
+//! let update_methods = presets::recommended_update_methods(problem_size);
+//! let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+//! let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+//!
+//! let mut rf = nrf::solver::RootFinder::new(parameters, init_guess, &iter_params, &res_config);
+//! let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+//!
+//! rf.solve(&mut user_model).unwrap();
+//! assert!((user_model.get_iteratives()[0] - 2f64.sqrt()).abs() < 1e-6);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+use crate::iteratives::{IterativeParams, IterativeParamsFD};
+use crate::residuals::NormalizationMethod;
+
+/// Tolerance used by all the presets
+pub const PRESET_TOLERANCE: f64 = 1e-6;
+
+/// Classes of problems for which solver parameters are provided
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Preset {
+    /// See [robust]
+    Robust,
+    /// See [fast_smooth]
+    FastSmooth,
+    /// See [expensive_model]
+    ExpensiveModel,
+}
+
+impl Preset {
+    /// The solver parameters of the preset for the given problem size
+    ///
+    /// It panics if the problem size is zero.
+    pub fn parameters(self, problem_size: usize) -> SolverParameters {
+        match self {
+            Preset::Robust => robust(problem_size),
+            Preset::FastSmooth => fast_smooth(problem_size),
+            Preset::ExpensiveModel => expensive_model(problem_size),
+        }
+    }
+
+    /// The maximum number of iterations of the preset
+    pub fn max_iter(self) -> usize {
+        match self {
+            Preset::Robust => 100,
+            Preset::FastSmooth => 50,
+            Preset::ExpensiveModel => 100,
+        }
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Preset::Robust => write!(f, "Robust"),
+            Preset::FastSmooth => write!(f, "Fast smooth"),
+            Preset::ExpensiveModel => write!(f, "Expensive model"),
+        }
+    }
+}
+
+fn check_problem_size(problem_size: usize) {
+    if problem_size == 0 {
+        panic!("The problem size of a preset must be strictly positive, got 0");
+    }
+}
+
+/// Parameters for difficult problems, when the convergence matters more than the number of evaluations
+///
+/// The Newton-Raphson method computes the jacobian at each iteration, with the damping activated.
+/// A period-2 cycle of the iterates is broken by averaging its two points,
+/// and the model is evaluated at the best iterate if the resolution fails.
+///
+/// Budget: 100 iterations.
+///
+/// It panics if the problem size is zero.
+pub fn robust(problem_size: usize) -> SolverParameters {
+    check_problem_size(problem_size);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        PRESET_TOLERANCE,
+        MaxIterations::new(Preset::Robust.max_iter()),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    parameters.set_cycle_detection(Some(super::CycleBreakingAction::DampedAverage));
+    parameters.set_return_best_on_failure(true);
+    parameters
+}
+
+/// Parameters for smooth problems with a good initial guess, when the model is cheap to evaluate
+///
+/// The second Broyden method updates the inverse of the jacobian, avoiding any linear resolution,
+/// without damping: the jacobian is only computed by 2 Newton-Raphson warmup iterations,
+/// see [SolverParameters::set_newton_warmup_iterations].
+/// Without them, the update of the jacobian computed at the initial guess can diverge.
+///
+/// Budget: 50 iterations.
+///
+/// It panics if the problem size is zero.
+pub fn fast_smooth(problem_size: usize) -> SolverParameters {
+    check_problem_size(problem_size);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        PRESET_TOLERANCE,
+        MaxIterations::new(Preset::FastSmooth.max_iter()),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenSecondMethod,
+        )),
+        Damping::Disabled,
+    );
+    parameters.set_newton_warmup_iterations(2);
+    parameters
+}
+
+/// Parameters for models expensive to evaluate, when the jacobian should be computed as rarely as possible
+///
+/// The stationary Newton method reuses the jacobian computed by 2 Newton-Raphson warmup iterations,
+/// see [SolverParameters::set_newton_warmup_iterations].
+/// With the damping activated, the jacobian is computed again when a step deteriorates the solution.
+///
+/// Budget: 100 iterations.
+///
+/// It panics if the problem size is zero.
+pub fn expensive_model(problem_size: usize) -> SolverParameters {
+    check_problem_size(problem_size);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        PRESET_TOLERANCE,
+        MaxIterations::new(Preset::ExpensiveModel.max_iter()),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Damping::Enabled,
+    );
+    parameters.set_newton_warmup_iterations(2);
+    parameters
+}
+
+/// Iteratives recommended with the presets, when the jacobian is provided by the model
+///
+/// They are unbounded, without step limitation, as [crate::iteratives::default_vec_iteratives]
+pub fn recommended_iteratives(problem_size: usize) -> Vec<IterativeParams> {
+    check_problem_size(problem_size);
+    vec![IterativeParams::default(); problem_size]
+}
+
+/// Iteratives recommended with the presets, when the jacobian is computed with finite differences
+///
+/// They are unbounded, without step limitation, as [crate::iteratives::default_vec_iteratives_fd]
+pub fn recommended_iteratives_fd(problem_size: usize) -> Vec<IterativeParamsFD> {
+    check_problem_size(problem_size);
+    vec![IterativeParamsFD::default(); problem_size]
+}
+
+/// Stopping criterias recommended with the presets
+///
+/// The absolute normalization is the one with which the quasi-Newton methods of the presets are tested.
+/// The tolerance of the presets then applies to the values of the residuals:
+/// the normalization can be changed when they are far from unity, see [NormalizationMethod].
+pub fn recommended_stopping_criterias(problem_size: usize) -> Vec<NormalizationMethod> {
+    check_problem_size(problem_size);
+    vec![NormalizationMethod::Abs; problem_size]
+}
+
+/// Update methods recommended with the presets
+///
+/// The absolute normalization gives the steps of the plain Newton method.
+pub fn recommended_update_methods(problem_size: usize) -> Vec<NormalizationMethod> {
+    check_problem_size(problem_size);
+    vec![NormalizationMethod::Abs; problem_size]
+}
@@ -1,8 +1,16 @@
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::solver_n_dimensional::prelude::*;
+
+use super::compatibility::{FiniteDifferenceUse, MethodRequirements};
 
 /// Choice of the iterative algorithm for the resolution
 ///
-/// All of them are Newton based methods
+/// All of them are Newton based methods,
+/// the nonlinear Gauss-Seidel method applying it to each equation separately
 ///
 /// All Newton based iterative methods have a local convergence.
 /// They also assume that the jacobian is invertible at the root (simple root)
@@ -23,6 +31,22 @@ pub enum ResolutionMethod {
     /// Instead of using the jacobian, there are using a approximation of this matrix (or its inverse).
     /// In most of the case, a computation of the true jacobian is still required for initialization purpose.
     QuasiNewton(QuasiNewtonMethod),
+    /// Nonlinear Gauss-Seidel method, for loosely coupled systems
+    ///
+    /// Each iteration is a sweep over the iteratives:
+    /// the iterative `i` is updated by `inner_scalar_iterations` scalar Newton iterations on the residual `i`,
+    /// the other iteratives being held fixed, before moving on to the next one.
+    /// The derivative of the residual with respect to its iterative is computed per finite difference,
+    /// no jacobian being computed: the iteratives must be working with finite differences.
+    /// The scalar iterations of an iterative stop once its residual is below the tolerance of the solver.
+    ///
+    /// The step limitations and the bounds of each iterative apply to each scalar step.
+    /// A scalar iteration takes two model evaluations, the new iterate of the sweep being evaluated as for the other methods.
+    ///
+    /// The method converges if each equation mostly depends on its own iterative,
+    /// for example with a diagonally dominant jacobian.
+    /// Otherwise, the convergence is not reached within the maximum number of iterations.
+    NonlinearGaussSeidel { inner_scalar_iterations: usize },
 }
 
 impl fmt::Display for ResolutionMethod {
@@ -31,14 +55,60 @@ impl fmt::Display for ResolutionMethod {
         match self {
             ResolutionMethod::NewtonRaphson => content.push_str("Newton-Raphson"),
             ResolutionMethod::QuasiNewton(method) => {
-                content.push_str(&format!("Quasi Newton: {}", method.to_string()))
+                content.push_str(&format!("Quasi Newton: {}", method))
             }
+            ResolutionMethod::NonlinearGaussSeidel {
+                inner_scalar_iterations,
+            } => content.push_str(&format!(
+                "Nonlinear Gauss-Seidel with {} scalar iterations",
+                inner_scalar_iterations
+            )),
         };
 
         write!(f, "{}", content)
     }
 }
 
+impl ResolutionMethod {
+    /// What the method needs from the solver and from the model
+    ///
+    /// See [super::check_compatibility] to check a whole configuration.
+    pub fn requirements(&self) -> MethodRequirements {
+        match self {
+            ResolutionMethod::NonlinearGaussSeidel { .. } => MethodRequirements {
+                jacobian_storage: false,
+                inverse_storage: false,
+                linear_solver: false,
+                finite_differences: FiniteDifferenceUse::Always,
+            },
+            // the steps are computed without any matrix
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { .. }) => {
+                MethodRequirements {
+                    jacobian_storage: false,
+                    inverse_storage: false,
+                    linear_solver: false,
+                    finite_differences: FiniteDifferenceUse::WithoutProvidedJacobian,
+                }
+            }
+            _ => MethodRequirements {
+                jacobian_storage: true,
+                // the second method of Greenstadt uses the inverse to update the jacobian
+                inverse_storage: matches!(
+                    self,
+                    ResolutionMethod::QuasiNewton(
+                        QuasiNewtonMethod::InverseJacobianUpdate(_)
+                            | QuasiNewtonMethod::JacobianUpdate(
+                                UpdateQuasiNewtonMethod::GreenstadtSecondMethod
+                            )
+                    )
+                ),
+                linear_solver: true,
+                finite_differences: FiniteDifferenceUse::WithoutProvidedJacobian,
+            },
+        }
+    }
+}
+
 /// Quasi-Newton methods are less computationnaly expensive than the Newton-Raphson method.
 ///
 /// However, the most robust method is the Newton-Raphson one.
@@ -52,6 +122,9 @@ impl fmt::Display for ResolutionMethod {
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum QuasiNewtonMethod {
     /// The first computed jacobian will be used for all iterations.
+    ///
+    /// Its linear convergence can be accelerated with the Aitken extrapolation of the iterates,
+    /// see `SolverParameters::set_aitken_acceleration()`.
     StationaryNewton,
     /// The update of the methods will be performed on the jacobian matrix:
     /// it will be inverted afterwards before applying the step update.
@@ -59,6 +132,25 @@ pub enum QuasiNewtonMethod {
     /// The update of the methods will be performed directly on the inverse jacobian matrix:
     /// Thus the jacobian won't be computed at all after the first step.
     InverseJacobianUpdate(UpdateQuasiNewtonMethod),
+    /// The second method of Broyden applied to the inverse of the jacobian with a limited memory,
+    /// for large problems where the storage of a matrix is prohibitive
+    ///
+    /// Only the last `memory` steps of the iteratives and of the residuals are kept:
+    /// the product of the approximated inverse with the residuals is computed from them,
+    /// the matrix being never formed (see [super::LimitedMemoryInverse]).
+    /// The approximation is seeded with the inverse of the diagonal of the jacobian,
+    /// computed at the first iteration from the model or with finite differences,
+    /// with as many model evaluations as the full jacobian but without its storage.
+    ///
+    /// As long as the number of updates does not exceed the memory,
+    /// the iterates are the ones of the `InverseJacobianUpdate(BroydenSecondMethod)` method
+    /// if the jacobian at the initial guess is diagonal.
+    ///
+    /// The jacobian is never stored: the [super::LinearSolver] is not used
+    /// and the `JacobianMatrix` accessors of the solver, such as the perturbations, stay empty.
+    /// The Newton warmup iterations, if any, compute the full jacobian.
+    /// With a memory of 0, the diagonal seed is used for all the iterations.
+    LimitedMemoryBroyden { memory: usize },
 }
 
 impl fmt::Display for QuasiNewtonMethod {
@@ -74,6 +166,11 @@ impl fmt::Display for QuasiNewtonMethod {
                 content.push_str("Jacobian matrix approximated");
                 content.push_str(&method.to_string());
             }
+            QuasiNewtonMethod::LimitedMemoryBroyden { memory } => content.push_str(&format!(
+                "Inverse jacobian approximated with the {} with a memory of {} steps",
+                UpdateQuasiNewtonMethod::BroydenSecondMethod,
+                memory
+            )),
         }
         write!(f, "{}", content)
     }
@@ -128,6 +225,19 @@ impl fmt::Display for QuasiNewtonMethod {
 /// | Second   | y_{i}              | J_{i+1} = J_{i} - (J_{i}*s_{i}-y_{i})*y_{i}^{T}*J_{i}/(y_{i}^{T}*J_{i}*s_{i}) |   H_{i+1} = H_{i} - (H_{i}*y_{i}-s_{i})y_{i}^{T}/(y_{i}^{T}*y_{i})             |
 ///
 ///
+/// ## Schubert method \[1970\]
+/// The first method of Broyden fills the jacobian within one update, destroying its sparsity.
+/// The Schubert method preserves a declared [super::SparsityPattern]
+/// by restricting the update of each row to its structurally nonzero entries.
+///
+/// For the row r of the jacobian, with s_{i}^{r} the vector s_{i} whose components outside of the pattern of the row are set to zero:
+///
+/// J_{i+1}^{r} = J_{i}^{r} - (J_{i}^{r}*s_{i}-y_{i}^{r})*s_{i}^{r T}/(s_{i}^{r T}*s_{i}^{r})
+///
+/// The method is only applied to the jacobian (not to its inverse) and requires a sparsity pattern,
+/// see [super::SolverParameters::set_sparsity_pattern].
+/// Without any zero in the pattern, it is the first method of Broyden.
+///
 /// ## Reference
 ///
 /// ### Dennis, Jr., J. E. (1967)
@@ -145,12 +255,22 @@ impl fmt::Display for QuasiNewtonMethod {
 /// Computing, p 68-89.
 ///
 /// doi:10.1007/BF02684472
+///
+/// ### Schubert, L. K. (1970)
+///
+/// Modification of a quasi-Newton method for nonlinear equations with a sparse Jacobian,
+///
+/// Mathematics of Computation, 24(109), p 27-30.
+///
+/// doi:10.1090/S0025-5718-1970-0258276-9
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UpdateQuasiNewtonMethod {
     BroydenFirstMethod,
     BroydenSecondMethod,
     GreenstadtFirstMethod,
     GreenstadtSecondMethod,
+    SchubertSparse,
 }
 
 impl fmt::Display for UpdateQuasiNewtonMethod {
@@ -167,6 +287,7 @@ impl fmt::Display for UpdateQuasiNewtonMethod {
             UpdateQuasiNewtonMethod::GreenstadtSecondMethod => {
                 content.push_str("Greenstadt Second Method")
             }
+            UpdateQuasiNewtonMethod::SchubertSparse => content.push_str("Schubert Sparse Method"),
         };
 
         write!(f, "{}", content)
@@ -284,3 +405,295 @@ where
 {
     jac - (jac * s - y) * hy.transpose() / ((hy.transpose() * s)[(0, 0)])
 }
+
+/// Relative threshold under which the denominator of a quasi-Newton update is considered degenerate
+///
+/// The update `u*v^T/denominator` is skipped if its magnitude `|u|*|v|/|denominator|`
+/// is greater than the norm of the matrix divided by this threshold.
+pub const DEGENERATE_UPDATE_THRESHOLD: f64 = 1e-8;
+
+/// Rank one update performed in place: `matrix = matrix - u*v^T/denominator`
+///
+/// The update is skipped if the denominator is degenerate relatively to the norm of the matrix,
+/// see [DEGENERATE_UPDATE_THRESHOLD].
+/// In this case, the matrix is left unchanged and `false` is returned.
+///
+/// The values of `u` are overwritten
+fn rank_one_update_in_place<D>(
+    matrix: &mut nalgebra::OMatrix<f64, D, D>,
+    u: &mut nalgebra::OVector<f64, D>,
+    v: &nalgebra::OVector<f64, D>,
+    denominator: f64,
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    // written to be false if any of the terms is NaN
+    let valid_denominator =
+        denominator.abs() * matrix.norm() > DEGENERATE_UPDATE_THRESHOLD * u.norm() * v.norm();
+    if !valid_denominator {
+        return false;
+    }
+
+    *u /= denominator;
+    matrix.ger(-1.0, u, v, 1.0);
+    true
+}
+
+/// Broyden first method update formula, performed in place
+///
+/// Same update as [broyden_first_method_udpate_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+///
+/// let mut jac = nalgebra::DMatrix::from_vec(2, 2, vec![2.0, 0.0, 1.0, 3.0]);
+/// let s = nalgebra::DVector::from_vec(vec![0.1, -0.2]);
+/// let y = nalgebra::DVector::from_vec(vec![0.3, 0.5]);
+/// let mut scratch = [nalgebra::DVector::zeros(2), nalgebra::DVector::zeros(2)];
+///
+/// let expected = nrf::solver::broyden_first_method_udpate_jac(&jac, &s, &y);
+/// nrf::solver::broyden_first_method_update_jac_in_place(&mut jac, &s, &y, &mut scratch);
+///
+/// assert!((jac - expected).amax() < 1e-12);
+/// ```
+pub fn broyden_first_method_update_jac_in_place<D>(
+    jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let [u, _] = scratch;
+    jac.mul_to(s, u);
+    *u -= y;
+    rank_one_update_in_place(jac, u, s, s.norm_squared())
+}
+
+/// Broyden first method update formula, performed in place
+///
+/// Same update as [broyden_first_method_udpate_inv_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn broyden_first_method_update_inv_jac_in_place<D>(
+    inv_jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let [u, v] = scratch;
+    inv_jac.mul_to(y, u);
+    *u -= s;
+    inv_jac.tr_mul_to(s, v);
+    let denominator = v.dot(y);
+    rank_one_update_in_place(inv_jac, u, v, denominator)
+}
+
+/// Broyden second method update formula, performed in place
+///
+/// Same update as [broyden_second_method_udpate_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn broyden_second_method_update_jac_in_place<D>(
+    jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    quasi_method_update_jac_in_place(jac, s, y, y, scratch)
+}
+
+/// Broyden second method update formula, performed in place
+///
+/// Same update as [broyden_second_method_udpate_inv_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn broyden_second_method_update_inv_jac_in_place<D>(
+    inv_jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    quasi_method_update_inv_jac_in_place(inv_jac, s, y, y, scratch)
+}
+
+/// Generic function for quasi method update, performed in place
+///
+/// Same update as [quasi_method_update_inv_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn quasi_method_update_inv_jac_in_place<D>(
+    inv_jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    c: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let [u, _] = scratch;
+    inv_jac.mul_to(y, u);
+    *u -= s;
+    rank_one_update_in_place(inv_jac, u, c, c.dot(y))
+}
+
+/// Generic function for quasi method update, performed in place
+///
+/// Same update as [quasi_method_update_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn quasi_method_update_jac_in_place<D>(
+    jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    c: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let [u, v] = scratch;
+    jac.mul_to(s, u);
+    *u -= y;
+    jac.tr_mul_to(c, v);
+    let denominator = v.dot(s);
+    rank_one_update_in_place(jac, u, v, denominator)
+}
+
+/// Greenstadt second method update formula, performed in place
+///
+/// Same update as [greenstadt_second_method_udpate_jac] without any allocation:
+/// the `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// Returns `false` if the update has been skipped because of a degenerate denominator,
+/// the matrix being left unchanged (see [DEGENERATE_UPDATE_THRESHOLD]).
+pub fn greenstadt_second_method_update_jac_in_place<D>(
+    jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    hy: &nalgebra::OVector<f64, D>,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let [u, _] = scratch;
+    jac.mul_to(s, u);
+    *u -= y;
+    rank_one_update_in_place(jac, u, hy, hy.dot(s))
+}
+
+/// Schubert update formula, performed in place
+///
+/// First method of Broyden restricted row by row to the structurally nonzero entries of the `pattern`,
+/// the other entries being left unchanged (see [UpdateQuasiNewtonMethod]).
+/// The `scratch` vectors are used as workspace and their values are overwritten.
+///
+/// The update of a row is skipped if its restricted step is degenerate
+/// relatively to the norm of the matrix (see [DEGENERATE_UPDATE_THRESHOLD]).
+/// Returns `false` if the update of all the rows has been skipped, the matrix being left unchanged.
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::SparsityPattern;
+///
+/// let mut jac = nalgebra::DMatrix::from_row_slice(2, 2, &[2.0, 0.0, 1.0, 3.0]);
+/// let s = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+/// let y = nalgebra::DVector::from_vec(vec![3.0, 4.0]);
+/// let pattern = SparsityPattern::banded(2, 1, 0);
+/// let mut scratch = [nalgebra::DVector::zeros(2), nalgebra::DVector::zeros(2)];
+///
+/// assert!(nrf::solver::schubert_update_jac_in_place(&mut jac, &s, &y, &pattern, &mut scratch));
+/// assert_eq!(jac, nalgebra::DMatrix::from_row_slice(2, 2, &[3.0, 0.0, 1.0, 3.0]));
+/// ```
+///
+/// # Panics
+///
+/// If the size of the pattern is not the one of the matrix
+pub fn schubert_update_jac_in_place<D>(
+    jac: &mut nalgebra::OMatrix<f64, D, D>,
+    s: &nalgebra::OVector<f64, D>,
+    y: &nalgebra::OVector<f64, D>,
+    pattern: &super::SparsityPattern,
+    scratch: &mut [nalgebra::OVector<f64, D>; 2],
+) -> bool
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    if pattern.len() != jac.nrows() {
+        panic!(
+            "The sparsity pattern is of size {}, the jacobian is of size {}",
+            pattern.len(),
+            jac.nrows()
+        );
+    }
+
+    let [u, _] = scratch;
+    jac.mul_to(s, u);
+    *u -= y;
+    let matrix_norm = jac.norm();
+
+    let mut updated = false;
+    for (row, &residual) in u.iter().enumerate() {
+        let columns = pattern.get_row(row);
+        let restricted_norm_squared: f64 = columns.iter().map(|&column| s[column].powi(2)).sum();
+        // written to be false if any of the terms is NaN
+        let valid_denominator = restricted_norm_squared * matrix_norm
+            > DEGENERATE_UPDATE_THRESHOLD * residual.abs() * restricted_norm_squared.sqrt();
+        if !valid_denominator {
+            continue;
+        }
+        let factor = residual / restricted_norm_squared;
+        for &column in columns {
+            jac[(row, column)] -= factor * s[column];
+        }
+        updated = true;
+    }
+    updated
+}
@@ -1,18 +1,51 @@
-use std::fmt;
+#[cfg(feature = "debug_log")]
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
 
 use crate::errors;
 use crate::iteratives;
 use crate::iteratives::Iterative;
 use crate::model;
-use crate::model::ModelError;
 use crate::residuals;
 
+use super::jacobian::{
+    count_jacobian_sources, evaluate_jacobian_diagonal_from_analytical_function,
+    evaluate_jacobian_diagonal_from_finite_difference, evaluate_jacobian_with_constant_columns,
+};
 use super::{
-    approximate_inv_jacobian, approximate_jacobian, evaluate_jacobian_from_analytical_function,
-    evaluate_jacobian_from_finite_difference, JacobianMatrix, SolverParameters,
+    apply_inaccurate_jacobian_policy, approximate_inv_jacobian, approximate_jacobian,
+    compute_raw_newton_step, evaluate_jacobian_from_analytical_function, JacobianMatrix,
+    JacobianSource, LimitedMemoryInverse, MethodState, QuasiNewtonUpdateStatus, SolverParameters,
+    SolverReport, SolverSnapshot,
 };
 
-use super::{QuasiNewtonMethod, ResolutionMethod};
+use super::convergence_rate::{
+    estimate_convergence_rate, ConvergenceRate, SUSPICIOUS_NEWTON_ORDER,
+};
+#[cfg(feature = "std")]
+use super::history::{
+    quasi_newton_update_metrics, write_history_file, IterationRecord, QuasiNewtonUpdateMetrics,
+};
+#[cfg(feature = "std")]
+use super::jacobian::{dump_path, JacobianDump};
+#[cfg(feature = "debug_log")]
+use super::InaccurateJacobianPolicy;
+#[cfg(feature = "std")]
+use super::MatrixFileFormat;
+use super::{check_compatibility, CompatibilityWarning, IterativesKind};
+use super::{
+    BoundPolicy, ConvergenceCriterion, CycleBreakingAction, Damping, ExhaustedBudget, ProblemSize,
+    QuasiNewtonMethod, ResolutionMethod, StepAcceptanceNorm, StructuralSingularityPolicy,
+    UpdateQuasiNewtonMethod, AITKEN_DENOMINATOR_THRESHOLD, CYCLE_TOLERANCE,
+};
+use super::{ConvergenceContext, ConvergencePredicate, PredicateMode};
+#[cfg(feature = "debug_log")]
+use super::{DebugFilePolicy, DebugWriteMode, LogOptions};
 
 /// Solver for rootfinding
 ///
@@ -35,18 +68,95 @@ where
     initial_guess: nalgebra::OVector<f64, D>,
     iters_params: &'a iteratives::Iteratives<'a, T>,
     residuals_config: &'a residuals::ResidualsConfig<'a>,
+    right_overrides: residuals::RightOverrides<D>,
+    #[cfg(feature = "debug_log")]
     debug: bool,
+    #[cfg(feature = "debug_log")]
+    log_options: LogOptions,
+    #[cfg(feature = "debug_log")]
+    debug_file_policy: DebugFilePolicy,
+    #[cfg(feature = "debug_log")]
+    log_metadata: Vec<(String, String)>,
 
     // solver placeholder
     iter: usize,
+    model_evaluations: usize,
+    saved_model_evaluations: usize,
+    exhausted_budget: Option<ExhaustedBudget>,
+    active_bound_variables: Vec<usize>,
+    converged_on_boundary: bool,
+    last_satisfied_criterion: Option<ConvergenceCriterion>,
+    best_iterate: Option<(nalgebra::OVector<f64, D>, f64)>,
+    // Stopping errors at the initial guess, if the model could be evaluated
+    initial_errors: Option<nalgebra::OVector<f64, D>>,
+    // Maximum error of the last iterate evaluated, if any
+    last_max_error: Option<f64>,
+    // Maximum errors of the initial guess and of each iteration, to estimate the convergence rate
+    max_errors: Vec<f64>,
+    // Only kept if the cycle detection is activated, with its maximum error
+    previous_iterate: Option<(nalgebra::OVector<f64, D>, f64)>,
+    cycles_detected: usize,
+    // Number of consecutive quasi-Newton updates that produced non-finite values
+    non_finite_updates: usize,
+    // Last iterates of the stationary Newton method computed with the same jacobian, only kept with the Aitken acceleration
+    aitken_iterates: Vec<nalgebra::OVector<f64, D>>,
+    aitken_extrapolations: usize,
+    unconverged_residuals: errors::UnconvergedResiduals,
+    // Stopping errors of the last iterate, kept to compute the errors over tolerance at the termination
+    last_errors: Option<nalgebra::OVector<f64, D>>,
+    errors_over_tolerance: Option<nalgebra::OVector<f64, D>>,
+    // Source of the jacobian of each iteration, the one of the current iteration being set while computing its step
+    jacobian_sources: Vec<JacobianSource>,
+    jacobian_source: Option<JacobianSource>,
+    // Declared by the user, with the jacobian of their first evaluation during the current resolution
+    constant_jacobian_columns: Vec<usize>,
+    constant_jacobian_values: Option<nalgebra::OMatrix<f64, D, D>>,
+    jacobian_reuse: bool,
+    // Pending writing of the next computed jacobians to files
+    #[cfg(feature = "std")]
+    jacobian_dump: Option<JacobianDump>,
+    #[cfg(feature = "std")]
+    jacobian_dump_failures: Vec<(usize, String)>,
+    // Only recorded if the history is activated, from the start of the resolution
+    #[cfg(feature = "std")]
+    history: Option<Vec<IterationRecord>>,
+    #[cfg(feature = "std")]
+    history_start: Option<std::time::Instant>,
+    // Metrics of the quasi-Newton update of the current iteration, only measured if the history is activated
+    #[cfg(feature = "std")]
+    update_metrics: Option<QuasiNewtonUpdateMetrics>,
+    #[cfg(feature = "debug_log")]
     solver_log: Option<super::log::SolverLog>,
     jacobian: JacobianMatrix<D>,
+    // Only used by the limited memory Broyden method, instead of the jacobian
+    limited_memory: Option<LimitedMemoryInverse<D>>,
+    residuals_values: residuals::ResidualsValues<D>,
     iteratives_step_size: Option<nalgebra::OVector<f64, D>>,
     residuals_step_size: Option<nalgebra::OVector<f64, D>>,
+    // Only computed if some iteratives have a convergence step tolerance or with a custom convergence predicate
+    last_step: Option<nalgebra::OVector<f64, D>>,
+    convergence_predicate: Option<ConvergencePredicate<'a, D>>,
+    predicate_mode: PredicateMode,
     residuals_values_current: Option<nalgebra::OVector<f64, D>>,
+    // Stopping errors of the current state of the model, discarded as soon as the model may be evaluated elsewhere
+    stopping_errors_current: Option<nalgebra::OVector<f64, D>>,
     valid_last_model_evaluation: bool,
 }
 
+/// Values of the model at the current guess, compared to the ones of the proposed guess
+struct CurrentIterate<D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    guess: nalgebra::OVector<f64, D>,
+    errors: nalgebra::OVector<f64, D>,
+    // only required by the damping with the update residuals
+    max_update_error: Option<f64>,
+    // only required by the scalar safeguard
+    update_residual: Option<f64>,
+}
+
 impl<'a, T, D> RootFinder<'a, T, D>
 where
     T: Iterative + fmt::Display + fmt::Debug,
@@ -56,62 +166,302 @@ where
     nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
+    /// Create a solver
+    ///
+    /// If the `parameters` have been built with [SolverParameters::with_inferred_size],
+    /// the problem size is the length of the initial guess.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the residuals configuration, of the initial guess or of the iteratives
+    /// is not the problem size, if the tolerance is rejected by [SolverParameters::check_tolerance],
+    /// or if the number of ignored residuals is not the number of fixed iteratives,
+    /// the message being the one of the [errors::RootFinderConstructionError].
+    /// [RootFinder::try_new] returns these errors instead, and should be preferred
+    /// when the elements are built at runtime, from a configuration provided by a user for example.
     pub fn new(
         parameters: SolverParameters,
         initial_guess: nalgebra::OVector<f64, D>,
         iters_params: &'a iteratives::Iteratives<'a, T>,
         residuals_config: &'a residuals::ResidualsConfig<'a>,
     ) -> Self {
+        match Self::try_new(parameters, initial_guess, iters_params, residuals_config) {
+            Ok(rootfinder) => rootfinder,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Create a solver, returning an error if the sizes of the inputs do not match the problem size,
+    /// if the tolerance is rejected by [SolverParameters::check_tolerance],
+    /// if the storage of the jacobian exceeds the memory limit, see [SolverParameters::check_memory_limit],
+    /// if the number of ignored residuals is not the number of fixed iteratives,
+    /// or if the parameters and the iteratives prevent the resolution whatever the model (see [check_compatibility]).
+    ///
+    /// The problem size is the one of the `parameters` if it has been provided,
+    /// otherwise it is inferred from the initial guess, which must not be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::errors::RootFinderConstructionError;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::{MaxIterations, ProblemSize, ResolutionMethod};
+    ///
+    /// let problem_size = 2;
+    /// let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// let parameters = nrf::solver::SolverParameters::new(ProblemSize::new(problem_size), 1e-6, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+    ///
+    /// let result = nrf::solver::RootFinder::try_new(
+    ///     parameters,
+    ///     nalgebra::DVector::from_vec(vec![1.0]),
+    ///     &iter_params,
+    ///     &res_config,
+    /// );
+    /// assert_eq!(
+    ///     result.err(),
+    ///     Some(RootFinderConstructionError::InitialGuessSizeMismatch {
+    ///         initial_guess: 1,
+    ///         problem_size: 2
+    ///     })
+    /// );
+    /// ```
+    pub fn try_new(
+        mut parameters: SolverParameters,
+        mut initial_guess: nalgebra::OVector<f64, D>,
+        iters_params: &'a iteratives::Iteratives<'a, T>,
+        residuals_config: &'a residuals::ResidualsConfig<'a>,
+    ) -> Result<Self, errors::RootFinderConstructionError> {
+        #[cfg(feature = "debug_log")]
         let debug = false;
+        #[cfg(feature = "debug_log")]
+        let log_options = LogOptions::default();
+        #[cfg(feature = "debug_log")]
+        let debug_file_policy = DebugFilePolicy::default();
+        #[cfg(feature = "debug_log")]
+        let log_metadata = Vec::new();
+        #[cfg(feature = "debug_log")]
         let solver_log = None;
         let iter = 0;
 
-        if residuals_config.len() != parameters.get_problem_size() {
-            panic!(
-                "Dimension mismatch :\n residuals_config.len() = {} and problem_size = {}",
-                residuals_config.len(),
-                parameters.get_problem_size()
+        let problem_size = match parameters.get_problem_size() {
+            Some(problem_size) => problem_size,
+            None if initial_guess.is_empty() => {
+                return Err(errors::RootFinderConstructionError::EmptyProblem)
+            }
+            None => {
+                parameters.set_problem_size(ProblemSize::new(initial_guess.len()));
+                initial_guess.len()
+            }
+        };
+        parameters.check_tolerance()?;
+        parameters.check_memory_limit()?;
+        // the model is not known yet: assuming it provides its jacobian leaves the warnings depending on it to solve()
+        if let Some(warning) =
+            check_compatibility(&parameters, true, IterativesKind::from(iters_params))
+                .err()
+                .unwrap_or_default()
+                .into_iter()
+                .find(CompatibilityWarning::prevents_resolution)
+        {
+            return Err(errors::RootFinderConstructionError::IncompatibleConfiguration(warning));
+        }
+        if residuals_config.len() != problem_size {
+            return Err(
+                errors::RootFinderConstructionError::ResidualsConfigSizeMismatch {
+                    residuals_config: residuals_config.len(),
+                    problem_size,
+                },
             );
         }
-        if initial_guess.len() != parameters.get_problem_size() {
-            panic!(
-                "Dimension mismatch :\n initial_guess.len() = {} and problem_size = {}",
-                initial_guess.len(),
-                parameters.get_problem_size()
+        if initial_guess.len() != problem_size {
+            return Err(
+                errors::RootFinderConstructionError::InitialGuessSizeMismatch {
+                    initial_guess: initial_guess.len(),
+                    problem_size,
+                },
             );
         }
-        if iters_params.len() != parameters.get_problem_size() {
-            panic!(
-                "Dimension mismatch :\n iters_params.len() = {} and problem_size = {}",
-                iters_params.len(),
-                parameters.get_problem_size()
+        if iters_params.len() != problem_size {
+            return Err(
+                errors::RootFinderConstructionError::IterativesSizeMismatch {
+                    iteratives: iters_params.len(),
+                    problem_size,
+                },
             );
         }
+        let fixed_iteratives = iters_params.fixed_iteratives();
+        let ignored_residuals = residuals_config.get_ignored_residuals();
+        if fixed_iteratives.len() != ignored_residuals.len() {
+            return Err(
+                errors::RootFinderConstructionError::FixedIterativesMismatch {
+                    fixed_iteratives: fixed_iteratives.len(),
+                    ignored_residuals: ignored_residuals.len(),
+                },
+            );
+        }
+        iters_params.apply_fixed_values(&mut initial_guess);
 
-        let jacobian = JacobianMatrix::new();
+        let mut jacobian = JacobianMatrix::new();
+        // the k-th ignored residual makes room for the k-th fixed iterative
+        jacobian.set_fixed_rows(
+            ignored_residuals
+                .iter()
+                .copied()
+                .zip(fixed_iteratives)
+                .collect(),
+        );
+        let residuals_values = residuals::ResidualsValues::new(
+            super::super::ovector_zeros_like(&initial_guess),
+            super::super::ovector_zeros_like(&initial_guess),
+        );
         let iteratives_step_size = None;
         let residuals_step_size = None;
         let residuals_values_current = None;
+        let stopping_errors_current = None;
         let valid_last_model_evaluation = true;
+        let model_evaluations = 0;
+        let saved_model_evaluations = 0;
+        let exhausted_budget = None;
+        let active_bound_variables = Vec::new();
+        let converged_on_boundary = false;
+        let last_satisfied_criterion = None;
+        let best_iterate = None;
+        let initial_errors = None;
+        let last_max_error = None;
+        let max_errors = Vec::new();
+        let previous_iterate = None;
+        let cycles_detected = 0;
+        let non_finite_updates = 0;
+        let aitken_iterates = Vec::new();
+        let aitken_extrapolations = 0;
+        let unconverged_residuals = errors::UnconvergedResiduals::default();
+        let jacobian_sources = Vec::new();
+        let jacobian_source = None;
+        let constant_jacobian_columns = Vec::new();
+        let constant_jacobian_values = None;
+        let jacobian_reuse = false;
+        let last_step = None;
+        let right_overrides = residuals::RightOverrides::default();
 
-        RootFinder {
+        Ok(RootFinder {
             parameters,
             initial_guess,
             iters_params,
             residuals_config,
+            right_overrides,
+            #[cfg(feature = "debug_log")]
             debug,
+            #[cfg(feature = "debug_log")]
+            log_options,
+            #[cfg(feature = "debug_log")]
+            debug_file_policy,
+            #[cfg(feature = "debug_log")]
+            log_metadata,
             iter,
+            model_evaluations,
+            saved_model_evaluations,
+            exhausted_budget,
+            active_bound_variables,
+            converged_on_boundary,
+            last_satisfied_criterion,
+            best_iterate,
+            initial_errors,
+            last_max_error,
+            max_errors,
+            previous_iterate,
+            cycles_detected,
+            non_finite_updates,
+            aitken_iterates,
+            aitken_extrapolations,
+            unconverged_residuals,
+            last_errors: None,
+            errors_over_tolerance: None,
+            jacobian_sources,
+            jacobian_source,
+            constant_jacobian_columns,
+            constant_jacobian_values,
+            jacobian_reuse,
+            #[cfg(feature = "std")]
+            jacobian_dump: None,
+            #[cfg(feature = "std")]
+            jacobian_dump_failures: Vec::new(),
+            #[cfg(feature = "std")]
+            history: None,
+            #[cfg(feature = "std")]
+            history_start: None,
+            #[cfg(feature = "std")]
+            update_metrics: None,
+            #[cfg(feature = "debug_log")]
             solver_log,
             jacobian,
+            limited_memory: None,
+            residuals_values,
             iteratives_step_size,
             residuals_step_size,
+            last_step,
+            convergence_predicate: None,
+            predicate_mode: PredicateMode::default(),
             residuals_values_current,
+            stopping_errors_current,
             valid_last_model_evaluation,
-        }
+        })
+    }
+
+    /// Create a solver whose initial guess is computed from the iteratives parametrization
+    ///
+    /// See the `default_guess()` method of the `Iterative` trait:
+    /// for the provided iteratives, the midpoint of the bounds is used if both are finite.
+    /// If the problem size of the `parameters` is to be inferred, the initial guess has the length of the iteratives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::{MaxIterations, ProblemSize, ResolutionMethod};
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// let problem_size = 1;
+    /// let iterative = iteratives::IterativeParams::new(f64::INFINITY, f64::INFINITY, 0.0, 3.0);
+    /// let vec_iter_params = vec![iterative; problem_size];
+    /// let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    ///
+    /// let parameters = nrf::solver::SolverParameters::new(ProblemSize::new(problem_size), 1e-6, MaxIterations::new(50), ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+    /// let mut rf = nrf::solver::RootFinder::<_, nalgebra::Dyn>::new_with_default_guess(parameters, &iter_params, &res_config);
+    /// # let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+    /// #     problem_size,
+    /// #     square2,
+    /// #     |x| nalgebra::DMatrix::from_element(1, 1, 2.0 * x[0]),
+    /// # );
+    /// rf.solve(&mut user_model).unwrap();
+    /// ```
+    pub fn new_with_default_guess(
+        parameters: SolverParameters,
+        iters_params: &'a iteratives::Iteratives<'a, T>,
+        residuals_config: &'a residuals::ResidualsConfig<'a>,
+    ) -> Self {
+        let problem_size = parameters.get_problem_size().unwrap_or(iters_params.len());
+        let initial_guess = iters_params.default_guess(D::from_usize(problem_size));
+        Self::new(parameters, initial_guess, iters_params, residuals_config)
     }
 
     /// Activate the gathering of the log
     ///
+    /// Available with the `debug_log` feature, enabled by default.
+    ///
     /// The path must be provided (as .txt file)
     /// This generate a .txt file at the given path with simulation informations.
     ///
@@ -135,342 +485,3671 @@ where
     /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
     /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
     /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
-    /// # let damping = false;
+    /// # let damping = nrf::solver::Damping::Disabled;
     /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
     ///
     /// rf.activate_debug(&"solver_log.txt");
     /// rf.solve(&mut user_model);
     /// ```
+    #[cfg(feature = "debug_log")]
     pub fn activate_debug(&mut self, path: &str) {
-        self.debug = true;
-        self.solver_log = Some(super::log::SolverLog::new(path));
+        self.activate_debug_with_mode(path, DebugWriteMode::Streaming);
     }
 
-    fn evaluate_errors<M>(&self, model: &M) -> nalgebra::OVector<f64, D>
-    where
-        M: model::Model<D>,
-    {
-        let residuals_values = model.get_residuals();
-        self.residuals_config
-            .evaluate_stopping_residuals(&residuals_values)
+    /// Activate the gathering of the log, with a given writing strategy
+    ///
+    /// With [DebugWriteMode::Buffered], the log is kept in memory
+    /// and written to the file at the end of each call to `solve()`,
+    /// whatever the outcome of the resolution.
+    ///
+    /// The content of the file is the same with both modes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use newton_rootfinder as nrf;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    ///
+    /// rf.activate_debug_with_mode(&"solver_log.txt", nrf::solver::DebugWriteMode::Buffered);
+    /// rf.solve(&mut user_model);
+    /// ```
+    #[cfg(feature = "debug_log")]
+    pub fn activate_debug_with_mode(&mut self, path: &str, write_mode: DebugWriteMode) {
+        self.debug = true;
+        self.solver_log = Some(super::log::SolverLog::new(
+            path,
+            write_mode,
+            self.debug_file_policy,
+            &self.log_metadata,
+        ));
     }
 
-    fn compute_jac<M>(&mut self, model: &mut M) -> Result<(), errors::SolverInternalError<M, D>>
-    where
-        M: model::Model<D>,
-    {
-        let successful_jac_computation = if model.jacobian_provided() {
-            evaluate_jacobian_from_analytical_function(
-                &mut self.jacobian,
-                model,
-                self.residuals_config,
-            )
-        } else {
-            evaluate_jacobian_from_finite_difference(
-                &mut self.jacobian,
-                model,
-                self.iters_params,
-                self.residuals_config,
-            )
-        };
-
-        match successful_jac_computation {
-            Ok(())
-            | Err(errors::SolverInternalError::InvalidJacobianError(
-                ModelError::InaccurateValuesError(_),
-            )) => Ok(()),
-            Err(model_error) => Err(model_error),
+    /// Add a metadata to the header of the debug log, such as the identifier of a job or the version of the model
+    ///
+    /// Available with the `debug_log` feature, enabled by default.
+    ///
+    /// The metadata are written as `key: value` lines in the order of the calls,
+    /// after the runner informations gathered with the `additional_log_info` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the debug log is already activated, as its header has then been written
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use newton_rootfinder as nrf;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    ///
+    /// rf.set_log_metadata("job id", "4242");
+    /// rf.set_log_metadata("model commit", "3f2a1c9");
+    /// rf.activate_debug(&"solver_log.txt");
+    /// rf.solve(&mut user_model);
+    /// ```
+    #[cfg(feature = "debug_log")]
+    pub fn set_log_metadata(&mut self, key: &str, value: &str) {
+        if self.debug {
+            panic!(
+                "The metadata \"{}\" must be set before the activation of the debug log",
+                key
+            );
         }
+        self.log_metadata.push((key.to_string(), value.to_string()));
     }
 
-    fn compute_newton_raphson_step<M>(
-        &mut self,
-        model: &mut M,
-    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
-    where
-        M: model::Model<D>,
-    {
-        let successful_jac_computation = self.compute_jac(model);
+    /// Set the options of the content of the debug log
+    ///
+    /// Available with the `debug_log` feature, enabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use newton_rootfinder as nrf;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    ///
+    /// // 5 significant digits, jacobian written in full up to 20x20
+    /// rf.set_log_options(nrf::solver::LogOptions::new(5, 20, 10));
+    /// rf.activate_debug(&"solver_log.txt");
+    /// rf.solve(&mut user_model);
+    /// ```
+    #[cfg(feature = "debug_log")]
+    pub fn set_log_options(&mut self, log_options: LogOptions) {
+        self.log_options = log_options;
+    }
 
+    /// Set the writing of the debug log when the solver performs several resolutions,
+    /// the default being [DebugFilePolicy::Append]
+    ///
+    /// Available with the `debug_log` feature, enabled by default.
+    ///
+    /// With [DebugFilePolicy::NumberedFiles], the files are created at the start of the resolutions.
+    ///
+    /// # Panics
+    ///
+    /// If the debug log is already activated, as its file has then been created
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use newton_rootfinder as nrf;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    ///
+    /// rf.set_debug_file_policy(nrf::solver::DebugFilePolicy::NumberedFiles);
+    /// rf.activate_debug(&"solver_log.txt");
+    /// // written to solver_log_001.txt and solver_log_002.txt
+    /// rf.solve(&mut user_model);
+    /// rf.solve(&mut user_model);
+    /// ```
+    #[cfg(feature = "debug_log")]
+    pub fn set_debug_file_policy(&mut self, debug_file_policy: DebugFilePolicy) {
         if self.debug {
-            self.jac_to_log();
-        }
-        match successful_jac_computation {
-            Ok(()) => Ok(self.compute_next_from_inv_jac(model)),
-            Err(error) => Err(error),
+            panic!("The debug file policy must be set before the activation of the debug log");
         }
+        self.debug_file_policy = debug_file_policy;
     }
 
-    /// Perform the jacobian evaluation
-    ///
-    /// Based on the resolution method:
-    /// - the jacobian can be computed and inverted
-    /// - the jacobian can be approximated and inverted
-    /// - the inverse of the jacobian can be approximated
-    fn evaluate_jacobian_quasi_newton_step<M>(
-        &mut self,
-        model: &mut M,
-        resolution_method: QuasiNewtonMethod,
-    ) -> Result<(), crate::errors::SolverInternalError<M, D>>
-    where
-        M: model::Model<D>,
-    {
-        if self.jacobian.compute_jacobian() {
-            let successful_jac_computation = self.compute_jac(model);
+    #[cfg(feature = "debug_log")]
+    pub fn get_debug_file_policy(&self) -> DebugFilePolicy {
+        self.debug_file_policy
+    }
 
-            match successful_jac_computation {
-                Ok(()) => (),
-                Err(error) => {
-                    if self.debug {
-                        self.jac_to_log();
-                    }
-                    return Err(error);
-                }
-            }
-        } else {
-            match resolution_method {
-                QuasiNewtonMethod::StationaryNewton => (),
-                QuasiNewtonMethod::JacobianUpdate(method) => {
-                    match approximate_jacobian(
-                        &mut self.jacobian,
-                        method,
-                        self.iteratives_step_size.as_ref().unwrap(),
-                        self.residuals_step_size.as_ref().unwrap(),
-                        self.residuals_values_current.as_ref().unwrap(),
-                    ) {
-                        Ok(()) => (),
+    pub fn get_parameters(&self) -> &SolverParameters {
+        &self.parameters
+    }
+
+    /// Problem size of the parameters, provided or inferred,
+    /// the length of the initial guess being checked against it when building the [RootFinder]
+    fn problem_size(&self) -> usize {
+        self.initial_guess.len()
+    }
+
+    /// Set the resolution method used from the next call to `solve()`
+    ///
+    /// The jacobian kept from the previous resolution is discarded,
+    /// as it might not be usable by the new method,
+    /// for example if only its inverse had been updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    /// rf.solve(&mut user_model).unwrap();
+    /// let newton_raphson_iter = rf.get_iter();
+    ///
+    /// rf.set_resolution_method(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+    ///     UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    /// )));
+    /// rf.solve(&mut user_model).unwrap();
+    /// assert!(rf.get_iter() >= newton_raphson_iter);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the storage of the jacobian with the new method exceeds the memory limit,
+    /// see [SolverParameters::check_memory_limit]
+    pub fn set_resolution_method(&mut self, resolution_method: ResolutionMethod) {
+        self.parameters.set_resolution_method(resolution_method);
+        if let Err(error) = self.parameters.check_memory_limit() {
+            panic!("{}", error);
+        }
+        self.clear_quasi_newton_state();
+    }
+
+    /// Storage in bytes estimated for the jacobian with the current parameters,
+    /// see [super::estimate_memory_bytes]
+    pub fn estimated_memory_bytes(&self) -> usize {
+        super::estimate_memory_bytes(
+            self.problem_size(),
+            self.parameters.get_resolution_method(),
+            self.parameters.get_linear_solver(),
+        )
+    }
+
+    /// Set the tolerance used from the next call to `solve()`, see [SolverParameters]
+    ///
+    /// # Panics
+    ///
+    /// If the tolerance is not accepted by [SolverParameters::check_tolerance],
+    /// the message being the one of the [errors::RootFinderConstructionError]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.parameters.set_tolerance(tolerance);
+        if let Err(error) = self.parameters.check_tolerance() {
+            panic!("{}", error);
+        }
+    }
+
+    /// Set the maximum number of iterations used from the next call to `solve()`, see [SolverParameters]
+    pub fn set_max_iter(&mut self, max_iter: usize) {
+        self.parameters.set_max_iter(max_iter);
+    }
+
+    /// Set the damping used from the next call to `solve()`, see [SolverParameters]
+    pub fn set_damping(&mut self, damping: Damping) {
+        self.parameters.set_damping(damping);
+    }
+
+    /// Set the initial guess used from the next call to `solve()`
+    ///
+    /// The values are copied into the storage of the current initial guess,
+    /// to solve several models with the same solver, see [super::solve_batch].
+    /// The fixed iteratives keep their fixed value.
+    pub fn set_initial_guess(&mut self, initial_guess: &nalgebra::OVector<f64, D>) {
+        if initial_guess.len() != self.problem_size() {
+            panic!(
+                "Dimension mismatch :\n initial_guess.len() = {} and problem_size = {}",
+                initial_guess.len(),
+                self.problem_size()
+            );
+        }
+        self.initial_guess.copy_from(initial_guess);
+        self.iters_params
+            .apply_fixed_values(&mut self.initial_guess);
+    }
+
+    /// Initial guess used from the next call to `solve()`
+    ///
+    /// With [BoundPolicy::Clamp], `solve()` overwrites the initial guess with its projection onto the bounds of the iteratives,
+    /// hence the projected values are returned after a resolution, and used by the next one.
+    pub fn get_initial_guess(&self) -> &nalgebra::OVector<f64, D> {
+        &self.initial_guess
+    }
+
+    /// Discard the jacobian and the steps used by the quasi-Newton updates
+    fn clear_quasi_newton_state(&mut self) {
+        self.force_jacobian_computation();
+        self.clear_quasi_newton_steps();
+    }
+
+    /// Compute the jacobian at the next iteration,
+    /// or the seed of the limited memory Broyden method, its steps being discarded
+    fn force_jacobian_computation(&mut self) {
+        self.jacobian.force_jacobian_computation();
+        self.limited_memory = None;
+    }
+
+    /// The jacobian used by the last iteration is an approximation that its computation would improve
+    ///
+    /// The seed of the limited memory Broyden method is only considered as approximated once updated
+    fn is_jacobian_approximated(&self) -> bool {
+        self.jacobian.is_jacobian_approximated()
+            || self
+                .limited_memory
+                .as_ref()
+                .map_or(false, |limited_memory| !limited_memory.is_empty())
+    }
+
+    /// Discard the steps used by the quasi-Newton updates
+    ///
+    /// Called at each computation of the jacobian:
+    /// the steps made with the previous jacobian must not be used to update the new one,
+    /// the first update following the computation only using the step made with it.
+    fn clear_quasi_newton_steps(&mut self) {
+        self.iteratives_step_size = None;
+        self.residuals_step_size = None;
+        self.residuals_values_current = None;
+    }
+
+    /// Reset the solver placeholders before a new resolution
+    ///
+    /// The storage allocated by a previous resolution is kept
+    fn reset(&mut self) {
+        self.iter = 0;
+        self.model_evaluations = 0;
+        self.saved_model_evaluations = 0;
+        self.exhausted_budget = None;
+        self.active_bound_variables.clear();
+        self.converged_on_boundary = false;
+        self.last_satisfied_criterion = None;
+        self.best_iterate = None;
+        self.initial_errors = None;
+        self.last_max_error = None;
+        self.max_errors.clear();
+        self.stopping_errors_current = None;
+        self.previous_iterate = None;
+        self.cycles_detected = 0;
+        self.non_finite_updates = 0;
+        self.aitken_iterates.clear();
+        self.aitken_extrapolations = 0;
+        self.unconverged_residuals = errors::UnconvergedResiduals::default();
+        self.last_errors = None;
+        self.errors_over_tolerance = None;
+        self.jacobian_sources.clear();
+        self.jacobian_source = None;
+        self.constant_jacobian_values = None;
+        self.last_step = None;
+        #[cfg(feature = "std")]
+        if let Some(history) = self.history.as_mut() {
+            history.clear();
+            self.history_start = Some(std::time::Instant::now());
+        }
+        #[cfg(feature = "std")]
+        {
+            self.update_metrics = None;
+            self.jacobian_dump_failures.clear();
+        }
+        self.clear_quasi_newton_steps();
+        self.jacobian
+            .set_inverse_required(self.parameters.get_linear_solver().requires_inverse());
+        self.jacobian.set_structural_reduction(
+            self.parameters.get_structural_singularity_policy()
+                == StructuralSingularityPolicy::Reduce,
+        );
+        if self.jacobian.get_sparsity_pattern() != self.parameters.get_sparsity_pattern() {
+            self.jacobian
+                .set_sparsity_pattern(self.parameters.get_sparsity_pattern().cloned());
+        }
+        if !(self.jacobian_reuse && self.jacobian.reuse()) {
+            self.jacobian.reset();
+        }
+        let reused_memory =
+            match self.parameters.get_resolution_method() {
+                ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden {
+                    memory,
+                }) if self.jacobian_reuse => Some(memory),
+                _ => None,
+            };
+        if self
+            .limited_memory
+            .as_ref()
+            .map(|limited_memory| limited_memory.get_memory())
+            != reused_memory
+        {
+            self.limited_memory = None;
+        }
+        self.valid_last_model_evaluation = true;
+    }
+
+    /// Start each resolution with the jacobian of the previous one, the default being `false`
+    ///
+    /// It is intended for a sequence of close problems, such as the time steps of an implicit integrator,
+    /// solved with a quasi-Newton method: the first iteration uses the last jacobian of the previous resolution
+    /// instead of computing it.
+    /// With the Newton-Raphson method, the jacobian is computed at each iteration, the reuse has no effect.
+    ///
+    /// The reused jacobian is considered as approximated:
+    /// with the damping, a rejected step triggers its computation, see [SolverParameters].
+    /// The jacobian is computed if the previous resolution has not left any,
+    /// if its computation has been requested for the next iteration,
+    /// or after [RootFinder::invalidate_jacobian], to be called if the problem changes significantly.
+    ///
+    /// With the [QuasiNewtonMethod::LimitedMemoryBroyden] method, the seed and the steps kept are reused.
+    pub fn set_jacobian_reuse(&mut self, jacobian_reuse: bool) {
+        self.jacobian_reuse = jacobian_reuse;
+    }
+
+    pub fn get_jacobian_reuse(&self) -> bool {
+        self.jacobian_reuse
+    }
+
+    /// Compute the jacobian at the next iteration,
+    /// in particular at the first one of the next resolution with [RootFinder::set_jacobian_reuse]
+    pub fn invalidate_jacobian(&mut self) {
+        self.force_jacobian_computation();
+    }
+
+    /// Number of iterations performed by the last resolution
+    ///
+    /// See [SolverParameters] for the definition of an iteration
+    pub fn get_iter(&self) -> usize {
+        self.iter
+    }
+
+    /// Number of model evaluations performed by the last resolution
+    ///
+    /// All the evaluations are counted, see [SolverParameters]
+    pub fn get_model_evaluations(&self) -> usize {
+        self.model_evaluations
+    }
+
+    /// Number of model evaluations saved by the last resolution thanks to the constant jacobian columns
+    ///
+    /// See [RootFinder::declare_constant_jacobian_columns]
+    pub fn get_saved_model_evaluations(&self) -> usize {
+        self.saved_model_evaluations
+    }
+
+    /// Declare columns of the jacobian as constant, to evaluate them only once per resolution
+    ///
+    /// The column `j` is the derivative of the residuals with respect to the iterative `j`:
+    /// it is constant if the residuals depend linearly on this iterative.
+    /// With the finite differences, these columns are evaluated with the first jacobian of a resolution
+    /// and reused for the following ones, even when the jacobian computation is forced,
+    /// saving one model evaluation per column (see [RootFinder::get_saved_model_evaluations]).
+    ///
+    /// The columns are normalized with the update methods of the residuals:
+    /// they are only constant with the `Abs` and `RelToReference` normalizations.
+    /// The declaration has no effect on a jacobian provided by the model.
+    ///
+    /// The declared columns replace the previous ones.
+    ///
+    /// # Panics
+    ///
+    /// If a column is not lower than the problem size
+    pub fn declare_constant_jacobian_columns(&mut self, columns: &[usize]) {
+        let problem_size = self.problem_size();
+        if let Some(column) = columns.iter().find(|&&column| column >= problem_size) {
+            panic!(
+                "The constant jacobian column {} is out of range, the problem size is {}",
+                column, problem_size
+            );
+        }
+        self.constant_jacobian_columns = columns.to_vec();
+        self.constant_jacobian_columns.sort_unstable();
+        self.constant_jacobian_columns.dedup();
+        self.constant_jacobian_values = None;
+    }
+
+    /// Columns of the jacobian declared as constant
+    ///
+    /// See [RootFinder::declare_constant_jacobian_columns]
+    pub fn get_constant_jacobian_columns(&self) -> &[usize] {
+        &self.constant_jacobian_columns
+    }
+
+    /// Write the next jacobian computed by the solver to a file, for analysis with external tools
+    ///
+    /// Available with the `std` feature, enabled by default.
+    ///
+    /// Only the jacobians computed with finite differences or provided by the model are written,
+    /// not the ones approximated by the quasi-Newton methods.
+    /// The file header gives the iteration at which the jacobian has been computed, see [MatrixFileFormat].
+    ///
+    /// The request is discarded once the jacobian written, even if it has been computed in a later resolution.
+    /// It replaces a previous call to this method or to [RootFinder::dump_every_jacobian].
+    ///
+    /// If the file cannot be written, the resolution continues:
+    /// the failure is recorded, see [RootFinder::get_jacobian_dump_failures].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use newton_rootfinder as nrf;
+    /// # use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::{MatrixFileFormat, ResolutionMethod};
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #     let mut y = x * x;
+    /// #     y[0] -= 2.0;
+    /// #    y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// rf.dump_jacobian_on_next_computation("jacobian.csv", MatrixFileFormat::Csv);
+    /// rf.solve(&mut user_model).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dump_jacobian_on_next_computation(&mut self, path: &str, format: MatrixFileFormat) {
+        self.jacobian_dump = Some(JacobianDump {
+            path_template: path.to_string(),
+            format,
+            every_computation: false,
+        });
+    }
+
+    /// Write every jacobian computed by the solver to a file, see [RootFinder::dump_jacobian_on_next_computation]
+    ///
+    /// Available with the `std` feature, enabled by default.
+    ///
+    /// The `{iter}` placeholder of the `path_template` is replaced by the iteration
+    /// at which the jacobian has been computed, for example `"jacobian_{iter}.mtx"`.
+    /// If a file cannot be written, the resolution continues,
+    /// see [RootFinder::get_jacobian_dump_failures].
+    ///
+    /// # Panics
+    ///
+    /// If the `path_template` does not contain the `{iter}` placeholder,
+    /// each jacobian overwriting the previous one.
+    #[cfg(feature = "std")]
+    pub fn dump_every_jacobian(&mut self, path_template: &str, format: MatrixFileFormat) {
+        if !path_template.contains("{iter}") {
+            panic!(
+                "The path template {} of the jacobian files must contain the {{iter}} placeholder",
+                path_template
+            );
+        }
+        self.jacobian_dump = Some(JacobianDump {
+            path_template: path_template.to_string(),
+            format,
+            every_computation: true,
+        });
+    }
+
+    /// Jacobian files that could not be written during the last resolution,
+    /// with the iteration at which the jacobian has been computed and the description of the error
+    ///
+    /// Available with the `std` feature, enabled by default.
+    ///
+    /// See [RootFinder::dump_jacobian_on_next_computation] and [RootFinder::dump_every_jacobian].
+    #[cfg(feature = "std")]
+    pub fn get_jacobian_dump_failures(&self) -> &[(usize, String)] {
+        &self.jacobian_dump_failures
+    }
+
+    /// Write the jacobian just computed if requested by the user, see [RootFinder::dump_jacobian_on_next_computation]
+    ///
+    /// A file that cannot be written does not stop the resolution: the failure is recorded and logged.
+    /// The request of a single dump is discarded anyway.
+    #[cfg(feature = "std")]
+    fn dump_computed_jacobian(&mut self) {
+        if self.jacobian.get_jacobian().is_none() {
+            return;
+        }
+        if let Some(dump) = &self.jacobian_dump {
+            let path = dump_path(&dump.path_template, self.iter);
+            if let Err(error) = self
+                .jacobian
+                .write_jacobian_file(&path, dump.format, self.iter)
+            {
+                let failure = format!("cannot write the jacobian file {}: {}", path, error);
+                #[cfg(feature = "debug_log")]
+                if self.debug {
+                    self.solver_log
+                        .as_ref()
+                        .unwrap()
+                        .add_content(&format!("WARNING: {}\n\n", failure));
+                }
+                self.jacobian_dump_failures.push((self.iter, failure));
+            }
+            if !dump.every_computation {
+                self.jacobian_dump = None;
+            }
+        }
+    }
+
+    /// Replace the right members of some residuals by functions of the iteratives
+    ///
+    /// After each evaluation of the model, the right member of the residual `i` is replaced
+    /// by the value of `overrides[i]` at the iteratives of the model, if any, before the normalization.
+    /// It allows to give a target depending on the iteratives, such as a setpoint schedule,
+    /// without modifying the model.
+    ///
+    /// The contribution of the overrides to the jacobian is taken into account by the finite differences.
+    /// The overrides are not supported with a jacobian provided by the model:
+    /// the resolution panics, see [RootFinder::solve].
+    ///
+    /// The overrides replace the previous ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// #
+    /// // Equation: x**2 = 2, the target 2 being given by the override
+    /// fn square(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    ///     x.component_mul(x)
+    /// }
+    ///
+    /// # let problem_size = 1;
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// let target: residuals::RightMemberOverride<nalgebra::Dyn> = Box::new(|_| 2.0);
+    /// rf.set_right_overrides(vec![Some(target)]);
+    ///
+    /// let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square);
+    /// rf.solve(&mut user_model).unwrap();
+    /// assert!((user_model.get_iteratives()[0] - 2f64.sqrt()).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the number of overrides is not the problem size
+    pub fn set_right_overrides(
+        &mut self,
+        overrides: Vec<Option<residuals::RightMemberOverride<D>>>,
+    ) {
+        let problem_size = self.problem_size();
+        if overrides.len() != problem_size {
+            panic!(
+                "Dimension mismatch between the residuals and the right members overrides {} != {}",
+                problem_size,
+                overrides.len()
+            );
+        }
+        self.right_overrides = residuals::RightOverrides::new(overrides);
+    }
+
+    /// Residuals whose right member is overridden
+    ///
+    /// See [RootFinder::set_right_overrides]
+    pub fn get_overridden_residuals(&self) -> Vec<usize> {
+        self.right_overrides.get_overridden_residuals()
+    }
+
+    /// Set a custom convergence predicate, called on the state of the resolution
+    /// after the evaluation of the initial guess and after each iteration
+    ///
+    /// The predicate receives a [ConvergenceContext] made of shared references:
+    /// the iteration number, the stopping errors, the last step, the iteratives and the model evaluations.
+    /// Depending on the [PredicateMode] (see [RootFinder::set_convergence_predicate_mode]),
+    /// it replaces the tolerance check of the residuals (default) or is combined with it.
+    /// The convergence step tolerances of the iteratives are still required.
+    ///
+    /// When the predicate decides the convergence, the last satisfied criterion is
+    /// [ConvergenceCriterion::CustomPredicate].
+    /// The polish of the solution is not affected by the predicate.
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives;
+    /// use nrf::model::Model;
+    /// use nrf::residuals;
+    /// use nrf::solver::ResolutionMethod;
+    ///
+    /// fn square(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    ///     x.map(|v| v * v - 2.0)
+    /// }
+    ///
+    /// let problem_size = 1;
+    /// let init_guess = nalgebra::DVector::from_element(problem_size, 1.0);
+    /// let iteratives_vec = iteratives::default_vec_iteratives_fd(problem_size);
+    /// let iter_params = iteratives::Iteratives::new(&iteratives_vec);
+    /// let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    ///
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// rf.set_convergence_predicate(Box::new(|context| {
+    ///     context.get_stopping_errors().amax() < context.get_tolerance()
+    ///         && context.get_last_step().map_or(false, |step| step.amax() < 1e-9)
+    /// }));
+    ///
+    /// let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square);
+    /// rf.solve(&mut user_model).unwrap();
+    /// assert_eq!(
+    ///     rf.get_last_satisfied_criterion(),
+    ///     Some(nrf::solver::ConvergenceCriterion::CustomPredicate)
+    /// );
+    /// ```
+    pub fn set_convergence_predicate(&mut self, predicate: ConvergencePredicate<'a, D>) {
+        self.convergence_predicate = Some(predicate);
+    }
+
+    /// Remove the custom convergence predicate, see [RootFinder::set_convergence_predicate]
+    pub fn clear_convergence_predicate(&mut self) {
+        self.convergence_predicate = None;
+    }
+
+    /// Whether a custom convergence predicate is set, see [RootFinder::set_convergence_predicate]
+    pub fn has_convergence_predicate(&self) -> bool {
+        self.convergence_predicate.is_some()
+    }
+
+    /// Set the combination of the custom convergence predicate with the tolerance check,
+    /// see [RootFinder::set_convergence_predicate]
+    pub fn set_convergence_predicate_mode(&mut self, predicate_mode: PredicateMode) {
+        self.predicate_mode = predicate_mode;
+    }
+
+    pub fn get_convergence_predicate_mode(&self) -> PredicateMode {
+        self.predicate_mode
+    }
+
+    /// Budget exhausted by the last resolution, if it stopped without reaching convergence
+    ///
+    /// `None` if the last resolution converged or failed for another reason
+    pub fn get_exhausted_budget(&self) -> Option<ExhaustedBudget> {
+        self.exhausted_budget
+    }
+
+    /// Iteratives on one of their bounds at the end of the last resolution
+    ///
+    /// The bounds are checked once the resolution loop is over, whether the convergence is reached or not.
+    /// See [crate::iteratives::ACTIVE_BOUND_TOLERANCE] for the tolerance used.
+    pub fn get_active_bound_variables(&self) -> &[usize] {
+        &self.active_bound_variables
+    }
+
+    /// Whether the last resolution reached the convergence with iteratives on their bounds
+    ///
+    /// In that case, the solution might only be the best point within the bounds and not a true root,
+    /// see [SolverParameters] to reject such a solution
+    pub fn is_converged_on_boundary(&self) -> bool {
+        self.converged_on_boundary
+    }
+
+    /// Convergence criterion satisfied last by the last resolution
+    ///
+    /// It is [ConvergenceCriterion::Step] if the residuals were already below the tolerance
+    /// at the previous iteration, while some iteratives steps were not below their convergence step tolerance.
+    /// It is [ConvergenceCriterion::InitialGuess] if the initial guess was already converged:
+    /// the initial errors are then the final ones, and the jacobian has not been computed.
+    ///
+    /// `None` if the last resolution did not converge
+    pub fn get_last_satisfied_criterion(&self) -> Option<ConvergenceCriterion> {
+        self.last_satisfied_criterion
+    }
+
+    /// Iteratives with the lowest maximum stopping error met by the last resolution, along with this error
+    ///
+    /// It allows to restart a failed resolution from the best point rather than from the last one,
+    /// see [SolverParameters] to evaluate the model at this point when the resolution fails.
+    ///
+    /// `None` if the model could not be evaluated at the initial guess
+    /// or if no iterate had finite errors
+    pub fn get_best_iterate(&self) -> Option<(nalgebra::OVector<f64, D>, f64)> {
+        self.best_iterate.clone()
+    }
+
+    /// Number of cycles of the iterates detected by the last resolution
+    ///
+    /// Always `0` if the cycle detection is not activated, see [SolverParameters]
+    pub fn get_cycles_detected(&self) -> usize {
+        self.cycles_detected
+    }
+
+    /// Number of Aitken extrapolations kept by the last resolution
+    ///
+    /// Always `0` if the Aitken acceleration is not activated, see [SolverParameters]
+    pub fn get_aitken_extrapolations(&self) -> usize {
+        self.aitken_extrapolations
+    }
+
+    /// Source of the jacobian used by each iteration of the last resolution, in the order of the iterations
+    ///
+    /// It tells which iterations computed the jacobian, with the model or with finite differences,
+    /// updated it with a quasi-Newton method or reused the previous one,
+    /// for example during a Newton warmup or after a step rejected by the damping.
+    /// The sweeps of the nonlinear Gauss-Seidel method are recorded as [JacobianSource::ScalarFiniteDifference].
+    pub fn get_jacobian_sources(&self) -> &[JacobianSource] {
+        &self.jacobian_sources
+    }
+
+    /// Record each iteration of the next resolutions, the default being `false`
+    ///
+    /// Available with the `std` feature, enabled by default.
+    ///
+    /// The records, including the wall-clock duration of each iteration,
+    /// are given by [RootFinder::get_history] and can be written with [RootFinder::write_history].
+    ///
+    /// The quality of each quasi-Newton update is also measured, see [super::QuasiNewtonUpdateMetrics]:
+    /// it requires a copy of the matrix before the update, which is only made if the history is activated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use newton_rootfinder as nrf;
+    /// # use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #     let mut y = x * x;
+    /// #     y[0] -= 2.0;
+    /// #    y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// rf.set_history(true);
+    /// rf.solve(&mut user_model).unwrap();
+    ///
+    /// let history = rf.get_history();
+    /// assert_eq!(history.len(), rf.get_iter());
+    /// assert_eq!(history[0].get_iteration(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_history(&mut self, history: bool) {
+        self.history = if history { Some(Vec::new()) } else { None };
+        self.history_start = None;
+    }
+
+    #[cfg(feature = "std")]
+    pub fn get_history_activated(&self) -> bool {
+        self.history.is_some()
+    }
+
+    /// Records of the iterations of the last resolution, empty if the history is not activated
+    ///
+    /// See [RootFinder::set_history]
+    #[cfg(feature = "std")]
+    pub fn get_history(&self) -> &[IterationRecord] {
+        self.history.as_deref().unwrap_or(&[])
+    }
+
+    /// Write the history of the last resolution to a CSV file, one line per iteration
+    ///
+    /// The columns are `iteration,max_error,model_evaluations,duration_s,timestamp_offset_s,secant_residual,update_norm`,
+    /// the durations being in seconds, see [IterationRecord].
+    /// The metrics of the quasi-Newton updates, see [super::QuasiNewtonUpdateMetrics], are empty for the iterations without update.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be written
+    #[cfg(feature = "std")]
+    pub fn write_history(&self, path: &str) {
+        write_history_file(self.get_history(), path);
+    }
+
+    /// Record the iteration just accepted if the history is activated
+    #[cfg(feature = "std")]
+    fn record_iteration(&mut self, iteration_start: std::time::Instant) {
+        let now = std::time::Instant::now();
+        let history_start = *self.history_start.get_or_insert(iteration_start);
+        let record = IterationRecord {
+            iteration: self.iter,
+            max_error: self.last_max_error.unwrap_or(f64::NAN),
+            model_evaluations: self.model_evaluations,
+            duration: now.duration_since(iteration_start),
+            timestamp_offset: now.duration_since(history_start),
+            update_metrics: self.update_metrics.take(),
+        };
+        if let Some(history) = self.history.as_mut() {
+            history.push(record);
+        }
+    }
+
+    /// Number of iterations of the last resolution per source of the jacobian, in the order of their first use
+    ///
+    /// See [RootFinder::get_jacobian_sources]
+    pub fn get_jacobian_source_counts(&self) -> Vec<(JacobianSource, usize)> {
+        count_jacobian_sources(&self.jacobian_sources)
+    }
+
+    /// Stopping errors at the initial guess of the last resolution,
+    /// i.e. the residuals normalized with the stopping criteria
+    ///
+    /// `None` if the model could not be evaluated at the initial guess
+    pub fn get_initial_errors(&self) -> Option<&nalgebra::OVector<f64, D>> {
+        self.initial_errors.as_ref()
+    }
+
+    /// Maximum of the stopping errors at the initial guess of the last resolution
+    ///
+    /// See [RootFinder::get_initial_errors]
+    pub fn get_initial_max_error(&self) -> Option<f64> {
+        self.initial_errors.as_ref().map(Self::max_error)
+    }
+
+    /// Maximum stopping error of the last iterate evaluated by the last resolution
+    ///
+    /// `None` if the model could not be evaluated at the initial guess
+    pub fn get_last_max_error(&self) -> Option<f64> {
+        self.last_max_error
+    }
+
+    /// Convergence order and asymptotic rate estimated from the maximum errors of the last iterations of the last resolution
+    ///
+    /// `None` if fewer than [super::MIN_ITERATIONS_CONVERGENCE_RATE] iterations have been performed,
+    /// or if the errors of the last iterations are not decreasing, see [ConvergenceRate].
+    /// A resumed resolution only uses the iterations performed since it has been resumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use newton_rootfinder as nrf;
+    /// # use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #     let mut y = x * x;
+    /// #     y[0] -= 2.0;
+    /// #    y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     nalgebra::DVector::from_vec(vec![1.0]),
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// rf.solve(&mut user_model).unwrap();
+    ///
+    /// let order = rf.get_convergence_rate().unwrap().get_order();
+    /// assert!(order > 1.8);
+    /// ```
+    pub fn get_convergence_rate(&self) -> Option<ConvergenceRate> {
+        estimate_convergence_rate(&self.max_errors)
+    }
+
+    /// The last resolution used the Newton-Raphson method with the jacobian provided by the model,
+    /// but its convergence order is estimated below [SUSPICIOUS_NEWTON_ORDER]
+    ///
+    /// The convergence of the Newton-Raphson method is expected to be quadratic:
+    /// a linear convergence usually comes from an error in the jacobian of the model,
+    /// which can be compared to the one given by [super::evaluate_jacobian_from_finite_difference].
+    /// It is only a heuristic, see [RootFinder::get_convergence_rate]:
+    /// the convergence is also linear towards a root where the jacobian is singular.
+    pub fn get_suspicious_jacobian(&self) -> bool {
+        self.parameters.get_resolution_method() == ResolutionMethod::NewtonRaphson
+            && !self.jacobian_sources.is_empty()
+            && self
+                .jacobian_sources
+                .iter()
+                .all(|source| *source == JacobianSource::AnalyticalModel)
+            && self
+                .get_convergence_rate()
+                .map_or(false, |convergence_rate| {
+                    convergence_rate.get_order() < SUSPICIOUS_NEWTON_ORDER
+                })
+    }
+
+    /// Residuals above their tolerance when the last resolution stopped without reaching the convergence,
+    /// with their stopping error, sorted by decreasing error
+    ///
+    /// Empty if the last resolution converged, see [errors::UnconvergedResiduals] for more details
+    pub fn get_unconverged_residuals(&self) -> Vec<(usize, f64)> {
+        self.unconverged_residuals.get_residuals().to_vec()
+    }
+
+    /// Stopping errors of the last iterate of the last resolution, expressed in units of the tolerance of each residual
+    ///
+    /// A value of `2.4` is a residual at 2.4 times its tolerance.
+    /// With the convergence on the groups, the tolerance of each residual is the one of its group,
+    /// see [residuals::ResidualsConfig::residual_tolerances].
+    /// They are computed at the termination of the resolution, whether it converged or not,
+    /// from the errors of [RootFinder::get_last_max_error]:
+    /// `None` if the model could not be evaluated at the initial guess.
+    pub fn get_errors_over_tolerance(&self) -> Option<&nalgebra::OVector<f64, D>> {
+        self.errors_over_tolerance.as_ref()
+    }
+
+    /// Number of residuals above their tolerance at the end of the last resolution, see [RootFinder::get_errors_over_tolerance]
+    pub fn get_residuals_above_tolerance(&self) -> usize {
+        self.errors_over_tolerance
+            .as_ref()
+            .map_or(0, super::batch::count_above_tolerance)
+    }
+
+    /// Residual the farthest from its tolerance at the end of the last resolution,
+    /// with its error in units of its tolerance, see [RootFinder::get_errors_over_tolerance]
+    pub fn get_worst_residual(&self) -> Option<(usize, f64)> {
+        self.errors_over_tolerance
+            .as_ref()
+            .and_then(super::batch::worst_residual)
+    }
+
+    /// Residuals blocking the convergence, `tolerance` giving the tolerance of each residual
+    ///
+    /// With the convergence on the groups, the residuals of the groups not converged are checked against their tolerance instead
+    fn find_unconverged_residuals<F>(
+        &self,
+        errors: &nalgebra::OVector<f64, D>,
+        tolerance: F,
+        group_convergence: bool,
+    ) -> errors::UnconvergedResiduals
+    where
+        F: Fn(usize) -> f64,
+    {
+        // a non-finite error is not converged
+        let is_above = |error: f64, tolerance: f64| error.is_nan() || error > tolerance;
+        let groups = self.residuals_config.get_groups();
+        let (residuals, groups): (Vec<(usize, f64)>, Vec<String>) = if group_convergence {
+            let aggregation = self.residuals_config.get_group_aggregation();
+            let solver_tolerance = self.parameters.get_tolerance();
+            let unconverged_groups: Vec<_> = groups
+                .iter()
+                .filter(|group| {
+                    group.aggregate(errors, aggregation)
+                        > group.get_tolerance().unwrap_or(solver_tolerance)
+                })
+                .collect();
+            let mut residuals: Vec<(usize, f64)> = Vec::new();
+            for group in &unconverged_groups {
+                let group_tolerance = group.get_tolerance().unwrap_or(solver_tolerance);
+                for &i in group.get_indices() {
+                    if is_above(errors[i], group_tolerance) {
+                        residuals.push((i, errors[i]));
+                    }
+                }
+            }
+            let groups = unconverged_groups
+                .iter()
+                .map(|group| group.get_name().to_string())
+                .collect();
+            (residuals, groups)
+        } else {
+            let residuals: Vec<(usize, f64)> = errors
+                .iter()
+                .enumerate()
+                .filter(|(i, error)| is_above(**error, tolerance(*i)))
+                .map(|(i, error)| (i, *error))
+                .collect();
+            let groups = groups
+                .iter()
+                .filter(|group| {
+                    group
+                        .get_indices()
+                        .iter()
+                        .any(|i| residuals.iter().any(|(j, _)| i == j))
+                })
+                .map(|group| group.get_name().to_string())
+                .collect();
+            (residuals, groups)
+        };
+        errors::UnconvergedResiduals::new(residuals, groups)
+    }
+
+    /// Keep the stopping errors of the last iterate, see [RootFinder::get_last_max_error]
+    fn record_last_errors(&mut self, errors: &nalgebra::OVector<f64, D>) {
+        self.last_max_error = Some(Self::max_error(errors));
+        self.max_errors.push(Self::max_error(errors));
+        match self.last_errors.as_mut() {
+            Some(last_errors) => last_errors.copy_from(errors),
+            None => self.last_errors = Some(errors.clone()),
+        }
+    }
+
+    /// Keep the current iteratives if their maximum error is the lowest met so far
+    fn record_best_iterate<M>(&mut self, model: &M, errors: &nalgebra::OVector<f64, D>)
+    where
+        M: model::Model<D>,
+    {
+        let max_error = Self::max_error(errors);
+        let is_best = match &self.best_iterate {
+            Some((_, best_error)) => max_error < *best_error,
+            None => max_error.is_finite(),
+        };
+        if is_best {
+            self.best_iterate = Some((model.get_iteratives(), max_error));
+        }
+    }
+
+    /// Evaluate the model at the best iterate, if it is not the current one
+    ///
+    /// An error of this evaluation is ignored, the error of the resolution being returned
+    fn restore_best_iterate<M>(&mut self, model: &mut M)
+    where
+        M: model::Model<D>,
+    {
+        if let Some((best_iteratives, _)) = &self.best_iterate {
+            if *best_iteratives != model.get_iteratives() {
+                model.set_iteratives(best_iteratives);
+                self.model_evaluations += 1;
+                self.valid_last_model_evaluation = model.evaluate().is_ok();
+
+                #[cfg(feature = "debug_log")]
+                if self.debug {
+                    self.best_iterate_to_log();
+                }
+            }
+        }
+    }
+
+    /// Budget of the resolution that is exhausted at the current state of the solver, if any
+    fn check_budget(&self) -> Option<ExhaustedBudget> {
+        if self.iter >= self.parameters.get_max_iter() {
+            Some(ExhaustedBudget::Iterations)
+        } else {
+            match self.parameters.get_max_model_evaluations() {
+                Some(max_model_evaluations) if self.model_evaluations >= max_model_evaluations => {
+                    Some(ExhaustedBudget::ModelEvaluations)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// (residual, iterative) pairs removed from the system during the last resolution,
+    /// see [StructuralSingularityPolicy::Reduce]
+    pub fn get_structural_reductions(&self) -> &[(usize, usize)] {
+        self.jacobian.get_reduced_rows()
+    }
+
+    /// Perturbations used by the last evaluation of the jacobian per finite difference
+    ///
+    /// `None` if the jacobian has not been evaluated per finite difference during the last resolution
+    pub fn get_perturbations(&self) -> &Option<nalgebra::OVector<f64, D>> {
+        self.jacobian.get_perturbations()
+    }
+
+    /// Iteratives whose perturbed value was out of their bounds
+    /// during the last evaluation of the jacobian per finite difference
+    ///
+    /// The model has then been evaluated outside of the bounds of these iteratives,
+    /// which can degrade the accuracy of the jacobian
+    pub fn get_perturbations_out_of_bounds(&self) -> &[usize] {
+        self.jacobian.get_perturbations_out_of_bounds()
+    }
+
+    /// Columns for which the model returned inaccurate values
+    /// during the last evaluation of the jacobian per finite difference,
+    /// treated according to [SolverParameters::set_inaccurate_jacobian_policy]
+    pub fn get_inaccurate_columns(&self) -> &[super::InaccurateColumn] {
+        self.jacobian.get_inaccurate_columns()
+    }
+
+    /// Write the residuals values of the model in the solver placeholder, with the right members overridden
+    fn write_model_residuals<M>(&mut self, model: &M)
+    where
+        M: model::Model<D>,
+    {
+        model.write_residuals(&mut self.residuals_values);
+        if !self.right_overrides.is_empty() {
+            self.right_overrides
+                .apply(&model.get_iteratives(), &mut self.residuals_values);
+        }
+    }
+
+    /// Residuals values of the model, with the right members overridden
+    fn model_residuals<M>(&self, model: &M) -> residuals::ResidualsValues<D>
+    where
+        M: model::Model<D>,
+    {
+        let mut residuals = model.get_residuals();
+        if !self.right_overrides.is_empty() {
+            self.right_overrides
+                .apply(&model.get_iteratives(), &mut residuals);
+        }
+        residuals
+    }
+
+    /// The residuals values are written in the solver placeholder, reused at each call
+    fn evaluate_errors<M>(&mut self, model: &M) -> nalgebra::OVector<f64, D>
+    where
+        M: model::Model<D>,
+    {
+        self.write_model_residuals(model);
+        let mut errors = self
+            .residuals_config
+            .evaluate_stopping_residuals(&self.residuals_values);
+        self.clear_reduced_residuals(&mut errors);
+        errors
+    }
+
+    /// Set the residuals removed from the system by a structural reduction to zero,
+    /// see [StructuralSingularityPolicy::Reduce]
+    fn clear_reduced_residuals(&self, residuals: &mut nalgebra::OVector<f64, D>) {
+        for &(residual, _) in self.jacobian.get_reduced_rows() {
+            residuals[residual] = 0.0;
+        }
+    }
+
+    /// Errors of the current state of the model, only evaluated if they are not kept from a previous computation
+    ///
+    /// They are kept for the current iterate once it is accepted, until the model may be evaluated elsewhere.
+    fn current_errors<M>(&mut self, model: &M) -> nalgebra::OVector<f64, D>
+    where
+        M: model::Model<D>,
+    {
+        match self.stopping_errors_current.take() {
+            Some(errors) => errors,
+            None => self.evaluate_errors(model),
+        }
+    }
+
+    /// Maximum of the errors, used for the convergence check and the damping
+    ///
+    /// `amax()` ignores or propagates `NaN` depending on its position in the vector,
+    /// and any comparison with `NaN` is false: a non-finite error could be taken for convergence.
+    /// Hence, non-finite errors are mapped to an infinite maximum error.
+    fn max_error(errors: &nalgebra::OVector<f64, D>) -> f64 {
+        if errors.iter().all(|error| error.is_finite()) {
+            errors.amax()
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Check the errors against the tolerance, or the errors of the groups if the convergence is checked on the groups
+    fn are_residuals_converged(&self, errors: &nalgebra::OVector<f64, D>) -> bool {
+        if self.residuals_config.get_group_convergence() {
+            self.residuals_config
+                .are_groups_converged(errors, self.parameters.get_tolerance())
+        } else {
+            Self::max_error(errors) <= self.parameters.get_tolerance()
+        }
+    }
+
+    /// Check the convergence of the residuals at an iteration of the resolution,
+    /// on the errors smoothed if required and over the consecutive iterations required, see [SolverParameters]
+    ///
+    /// `smoothed_errors` and `below_tolerance`, the number of consecutive iterations below the tolerance,
+    /// are updated with the `errors` of the iteration
+    fn confirm_residuals_convergence(
+        &self,
+        errors: &nalgebra::OVector<f64, D>,
+        smoothed_errors: &mut Option<nalgebra::OVector<f64, D>>,
+        below_tolerance: &mut usize,
+    ) -> bool {
+        if let Some(coefficient) = self.parameters.get_error_smoothing() {
+            match smoothed_errors.as_mut() {
+                Some(smoothed) => smoothed.zip_apply(errors, |smoothed, error| {
+                    *smoothed = if smoothed.is_finite() && error.is_finite() {
+                        coefficient * error + (1.0 - coefficient) * *smoothed
+                    } else {
+                        error
+                    }
+                }),
+                None => *smoothed_errors = Some(errors.clone()),
+            }
+        }
+
+        let checked_errors = smoothed_errors.as_ref().unwrap_or(errors);
+        if self.are_residuals_converged(checked_errors) {
+            *below_tolerance += 1;
+        } else {
+            *below_tolerance = 0;
+        }
+
+        #[cfg(feature = "debug_log")]
+        if self.debug
+            && (smoothed_errors.is_some() || self.parameters.get_consecutive_convergences() > 1)
+        {
+            self.convergence_confirmation_to_log(
+                smoothed_errors.as_ref().map(Self::max_error),
+                *below_tolerance,
+            );
+        }
+
+        *below_tolerance >= self.parameters.get_consecutive_convergences()
+    }
+
+    /// Combine the convergence of the residuals with the custom convergence predicate, if any,
+    /// see [RootFinder::set_convergence_predicate]
+    ///
+    /// Return the decision of the convergence of the residuals and whether the predicate decided it
+    fn apply_convergence_predicate(
+        &self,
+        iteratives: &nalgebra::OVector<f64, D>,
+        errors: &nalgebra::OVector<f64, D>,
+        residuals_converged: bool,
+    ) -> (bool, bool) {
+        let predicate = match &self.convergence_predicate {
+            Some(predicate) => predicate,
+            None => return (residuals_converged, false),
+        };
+        let context = ConvergenceContext {
+            iter: self.iter,
+            stopping_errors: errors,
+            last_step: if self.iter == 0 {
+                None
+            } else {
+                self.last_step.as_ref()
+            },
+            iteratives,
+            model_evaluations: self.model_evaluations,
+            tolerance: self.parameters.get_tolerance(),
+        };
+        let satisfied = predicate(&context);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.convergence_predicate_to_log(satisfied);
+        }
+
+        match self.predicate_mode {
+            PredicateMode::Replace => (satisfied, satisfied),
+            PredicateMode::And => (
+                residuals_converged && satisfied,
+                residuals_converged && satisfied,
+            ),
+            PredicateMode::Or => (residuals_converged || satisfied, satisfied),
+        }
+    }
+
+    /// Whether the step of the last iteration must be kept,
+    /// for the convergence step tolerances or the custom convergence predicate
+    fn is_last_step_required(&self) -> bool {
+        self.iters_params.has_step_tolerances() || self.convergence_predicate.is_some()
+    }
+
+    /// Keep the step of the iteration, made from `start_guess`, if required (see [RootFinder::is_last_step_required])
+    fn record_last_step<M>(&mut self, model: &M, start_guess: Option<nalgebra::OVector<f64, D>>)
+    where
+        M: model::Model<D>,
+    {
+        if let Some(start_guess) = start_guess {
+            self.last_step = Some(model.get_iteratives() - start_guess);
+        }
+    }
+
+    /// Check the steps of the iteratives having a convergence step tolerance
+    ///
+    /// Before the first iteration, no step has been made and the criterion is satisfied
+    fn are_steps_converged(&self) -> bool {
+        match &self.last_step {
+            Some(step) => self.iters_params.are_steps_converged(step),
+            None => true,
+        }
+    }
+
+    /// Warnings of [check_compatibility] for the model and the iteratives
+    fn compatibility_warnings<M>(&self, model: &M) -> Vec<CompatibilityWarning>
+    where
+        M: model::Model<D>,
+    {
+        check_compatibility(
+            &self.parameters,
+            model.jacobian_provided(),
+            IterativesKind::from(self.iters_params),
+        )
+        .err()
+        .unwrap_or_default()
+    }
+
+    /// Relative amplitude of the jitter of the finite difference perturbations with the current iteration,
+    /// see [SolverParameters::set_perturbation_jitter]
+    fn perturbation_jitter(&self) -> Option<(f64, usize)> {
+        self.parameters
+            .get_perturbation_jitter()
+            .map(|amplitude| (amplitude, self.iter))
+    }
+
+    /// Check that the jacobian can be computed with the model and the iteratives
+    ///
+    /// If the model provides its jacobian, it is used and the finite difference parameters are ignored.
+    /// Otherwise, the iteratives must be working with finite differences.
+    /// The overrides of the right members require the finite differences,
+    /// as the nonlinear Gauss-Seidel method
+    fn check_jacobian_configuration<M>(&self, model: &M)
+    where
+        M: model::Model<D>,
+    {
+        if let Some(warning) = self
+            .compatibility_warnings(model)
+            .iter()
+            .find(|warning| warning.prevents_resolution())
+        {
+            panic!("{}", warning);
+        }
+        if let Some(sparsity_pattern) = self.parameters.get_sparsity_pattern() {
+            if sparsity_pattern.len() != self.problem_size() {
+                panic!(
+                    "The sparsity pattern is of size {}, the problem size is {}",
+                    sparsity_pattern.len(),
+                    self.problem_size()
+                );
+            }
+        }
+        if !self.jacobian.get_fixed_rows().is_empty() {
+            if let resolution_method @ (ResolutionMethod::NonlinearGaussSeidel { .. }
+            | ResolutionMethod::QuasiNewton(
+                QuasiNewtonMethod::LimitedMemoryBroyden { .. },
+            )) = self.parameters.get_resolution_method()
+            {
+                panic!(
+                    "The fixed iteratives {:?} require a resolution method working with the jacobian, got {}",
+                    self.iters_params.fixed_iteratives(),
+                    resolution_method
+                );
+            }
+        }
+        if model.jacobian_provided() && !self.right_overrides.is_empty() {
+            panic!(
+                "The right members of the residuals {:?} are overridden, which is not supported with the jacobian provided by the model: use a model computing the jacobian with finite differences",
+                self.right_overrides.get_overridden_residuals()
+            );
+        }
+    }
+
+    fn compute_jac<M>(&mut self, model: &mut M) -> Result<(), errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.clear_quasi_newton_steps();
+        // the model can be evaluated at other points to compute the jacobian
+        self.stopping_errors_current = None;
+        // the Aitken extrapolation assumes the iterates to be computed with the same jacobian
+        self.aitken_iterates.clear();
+
+        let policy = self.parameters.get_inaccurate_jacobian_policy();
+        #[cfg(feature = "debug_log")]
+        let reduced_rows = self.jacobian.get_reduced_rows().len();
+        let source = if model.jacobian_provided() {
+            JacobianSource::AnalyticalModel
+        } else {
+            JacobianSource::FiniteDifference
+        };
+        self.jacobian_source = Some(source);
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.method_state_to_log(MethodState::FullComputation { source });
+        }
+        let successful_jac_computation = if model.jacobian_provided() {
+            let result = evaluate_jacobian_from_analytical_function(
+                &mut self.jacobian,
+                model,
+                self.residuals_config,
+            );
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                if let Err(errors::SolverInternalError::InvalidJacobianError(error)) = &result {
+                    if error.is_inaccurate_values_error() {
+                        self.inaccurate_analytical_jacobian_to_log(policy);
+                    }
+                }
+            }
+            result
+        } else {
+            let saved_evaluations = match self.constant_jacobian_values {
+                Some(_) => self.constant_jacobian_columns.len(),
+                None => 0,
+            };
+            self.saved_model_evaluations += saved_evaluations;
+            let perturbation_jitter = self.perturbation_jitter();
+            let result = evaluate_jacobian_with_constant_columns(
+                &mut self.jacobian,
+                model,
+                self.iters_params,
+                self.residuals_config,
+                &self.right_overrides,
+                policy,
+                perturbation_jitter,
+                &self.constant_jacobian_columns,
+                &mut self.constant_jacobian_values,
+                &mut self.model_evaluations,
+            );
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                if let Some((amplitude, iteration)) = perturbation_jitter {
+                    self.solver_log.as_ref().unwrap().add_content(&format!(
+                        "Perturbations jittered with the relative amplitude {} (iteration {})\n\n",
+                        amplitude, iteration
+                    ));
+                }
+                self.perturbations_to_log();
+                self.inaccurate_columns_to_log(policy);
+            }
+            result
+        };
+
+        let result = apply_inaccurate_jacobian_policy(successful_jac_computation, policy);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug && self.jacobian.get_reduced_rows().len() > reduced_rows {
+            self.structural_reduction_to_log(reduced_rows);
+        }
+
+        #[cfg(feature = "std")]
+        if result.is_ok() {
+            self.dump_computed_jacobian();
+        }
+
+        result
+    }
+
+    fn compute_newton_raphson_step<M>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let successful_jac_computation = self.compute_jac(model);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.jac_to_log();
+        }
+        match successful_jac_computation {
+            Ok(()) => self.compute_next(model),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Perform the jacobian evaluation
+    ///
+    /// Based on the resolution method:
+    /// - the jacobian can be computed and inverted
+    /// - the jacobian can be approximated and inverted
+    /// - the inverse of the jacobian can be approximated
+    fn evaluate_jacobian_quasi_newton_step<M>(
+        &mut self,
+        model: &mut M,
+        resolution_method: QuasiNewtonMethod,
+    ) -> Result<(), crate::errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if !self.jacobian.compute_jacobian() {
+            // the matrix before the update, to measure its quality
+            #[cfg(feature = "std")]
+            let previous_matrix = match resolution_method {
+                _ if self.history.is_none() || self.iteratives_step_size.is_none() => None,
+                QuasiNewtonMethod::JacobianUpdate(_) => self.jacobian.get_jacobian().clone(),
+                QuasiNewtonMethod::InverseJacobianUpdate(_) => self.jacobian.get_inverse().clone(),
+                _ => None,
+            };
+
+            let update_status = match resolution_method {
+                // the limited memory method does not use the jacobian matrix,
+                // see `compute_limited_memory_broyden_step()`
+                QuasiNewtonMethod::StationaryNewton
+                | QuasiNewtonMethod::LimitedMemoryBroyden { .. } => None,
+                // first iteration with the jacobian of the previous resolution, see `set_jacobian_reuse()`
+                _ if self.iteratives_step_size.is_none() => None,
+                QuasiNewtonMethod::JacobianUpdate(method) => {
+                    match approximate_jacobian(
+                        &mut self.jacobian,
+                        method,
+                        self.iteratives_step_size.as_ref().unwrap(),
+                        self.residuals_step_size.as_ref().unwrap(),
+                        self.residuals_values_current.as_ref().unwrap(),
+                    ) {
+                        Ok(status) => Some(status),
                         Err(_) => {
                             return Err(errors::SolverInternalError::InvalidJacobianInverseError)
                         }
                     }
                 }
-                QuasiNewtonMethod::InverseJacobianUpdate(method) => {
-                    approximate_inv_jacobian(
-                        &mut self.jacobian,
-                        method,
-                        self.iteratives_step_size.as_ref().unwrap(),
-                        self.residuals_step_size.as_ref().unwrap(),
-                        self.residuals_values_current.as_ref().unwrap(),
-                    );
+                QuasiNewtonMethod::InverseJacobianUpdate(method) => Some(approximate_inv_jacobian(
+                    &mut self.jacobian,
+                    method,
+                    self.iteratives_step_size.as_ref().unwrap(),
+                    self.residuals_step_size.as_ref().unwrap(),
+                    self.residuals_values_current.as_ref().unwrap(),
+                )),
+            };
+
+            #[cfg(feature = "std")]
+            if let (Some(previous), Some(_)) = (previous_matrix, update_status) {
+                let inverse = matches!(
+                    resolution_method,
+                    QuasiNewtonMethod::InverseJacobianUpdate(_)
+                );
+                let updated = if inverse {
+                    self.jacobian.get_inverse()
+                } else {
+                    self.jacobian.get_jacobian()
+                };
+                self.update_metrics = updated.as_ref().map(|updated| {
+                    quasi_newton_update_metrics(
+                        &previous,
+                        updated,
+                        self.iteratives_step_size.as_ref().unwrap(),
+                        self.residuals_step_size.as_ref().unwrap(),
+                        inverse,
+                    )
+                });
+            }
+
+            let method_state = match (resolution_method, update_status) {
+                (
+                    QuasiNewtonMethod::JacobianUpdate(method)
+                    | QuasiNewtonMethod::InverseJacobianUpdate(method),
+                    Some(status),
+                ) => MethodState::from_update_status(method, status),
+                _ => MethodState::Reused,
+            };
+            // a non-finite update is replaced by the computation of the jacobian below
+            self.jacobian_source = Some(method_state.jacobian_source());
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.method_state_to_log(method_state);
+            }
+
+            // The jacobian recomputed after a non-finite update did not prevent the next one:
+            // the resolution is stopped instead of recomputing the jacobian at each iteration
+            if update_status == Some(QuasiNewtonUpdateStatus::NonFinite) {
+                self.non_finite_updates += 1;
+                if self.non_finite_updates > 1 {
+                    return Err(errors::SolverInternalError::NonFiniteQuasiNewtonUpdatesError);
+                }
+            } else if update_status.is_some() {
+                self.non_finite_updates = 0;
+            }
+        }
+
+        // A non-finite update forces the computation of the jacobian,
+        // which is then performed right away instead of taking a step with the invalid matrix
+        if self.jacobian.compute_jacobian() {
+            let successful_jac_computation = self.compute_jac(model);
+
+            match successful_jac_computation {
+                Ok(()) => (),
+                Err(error) => {
+                    #[cfg(feature = "debug_log")]
+                    if self.debug {
+                        self.jac_to_log();
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.jac_to_log();
+        }
+
+        Ok(())
+    }
+
+    fn compute_quasi_newton_step<M>(
+        &mut self,
+        model: &mut M,
+        resolution_method: QuasiNewtonMethod,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if let QuasiNewtonMethod::LimitedMemoryBroyden { memory } = resolution_method {
+            return self.compute_limited_memory_broyden_step(model, memory);
+        }
+
+        match self.evaluate_jacobian_quasi_newton_step(model, resolution_method) {
+            Ok(()) => self.compute_next(model),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Compute the next guess with the limited memory Broyden method, see [QuasiNewtonMethod::LimitedMemoryBroyden]
+    ///
+    /// The approximation is updated with the last step, or seeded with the diagonal of the jacobian
+    /// if there is none, the step being the product of the approximated inverse with the residuals.
+    fn compute_limited_memory_broyden_step<M>(
+        &mut self,
+        model: &mut M,
+        memory: usize,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if let Some(limited_memory) = self.limited_memory.as_mut() {
+            let update_status = match (&self.iteratives_step_size, &self.residuals_step_size) {
+                (Some(iteratives_step_size), Some(residuals_step_size)) => {
+                    Some(limited_memory.update(iteratives_step_size, residuals_step_size))
+                }
+                // first iteration with the approximation of the previous resolution, see `set_jacobian_reuse()`
+                _ => None,
+            };
+
+            let method_state = match update_status {
+                Some(status) => MethodState::from_update_status(
+                    UpdateQuasiNewtonMethod::BroydenSecondMethod,
+                    status,
+                ),
+                None => MethodState::Reused,
+            };
+            self.jacobian_source = Some(method_state.jacobian_source());
+            // a non-finite update is replaced by the computation of the seed below
+            if update_status == Some(QuasiNewtonUpdateStatus::NonFinite) {
+                self.limited_memory = None;
+            }
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.method_state_to_log(method_state);
+            }
+        }
+
+        // the residuals and the iteratives of the step are the ones of the reference point of the seed computation
+        self.write_model_residuals(model);
+        let residuals = self
+            .residuals_config
+            .evaluate_update_residuals(&self.residuals_values);
+        let iter_values = model.get_iteratives();
+
+        if self.limited_memory.is_none() {
+            self.clear_quasi_newton_steps();
+            // the stopping errors of the reference point are kept, the iteratives being set back to it
+            let errors = self.current_errors(model);
+            let policy = self.parameters.get_inaccurate_jacobian_policy();
+            let diagonal = if model.jacobian_provided() {
+                self.jacobian_source = Some(JacobianSource::AnalyticalModel);
+                evaluate_jacobian_diagonal_from_analytical_function(model, self.residuals_config)
+                    .map_err(errors::SolverInternalError::InvalidJacobianError)
+            } else {
+                self.jacobian_source = Some(JacobianSource::FiniteDifference);
+                evaluate_jacobian_diagonal_from_finite_difference(
+                    model,
+                    self.iters_params,
+                    self.residuals_config,
+                    &self.right_overrides,
+                    policy,
+                    self.perturbation_jitter(),
+                    &mut self.model_evaluations,
+                )
+            };
+            self.stopping_errors_current = Some(errors);
+
+            // the inaccurate values of the finite differences are treated according to the policy,
+            // the analytical diagonal being unavailable in case of error
+            let diagonal = diagonal?;
+            match LimitedMemoryInverse::new(memory, &diagonal) {
+                Ok(limited_memory) => self.limited_memory = Some(limited_memory),
+                Err(errors::NonInvertibleJacobian) => {
+                    return Err(errors::SolverInternalError::InvalidJacobianInverseError)
+                }
+            }
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.limited_memory_seed_to_log();
+            }
+        }
+
+        let raw_step = -self.limited_memory.as_ref().unwrap().apply(&residuals);
+
+        Ok(self.limit_step(&iter_values, &raw_step))
+    }
+
+    /// Compute the next guess by solving the linear system with the method of the [SolverParameters]
+    fn compute_next<M>(
+        &mut self,
+        model: &M,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.write_model_residuals(model);
+        let mut residuals = self
+            .residuals_config
+            .evaluate_update_residuals(&self.residuals_values);
+        self.clear_reduced_residuals(&mut residuals);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.rel_safeguard_to_log();
+        }
+
+        let raw_step = compute_raw_newton_step(
+            &self.jacobian,
+            &residuals,
+            self.parameters.get_linear_solver(),
+        )?;
+
+        let iter_values = model.get_iteratives();
+
+        Ok(self.limit_step(&iter_values, &raw_step))
+    }
+
+    /// Apply the step limitations of the iteratives to the step computed by the resolution method
+    fn limit_step(
+        &self,
+        iter_values: &nalgebra::OVector<f64, D>,
+        raw_step: &nalgebra::OVector<f64, D>,
+    ) -> nalgebra::OVector<f64, D> {
+        let step_scale = self.parameters.get_first_iteration_step_scale();
+        if self.iter == 1 && step_scale != 1.0 {
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.solver_log.as_ref().unwrap().add_content(&format!(
+                    "Step limitations of the first iteration multiplied by {}\n\n",
+                    step_scale
+                ));
+            }
+            return self.iters_params.step_limitations_with_scale(
+                iter_values,
+                raw_step,
+                step_scale,
+            );
+        }
+
+        self.iters_params.step_limitations(iter_values, raw_step)
+    }
+
+    /// Update residuals of the model, the reduced residuals being cleared
+    fn update_residuals<M>(&mut self, model: &M) -> nalgebra::OVector<f64, D>
+    where
+        M: model::Model<D>,
+    {
+        self.write_model_residuals(model);
+        let mut residuals = self
+            .residuals_config
+            .evaluate_update_residuals(&self.residuals_values);
+        self.clear_reduced_residuals(&mut residuals);
+        residuals
+    }
+
+    /// Maximum of the update residuals of the model, compared to accept a step, see [StepAcceptanceNorm]
+    fn max_update_error<M>(&mut self, model: &M) -> f64
+    where
+        M: model::Model<D>,
+    {
+        let residuals = self.update_residuals(model);
+        Self::max_error(&residuals)
+    }
+
+    /// Check if the scalar safeguard applies to the resolution, see [SolverParameters]
+    fn is_scalar_safeguard_active(&self) -> bool {
+        self.parameters.get_scalar_safeguard() && self.problem_size() == 1
+    }
+
+    /// Refine the proposed guess of a problem of size 1 if the root is bracketed, see [SolverParameters]
+    ///
+    /// The bracket is refined with the Illinois variant of the regula falsi,
+    /// the model being left at the last point evaluated.
+    /// Return `true` if the root has been bracketed between the current and the proposed guesses.
+    fn scalar_safeguard<M>(
+        &mut self,
+        model: &mut M,
+        current_update_residual: f64,
+        current_guess: &nalgebra::OVector<f64, D>,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+        errors_next: &mut nalgebra::OVector<f64, D>,
+    ) -> Result<bool, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if !self.valid_last_model_evaluation {
+            return Ok(false);
+        }
+        let proposed_update_residual = self.update_residuals(model)[0];
+        // a NaN value does not bracket the root
+        let product = current_update_residual * proposed_update_residual;
+        if product.is_nan() || product >= 0.0 {
+            return Ok(false);
+        }
+
+        let (mut x_a, mut f_a) = (current_guess[0], current_update_residual);
+        let (mut x_b, mut f_b) = (proposed_guess[0], proposed_update_residual);
+        let mut guess = proposed_guess.clone();
+
+        for _ in 0..self.parameters.get_scalar_safeguard_refinements() {
+            guess[0] = (x_a * f_b - x_b * f_a) / (f_b - f_a);
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.scalar_safeguard_to_log(x_a, x_b);
+            }
+
+            model.set_iteratives(&guess);
+            self.model_evaluations += 1;
+            match model.evaluate() {
+                Ok(()) => {
+                    self.valid_last_model_evaluation = true;
+                }
+                Err(error) if error.is_inaccurate_values_error() => {
+                    self.valid_last_model_evaluation = false;
+                }
+                Err(e) => {
+                    self.valid_last_model_evaluation = false;
+                    return Err(errors::SolverError::ModelEvaluationError(e));
+                }
+            }
+            *errors_next = self.evaluate_errors(model);
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.iteration_to_log(model, errors_next);
+            }
+
+            let f_c = self.update_residuals(model)[0];
+            if !self.valid_last_model_evaluation || f_c == 0.0 || f_c.is_nan() {
+                break;
+            }
+            if f_c * f_b < 0.0 {
+                x_a = x_b;
+                f_a = f_b;
+            } else {
+                // Illinois variant: the retained end point is given half its weight
+                f_a /= 2.0;
+            }
+            x_b = guess[0];
+            f_b = f_c;
+        }
+
+        Ok(true)
+    }
+
+    /// Check if the step to the current state of the model deteriorates the solution, see [StepAcceptanceNorm]
+    ///
+    /// `max_update_error` is only required if the update residuals are compared
+    fn is_step_rejected<M>(
+        &mut self,
+        model: &M,
+        max_error: f64,
+        max_update_error: Option<f64>,
+        errors_next: &nalgebra::OVector<f64, D>,
+    ) -> bool
+    where
+        M: model::Model<D>,
+    {
+        let stopping_increased = Self::max_error(errors_next) > max_error;
+        let update_increased = match max_update_error {
+            Some(max_update_error) => self.max_update_error(model) > max_update_error,
+            None => false,
+        };
+
+        match self.parameters.get_step_acceptance_norm() {
+            StepAcceptanceNorm::Stopping => stopping_increased,
+            StepAcceptanceNorm::Update => update_increased,
+            StepAcceptanceNorm::Both => stopping_increased || update_increased,
+        }
+    }
+
+    fn damping<M>(
+        &mut self,
+        model: &mut M,
+        max_error: f64,
+        max_update_error: Option<f64>,
+        current_guess: &nalgebra::OVector<f64, D>,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+        errors_next: &mut nalgebra::OVector<f64, D>,
+    ) where
+        M: model::Model<D>,
+    {
+        if self.is_step_rejected(model, max_error, max_update_error, errors_next) {
+            // see documentation of the `SolverParameters` struct
+            if self.parameters.get_resolution_method() != ResolutionMethod::NewtonRaphson
+                && self.is_jacobian_approximated()
+            {
+                self.force_jacobian_computation();
+                #[cfg(feature = "debug_log")]
+                if self.debug {
+                    self.recompute_jacobian_to_log();
+                }
+            } else {
+                let damping_factor = 1.0 / 2.0;
+                let damped_guess =
+                    current_guess * (1.0 - damping_factor) + proposed_guess * damping_factor;
+                model.set_iteratives(&damped_guess);
+                model.evaluate().unwrap();
+                self.model_evaluations += 1;
+                *errors_next = self.evaluate_errors(model);
+
+                #[cfg(feature = "debug_log")]
+                if self.debug {
+                    self.damping_to_log(model, errors_next);
+                }
+            }
+        }
+    }
+
+    /// Check if the proposed guess closes a period-2 cycle, see [SolverParameters]
+    fn is_cycling(&self, proposed_guess: &nalgebra::OVector<f64, D>, max_error_next: f64) -> bool {
+        match &self.previous_iterate {
+            Some((previous_guess, previous_max_error)) => {
+                max_error_next >= *previous_max_error
+                    && proposed_guess.iter().zip(previous_guess.iter()).all(
+                        |(proposed, previous)| {
+                            (proposed - previous).abs() <= CYCLE_TOLERANCE * previous.abs().max(1.0)
+                        },
+                    )
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the proposed guess according to the action of the cycle detection
+    fn break_cycle<M>(
+        &mut self,
+        model: &mut M,
+        action: CycleBreakingAction,
+        current_guess: &nalgebra::OVector<f64, D>,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+        errors_next: &mut nalgebra::OVector<f64, D>,
+    ) -> Result<(), errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.cycles_detected += 1;
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.cycle_to_log(action);
+        }
+
+        let new_guess = match action {
+            CycleBreakingAction::DampedAverage => {
+                let (previous_guess, _) = self.previous_iterate.as_ref().unwrap();
+                (previous_guess + current_guess) * 0.5
+            }
+            CycleBreakingAction::DampedHalfStep => {
+                current_guess + (proposed_guess - current_guess) * 0.5
+            }
+            CycleBreakingAction::RecomputeJacobian => {
+                self.force_jacobian_computation();
+                return Ok(());
+            }
+        };
+
+        model.set_iteratives(&new_guess);
+        self.model_evaluations += 1;
+        match model.evaluate() {
+            Ok(()) => {
+                self.valid_last_model_evaluation = true;
+            }
+            Err(error) if error.is_inaccurate_values_error() => {
+                self.valid_last_model_evaluation = false;
+            }
+            Err(e) => {
+                self.valid_last_model_evaluation = false;
+                return Err(errors::SolverError::ModelEvaluationError(e));
+            }
+        }
+        *errors_next = self.evaluate_errors(model);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.iteration_to_log(model, errors_next);
+        }
+
+        Ok(())
+    }
+
+    fn update_model<M>(
+        &mut self,
+        model: &mut M,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+    ) -> Result<nalgebra::OVector<f64, D>, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let current_iterate = self.current_iterate(model);
+        self.update_model_from(model, current_iterate, proposed_guess)
+    }
+
+    /// Values of the model at the current guess compared by `update_model()` to the proposed guess
+    fn current_iterate<M>(&mut self, model: &M) -> CurrentIterate<D>
+    where
+        M: model::Model<D>,
+    {
+        let errors = self.current_errors(model);
+        // the update residuals are only compared by the damping if required
+        let max_update_error = match self.parameters.get_step_acceptance_norm() {
+            StepAcceptanceNorm::Update | StepAcceptanceNorm::Both
+                if self.parameters.get_damping().is_enabled() =>
+            {
+                Some(self.max_update_error(model))
+            }
+            _ => None,
+        };
+        let update_residual = if self.is_scalar_safeguard_active() {
+            Some(self.update_residuals(model)[0])
+        } else {
+            None
+        };
+        CurrentIterate {
+            guess: model.get_iteratives(),
+            errors,
+            max_update_error,
+            update_residual,
+        }
+    }
+
+    /// Evaluate the model at the proposed guess, from the values of the current guess
+    ///
+    /// The model does not need to be evaluated at the current guess,
+    /// its iteratives and its memory must however be the ones of the current guess.
+    fn update_model_from<M>(
+        &mut self,
+        model: &mut M,
+        current_iterate: CurrentIterate<D>,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+    ) -> Result<nalgebra::OVector<f64, D>, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let CurrentIterate {
+            guess: current_guess,
+            errors,
+            max_update_error,
+            update_residual: current_update_residual,
+        } = current_iterate;
+        let max_error = Self::max_error(&errors);
+
+        let reduced_guess = self.evaluate_proposed_guess(model, &current_guess, proposed_guess)?;
+        let proposed_guess = reduced_guess.as_ref().unwrap_or(proposed_guess);
+        let mut errors_next = self.evaluate_errors(model);
+        self.check_residuals_magnitude()?;
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.iteration_to_log(model, &errors_next);
+        }
+
+        // a refined step is accepted without the cycle detection and the damping
+        let bracketed = match current_update_residual {
+            Some(current_update_residual) => self.scalar_safeguard(
+                model,
+                current_update_residual,
+                &current_guess,
+                proposed_guess,
+                &mut errors_next,
+            )?,
+            None => false,
+        };
+
+        let cycle_detection = self.parameters.get_cycle_detection();
+        match cycle_detection {
+            _ if bracketed => (),
+            Some(action) if self.is_cycling(proposed_guess, Self::max_error(&errors_next)) => {
+                self.break_cycle(
+                    model,
+                    action,
+                    &current_guess,
+                    proposed_guess,
+                    &mut errors_next,
+                )?;
+            }
+            _ => {
+                if self.parameters.get_damping().is_enabled() {
+                    self.damping(
+                        model,
+                        max_error,
+                        max_update_error,
+                        &current_guess,
+                        proposed_guess,
+                        &mut errors_next,
+                    );
+                }
+            }
+        }
+
+        // the scalar safeguard, the damping and the breaking of a cycle evaluate the model at another point
+        self.check_residuals_magnitude()?;
+
+        match self.parameters.get_resolution_method() {
+            ResolutionMethod::NewtonRaphson => (),
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton) => (),
+            ResolutionMethod::NonlinearGaussSeidel { .. } => (),
+            _ => {
+                self.iteratives_step_size = Some(model.get_iteratives() - &current_guess);
+                self.residuals_step_size = Some(errors_next.clone() - errors);
+                self.residuals_values_current = Some(errors_next.clone())
+            }
+        };
+
+        if cycle_detection.is_some() {
+            self.previous_iterate = Some((current_guess, max_error));
+        }
+
+        self.stopping_errors_current = Some(errors_next.clone());
+        Ok(errors_next)
+    }
+
+    /// Bring the initial guess within the bounds of the iteratives, according to the [BoundPolicy]
+    ///
+    /// The iteratives projected onto their bounds are returned with their original value,
+    /// the fixed iteratives and the `NaN` values being left unchanged.
+    /// The projected values overwrite the initial guess, see [RootFinder::get_initial_guess]
+    fn reconcile_initial_guess<M>(&mut self) -> Result<Vec<(usize, f64)>, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let iters_params = self.iters_params;
+        let mut projected = Vec::new();
+        for (i, iterative_var) in iters_params.iter().enumerate() {
+            let value = self.initial_guess[i];
+            let (min_value, max_value) = iterative_var.bounds();
+            if iterative_var.fixed_value().is_some() || !(value < min_value || value > max_value) {
+                continue;
+            }
+            match self.parameters.get_bound_policy() {
+                BoundPolicy::Clamp => {
+                    self.initial_guess[i] = value.max(min_value).min(max_value);
+                    projected.push((i, value));
+                }
+                BoundPolicy::Ignore => (),
+                BoundPolicy::Fail => {
+                    return Err(errors::SolverError::InitialGuessOutOfBounds {
+                        iterative: i,
+                        value,
+                        min_value,
+                        max_value,
+                    })
+                }
+            }
+        }
+        Ok(projected)
+    }
+
+    /// Check the members of the residuals values of the last evaluation against their maximum magnitude,
+    /// see [residuals::ResidualsConfig::set_max_magnitudes]
+    fn check_residuals_magnitude<M>(&self) -> Result<(), errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        match self
+            .residuals_config
+            .find_exceeded_magnitude(&self.residuals_values)
+        {
+            Some((id, value, limit)) => Err(errors::SolverError::ResidualMagnitudeExceeded {
+                id,
+                value,
+                limit,
+                iteration: self.iter,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Evaluate the model at the proposed guess
+    ///
+    /// If the model returns unusable values naming iteratives in the context of the error,
+    /// the step is reduced on these iteratives, see [super::MAX_CONTEXT_STEP_REDUCTIONS].
+    /// The reduced guess is returned if any.
+    ///
+    /// A context naming an iterative outside of the problem is an error of the model,
+    /// returned without reducing the step.
+    fn evaluate_proposed_guess<M>(
+        &mut self,
+        model: &mut M,
+        current_guess: &nalgebra::OVector<f64, D>,
+        proposed_guess: &nalgebra::OVector<f64, D>,
+    ) -> Result<Option<nalgebra::OVector<f64, D>>, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let mut reduced_guess: Option<nalgebra::OVector<f64, D>> = None;
+        let mut reductions = 0;
+
+        loop {
+            model.set_iteratives(reduced_guess.as_ref().unwrap_or(proposed_guess));
+            self.model_evaluations += 1;
+            let error = match model.evaluate() {
+                Ok(()) => {
+                    self.valid_last_model_evaluation = true;
+                    return Ok(reduced_guess);
+                }
+                Err(error) => error,
+            };
+
+            self.valid_last_model_evaluation = false;
+            if error.is_inaccurate_values_error() {
+                return Ok(reduced_guess);
+            }
+            let iterative_indices = error
+                .get_context()
+                .map(|context| context.iterative_indices)
+                .unwrap_or_default();
+            if iterative_indices.is_empty()
+                || iterative_indices.iter().any(|&i| i >= proposed_guess.len())
+                || reductions == super::MAX_CONTEXT_STEP_REDUCTIONS
+            {
+                return Err(errors::SolverError::ModelEvaluationError(error));
+            }
+
+            reductions += 1;
+            let guess = reduced_guess.get_or_insert_with(|| proposed_guess.clone());
+            for i in iterative_indices {
+                guess[i] = (current_guess[i] + guess[i]) / 2.0;
+            }
+
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.context_step_reduction_to_log(&error);
+            }
+        }
+    }
+
+    /// Perform an iteration of the resolution method, returning the errors at the new guess
+    fn iterate<M>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.iter += 1;
+
+        // the first iterations are performed with the Newton-Raphson method during the warmup
+        let warmup_iterations = self.parameters.get_newton_warmup_iterations();
+        // the iterate from which the step is made, only kept for the Aitken extrapolation
+        let current_guess = self
+            .is_aitken_acceleration_active()
+            .then(|| model.get_iteratives());
+        // taken before the jacobian, the model being left at a perturbed point by the finite differences
+        let start_guess = self.is_last_step_required().then(|| model.get_iteratives());
+        let proposed_guess = match self.parameters.get_resolution_method() {
+            ResolutionMethod::QuasiNewton(quasi_newton_method) if self.iter > warmup_iterations => {
+                #[cfg(feature = "debug_log")]
+                if self.debug && warmup_iterations > 0 && self.iter == warmup_iterations + 1 {
+                    self.end_of_warmup_to_log();
+                }
+                self.compute_quasi_newton_step(model, quasi_newton_method)
+            }
+            ResolutionMethod::NonlinearGaussSeidel {
+                inner_scalar_iterations,
+            } => {
+                // the model is evaluated at the current guess at the beginning of the sweep
+                let current_iterate = self.current_iterate(model);
+                let proposed_guess =
+                    self.compute_gauss_seidel_sweep(model, inner_scalar_iterations)?;
+                self.jacobian_sources
+                    .push(JacobianSource::ScalarFiniteDifference);
+                let errors_next =
+                    self.update_model_from(model, current_iterate, &proposed_guess)?;
+                self.record_last_step(model, start_guess);
+                return Ok(errors_next);
+            }
+            _ => self.compute_newton_raphson_step(model),
+        };
+
+        if let Some(source) = self.jacobian_source.take() {
+            self.jacobian_sources.push(source);
+        }
+
+        match (proposed_guess, current_guess) {
+            (Ok(value), Some(current_guess)) => {
+                let errors_next = self.update_model(model, &value)?;
+                self.record_last_step(model, start_guess);
+                self.aitken_acceleration(model, current_guess, errors_next)
+            }
+            (Ok(value), None) => {
+                let errors_next = self.update_model(model, &value)?;
+                self.record_last_step(model, start_guess);
+                Ok(errors_next)
+            }
+            // the condition of the jacobian is unknown if the inversion did not fail on the jacobian matrix,
+            // such as with the limited memory Broyden method
+            (Err(error), _) => Err(errors::SolverError::from_internal(
+                error,
+                self.iter,
+                self.jacobian.get_condition_estimate(),
+            )),
+        }
+    }
+
+    /// The Aitken acceleration applies to the iterations of the stationary Newton method after the warmup
+    fn is_aitken_acceleration_active(&self) -> bool {
+        self.parameters.get_aitken_acceleration().is_some()
+            && self.parameters.get_resolution_method()
+                == ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+            && self.iter > self.parameters.get_newton_warmup_iterations()
+    }
+
+    /// Replace the accepted iterate by the Aitken extrapolation of the last three iterates if it decreases the error,
+    /// see [SolverParameters]
+    ///
+    /// The model is at the accepted iterate, with the errors `errors_next`,
+    /// the step having been made from `current_guess`.
+    fn aitken_acceleration<M>(
+        &mut self,
+        model: &mut M,
+        current_guess: nalgebra::OVector<f64, D>,
+        errors_next: nalgebra::OVector<f64, D>,
+    ) -> Result<nalgebra::OVector<f64, D>, errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let aitken_every = self.parameters.get_aitken_acceleration().unwrap();
+        // the iterates are cleared whenever the jacobian is computed, the step starting from the current guess
+        if self.aitken_iterates.is_empty() {
+            self.aitken_iterates.push(current_guess);
+        }
+        self.aitken_iterates.push(model.get_iteratives());
+        if self.aitken_iterates.len() > 3 {
+            self.aitken_iterates.remove(0);
+        }
+        if self.aitken_iterates.len() < 3 || self.iter % aitken_every != 0 {
+            return Ok(errors_next);
+        }
+
+        let plain_iterate = &self.aitken_iterates[2];
+        let first_difference = &self.aitken_iterates[1] - &self.aitken_iterates[0];
+        let second_difference = plain_iterate - &self.aitken_iterates[1];
+        let difference_variation = &second_difference - &first_difference;
+        let denominator = difference_variation.norm_squared();
+        if denominator <= AITKEN_DENOMINATOR_THRESHOLD * second_difference.norm_squared() {
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.solver_log.as_ref().unwrap().add_content(
+                    "Aitken extrapolation skipped, the differences of the last iterates are almost identical\n\n",
+                );
+            }
+            return Ok(errors_next);
+        }
+
+        let raw_step =
+            -(second_difference.dot(&difference_variation) / denominator) * &second_difference;
+        let extrapolated = self.iters_params.step_limitations(plain_iterate, &raw_step);
+        let plain_iterate = plain_iterate.clone();
+        let max_error_plain = Self::max_error(&errors_next);
+        let memory = model.get_memory();
+
+        model.set_iteratives(&extrapolated);
+        self.model_evaluations += 1;
+        let errors_extrapolated = match model.evaluate() {
+            Ok(()) => Some(self.evaluate_errors(model)),
+            Err(_) => None,
+        };
+        let max_error_extrapolated = errors_extrapolated
+            .as_ref()
+            .map_or(f64::INFINITY, Self::max_error);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.aitken_extrapolation_to_log(max_error_plain, max_error_extrapolated);
+        }
+
+        match errors_extrapolated {
+            Some(errors_extrapolated) if max_error_extrapolated < max_error_plain => {
+                #[cfg(feature = "debug_log")]
+                if self.debug {
+                    self.iteration_to_log(model, &errors_extrapolated);
+                }
+                self.aitken_extrapolations += 1;
+                self.valid_last_model_evaluation = true;
+                if self.is_last_step_required() {
+                    self.last_step = Some(&extrapolated - &self.aitken_iterates[1]);
+                }
+                // the next iterates are computed from the extrapolated point
+                self.aitken_iterates.clear();
+                self.stopping_errors_current = Some(errors_extrapolated.clone());
+                Ok(errors_extrapolated)
+            }
+            _ => {
+                model.set_memory(&memory);
+                model.set_iteratives(&plain_iterate);
+                self.model_evaluations += 1;
+                match model.evaluate() {
+                    Ok(()) => (),
+                    Err(error) if error.is_inaccurate_values_error() => (),
+                    Err(error) => return Err(errors::SolverError::ModelEvaluationError(error)),
+                }
+                self.write_model_residuals(model);
+                self.stopping_errors_current = Some(errors_next.clone());
+                Ok(errors_next)
+            }
+        }
+    }
+
+    /// Sweep of the nonlinear Gauss-Seidel method over the iteratives, see [ResolutionMethod::NonlinearGaussSeidel]
+    ///
+    /// The iteratives and the memory of the model are set back to the current guess at the end of the sweep,
+    /// without evaluating it again: `update_model_from()` compares the proposed guess
+    /// to the values of the current guess taken before the sweep.
+    fn compute_gauss_seidel_sweep<M>(
+        &mut self,
+        model: &mut M,
+        inner_scalar_iterations: usize,
+    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        // the model is evaluated at other points during the sweep
+        self.stopping_errors_current = None;
+        let tolerance = self.parameters.get_tolerance();
+        let current_guess = model.get_iteratives();
+        let current_memory = model.get_memory();
+        let mut proposed_guess = current_guess.clone();
+        // the model is evaluated at the current guess at the beginning of the sweep
+        let mut evaluated = true;
+
+        for i in 0..proposed_guess.len() {
+            for _ in 0..inner_scalar_iterations {
+                if !evaluated {
+                    model.set_iteratives(&proposed_guess);
+                    self.evaluate_sweep_point(model)?;
+                    evaluated = true;
+                }
+                self.write_model_residuals(model);
+                if self
+                    .residuals_config
+                    .evaluate_stopping_residuals(&self.residuals_values)[i]
+                    .abs()
+                    <= tolerance
+                {
+                    break;
+                }
+                // the update methods of the reference point are kept for the perturbed one
+                let update_methods = self
+                    .residuals_config
+                    .get_safeguarded_update_methods(&self.residuals_values);
+                let residual = self
+                    .residuals_config
+                    .evaluate_update_residuals_with(&self.residuals_values, &update_methods)[i];
+
+                let perturbation = self.iters_params.compute_perturbations(&proposed_guess)[i];
+                let memory_ref = model.get_memory();
+                let mut perturbed_guess = proposed_guess.clone();
+                perturbed_guess[i] += perturbation;
+                model.set_iteratives(&perturbed_guess);
+                self.evaluate_sweep_point(model)?;
+                evaluated = false;
+                self.write_model_residuals(model);
+                let perturbed_residual = self
+                    .residuals_config
+                    .evaluate_update_residuals_with(&self.residuals_values, &update_methods)[i];
+                model.set_memory(&memory_ref);
+
+                let derivative = (perturbed_residual - residual) / perturbation;
+                if !(derivative.is_finite() && derivative != 0.0) {
+                    // the residual does not depend on its iterative at this point
+                    break;
+                }
+                let mut raw_step: nalgebra::OVector<f64, D> =
+                    super::super::ovector_zeros_like(&proposed_guess);
+                raw_step[i] = -residual / derivative;
+                proposed_guess = self
+                    .iters_params
+                    .step_limitations(&proposed_guess, &raw_step);
+            }
+        }
+
+        model.set_memory(&current_memory);
+        model.set_iteratives(&current_guess);
+        Ok(proposed_guess)
+    }
+
+    /// Evaluation of the model during a sweep of the nonlinear Gauss-Seidel method
+    ///
+    /// As for the finite differences, the inaccurate values are accepted
+    fn evaluate_sweep_point<M>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.model_evaluations += 1;
+        match model.evaluate() {
+            Ok(()) => Ok(()),
+            Err(error) if error.is_inaccurate_values_error() => Ok(()),
+            Err(error) => Err(errors::SolverError::ModelEvaluationError(error)),
+        }
+    }
+
+    /// The core function performing the resolution on a given `Model`
+    ///
+    /// If the model provides its jacobian, it takes precedence over the finite differences:
+    /// the finite difference parameters of the iteratives, if any, are not used.
+    ///
+    /// # Panics
+    ///
+    /// If the configuration has a [CompatibilityWarning] preventing the resolution, see [check_compatibility],
+    /// for example if the model does not provide its jacobian and the iteratives are not working with finite differences,
+    /// or if the model provides its jacobian while right members are overridden, see [RootFinder::set_right_overrides]
+    pub fn solve<M>(&mut self, model: &mut M) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.solver_log.as_mut().unwrap().start_solve();
+        }
+
+        self.solve_attempt(model)
+    }
+
+    /// Resolution of `solve()`, the debug log of the resolution having been started
+    fn solve_attempt<M>(&mut self, model: &mut M) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let result = self.run_resolution(model);
+        self.terminate(model, result)
+    }
+
+    /// End of a resolution, restoring the best iterate on failure if required
+    fn terminate<M>(
+        &mut self,
+        model: &mut M,
+        result: Result<(), crate::errors::SolverError<M, D>>,
+    ) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if self.parameters.get_return_best_on_failure()
+            && matches!(
+                result,
+                Err(errors::SolverError::NonConvergenceError(_)
+                    | errors::SolverError::ModelEvaluationError(_)
+                    | errors::SolverError::JacobianEvaluationError(_)
+                    | errors::SolverError::JacobianInversionError { .. }
+                    | errors::SolverError::StructurallySingularJacobian { .. }
+                    | errors::SolverError::JacobianError(_)
+                    | errors::SolverError::ResidualMagnitudeExceeded { .. })
+            )
+        {
+            self.restore_best_iterate(model);
+        }
+
+        self.errors_over_tolerance = self.last_errors.as_ref().map(|errors| {
+            self.residuals_config
+                .evaluate_errors_over_tolerance(errors, self.parameters.get_tolerance())
+        });
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.termination_to_log(&result);
+        }
+
+        result
+    }
+
+    /// Perform the resolution as [RootFinder::solve], returning a [SolverReport] on success
+    ///
+    /// The report keeps a copy of the iteratives, of the residuals and of the stopping errors of the model at the solution,
+    /// which `solve()` does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use newton_rootfinder as nrf;
+    /// # use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #     let mut y = x * x;
+    /// #     y[0] -= 2.0;
+    /// #    y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// let report = rf.solve_with_report(&mut user_model).unwrap();
+    ///
+    /// assert_eq!(report.get_final_iteratives(), &user_model.get_iteratives());
+    /// let (left, right) = report.get_final_residuals().get_values(0);
+    /// assert!((left - right).abs() <= 1e-6);
+    /// ```
+    pub fn solve_with_report<M>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<SolverReport<D>, crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.solve(model)?;
+
+        let final_residuals = self.model_residuals(model);
+        let mut final_errors = self
+            .residuals_config
+            .evaluate_stopping_residuals(&final_residuals);
+        self.clear_reduced_residuals(&mut final_errors);
+        Ok(SolverReport::new(
+            self,
+            model.get_iteratives(),
+            final_residuals,
+            final_errors,
+        ))
+    }
+
+    /// Take a snapshot of the numerical state of the last resolution, to continue it later with [RootFinder::resume]
+    ///
+    /// It is typically taken once the resolution has been stopped by its budget of iterations or of model evaluations
+    /// (see [SolverParameters]), the state of the model being saved by the user at the same time.
+    /// The best iterate must then not have been restored, see [SolverParameters::set_return_best_on_failure].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use newton_rootfinder as nrf;
+    /// # use nrf::model::Model;
+    /// # use nrf::iteratives;
+    /// # use nrf::residuals;
+    /// # use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #     let mut y = x * x;
+    /// #     y[0] -= 2.0;
+    /// #    y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess.clone(),
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// rf.set_max_iter(1);
+    /// assert!(rf.solve(&mut user_model).is_err());
+    /// let snapshot = rf.snapshot();
+    /// let iteratives = user_model.get_iteratives();
+    ///
+    /// // later, with a new solver of the same configuration
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::NewtonRaphson,
+    ///     nrf::solver::Damping::Disabled,
+    /// );
+    /// let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// user_model.set_iteratives(&iteratives);
+    /// rf.resume(&snapshot, &mut user_model).unwrap();
+    /// assert!(rf.get_iter() > 1);
+    /// ```
+    pub fn snapshot(&self) -> SolverSnapshot<D> {
+        let vector_to_vec = super::snapshot::vector_to_vec::<D>;
+        let matrix_to_vec = super::snapshot::matrix_to_vec::<D>;
+        SolverSnapshot {
+            configuration_checksum: self.configuration_checksum(),
+            problem_size: self.problem_size(),
+            iter: self.iter,
+            model_evaluations: self.model_evaluations,
+            saved_model_evaluations: self.saved_model_evaluations,
+            jacobian: self.jacobian.get_jacobian().as_ref().map(matrix_to_vec),
+            inverse: self.jacobian.get_inverse().as_ref().map(matrix_to_vec),
+            compute_jacobian_at_next_iteration: self.jacobian.compute_jacobian(),
+            jacobian_approximated: self.jacobian.is_jacobian_approximated(),
+            iteratives_step_size: self.iteratives_step_size.as_ref().map(vector_to_vec),
+            residuals_step_size: self.residuals_step_size.as_ref().map(vector_to_vec),
+            residuals_values_current: self.residuals_values_current.as_ref().map(vector_to_vec),
+            best_iterate: self
+                .best_iterate
+                .as_ref()
+                .map(|(iterate, max_error)| (vector_to_vec(iterate), *max_error)),
+            initial_errors: self.initial_errors.as_ref().map(vector_to_vec),
+            previous_iterate: self
+                .previous_iterate
+                .as_ref()
+                .map(|(iterate, max_error)| (vector_to_vec(iterate), *max_error)),
+            cycles_detected: self.cycles_detected,
+            aitken_iterates: self.aitken_iterates.iter().map(vector_to_vec).collect(),
+            aitken_extrapolations: self.aitken_extrapolations,
+            jacobian_sources: self.jacobian_sources.clone(),
+            constant_jacobian_values: self.constant_jacobian_values.as_ref().map(matrix_to_vec),
+            last_step: self.last_step.as_ref().map(vector_to_vec),
+            valid_last_model_evaluation: self.valid_last_model_evaluation,
+            limited_memory: self.limited_memory.as_ref().map(|limited_memory| {
+                super::snapshot::LimitedMemorySnapshot {
+                    memory: limited_memory.get_memory(),
+                    inverse_diagonal: vector_to_vec(limited_memory.get_inverse_diagonal()),
+                    steps: limited_memory
+                        .get_steps()
+                        .iter()
+                        .map(|(s, y)| (vector_to_vec(s), vector_to_vec(y)))
+                        .collect(),
                 }
+            }),
+            last_max_error: self.last_max_error,
+            max_errors: self.max_errors.clone(),
+            last_errors: self.last_errors.as_ref().map(vector_to_vec),
+            non_finite_updates: self.non_finite_updates,
+            #[cfg(feature = "std")]
+            history: self.history.clone(),
+            dimension: core::marker::PhantomData,
+        }
+    }
+
+    /// Continue the resolution of a [SolverSnapshot], from the current state of the model
+    ///
+    /// The model must be at the iterate it had when the snapshot was taken:
+    /// it is evaluated once at its iteratives to restore its residuals, this evaluation being counted.
+    /// The following iterations are then the ones the resolution would have performed without interruption.
+    /// The counters, such as [RootFinder::get_iter], continue from the ones of the snapshot,
+    /// the budgets of the solver parameters applying to the whole resolution.
+    ///
+    /// In the debug log, the resolution starts with the iteration of the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// If the configuration of the solver is not the one of the solver that took the snapshot,
+    /// see [SolverSnapshot::get_configuration_checksum],
+    /// or for the reasons given by [RootFinder::solve]
+    pub fn resume<M>(
+        &mut self,
+        snapshot: &SolverSnapshot<D>,
+        model: &mut M,
+    ) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.solver_log.as_mut().unwrap().start_solve();
+        }
+
+        let result = self.run_resumed_resolution(snapshot, model);
+        self.terminate(model, result)
+    }
+
+    fn run_resumed_resolution<M>(
+        &mut self,
+        snapshot: &SolverSnapshot<D>,
+        model: &mut M,
+    ) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        if snapshot.configuration_checksum != self.configuration_checksum() {
+            panic!(
+                "The snapshot has been taken by a solver with another configuration: checksum {:#x} instead of {:#x}",
+                snapshot.configuration_checksum,
+                self.configuration_checksum()
+            );
+        }
+        self.check_jacobian_configuration(model);
+        self.reset();
+        self.restore(snapshot);
+
+        self.model_evaluations += 1;
+        match model.evaluate() {
+            Ok(()) => (),
+            Err(error) if error.is_inaccurate_values_error() => (),
+            Err(error) => return Err(crate::errors::SolverError::ModelEvaluationError(error)),
+        }
+
+        let errors = self.evaluate_errors(model);
+        self.stopping_errors_current = Some(errors.clone());
+        // the errors of the snapshot are the ones of the same iterate
+        self.max_errors.pop();
+        self.record_last_errors(&errors);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.parameters_to_log();
+            self.compatibility_warnings_to_log(&self.compatibility_warnings(model));
+            if !self.right_overrides.is_empty() {
+                self.right_overrides_to_log();
+            }
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Resumed from the snapshot taken at iteration {}\n\n",
+                self.iter
+            ));
+            self.iteration_to_log(model, &errors);
+        }
+
+        self.run_iterations(model, errors)
+    }
+
+    /// Restore the numerical state of a [SolverSnapshot], the solver having been reset
+    fn restore(&mut self, snapshot: &SolverSnapshot<D>) {
+        let shape = self.initial_guess.shape_generic().0;
+        let to_vector = |values: &Vec<f64>| super::super::ovector_from_slice_generic(shape, values);
+        let to_matrix = |values: &Vec<f64>| super::super::omatrix_from_slice_generic(shape, values);
+
+        self.iter = snapshot.iter;
+        self.model_evaluations = snapshot.model_evaluations;
+        self.saved_model_evaluations = snapshot.saved_model_evaluations;
+        self.jacobian.restore(
+            snapshot.jacobian.as_ref().map(to_matrix),
+            snapshot.inverse.as_ref().map(to_matrix),
+            snapshot.compute_jacobian_at_next_iteration,
+            snapshot.jacobian_approximated,
+        );
+        self.iteratives_step_size = snapshot.iteratives_step_size.as_ref().map(to_vector);
+        self.residuals_step_size = snapshot.residuals_step_size.as_ref().map(to_vector);
+        self.residuals_values_current = snapshot.residuals_values_current.as_ref().map(to_vector);
+        self.best_iterate = snapshot
+            .best_iterate
+            .as_ref()
+            .map(|(iterate, max_error)| (to_vector(iterate), *max_error));
+        self.initial_errors = snapshot.initial_errors.as_ref().map(to_vector);
+        self.previous_iterate = snapshot
+            .previous_iterate
+            .as_ref()
+            .map(|(iterate, max_error)| (to_vector(iterate), *max_error));
+        self.cycles_detected = snapshot.cycles_detected;
+        self.aitken_iterates = snapshot.aitken_iterates.iter().map(to_vector).collect();
+        self.aitken_extrapolations = snapshot.aitken_extrapolations;
+        self.jacobian_sources.clone_from(&snapshot.jacobian_sources);
+        self.constant_jacobian_values = snapshot.constant_jacobian_values.as_ref().map(to_matrix);
+        self.last_step = snapshot.last_step.as_ref().map(to_vector);
+        self.valid_last_model_evaluation = snapshot.valid_last_model_evaluation;
+        self.limited_memory = snapshot.limited_memory.as_ref().map(|limited_memory| {
+            LimitedMemoryInverse::from_steps(
+                limited_memory.memory,
+                to_vector(&limited_memory.inverse_diagonal),
+                limited_memory
+                    .steps
+                    .iter()
+                    .map(|(s, y)| (to_vector(s), to_vector(y)))
+                    .collect(),
+            )
+        });
+        self.last_max_error = snapshot.last_max_error;
+        self.max_errors.clone_from(&snapshot.max_errors);
+        self.last_errors = snapshot.last_errors.as_ref().map(to_vector);
+        self.non_finite_updates = snapshot.non_finite_updates;
+        // only restored if the history is activated, the timestamps continuing the ones of the snapshot
+        #[cfg(feature = "std")]
+        if let (Some(history), Some(records)) = (self.history.as_mut(), &snapshot.history) {
+            history.clone_from(records);
+            if let Some(record) = records.last() {
+                self.history_start = std::time::Instant::now()
+                    .checked_sub(record.timestamp_offset)
+                    .or(self.history_start);
+            }
+        }
+    }
+
+    /// Checksum of the configuration, see [SolverSnapshot::get_configuration_checksum]
+    fn configuration_checksum(&self) -> u64 {
+        let mut parameters = self.parameters.clone();
+        parameters.set_max_iter(0);
+        parameters.set_max_model_evaluations(None);
+
+        let mut hasher = super::snapshot::ConfigurationHasher::new();
+        write!(
+            hasher,
+            "{:?}|{:?}|{:?}|{:?}",
+            parameters, self.iters_params, self.residuals_config, self.right_overrides
+        )
+        .unwrap();
+        hasher.finish()
+    }
+
+    fn run_resolution<M>(&mut self, model: &mut M) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        self.check_jacobian_configuration(model);
+        self.reset();
+        #[cfg_attr(not(feature = "debug_log"), allow(unused_variables))]
+        let projected_iteratives = self.reconcile_initial_guess()?;
+        model.set_iteratives(&self.initial_guess);
+
+        // The first evaluation must yield usuable values
+        // However, then don't need to be accurate,
+        // unless the initial guess is the solution, see `run_iterations()`
+        self.model_evaluations += 1;
+        match model.evaluate() {
+            Ok(()) => (),
+            Err(error) if error.is_inaccurate_values_error() => {
+                self.valid_last_model_evaluation = false
+            }
+            Err(error) => {
+                return Err(crate::errors::SolverError::ModelInitialEvaluationError(
+                    error.message(),
+                ))
+            }
+        }
+
+        let errors = self.evaluate_errors(model);
+        self.check_residuals_magnitude()?;
+        self.initial_errors = Some(errors.clone());
+        self.stopping_errors_current = Some(errors.clone());
+        self.record_last_errors(&errors);
+        self.record_best_iterate(model, &errors);
+
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.parameters_to_log();
+            self.compatibility_warnings_to_log(&self.compatibility_warnings(model));
+            if !self.right_overrides.is_empty() {
+                self.right_overrides_to_log();
+            }
+            self.projected_iteratives_to_log(&projected_iteratives);
+            self.iteration_to_log(model, &errors);
+        }
+
+        self.run_iterations(model, errors)
+    }
+
+    /// Iterate from the current state of the model, whose stopping errors are `errors`, until the end of the resolution
+    fn run_iterations<M>(
+        &mut self,
+        model: &mut M,
+        mut errors: nalgebra::OVector<f64, D>,
+    ) -> Result<(), crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        let mut smoothed_errors = None;
+        let mut below_tolerance = 0;
+        let residuals_converged =
+            self.confirm_residuals_convergence(&errors, &mut smoothed_errors, &mut below_tolerance);
+        let (mut residuals_converged, mut predicate_satisfied) =
+            self.apply_convergence_predicate(&model.get_iteratives(), &errors, residuals_converged);
+        let mut steps_converged = self.are_steps_converged();
+        let mut residuals_converged_previously = false;
+
+        // Warning: unrolling by hand the first iteration (which is always a Newton-Raphson step)
+        //          is actually slowing down the code (run benchmarks to see it)
+        while !(residuals_converged && steps_converged) && self.check_budget().is_none() {
+            residuals_converged_previously = residuals_converged;
+
+            #[cfg(feature = "std")]
+            let iteration_start = self.history.as_ref().map(|_| std::time::Instant::now());
+            match self.iterate(model) {
+                Ok(value) => errors = value,
+                Err(e) => return Err(e),
+            }
+            self.record_last_errors(&errors);
+            self.record_best_iterate(model, &errors);
+            #[cfg(feature = "std")]
+            if let Some(iteration_start) = iteration_start {
+                self.record_iteration(iteration_start);
+            }
+
+            residuals_converged = self.confirm_residuals_convergence(
+                &errors,
+                &mut smoothed_errors,
+                &mut below_tolerance,
+            );
+            (residuals_converged, predicate_satisfied) = self.apply_convergence_predicate(
+                &model.get_iteratives(),
+                &errors,
+                residuals_converged,
+            );
+            steps_converged = self.are_steps_converged();
+        }
+
+        self.active_bound_variables = self.iters_params.active_bounds(&model.get_iteratives());
+
+        if residuals_converged && steps_converged {
+            // the initial errors are then the final ones, and the jacobian has not been computed
+            self.last_satisfied_criterion = if predicate_satisfied {
+                Some(ConvergenceCriterion::CustomPredicate)
+            } else if self.iter == 0 {
+                Some(ConvergenceCriterion::InitialGuess)
+            } else if residuals_converged_previously {
+                Some(ConvergenceCriterion::Step)
+            } else {
+                Some(ConvergenceCriterion::Residuals)
             };
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.convergence_to_log();
+            }
+        }
+
+        if !(residuals_converged && steps_converged) {
+            self.exhausted_budget = self.check_budget();
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.exhausted_budget_to_log();
+            }
+            let tolerance = self.parameters.get_tolerance();
+            self.unconverged_residuals = self.find_unconverged_residuals(
+                &errors,
+                |_| tolerance,
+                self.residuals_config.get_group_convergence(),
+            );
+            Err(crate::errors::SolverError::NonConvergenceError(
+                self.unconverged_residuals.clone(),
+            ))
+        } else if !self.valid_last_model_evaluation {
+            Err(crate::errors::SolverError::FinalEvaluationError)
+        } else if !self.active_bound_variables.is_empty() {
+            self.converged_on_boundary = true;
+            #[cfg(feature = "debug_log")]
+            if self.debug {
+                self.active_bounds_to_log();
+            }
+            if self.parameters.get_reject_solution_on_bounds() {
+                Err(crate::errors::SolverError::ConvergedOnBoundsError)
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Continue the resolution to reach a tighter tolerance on a subset of the residuals
+    ///
+    /// Once the resolution has converged, the residuals of the given `indices` are driven below `tight_tolerance`,
+    /// while the other ones only have to stay below the tolerance of the [SolverParameters].
+    /// Unlike a new resolution with a tighter tolerance,
+    /// no effort is spent on residuals that cannot reach it, for example because of the noise of the model.
+    ///
+    /// The iterations start from the current state of the model,
+    /// with the resolution method of the solver parameters and the jacobian of the previous resolution.
+    /// They are added to the counters of the previous resolution
+    /// (see [RootFinder::get_iter] and [RootFinder::get_model_evaluations]).
+    ///
+    /// The number of extra iterations performed is returned,
+    /// `0` if the tolerances were already reached.
+    /// If they are not reached within `max_extra_iter` iterations,
+    /// `SolverError::NonConvergenceError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::ResolutionMethod;
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Disabled;
+    /// let mut rf = nrf::solver::default_with_guess(init_guess, &iter_params, &res_config, ResolutionMethod::NewtonRaphson, damping);
+    /// rf.solve(&mut user_model).unwrap();
+    ///
+    /// let extra_iterations = rf.polish(&mut user_model, &[0], 1e-14, 5).unwrap();
+    /// assert!(extra_iterations <= 5);
+    /// ```
+    pub fn polish<M>(
+        &mut self,
+        model: &mut M,
+        indices: &[usize],
+        tight_tolerance: f64,
+        max_extra_iter: usize,
+    ) -> Result<usize, crate::errors::SolverError<M, D>>
+    where
+        M: model::Model<D>,
+    {
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.solver_log.as_mut().unwrap().ensure_started();
         }
 
+        let result = self.run_polish(model, indices, tight_tolerance, max_extra_iter);
+
+        #[cfg(feature = "debug_log")]
         if self.debug {
-            self.jac_to_log();
+            self.solver_log.as_ref().unwrap().flush();
         }
 
-        Ok(())
+        result
     }
 
-    fn compute_quasi_newton_step<M>(
+    fn run_polish<M>(
         &mut self,
         model: &mut M,
-        resolution_method: QuasiNewtonMethod,
-    ) -> Result<nalgebra::OVector<f64, D>, crate::errors::SolverInternalError<M, D>>
+        indices: &[usize],
+        tight_tolerance: f64,
+        max_extra_iter: usize,
+    ) -> Result<usize, crate::errors::SolverError<M, D>>
     where
         M: model::Model<D>,
     {
-        match self.evaluate_jacobian_quasi_newton_step(model, resolution_method) {
-            Ok(()) => Ok(self.compute_next_from_inv_jac(model)),
-            Err(crate::errors::SolverInternalError::InvalidJacobianError(error)) => Err(
-                crate::errors::SolverInternalError::InvalidJacobianError(error),
-            ),
-            Err(crate::errors::SolverInternalError::InvalidJacobianInverseError) => {
-                Err(crate::errors::SolverInternalError::InvalidJacobianInverseError)
+        self.check_jacobian_configuration(model);
+        let problem_size = self.problem_size();
+        let mut tolerances = vec![self.parameters.get_tolerance(); problem_size];
+        for &index in indices {
+            if index >= problem_size {
+                panic!(
+                    "The polished residual index {} is out of range, the problem size is {}",
+                    index, problem_size
+                );
             }
+            tolerances[index] = tight_tolerance;
         }
-    }
-
-    fn compute_next_from_inv_jac<M>(&self, model: &M) -> nalgebra::OVector<f64, D>
-    where
-        M: model::Model<D>,
-    {
-        let residuals = self
-            .residuals_config
-            .evaluate_update_residuals(&model.get_residuals());
 
-        let raw_step = -self.jacobian.get_inverse().as_ref().unwrap() * residuals;
+        #[cfg(feature = "debug_log")]
+        if self.debug {
+            self.polish_to_log(indices, tight_tolerance);
+        }
 
-        let iter_values = model.get_iteratives();
+        let is_polished = |errors: &nalgebra::OVector<f64, D>| {
+            errors
+                .iter()
+                .zip(tolerances.iter())
+                .all(|(error, tolerance)| error <= tolerance)
+        };
 
-        self.iters_params.step_limitations(&iter_values, &raw_step)
-    }
+        self.unconverged_residuals = errors::UnconvergedResiduals::default();
+        let mut errors = self.evaluate_errors(model);
+        // the model may have been evaluated since the end of the resolution
+        self.stopping_errors_current = Some(errors.clone());
+        let mut extra_iter = 0;
 
-    fn damping<M>(
-        &mut self,
-        model: &mut M,
-        max_error: f64,
-        current_guess: &nalgebra::OVector<f64, D>,
-        proposed_guess: &nalgebra::OVector<f64, D>,
-        errors_next: &mut nalgebra::OVector<f64, D>,
-    ) where
-        M: model::Model<D>,
-    {
-        let max_error_next = errors_next.amax();
-        if max_error_next > max_error {
-            // see documentation of the `SolverParameters` struct
-            if self.parameters.get_resolution_method() != ResolutionMethod::NewtonRaphson
-                && self.jacobian.is_jacobian_approximated()
-            {
-                self.jacobian.force_jacobian_computation();
-                if self.debug {
-                    self.recompute_jacobian_to_log();
-                }
-            } else {
-                let damping_factor = 1.0 / 2.0;
-                let damped_guess =
-                    current_guess * (1.0 - damping_factor) + proposed_guess * damping_factor;
-                model.set_iteratives(&damped_guess);
-                model.evaluate().unwrap();
-                *errors_next = self.evaluate_errors(model);
+        while !is_polished(&errors) && extra_iter < max_extra_iter {
+            extra_iter += 1;
+            errors = self.iterate(model)?;
+        }
 
-                if self.debug {
-                    self.damping_to_log(model, errors_next);
-                }
-            }
+        if !is_polished(&errors) {
+            self.unconverged_residuals =
+                self.find_unconverged_residuals(&errors, |i| tolerances[i], false);
+            Err(crate::errors::SolverError::NonConvergenceError(
+                self.unconverged_residuals.clone(),
+            ))
+        } else if !self.valid_last_model_evaluation {
+            Err(crate::errors::SolverError::FinalEvaluationError)
+        } else {
+            Ok(extra_iter)
         }
     }
 
-    fn update_model<M>(
+    /// Perform the resolution, falling back on other resolution methods in case of failure
+    ///
+    /// The resolution method of the solver parameters is attempted first,
+    /// then each of the `fallback_methods` in the given order, until one succeeds.
+    /// Each attempt restarts from the initial guess with a reset solver state,
+    /// the damping setting being shared by all attempts.
+    ///
+    /// The resolution method that succeeded is returned,
+    /// otherwise the error gathers the failure of each attempt.
+    /// In both cases, the resolution method of the solver parameters is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newton_rootfinder as nrf;
+    /// use nrf::iteratives;
+    /// use nrf::residuals;
+    /// use nrf::solver::{QuasiNewtonMethod, ResolutionMethod};
+    /// # pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    /// #   let mut y = x * x;
+    /// #   y[0] -= 2.0;
+    /// #   y
+    /// # }
+    /// # let problem_size = 1;
+    /// # let init_guess = nalgebra::DVector::from_vec(vec![1.0]);
+    /// # let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    /// # let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    /// # let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    /// # let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    /// # let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    /// # let damping = nrf::solver::Damping::Enabled;
+    /// let mut rf = nrf::solver::default_with_guess(
+    ///     init_guess,
+    ///     &iter_params,
+    ///     &res_config,
+    ///     ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+    ///     damping,
+    /// );
+    ///
+    /// let method = rf
+    ///     .solve_with_fallback(&mut user_model, &[ResolutionMethod::NewtonRaphson])
+    ///     .unwrap();
+    /// assert_eq!(method, ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the storage of the jacobian with one of the fallback methods exceeds the memory limit,
+    /// see [SolverParameters::check_memory_limit], before any attempt
+    pub fn solve_with_fallback<M>(
         &mut self,
         model: &mut M,
-        proposed_guess: &nalgebra::OVector<f64, D>,
-    ) -> Result<nalgebra::OVector<f64, D>, errors::SolverError<M, D>>
+        fallback_methods: &[ResolutionMethod],
+    ) -> Result<ResolutionMethod, crate::errors::FallbackError<M, D>>
     where
         M: model::Model<D>,
     {
-        let errors = self.evaluate_errors(model);
-        let max_error = errors.amax();
-        let current_guess = model.get_iteratives();
+        let initial_method = self.parameters.get_resolution_method();
+        let mut attempts = Vec::new();
 
-        model.set_iteratives(proposed_guess);
-        match model.evaluate() {
-            Ok(()) => {
-                self.valid_last_model_evaluation = true;
-            }
-            Err(ModelError::InaccurateValuesError(_)) => {
-                self.valid_last_model_evaluation = false;
-            }
-            Err(e) => {
-                self.valid_last_model_evaluation = false;
-                return Err(errors::SolverError::ModelEvaluationError(e));
+        for resolution_method in fallback_methods {
+            let mut parameters = self.parameters.clone();
+            parameters.set_resolution_method(*resolution_method);
+            if let Err(error) = parameters.check_memory_limit() {
+                panic!("{}", error);
             }
         }
-        let mut errors_next = self.evaluate_errors(model);
 
+        // the attempts are written to the log of a single resolution
+        #[cfg(feature = "debug_log")]
         if self.debug {
-            self.iteration_to_log(model, &errors_next);
+            self.solver_log.as_mut().unwrap().start_solve();
         }
 
-        if self.parameters.get_damping() {
-            self.damping(
-                model,
-                max_error,
-                &current_guess,
-                proposed_guess,
-                &mut errors_next,
-            );
-        }
+        for resolution_method in
+            core::iter::once(initial_method).chain(fallback_methods.iter().copied())
+        {
+            #[cfg(feature = "debug_log")]
+            if !attempts.is_empty() && self.debug {
+                self.fallback_to_log(resolution_method);
+            }
 
-        match self.parameters.get_resolution_method() {
-            ResolutionMethod::NewtonRaphson => (),
-            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton) => (),
-            _ => {
-                self.iteratives_step_size = Some(model.get_iteratives() - current_guess);
-                self.residuals_step_size = Some(errors_next.clone() - errors);
-                self.residuals_values_current = Some(errors_next.clone())
+            self.parameters.set_resolution_method(resolution_method);
+            let result = self.solve_attempt(model);
+
+            match result {
+                Ok(()) => {
+                    self.parameters.set_resolution_method(initial_method);
+                    return Ok(resolution_method);
+                }
+                Err(error) => attempts.push((resolution_method, error)),
             }
-        };
+        }
 
-        Ok(errors_next)
+        self.parameters.set_resolution_method(initial_method);
+        Err(crate::errors::FallbackError::new(attempts))
     }
+}
 
-    /// The core function performing the resolution on a given `Model`
-    pub fn solve<M>(&mut self, model: &mut M) -> Result<(), crate::errors::SolverError<M, D>>
+// Writing of the debug log
+#[cfg(feature = "debug_log")]
+impl<'a, T, D> RootFinder<'a, T, D>
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+{
+    fn fallback_to_log(&self, resolution_method: ResolutionMethod) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Resolution failed, falling back on the resolution method: {}\n\n",
+            resolution_method
+        ));
+    }
+
+    fn parameters_to_log(&self) {
+        self.solver_log.as_ref().unwrap().add_parameters(
+            &self.parameters.to_string(),
+            &self
+                .iters_params
+                .format_with(self.log_options.get_displayed_entries()),
+            &self
+                .residuals_config
+                .format_with(self.log_options.get_displayed_entries()),
+        );
+    }
+
+    /// The warnings preventing the resolution are not written, the resolution panicking before
+    fn compatibility_warnings_to_log(&self, warnings: &[CompatibilityWarning]) {
+        for warning in warnings {
+            self.solver_log
+                .as_ref()
+                .unwrap()
+                .add_content(&format!("{}\n\n", warning));
+        }
+    }
+
+    fn projected_iteratives_to_log(&self, projected_iteratives: &[(usize, f64)]) {
+        for &(i, value) in projected_iteratives {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Warning: the initial guess of the iterative {} is out of its bounds, {} projected to {}\n\n",
+                i, value, self.initial_guess[i]
+            ));
+        }
+    }
+
+    fn right_overrides_to_log(&self) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "The right members of the residuals {:?} are computed from the iteratives\n\n",
+            self.right_overrides.get_overridden_residuals()
+        ));
+    }
+
+    fn iteration_to_log<M>(&self, model: &M, errors: &nalgebra::OVector<f64, D>)
     where
         M: model::Model<D>,
     {
-        model.set_iteratives(&self.initial_guess);
+        let iteratives = model.get_iteratives();
+        let residuals = self.model_residuals(model);
+        let update_residuals = self.residuals_config.evaluate_update_residuals(&residuals);
+        self.solver_log.as_ref().unwrap().add_new_iteration(
+            &iteratives,
+            &residuals,
+            &update_residuals,
+            errors,
+            self.iter,
+            self.jacobian_sources.last().copied(),
+            &self.log_options,
+        );
+        self.group_errors_to_log(errors);
+    }
 
-        // The first evaluation must yield usuable values
-        // However, then don't need to be accurate
-        match model.evaluate() {
-            Ok(()) => (),
-            Err(ModelError::InaccurateValuesError(_)) => (),
-            Err(ModelError::UnusableValuesError(error)) => {
-                return Err(crate::errors::SolverError::ModelInitialEvaluationError(
-                    error.to_string(),
-                ))
-            }
+    fn group_errors_to_log(&self, errors: &nalgebra::OVector<f64, D>) {
+        if !self.residuals_config.get_groups().is_empty() {
+            self.solver_log
+                .as_ref()
+                .unwrap()
+                .add_content(&self.residuals_config.format_group_errors(errors));
         }
+    }
 
-        let mut errors = self.evaluate_errors(model);
-        let mut max_error = errors.amax();
+    fn method_state_to_log(&self, method_state: MethodState) {
+        self.solver_log
+            .as_ref()
+            .unwrap()
+            .add_content(&format!("Method state: {}\n\n", method_state));
+    }
 
-        if self.debug {
-            self.parameters_to_log();
-            self.iteration_to_log(model, &errors);
+    fn limited_memory_seed_to_log(&self) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Limited memory Broyden method seeded with the diagonal of the jacobian, keeping the last {} steps\n\n",
+            self.limited_memory.as_ref().unwrap().get_memory()
+        ));
+    }
+
+    fn rel_safeguard_to_log(&self) {
+        let safeguarded = self
+            .residuals_config
+            .get_safeguarded_residuals(&self.residuals_values);
+        if !safeguarded.is_empty() {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Relative normalization near zero, the adaptative normalization is used for the update of the residuals {:?}\n\n",
+                safeguarded
+            ));
         }
+    }
 
-        // Warning: unrolling by hand the first iteration (which is always a Newton-Raphson step)
-        //          is actually slowing down the code (run benchmarks to see it)
-        while max_error > self.parameters.get_tolerance()
-            && self.iter < self.parameters.get_max_iter()
-        {
-            self.iter += 1;
+    fn best_iterate_to_log(&self) {
+        if let Some((_, best_error)) = &self.best_iterate {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Resolution failed, the model is evaluated at the best iterate, with a maximum error of {}\n\n",
+                best_error
+            ));
+        }
+    }
 
-            let proposed_guess = match self.parameters.get_resolution_method() {
-                ResolutionMethod::NewtonRaphson => self.compute_newton_raphson_step(model),
-                ResolutionMethod::QuasiNewton(quasi_newton_method) => {
-                    self.compute_quasi_newton_step(model, quasi_newton_method)
-                }
-            };
+    fn aitken_extrapolation_to_log(&self, max_error_plain: f64, max_error_extrapolated: f64) {
+        let content = if max_error_extrapolated < max_error_plain {
+            format!(
+                "Aitken extrapolation of the last three iterates, the maximum error decreasing from {} to {}\n\n",
+                max_error_plain, max_error_extrapolated
+            )
+        } else {
+            format!(
+                "Aitken extrapolation rejected, with a maximum error of {} instead of {}: the plain iterate is kept\n\n",
+                max_error_extrapolated, max_error_plain
+            )
+        };
+        self.solver_log.as_ref().unwrap().add_content(&content);
+    }
 
-            match proposed_guess {
-                Ok(value) => match self.update_model(model, &value) {
-                    Ok(value) => errors = value,
-                    Err(e) => return Err(e),
-                },
-                Err(error) => {
-                    return Err(errors::SolverError::JacobianError(error));
-                }
-            }
+    fn end_of_warmup_to_log(&self) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "End of the Newton warmup after {} iterations, switching to {}\n\n",
+            self.parameters.get_newton_warmup_iterations(),
+            self.parameters.get_resolution_method()
+        ));
+    }
 
-            max_error = errors.amax();
+    fn exhausted_budget_to_log(&self) {
+        if let Some(budget) = self.exhausted_budget {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Resolution stopped, the {} has been reached: {} iterations and {} model evaluations performed\n\n",
+                budget, self.iter, self.model_evaluations
+            ));
         }
+    }
 
-        if max_error > self.parameters.get_tolerance() {
-            Err(crate::errors::SolverError::NonConvergenceError)
-        } else if self.valid_last_model_evaluation {
-            Ok(())
-        } else {
-            Err(crate::errors::SolverError::FinalEvaluationError)
+    fn convergence_confirmation_to_log(
+        &self,
+        smoothed_max_error: Option<f64>,
+        below_tolerance: usize,
+    ) {
+        let mut content = String::new();
+        if let Some(max_error) = smoothed_max_error {
+            content.push_str(&format!("Smoothed maximum error: {}\n", max_error));
         }
+        content.push_str(&format!(
+            "Errors below the tolerance for {} consecutive iteration(s), {} required\n\n",
+            below_tolerance,
+            self.parameters.get_consecutive_convergences()
+        ));
+        self.solver_log.as_ref().unwrap().add_content(&content);
     }
 
-    fn parameters_to_log(&self) {
-        self.solver_log.as_ref().unwrap().add_parameters(
-            &self.parameters.to_string(),
-            &self.iters_params.to_string(),
-            &self.residuals_config.to_string(),
-        );
+    fn convergence_predicate_to_log(&self, satisfied: bool) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Custom convergence predicate ({}): {}\n",
+            self.predicate_mode,
+            if satisfied {
+                "satisfied"
+            } else {
+                "not satisfied"
+            }
+        ));
     }
 
-    fn iteration_to_log<M>(&self, model: &M, errors: &nalgebra::OVector<f64, D>)
+    fn convergence_to_log(&self) {
+        if self.last_satisfied_criterion == Some(ConvergenceCriterion::InitialGuess) {
+            self.solver_log.as_ref().unwrap().add_content(
+                "Convergence reached at the initial guess, no iteration has been performed\n\n",
+            );
+        } else if self.last_satisfied_criterion == Some(ConvergenceCriterion::CustomPredicate) {
+            self.solver_log
+                .as_ref()
+                .unwrap()
+                .add_content("Convergence reached, custom predicate satisfied\n\n");
+        } else if let Some(criterion) = self.last_satisfied_criterion {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Convergence reached, the last criterion satisfied is the {}\n\n",
+                criterion
+            ));
+        }
+    }
+
+    /// Close the log of the resolution with its outcome, and write it to the file in buffered mode
+    fn termination_to_log<M>(&self, result: &Result<(), crate::errors::SolverError<M, D>>)
     where
         M: model::Model<D>,
     {
-        let iteratives = model.get_iteratives();
-        let residuals = model.get_residuals();
-        self.solver_log.as_ref().unwrap().add_new_iteration(
-            &iteratives,
-            &residuals,
-            errors,
+        let outcome = match result {
+            Ok(()) => String::from("Convergence reached"),
+            Err(error) => error.to_string(),
+        };
+        self.solver_log.as_ref().unwrap().add_termination(
+            &outcome,
             self.iter,
+            self.get_initial_max_error(),
+            self.last_max_error,
+            self.get_convergence_rate(),
+            self.get_suspicious_jacobian(),
+            self.get_residuals_above_tolerance(),
+            self.get_worst_residual(),
         );
     }
 
+    fn polish_to_log(&self, indices: &[usize], tight_tolerance: f64) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Polishing the residuals {:?} to the tolerance {}\n\n",
+            indices, tight_tolerance
+        ));
+    }
+
+    fn active_bounds_to_log(&self) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Convergence reached with iteratives on their bounds: {:?}\n\n",
+            self.active_bound_variables
+        ));
+    }
+
+    fn cycle_to_log(&self, action: CycleBreakingAction) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Cycle detected, the proposed iterate is the one of two iterations before without improving the error: {}\n\n",
+            action
+        ));
+    }
+
+    fn scalar_safeguard_to_log(&self, x_a: f64, x_b: f64) {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Scalar safeguard: refinement of the root bracketed between {} and {}\n\n",
+            x_a, x_b
+        ));
+    }
+
+    fn context_step_reduction_to_log<M>(&self, error: &model::ModelError<M, D>)
+    where
+        M: model::Model<D>,
+    {
+        self.solver_log.as_ref().unwrap().add_content(&format!(
+            "Model error at the proposed iterate, the step is halved on the iteratives of its context: {}\n\n",
+            error
+        ));
+    }
+
     fn recompute_jacobian_to_log(&self) {
         self.solver_log.as_ref().unwrap().add_content(
             "Iteration refused, the jacobian will be recomputed at the next iteration\n\n",
@@ -482,18 +4161,75 @@ where
         M: model::Model<D>,
     {
         let iteratives = model.get_iteratives();
-        let residuals = model.get_residuals();
+        let residuals = self.model_residuals(model);
+        let update_residuals = self.residuals_config.evaluate_update_residuals(&residuals);
+        self.solver_log.as_ref().unwrap().add_damping(
+            &iteratives,
+            &residuals,
+            &update_residuals,
+            errors,
+            &self.log_options,
+        );
+        self.group_errors_to_log(errors);
+    }
+
+    fn perturbations_to_log(&self) {
+        if let Some(perturbations) = self.jacobian.get_perturbations() {
+            self.solver_log.as_ref().unwrap().add_perturbations(
+                perturbations,
+                self.jacobian.get_perturbations_out_of_bounds(),
+                self.log_options.get_displayed_entries(),
+            );
+        }
+    }
+
+    fn inaccurate_columns_to_log(&self, policy: InaccurateJacobianPolicy) {
+        for inaccurate_column in self.jacobian.get_inaccurate_columns() {
+            let action = match (policy, inaccurate_column.get_recovered()) {
+                (_, true) => "accurate values after reducing the perturbation",
+                (InaccurateJacobianPolicy::Accept, false) => "inaccurate values accepted",
+                (_, false) => "inaccurate values rejected, the jacobian evaluation failed",
+            };
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "Inaccurate jacobian column {} ({} retries): {}\n\n",
+                inaccurate_column.get_column(),
+                inaccurate_column.get_retries(),
+                action
+            ));
+        }
+    }
+
+    /// Pairs of the structural reduction added by the last computation of the jacobian, from `start`
+    fn structural_reduction_to_log(&self, start: usize) {
+        for &(residual, iterative) in &self.jacobian.get_reduced_rows()[start..] {
+            self.solver_log.as_ref().unwrap().add_content(&format!(
+                "WARNING: the residual {} depends on no iterative and the iterative {} affects no residual, \
+                 both are removed from the system until the end of the resolution\n\n",
+                residual, iterative
+            ));
+        }
+    }
+
+    fn inaccurate_analytical_jacobian_to_log(&self, policy: InaccurateJacobianPolicy) {
+        let action = match policy {
+            InaccurateJacobianPolicy::Accept => "inaccurate values accepted",
+            _ => "inaccurate values rejected, the jacobian evaluation failed",
+        };
         self.solver_log
             .as_ref()
             .unwrap()
-            .add_damping(&iteratives, &residuals, errors);
+            .add_content(&format!("Inaccurate analytical jacobian: {}\n\n", action));
     }
 
     fn jac_to_log(&self) {
         self.solver_log
             .as_ref()
             .unwrap()
-            .add_content(&self.jacobian.to_string());
+            .add_content(&self.jacobian.format_with(
+                self.log_options.get_jacobian_significant_digits(),
+                self.log_options.get_jacobian_max_full_size(),
+                self.log_options.get_jacobian_largest_entries(),
+            ));
     }
 }
 
@@ -507,20 +4243,41 @@ where
     nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("Rootfinder")
+        let mut debug_struct = f.debug_struct("Rootfinder");
+        debug_struct
             .field("Solver parameters", &self.parameters)
             .field("Initial Guess", &self.initial_guess)
             .field("Iterative configuration", &self.iters_params)
             .field("Residual configuration", &self.residuals_config)
-            .field("Debug option activated", &self.debug)
+            .field(
+                "Overridden right members",
+                &self.right_overrides.get_overridden_residuals(),
+            )
+            .field(
+                "Custom convergence predicate set",
+                &self.convergence_predicate.is_some(),
+            )
+            .field("Custom convergence predicate mode", &self.predicate_mode);
+        #[cfg(feature = "debug_log")]
+        debug_struct.field("Debug option activated", &self.debug);
+        debug_struct
             .field("Current iteration", &self.iter)
+            .field("Model evaluations", &self.model_evaluations)
+            .field("Saved model evaluations", &self.saved_model_evaluations)
+            .field("Active bound variables", &self.active_bound_variables)
+            .field("Last satisfied criterion", &self.last_satisfied_criterion)
+            .field("Initial errors", &self.initial_errors)
+            .field("Last max error", &self.last_max_error)
+            .field("Jacobian sources", &self.jacobian_sources)
             .field(
                 "Status last model evaluation",
                 &self.valid_last_model_evaluation,
             )
             .field("Placeholder jacobian", &self.jacobian)
+            .field("Placeholder residuals values", &self.residuals_values)
             .field("Placeholder iterative step", &self.iteratives_step_size)
             .field("Placeholder residual step", &self.residuals_step_size)
+            .field("Placeholder last step", &self.last_step)
             .field(
                 "Placeholder current residual",
                 &self.residuals_values_current,
@@ -528,3 +4285,59 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Model;
+    use crate::solver::{MaxIterations, UpdateQuasiNewtonMethod};
+
+    fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+        let mut y = x * x;
+        y[0] -= 2.0;
+        y
+    }
+
+    #[test]
+    fn quasi_newton_steps_cleared_by_jacobian_computation() {
+        let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let methods = vec![residuals::NormalizationMethod::Abs];
+        let res_config = residuals::ResidualsConfig::new(&methods, &methods);
+        let parameters = SolverParameters::new(
+            ProblemSize::new(1),
+            1e-6,
+            MaxIterations::new(50),
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+                UpdateQuasiNewtonMethod::BroydenSecondMethod,
+            )),
+            Damping::Disabled,
+        );
+        let init = nalgebra::DVector::from_vec(vec![1.0]);
+        let mut rf = RootFinder::new(parameters, init.clone(), &iter_params, &res_config);
+        let mut model = model::UserModelFromFunction::new(1, square2);
+        rf.reset();
+        model.set_iteratives(&init);
+        model.evaluate().unwrap();
+
+        // steps of an iteration made with a previous jacobian
+        rf.iteratives_step_size = Some(nalgebra::DVector::from_vec(vec![0.5]));
+        rf.residuals_step_size = Some(nalgebra::DVector::from_vec(vec![-0.75]));
+        rf.residuals_values_current = Some(nalgebra::DVector::from_vec(vec![1.0]));
+
+        rf.compute_jac(&mut model).unwrap();
+        assert_eq!(rf.iteratives_step_size, None);
+        assert_eq!(rf.residuals_step_size, None);
+        assert_eq!(rf.residuals_values_current, None);
+
+        // the next update only uses the step made with the computed jacobian
+        let current_guess = model.get_iteratives();
+        let proposed_guess = rf.compute_next(&model).unwrap();
+        let errors = rf.update_model(&mut model, &proposed_guess).unwrap();
+        assert_eq!(
+            rf.iteratives_step_size,
+            Some(proposed_guess - current_guess)
+        );
+        assert_eq!(rf.residuals_values_current, Some(errors));
+    }
+}
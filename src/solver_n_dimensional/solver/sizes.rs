@@ -0,0 +1,89 @@
+use core::fmt;
+
+/// Dimension of a problem, i.e. its number of iteratives and residuals
+///
+/// It is given to `SolverParameters::new()` next to the maximum number of iterations:
+/// the distinct types prevent swapping them silently.
+///
+/// ```compile_fail
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::{MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+///
+/// // The maximum number of iterations is given as the problem size
+/// let parameters = SolverParameters::new(
+///     MaxIterations::new(60),
+///     1e-6,
+///     ProblemSize::new(3),
+///     ResolutionMethod::NewtonRaphson,
+///     nrf::solver::Damping::Disabled,
+/// );
+/// ```
+///
+/// There is no conversion from an `usize`, which would let two swapped integers compile:
+///
+/// ```compile_fail
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::{ResolutionMethod, SolverParameters};
+///
+/// let parameters = SolverParameters::new(
+///     60.into(),
+///     1e-6,
+///     3.into(),
+///     ResolutionMethod::NewtonRaphson,
+///     nrf::solver::Damping::Disabled,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProblemSize(usize);
+
+impl ProblemSize {
+    /// It panics if the size is zero
+    pub fn new(problem_size: usize) -> Self {
+        if problem_size == 0 {
+            panic!("The problem size must be strictly positive, got 0");
+        }
+        ProblemSize(problem_size)
+    }
+
+    pub fn get(self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for ProblemSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Maximum number of iterations of a resolution, see `SolverParameters`
+///
+/// A raw `usize` is not accepted in place of it:
+///
+/// ```compile_fail
+/// use newton_rootfinder as nrf;
+/// use nrf::solver::{ProblemSize, ResolutionMethod, SolverParameters};
+///
+/// let parameters =
+///     SolverParameters::new(ProblemSize::new(3), 1e-6, 60, ResolutionMethod::NewtonRaphson, nrf::solver::Damping::Disabled);
+/// ```
+///
+/// Zero iterations are allowed, the resolution then only evaluates the initial guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxIterations(usize);
+
+impl MaxIterations {
+    pub fn new(max_iter: usize) -> Self {
+        MaxIterations(max_iter)
+    }
+
+    pub fn get(self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for MaxIterations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
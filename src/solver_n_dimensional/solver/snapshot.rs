@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use super::IterationRecord;
+use super::JacobianSource;
+
+/// Numerical state of a resolution, taken with [crate::solver::RootFinder::snapshot]
+/// to continue it later with [crate::solver::RootFinder::resume]
+///
+/// The snapshot holds the counters of the resolution, the jacobian and its inverse,
+/// the steps used by the quasi-Newton updates, the approximation of the
+/// [crate::solver::QuasiNewtonMethod::LimitedMemoryBroyden] method, the best iterate,
+/// the iterates and the errors kept by the solver.
+/// With the `std` feature, it also holds the history of the iterations, if activated,
+/// see [crate::solver::RootFinder::set_history].
+///
+/// The current iterate is not part of the snapshot: it is the one of the model,
+/// whose state must be restored by the user before resuming the resolution.
+/// The configuration of the solver (parameters, iteratives and residuals) is not part of the snapshot either,
+/// only a checksum of it, see [SolverSnapshot::get_configuration_checksum].
+///
+/// With the `serde` feature, the snapshot implements `Serialize` and `Deserialize`,
+/// to be written to a file for example.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct SolverSnapshot<D = nalgebra::Dyn> {
+    pub(crate) configuration_checksum: u64,
+    pub(crate) problem_size: usize,
+    pub(crate) iter: usize,
+    pub(crate) model_evaluations: usize,
+    pub(crate) saved_model_evaluations: usize,
+    // Matrices stored column by column
+    pub(crate) jacobian: Option<Vec<f64>>,
+    pub(crate) inverse: Option<Vec<f64>>,
+    pub(crate) compute_jacobian_at_next_iteration: bool,
+    pub(crate) jacobian_approximated: bool,
+    pub(crate) iteratives_step_size: Option<Vec<f64>>,
+    pub(crate) residuals_step_size: Option<Vec<f64>>,
+    pub(crate) residuals_values_current: Option<Vec<f64>>,
+    pub(crate) best_iterate: Option<(Vec<f64>, f64)>,
+    pub(crate) initial_errors: Option<Vec<f64>>,
+    pub(crate) previous_iterate: Option<(Vec<f64>, f64)>,
+    pub(crate) cycles_detected: usize,
+    pub(crate) aitken_iterates: Vec<Vec<f64>>,
+    pub(crate) aitken_extrapolations: usize,
+    pub(crate) jacobian_sources: Vec<JacobianSource>,
+    pub(crate) constant_jacobian_values: Option<Vec<f64>>,
+    pub(crate) last_step: Option<Vec<f64>>,
+    pub(crate) valid_last_model_evaluation: bool,
+    pub(crate) limited_memory: Option<LimitedMemorySnapshot>,
+    pub(crate) last_max_error: Option<f64>,
+    pub(crate) max_errors: Vec<f64>,
+    pub(crate) last_errors: Option<Vec<f64>>,
+    pub(crate) non_finite_updates: usize,
+    #[cfg(feature = "std")]
+    pub(crate) history: Option<Vec<IterationRecord>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dimension: PhantomData<D>,
+}
+
+impl<D> SolverSnapshot<D> {
+    /// Number of iterations performed when the snapshot has been taken
+    pub fn get_iter(&self) -> usize {
+        self.iter
+    }
+
+    /// Number of model evaluations performed when the snapshot has been taken
+    pub fn get_model_evaluations(&self) -> usize {
+        self.model_evaluations
+    }
+
+    pub fn get_problem_size(&self) -> usize {
+        self.problem_size
+    }
+
+    /// Checksum of the configuration of the solver that took the snapshot
+    ///
+    /// It covers the solver parameters, except the budgets of iterations and model evaluations,
+    /// the iteratives, the residuals configuration and the overridden residuals.
+    /// The budgets can be changed to continue a resolution stopped by them.
+    pub fn get_configuration_checksum(&self) -> u64 {
+        self.configuration_checksum
+    }
+}
+
+/// Approximation of the [crate::solver::QuasiNewtonMethod::LimitedMemoryBroyden] method,
+/// see [crate::solver::LimitedMemoryInverse]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct LimitedMemorySnapshot {
+    pub(crate) memory: usize,
+    pub(crate) inverse_diagonal: Vec<f64>,
+    // Steps of the iteratives and of the residuals, from the oldest one
+    pub(crate) steps: Vec<(Vec<f64>, Vec<f64>)>,
+}
+
+/// FNV-1a hash of the formatted values, used for the checksum of the configuration
+pub(crate) struct ConfigurationHasher(u64);
+
+impl ConfigurationHasher {
+    pub(crate) fn new() -> Self {
+        ConfigurationHasher(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Write for ConfigurationHasher {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn vector_to_vec<D>(vector: &nalgebra::OVector<f64, D>) -> Vec<f64>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    vector.iter().copied().collect()
+}
+
+pub(crate) fn matrix_to_vec<D>(matrix: &nalgebra::OMatrix<f64, D, D>) -> Vec<f64>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    matrix.iter().copied().collect()
+}
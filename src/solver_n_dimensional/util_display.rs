@@ -0,0 +1,24 @@
+use alloc::format;
+use alloc::string::String;
+use core::ops::Range;
+
+/// Default number of entries displayed at the beginning and at the end of a long table
+pub const DEFAULT_DISPLAYED_ENTRIES: usize = 10;
+
+/// Range of the entries hidden from a table of `len` entries,
+/// when only its first and last `displayed_entries` entries are displayed
+///
+/// `None` if all the entries are displayed
+pub fn hidden_entries(len: usize, displayed_entries: Option<usize>) -> Option<Range<usize>> {
+    match displayed_entries {
+        Some(displayed_entries) if len > 2 * displayed_entries => {
+            Some(displayed_entries..len - displayed_entries)
+        }
+        _ => None,
+    }
+}
+
+/// Line replacing the hidden entries of a table
+pub fn hidden_entries_marker(hidden: &Range<usize>) -> String {
+    format!("… ({} more)\n", hidden.len())
+}
@@ -8,16 +8,6 @@ where
     nalgebra::OMatrix::zeros_generic(nrows, ncols)
 }
 
-pub fn omatrix_zeros_like<D>(input: &nalgebra::OMatrix<f64, D, D>) -> nalgebra::OMatrix<f64, D, D>
-where
-    D: nalgebra::Dim,
-    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
-{
-    let (nrows, ncols) = input.shape_generic();
-
-    nalgebra::OMatrix::zeros_generic(nrows, ncols)
-}
-
 pub fn omatrix_zeros_like_ovector<D>(
     input: &nalgebra::OVector<f64, D>,
 ) -> nalgebra::OMatrix<f64, D, D>
@@ -46,3 +36,37 @@ where
 {
     nalgebra::OMatrix::zeros_generic(nrows, nrows)
 }
+
+/// Build a vector of generic dimension from a slice
+///
+/// Useful to write an initial guess once
+/// and use it either with a `nalgebra::Dyn` or a `nalgebra::Const<N>` problem
+///
+/// # Panics
+/// The length of the slice must match the dimension
+///
+/// # Examples
+/// ```
+/// use newton_rootfinder as nrf;
+///
+/// let guess_dyn = nrf::ovector_from_slice_generic(nalgebra::Dyn(2), &[1.0, 2.0]);
+/// let guess_static = nrf::ovector_from_slice_generic(nalgebra::Const::<2>, &[1.0, 2.0]);
+///
+/// assert_eq!(guess_dyn, nalgebra::DVector::from_vec(vec![1.0, 2.0]));
+/// assert_eq!(guess_static, nalgebra::Vector2::new(1.0, 2.0));
+/// ```
+pub fn ovector_from_slice_generic<D>(nrows: D, values: &[f64]) -> nalgebra::OVector<f64, D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+{
+    nalgebra::OMatrix::from_column_slice_generic(nrows, nalgebra::Const::<1_usize>, values)
+}
+
+pub fn omatrix_from_slice_generic<D>(nrows: D, values: &[f64]) -> nalgebra::OMatrix<f64, D, D>
+where
+    D: nalgebra::Dim,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    nalgebra::OMatrix::from_column_slice_generic(nrows, nrows, values)
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crate::iteratives;
+use crate::iteratives::Iterative;
+use crate::residuals;
+use crate::solver::{RootFinder, SolverParameters};
+
+/// Configuration parsed from a xml file, returned by [super::load_xml_fd] and [super::load_xml_jac]
+///
+/// It bundles the elements required by a [RootFinder], which only borrows them:
+/// - the iteratives are wrapped with [SolverConfig::build_iteratives]
+/// - the residuals configuration is built with [SolverConfig::build_residuals_config]
+///
+/// [SolverConfig::with_rootfinder] builds the solver from these elements, within a closure using it.
+///
+/// # Examples
+///
+/// ```
+/// use newton_rootfinder as nrf;
+/// use nrf::model::Model;
+///
+/// fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+///     x.map(|x| x * x - 2.0)
+/// }
+///
+/// const CONFIGURATION: &str = r#"
+///     <nrf>
+///         <solver problem_size="1" max_iter="50" tolerance="1e-6" damping="false" resolution_method="NR"/>
+///         <iteratives min_value="-inf" max_value="inf" max_step_abs="inf" max_step_rel="inf" dx_abs="5e-8" dx_rel="5e-8" perturbation_method="Max">
+///             <iterative id="0"/>
+///         </iteratives>
+///         <residuals stopping_criteria="Abs" update_method="Abs">
+///             <residual id="0"/>
+///         </residuals>
+///     </nrf>"#;
+///
+/// let config = nrf::xml_parser::load_xml_fd_str(CONFIGURATION).unwrap();
+/// let mut user_model =
+///     nrf::model::UserModelFromFunction::new(config.get_parameters().get_problem_size().unwrap(), square2);
+///
+/// config
+///     .with_rootfinder(nalgebra::DVector::from_vec(vec![1.0]), |rf| {
+///         rf.solve(&mut user_model)
+///     })
+///     .unwrap();
+/// assert!((user_model.get_iteratives()[0] - 2.0_f64.sqrt()).abs() < 1e-6);
+/// ```
+#[derive(Clone)]
+pub struct SolverConfig<T: Iterative> {
+    parameters: SolverParameters,
+    iteratives: Vec<T>,
+    stopping_criterias: Vec<residuals::NormalizationMethod>,
+    update_methods: Vec<residuals::NormalizationMethod>,
+    max_magnitudes: Vec<Option<f64>>,
+    ignored_residuals: Vec<usize>,
+}
+
+impl<T> SolverConfig<T>
+where
+    T: Iterative,
+{
+    pub(super) fn new(
+        parameters: SolverParameters,
+        iteratives: Vec<T>,
+        stopping_criterias: Vec<residuals::NormalizationMethod>,
+        update_methods: Vec<residuals::NormalizationMethod>,
+        max_magnitudes: Vec<Option<f64>>,
+        ignored_residuals: Vec<usize>,
+    ) -> Self {
+        SolverConfig {
+            parameters,
+            iteratives,
+            stopping_criterias,
+            update_methods,
+            max_magnitudes,
+            ignored_residuals,
+        }
+    }
+
+    /// Elements in the order returned by the parsers such as [super::from_xml_finite_diff]
+    #[allow(clippy::type_complexity)]
+    pub(super) fn into_tuple(
+        self,
+    ) -> (
+        SolverParameters,
+        Vec<T>,
+        Vec<residuals::NormalizationMethod>,
+        Vec<residuals::NormalizationMethod>,
+    ) {
+        (
+            self.parameters,
+            self.iteratives,
+            self.stopping_criterias,
+            self.update_methods,
+        )
+    }
+
+    pub fn get_parameters(&self) -> &SolverParameters {
+        &self.parameters
+    }
+
+    pub fn get_iteratives(&self) -> &[T] {
+        &self.iteratives
+    }
+
+    pub fn get_stopping_criterias(&self) -> &[residuals::NormalizationMethod] {
+        &self.stopping_criterias
+    }
+
+    pub fn get_update_methods(&self) -> &[residuals::NormalizationMethod] {
+        &self.update_methods
+    }
+
+    /// Maximum magnitudes of the members of the residuals, see [residuals::ResidualsConfig::set_max_magnitudes]
+    pub fn get_max_magnitudes(&self) -> &[Option<f64>] {
+        &self.max_magnitudes
+    }
+
+    /// Ignored residuals, see [residuals::ResidualsConfig::set_ignored_residuals]
+    pub fn get_ignored_residuals(&self) -> &[usize] {
+        &self.ignored_residuals
+    }
+
+    /// Wrap the iteratives, to be given to [RootFinder::new]
+    pub fn build_iteratives(&self) -> iteratives::Iteratives<'_, T> {
+        iteratives::Iteratives::new(&self.iteratives)
+    }
+
+    /// Build the residuals configuration, to be given to [RootFinder::new]
+    pub fn build_residuals_config(&self) -> residuals::ResidualsConfig<'_> {
+        let mut residuals_config =
+            residuals::ResidualsConfig::new(&self.stopping_criterias, &self.update_methods);
+        residuals_config.set_max_magnitudes(self.max_magnitudes.clone());
+        residuals_config.set_ignored_residuals(self.ignored_residuals.clone());
+        residuals_config
+    }
+
+    /// Build a [RootFinder] starting from `initial_guess` and call `f` with it, returning its result
+    ///
+    /// The solver borrows the iteratives and the residuals configuration built from the configuration,
+    /// which only live during this call: the closure performs the resolutions with it.
+    /// To keep a solver, build these elements with [SolverConfig::build_iteratives]
+    /// and [SolverConfig::build_residuals_config] and call [RootFinder::new].
+    ///
+    /// # Panics
+    ///
+    /// If the length of `initial_guess` is not the problem size, as with [RootFinder::new]
+    pub fn with_rootfinder<D, F, R>(&self, initial_guess: nalgebra::OVector<f64, D>, f: F) -> R
+    where
+        T: fmt::Display + fmt::Debug,
+        D: nalgebra::DimMin<D, Output = D>,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+        nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+        nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+        nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<D>,
+        F: FnOnce(&mut RootFinder<'_, T, D>) -> R,
+    {
+        let iteratives = self.build_iteratives();
+        let residuals_config = self.build_residuals_config();
+        let mut rf = RootFinder::new(
+            self.parameters.clone(),
+            initial_guess,
+            &iteratives,
+            &residuals_config,
+        );
+        f(&mut rf)
+    }
+}
+
+impl<T> fmt::Debug for SolverConfig<T>
+where
+    T: Iterative + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Solver configuration")
+            .field("Solver parameters", &self.parameters)
+            .field("Iteratives", &self.iteratives)
+            .field("Stopping criterias", &self.stopping_criterias)
+            .field("Update methods", &self.update_methods)
+            .field("Max magnitudes", &self.max_magnitudes)
+            .field("Ignored residuals", &self.ignored_residuals)
+            .finish()
+    }
+}
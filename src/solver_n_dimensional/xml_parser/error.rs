@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by the parsing of a xml configuration
+///
+/// Each variant holds the description of the error, which is displayed as is:
+/// - [XmlParseError::File] : a configuration file, or one of its includes, cannot be read
+/// - [XmlParseError::Syntax] : the content is not a valid xml document
+/// - [XmlParseError::Structure] : a node is missing, unexpected, duplicated or has an unexpected id
+/// - [XmlParseError::MissingAttribute] : a required attribute is not provided
+/// - [XmlParseError::InvalidValue] : the value of an attribute cannot be parsed or is not allowed
+/// - [XmlParseError::DimensionMismatch] : the number of iteratives or residuals differs from the problem size
+///
+/// The parsers of configuration files, such as [super::from_xml_finite_diff], panic with this description
+#[derive(Clone, PartialEq)]
+pub enum XmlParseError {
+    File(String),
+    Syntax(String),
+    Structure(String),
+    MissingAttribute(String),
+    InvalidValue(String),
+    DimensionMismatch(String),
+}
+
+impl fmt::Display for XmlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::File(message)
+            | Self::Syntax(message)
+            | Self::Structure(message)
+            | Self::MissingAttribute(message)
+            | Self::InvalidValue(message)
+            | Self::DimensionMismatch(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl fmt::Debug for XmlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for XmlParseError {}
@@ -0,0 +1,194 @@
+use super::XmlParseError;
+use minidom::Element;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Nodes that can be provided by an included file, in their expected order
+const INCLUDABLE_NODES: [&str; 3] = ["solver", "iteratives", "residuals"];
+
+/// Read the root node of a configuration file, with its includes resolved
+///
+/// The `<include file="..."/>` nodes are resolved relative to the directory of the including file.
+/// The nodes of the included files are merged in their order of inclusion,
+/// then the nodes of the including file are merged, whatever their position relative to the includes:
+/// - the attributes of a node override the ones of the same node from the previous files
+/// - the children of a node replace the ones of the same node from the previous files
+///
+/// A node provided twice by the same file is an error, as well as a cyclic include,
+/// the include stack being reported in the error message.
+pub fn parse_root_node_with_includes(filepath: &str) -> Result<Element, XmlParseError> {
+    let mut include_stack = Vec::new();
+    resolve_includes(Path::new(filepath), &mut include_stack)
+}
+
+/// Parse the root node of a configuration provided as a string, with its includes resolved
+///
+/// The included files are resolved relative to the current directory
+pub fn parse_root_node_from_str_with_includes(content: &str) -> Result<Element, XmlParseError> {
+    let mut include_stack = Vec::new();
+    let root = parse_root_node(content, "the configuration", &include_stack)?;
+    merge_includes(&root, Path::new(""), &mut include_stack)
+}
+
+fn resolve_includes(
+    filepath: &Path,
+    include_stack: &mut Vec<PathBuf>,
+) -> Result<Element, XmlParseError> {
+    let canonical_path = fs::canonicalize(filepath).map_err(|error| {
+        XmlParseError::File(format!(
+            "Unable to read the configuration file {}: {}{}",
+            filepath.display(),
+            error,
+            format_include_stack(include_stack)
+        ))
+    })?;
+
+    if include_stack.contains(&canonical_path) {
+        include_stack.push(canonical_path);
+        return Err(XmlParseError::File(format!(
+            "Cyclic include of the configuration file {}{}",
+            filepath.display(),
+            format_include_stack(include_stack)
+        )));
+    }
+    include_stack.push(canonical_path);
+
+    let content = fs::read_to_string(filepath).map_err(|error| {
+        XmlParseError::File(format!(
+            "Unable to read the configuration file {}: {}{}",
+            filepath.display(),
+            error,
+            format_include_stack(include_stack)
+        ))
+    })?;
+    let root = parse_root_node(
+        &content,
+        // only formatted if the content is invalid
+        &format_args!("the configuration file {}", filepath.display()),
+        include_stack,
+    )?;
+
+    let directory = filepath.parent().unwrap_or_else(|| Path::new(""));
+    let merged_root = merge_includes(&root, directory, include_stack)?;
+    include_stack.pop();
+    Ok(merged_root)
+}
+
+fn parse_root_node(
+    content: &str,
+    origin: &(impl fmt::Display + ?Sized),
+    include_stack: &[PathBuf],
+) -> Result<Element, XmlParseError> {
+    let root: Element = content.parse().map_err(|error| {
+        XmlParseError::Syntax(format!(
+            "Invalid xml in {}: {}{}",
+            origin,
+            error,
+            format_include_stack(include_stack)
+        ))
+    })?;
+    if root.name() != "nrf" {
+        return Err(XmlParseError::Structure(format!(
+            "Expected the first node to be \"nrf\", got {}{}",
+            root.name(),
+            format_include_stack(include_stack)
+        )));
+    }
+    Ok(root)
+}
+
+fn merge_includes(
+    root: &Element,
+    directory: &Path,
+    include_stack: &mut Vec<PathBuf>,
+) -> Result<Element, XmlParseError> {
+    let mut nodes: [Option<Element>; 3] = [None, None, None];
+
+    for include_node in root.children().filter(|node| node.name() == "include") {
+        let included_file = include_node.attr("file").ok_or_else(|| {
+            XmlParseError::MissingAttribute(format!(
+                "The attribute \"file\" is missing in the include node{}",
+                format_include_stack(include_stack)
+            ))
+        })?;
+        let included_root = resolve_includes(&directory.join(included_file), include_stack)?;
+        for (position, node) in included_root.children().enumerate() {
+            merge_node(&mut nodes, node, position, include_stack)?;
+        }
+    }
+
+    let mut positions: [Option<usize>; 3] = [None, None, None];
+    for (position, node) in root.children().enumerate() {
+        if node.name() == "include" {
+            continue;
+        }
+        let index = merge_node(&mut nodes, node, position, include_stack)?;
+        if let Some(previous_position) = positions[index].replace(position) {
+            return Err(XmlParseError::Structure(format!(
+                "The node {} below nrf is a duplicate of the node {}, got {} twice{}",
+                position,
+                previous_position,
+                node.name(),
+                format_include_stack(include_stack)
+            )));
+        }
+    }
+
+    let mut merged_root = Element::bare("nrf");
+    for node in nodes.into_iter().flatten() {
+        merged_root.append_child(node);
+    }
+    Ok(merged_root)
+}
+
+fn merge_node(
+    nodes: &mut [Option<Element>; 3],
+    node: &Element,
+    position: usize,
+    include_stack: &[PathBuf],
+) -> Result<usize, XmlParseError> {
+    let index = INCLUDABLE_NODES
+        .iter()
+        .position(|name| *name == node.name())
+        .ok_or_else(|| {
+            XmlParseError::Structure(format!(
+                "The node {} below nrf is expected to be \"include\", \"solver\", \"iteratives\" or \"residuals\", got {}{}",
+                position,
+                node.name(),
+                format_include_stack(include_stack)
+            ))
+        })?;
+
+    nodes[index] = Some(match nodes[index].take() {
+        None => node.clone(),
+        Some(previous) => {
+            let mut merged = Element::bare(node.name());
+            for (name, value) in previous.attrs().chain(node.attrs()) {
+                merged.set_attr(name, value);
+            }
+            let children = if node.children().next().is_some() {
+                node.children()
+            } else {
+                previous.children()
+            };
+            for child in children {
+                merged.append_child(child.clone());
+            }
+            merged
+        }
+    });
+    Ok(index)
+}
+
+fn format_include_stack(include_stack: &[PathBuf]) -> String {
+    if include_stack.len() < 2 {
+        return String::new();
+    }
+
+    let files: Vec<String> = include_stack
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    format!(", include stack: {}", files.join(" -> "))
+}
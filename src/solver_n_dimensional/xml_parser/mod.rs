@@ -2,8 +2,11 @@
 //!
 //! # Parser
 //! A parser to an xml configuration file is provided to ease the construction of the parameters:
-//! - [from_xml_finite_diff()]
-//! - [from_xml_jacobian()]
+//! - [load_xml_fd()]
+//! - [load_xml_jac()]
+//!
+//! They return a [SolverConfig], building the iteratives, the residuals configuration and the solver.
+//! The parsers [from_xml_finite_diff()] and [from_xml_jacobian()] return the same elements as a tuple.
 //!
 //! For the meaning of each parameters, please refer to the documentation of the related module:
 //! - solver: [crate::solver::SolverParameters]
@@ -29,19 +32,204 @@
 //! </nrf>
 //! ```
 //!
+//! The nrf node must contain exactly the solver, iteratives and residuals nodes, in this order,
+//! and the iteratives and residuals nodes only iterative and residual nodes.
+//! Any other node makes the parser panic, the error message giving the index of the node below its parent.
+//! Comments are allowed anywhere.
+//!
 //! The values provided in the iteratives and residuals nodes will act as default values.
 //!
 //! These values are taken into account only if none are provided for a given iterative or residual
 //!
+//! The `RelToReference` normalization requires a `reference` attribute,
+//! either on the residual node or on the residuals node as default value:
+//!
+//! ```xml
+//! <residual id="0" stopping_criteria="RelToReference" update_method="Abs" reference="1e5"/>
+//! ```
+//!
+//! The `AdaptScaled` normalization is given with its scale after the `Adapt` value:
+//!
+//! ```xml
+//! <residual id="0" stopping_criteria="Adapt:1e5" update_method="Adapt:1e5"/>
+//! ```
+//!
+//! The denominator of the `Rel` normalization can be bounded below with the optional `rel_floor` attribute,
+//! either on the residual node or on the residuals node as default value,
+//! see [crate::residuals::ResidualConfig::set_rel_floor]:
+//!
+//! ```xml
+//! <residual id="0" stopping_criteria="Rel" update_method="Rel" rel_floor="1e-3"/>
+//! ```
+//!
+//! The left and right members of a residual can be bounded with the optional `max_magnitude` attribute,
+//! either on the residual node or on the residuals node as default value,
+//! see [crate::residuals::ResidualsConfig::set_max_magnitudes]:
+//!
+//! ```xml
+//! <residual id="0" stopping_criteria="Abs" update_method="Abs" max_magnitude="1e30"/>
+//! ```
+//!
+//! An iterative can require its last step to be below a tolerance to reach the convergence
+//! with the optional `step_tolerance` attribute, see [crate::iteratives::IterativeParams::set_convergence_step_tolerance]:
+//!
+//! ```xml
+//! <iterative id="0" step_tolerance="1e-6"/>
+//! ```
+//!
+//! The steps increasing and decreasing an iterative can be limited differently
+//! with the optional `max_step_abs_up`, `max_step_abs_down`, `max_step_rel_up` and `max_step_rel_down` attributes,
+//! the unset ones being given by `max_step_abs` and `max_step_rel`,
+//! see [crate::iteratives::IterativeParams::set_max_step_abs_up]:
+//!
+//! ```xml
+//! <iterative id="0" max_step_abs="100" max_step_abs_up="10"/>
+//! ```
+//!
+//! With finite differences, the direction of the perturbation of an iterative is set
+//! with the optional `perturbation_sign` attribute, taking the values `Positive` (default),
+//! `Negative` and `AwayFromNearestBound`, see [crate::iteratives::PerturbationSign]:
+//!
+//! ```xml
+//! <iterative id="0" max_value="1" perturbation_sign="AwayFromNearestBound"/>
+//! ```
+//!
+//! An iterative can be fixed at a value with the optional `fixed` attribute of the iterative node,
+//! see [crate::iteratives::IterativeParams::set_fixed].
+//! As many residuals must be excluded from the system with the optional `ignored` attribute of the residual node,
+//! see [crate::residuals::ResidualsConfig::set_ignored_residuals]:
+//!
+//! ```xml
+//! <iterative id="2" fixed="3.14"/>
+//! <residual id="2" ignored="true"/>
+//! ```
+//!
+//! The Newton-Raphson iterations performed before a quasi-Newton method are set
+//! with the optional `newton_warmup` attribute of the solver node,
+//! see [crate::solver::SolverParameters::set_newton_warmup_iterations]:
+//!
+//! ```xml
+//! <solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="SN" newton_warmup="2"/>
+//! ```
+//!
+//! The parameters of a preset, see [crate::solver::presets], are used with the optional `preset` attribute
+//! of the solver node, taking the values `robust`, `fast_smooth` and `expensive_model`.
+//! The `max_iter`, `tolerance` and `resolution_method` attributes are then optional,
+//! and they override the values of the preset with the `damping` and `newton_warmup` attributes:
+//!
+//! ```xml
+//! <solver problem_size="3" preset="robust" max_iter="30"/>
+//! ```
+//!
+//! The other options of [crate::solver::SolverParameters] are set with optional attributes of the solver node,
+//! their default values being the ones of the programmatic API:
+//!
+//! | Attribute                       | Values                                                  | Setter of `SolverParameters`       |
+//! |---------------------------------|---------------------------------------------------------|------------------------------------|
+//! | `max_model_evaluations`         | positive integer                                        | `set_max_model_evaluations()`      |
+//! | `reject_solution_on_bounds`     | `true`, `false`                                         | `set_reject_solution_on_bounds()`  |
+//! | `bound_policy`                  | `Clamp`, `Fail`, `Ignore`                               | `set_bound_policy()`               |
+//! | `return_best_on_failure`        | `true`, `false`                                         | `set_return_best_on_failure()`     |
+//! | `aitken_every`                  | strictly positive integer                               | `set_aitken_acceleration()`        |
+//! | `first_iteration_step_scale`    | strictly positive float                                 | `set_first_iteration_step_scale()` |
+//! | `linear_solver`                 | `DirectInverse`, `Gmres`                                | `set_linear_solver()`              |
+//! | `inaccurate_jacobian_policy`    | `Accept`, `RetryColumnWithReducedStep`, `Fail`          | `set_inaccurate_jacobian_policy()` |
+//! | `structural_singularity_policy` | `Fail`, `Reduce`                                        | `set_structural_singularity_policy()` |
+//! | `perturbation_jitter`           | float in `]0, 1[`                                       | `set_perturbation_jitter()`        |
+//! | `cycle_detection`               | `DampedAverage`, `DampedHalfStep`, `RecomputeJacobian`  | `set_cycle_detection()`            |
+//! | `step_acceptance_norm`          | `Stopping`, `Update`, `Both`                            | `set_step_acceptance_norm()`       |
+//! | `memory_limit_bytes`            | positive integer                                        | `set_memory_limit_bytes()`         |
+//! | `error_smoothing`               | float in `]0, 1]`                                       | `set_error_smoothing()`            |
+//! | `consecutive_convergences`      | strictly positive integer                               | `set_consecutive_convergences()`   |
+//! | `scalar_safeguard`              | `true`, `false`                                         | `set_scalar_safeguard()`           |
+//! | `scalar_safeguard_refinements`  | strictly positive integer                               | `set_scalar_safeguard_refinements()` |
+//!
+//! The `Gmres` linear solver requires the `gmres_restart`, `gmres_max_iter` and `gmres_tol` attributes,
+//! and the `RetryColumnWithReducedStep` policy the `max_retries` attribute:
+//!
+//! ```xml
+//! <solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="NR"
+//!         linear_solver="Gmres" gmres_restart="10" gmres_max_iter="50" gmres_tol="1e-10"
+//!         inaccurate_jacobian_policy="RetryColumnWithReducedStep" max_retries="3"/>
+//! ```
+//!
+//! # Include
+//!
+//! The nodes shared by several configuration files can be gathered in a file included by the others,
+//! its path being relative to the directory of the including file:
+//!
+//! ```xml
+//! <?xml version="1.0" encoding="UTF-8" standalone="no" ?>
+//! <nrf>
+//!     <include file="common_defaults.xml"/>
+//!     <solver max_iter="30"/>
+//!     <iteratives>
+//!         <iterative id="0"/>
+//!         <iterative id="1" min_value="0"/>
+//!     </iteratives>
+//!     <residuals>
+//!         <residual id="0"/>
+//!         <residual id="1" stopping_criteria="Adapt"/>
+//!     </residuals>
+//! </nrf>
+//! ```
+//!
+//! The included file has the same structure, each of its nodes being optional.
+//! It usually provides the solver node and the default values of the iteratives and residuals nodes.
+//!
+//! The nodes of the including file are merged with the ones of the included files:
+//! - an attribute of the including file overrides the one of the included file
+//! - the iterative and residual children of the including file replace the ones of the included file
+//!
+//! Several files can be included, the last one taking precedence, and an included file can include other files.
+//! A node provided twice by the same file or a cyclic include makes the parser panic,
+//! the stack of the included files being given in the error message.
+//!
+//! # Embedded configuration
+//!
+//! A configuration embedded in the binary with `include_str!` is parsed with
+//! [load_xml_fd_str()] or [load_xml_jac_str()], which return a [XmlParseError] instead of panicking,
+//! as [from_xml_finite_diff_str()] and [from_xml_jacobian_str()].
+//! Its includes are resolved relative to the current directory, hence an embedded configuration should not rely on them.
+//!
+//! To catch the errors before the binary is shipped, [validate()] parses and cross-checks the configuration,
+//! without any model, and returns a [ConfigSummary].
+//! The kind of iteratives given to it selects the parser the configuration is intended for.
+//! It can be called from a test or from a build script,
+//! with `newton_rootfinder` as build dependency with the `xml_config_file` feature:
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     println!("cargo:rerun-if-changed=config/solver.xml");
+//!     let content = std::fs::read_to_string("config/solver.xml").unwrap();
+//!     let kind = newton_rootfinder::solver::IterativesKind::WithFiniteDifference;
+//!     if let Err(error) = newton_rootfinder::xml_parser::validate(&content, kind) {
+//!         panic!("Invalid solver configuration config/solver.xml: {}", error);
+//!     }
+//! }
+//! ```
+//!
+//! The `embedded_config` example embeds its configuration and checks it in a test.
+//!
 
+mod config;
+mod error;
+mod include;
 mod node_iterative;
 mod node_iterative_fd;
 mod node_iterative_jac;
 mod node_residual;
 mod node_solver;
 mod util;
+mod validate;
 mod xml_file_fd;
 mod xml_file_jac;
 
-pub use xml_file_fd::from_xml_finite_diff;
-pub use xml_file_jac::from_xml_jacobian;
+pub use config::SolverConfig;
+pub use error::XmlParseError;
+pub use validate::{validate, ConfigSummary};
+pub use xml_file_fd::{
+    from_xml_finite_diff, from_xml_finite_diff_str, load_xml_fd, load_xml_fd_str,
+};
+pub use xml_file_jac::{from_xml_jacobian, from_xml_jacobian_str, load_xml_jac, load_xml_jac_str};
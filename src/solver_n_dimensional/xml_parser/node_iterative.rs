@@ -1,31 +1,52 @@
+use super::XmlParseError;
 use crate::iteratives;
 use minidom::Element;
+use std::fmt;
 
 pub fn parse_perturbation_method(
     node: &Element,
-    node_info: &str,
-) -> iteratives::PerturbationMethod {
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::PerturbationMethod, XmlParseError> {
     match node
             .attr("perturbation_method")
-            .unwrap_or_else(|| panic!("The attribute \"perturbation_method\" is missing in {}", node_info)) {
-                "Max" => iteratives::PerturbationMethod::Max,
-                "Sum" => iteratives::PerturbationMethod::Sum,
-                _     => panic!("The attribute \"perturbation_method\" at the {} has an improper values, valid values are \"Sum\" and \"Max\"", node_info),
+            .ok_or_else(|| XmlParseError::MissingAttribute(format!("The attribute \"perturbation_method\" is missing in {}", node_info)))? {
+                "Max" => Ok(iteratives::PerturbationMethod::Max),
+                "Sum" => Ok(iteratives::PerturbationMethod::Sum),
+                "Quadrature" => Ok(iteratives::PerturbationMethod::Quadrature),
+                _     => Err(XmlParseError::InvalidValue(format!("The attribute \"perturbation_method\" at the {} has an improper values, valid values are \"Sum\", \"Max\" and \"Quadrature\"", node_info))),
             }
 }
 
 pub fn parse_perturbation_method_with_default(
     node: &Element,
     default: iteratives::PerturbationMethod,
-    node_info: &str,
-) -> iteratives::PerturbationMethod {
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::PerturbationMethod, XmlParseError> {
     match node
             .attr("perturbation_method") {
-                None => default,
+                None => Ok(default),
                 Some(value) => match value {
-                    "Max" => iteratives::PerturbationMethod::Max,
-                    "Sum" => iteratives::PerturbationMethod::Sum,
-                    _     => panic!("The attribute \"perturbation_method\" at the {} has an improper values, valid values are \"Sum\" and \"Max\"", node_info),
+                    "Max" => Ok(iteratives::PerturbationMethod::Max),
+                    "Sum" => Ok(iteratives::PerturbationMethod::Sum),
+                    "Quadrature" => Ok(iteratives::PerturbationMethod::Quadrature),
+                    _     => Err(XmlParseError::InvalidValue(format!("The attribute \"perturbation_method\" at the {} has an improper values, valid values are \"Sum\", \"Max\" and \"Quadrature\"", node_info))),
+                },
+            }
+}
+
+pub fn parse_perturbation_sign_with_default(
+    node: &Element,
+    default: iteratives::PerturbationSign,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::PerturbationSign, XmlParseError> {
+    match node
+            .attr("perturbation_sign") {
+                None => Ok(default),
+                Some(value) => match value {
+                    "Positive" => Ok(iteratives::PerturbationSign::Positive),
+                    "Negative" => Ok(iteratives::PerturbationSign::Negative),
+                    "AwayFromNearestBound" => Ok(iteratives::PerturbationSign::AwayFromNearestBound),
+                    _     => Err(XmlParseError::InvalidValue(format!("The attribute \"perturbation_sign\" at the {} has an improper values, valid values are \"Positive\", \"Negative\" and \"AwayFromNearestBound\"", node_info))),
                 },
             }
 }
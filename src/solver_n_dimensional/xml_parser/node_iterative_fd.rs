@@ -1,75 +1,119 @@
+use super::XmlParseError;
 use crate::iteratives;
 use minidom::Element;
+use std::fmt;
 
-pub fn parse_iteratives_fd_node(iteratives_node: &Element) -> Vec<iteratives::IterativeParamsFD> {
+pub fn parse_iteratives_fd_node(
+    iteratives_node: &Element,
+) -> Result<Vec<iteratives::IterativeParamsFD>, XmlParseError> {
     let mut iteratives = Vec::new();
 
-    let iterative_fd_default = parse_iterative_fd_node(iteratives_node, "iteratives node");
+    let iterative_fd_default = parse_iterative_fd_node(iteratives_node, "iteratives node")?;
 
     for (expected_id, iterative_node) in iteratives_node.children().enumerate() {
-        if iterative_node.name() != "iterative" {
-            panic!(
-                "Node below iteratives are expected to be \"iterative\", got {}",
-                iterative_node.name()
-            );
-        }
-        let id = super::util::parse_id(iterative_node, expected_id, "iterative node");
-        let node_info = format!("iterative node id = {}", id);
-        let iterative =
-            parse_iterative_fd_node_with_default(iterative_node, &iterative_fd_default, &node_info);
+        super::util::check_child_name(iterative_node, "iterative", expected_id, "iteratives")?;
+        let id = super::util::parse_id(iterative_node, expected_id, "iterative node")?;
+        let node_info = super::util::NodeInfo {
+            name: "iterative",
+            id,
+        };
+        let iterative = parse_iterative_fd_node_with_default(
+            iterative_node,
+            &iterative_fd_default,
+            &node_info,
+        )?;
 
         iteratives.push(iterative);
     }
 
-    iteratives
+    Ok(iteratives)
 }
 
 fn parse_iterative_fd_node(
     iterative_node: &Element,
-    node_info: &str,
-) -> iteratives::IterativeParamsFD {
-    let iterative = super::node_iterative_jac::parse_iterative_jac_node(iterative_node, node_info);
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::IterativeParamsFD, XmlParseError> {
+    let iterative = super::node_iterative_jac::parse_iterative_jac_node(iterative_node, node_info)?;
 
-    let dx_abs = super::util::parse_float_attribute(iterative_node, "dx_abs", node_info);
-    let dx_rel = super::util::parse_float_attribute(iterative_node, "dx_rel", node_info);
+    let dx_abs = super::util::parse_float_attribute(iterative_node, "dx_abs", node_info)?;
+    let dx_rel = super::util::parse_float_attribute(iterative_node, "dx_rel", node_info)?;
 
     let perturbation_method =
-        super::node_iterative::parse_perturbation_method(iterative_node, node_info);
-
-    iteratives::IterativeParamsFD::extend(iterative, dx_abs, dx_rel, perturbation_method)
+        super::node_iterative::parse_perturbation_method(iterative_node, node_info)?;
+    let perturbation_sign = super::node_iterative::parse_perturbation_sign_with_default(
+        iterative_node,
+        iteratives::PerturbationSign::default(),
+        node_info,
+    )?;
+
+    extend_iterative(
+        iterative,
+        dx_abs,
+        dx_rel,
+        perturbation_method,
+        perturbation_sign,
+    )
 }
 
 fn parse_iterative_fd_node_with_default(
     iterative_node: &Element,
     iterative_default: &iteratives::IterativeParamsFD,
-    node_info: &str,
-) -> iteratives::IterativeParamsFD {
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::IterativeParamsFD, XmlParseError> {
     let iterative = super::node_iterative_jac::parse_iterative_jac_node_with_default(
         iterative_node,
         iterative_default.get_iterative_params(),
         node_info,
-    );
+    )?;
 
     let dx_abs = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_dx_abs(),
         "dx_abs",
         node_info,
-    );
+    )?;
     let dx_rel = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_dx_rel(),
         "dx_rel",
         node_info,
-    );
+    )?;
 
     let perturbation_method = super::node_iterative::parse_perturbation_method_with_default(
         iterative_node,
         iterative_default.get_perturbation_method(),
         node_info,
-    );
+    )?;
+    let perturbation_sign = super::node_iterative::parse_perturbation_sign_with_default(
+        iterative_node,
+        iterative_default.get_perturbation_sign(),
+        node_info,
+    )?;
+
+    extend_iterative(
+        iterative,
+        dx_abs,
+        dx_rel,
+        perturbation_method,
+        perturbation_sign,
+    )
+}
 
-    iteratives::IterativeParamsFD::extend(iterative, dx_abs, dx_rel, perturbation_method)
+/// Extend the parameters of an iterative, with the checks of the constructor reported as errors
+fn extend_iterative(
+    iterative: iteratives::IterativeParams,
+    dx_abs: f64,
+    dx_rel: f64,
+    perturbation_method: iteratives::PerturbationMethod,
+    perturbation_sign: iteratives::PerturbationSign,
+) -> Result<iteratives::IterativeParamsFD, XmlParseError> {
+    iteratives::IterativeParamsFD::check_perturbations(dx_abs, dx_rel)
+        .map_err(XmlParseError::InvalidValue)?;
+
+    let mut iterative =
+        iteratives::IterativeParamsFD::extend(iterative, dx_abs, dx_rel, perturbation_method);
+    iterative.set_perturbation_sign(perturbation_sign);
+    Ok(iterative)
 }
 
 #[cfg(test)]
@@ -81,10 +125,10 @@ mod tests {
 
     #[test]
     fn parsing_iterative_fd_node_1() {
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max"/>"#;
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
-        let iterative = parse_iterative_fd_node(&iterative_node, &node_info);
+        let iterative = parse_iterative_fd_node(&iterative_node, node_info).unwrap();
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             10.0,
@@ -100,7 +144,7 @@ mod tests {
 
     #[test]
     fn parsing_iterative_fd_node_2() {
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max"/>"#;
         let iterative_default = iteratives::IterativeParamsFD::new(
             f64::INFINITY,
             f64::INFINITY,
@@ -113,7 +157,8 @@ mod tests {
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
         let iterative =
-            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             10.0,
@@ -129,10 +174,10 @@ mod tests {
 
     #[test]
     fn parsing_iterative_fd_node_3() {
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Sum"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Sum"/>"#;
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
-        let iterative = parse_iterative_fd_node(&iterative_node, &node_info);
+        let iterative = parse_iterative_fd_node(&iterative_node, node_info).unwrap();
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             10.0,
@@ -148,7 +193,7 @@ mod tests {
 
     #[test]
     fn parsing_iterative_fd_node_4() {
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Sum"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Sum"/>"#;
         let iterative_default = iteratives::IterativeParamsFD::new(
             f64::INFINITY,
             f64::INFINITY,
@@ -161,7 +206,8 @@ mod tests {
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
         let iterative =
-            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             10.0,
@@ -177,7 +223,7 @@ mod tests {
 
     #[test]
     fn parsing_iterative_fd_node_5() {
-        const DATA: &'static str = r#"<iterative id="0"/>"#;
+        const DATA: &str = r#"<iterative id="0"/>"#;
         let iterative_default = iteratives::IterativeParamsFD::new(
             f64::INFINITY,
             f64::INFINITY,
@@ -190,13 +236,14 @@ mod tests {
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
         let iterative =
-            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         assert_eq!(iterative, iterative_default);
     }
     #[test]
     fn parsing_iterative_fd_node_6() {
-        const DATA: &'static str =
+        const DATA: &str =
             r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" perturbation_method="Sum"/>"#;
         let iterative_default = iteratives::IterativeParamsFD::new(
             f64::INFINITY,
@@ -210,7 +257,8 @@ mod tests {
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
         let iterative =
-            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             10.0,
@@ -226,18 +274,18 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "The attribute \"perturbation_method\" at the iterative node id = 0 has an improper values, valid values are \"Sum\" and \"Max\""
+        expected = "The attribute \"perturbation_method\" at the iterative node id = 0 has an improper values, valid values are \"Sum\", \"Max\" and \"Quadrature\""
     )]
     fn parsing_iterative_fd_node_7() {
         let node_info = "iterative node id = 0";
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="max"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="max"/>"#;
         let iterative_node: Element = DATA.parse().unwrap();
-        let _iterative = parse_iterative_fd_node(&iterative_node, &node_info);
+        let _iterative = parse_iterative_fd_node(&iterative_node, node_info).unwrap();
     }
 
     #[test]
     #[should_panic(
-        expected = "The attribute \"perturbation_method\" at the iterative node id = 0 has an improper values, valid values are \"Sum\" and \"Max\""
+        expected = "The attribute \"perturbation_method\" at the iterative node id = 0 has an improper values, valid values are \"Sum\", \"Max\" and \"Quadrature\""
     )]
     fn parsing_iterative_fd_node_8() {
         let iterative_default = iteratives::IterativeParamsFD::new(
@@ -250,23 +298,75 @@ mod tests {
             iteratives::PerturbationMethod::Max,
         );
         let node_info = "iterative node id = 0";
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="max"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="max"/>"#;
         let iterative_node: Element = DATA.parse().unwrap();
         let _iterative =
-            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
+    }
+
+    #[test]
+    fn parsing_iterative_fd_node_perturbation_sign() {
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="1" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max" perturbation_sign="AwayFromNearestBound"/>"#;
+        let node_info = "iterative node id = 0";
+        let iterative_node: Element = DATA.parse().unwrap();
+        let iterative = parse_iterative_fd_node(&iterative_node, node_info).unwrap();
+
+        assert_eq!(
+            iterative.get_perturbation_sign(),
+            iteratives::PerturbationSign::AwayFromNearestBound
+        );
+    }
+
+    #[test]
+    fn parsing_iterative_fd_node_perturbation_sign_with_default() {
+        let mut iterative_default = iteratives::IterativeParamsFD::default();
+        iterative_default.set_perturbation_sign(iteratives::PerturbationSign::Negative);
+        let node_info = "iterative node id = 0";
+
+        let iterative_node: Element = r#"<iterative id="0"/>"#.parse().unwrap();
+        let iterative =
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
+        assert_eq!(
+            iterative.get_perturbation_sign(),
+            iteratives::PerturbationSign::Negative
+        );
+
+        let iterative_node: Element = r#"<iterative id="0" perturbation_sign="Positive"/>"#
+            .parse()
+            .unwrap();
+        let iterative =
+            parse_iterative_fd_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
+        assert_eq!(
+            iterative.get_perturbation_sign(),
+            iteratives::PerturbationSign::Positive
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"perturbation_sign\" at the iterative node id = 0 has an improper values, valid values are \"Positive\", \"Negative\" and \"AwayFromNearestBound\""
+    )]
+    fn parsing_iterative_fd_node_perturbation_sign_invalid() {
+        let node_info = "iterative node id = 0";
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" dx_abs="0.1" dx_rel="0.2" perturbation_method="Max" perturbation_sign="negative"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let _iterative = parse_iterative_fd_node(&iterative_node, node_info).unwrap();
     }
 }
 
 #[test]
 fn parsing_iteratives_fd_node_1() {
-    const DATA: &'static str = r#"
+    const DATA: &str = r#"
             <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-8" perturbation_method="Max">
                 <iterative id="0"/>
                 <iterative id="1"/>
                 <iterative id="2"/>
             </iteratives>"#;
     let iteratives_node: Element = DATA.parse().unwrap();
-    let iteratives = parse_iteratives_fd_node(&iteratives_node);
+    let iteratives = parse_iteratives_fd_node(&iteratives_node).unwrap();
 
     let iterative_ref = iteratives::IterativeParamsFD::new(
         f64::INFINITY,
@@ -284,14 +384,14 @@ fn parsing_iteratives_fd_node_1() {
 
 #[test]
 fn parsing_iteratives_fd_node_2() {
-    const DATA: &'static str = r#"
+    const DATA: &str = r#"
             <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-8" perturbation_method="Max">
                 <iterative id="0" max_step_abs="10" max_step_rel="0.5" min_value="10" max_value="100" dx_abs="3e-8" dx_rel="8e-8" perturbation_method="Max"/>
                 <iterative id="1" max_value="0" dx_abs="1.5e-8" dx_rel="2e-8" perturbation_method="Sum"/>
                 <iterative id="2" max_value="inf" dx_abs="1.5e-8" dx_rel="2e-8" perturbation_method="Sum"/>
             </iteratives>"#;
     let iteratives_node: Element = DATA.parse().unwrap();
-    let iteratives = parse_iteratives_fd_node(&iteratives_node);
+    let iteratives = parse_iteratives_fd_node(&iteratives_node).unwrap();
 
     let iterative1_ref = iteratives::IterativeParamsFD::new(
         10.0,
@@ -327,3 +427,28 @@ fn parsing_iteratives_fd_node_2() {
 
     assert_eq!(iteratives, iteratives_ref);
 }
+
+#[test]
+fn parsing_iteratives_fd_node_quadrature() {
+    const DATA: &str = r#"
+            <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-8" perturbation_method="Quadrature">
+                <iterative id="0"/>
+                <iterative id="1" perturbation_method="Max"/>
+                <iterative id="2" perturbation_method="Quadrature"/>
+            </iteratives>"#;
+    let iteratives_node: Element = DATA.parse().unwrap();
+    let iteratives = parse_iteratives_fd_node(&iteratives_node).unwrap();
+
+    let perturbation_methods: Vec<iteratives::PerturbationMethod> = iteratives
+        .iter()
+        .map(|iterative| iterative.get_perturbation_method())
+        .collect();
+    assert_eq!(
+        perturbation_methods,
+        vec![
+            iteratives::PerturbationMethod::Quadrature,
+            iteratives::PerturbationMethod::Max,
+            iteratives::PerturbationMethod::Quadrature,
+        ]
+    );
+}
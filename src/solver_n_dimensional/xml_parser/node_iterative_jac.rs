@@ -1,74 +1,180 @@
+use super::XmlParseError;
 use crate::iteratives;
 use minidom::Element;
+use std::fmt;
 
-pub fn parse_iteratives_jac_node(iteratives_node: &Element) -> Vec<iteratives::IterativeParams> {
+pub fn parse_iteratives_jac_node(
+    iteratives_node: &Element,
+) -> Result<Vec<iteratives::IterativeParams>, XmlParseError> {
     let mut iteratives = Vec::new();
 
-    let iterative_default = parse_iterative_jac_node(iteratives_node, "iteratives node");
+    let iterative_default = parse_iterative_jac_node(iteratives_node, "iteratives node")?;
 
     for (expected_id, iterative_node) in iteratives_node.children().enumerate() {
-        if iterative_node.name() != "iterative" {
-            panic!(
-                "Node below iteratives are expected to be \"iterative\", got {}",
-                iterative_node.name()
-            );
-        }
-        let id = super::util::parse_id(iterative_node, expected_id, "iterative node");
-        let node_info = format!("iterative node id = {}", id);
+        super::util::check_child_name(iterative_node, "iterative", expected_id, "iteratives")?;
+        let id = super::util::parse_id(iterative_node, expected_id, "iterative node")?;
+        let node_info = super::util::NodeInfo {
+            name: "iterative",
+            id,
+        };
         let iterative =
-            parse_iterative_jac_node_with_default(iterative_node, &iterative_default, &node_info);
+            parse_iterative_jac_node_with_default(iterative_node, &iterative_default, &node_info)?;
 
         iteratives.push(iterative);
     }
 
-    iteratives
+    Ok(iteratives)
 }
 
 pub fn parse_iterative_jac_node(
     iterative_node: &Element,
-    node_info: &str,
-) -> iteratives::IterativeParams {
-    let min_value = super::util::parse_float_attribute(iterative_node, "min_value", node_info);
-    let max_value = super::util::parse_float_attribute(iterative_node, "max_value", node_info);
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::IterativeParams, XmlParseError> {
+    let min_value = super::util::parse_float_attribute(iterative_node, "min_value", node_info)?;
+    let max_value = super::util::parse_float_attribute(iterative_node, "max_value", node_info)?;
     let max_step_abs =
-        super::util::parse_float_attribute(iterative_node, "max_step_abs", node_info);
+        super::util::parse_float_attribute(iterative_node, "max_step_abs", node_info)?;
     let max_step_rel =
-        super::util::parse_float_attribute(iterative_node, "max_step_rel", node_info);
+        super::util::parse_float_attribute(iterative_node, "max_step_rel", node_info)?;
+    let directional_max_steps = parse_directional_max_steps(iterative_node, None, node_info)?;
+    let step_tolerance =
+        parse_optional_float_attribute(iterative_node, "step_tolerance", node_info)?;
 
-    iteratives::IterativeParams::new(max_step_abs, max_step_rel, min_value, max_value)
+    new_iterative(
+        max_step_abs,
+        max_step_rel,
+        min_value,
+        max_value,
+        directional_max_steps,
+        step_tolerance,
+        None,
+    )
 }
 
 pub fn parse_iterative_jac_node_with_default(
     iterative_node: &Element,
     iterative_default: &iteratives::IterativeParams,
-    node_info: &str,
-) -> iteratives::IterativeParams {
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<iteratives::IterativeParams, XmlParseError> {
     let min_value = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_min_value(),
         "min_value",
         node_info,
-    );
+    )?;
     let max_value = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_max_value(),
         "max_value",
         node_info,
-    );
+    )?;
     let max_step_abs = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_max_step_abs(),
         "max_step_abs",
         node_info,
-    );
+    )?;
     let max_step_rel = super::util::parse_float_attribute_with_default(
         iterative_node,
         iterative_default.get_max_step_rel(),
         "max_step_rel",
         node_info,
-    );
+    )?;
+    let directional_max_steps =
+        parse_directional_max_steps(iterative_node, Some(iterative_default), node_info)?;
+    let step_tolerance =
+        parse_optional_float_attribute(iterative_node, "step_tolerance", node_info)?
+            .or(iterative_default.get_convergence_step_tolerance());
+    // specific to each iterative, not inherited from the iteratives node
+    let fixed = parse_optional_float_attribute(iterative_node, "fixed", node_info)?;
 
-    iteratives::IterativeParams::new(max_step_abs, max_step_rel, min_value, max_value)
+    new_iterative(
+        max_step_abs,
+        max_step_rel,
+        min_value,
+        max_value,
+        directional_max_steps,
+        step_tolerance,
+        fixed,
+    )
+}
+
+/// Build the parameters of an iterative, with the checks of the constructor reported as errors
+fn new_iterative(
+    max_step_abs: f64,
+    max_step_rel: f64,
+    min_value: f64,
+    max_value: f64,
+    directional_max_steps: [Option<f64>; 4],
+    step_tolerance: Option<f64>,
+    fixed: Option<f64>,
+) -> Result<iteratives::IterativeParams, XmlParseError> {
+    iteratives::IterativeParams::check_values(max_step_abs, max_step_rel, min_value, max_value)
+        .and_then(|()| {
+            DIRECTIONAL_MAX_STEPS
+                .iter()
+                .zip(directional_max_steps)
+                .try_for_each(|(name, max_step)| {
+                    iteratives::IterativeParams::check_directional_max_step(name, max_step)
+                })
+        })
+        .and_then(|()| {
+            iteratives::IterativeParams::check_convergence_step_tolerance(step_tolerance)
+        })
+        .and_then(|()| iteratives::IterativeParams::check_fixed(fixed))
+        .map_err(XmlParseError::InvalidValue)?;
+
+    let mut iterative =
+        iteratives::IterativeParams::new(max_step_abs, max_step_rel, min_value, max_value);
+    let [max_step_abs_up, max_step_abs_down, max_step_rel_up, max_step_rel_down] =
+        directional_max_steps;
+    iterative.set_max_step_abs_up(max_step_abs_up);
+    iterative.set_max_step_abs_down(max_step_abs_down);
+    iterative.set_max_step_rel_up(max_step_rel_up);
+    iterative.set_max_step_rel_down(max_step_rel_down);
+    iterative.set_convergence_step_tolerance(step_tolerance);
+    iterative.set_fixed(fixed);
+    Ok(iterative)
+}
+
+/// Optional attributes of the directional step limitations, in the order of [parse_directional_max_steps]
+const DIRECTIONAL_MAX_STEPS: [&str; 4] = [
+    "max_step_abs_up",
+    "max_step_abs_down",
+    "max_step_rel_up",
+    "max_step_rel_down",
+];
+
+/// Parse the directional step limitations, the missing ones being taken from the default iterative if any
+fn parse_directional_max_steps(
+    node: &Element,
+    iterative_default: Option<&iteratives::IterativeParams>,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<[Option<f64>; 4], XmlParseError> {
+    let defaults = iterative_default.map_or([None; 4], |iterative_default| {
+        [
+            iterative_default.get_max_step_abs_up(),
+            iterative_default.get_max_step_abs_down(),
+            iterative_default.get_max_step_rel_up(),
+            iterative_default.get_max_step_rel_down(),
+        ]
+    });
+
+    let mut max_steps = [None; 4];
+    for (i, name) in DIRECTIONAL_MAX_STEPS.iter().enumerate() {
+        max_steps[i] = parse_optional_float_attribute(node, name, node_info)?.or(defaults[i]);
+    }
+    Ok(max_steps)
+}
+
+fn parse_optional_float_attribute(
+    node: &Element,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<Option<f64>, XmlParseError> {
+    node.attr(attribute)
+        .map(|_| super::util::parse_float_attribute(node, attribute, node_info))
+        .transpose()
 }
 
 #[cfg(test)]
@@ -80,10 +186,10 @@ mod tests {
 
     #[test]
     fn parsing_iterative_node_1() {
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf", max_value="inf"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf", max_value="inf"/>"#;
         let iterative_node: Element = DATA.parse().unwrap();
         let node_info = "iterative node id = 0";
-        let iterative = parse_iterative_jac_node(&iterative_node, node_info);
+        let iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
 
         let iterative_ref =
             iteratives::IterativeParams::new(10.0, 0.4, f64::NEG_INFINITY, f64::INFINITY);
@@ -98,11 +204,12 @@ mod tests {
             f64::NEG_INFINITY,
             f64::INFINITY,
         );
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf", max_value="inf"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf", max_value="inf"/>"#;
         let iterative_node: Element = DATA.parse().unwrap();
         let node_info = "iterative node id = 0";
         let iterative =
-            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         let iterative_ref =
             iteratives::IterativeParams::new(10.0, 0.4, f64::NEG_INFINITY, f64::INFINITY);
@@ -114,10 +221,10 @@ mod tests {
         expected = "The attribute \"min_value\" is missing in the iterative node id = 0"
     )]
     fn parsing_iterative_node_3() {
-        const DATA: &'static str = r#"<iterative id="0"/>"#;
+        const DATA: &str = r#"<iterative id="0"/>"#;
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
-        let iterative = parse_iterative_jac_node(&iterative_node, &node_info);
+        let iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
 
         let iterative_ref =
             iteratives::IterativeParams::new(10.0, 0.5, f64::NEG_INFINITY, f64::INFINITY);
@@ -128,11 +235,12 @@ mod tests {
     fn parsing_iterative_node_4() {
         let iterative_default =
             iteratives::IterativeParams::new(10.0, 0.5, f64::NEG_INFINITY, f64::INFINITY);
-        const DATA: &'static str = r#"<iterative id="0"/>"#;
+        const DATA: &str = r#"<iterative id="0"/>"#;
         let node_info = "iterative node id = 0";
         let iterative_node: Element = DATA.parse().unwrap();
         let iterative =
-            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
 
         let iterative_ref =
             iteratives::IterativeParams::new(10.0, 0.5, f64::NEG_INFINITY, f64::INFINITY);
@@ -148,23 +256,71 @@ mod tests {
             f64::NEG_INFINITY,
             f64::INFINITY,
         );
-        const DATA: &'static str = r#"<iterative id="0" max_step_abs="10" max_step_rel="-0.4" min_value="-inf", max_value="inf"/>"#;
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="-0.4" min_value="-inf", max_value="inf"/>"#;
         let iterative_node: Element = DATA.parse().unwrap();
         let node_info = "iterative node id = 0";
         let _iterative =
-            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, &node_info);
+            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
+    }
+
+    #[test]
+    fn parsing_iterative_node_6() {
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" step_tolerance="1e-3"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let node_info = "iterative node id = 0";
+        let iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
+
+        let mut iterative_ref =
+            iteratives::IterativeParams::new(10.0, 0.4, f64::NEG_INFINITY, f64::INFINITY);
+        iterative_ref.set_convergence_step_tolerance(Some(1e-3));
+        assert_eq!(iterative, iterative_ref);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "convergence_step_tolerance must be strictly positive, provided value was 0"
+    )]
+    fn parsing_iterative_node_7() {
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" step_tolerance="0"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let node_info = "iterative node id = 0";
+        let _iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
+    }
+
+    #[test]
+    fn parsing_iterative_node_directional_max_steps() {
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" max_step_abs_up="1" max_step_rel_down="0.1"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let node_info = "iterative node id = 0";
+        let iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
+
+        let mut iterative_ref =
+            iteratives::IterativeParams::new(10.0, 0.4, f64::NEG_INFINITY, f64::INFINITY);
+        iterative_ref.set_max_step_abs_up(Some(1.0));
+        iterative_ref.set_max_step_rel_down(Some(0.1));
+        assert_eq!(iterative, iterative_ref);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_step_abs_down must be strictly positive, provided value was -1")]
+    fn parsing_iterative_node_directional_max_steps_invalid() {
+        const DATA: &str = r#"<iterative id="0" max_step_abs="10" max_step_rel="0.4" min_value="-inf" max_value="inf" max_step_abs_down="-1"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let node_info = "iterative node id = 0";
+        let _iterative = parse_iterative_jac_node(&iterative_node, node_info).unwrap();
     }
 
     #[test]
     fn parsing_iteratives_node_1() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
                 <iterative id="0"/>
                 <iterative id="1"/>
                 <iterative id="2"/>
             </iteratives>"#;
         let iteratives_node: Element = DATA.parse().unwrap();
-        let iteratives = parse_iteratives_jac_node(&iteratives_node);
+        let iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
 
         let iterative_ref = iteratives::IterativeParams::new(
             f64::INFINITY,
@@ -179,14 +335,14 @@ mod tests {
 
     #[test]
     fn parsing_iteratives_node_2() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
                 <iterative id="0"/>
                 <iterative id="1" max_step_rel="0.5"/>
                 <iterative id="2"/>
             </iteratives>"#;
         let iteratives_node: Element = DATA.parse().unwrap();
-        let iteratives = parse_iteratives_jac_node(&iteratives_node);
+        let iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
 
         let iterative_ref = iteratives::IterativeParams::new(
             f64::INFINITY,
@@ -201,18 +357,91 @@ mod tests {
         assert_eq!(iteratives, iteratives_ref);
     }
 
+    #[test]
+    fn parsing_iteratives_node_step_tolerance() {
+        const DATA: &str = r#"
+            <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" step_tolerance="1e-3">
+                <iterative id="0"/>
+                <iterative id="1" step_tolerance="1e-6"/>
+            </iteratives>"#;
+        let iteratives_node: Element = DATA.parse().unwrap();
+        let iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
+
+        assert_eq!(iteratives[0].get_convergence_step_tolerance(), Some(1e-3));
+        assert_eq!(iteratives[1].get_convergence_step_tolerance(), Some(1e-6));
+    }
+
+    #[test]
+    fn parsing_iteratives_node_fixed() {
+        const DATA: &str = r#"
+            <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
+                <iterative id="0"/>
+                <iterative id="1" fixed="2.5"/>
+            </iteratives>"#;
+        let iteratives_node: Element = DATA.parse().unwrap();
+        let iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
+
+        assert_eq!(iteratives[0].get_fixed(), None);
+        assert_eq!(iteratives[1].get_fixed(), Some(2.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "The fixed value must be finite, provided value was inf")]
+    fn parsing_iterative_node_fixed_not_finite() {
+        let iterative_default = iteratives::IterativeParams::default();
+        const DATA: &str = r#"<iterative id="0" fixed="inf"/>"#;
+        let iterative_node: Element = DATA.parse().unwrap();
+        let node_info = "iterative node id = 0";
+        let _iterative =
+            parse_iterative_jac_node_with_default(&iterative_node, &iterative_default, node_info)
+                .unwrap();
+    }
+
+    #[test]
+    fn parsing_iteratives_node_directional_max_steps() {
+        const DATA: &str = r#"
+            <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" max_step_abs_up="5">
+                <iterative id="0"/>
+                <iterative id="1" max_step_abs_up="2" max_step_abs_down="3"/>
+            </iteratives>"#;
+        let iteratives_node: Element = DATA.parse().unwrap();
+        let iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
+
+        assert_eq!(iteratives[0].get_max_step_abs_up(), Some(5.0));
+        assert_eq!(iteratives[0].get_max_step_abs_down(), None);
+        assert_eq!(iteratives[1].get_max_step_abs_up(), Some(2.0));
+        assert_eq!(iteratives[1].get_max_step_abs_down(), Some(3.0));
+        assert_eq!(iteratives[1].get_max_step_rel_up(), None);
+    }
+
     #[test]
     #[should_panic(
         expected = "The ids must be in order starting from 0, got id 4 when the expected one was 2"
     )]
     fn parsing_iteratives_node_3() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
                 <iterative id="0"/>
                 <iterative id="1" max_step_rel="0.5"/>
                 <iterative id="4"/>
             </iteratives>"#;
         let iteratives_node: Element = DATA.parse().unwrap();
-        let _iteratives = parse_iteratives_jac_node(&iteratives_node);
+        let _iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The node 2 below iteratives is expected to be \"iterative\", got residual"
+    )]
+    fn parsing_iteratives_node_unexpected_child() {
+        const DATA: &str = r#"
+            <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
+                <iterative id="0"/>
+                <!-- a comment is not a node -->
+                <iterative id="1"/>
+                <residual id="2"/>
+            </iteratives>"#;
+        let iteratives_node: Element = DATA.parse().unwrap();
+        let _iteratives = parse_iteratives_jac_node(&iteratives_node).unwrap();
     }
 }
@@ -1,100 +1,248 @@
 use super::util;
+use super::XmlParseError;
 use crate::residuals;
 use minidom::Element;
+use std::fmt;
 
+#[allow(clippy::type_complexity)]
 pub fn parse_residuals_node(
     residuals_node: &Element,
-) -> (
-    Vec<residuals::NormalizationMethod>,
-    Vec<residuals::NormalizationMethod>,
-) {
+) -> Result<
+    (
+        Vec<residuals::NormalizationMethod>,
+        Vec<residuals::NormalizationMethod>,
+        Vec<Option<f64>>,
+        Vec<usize>,
+    ),
+    XmlParseError,
+> {
     //Parsing of default values
-    let residuals_config_default = parse_residual_node(residuals_node, "residuals node");
+    let residuals_config_default = parse_residual_node(residuals_node, "residuals node")?;
+    let reference_default = parse_reference_attribute(residuals_node, "residuals node")?;
 
     let mut residuals = Vec::new();
 
     for (expected_id, residual_node) in residuals_node.children().enumerate() {
-        if residual_node.name() != "residual" {
-            panic!(
-                "Node below residuals are expected to be \"residuals\", got {}",
-                residual_node.name()
-            );
-        }
-
-        let id = util::parse_id(residual_node, expected_id, "residual_node");
-        let node_info = format!("residual node id = {}", id);
-        let residual =
-            parse_residual_node_with_default(residual_node, residuals_config_default, &node_info);
+        util::check_child_name(residual_node, "residual", expected_id, "residuals")?;
+
+        let id = util::parse_id(residual_node, expected_id, "residual_node")?;
+        let node_info = util::NodeInfo {
+            name: "residual",
+            id,
+        };
+        let residual = parse_residual_node_with_default(
+            residual_node,
+            residuals_config_default,
+            reference_default,
+            &node_info,
+        )?;
 
         residuals.push(residual);
     }
 
+    let max_magnitudes = residuals::ResidualsConfig::convert_into_max_magnitudes(&residuals);
+    let ignored_residuals = residuals::ResidualsConfig::convert_into_ignored_residuals(&residuals);
     let (stopping_criterias, update_methods) =
         residuals::ResidualsConfig::convert_into_vecs(residuals);
-    (stopping_criterias, update_methods)
+    Ok((
+        stopping_criterias,
+        update_methods,
+        max_magnitudes,
+        ignored_residuals,
+    ))
 }
 
-fn parse_residual_node(residual_node: &Element, node_info: &str) -> residuals::ResidualConfig {
-    let stopping_critera =
-        parse_normalization_method_attribute(residual_node, "stopping_criteria", node_info);
+fn parse_residual_node(
+    residual_node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::ResidualConfig, XmlParseError> {
+    let reference = parse_reference_attribute(residual_node, node_info)?;
+    let stopping_critera = parse_normalization_method_attribute(
+        residual_node,
+        "stopping_criteria",
+        reference,
+        node_info,
+    )?;
     let update_method =
-        parse_normalization_method_attribute(residual_node, "update_method", node_info);
-
-    residuals::ResidualConfig::new(stopping_critera, update_method)
+        parse_normalization_method_attribute(residual_node, "update_method", reference, node_info)?;
+    let rel_floor = parse_rel_floor_attribute(residual_node, 0.0, node_info)?;
+    let max_magnitude = parse_max_magnitude_attribute(residual_node, None, node_info)?;
+
+    let mut residual_config = residuals::ResidualConfig::new(stopping_critera, update_method);
+    residual_config.set_rel_floor(rel_floor);
+    residual_config.set_max_magnitude(max_magnitude);
+    Ok(residual_config)
 }
 
 fn parse_residual_node_with_default(
     residual_node: &Element,
     residuals_config_default: residuals::ResidualConfig,
-    node_info: &str,
-) -> residuals::ResidualConfig {
+    reference_default: Option<f64>,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::ResidualConfig, XmlParseError> {
+    let reference = parse_reference_attribute(residual_node, node_info)?.or(reference_default);
     let stopping_critera = parse_normalization_method_attribute_with_default(
         residual_node,
         residuals_config_default.get_stopping_criteria(),
         "stopping_criteria",
+        reference,
         node_info,
-    );
+    )?;
     let update_method = parse_normalization_method_attribute_with_default(
         residual_node,
         residuals_config_default.get_update_method(),
         "update_method",
+        reference,
         node_info,
-    );
+    )?;
+    let rel_floor = parse_rel_floor_attribute(
+        residual_node,
+        residuals_config_default.get_rel_floor(),
+        node_info,
+    )?;
+    let max_magnitude = parse_max_magnitude_attribute(
+        residual_node,
+        residuals_config_default.get_max_magnitude(),
+        node_info,
+    )?;
+    // specific to each residual, not inherited from the residuals node
+    let ignored = parse_ignored_attribute(residual_node, node_info)?;
+
+    let mut residual_config = residuals::ResidualConfig::new(stopping_critera, update_method);
+    residual_config.set_rel_floor(rel_floor);
+    residual_config.set_max_magnitude(max_magnitude);
+    residual_config.set_ignored(ignored);
+    Ok(residual_config)
+}
+
+/// Parse the floor of the `Rel` normalization, see [residuals::ResidualConfig::set_rel_floor]
+fn parse_rel_floor_attribute(
+    node: &Element,
+    default: f64,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<f64, XmlParseError> {
+    let rel_floor =
+        util::parse_float_attribute_with_default(node, default, "rel_floor", node_info)?;
+    if !(rel_floor >= 0.0 && rel_floor.is_finite()) {
+        return Err(XmlParseError::InvalidValue(format!(
+            "The attribute \"rel_floor\" on node {} must be positive and finite, got {}",
+            node_info, rel_floor
+        )));
+    }
+    Ok(rel_floor)
+}
+
+/// Parse the maximum magnitude of the members of the residual, see [residuals::ResidualConfig::set_max_magnitude]
+fn parse_max_magnitude_attribute(
+    node: &Element,
+    default: Option<f64>,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<Option<f64>, XmlParseError> {
+    let max_magnitude = match node.attr("max_magnitude") {
+        Some(_) => util::parse_float_attribute(node, "max_magnitude", node_info)?,
+        None => return Ok(default),
+    };
+    if max_magnitude.is_nan() || max_magnitude <= 0.0 {
+        return Err(XmlParseError::InvalidValue(format!(
+            "The attribute \"max_magnitude\" on node {} must be strictly positive, got {}",
+            node_info, max_magnitude
+        )));
+    }
+    Ok(Some(max_magnitude))
+}
+
+/// Parse the exclusion of the residual from the system, see [residuals::ResidualConfig::set_ignored]
+fn parse_ignored_attribute(
+    node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<bool, XmlParseError> {
+    match node.attr("ignored") {
+        Some(value) => value.parse().map_err(|_| {
+            XmlParseError::InvalidValue(format!(
+                "The attribute \"ignored\" on node {} is not a valid boolean, valid values are \"true\" and \"false\" (case sensitive)",
+                node_info
+            ))
+        }),
+        None => Ok(false),
+    }
+}
+
+fn parse_reference_attribute(
+    node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<Option<f64>, XmlParseError> {
+    node.attr("reference")
+        .map(|_| util::parse_float_attribute(node, "reference", node_info))
+        .transpose()
+}
 
-    residuals::ResidualConfig::new(stopping_critera, update_method)
+fn parse_normalization_method(
+    value: &str,
+    reference: Option<f64>,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::NormalizationMethod, XmlParseError> {
+    match value {
+        "Abs"   => Ok(residuals::NormalizationMethod::Abs),
+        "Rel"   => Ok(residuals::NormalizationMethod::Rel),
+        "Adapt" => Ok(residuals::NormalizationMethod::Adapt),
+        "RelToReference" => Ok(residuals::NormalizationMethod::RelToReference(
+            reference.ok_or_else(|| XmlParseError::MissingAttribute(format!("The attribute \"reference\" is missing in {}, it is required by the \"RelToReference\" value of the attribute \"{}\"", node_info, attribute)))?,
+        )),
+        _ if value.starts_with("Adapt:") => parse_adapt_scale(&value["Adapt:".len()..], attribute, node_info),
+        _       => Err(XmlParseError::InvalidValue(format!("The attribute \"{}\" at {} has an improper values, valid values are \"Abs\", \"Rel\", \"Adapt\", \"Adapt:<scale>\" and \"RelToReference\"", attribute, node_info))),
+    }
+}
+
+/// Parse the scale of the `AdaptScaled` normalization, given as "Adapt:<scale>"
+fn parse_adapt_scale(
+    scale: &str,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::NormalizationMethod, XmlParseError> {
+    scale
+        .parse::<f64>()
+        .map(residuals::NormalizationMethod::AdaptScaled)
+        .map_err(|_| {
+            XmlParseError::InvalidValue(format!(
+                "The scale \"{}\" of the attribute \"{}\" at {} is not a valid float",
+                scale, attribute, node_info
+            ))
+        })
 }
 
 fn parse_normalization_method_attribute(
     node: &Element,
     attribute: &str,
-    node_info: &str,
-) -> residuals::NormalizationMethod {
-    match node
-            .attr(attribute)
-            .unwrap_or_else(|| panic!("The attribute \"{}\" is missing in {}", attribute, node_info)) {
-                "Abs"   => residuals::NormalizationMethod::Abs,
-                "Rel"   => residuals::NormalizationMethod::Rel,
-                "Adapt" => residuals::NormalizationMethod::Adapt,
-                _       => panic!("The attribute \"{}\" at {} has an improper values, valid values are \"Abs\", \"Rel\" and \"Adapt\"", attribute, node_info),
-            }
+    reference: Option<f64>,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::NormalizationMethod, XmlParseError> {
+    let value = node.attr(attribute).ok_or_else(|| {
+        XmlParseError::MissingAttribute(format!(
+            "The attribute \"{}\" is missing in {}",
+            attribute, node_info
+        ))
+    })?;
+
+    parse_normalization_method(value, reference, attribute, node_info)
 }
 
+/// If the attribute is not provided, the default value is taken,
+/// with its reference overridden by the one of the node if provided.
 fn parse_normalization_method_attribute_with_default(
     node: &Element,
     default: residuals::NormalizationMethod,
     attribute: &str,
-    node_info: &str,
-) -> residuals::NormalizationMethod {
-    match node
-            .attr(attribute) {
-                None => default,
-                Some(value) => match value {
-                                    "Abs"   => residuals::NormalizationMethod::Abs,
-                                    "Rel"   => residuals::NormalizationMethod::Rel,
-                                    "Adapt" => residuals::NormalizationMethod::Adapt,
-                                    _       => panic!("The attribute \"{}\" at {} has an improper values, valid values are \"Abs\", \"Rel\" and \"Adapt\"", attribute, node_info),
-                }
-            }
+    reference: Option<f64>,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<residuals::NormalizationMethod, XmlParseError> {
+    match (node.attr(attribute), default) {
+        (None, residuals::NormalizationMethod::RelToReference(default_reference)) => Ok(
+            residuals::NormalizationMethod::RelToReference(reference.unwrap_or(default_reference)),
+        ),
+        (None, _) => Ok(default),
+        (Some(value), _) => parse_normalization_method(value, reference, attribute, node_info),
+    }
 }
 
 #[cfg(test)]
@@ -106,10 +254,9 @@ mod tests {
     #[test]
     fn parsing_residual_node_1() {
         let node_info = "residual node id = 0";
-        const DATA: &'static str =
-            r#"<residual id="0" stopping_criteria="Adapt" update_method="Abs"/>"#;
+        const DATA: &str = r#"<residual id="0" stopping_criteria="Adapt" update_method="Abs"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let residual = parse_residual_node(&residual_node, &node_info);
+        let residual = parse_residual_node(&residual_node, node_info).unwrap();
 
         let residual_ref = residuals::ResidualConfig::new(
             residuals::NormalizationMethod::Adapt,
@@ -125,11 +272,15 @@ mod tests {
             residuals::NormalizationMethod::Rel,
         );
         let node_info = "residual node id = 0";
-        const DATA: &'static str =
-            r#"<residual id="0" stopping_criteria="Adapt" update_method="Abs"/>"#;
+        const DATA: &str = r#"<residual id="0" stopping_criteria="Adapt" update_method="Abs"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let residual =
-            parse_residual_node_with_default(&residual_node, residual_config_default, &node_info);
+        let residual = parse_residual_node_with_default(
+            &residual_node,
+            residual_config_default,
+            None,
+            node_info,
+        )
+        .unwrap();
 
         let residual_ref = residuals::ResidualConfig::new(
             residuals::NormalizationMethod::Adapt,
@@ -144,9 +295,9 @@ mod tests {
     )]
     fn parsing_residual_node_3() {
         let node_info = "residual node id = 0";
-        const DATA: &'static str = r#"<residual id="0"/>"#;
+        const DATA: &str = r#"<residual id="0"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let residual = parse_residual_node(&residual_node, &node_info);
+        let residual = parse_residual_node(&residual_node, node_info).unwrap();
 
         let residual_ref = residuals::ResidualConfig::new(
             residuals::NormalizationMethod::Rel,
@@ -162,10 +313,15 @@ mod tests {
             residuals::NormalizationMethod::Rel,
         );
         let node_info = "residual node id = 0";
-        const DATA: &'static str = r#"<residual id="0"/>"#;
+        const DATA: &str = r#"<residual id="0"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let residual =
-            parse_residual_node_with_default(&residual_node, residual_config_default, &node_info);
+        let residual = parse_residual_node_with_default(
+            &residual_node,
+            residual_config_default,
+            None,
+            node_info,
+        )
+        .unwrap();
 
         let residual_ref = residuals::ResidualConfig::new(
             residuals::NormalizationMethod::Rel,
@@ -176,19 +332,18 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "The attribute \"stopping_criteria\" at residual node id = 0 has an improper values, valid values are \"Abs\", \"Rel\" and \"Adapt\""
+        expected = "The attribute \"stopping_criteria\" at residual node id = 0 has an improper values, valid values are \"Abs\", \"Rel\", \"Adapt\", \"Adapt:<scale>\" and \"RelToReference\""
     )]
     fn parsing_residual_node_5() {
         let node_info = "residual node id = 0";
-        const DATA: &'static str =
-            r#"<residual id="0" stopping_criteria="adapt" update_method="Abs"/>"#;
+        const DATA: &str = r#"<residual id="0" stopping_criteria="adapt" update_method="Abs"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let _residual = parse_residual_node(&residual_node, &node_info);
+        let _residual = parse_residual_node(&residual_node, node_info).unwrap();
     }
 
     #[test]
     #[should_panic(
-        expected = "The attribute \"stopping_criteria\" at residual node id = 0 has an improper values, valid values are \"Abs\", \"Rel\" and \"Adapt\""
+        expected = "The attribute \"stopping_criteria\" at residual node id = 0 has an improper values, valid values are \"Abs\", \"Rel\", \"Adapt\", \"Adapt:<scale>\" and \"RelToReference\""
     )]
     fn parsing_residual_node_6() {
         let residual_config_default = residuals::ResidualConfig::new(
@@ -196,23 +351,78 @@ mod tests {
             residuals::NormalizationMethod::Rel,
         );
         let node_info = "residual node id = 0";
-        const DATA: &'static str =
-            r#"<residual id="0" stopping_criteria="adapt" update_method="Abs"/>"#;
+        const DATA: &str = r#"<residual id="0" stopping_criteria="adapt" update_method="Abs"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let _residual = parse_residual_node_with_default(
+            &residual_node,
+            residual_config_default,
+            None,
+            node_info,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn parsing_residual_node_7() {
+        let node_info = "residual node id = 0";
+        const DATA: &str = r#"<residual id="0" stopping_criteria="RelToReference" update_method="Abs" reference="1e5"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let residual = parse_residual_node(&residual_node, node_info).unwrap();
+
+        let residual_ref = residuals::ResidualConfig::new(
+            residuals::NormalizationMethod::RelToReference(1e5),
+            residuals::NormalizationMethod::Abs,
+        );
+        assert_eq!(residual, residual_ref);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"reference\" is missing in residual node id = 0, it is required by the \"RelToReference\" value of the attribute \"stopping_criteria\""
+    )]
+    fn parsing_residual_node_8() {
+        let node_info = "residual node id = 0";
+        const DATA: &str =
+            r#"<residual id="0" stopping_criteria="RelToReference" update_method="Abs"/>"#;
         let residual_node: Element = DATA.parse().unwrap();
-        let _residual =
-            parse_residual_node_with_default(&residual_node, residual_config_default, &node_info);
+        let _residual = parse_residual_node(&residual_node, node_info).unwrap();
+    }
+
+    #[test]
+    fn parsing_residual_node_9() {
+        let residual_config_default = residuals::ResidualConfig::new(
+            residuals::NormalizationMethod::RelToReference(1e5),
+            residuals::NormalizationMethod::RelToReference(1e5),
+        );
+        let node_info = "residual node id = 0";
+        const DATA: &str = r#"<residual id="0" update_method="Abs" reference="10"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let residual = parse_residual_node_with_default(
+            &residual_node,
+            residual_config_default,
+            None,
+            node_info,
+        )
+        .unwrap();
+
+        let residual_ref = residuals::ResidualConfig::new(
+            residuals::NormalizationMethod::RelToReference(10.0),
+            residuals::NormalizationMethod::Abs,
+        );
+        assert_eq!(residual, residual_ref);
     }
 
     #[test]
     fn parsing_residuals_node_1() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="0"/>
                 <residual id="1"/>
                 <residual id="2"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (stopping_criterias, update_methods) = parse_residuals_node(&residuals_node);
+        let (stopping_criterias, update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
 
         let stopping_ref = vec![residuals::NormalizationMethod::Adapt; 3];
         let update_ref = vec![residuals::NormalizationMethod::Abs; 3];
@@ -223,14 +433,15 @@ mod tests {
 
     #[test]
     fn parsing_residuals_node_2() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="0" stopping_criteria="Rel"/>
                 <residual id="1"/>
                 <residual id="2"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (stopping_criterias, update_methods) = parse_residuals_node(&residuals_node);
+        let (stopping_criterias, update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
 
         let mut stopping_ref = vec![residuals::NormalizationMethod::Adapt; 3];
         stopping_ref[0] = residuals::NormalizationMethod::Rel;
@@ -240,19 +451,41 @@ mod tests {
         assert_eq!(update_methods, update_ref);
     }
 
+    #[test]
+    fn parsing_residuals_node_7() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="RelToReference" update_method="Abs" reference="1e5">
+                <residual id="0"/>
+                <residual id="1" reference="2.5"/>
+                <residual id="2" update_method="RelToReference"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (stopping_criterias, update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+
+        let mut stopping_ref = vec![residuals::NormalizationMethod::RelToReference(1e5); 3];
+        stopping_ref[1] = residuals::NormalizationMethod::RelToReference(2.5);
+        let mut update_ref = vec![residuals::NormalizationMethod::Abs; 3];
+        update_ref[2] = residuals::NormalizationMethod::RelToReference(1e5);
+
+        assert_eq!(stopping_criterias, stopping_ref);
+        assert_eq!(update_methods, update_ref);
+    }
+
     #[test]
     #[should_panic(
         expected = "The ids must be in order starting from 0, got id 2 when the expected one was 1"
     )]
     fn parsing_residuals_node_3() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="0"/>
                 <residual id="2"/>
                 <residual id="1"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (_stopping_criterias, _update_methods) = parse_residuals_node(&residuals_node);
+        let (_stopping_criterias, _update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
     }
 
     #[test]
@@ -260,14 +493,15 @@ mod tests {
         expected = "The ids must be in order starting from 0, got id 1 when the expected one was 2"
     )]
     fn parsing_residuals_node_4() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="0"/>
                 <residual id="1"/>
                 <residual id="1"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (_stopping_criterias, _update_methods) = parse_residuals_node(&residuals_node);
+        let (_stopping_criterias, _update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
     }
 
     #[test]
@@ -275,26 +509,177 @@ mod tests {
         expected = "The ids must be in order starting from 0, got id 3 when the expected one was 2"
     )]
     fn parsing_residuals_node_5() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="0"/>
                 <residual id="1"/>
                 <residual id="3"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (_stopping_criterias, _update_methods) = parse_residuals_node(&residuals_node);
+        let (_stopping_criterias, _update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
     }
 
     #[test]
     #[should_panic(expected = "The attribute \"id\" is not a valid positive integer")]
     fn parsing_residuals_node_6() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <residuals stopping_criteria="Adapt" update_method="Abs">
                 <residual id="-1"/>
                 <residual id="0"/>
                 <residual id="1"/>
             </residuals>"#;
         let residuals_node: Element = DATA.parse().unwrap();
-        let (_stopping_criterias, _update_methods) = parse_residuals_node(&residuals_node);
+        let (_stopping_criterias, _update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The node 1 below residuals is expected to be \"residual\", got residuals"
+    )]
+    fn parsing_residuals_node_misspelled_child() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Adapt" update_method="Abs">
+                <residual id="0"/>
+                <residuals id="1"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (_stopping_criterias, _update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+    }
+
+    #[test]
+    fn parsing_residual_node_adapt_scaled() {
+        let node_info = "residual node id = 0";
+        const DATA: &str =
+            r#"<residual id="0" stopping_criteria="Adapt:1e5" update_method="Adapt"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let residual = parse_residual_node(&residual_node, node_info).unwrap();
+
+        let residual_ref = residuals::ResidualConfig::new(
+            residuals::NormalizationMethod::AdaptScaled(1e5),
+            residuals::NormalizationMethod::Adapt,
+        );
+        assert_eq!(residual, residual_ref);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The scale \"1e5Pa\" of the attribute \"update_method\" at residual node id = 0 is not a valid float"
+    )]
+    fn parsing_residual_node_adapt_scaled_invalid_scale() {
+        let node_info = "residual node id = 0";
+        const DATA: &str =
+            r#"<residual id="0" stopping_criteria="Abs" update_method="Adapt:1e5Pa"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let _residual = parse_residual_node(&residual_node, node_info).unwrap();
+    }
+
+    #[test]
+    fn parsing_residuals_node_rel_floor() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Rel" update_method="Rel" rel_floor="1e-3">
+                <residual id="0"/>
+                <residual id="1" rel_floor="0"/>
+                <residual id="2" rel_floor="10" update_method="Abs"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (stopping_criterias, update_methods, _, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+
+        let stopping_ref = vec![
+            residuals::NormalizationMethod::RelFloored(1e-3),
+            residuals::NormalizationMethod::Rel,
+            residuals::NormalizationMethod::RelFloored(10.0),
+        ];
+        let update_ref = vec![
+            residuals::NormalizationMethod::RelFloored(1e-3),
+            residuals::NormalizationMethod::Rel,
+            residuals::NormalizationMethod::Abs,
+        ];
+
+        assert_eq!(stopping_criterias, stopping_ref);
+        assert_eq!(update_methods, update_ref);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"rel_floor\" on node residual node id = 0 must be positive and finite, got -1"
+    )]
+    fn parsing_residual_node_negative_rel_floor() {
+        let node_info = "residual node id = 0";
+        const DATA: &str =
+            r#"<residual id="0" stopping_criteria="Rel" update_method="Rel" rel_floor="-1"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let _residual = parse_residual_node(&residual_node, node_info).unwrap();
+    }
+
+    #[test]
+    fn parsing_residuals_node_max_magnitude() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Abs" update_method="Abs" max_magnitude="1e30">
+                <residual id="0"/>
+                <residual id="1" max_magnitude="1e6"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (_stopping_criterias, _update_methods, max_magnitudes, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+
+        assert_eq!(max_magnitudes, vec![Some(1e30), Some(1e6)]);
+    }
+
+    #[test]
+    fn parsing_residuals_node_without_max_magnitude() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Abs" update_method="Abs">
+                <residual id="0" max_magnitude="1e6"/>
+                <residual id="1"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (_stopping_criterias, _update_methods, max_magnitudes, _) =
+            parse_residuals_node(&residuals_node).unwrap();
+
+        assert_eq!(max_magnitudes, vec![Some(1e6), None]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"max_magnitude\" on node residual node id = 0 must be strictly positive, got 0"
+    )]
+    fn parsing_residual_node_null_max_magnitude() {
+        let node_info = "residual node id = 0";
+        const DATA: &str =
+            r#"<residual id="0" stopping_criteria="Abs" update_method="Abs" max_magnitude="0"/>"#;
+        let residual_node: Element = DATA.parse().unwrap();
+        let _residual = parse_residual_node(&residual_node, node_info).unwrap();
+    }
+
+    #[test]
+    fn parsing_residuals_node_ignored() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Abs" update_method="Abs">
+                <residual id="0"/>
+                <residual id="1" ignored="true"/>
+                <residual id="2" ignored="false"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let (_stopping_criterias, _update_methods, _max_magnitudes, ignored_residuals) =
+            parse_residuals_node(&residuals_node).unwrap();
+
+        assert_eq!(ignored_residuals, vec![1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"ignored\" on node residual node id = 0 is not a valid boolean"
+    )]
+    fn parsing_residuals_node_invalid_ignored() {
+        const DATA: &str = r#"
+            <residuals stopping_criteria="Abs" update_method="Abs">
+                <residual id="0" ignored="yes"/>
+            </residuals>"#;
+        let residuals_node: Element = DATA.parse().unwrap();
+        let _residuals = parse_residuals_node(&residuals_node).unwrap();
     }
 }
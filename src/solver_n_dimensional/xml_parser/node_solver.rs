@@ -1,46 +1,347 @@
 use minidom::Element;
+use std::fmt;
 
 use super::util;
+use super::XmlParseError;
+use crate::solver::presets::Preset;
 use crate::solver::SolverParameters;
+use crate::solver::{
+    BoundPolicy, CycleBreakingAction, Damping, InaccurateJacobianPolicy, LinearSolver,
+    StepAcceptanceNorm, StructuralSingularityPolicy,
+};
+use crate::solver::{MaxIterations, ProblemSize, SparsityPattern};
 use crate::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
 
 /// Parse a solver node
-pub fn parse_solver_node(solver_node: &Element) -> SolverParameters {
+///
+/// With the `preset` attribute, the parameters of the preset are used,
+/// the other attributes being optional and overriding them.
+///
+/// The optional `sparsity_band` attribute declares a banded sparsity pattern,
+/// with as many diagonals below and above the main one (`1` for a tridiagonal jacobian),
+/// see [SparsityPattern::banded].
+///
+/// The tolerance must be at least [crate::solver::MIN_TOLERANCE],
+/// unless the optional `allow_extreme_tolerance` attribute is `"true"`,
+/// see [SolverParameters::allow_extreme_tolerance].
+///
+/// The other optional attributes are the options of [SolverParameters],
+/// the default values being the ones of the programmatic API, see the documentation of the [super] module.
+/// A value rejected by a setter of [SolverParameters] is an [XmlParseError::InvalidValue].
+pub fn parse_solver_node(solver_node: &Element) -> Result<SolverParameters, XmlParseError> {
     let node_info = "solver node";
-    let problem_size = util::parse_int_attribute(solver_node, "problem_size", node_info);
-    let max_iter = util::parse_int_attribute(solver_node, "max_iter", node_info);
-    let tolerance = util::parse_float_attribute(solver_node, "tolerance", node_info);
-    let resolution_method = parse_resolution_method(solver_node, node_info);
-
-    let damping: bool = match solver_node.attr("damping") {
-        Some(value) => value.parse().expect("The attribute \"damping\" is not a valid boolean, valid values are \"true\" and \"false\" (case sensitive)"),
-        None => false,
+    let problem_size = util::parse_int_attribute(solver_node, "problem_size", node_info)?;
+    if problem_size == 0 {
+        return Err(XmlParseError::InvalidValue(format!(
+            "The attribute \"problem_size\" at the {} must be strictly positive",
+            node_info
+        )));
+    }
+
+    let mut solver_parameters = match solver_node.attr("preset") {
+        Some(value) => {
+            let preset = parse_preset(value, node_info)?;
+            let mut solver_parameters = preset.parameters(problem_size);
+            if solver_node.attr("max_iter").is_some() {
+                solver_parameters.set_max_iter(util::parse_int_attribute(
+                    solver_node,
+                    "max_iter",
+                    node_info,
+                )?);
+            }
+            if solver_node.attr("tolerance").is_some() {
+                solver_parameters.set_tolerance(util::parse_float_attribute(
+                    solver_node,
+                    "tolerance",
+                    node_info,
+                )?);
+            }
+            if solver_node.attr("resolution_method").is_some() {
+                solver_parameters
+                    .set_resolution_method(parse_resolution_method(solver_node, node_info)?);
+            }
+            if let Some(damping) = parse_damping(solver_node)? {
+                solver_parameters.set_damping(damping);
+            }
+            solver_parameters
+        }
+        None => {
+            let max_iter = util::parse_int_attribute(solver_node, "max_iter", node_info)?;
+            let tolerance = util::parse_float_attribute(solver_node, "tolerance", node_info)?;
+            let resolution_method = parse_resolution_method(solver_node, node_info)?;
+            let damping = parse_damping(solver_node)?.unwrap_or_default();
+
+            SolverParameters::new(
+                ProblemSize::new(problem_size),
+                tolerance,
+                MaxIterations::new(max_iter),
+                resolution_method,
+                damping,
+            )
+        }
     };
 
-    SolverParameters::new(
-        problem_size,
-        tolerance,
-        max_iter,
-        resolution_method,
-        damping,
-    )
+    if solver_node.attr("newton_warmup").is_some() {
+        solver_parameters.set_newton_warmup_iterations(util::parse_int_attribute(
+            solver_node,
+            "newton_warmup",
+            node_info,
+        )?);
+    }
+
+    if parse_bool_attribute(solver_node, "allow_extreme_tolerance")? == Some(true) {
+        solver_parameters.allow_extreme_tolerance();
+    }
+    solver_parameters.check_tolerance().map_err(|error| {
+        XmlParseError::InvalidValue(format!(
+            "The attribute \"tolerance\" at the {} is invalid: {}",
+            node_info, error
+        ))
+    })?;
+
+    if solver_node.attr("sparsity_band").is_some() {
+        let band = util::parse_int_attribute(solver_node, "sparsity_band", node_info)?;
+        solver_parameters.set_sparsity_pattern(Some(SparsityPattern::banded(
+            problem_size,
+            band,
+            band,
+        )));
+    }
+
+    if solver_node.attr("max_model_evaluations").is_some() {
+        solver_parameters.set_max_model_evaluations(Some(util::parse_int_attribute(
+            solver_node,
+            "max_model_evaluations",
+            node_info,
+        )?));
+    }
+    if let Some(reject) = parse_bool_attribute(solver_node, "reject_solution_on_bounds")? {
+        solver_parameters.set_reject_solution_on_bounds(reject);
+    }
+    if let Some(value) = solver_node.attr("bound_policy") {
+        let policy = match value {
+            "Clamp" => BoundPolicy::Clamp,
+            "Fail" => BoundPolicy::Fail,
+            "Ignore" => BoundPolicy::Ignore,
+            _ => return Err(XmlParseError::InvalidValue(format!("The attribute \"bound_policy\" at the {} has an improper value, valid values are \"Clamp\", \"Fail\" and \"Ignore\"", node_info))),
+        };
+        solver_parameters.set_bound_policy(policy);
+    }
+    if let Some(return_best) = parse_bool_attribute(solver_node, "return_best_on_failure")? {
+        solver_parameters.set_return_best_on_failure(return_best);
+    }
+    if solver_node.attr("aitken_every").is_some() {
+        let aitken_every = util::parse_int_attribute(solver_node, "aitken_every", node_info)?;
+        check_value(
+            aitken_every > 0,
+            "aitken_every",
+            node_info,
+            "strictly positive",
+        )?;
+        solver_parameters.set_aitken_acceleration(Some(aitken_every));
+    }
+    if solver_node.attr("first_iteration_step_scale").is_some() {
+        let scale =
+            util::parse_float_attribute(solver_node, "first_iteration_step_scale", node_info)?;
+        check_value(
+            scale > 0.0,
+            "first_iteration_step_scale",
+            node_info,
+            "strictly positive",
+        )?;
+        solver_parameters.set_first_iteration_step_scale(scale);
+    }
+    if solver_node.attr("linear_solver").is_some() {
+        let linear_solver = parse_linear_solver(solver_node, node_info)?;
+        if !linear_solver.is_compatible_with(solver_parameters.get_resolution_method()) {
+            return Err(XmlParseError::InvalidValue(format!(
+                "The attribute \"linear_solver\" at the {} is invalid: the linear solver {} cannot be used with the resolution method {}",
+                node_info,
+                linear_solver,
+                solver_parameters.get_resolution_method()
+            )));
+        }
+        solver_parameters.set_linear_solver(linear_solver);
+    }
+    if solver_node.attr("inaccurate_jacobian_policy").is_some() {
+        solver_parameters.set_inaccurate_jacobian_policy(parse_inaccurate_jacobian_policy(
+            solver_node,
+            node_info,
+        )?);
+    }
+    if let Some(value) = solver_node.attr("structural_singularity_policy") {
+        let policy = match value {
+            "Fail" => StructuralSingularityPolicy::Fail,
+            "Reduce" => StructuralSingularityPolicy::Reduce,
+            _ => return Err(XmlParseError::InvalidValue(format!("The attribute \"structural_singularity_policy\" at the {} has an improper value, valid values are \"Fail\" and \"Reduce\"", node_info))),
+        };
+        solver_parameters.set_structural_singularity_policy(policy);
+    }
+    if solver_node.attr("perturbation_jitter").is_some() {
+        let amplitude = util::parse_float_attribute(solver_node, "perturbation_jitter", node_info)?;
+        check_value(
+            amplitude > 0.0 && amplitude < 1.0,
+            "perturbation_jitter",
+            node_info,
+            "strictly between 0 and 1",
+        )?;
+        solver_parameters.set_perturbation_jitter(Some(amplitude));
+    }
+    if let Some(value) = solver_node.attr("cycle_detection") {
+        let action = match value {
+            "DampedAverage" => CycleBreakingAction::DampedAverage,
+            "DampedHalfStep" => CycleBreakingAction::DampedHalfStep,
+            "RecomputeJacobian" => CycleBreakingAction::RecomputeJacobian,
+            _ => return Err(XmlParseError::InvalidValue(format!("The attribute \"cycle_detection\" at the {} has an improper value, valid values are \"DampedAverage\", \"DampedHalfStep\" and \"RecomputeJacobian\"", node_info))),
+        };
+        solver_parameters.set_cycle_detection(Some(action));
+    }
+    if let Some(value) = solver_node.attr("step_acceptance_norm") {
+        let norm = match value {
+            "Stopping" => StepAcceptanceNorm::Stopping,
+            "Update" => StepAcceptanceNorm::Update,
+            "Both" => StepAcceptanceNorm::Both,
+            _ => return Err(XmlParseError::InvalidValue(format!("The attribute \"step_acceptance_norm\" at the {} has an improper value, valid values are \"Stopping\", \"Update\" and \"Both\"", node_info))),
+        };
+        solver_parameters.set_step_acceptance_norm(norm);
+    }
+    if solver_node.attr("memory_limit_bytes").is_some() {
+        solver_parameters.set_memory_limit_bytes(Some(util::parse_int_attribute(
+            solver_node,
+            "memory_limit_bytes",
+            node_info,
+        )?));
+    }
+    if solver_node.attr("error_smoothing").is_some() {
+        let coefficient = util::parse_float_attribute(solver_node, "error_smoothing", node_info)?;
+        check_value(
+            coefficient > 0.0 && coefficient <= 1.0,
+            "error_smoothing",
+            node_info,
+            "in ]0, 1]",
+        )?;
+        solver_parameters.set_error_smoothing(Some(coefficient));
+    }
+    if solver_node.attr("consecutive_convergences").is_some() {
+        let consecutive_convergences =
+            util::parse_int_attribute(solver_node, "consecutive_convergences", node_info)?;
+        check_value(
+            consecutive_convergences > 0,
+            "consecutive_convergences",
+            node_info,
+            "strictly positive",
+        )?;
+        solver_parameters.set_consecutive_convergences(consecutive_convergences);
+    }
+    if let Some(scalar_safeguard) = parse_bool_attribute(solver_node, "scalar_safeguard")? {
+        solver_parameters.set_scalar_safeguard(scalar_safeguard);
+    }
+    if solver_node.attr("scalar_safeguard_refinements").is_some() {
+        let refinements =
+            util::parse_int_attribute(solver_node, "scalar_safeguard_refinements", node_info)?;
+        check_value(
+            refinements > 0,
+            "scalar_safeguard_refinements",
+            node_info,
+            "strictly positive",
+        )?;
+        solver_parameters.set_scalar_safeguard_refinements(refinements);
+    }
+
+    Ok(solver_parameters)
 }
 
-fn parse_resolution_method(node: &Element, node_info: &str) -> ResolutionMethod {
+fn check_value(
+    valid: bool,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+    requirement: &str,
+) -> Result<(), XmlParseError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(XmlParseError::InvalidValue(format!(
+            "The attribute \"{}\" at the {} must be {}",
+            attribute, node_info, requirement
+        )))
+    }
+}
+
+fn parse_linear_solver(
+    node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<LinearSolver, XmlParseError> {
+    match node.attr("linear_solver").unwrap_or_default() {
+        "DirectInverse" => Ok(LinearSolver::DirectInverse),
+        "Gmres" => Ok(LinearSolver::Gmres {
+            restart: util::parse_int_attribute(node, "gmres_restart", node_info)?,
+            max_iter: util::parse_int_attribute(node, "gmres_max_iter", node_info)?,
+            tol: util::parse_float_attribute(node, "gmres_tol", node_info)?,
+        }),
+        _ => Err(XmlParseError::InvalidValue(format!("The attribute \"linear_solver\" at the {} has an improper value, valid values are \"DirectInverse\" and \"Gmres\"", node_info))),
+    }
+}
+
+fn parse_inaccurate_jacobian_policy(
+    node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<InaccurateJacobianPolicy, XmlParseError> {
+    match node.attr("inaccurate_jacobian_policy").unwrap_or_default() {
+        "Accept" => Ok(InaccurateJacobianPolicy::Accept),
+        "RetryColumnWithReducedStep" => Ok(InaccurateJacobianPolicy::RetryColumnWithReducedStep {
+            max_retries: util::parse_int_attribute(node, "max_retries", node_info)?,
+        }),
+        "Fail" => Ok(InaccurateJacobianPolicy::Fail),
+        _ => Err(XmlParseError::InvalidValue(format!("The attribute \"inaccurate_jacobian_policy\" at the {} has an improper value, valid values are \"Accept\", \"RetryColumnWithReducedStep\" and \"Fail\"", node_info))),
+    }
+}
+
+/// The `"true"` and `"false"` values give [Damping::Enabled] and [Damping::Disabled]
+fn parse_damping(solver_node: &Element) -> Result<Option<Damping>, XmlParseError> {
+    parse_bool_attribute(solver_node, "damping").map(|damping| damping.map(Damping::from))
+}
+
+fn parse_bool_attribute(
+    solver_node: &Element,
+    attribute: &str,
+) -> Result<Option<bool>, XmlParseError> {
+    match solver_node.attr(attribute) {
+        Some(value) => value.parse().map(Some).map_err(|_| XmlParseError::InvalidValue(format!("The attribute \"{}\" is not a valid boolean, valid values are \"true\" and \"false\" (case sensitive)", attribute))),
+        None => Ok(None),
+    }
+}
+
+fn parse_preset(
+    value: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<Preset, XmlParseError> {
+    match value {
+        "robust" => Ok(Preset::Robust),
+        "fast_smooth" => Ok(Preset::FastSmooth),
+        "expensive_model" => Ok(Preset::ExpensiveModel),
+        _ => Err(XmlParseError::InvalidValue(format!("The attribute \"preset\" at the {} has an improper value, valid values are \"robust\", \"fast_smooth\" and \"expensive_model\"", node_info))),
+    }
+}
+
+fn parse_resolution_method(
+    node: &Element,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<ResolutionMethod, XmlParseError> {
     match node
             .attr("resolution_method")
-            .unwrap_or_else(|| panic!("The attribute \"resolution_method\" is missing in {}", node_info)) {
-                "NR" => ResolutionMethod::NewtonRaphson,
-                "SN" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
-                "BROY1" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod)),
-                "BROY1_INV" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod)),
-                "BROY2" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod)),
-                "BROY2_INV" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod)),
-                "GRST1" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtFirstMethod)),
-                "GRST1_INV" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtFirstMethod)),
-                "GRST2" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtSecondMethod)),
-                "GRST2_INV" => ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtSecondMethod)),
-                _     => panic!("The attribute \"resolution_method\" at the {} has an improper values, valid values are \"NR\", \"SN\", \"BROY1\", \"BROY1_INV\", \"BROY2\", \"BROY2_INV\", \"GRST1\", \"GRST1_INV\", \"GRST2\", \"GRST2_INV\"", node_info),
+            .ok_or_else(|| XmlParseError::MissingAttribute(format!("The attribute \"resolution_method\" is missing in {}", node_info)))? {
+                "NR" => Ok(ResolutionMethod::NewtonRaphson),
+                "SN" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)),
+                "BROY1" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod))),
+                "BROY1_INV" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod))),
+                "BROY2" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod))),
+                "BROY2_INV" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod))),
+                "GRST1" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtFirstMethod))),
+                "GRST1_INV" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtFirstMethod))),
+                "GRST2" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtSecondMethod))),
+                "GRST2_INV" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::GreenstadtSecondMethod))),
+                "SCHU" => Ok(ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::SchubertSparse))),
+                _     => Err(XmlParseError::InvalidValue(format!("The attribute \"resolution_method\" at the {} has an improper values, valid values are \"NR\", \"SN\", \"BROY1\", \"BROY1_INV\", \"BROY2\", \"BROY2_INV\", \"GRST1\", \"GRST1_INV\", \"GRST2\", \"GRST2_INV\", \"SCHU\"", node_info))),
             }
 }
 
@@ -52,40 +353,40 @@ mod tests {
 
     #[test]
     fn parsing_solver_node_1() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
             ResolutionMethod::NewtonRaphson
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     fn parsing_solver_node_resolution_method_1() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="SN"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="SN"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
             ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     fn parsing_solver_node_resolution_method_2() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY1"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY1"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
@@ -94,15 +395,15 @@ mod tests {
             ))
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     fn parsing_solver_node_resolution_method_3() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY2"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY2"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
@@ -111,15 +412,15 @@ mod tests {
             ))
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     fn parsing_solver_node_resolution_method_4() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY1_INV"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY1_INV"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
@@ -128,15 +429,15 @@ mod tests {
             ))
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     fn parsing_solver_node_resolution_method_5() {
-        const DATA: &'static str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY2_INV"/>"#;
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="BROY2_INV"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(
             solver_parameters.get_resolution_method(),
@@ -145,43 +446,233 @@ mod tests {
             ))
         );
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
     }
 
     #[test]
     #[should_panic(expected = "The attribute \"problem_size\" is missing in the solver node")]
     fn parsing_solver_node_2() {
-        const DATA: &'static str = r#"<solver problem_Size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
+        const DATA: &str = r#"<solver problem_Size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let _solver_parameters = parse_solver_node(&solver_node);
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
     }
     #[test]
     #[should_panic(expected = "The attribute \"problem_size\" is not a valid positive integer")]
     fn parsing_solver_node_3() {
-        const DATA: &'static str = r#"<solver problem_size="3.0" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
+        const DATA: &str = r#"<solver problem_size="3.0" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let _solver_parameters = parse_solver_node(&solver_node);
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
     }
     #[test]
     #[should_panic(expected = "The attribute \"problem_size\" is not a valid positive integer")]
     fn parsing_solver_node_4() {
-        const DATA: &'static str = r#"<solver problem_size="-3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
+        const DATA: &str = r#"<solver problem_size="-3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
+    }
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"problem_size\" at the solver node must be strictly positive"
+    )]
+    fn parsing_solver_node_empty_problem() {
+        const DATA: &str = r#"<solver problem_size="0" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let _solver_parameters = parse_solver_node(&solver_node);
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
     }
+
     #[test]
     fn parsing_solver_node_5() {
-        const DATA: &'static str =
+        const DATA: &str =
             r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="SN"/>"#;
         let solver_node: Element = DATA.parse().unwrap();
-        let solver_parameters = parse_solver_node(&solver_node);
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
         assert_eq!(
             solver_parameters.get_resolution_method(),
             ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
         );
-        assert_eq!(solver_parameters.get_damping(), false);
+        assert!(!solver_parameters.get_damping().is_enabled());
+        assert_eq!(solver_parameters.get_newton_warmup_iterations(), 0);
+    }
+
+    #[test]
+    fn parsing_solver_node_newton_warmup() {
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="SN" newton_warmup="2"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_newton_warmup_iterations(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "The attribute \"newton_warmup\" is not a valid positive integer")]
+    fn parsing_solver_node_newton_warmup_invalid() {
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="SN" newton_warmup="two"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
+    }
+
+    #[test]
+    fn parsing_solver_node_schubert() {
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="SCHU" sparsity_band="1"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(
+            solver_parameters.get_resolution_method(),
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+                UpdateQuasiNewtonMethod::SchubertSparse
+            ))
+        );
+        assert_eq!(
+            solver_parameters.get_sparsity_pattern(),
+            Some(&SparsityPattern::banded(3, 1, 1))
+        );
+    }
+
+    #[test]
+    fn parsing_solver_node_tolerance_floor() {
+        const DATA: &str =
+            r#"<solver problem_size="3" max_iter="60" tolerance="0" resolution_method="NR"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        assert_eq!(
+            parse_solver_node(&solver_node).err(),
+            Some(XmlParseError::InvalidValue("The attribute \"tolerance\" at the solver node is invalid: The tolerance 0e0 is below the floor 1e-15: the residuals would never reach it. Use `SolverParameters::allow_extreme_tolerance()` to accept it".to_string()))
+        );
+
+        const ALLOWED: &str = r#"<solver problem_size="3" max_iter="60" tolerance="0" resolution_method="NR" allow_extreme_tolerance="true"/>"#;
+        let solver_node: Element = ALLOWED.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_tolerance(), 0.0);
+        assert!(solver_parameters.get_extreme_tolerance_allowed());
+
+        const NAN: &str = r#"<solver problem_size="3" max_iter="60" tolerance="NaN" resolution_method="NR" allow_extreme_tolerance="true"/>"#;
+        let solver_node: Element = NAN.parse().unwrap();
+        assert!(parse_solver_node(&solver_node).is_err());
+    }
+
+    #[test]
+    fn parsing_solver_node_preset() {
+        const DATA: &str = r#"<solver problem_size="3" preset="robust"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
+        assert_eq!(solver_parameters.get_max_iter(), 100);
+        assert_eq!(solver_parameters.get_tolerance(), 1e-6);
+        assert_eq!(
+            solver_parameters.get_resolution_method(),
+            ResolutionMethod::NewtonRaphson
+        );
+        assert!(solver_parameters.get_damping().is_enabled());
+    }
+
+    #[test]
+    fn parsing_solver_node_preset_overridden() {
+        const DATA: &str = r#"<solver problem_size="3" preset="expensive_model" max_iter="20" tolerance="1e-8" damping="false" newton_warmup="1"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_max_iter(), 20);
+        assert_eq!(solver_parameters.get_tolerance(), 1e-8);
+        assert_eq!(
+            solver_parameters.get_resolution_method(),
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+        );
+        assert!(!solver_parameters.get_damping().is_enabled());
+        assert_eq!(solver_parameters.get_newton_warmup_iterations(), 1);
+    }
+
+    #[test]
+    fn parsing_solver_node_preset_resolution_method_overridden() {
+        const DATA: &str =
+            r#"<solver problem_size="3" preset="fast_smooth" resolution_method="BROY1"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let solver_parameters = parse_solver_node(&solver_node).unwrap();
+        assert_eq!(solver_parameters.get_max_iter(), 50);
+        assert_eq!(
+            solver_parameters.get_resolution_method(),
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+                UpdateQuasiNewtonMethod::BroydenFirstMethod
+            ))
+        );
+        assert_eq!(solver_parameters.get_newton_warmup_iterations(), 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The attribute \"preset\" at the solver node has an improper value, valid values are \"robust\", \"fast_smooth\" and \"expensive_model\""
+    )]
+    fn parsing_solver_node_preset_invalid() {
+        const DATA: &str = r#"<solver problem_size="3" preset="Robust"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "The attribute \"max_iter\" is missing in the solver node")]
+    fn parsing_solver_node_without_preset() {
+        const DATA: &str = r#"<solver problem_size="3" tolerance="1e-6" resolution_method="NR"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        let _solver_parameters = parse_solver_node(&solver_node).unwrap();
+    }
+
+    #[test]
+    fn parsing_solver_node_invalid_options() {
+        let cases = [
+            (
+                r#"aitken_every="0""#,
+                "The attribute \"aitken_every\" at the solver node must be strictly positive",
+            ),
+            (
+                r#"perturbation_jitter="1""#,
+                "The attribute \"perturbation_jitter\" at the solver node must be strictly between 0 and 1",
+            ),
+            (
+                r#"error_smoothing="0""#,
+                "The attribute \"error_smoothing\" at the solver node must be in ]0, 1]",
+            ),
+            (
+                r#"consecutive_convergences="0""#,
+                "The attribute \"consecutive_convergences\" at the solver node must be strictly positive",
+            ),
+            (
+                r#"scalar_safeguard_refinements="0""#,
+                "The attribute \"scalar_safeguard_refinements\" at the solver node must be strictly positive",
+            ),
+            (
+                r#"step_acceptance_norm="Max""#,
+                "The attribute \"step_acceptance_norm\" at the solver node has an improper value, valid values are \"Stopping\", \"Update\" and \"Both\"",
+            ),
+            (
+                r#"bound_policy="Project""#,
+                "The attribute \"bound_policy\" at the solver node has an improper value, valid values are \"Clamp\", \"Fail\" and \"Ignore\"",
+            ),
+            (
+                r#"linear_solver="Gmres" gmres_restart="10" gmres_max_iter="50""#,
+                "The attribute \"gmres_tol\" is missing in the solver node",
+            ),
+            (
+                r#"inaccurate_jacobian_policy="RetryColumnWithReducedStep""#,
+                "The attribute \"max_retries\" is missing in the solver node",
+            ),
+        ];
+        for (attributes, message) in cases {
+            let data = format!(
+                r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="NR" {}/>"#,
+                attributes
+            );
+            let solver_node: Element = data.parse().unwrap();
+            let error = parse_solver_node(&solver_node).err().unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn parsing_solver_node_incompatible_linear_solver() {
+        const DATA: &str = r#"<solver problem_size="3" max_iter="60" tolerance="1e-6" resolution_method="BROY1_INV" linear_solver="Gmres" gmres_restart="10" gmres_max_iter="50" gmres_tol="1e-10"/>"#;
+        let solver_node: Element = DATA.parse().unwrap();
+        assert!(matches!(
+            parse_solver_node(&solver_node),
+            Err(XmlParseError::InvalidValue(_))
+        ));
     }
 }
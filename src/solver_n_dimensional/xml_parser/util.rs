@@ -1,64 +1,179 @@
 use minidom::Element;
+use std::fmt;
 
-pub fn parse_int_attribute(node: &Element, attribute: &str, node_info: &str) -> usize {
+use super::XmlParseError;
+use crate::solver::SolverParameters;
+
+/// Description of an iterative or residual node in the error messages, such as `iterative node id = 2`
+///
+/// It is only formatted when an error is reported: the successful parsing of a node does not allocate it
+#[derive(Debug, Copy, Clone)]
+pub struct NodeInfo {
+    pub name: &'static str,
+    pub id: usize,
+}
+
+impl fmt::Display for NodeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} node id = {}", self.name, self.id)
+    }
+}
+
+pub fn parse_int_attribute(
+    node: &Element,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<usize, XmlParseError> {
     node.attr(attribute)
-        .unwrap_or_else(|| {
-            panic!(
+        .ok_or_else(|| {
+            XmlParseError::MissingAttribute(format!(
                 "The attribute \"{}\" is missing in the {}",
                 attribute, node_info
-            )
-        })
+            ))
+        })?
         .parse::<usize>()
-        .unwrap_or_else(|_| {
-            panic!(
+        .map_err(|_| {
+            XmlParseError::InvalidValue(format!(
                 "The attribute \"{}\" is not a valid positive integer",
                 attribute
-            )
+            ))
         })
 }
 
-pub fn parse_float_attribute(node: &Element, attribute: &str, node_info: &str) -> f64 {
+pub fn parse_float_attribute(
+    node: &Element,
+    attribute: &str,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<f64, XmlParseError> {
     node
         .attr(attribute)
-        .unwrap_or_else(|| panic!("The attribute \"{}\" is missing in the {}", attribute, node_info))
+        .ok_or_else(|| XmlParseError::MissingAttribute(format!("The attribute \"{}\" is missing in the {}", attribute, node_info)))?
         .parse::<f64>()
-        .unwrap_or_else(|_| panic!("The attribute \"{}\" is not a valid float, for infinity, the valid values are \"-inf\" and \"inf\" ", attribute))
+        .map_err(|_| XmlParseError::InvalidValue(format!("The attribute \"{}\" is not a valid float, for infinity, the valid values are \"-inf\" and \"inf\" ", attribute)))
 }
 
 pub fn parse_float_attribute_with_default(
     node: &Element,
     default: f64,
     attribute: &str,
-    node_info: &str,
-) -> f64 {
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<f64, XmlParseError> {
     match node
             .attr(attribute) {
-                None => default,
+                None => Ok(default),
                 Some(value) => value
                             .parse::<f64>()
-                            .unwrap_or_else(|_| panic!("The attribute \"{}\" on node {} is not a valid float, for infinity, the valid values are \"-inf\" and \"inf\" ", attribute, node_info))
+                            .map_err(|_| XmlParseError::InvalidValue(format!("The attribute \"{}\" on node {} is not a valid float, for infinity, the valid values are \"-inf\" and \"inf\" ", attribute, node_info)))
 
             }
 }
 
-pub fn check_node_name_and_panic(node: &Element, expected_name: &str) {
+/// Check the name of a node, identified by its position below its parent
+///
+/// minidom does not keep track of the position of the nodes in the file,
+/// hence the error messages report the index of the node among the children of its parent.
+/// Comments are not children nodes, and do not change the index
+pub fn check_child_name(
+    node: &Element,
+    expected_name: &str,
+    index: usize,
+    parent_name: &str,
+) -> Result<(), XmlParseError> {
     if node.name() != expected_name {
-        panic!(
-            "The node is expected to be \"{}\", got {}",
+        return Err(XmlParseError::Structure(format!(
+            "The node {} below {} is expected to be \"{}\", got {}",
+            index,
+            parent_name,
             expected_name,
             node.name()
-        );
+        )));
+    }
+    Ok(())
+}
+
+pub fn next_child<'a>(
+    children: &mut impl Iterator<Item = &'a Element>,
+    expected_name: &str,
+    index: usize,
+    parent_name: &str,
+) -> Result<&'a Element, XmlParseError> {
+    let node = children.next().ok_or_else(|| {
+        XmlParseError::Structure(format!(
+            "The node {} below {} is missing, it is expected to be \"{}\"",
+            index, parent_name, expected_name
+        ))
+    })?;
+    check_child_name(node, expected_name, index, parent_name)?;
+    Ok(node)
+}
+
+pub fn check_no_extra_children(
+    parent: &Element,
+    expected_number: usize,
+) -> Result<(), XmlParseError> {
+    if let Some(node) = parent.children().nth(expected_number) {
+        return Err(XmlParseError::Structure(format!(
+            "Unexpected node {} below {}, got {} when only {} nodes are expected",
+            expected_number,
+            parent.name(),
+            node.name(),
+            expected_number
+        )));
     }
+    Ok(())
 }
 
-pub fn parse_id(node: &Element, expected_id: usize, node_info: &str) -> usize {
-    let id = parse_int_attribute(node, "id", node_info);
+pub fn parse_id(
+    node: &Element,
+    expected_id: usize,
+    node_info: &(impl fmt::Display + ?Sized),
+) -> Result<usize, XmlParseError> {
+    let id = parse_int_attribute(node, "id", node_info)?;
     if expected_id != id {
-        panic!(
+        return Err(XmlParseError::Structure(format!(
             "The ids must be in order starting from 0, got id {} when the expected one was {}",
             id, expected_id
-        );
+        )));
+    }
+
+    Ok(id)
+}
+
+pub fn check_dimensions(
+    parameters: &SolverParameters,
+    iteratives_number: usize,
+    residuals_number: usize,
+) -> Result<(), XmlParseError> {
+    // the problem size is always provided by the solver node
+    let problem_size = parameters.get_problem_size().unwrap_or(iteratives_number);
+    if problem_size != iteratives_number {
+        return Err(XmlParseError::DimensionMismatch(format!(
+            "Dimension mismatch, got problem_size = {} and the number of iteratives variables is {}",
+            problem_size, iteratives_number
+        )));
+    }
+
+    if problem_size != residuals_number {
+        return Err(XmlParseError::DimensionMismatch(format!(
+            "Dimension mismatch, got problem_size = {} and the number of residuals variables is {}",
+            problem_size, residuals_number
+        )));
+    }
+
+    Ok(())
+}
+
+/// The system must stay square, see [crate::residuals::ResidualsConfig::set_ignored_residuals]
+pub fn check_fixed_iteratives(
+    fixed_iteratives: usize,
+    ignored_residuals: usize,
+) -> Result<(), XmlParseError> {
+    if fixed_iteratives != ignored_residuals {
+        return Err(XmlParseError::DimensionMismatch(format!(
+            "Dimension mismatch, got {} fixed iteratives and {} ignored residuals",
+            fixed_iteratives, ignored_residuals
+        )));
     }
 
-    id
+    Ok(())
 }
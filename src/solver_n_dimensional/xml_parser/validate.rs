@@ -0,0 +1,95 @@
+use super::include::parse_root_node_from_str_with_includes;
+use super::xml_file_fd::parse_nrf_node_fd;
+use super::xml_file_jac::parse_nrf_node_jac;
+use super::{SolverConfig, XmlParseError};
+use crate::iteratives::Iterative;
+use crate::solver::{check_compatibility, CompatibilityWarning, IterativesKind, ResolutionMethod};
+
+/// Summary of a valid configuration, returned by [validate]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSummary {
+    problem_size: usize,
+    resolution_method: ResolutionMethod,
+    iteratives_number: usize,
+    residuals_number: usize,
+    finite_difference: bool,
+    compatibility_warnings: Vec<CompatibilityWarning>,
+}
+
+impl ConfigSummary {
+    fn new<T: Iterative>(config: &SolverConfig<T>, finite_difference: bool) -> Self {
+        let iteratives_kind = if finite_difference {
+            IterativesKind::WithFiniteDifference
+        } else {
+            IterativesKind::WithoutFiniteDifference
+        };
+        // the finite difference parser is intended for the models not providing their jacobian
+        let compatibility_warnings =
+            check_compatibility(config.get_parameters(), !finite_difference, iteratives_kind)
+                .err()
+                .unwrap_or_default();
+
+        ConfigSummary {
+            // the problem size is always provided by the solver node
+            problem_size: config
+                .get_parameters()
+                .get_problem_size()
+                .unwrap_or(config.get_iteratives().len()),
+            resolution_method: config.get_parameters().get_resolution_method(),
+            iteratives_number: config.get_iteratives().len(),
+            residuals_number: config.get_stopping_criterias().len(),
+            finite_difference,
+            compatibility_warnings,
+        }
+    }
+
+    pub fn get_problem_size(&self) -> usize {
+        self.problem_size
+    }
+
+    pub fn get_resolution_method(&self) -> ResolutionMethod {
+        self.resolution_method
+    }
+
+    pub fn get_iteratives_number(&self) -> usize {
+        self.iteratives_number
+    }
+
+    pub fn get_residuals_number(&self) -> usize {
+        self.residuals_number
+    }
+
+    /// The configuration has been checked as one of the [super::from_xml_finite_diff_str] parser
+    pub fn get_finite_difference(&self) -> bool {
+        self.finite_difference
+    }
+
+    /// Warnings of [check_compatibility] for the configuration,
+    /// assuming the model provides its jacobian if and only if the configuration is not a finite difference one
+    pub fn get_compatibility_warnings(&self) -> &[CompatibilityWarning] {
+        &self.compatibility_warnings
+    }
+}
+
+/// Parse and cross-check a configuration provided as a string, without any model
+///
+/// The configuration is checked in the same way as by the parsers:
+/// structure of the nodes, missing attributes, invalid values and dimensions.
+/// With `IterativesKind::WithFiniteDifference`, the configuration is checked as one of the
+/// [super::load_xml_fd_str] parser, otherwise as one of the [super::load_xml_jac_str] parser.
+///
+/// It is intended to be called from a build script or a test, see the module documentation.
+pub fn validate(
+    content: &str,
+    iteratives_kind: IterativesKind,
+) -> Result<ConfigSummary, XmlParseError> {
+    let root = parse_root_node_from_str_with_includes(content)?;
+    match iteratives_kind {
+        IterativesKind::WithFiniteDifference => {
+            parse_nrf_node_fd(&root).map(|config| ConfigSummary::new(&config, true))
+        }
+        IterativesKind::WithoutFiniteDifference => {
+            parse_nrf_node_jac(&root).map(|config| ConfigSummary::new(&config, false))
+        }
+    }
+}
@@ -1,14 +1,15 @@
 use minidom::Element;
-use std::fs;
 
 use crate::iteratives;
 use crate::residuals;
 use crate::solver::SolverParameters;
 
+use super::include::{parse_root_node_from_str_with_includes, parse_root_node_with_includes};
 use super::node_iterative_fd::parse_iteratives_fd_node;
 use super::node_residual::parse_residuals_node;
 use super::node_solver::parse_solver_node;
-use super::util::check_node_name_and_panic;
+use super::util::{check_dimensions, check_fixed_iteratives, check_no_extra_children, next_child};
+use super::{SolverConfig, XmlParseError};
 
 /// Parser for a solver operating with a model with the jacobian not provided
 ///
@@ -35,7 +36,9 @@ use super::util::check_node_name_and_panic;
 ///     <residuals>...</residuals>
 /// </nrf>
 ///
-
+///
+/// The elements are returned in the order of the fields of [SolverConfig],
+/// see [load_xml_fd] to get them bundled in this struct
 pub fn from_xml_finite_diff(
     filepath: &str,
 ) -> (
@@ -44,10 +47,49 @@ pub fn from_xml_finite_diff(
     Vec<residuals::NormalizationMethod>,
     Vec<residuals::NormalizationMethod>,
 ) {
-    let content = fs::read_to_string(filepath).unwrap();
-    parse_root_node_fd(&content)
+    load_xml_fd(filepath).into_tuple()
+}
+
+/// Parser returning the configuration as a [SolverConfig], see [from_xml_finite_diff]
+///
+/// # Panics
+///
+/// With the description of the [XmlParseError], as [from_xml_finite_diff]
+pub fn load_xml_fd(filepath: &str) -> SolverConfig<iteratives::IterativeParamsFD> {
+    parse_root_node_with_includes(filepath)
+        .and_then(|root| parse_nrf_node_fd(&root))
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Parser for a configuration provided as a string, for example embedded in the binary with `include_str!`
+///
+/// It works in the same way as [from_xml_finite_diff], the included files being resolved relative to the current directory,
+/// but the errors are returned instead of making the parser panic.
+///
+/// The configuration can be checked ahead of time with [super::validate]
+#[allow(clippy::type_complexity)]
+pub fn from_xml_finite_diff_str(
+    content: &str,
+) -> Result<
+    (
+        SolverParameters,
+        Vec<iteratives::IterativeParamsFD>,
+        Vec<residuals::NormalizationMethod>,
+        Vec<residuals::NormalizationMethod>,
+    ),
+    XmlParseError,
+> {
+    load_xml_fd_str(content).map(SolverConfig::into_tuple)
+}
+
+/// Parser of a configuration provided as a string, returning it as a [SolverConfig], see [from_xml_finite_diff_str]
+pub fn load_xml_fd_str(
+    content: &str,
+) -> Result<SolverConfig<iteratives::IterativeParamsFD>, XmlParseError> {
+    parse_root_node_from_str_with_includes(content).and_then(|root| parse_nrf_node_fd(&root))
 }
 
+#[cfg(test)]
 fn parse_root_node_fd(
     content: &str,
 ) -> (
@@ -57,37 +99,47 @@ fn parse_root_node_fd(
     Vec<residuals::NormalizationMethod>,
 ) {
     let root: Element = content.parse().unwrap();
+    parse_nrf_node_fd(&root).unwrap().into_tuple()
+}
+
+pub(super) fn parse_nrf_node_fd(
+    root: &Element,
+) -> Result<SolverConfig<iteratives::IterativeParamsFD>, XmlParseError> {
     if root.name() != "nrf" {
-        panic!("Expected the first node to be \"nrf\", got {}", root.name());
+        return Err(XmlParseError::Structure(format!(
+            "Expected the first node to be \"nrf\", got {}",
+            root.name()
+        )));
     }
 
     let mut tree = root.children();
+    let solver_node = next_child(&mut tree, "solver", 0, "nrf")?;
+    let iteratives_node = next_child(&mut tree, "iteratives", 1, "nrf")?;
+    let residuals_node = next_child(&mut tree, "residuals", 2, "nrf")?;
+    check_no_extra_children(root, 3)?;
 
-    let solver_node = tree.next().unwrap();
-    check_node_name_and_panic(solver_node, "solver");
-    let parameters = parse_solver_node(solver_node);
-
-    let iteratives_node = tree.next().unwrap();
-    check_node_name_and_panic(iteratives_node, "iteratives");
-    let iteratives = parse_iteratives_fd_node(iteratives_node);
+    let parameters = parse_solver_node(solver_node)?;
+    let iteratives = parse_iteratives_fd_node(iteratives_node)?;
+    let (stopping_criterias, update_methods, max_magnitudes, ignored_residuals) =
+        parse_residuals_node(residuals_node)?;
 
-    let residuals_node = tree.next().unwrap();
-    check_node_name_and_panic(residuals_node, "residuals");
-    let (stopping_criterias, update_methods) = parse_residuals_node(residuals_node);
+    check_dimensions(&parameters, iteratives.len(), stopping_criterias.len())?;
+    check_fixed_iteratives(
+        iteratives
+            .iter()
+            .filter(|iterative| iterative.get_fixed().is_some())
+            .count(),
+        ignored_residuals.len(),
+    )?;
 
-    if parameters.get_problem_size() != iteratives.len() {
-        panic!("Dimension mismatch, got problem_size = {} and the number of iteratives variables is {}", parameters.get_problem_size(), iteratives.len());
-    }
-
-    if parameters.get_problem_size() != stopping_criterias.len() {
-        panic!(
-            "Dimension mismatch, got problem_size = {} and the number of residuals variables is {}",
-            parameters.get_problem_size(),
-            stopping_criterias.len()
-        );
-    }
-
-    (parameters, iteratives, stopping_criterias, update_methods)
+    Ok(SolverConfig::new(
+        parameters,
+        iteratives,
+        stopping_criterias,
+        update_methods,
+        max_magnitudes,
+        ignored_residuals,
+    ))
 }
 
 #[cfg(test)]
@@ -98,7 +150,7 @@ mod tests {
 
     #[test]
     fn parsing_root_fd_1() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
@@ -113,16 +165,16 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
-            parse_root_node_fd(&DATA);
+            parse_root_node_fd(DATA);
 
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
         assert_eq!(
             solver_parameters.get_resolution_method(),
             ResolutionMethod::NewtonRaphson
         );
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
 
         let iterative_ref = iteratives::IterativeParamsFD::new(
             f64::INFINITY,
@@ -158,7 +210,7 @@ mod tests {
         expected = "Dimension mismatch, got problem_size = 4 and the number of iteratives variables is 3"
     )]
     fn parsing_root_fd_2() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="4" max_iter="60" tolerance="1e-6" damping="true" resolution_method="SN"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
@@ -173,13 +225,13 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
-            parse_root_node_fd(&DATA);
+            parse_root_node_fd(DATA);
     }
 
     #[test]
     #[should_panic(expected = "The attribute \"resolution_method\" is missing in solver node")]
     fn parsing_root_fd_3() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
@@ -194,15 +246,15 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
-            parse_root_node_fd(&DATA);
+            parse_root_node_fd(DATA);
     }
 
     #[test]
     #[should_panic(
-        expected = "The attribute \"resolution_method\" at the solver node has an improper values, valid values are \"NR\", \"SN\", \"BROY1\", \"BROY1_INV\", \"BROY2\", \"BROY2_INV\", \"GRST1\", \"GRST1_INV\", \"GRST2\", \"GRST2_INV\""
+        expected = "The attribute \"resolution_method\" at the solver node has an improper values, valid values are \"NR\", \"SN\", \"BROY1\", \"BROY1_INV\", \"BROY2\", \"BROY2_INV\", \"GRST1\", \"GRST1_INV\", \"GRST2\", \"GRST2_INV\", \"SCHU\""
     )]
     fn parsing_root_fd_4() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="SR"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
@@ -217,6 +269,85 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
-            parse_root_node_fd(&DATA);
+            parse_root_node_fd(DATA);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Unexpected node 3 below nrf, got solvre when only 3 nodes are expected"
+    )]
+    fn parsing_root_fd_trailing_node() {
+        const DATA: &str = r#"
+            <nrf>
+                <solver problem_size="1" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
+                <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
+                    <iterative id="0"/>
+                </iteratives>
+                <residuals stopping_criteria="Abs" update_method="Abs">
+                    <residual id="0"/>
+                </residuals>
+                <solvre max_iter="30"/>
+            </nrf>"#;
+        let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
+            parse_root_node_fd(DATA);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The node 1 below nrf is expected to be \"iteratives\", got iterative"
+    )]
+    fn parsing_root_fd_misspelled_node() {
+        const DATA: &str = r#"
+            <nrf>
+                <solver problem_size="1" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
+                <iterative max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
+                    <iterative id="0"/>
+                </iterative>
+                <residuals stopping_criteria="Abs" update_method="Abs">
+                    <residual id="0"/>
+                </residuals>
+            </nrf>"#;
+        let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
+            parse_root_node_fd(DATA);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The node 2 below nrf is missing, it is expected to be \"residuals\""
+    )]
+    fn parsing_root_fd_missing_node() {
+        const DATA: &str = r#"
+            <nrf>
+                <solver problem_size="1" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
+                <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
+                    <iterative id="0"/>
+                </iteratives>
+            </nrf>"#;
+        let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
+            parse_root_node_fd(DATA);
+    }
+
+    #[test]
+    fn parsing_root_fd_comments() {
+        const DATA: &str = r#"
+            <nrf>
+                <!-- solver parameters -->
+                <solver problem_size="1" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
+                <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf" dx_abs="5e-8" dx_rel="5e-9" perturbation_method="Max">
+                    <!-- <iterative id="1"/> -->
+                    <iterative id="0"/>
+                </iteratives>
+                <residuals stopping_criteria="Abs" update_method="Abs">
+                    <residual id="0"/>
+                    <!-- end of the residuals -->
+                </residuals>
+                <!-- end of the configuration -->
+            </nrf>"#;
+        let (solver_parameters, iteratives_parsed, stopping_criterias, _update_methods) =
+            parse_root_node_fd(DATA);
+
+        assert_eq!(solver_parameters.get_problem_size(), Some(1));
+        assert_eq!(iteratives_parsed.len(), 1);
+        assert_eq!(stopping_criterias.len(), 1);
     }
 }
@@ -1,14 +1,15 @@
 use minidom::Element;
-use std::fs;
 
 use crate::iteratives;
 use crate::residuals;
 use crate::solver::SolverParameters;
 
+use super::include::{parse_root_node_from_str_with_includes, parse_root_node_with_includes};
 use super::node_iterative_jac::parse_iteratives_jac_node;
 use super::node_residual::parse_residuals_node;
 use super::node_solver::parse_solver_node;
-use super::util::check_node_name_and_panic;
+use super::util::{check_dimensions, check_fixed_iteratives, check_no_extra_children, next_child};
+use super::{SolverConfig, XmlParseError};
 
 /// Parser for a solver operating with a model with the jacobian provided
 ///
@@ -46,6 +47,9 @@ use super::util::check_node_name_and_panic;
 ///     - "BROY2_INV" for [Broyden Second Method](crate::solver::UpdateQuasiNewtonMethod::BroydenSecondMethod) approximating the [inverse jacobian](crate::solver::QuasiNewtonMethod::InverseJacobianUpdate)
 ///     - "GRST1_INV" for [Greenstadt First Method](crate::solver::UpdateQuasiNewtonMethod::GreenstadtFirstMethod) approximating the [inverse jacobian](crate::solver::QuasiNewtonMethod::InverseJacobianUpdate)
 ///     - "GRST2_INV" for [Greenstadt Second Method](crate::solver::UpdateQuasiNewtonMethod::GreenstadtSecondMethod) approximating the [inverse jacobian](crate::solver::QuasiNewtonMethod::InverseJacobianUpdate)
+///     - "SCHU" for [Schubert Sparse Method](crate::solver::UpdateQuasiNewtonMethod::SchubertSparse) approximating the [jacobian](crate::solver::QuasiNewtonMethod::JacobianUpdate),
+///       with the sparsity pattern declared by the optional `sparsity_band` attribute
+///       (number of diagonals below and above the main one, see [SparsityPattern::banded](crate::solver::SparsityPattern::banded))
 ///
 ///```xml
 /// <?xml version="1.0" encoding="UTF-8" standalone="no" ?>
@@ -124,7 +128,7 @@ use super::util::check_node_name_and_panic;
 /// let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
 /// let residuals_config =
 ///    nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-/// let problem_size = solver_parameters.get_problem_size();
+/// let problem_size = solver_parameters.get_problem_size().unwrap();
 ///
 /// let init = nalgebra::DVector::zeros(5);
 ///
@@ -135,6 +139,9 @@ use super::util::check_node_name_and_panic;
 ///    &residuals_config,
 /// );
 ///```
+///
+/// The elements are returned in the order of the fields of [SolverConfig],
+/// see [load_xml_jac] to get them bundled in this struct
 pub fn from_xml_jacobian(
     filepath: &str,
 ) -> (
@@ -143,10 +150,49 @@ pub fn from_xml_jacobian(
     Vec<residuals::NormalizationMethod>,
     Vec<residuals::NormalizationMethod>,
 ) {
-    let content = fs::read_to_string(filepath).unwrap();
-    parse_root_node_jac(&content)
+    load_xml_jac(filepath).into_tuple()
+}
+
+/// Parser returning the configuration as a [SolverConfig], see [from_xml_jacobian]
+///
+/// # Panics
+///
+/// With the description of the [XmlParseError], as [from_xml_jacobian]
+pub fn load_xml_jac(filepath: &str) -> SolverConfig<iteratives::IterativeParams> {
+    parse_root_node_with_includes(filepath)
+        .and_then(|root| parse_nrf_node_jac(&root))
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Parser for a configuration provided as a string, for example embedded in the binary with `include_str!`
+///
+/// It works in the same way as [from_xml_jacobian], the included files being resolved relative to the current directory,
+/// but the errors are returned instead of making the parser panic.
+///
+/// The configuration can be checked ahead of time with [super::validate]
+#[allow(clippy::type_complexity)]
+pub fn from_xml_jacobian_str(
+    content: &str,
+) -> Result<
+    (
+        SolverParameters,
+        Vec<iteratives::IterativeParams>,
+        Vec<residuals::NormalizationMethod>,
+        Vec<residuals::NormalizationMethod>,
+    ),
+    XmlParseError,
+> {
+    load_xml_jac_str(content).map(SolverConfig::into_tuple)
+}
+
+/// Parser of a configuration provided as a string, returning it as a [SolverConfig], see [from_xml_jacobian_str]
+pub fn load_xml_jac_str(
+    content: &str,
+) -> Result<SolverConfig<iteratives::IterativeParams>, XmlParseError> {
+    parse_root_node_from_str_with_includes(content).and_then(|root| parse_nrf_node_jac(&root))
 }
 
+#[cfg(test)]
 fn parse_root_node_jac(
     content: &str,
 ) -> (
@@ -156,37 +202,47 @@ fn parse_root_node_jac(
     Vec<residuals::NormalizationMethod>,
 ) {
     let root: Element = content.parse().unwrap();
+    parse_nrf_node_jac(&root).unwrap().into_tuple()
+}
+
+pub(super) fn parse_nrf_node_jac(
+    root: &Element,
+) -> Result<SolverConfig<iteratives::IterativeParams>, XmlParseError> {
     if root.name() != "nrf" {
-        panic!("Expected the first node to be \"nrf\", got {}", root.name());
+        return Err(XmlParseError::Structure(format!(
+            "Expected the first node to be \"nrf\", got {}",
+            root.name()
+        )));
     }
 
     let mut tree = root.children();
+    let solver_node = next_child(&mut tree, "solver", 0, "nrf")?;
+    let iteratives_node = next_child(&mut tree, "iteratives", 1, "nrf")?;
+    let residuals_node = next_child(&mut tree, "residuals", 2, "nrf")?;
+    check_no_extra_children(root, 3)?;
 
-    let solver_node = tree.next().unwrap();
-    check_node_name_and_panic(solver_node, "solver");
-    let parameters = parse_solver_node(solver_node);
-
-    let iteratives_node = tree.next().unwrap();
-    check_node_name_and_panic(iteratives_node, "iteratives");
-    let iteratives = parse_iteratives_jac_node(iteratives_node);
+    let parameters = parse_solver_node(solver_node)?;
+    let iteratives = parse_iteratives_jac_node(iteratives_node)?;
+    let (stopping_criterias, update_methods, max_magnitudes, ignored_residuals) =
+        parse_residuals_node(residuals_node)?;
 
-    let residuals_node = tree.next().unwrap();
-    check_node_name_and_panic(residuals_node, "residuals");
-    let (stopping_criterias, update_methods) = parse_residuals_node(residuals_node);
-
-    if parameters.get_problem_size() != iteratives.len() {
-        panic!("Dimension mismatch, got problem_size = {} and the number of iteratives variables is {}", parameters.get_problem_size(), iteratives.len());
-    }
-
-    if parameters.get_problem_size() != stopping_criterias.len() {
-        panic!(
-            "Dimension mismatch, got problem_size = {} and the number of residuals variables is {}",
-            parameters.get_problem_size(),
-            stopping_criterias.len()
-        );
-    }
+    check_dimensions(&parameters, iteratives.len(), stopping_criterias.len())?;
+    check_fixed_iteratives(
+        iteratives
+            .iter()
+            .filter(|iterative| iterative.get_fixed().is_some())
+            .count(),
+        ignored_residuals.len(),
+    )?;
 
-    (parameters, iteratives, stopping_criterias, update_methods)
+    Ok(SolverConfig::new(
+        parameters,
+        iteratives,
+        stopping_criterias,
+        update_methods,
+        max_magnitudes,
+        ignored_residuals,
+    ))
 }
 
 #[cfg(test)]
@@ -198,7 +254,7 @@ mod tests {
 
     #[test]
     fn parsing_root_1() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="3" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
@@ -213,16 +269,16 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
-            parse_root_node_jac(&DATA);
+            parse_root_node_jac(DATA);
 
-        assert_eq!(solver_parameters.get_problem_size(), 3);
+        assert_eq!(solver_parameters.get_problem_size(), Some(3));
         assert_eq!(solver_parameters.get_max_iter(), 60);
         assert_eq!(solver_parameters.get_tolerance(), 1e-6);
         assert_eq!(
             solver_parameters.get_resolution_method(),
             ResolutionMethod::NewtonRaphson
         );
-        assert_eq!(solver_parameters.get_damping(), true);
+        assert!(solver_parameters.get_damping().is_enabled());
 
         let iterative_ref = iteratives::IterativeParams::new(
             f64::INFINITY,
@@ -255,7 +311,7 @@ mod tests {
         expected = "Dimension mismatch, got problem_size = 4 and the number of iteratives variables is 3"
     )]
     fn parsing_root_2() {
-        const DATA: &'static str = r#"
+        const DATA: &str = r#"
             <nrf>
                 <solver problem_size="4" max_iter="60" tolerance="1e-6" damping="true" resolution_method="NR"/>
                 <iteratives max_step_abs="inf" max_step_rel="inf" min_value="-inf" max_value="inf">
@@ -270,6 +326,6 @@ mod tests {
                 </residuals>
             </nrf>"#;
         let (_solver_parameters, _iteratives_parsed, _stopping_criterias, _update_methods) =
-            parse_root_node_jac(&DATA);
+            parse_root_node_jac(DATA);
     }
 }
@@ -3,37 +3,140 @@ use util::test_cases::broyden1965::*;
 
 use nrf::model::Model;
 
+const FILEPATH: &str = "./tests/advanced_parametrization/broyden_case10.xml";
+
+fn assert_solution(user_model: &impl Model<nalgebra::Dyn>) {
+    let solution = solution_broyden1965_case10();
+
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            user_model.get_iteratives()[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
 #[test]
 fn broyden_case10_fd() {
-    const FILEPATH: &'static str = "./tests/advanced_parametrization/broyden_case10.xml";
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+
+    config
+        .with_rootfinder(init_broyden1965_case10(), |rf| rf.solve(&mut user_model))
+        .unwrap();
 
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH);
+    assert_solution(&user_model);
+}
+
+#[test]
+fn broyden_case10_jac() {
+    // the finite difference attributes are not used by the jacobian parser
+    let config = nrf::xml_parser::load_xml_jac(FILEPATH);
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case10,
+        broyden1965_case10_jac,
+    );
+
+    config
+        .with_rootfinder(init_broyden1965_case10(), |rf| rf.solve(&mut user_model))
+        .unwrap();
+
+    assert_solution(&user_model);
+}
 
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
+#[test]
+fn broyden_case10_fd_built_elements() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let iteratives = config.build_iteratives();
+    let residuals_config = config.build_residuals_config();
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
 
     let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
+        config.get_parameters().clone(),
         init_broyden1965_case10(),
         &iteratives,
         &residuals_config,
     );
-
     let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
 
     rf.solve(&mut user_model).unwrap();
 
-    let solution = solution_broyden1965_case10();
+    assert_solution(&user_model);
+}
 
-    for i in 0..problem_size {
-        assert!(float_cmp::approx_eq!(
-            f64,
-            user_model.get_iteratives()[i],
-            solution[i],
-            epsilon = 1e-6
-        ));
-    }
+#[test]
+fn broyden_case10_fd_solve_from_config() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+    let report_ref = config
+        .with_rootfinder(init_broyden1965_case10(), |rf| {
+            rf.solve_with_report(&mut user_model)
+        })
+        .unwrap();
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+    let report =
+        nrf::solver::solve_from_config(&config, &mut user_model, init_broyden1965_case10())
+            .unwrap();
+
+    assert_eq!(report, report_ref);
+    assert_solution(&user_model);
+}
+
+#[test]
+fn broyden_case10_jac_solve_from_config() {
+    let config = nrf::xml_parser::load_xml_jac(FILEPATH);
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
+
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case10,
+        broyden1965_case10_jac,
+    );
+    let report_ref = config
+        .with_rootfinder(init_broyden1965_case10(), |rf| {
+            rf.solve_with_report(&mut user_model)
+        })
+        .unwrap();
+
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case10,
+        broyden1965_case10_jac,
+    );
+    let report =
+        nrf::solver::solve_from_config(&config, &mut user_model, init_broyden1965_case10())
+            .unwrap();
+
+    assert_eq!(report, report_ref);
+    assert_solution(&user_model);
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n model.len_problem() = 3 and problem_size = 2")]
+fn solve_from_config_model_size_mismatch() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let mut user_model = nrf::model::UserModelFromFunction::new(3, broyden1965_case10);
+
+    let _ = nrf::solver::solve_from_config(&config, &mut user_model, init_broyden1965_case10());
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n initial_guess.len() = 3 and problem_size = 2")]
+fn solve_from_config_initial_guess_size_mismatch() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+
+    let _ = nrf::solver::solve_from_config(
+        &config,
+        &mut user_model,
+        nalgebra::DVector::from_element(3, 1.0),
+    );
 }
@@ -1,2 +1,4 @@
 #[cfg(feature = "xml_config_file")]
 mod broyden;
+mod normalization;
+mod rel_safeguard;
@@ -0,0 +1,360 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+
+const PRESSURE_SCALE: f64 = 1e5;
+
+// Both members of the residual are close to zero at the solution
+fn pressure_balance(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = nalgebra::DVector::zeros(1);
+    outputs[0] = PRESSURE_SCALE * (x[0] * x[0] - 2.0);
+    outputs
+}
+
+fn solve_pressure_balance(
+    stopping_criteria: residuals::NormalizationMethod,
+) -> Result<f64, String> {
+    let problem_size = 1;
+    let init = nalgebra::DVector::from_vec(vec![1.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![stopping_criteria; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, pressure_balance);
+
+    match rf.solve(&mut user_model) {
+        Ok(()) => Ok(user_model.get_iteratives()[0]),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[test]
+fn rel_normalization_near_zero_sum() {
+    let result = solve_pressure_balance(residuals::NormalizationMethod::Rel);
+    assert!(result
+        .unwrap_err()
+        .starts_with("Convergence not reached: 1 residual(s) above the tolerance"));
+}
+
+#[test]
+fn rel_to_reference_normalization_near_zero_sum() {
+    let result = solve_pressure_balance(residuals::NormalizationMethod::RelToReference(
+        PRESSURE_SCALE,
+    ));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        result.unwrap(),
+        std::f64::consts::SQRT_2,
+        epsilon = 1e-6
+    ));
+}
+
+#[test]
+fn rel_to_reference_update_method() {
+    let problem_size = 1;
+    let init = nalgebra::DVector::from_vec(vec![1.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals =
+        vec![residuals::NormalizationMethod::RelToReference(PRESSURE_SCALE); problem_size];
+    let update_methods =
+        vec![residuals::NormalizationMethod::RelToReference(PRESSURE_SCALE); problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, pressure_balance);
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert!(float_cmp::approx_eq!(
+        f64,
+        user_model.get_iteratives()[0],
+        std::f64::consts::SQRT_2,
+        epsilon = 1e-6
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "The reference of the residual 1 must be strictly positive and finite, got 0"
+)]
+fn rel_to_reference_non_positive_reference() {
+    let stopping_residuals = vec![
+        residuals::NormalizationMethod::Abs,
+        residuals::NormalizationMethod::RelToReference(0.0),
+    ];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let _res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+}
+
+#[test]
+fn adapt_scaled_normalization_near_zero_sum() {
+    let result =
+        solve_pressure_balance(residuals::NormalizationMethod::AdaptScaled(PRESSURE_SCALE));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        result.unwrap(),
+        std::f64::consts::SQRT_2,
+        epsilon = 1e-6
+    ));
+}
+
+#[test]
+fn adapt_scaled_normalization_values() {
+    let values = [(0.1, -0.15), (101.1, 101.25), (-3.0, 7.5), (2.5e6, -1.0e5)];
+
+    for (left, right) in values {
+        assert_eq!(
+            residuals::normalization(left, right, residuals::NormalizationMethod::Adapt),
+            residuals::normalization(
+                left,
+                right,
+                residuals::NormalizationMethod::AdaptScaled(1.0)
+            )
+        );
+    }
+
+    // Behaves like Abs/scale for small values and like Rel for big values
+    let small_values =
+        residuals::normalization(0.1, -0.15, residuals::NormalizationMethod::AdaptScaled(1e5));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        small_values,
+        0.25 / (1e5 + 0.025),
+        ulps = 2
+    ));
+
+    let big_values = residuals::normalization(
+        2.0e10,
+        1.9e10,
+        residuals::NormalizationMethod::AdaptScaled(1e5),
+    );
+    let big_values_rel =
+        residuals::normalization(2.0e10, 1.9e10, residuals::NormalizationMethod::Rel);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        big_values,
+        big_values_rel,
+        epsilon = 1e-5 * big_values_rel.abs()
+    ));
+}
+
+#[test]
+fn adapt_scaled_normalization_derivative() {
+    let methods = [
+        residuals::NormalizationMethod::Adapt,
+        residuals::NormalizationMethod::AdaptScaled(1.0),
+        residuals::NormalizationMethod::AdaptScaled(1e5),
+        residuals::NormalizationMethod::AdaptScaled(1e-3),
+    ];
+    // (left, right, derivative of left, derivative of right)
+    let points = [
+        (0.1, -0.15, 1.0, 0.5),
+        (101.1, 101.25, -2.0, 3.0),
+        (-3.0e5, 7.5e4, 0.7, -1.2),
+        (-4.0, -6.0, 1.0, 1.0),
+    ];
+
+    for method in methods {
+        for (left, right, d_left, d_right) in points {
+            let deriv = residuals::deriv_normalization(left, right, d_left, d_right, method);
+
+            // central finite difference along the direction (d_left, d_right)
+            let h = 1e-6 * (1.0 + left.abs().max(right.abs()));
+            let forward = residuals::normalization(left + h * d_left, right + h * d_right, method);
+            let backward = residuals::normalization(left - h * d_left, right - h * d_right, method);
+            let deriv_fd = (forward - backward) / (2.0 * h);
+
+            assert!(
+                (deriv - deriv_fd).abs() <= 1e-6 * (1.0 + deriv.abs()),
+                "{}: derivative {} at ({}, {}), finite difference {}",
+                method,
+                deriv,
+                left,
+                right,
+                deriv_fd
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "The scale of the residual 0 must be strictly positive and finite, got -1"
+)]
+fn adapt_scaled_non_positive_scale() {
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![
+        residuals::NormalizationMethod::AdaptScaled(-1.0),
+        residuals::NormalizationMethod::Abs,
+    ];
+    let _res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+}
+
+#[test]
+fn rel_floored_normalization_values() {
+    let values = [(0.1, -0.15), (101.1, 101.25), (-3.0, 7.5), (2.5e6, -1.0e5)];
+
+    for (left, right) in values {
+        // A null floor gives the relative normalization
+        assert_eq!(
+            residuals::normalization(left, right, residuals::NormalizationMethod::Rel),
+            residuals::normalization(left, right, residuals::NormalizationMethod::RelFloored(0.0))
+        );
+    }
+
+    // Behaves like Abs/floor for small values and like Rel for big values
+    let small_values =
+        residuals::normalization(0.1, -0.15, residuals::NormalizationMethod::RelFloored(1e5));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        small_values,
+        0.25 / 1e5,
+        ulps = 2
+    ));
+
+    let big_values = residuals::normalization(
+        2.0e10,
+        1.9e10,
+        residuals::NormalizationMethod::RelFloored(1e5),
+    );
+    let big_values_rel =
+        residuals::normalization(2.0e10, 1.9e10, residuals::NormalizationMethod::Rel);
+    assert_eq!(big_values, big_values_rel);
+}
+
+#[test]
+fn rel_floored_normalization_derivative() {
+    let methods = [
+        residuals::NormalizationMethod::Rel,
+        residuals::NormalizationMethod::RelFloored(1.0),
+        residuals::NormalizationMethod::RelFloored(1e5),
+        residuals::NormalizationMethod::RelFloored(1e-3),
+    ];
+    // (left, right, derivative of left, derivative of right),
+    // the floors being active or not depending on the point
+    let points = [
+        (0.1, -0.15, 1.0, 0.5),
+        (101.1, 101.25, -2.0, 3.0),
+        (-3.0e5, 7.5e4, 0.7, -1.2),
+        (-4.0, -6.0, 1.0, 1.0),
+        (2.0, -2.0005, 1.0, 0.3),
+    ];
+
+    for method in methods {
+        for (left, right, d_left, d_right) in points {
+            let deriv = residuals::deriv_normalization(left, right, d_left, d_right, method);
+
+            // central finite difference along the direction (d_left, d_right)
+            let h = 1e-7 * (1.0 + left.abs().max(right.abs()));
+            let forward = residuals::normalization(left + h * d_left, right + h * d_right, method);
+            let backward = residuals::normalization(left - h * d_left, right - h * d_right, method);
+            let deriv_fd = (forward - backward) / (2.0 * h);
+
+            assert!(
+                (deriv - deriv_fd).abs() <= 1e-5 * (1.0 + deriv.abs()),
+                "{}: derivative {} at ({}, {}), finite difference {}",
+                method,
+                deriv,
+                left,
+                right,
+                deriv_fd
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The floor of the residual 0 must be positive and finite, got -1")]
+fn rel_floored_negative_floor() {
+    let stopping_residuals = vec![residuals::NormalizationMethod::RelFloored(-1.0)];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let _res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+}
+
+// The right member is null: the relative update residual is either -2 or 2,
+// its sum with the left one crossing zero at the solution
+fn square_root_of_two(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] - 2.0])
+}
+
+fn solve_square_root_of_two(rel_floor: f64) -> Result<f64, String> {
+    let problem_size = 1;
+    let init = nalgebra::DVector::from_vec(vec![1.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let mut residual_config = residuals::ResidualConfig::new(
+        residuals::NormalizationMethod::Abs,
+        residuals::NormalizationMethod::Rel,
+    );
+    residual_config.set_rel_floor(rel_floor);
+    let (stopping_residuals, update_methods) =
+        residuals::ResidualsConfig::convert_into_vecs(vec![residual_config; problem_size]);
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square_root_of_two);
+
+    match rf.solve(&mut user_model) {
+        Ok(()) => Ok(user_model.get_iteratives()[0]),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[test]
+fn rel_floor_default() {
+    let residual_config = residuals::ResidualConfig::new(
+        residuals::NormalizationMethod::Rel,
+        residuals::NormalizationMethod::Rel,
+    );
+    assert_eq!(residual_config.get_rel_floor(), 0.0);
+    let (stopping_residuals, update_methods) =
+        residuals::ResidualsConfig::convert_into_vecs(vec![residual_config]);
+    assert_eq!(stopping_residuals, [residuals::NormalizationMethod::Rel]);
+    assert_eq!(update_methods, [residuals::NormalizationMethod::Rel]);
+
+    assert!(solve_square_root_of_two(0.0).is_err());
+}
+
+#[test]
+fn rel_floor_near_zero_sum() {
+    let result = solve_square_root_of_two(1.0);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        result.unwrap(),
+        std::f64::consts::SQRT_2,
+        epsilon = 1e-6
+    ));
+}
+
+#[test]
+#[should_panic(
+    expected = "The floor of the relative normalization must be positive and finite, got NaN"
+)]
+fn rel_floor_invalid() {
+    let mut residual_config = residuals::ResidualConfig::default();
+    residual_config.set_rel_floor(f64::NAN);
+}
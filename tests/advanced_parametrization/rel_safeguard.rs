@@ -0,0 +1,168 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals::{self, NormalizationMethod};
+
+/// Equation `x = 1` with the analytical jacobian
+///
+/// With the relative normalization, the Newton step is `x - (x^2 - 1)/2`:
+/// starting from `x = 3`, the first iterate is `x = -1`,
+/// where the mean of the left and right members is null
+struct CrossingModel {
+    iteratives: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for CrossingModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.iteratives.clone(),
+            nalgebra::DVector::from_vec(vec![1.0]),
+        )
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, nrf::model::ModelError<Self, nalgebra::Dyn>>
+    {
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::from_element(1, 1, 1.0),
+            nalgebra::DMatrix::zeros(1, 1),
+        ))
+    }
+}
+
+fn solve_crossing(
+    rel_safeguard_epsilon: Option<f64>,
+    log_path: Option<&str>,
+) -> Result<f64, String> {
+    let vec_iter_params = iteratives::default_vec_iteratives(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_criterias = [NormalizationMethod::Abs];
+    let update_methods = [NormalizationMethod::Rel];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+    if let Some(epsilon) = rel_safeguard_epsilon {
+        res_config.set_rel_safeguard_epsilon(epsilon);
+    }
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![3.0]),
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    #[cfg(feature = "debug_log")]
+    if let Some(log_path) = log_path {
+        rf.activate_debug(log_path);
+    }
+    #[cfg(not(feature = "debug_log"))]
+    let _ = log_path;
+
+    let mut user_model = CrossingModel {
+        iteratives: nalgebra::DVector::zeros(1),
+    };
+    rf.solve(&mut user_model)
+        .map(|()| user_model.get_iteratives()[0])
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn rel_safeguard_default() {
+    let solution = solve_crossing(None, None).unwrap();
+    assert!(float_cmp::approx_eq!(f64, solution, 1.0, epsilon = 1e-6));
+}
+
+#[test]
+fn rel_safeguard_disabled() {
+    assert!(solve_crossing(Some(0.0), None)
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+}
+
+#[test]
+fn rel_safeguard_thresholds() {
+    let stopping_criterias = [NormalizationMethod::Abs; 3];
+    let update_methods = [
+        NormalizationMethod::Rel,
+        NormalizationMethod::Rel,
+        NormalizationMethod::Abs,
+    ];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+    assert_eq!(
+        res_config.get_rel_safeguard_epsilons(),
+        &[residuals::DEFAULT_REL_SAFEGUARD_EPSILON; 3]
+    );
+
+    res_config.set_rel_safeguard_epsilons(vec![0.1, 0.0, 0.1]);
+    let values = residuals::ResidualsValues::new(
+        nalgebra::DVector::from_vec(vec![0.05, 0.05, 0.05]),
+        nalgebra::DVector::from_vec(vec![-0.1, -0.1, -0.1]),
+    );
+    assert_eq!(res_config.get_safeguarded_residuals(&values), vec![0]);
+    assert_eq!(
+        res_config.get_safeguarded_update_methods(&values),
+        vec![
+            NormalizationMethod::Adapt,
+            NormalizationMethod::Rel,
+            NormalizationMethod::Abs
+        ]
+    );
+    let update_residuals = res_config.evaluate_update_residuals(&values);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        update_residuals[0],
+        residuals::normalization(0.05, -0.1, NormalizationMethod::Adapt),
+        ulps = 2
+    ));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        update_residuals[1],
+        residuals::normalization(0.05, -0.1, NormalizationMethod::Rel),
+        ulps = 2
+    ));
+}
+
+#[test]
+#[should_panic(expected = "The safeguard threshold of the residual 1 must be positive and finite")]
+fn rel_safeguard_negative_threshold() {
+    let methods = [NormalizationMethod::Rel; 2];
+    let mut res_config = residuals::ResidualsConfig::new(&methods, &methods);
+    res_config.set_rel_safeguard_epsilons(vec![1e-8, -1.0]);
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn rel_safeguard_in_log() {
+    const LOG_PATH: &str = "./tests/advanced_parametrization/rel_safeguard_log.txt";
+    solve_crossing(None, Some(LOG_PATH)).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains(
+        "Relative normalization near zero, the adaptative normalization is used for the update of the residuals [0]"
+    ));
+}
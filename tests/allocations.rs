@@ -0,0 +1,136 @@
+//! Allocation counting on the jacobian and residuals paths
+//!
+//! This test is a separate binary as it requires its own global allocator.
+//! The counter is per thread so that tests running in parallel do not interfere.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::solver::{JacobianMatrix, UpdateQuasiNewtonMethod};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    let start = ALLOCATIONS.with(|count| count.get());
+    f();
+    ALLOCATIONS.with(|count| count.get()) - start
+}
+
+const METHODS: [UpdateQuasiNewtonMethod; 4] = [
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    UpdateQuasiNewtonMethod::BroydenSecondMethod,
+    UpdateQuasiNewtonMethod::GreenstadtFirstMethod,
+    UpdateQuasiNewtonMethod::GreenstadtSecondMethod,
+];
+
+fn jacobian_example(problem_size: usize) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_fn(problem_size, problem_size, |i, j| {
+        if i == j {
+            4.0 + i as f64
+        } else {
+            1.0 / (1.0 + i as f64 + 2.0 * j as f64)
+        }
+    })
+}
+
+#[test]
+fn no_allocation_when_approximating_jacobian() {
+    let problem_size = 20;
+    let s = nalgebra::DVector::from_element(problem_size, 1e-2);
+    let y = nalgebra::DVector::from_fn(problem_size, |i, _| 1e-2 * (1.0 + i as f64));
+    let f = nalgebra::DVector::from_element(problem_size, 1.0);
+
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_example(problem_size))
+        .unwrap();
+
+    for method in METHODS {
+        let allocations = count_allocations(|| {
+            nrf::solver::approximate_jacobian(&mut jacobian, method, &s, &y, &f).unwrap();
+        });
+        assert_eq!(allocations, 0, "{}", method);
+    }
+}
+
+#[test]
+fn no_allocation_when_approximating_inverse_jacobian() {
+    let problem_size = 20;
+    let s = nalgebra::DVector::from_element(problem_size, 1e-2);
+    let y = nalgebra::DVector::from_fn(problem_size, |i, _| 1e-2 * (1.0 + i as f64));
+    let f = nalgebra::DVector::from_element(problem_size, 1.0);
+
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_example(problem_size))
+        .unwrap();
+
+    for method in METHODS {
+        let allocations = count_allocations(|| {
+            nrf::solver::approximate_inv_jacobian(&mut jacobian, method, &s, &y, &f);
+        });
+        assert_eq!(allocations, 0, "{}", method);
+    }
+}
+
+#[test]
+fn no_allocation_when_inverting_exact_jacobian_after_reset() {
+    let problem_size = 20;
+
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_example(problem_size))
+        .unwrap();
+    jacobian.reset();
+
+    let matrix = jacobian_example(problem_size);
+    let allocations = count_allocations(|| {
+        jacobian.update_jacobian_with_exact_value(matrix).unwrap();
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn no_allocation_when_writing_residuals() {
+    let problem_size = 20;
+    let mut user_model =
+        nrf::model::UserModelFromFunction::new(problem_size, |x| x.component_mul(x));
+    user_model.set_iteratives(&nalgebra::DVector::from_element(problem_size, 2.0));
+    user_model.evaluate().unwrap();
+
+    let mut residuals = nrf::residuals::ResidualsValues::new(
+        nalgebra::DVector::zeros(problem_size),
+        nalgebra::DVector::zeros(problem_size),
+    );
+
+    let allocations = count_allocations(|| {
+        user_model.write_residuals(&mut residuals);
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(residuals.get_values(19), (4.0, 0.0));
+}
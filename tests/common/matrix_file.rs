@@ -0,0 +1,51 @@
+/// Iteration of the header comment and values of a jacobian file written in the CSV format
+pub fn read_csv_matrix_file(path: &str) -> (usize, nalgebra::DMatrix<f64>) {
+    let content = std::fs::read_to_string(path).unwrap();
+    let mut lines = content.lines();
+    let iteration = parse_header(lines.next().unwrap(), "# Jacobian at iteration ");
+
+    let rows: Vec<Vec<f64>> = lines
+        .map(|line| {
+            line.split(',')
+                .map(|value| value.parse().unwrap())
+                .collect()
+        })
+        .collect();
+    let n = rows.len();
+    let matrix = nalgebra::DMatrix::from_fn(n, rows[0].len(), |i, j| rows[i][j]);
+    (iteration, matrix)
+}
+
+/// Iteration of the header comment and values of a jacobian file written in the Matrix Market format
+pub fn read_matrix_market_file(path: &str) -> (usize, nalgebra::DMatrix<f64>) {
+    let content = std::fs::read_to_string(path).unwrap();
+    let mut lines = content.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "%%MatrixMarket matrix coordinate real general"
+    );
+    let iteration = parse_header(lines.next().unwrap(), "% Jacobian at iteration ");
+
+    let sizes: Vec<usize> = lines
+        .next()
+        .unwrap()
+        .split(' ')
+        .map(|value| value.parse().unwrap())
+        .collect();
+    let mut matrix = nalgebra::DMatrix::zeros(sizes[0], sizes[1]);
+    let mut entries = 0;
+    for line in lines {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let i: usize = fields[0].parse().unwrap();
+        let j: usize = fields[1].parse().unwrap();
+        // the indices are 1-based
+        matrix[(i - 1, j - 1)] = fields[2].parse().unwrap();
+        entries += 1;
+    }
+    assert_eq!(entries, sizes[2]);
+    (iteration, matrix)
+}
+
+fn parse_header(line: &str, prefix: &str) -> usize {
+    line.strip_prefix(prefix).unwrap().parse().unwrap()
+}
@@ -1,7 +1,17 @@
 mod matrix;
+#[cfg(feature = "std")]
+mod matrix_file;
+mod outcome;
+mod pipe_flow;
 mod run_case_closure;
 mod run_case_function;
 
 pub use matrix::float_matrix_comparison;
+#[cfg(feature = "std")]
+pub use matrix_file::{read_csv_matrix_file, read_matrix_market_file};
+#[cfg(feature = "debug_log")]
+pub use outcome::read_log;
+pub use outcome::{solve_function_fd, solve_outcome, Outcome};
+pub use pipe_flow::{pipe_flow_normalization, PipeFlowModel};
 pub use run_case_closure::{run_closure_case_fd, run_closure_case_jac};
 pub use run_case_function::{run_function_case_fd, run_function_case_jac};
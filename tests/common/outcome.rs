@@ -0,0 +1,53 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+
+/// Outcome of a resolution: the result, the final iteratives, the number of iterations and of model evaluations
+pub type Outcome = (Result<(), String>, nalgebra::DVector<f64>, usize, usize);
+
+/// Solve the model, the error of the resolution being given by its message
+pub fn solve_outcome<T, M>(
+    rf: &mut nrf::solver::RootFinder<T, nalgebra::Dyn>,
+    user_model: &mut M,
+) -> Outcome
+where
+    T: iteratives::Iterative + std::fmt::Display + std::fmt::Debug,
+    M: Model<nalgebra::Dyn>,
+{
+    let result = rf.solve(user_model).map_err(|error| error.to_string());
+    (
+        result,
+        user_model.get_iteratives(),
+        rf.get_iter(),
+        rf.get_model_evaluations(),
+    )
+}
+
+/// Solve the function from the initial guess,
+/// with the default finite difference iteratives and absolute residuals
+pub fn solve_function_fd(
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    init: nalgebra::DVector<f64>,
+    parameters: nrf::solver::SolverParameters,
+) -> Outcome {
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+    solve_outcome(&mut rf, &mut user_model)
+}
+
+/// Content of the debug log written at `log_path`, the file being removed
+#[cfg(feature = "debug_log")]
+pub fn read_log(log_path: &str) -> String {
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    log
+}
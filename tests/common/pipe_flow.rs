@@ -0,0 +1,79 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::residuals::{self, NormalizationMethod};
+
+use util::test_cases::pipe_flow::{pipe_flow, pipe_flow_jac, PipeFlowParameters};
+
+/// Model of the flow of a perfect gas in a pipe, with its analytical jacobian
+///
+/// See `util::test_cases::pipe_flow`
+pub struct PipeFlowModel {
+    params: PipeFlowParameters,
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    right: nalgebra::DVector<f64>,
+}
+
+impl PipeFlowModel {
+    pub fn new(params: PipeFlowParameters) -> Self {
+        PipeFlowModel {
+            params,
+            iteratives: nalgebra::DVector::zeros(4),
+            left: nalgebra::DVector::zeros(4),
+            right: nalgebra::DVector::zeros(4),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for PipeFlowModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        4
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        (self.left, self.right) = pipe_flow(&self.params, &self.iteratives);
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, nrf::model::ModelError<Self, nalgebra::Dyn>>
+    {
+        let (left, right) = pipe_flow_jac(&self.params, &self.iteratives);
+        Ok(residuals::JacobianValues::new(left, right))
+    }
+}
+
+/// Normalization of the residuals suited to the magnitudes of the pipe flow equations
+///
+/// The pressures and the enthalpy are compared relatively,
+/// while the mass flow, which can be null, uses the adaptative normalization
+pub fn pipe_flow_normalization() -> Vec<NormalizationMethod> {
+    vec![
+        NormalizationMethod::Adapt,
+        NormalizationMethod::Rel,
+        NormalizationMethod::Rel,
+        NormalizationMethod::Rel,
+    ]
+}
@@ -10,7 +10,7 @@ pub fn run_closure_case_fd(
     init: nalgebra::DVector<f64>,
     solution: nalgebra::DVector<f64>,
     resolution_method: nrf::solver::ResolutionMethod,
-    damping: bool,
+    damping: nrf::solver::Damping,
 ) {
     let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
@@ -45,7 +45,7 @@ pub fn run_closure_case_jac(
     init: nalgebra::DVector<f64>,
     solution: nalgebra::DVector<f64>,
     resolution_method: nrf::solver::ResolutionMethod,
-    damping: bool,
+    damping: nrf::solver::Damping,
 ) {
     let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
@@ -0,0 +1,118 @@
+//! Resolution with the numeric core only
+//!
+//! This test is a separate binary only compiled without the `debug_log` feature,
+//! to check the minimal build of the crate:
+//!
+//! `cargo test --no-default-features --features std --test core_without_debug_log`
+#![cfg(not(feature = "debug_log"))]
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+
+use util::test_cases::broyden1965::*;
+
+type Function = fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>;
+type Jacobian = fn(&nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64>;
+
+// The case 10 is excluded, as in the Newton-Raphson tests of the full build
+fn broyden_cases() -> Vec<(
+    Function,
+    Jacobian,
+    nalgebra::DVector<f64>,
+    nalgebra::DVector<f64>,
+)> {
+    vec![
+        (
+            broyden1965_case5,
+            broyden1965_case5_jac,
+            init_broyden1965_case5(),
+            solution_broyden1965_case5(),
+        ),
+        (
+            broyden1965_case6,
+            broyden1965_case6_jac,
+            init_broyden1965_case6(),
+            solution_broyden1965_case6(),
+        ),
+        (
+            broyden1965_case7,
+            broyden1965_case7_jac,
+            init_broyden1965_case7(),
+            solution_broyden1965_case7(),
+        ),
+        (
+            broyden1965_case8,
+            broyden1965_case8_jac,
+            init_broyden1965_case8(),
+            solution_broyden1965_case8(),
+        ),
+        (
+            broyden1965_case9,
+            broyden1965_case9_jac,
+            init_broyden1965_case9(),
+            solution_broyden1965_case9(),
+        ),
+    ]
+}
+
+fn assert_solution(iteratives: &nalgebra::DVector<f64>, solution: &nalgebra::DVector<f64>) {
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn broyden_cases_finite_difference() {
+    for (func, _, init, solution) in broyden_cases() {
+        let problem_size = init.len();
+        let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut rf = nrf::solver::default_with_guess(
+            init,
+            &iter_params,
+            &res_config,
+            nrf::solver::ResolutionMethod::NewtonRaphson,
+            nrf::solver::Damping::Disabled,
+        );
+        let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+        rf.solve(&mut user_model).unwrap();
+
+        assert_solution(&user_model.get_iteratives(), &solution);
+    }
+}
+
+#[test]
+fn broyden_cases_jacobian() {
+    for (func, jac, init, solution) in broyden_cases() {
+        let problem_size = init.len();
+        let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut rf = nrf::solver::default_with_guess(
+            init,
+            &iter_params,
+            &res_config,
+            nrf::solver::ResolutionMethod::NewtonRaphson,
+            nrf::solver::Damping::Disabled,
+        );
+        let mut user_model =
+            nrf::model::UserModelFromFunctionAndJacobian::new(problem_size, func, jac);
+
+        rf.solve(&mut user_model).unwrap();
+
+        assert_solution(&user_model.get_iteratives(), &solution);
+    }
+}
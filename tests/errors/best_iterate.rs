@@ -0,0 +1,142 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{MaxIterations, ProblemSize};
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+
+// Newton-Raphson cycles between 0 and 1 without converging: f(0) = 2 and f(1) = 1
+fn cycle(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|x| x.powi(3) - 2.0 * x + 2.0)
+}
+
+fn cycle_jac(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_element(1, 1, 3.0 * x[0].powi(2) - 2.0)
+}
+
+// Newton-Raphson diverges from 1.5, with growing oscillations around the root 0
+fn diverging(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(f64::atan)
+}
+
+fn diverging_jac(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_element(1, 1, 1.0 / (1.0 + x[0].powi(2)))
+}
+
+type Function = fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>;
+type Jacobian = fn(&nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64>;
+
+/// Resolution capped to 4 iterations, returning the solver outcome and the model
+fn solve_capped(
+    init: f64,
+    func: Function,
+    jac: Jacobian,
+    return_best_on_failure: bool,
+) -> (
+    Option<(nalgebra::DVector<f64>, f64)>,
+    nrf::model::UserModelFromFunctionAndJacobian,
+) {
+    let vec_iter_params = iteratives::default_vec_iteratives(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(4),
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    parameters.set_return_best_on_failure(return_best_on_failure);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![init]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(1, func, jac);
+
+    let result = rf.solve(&mut user_model).unwrap_err();
+    assert!(result
+        .to_string()
+        .starts_with("Convergence not reached: 1 residual(s) above the tolerance"));
+    (rf.get_best_iterate(), user_model)
+}
+
+#[test]
+fn best_iterate_of_cycle() {
+    let (best_iterate, user_model) = solve_capped(0.0, cycle, cycle_jac, false);
+
+    assert_eq!(
+        best_iterate,
+        Some((nalgebra::DVector::from_vec(vec![1.0]), 1.0))
+    );
+    // the last iterate is the worst one
+    assert_eq!(user_model.get_iteratives()[0], 0.0);
+}
+
+#[test]
+fn return_best_of_cycle() {
+    let (best_iterate, user_model) = solve_capped(0.0, cycle, cycle_jac, true);
+    let (best_iteratives, best_error) = best_iterate.unwrap();
+
+    assert_eq!(user_model.get_iteratives(), best_iteratives);
+    let (left, right) = user_model.get_residuals().get_values(0);
+    assert_eq!((left - right).abs(), best_error);
+}
+
+#[test]
+fn best_iterate_of_divergence() {
+    let (best_iterate, user_model) = solve_capped(1.5, diverging, diverging_jac, false);
+
+    assert_eq!(
+        best_iterate,
+        Some((nalgebra::DVector::from_vec(vec![1.5]), 1.5f64.atan()))
+    );
+    // 1.5, -1.69, 2.32, -5.11, 32.3
+    assert!(user_model.get_iteratives()[0] > 30.0);
+}
+
+#[test]
+fn return_best_of_divergence() {
+    let (_, user_model) = solve_capped(1.5, diverging, diverging_jac, true);
+
+    assert_eq!(user_model.get_iteratives()[0], 1.5);
+    let (left, right) = user_model.get_residuals().get_values(0);
+    assert_eq!(left - right, 1.5f64.atan());
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn return_best_in_log() {
+    const LOG_PATH: &str = "./tests/errors/best_iterate_log.txt";
+    let vec_iter_params = iteratives::default_vec_iteratives(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(4),
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    parameters.set_return_best_on_failure(true);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![0.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(1, cycle, cycle_jac);
+    rf.solve(&mut user_model).unwrap_err();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("Best iterate returned on failure"));
+    assert!(log.contains(
+        "Resolution failed, the model is evaluated at the best iterate, with a maximum error of 1\n"
+    ));
+}
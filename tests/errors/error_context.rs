@@ -0,0 +1,283 @@
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{ErrorContext, Model, ModelError};
+use nrf::residuals;
+
+/// Error outside of the validity domain, with the context given by the model if any
+#[derive(Debug)]
+struct OutOfDomain(Option<ErrorContext>);
+
+impl fmt::Display for OutOfDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Out of the validity domain")
+    }
+}
+
+impl std::error::Error for OutOfDomain {}
+
+/// Equations `x0^2 = 1`, `x1 = 2` and `ln(x2) = ln(0.5)`, the last one being only defined for `x2 > 0`
+///
+/// Starting from `x2 = 4`, the first Newton step leads to a negative `x2`.
+/// The error returned outside of the domain is given the `context` if any,
+/// as unusable values or as inaccurate values if `inaccurate`.
+struct LogarithmModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+    context: Option<ErrorContext>,
+    inaccurate: bool,
+    evaluated_points: Vec<nalgebra::DVector<f64>>,
+}
+
+impl LogarithmModel {
+    fn new(context: Option<ErrorContext>) -> Self {
+        LogarithmModel {
+            iteratives: nalgebra::DVector::zeros(3),
+            residuals: nalgebra::DVector::zeros(3),
+            context,
+            inaccurate: false,
+            evaluated_points: Vec::new(),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for LogarithmModel {
+    type InaccurateValuesError = OutOfDomain;
+    type UnusableValuesError = OutOfDomain;
+
+    fn len_problem(&self) -> usize {
+        3
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.evaluated_points.push(self.iteratives.clone());
+        let x = &self.iteratives;
+        self.residuals[0] = x[0] * x[0] - 1.0;
+        self.residuals[1] = x[1] - 2.0;
+        self.residuals[2] = x[2].ln() - 0.5_f64.ln();
+
+        if x[2] > 0.0 {
+            Ok(())
+        } else if self.inaccurate {
+            Err(ModelError::InaccurateValuesError(OutOfDomain(
+                self.context.clone(),
+            )))
+        } else {
+            Err(ModelError::UnusableValuesError(OutOfDomain(
+                self.context.clone(),
+            )))
+        }
+    }
+
+    fn inaccurate_values_error_context(error: &OutOfDomain) -> Option<ErrorContext> {
+        error.0.clone()
+    }
+
+    fn unusable_values_error_context(error: &OutOfDomain) -> Option<ErrorContext> {
+        error.0.clone()
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.residuals.clone(), nalgebra::DVector::zeros(3))
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        let x = &self.iteratives;
+        let jacobian = nalgebra::DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![
+            2.0 * x[0],
+            1.0,
+            1.0 / x[2],
+        ]));
+        Ok(residuals::JacobianValues::new(
+            jacobian,
+            nalgebra::DMatrix::zeros(3, 3),
+        ))
+    }
+}
+
+fn solve(
+    model: &mut LogarithmModel,
+) -> Result<(), nrf::errors::SolverError<LogarithmModel, nalgebra::Dyn>> {
+    let vec_iter_params = iteratives::default_vec_iteratives(3);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![3.0, 0.0, 4.0]),
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    rf.solve(model)
+}
+
+fn out_of_domain_context(iterative_indices: Vec<usize>) -> ErrorContext {
+    ErrorContext {
+        residual_indices: vec![2],
+        iterative_indices,
+        message: "logarithm of a negative value".to_string(),
+    }
+}
+
+#[test]
+fn error_without_context() {
+    let mut model = LogarithmModel::new(None);
+    let error = solve(&mut model).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Model evaluation failed: UnusableValuesError Error: Out of the validity domain"
+    );
+    assert!(error.get_model_error_context().is_none());
+    // the initial evaluation and the first iterate
+    assert_eq!(model.evaluated_points.len(), 2);
+}
+
+#[test]
+fn error_context_without_iteratives() {
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(Vec::new())));
+    let error = solve(&mut model).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Model evaluation failed: UnusableValuesError Error: Out of the validity domain (logarithm of a negative value, residuals [2])"
+    );
+    assert_eq!(
+        error.get_model_error_context(),
+        Some(out_of_domain_context(Vec::new()))
+    );
+    assert_eq!(model.evaluated_points.len(), 2);
+}
+
+#[test]
+fn error_context_step_reduction() {
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(vec![2])));
+    solve(&mut model).unwrap();
+
+    let solution = [1.0, 2.0, 0.5];
+    for (value, expected) in model.get_iteratives().iter().zip(solution) {
+        assert!(float_cmp::approx_eq!(f64, *value, expected, epsilon = 1e-6));
+    }
+
+    // Only the step of the variable 2 is halved until it is back in the domain
+    let points = &model.evaluated_points;
+    let initial = &points[0];
+    let proposed = &points[1];
+    assert!(proposed[2] <= 0.0);
+    let mut reduced_step = proposed[2] - initial[2];
+    let mut i = 2;
+    loop {
+        reduced_step /= 2.0;
+        assert_eq!(points[i][0], proposed[0]);
+        assert_eq!(points[i][1], proposed[1]);
+        assert!(float_cmp::approx_eq!(
+            f64,
+            points[i][2],
+            initial[2] + reduced_step,
+            ulps = 4
+        ));
+        if points[i][2] > 0.0 {
+            break;
+        }
+        i += 1;
+    }
+    assert!(i > 2);
+}
+
+#[test]
+fn error_context_step_reduction_exhausted() {
+    // the reduction of the variable 0 does not bring the variable 2 back in the domain
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(vec![0])));
+    let error = solve(&mut model).unwrap_err();
+
+    assert_eq!(
+        error.get_model_error_context(),
+        Some(out_of_domain_context(vec![0]))
+    );
+    assert_eq!(
+        model.evaluated_points.len(),
+        2 + nrf::solver::MAX_CONTEXT_STEP_REDUCTIONS
+    );
+    let proposed_step = model.evaluated_points[1][0] - 3.0;
+    let last_step = model.evaluated_points.last().unwrap()[0] - 3.0;
+    assert!(float_cmp::approx_eq!(
+        f64,
+        last_step,
+        proposed_step / 2.0_f64.powi(nrf::solver::MAX_CONTEXT_STEP_REDUCTIONS as i32),
+        epsilon = 1e-12
+    ));
+}
+
+#[test]
+fn error_context_iterative_out_of_problem() {
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(vec![2, 3])));
+    let error = solve(&mut model).unwrap_err();
+
+    // returned without any step reduction
+    assert_eq!(
+        error.get_model_error_context(),
+        Some(out_of_domain_context(vec![2, 3]))
+    );
+    assert_eq!(model.evaluated_points.len(), 2);
+}
+
+#[test]
+fn error_context_inaccurate_values_accepted() {
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(vec![2])));
+    model.inaccurate = true;
+    let _ = solve(&mut model);
+
+    // the proposed iterate is accepted without step reduction, the next iteration starting from it
+    let points = &model.evaluated_points;
+    assert!(points[1][2] <= 0.0);
+    assert_ne!(points[2][2], (points[0][2] + points[1][2]) / 2.0);
+}
+
+#[test]
+fn error_context_accessors() {
+    let error: ModelError<LogarithmModel, nalgebra::Dyn> =
+        ModelError::InaccurateValuesError(OutOfDomain(Some(out_of_domain_context(vec![2]))));
+    assert!(error.is_inaccurate_values_error());
+    assert!(!error.is_unusable_values_error());
+    assert_eq!(error.get_context(), Some(out_of_domain_context(vec![2])));
+    assert_eq!(
+        error.to_string(),
+        "InaccurateValues Error: Out of the validity domain (logarithm of a negative value, residuals [2], iteratives [2])"
+    );
+
+    let error: ModelError<LogarithmModel, nalgebra::Dyn> =
+        ModelError::UnusableValuesError(OutOfDomain(None));
+    assert!(error.get_context().is_none());
+    assert_eq!(
+        error.to_string(),
+        "UnusableValuesError Error: Out of the validity domain"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_context_dyn_model() {
+    let mut model = LogarithmModel::new(Some(out_of_domain_context(vec![2]))).into_dyn();
+    model.set_iteratives(&nalgebra::DVector::from_vec(vec![1.0, 2.0, -1.0]));
+    let error = model.evaluate().unwrap_err();
+
+    assert!(error.is_unusable_values_error());
+    assert_eq!(error.get_context(), Some(out_of_domain_context(vec![2])));
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::errors::SolverErrorKind;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{MaxIterations, ProblemSize};
+
+#[derive(Debug)]
+struct NegativeInput;
+
+impl fmt::Display for NegativeInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The input is negative")
+    }
+}
+
+impl Error for NegativeInput {}
+
+/// Equation `sqrt(x) = 2`, the model being unable to evaluate negative inputs
+struct SquareRootModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl SquareRootModel {
+    fn new() -> Self {
+        SquareRootModel {
+            iteratives: nalgebra::DVector::zeros(1),
+            left: nalgebra::DVector::zeros(1),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for SquareRootModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = NegativeInput;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        if self.iteratives[0] < 0.0 {
+            return Err(ModelError::UnusableValuesError(NegativeInput));
+        }
+        self.left[0] = self.iteratives[0].sqrt();
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), nalgebra::DVector::from_vec(vec![2.0]))
+    }
+}
+
+/// Equation `x**2 = -1`, without any root
+fn no_root(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.component_mul(x).add_scalar(1.0)
+}
+
+fn solve<M>(model: &mut M, init: f64) -> Result<(), nrf::errors::SolverError<M, nalgebra::Dyn>>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(20),
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![init]),
+        &iter_params,
+        &res_config,
+    );
+    rf.solve(model)
+}
+
+/// Error handling shared by all the models
+fn count(counters: &mut HashMap<SolverErrorKind, usize>, kind: SolverErrorKind) {
+    *counters.entry(kind).or_insert(0) += 1;
+}
+
+#[test]
+fn kinds_of_different_models() {
+    let mut counters = HashMap::new();
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, no_root);
+    let error = solve(&mut user_model, 1.0).unwrap_err();
+    assert_eq!(error.kind(), SolverErrorKind::NonConvergence);
+    count(&mut counters, error.kind());
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, no_root);
+    let error = solve(&mut user_model, 2.0).unwrap_err();
+    count(&mut counters, error.kind());
+
+    let mut sqrt_model = SquareRootModel::new();
+    let error = solve(&mut sqrt_model, -1.0).unwrap_err();
+    match error.kind() {
+        SolverErrorKind::ModelInitialEvaluation(message) => {
+            assert_eq!(message, "The input is negative")
+        }
+        kind => panic!("Unexpected error kind {:?}", kind),
+    }
+    count(&mut counters, error.kind());
+
+    assert!(solve(&mut SquareRootModel::new(), 1.0).is_ok());
+
+    assert_eq!(counters.len(), 2);
+    assert_eq!(counters[&SolverErrorKind::NonConvergence], 2);
+    assert_eq!(
+        counters[&SolverErrorKind::ModelInitialEvaluation("The input is negative".to_string())],
+        1
+    );
+}
+
+#[test]
+fn kinds_messages() {
+    let mut sqrt_model = SquareRootModel::new();
+    let error = solve(&mut sqrt_model, -1.0).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Initial model evaluation failed: The input is negative"
+    );
+
+    type UserError = nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>;
+    assert_eq!(
+        UserError::FinalEvaluationError.kind(),
+        SolverErrorKind::FinalEvaluation
+    );
+    assert_eq!(
+        UserError::ConvergedOnBoundsError.kind(),
+        SolverErrorKind::ConvergedOnBounds
+    );
+    assert_eq!(
+        UserError::JacobianInversionError {
+            iteration: 3,
+            condition_estimate: None
+        }
+        .kind(),
+        SolverErrorKind::JacobianInversion
+    );
+
+    type SquareRootError = nrf::errors::SolverError<SquareRootModel, nalgebra::Dyn>;
+    let error =
+        SquareRootError::JacobianEvaluationError(ModelError::UnusableValuesError(NegativeInput));
+    assert_eq!(
+        error.to_string(),
+        "Jacobian evaluation failed: UnusableValuesError Error: The input is negative"
+    );
+    assert_eq!(
+        error.kind(),
+        SolverErrorKind::JacobianEvaluation(
+            "UnusableValuesError Error: The input is negative".to_string()
+        )
+    );
+    assert_eq!(
+        UserError::JacobianError(
+            nrf::errors::SolverInternalError::NonFiniteQuasiNewtonUpdatesError
+        )
+        .kind(),
+        SolverErrorKind::Jacobian(
+            "Consecutive quasi-Newton updates produced non-finite values".to_string()
+        )
+    );
+}
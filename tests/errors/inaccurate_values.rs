@@ -29,9 +29,7 @@ pub enum MyCustomErrors {
 
 impl fmt::Display for MyCustomErrors {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            _ => write!(f, "{}", "Not a good value"),
-        }
+        write!(f, "Not a good value")
     }
 }
 
@@ -46,7 +44,7 @@ impl Model<nalgebra::Dyn> for MyDummyModel {
     }
 
     fn get_iteratives(&self) -> nalgebra::DVector<f64> {
-        return self.iteratives.clone();
+        self.iteratives.clone()
     }
 
     fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
@@ -54,10 +52,7 @@ impl Model<nalgebra::Dyn> for MyDummyModel {
     }
 
     fn get_residuals(&self) -> nrf::residuals::ResidualsValues<nalgebra::Dyn> {
-        return nrf::residuals::ResidualsValues::new(
-            self.residuals.clone(),
-            nalgebra::DVector::zeros(1),
-        );
+        nrf::residuals::ResidualsValues::new(self.residuals.clone(), nalgebra::DVector::zeros(1))
     }
 
     fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
@@ -74,7 +69,7 @@ fn test_convergence_with_error_from_model() {
     let mut init = nalgebra::DVector::zeros(problem_size);
     init[0] = 1.0;
 
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
 
     let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
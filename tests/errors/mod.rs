@@ -1,3 +1,9 @@
+mod best_iterate;
+mod error_context;
+mod error_kinds;
 mod inaccurate_values;
 mod non_convergence;
+mod non_finite_residuals;
 mod non_invertible_jacobian;
+mod residual_magnitude;
+mod unconverged_residuals;
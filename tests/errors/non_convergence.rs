@@ -19,7 +19,7 @@ fn non_convergence_case() {
     let problem_size = 5;
     let init = nalgebra::DVector::zeros(problem_size);
 
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
 
     let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
@@ -37,6 +37,10 @@ fn non_convergence_case() {
 
     let result = rf.solve(&mut user_model).unwrap_err();
     let expected: nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn> =
-        nrf::errors::SolverError::NonConvergenceError;
+        nrf::errors::SolverError::NonConvergenceError(nrf::errors::UnconvergedResiduals::new(
+            rf.get_unconverged_residuals(),
+            Vec::new(),
+        ));
     assert_eq!(expected.to_string(), result.to_string());
+    assert_eq!(rf.get_unconverged_residuals().len(), problem_size);
 }
@@ -0,0 +1,91 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+fn nan_residual(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = nalgebra::DVector::zeros(2);
+    outputs[0] = x[0] * x[0] - 2.0;
+    outputs[1] = f64::NAN;
+    outputs
+}
+
+fn nan_first_residual(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = nalgebra::DVector::zeros(2);
+    outputs[0] = f64::NAN;
+    outputs[1] = x[1] * x[1] - 2.0;
+    outputs
+}
+
+// The model produces NaN only close to the solution
+fn nan_near_solution(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = nalgebra::DVector::zeros(2);
+    outputs[0] = x[0] * x[0] - 2.0;
+    outputs[1] = if outputs[0].abs() < 1e-3 {
+        f64::NAN
+    } else {
+        x[1] - 1.0
+    };
+    outputs
+}
+
+fn solve_case(
+    function: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    resolution_method: ResolutionMethod,
+    damping: nrf::solver::Damping,
+) -> Result<(), nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>> {
+    let problem_size = 2;
+    let init = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        resolution_method,
+        damping,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, function);
+
+    rf.solve(&mut user_model)
+}
+
+fn resolution_methods() -> [ResolutionMethod; 3] {
+    [
+        ResolutionMethod::NewtonRaphson,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+    ]
+}
+
+#[test]
+fn nan_residual_is_not_converged() {
+    for resolution_method in resolution_methods() {
+        for damping in [
+            nrf::solver::Damping::Disabled,
+            nrf::solver::Damping::Enabled,
+        ] {
+            assert!(solve_case(nan_residual, resolution_method, damping).is_err());
+            assert!(solve_case(nan_first_residual, resolution_method, damping).is_err());
+        }
+    }
+}
+
+#[test]
+fn nan_residual_near_solution_is_not_converged() {
+    for resolution_method in resolution_methods() {
+        for damping in [
+            nrf::solver::Damping::Disabled,
+            nrf::solver::Damping::Enabled,
+        ] {
+            assert!(solve_case(nan_near_solution, resolution_method, damping).is_err());
+        }
+    }
+}
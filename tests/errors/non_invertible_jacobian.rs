@@ -19,7 +19,7 @@ fn non_convergence_case() {
     let problem_size = 5;
     let init = nalgebra::DVector::zeros(problem_size);
 
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
 
     let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
     let iter_params = iteratives::Iteratives::new(&vec_iter_params);
@@ -36,6 +36,40 @@ fn non_convergence_case() {
     let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, constant_problem);
 
     let result = rf.solve(&mut user_model).unwrap_err();
-    let expected = "Jacobian error: Non invertible jacobian";
+    let expected = "Structurally singular jacobian: the residuals [0, 1, 2, 3, 4] depend on no iterative and the iteratives [0, 1, 2, 3, 4] affect no residual";
     assert_eq!(result.to_string(), expected);
 }
+
+/// Equations `x + y = 1` and `x + y = 2`, whose jacobian has neither a null row nor a null column
+fn parallel_lines(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] + x[1] - 1.0, x[0] + x[1] - 2.0])
+}
+
+#[test]
+fn rank_deficient_jacobian() {
+    let init = nalgebra::DVector::zeros(2);
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, parallel_lines);
+
+    let result = rf.solve(&mut user_model).unwrap_err();
+    let expected = "Jacobian inversion failed at iteration 1, condition number estimate: inf";
+    assert_eq!(result.to_string(), expected);
+    assert!(matches!(
+        result,
+        nrf::errors::SolverError::JacobianInversionError {
+            iteration: 1,
+            condition_estimate: Some(condition_estimate),
+        } if condition_estimate == f64::INFINITY
+    ));
+}
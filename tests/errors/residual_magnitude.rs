@@ -0,0 +1,266 @@
+use newton_rootfinder as nrf;
+use nrf::errors::{SolverError, SolverErrorKind};
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    evaluate_jacobian_from_finite_difference, Damping, InaccurateJacobianPolicy, JacobianMatrix,
+    MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+};
+
+const BROKEN_VALUE: f64 = 1e40;
+const MAX_MAGNITUDE: f64 = 1e30;
+
+/// Equations `x0 = 1` and `x1^3 = 27`, the model being in a broken regime above `x1 = 2`
+///
+/// Starting from `x1 = 1`, the first Newton step goes to `x1 = 9.67`
+fn explosion_above_two(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = nalgebra::DVector::zeros(2);
+    outputs[0] = x[0] - 1.0;
+    outputs[1] = if x[1] > 2.0 {
+        BROKEN_VALUE
+    } else {
+        x[1].powi(3) - 27.0
+    };
+    outputs
+}
+
+/// Equation `x^2 = 2`, the model being in a broken regime above `1.5`
+///
+/// Starting from `1.45`, only the points perturbed by `0.1` are in this regime
+fn explosion_above_validity(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut outputs = x.component_mul(x).add_scalar(-2.0);
+    if x[0] > 1.5 {
+        outputs[0] = BROKEN_VALUE;
+    }
+    outputs
+}
+
+#[test]
+fn exceeded_at_proposed_iterate() {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_max_magnitudes(vec![Some(MAX_MAGNITUDE); problem_size]);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0, 1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, explosion_above_two);
+
+    let error = rf.solve(&mut user_model).unwrap_err();
+
+    assert!(matches!(
+        error,
+        SolverError::ResidualMagnitudeExceeded {
+            id: 1,
+            value: BROKEN_VALUE,
+            limit: MAX_MAGNITUDE,
+            iteration: 1,
+        }
+    ));
+    assert_eq!(error.kind(), SolverErrorKind::ResidualMagnitudeExceeded(1));
+    assert_eq!(
+        error.to_string(),
+        "Magnitude of the residual 1 exceeded at iteration 1: 1e40 is above the limit 1e30"
+    );
+    assert_eq!(rf.get_iter(), 1);
+}
+
+#[test]
+fn exceeded_at_initial_guess() {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_max_magnitudes(vec![None, Some(MAX_MAGNITUDE)]);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0, 3.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, explosion_above_two);
+
+    let error = rf.solve(&mut user_model).unwrap_err();
+
+    assert_eq!(error.kind(), SolverErrorKind::ResidualMagnitudeExceeded(1));
+    assert!(matches!(
+        error,
+        SolverError::ResidualMagnitudeExceeded { iteration: 0, .. }
+    ));
+}
+
+#[test]
+fn without_max_magnitude() {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0, 1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, explosion_above_two);
+
+    let error = rf.solve(&mut user_model).unwrap_err();
+
+    assert_ne!(error.kind(), SolverErrorKind::ResidualMagnitudeExceeded(1));
+}
+
+fn solve_perturbed_explosion(
+    resolution_method: ResolutionMethod,
+    policy: InaccurateJacobianPolicy,
+) -> (
+    Result<(), SolverErrorKind>,
+    Vec<nrf::solver::InaccurateColumn>,
+    nalgebra::DVector<f64>,
+) {
+    let iterative = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        0.1,
+        1e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_max_magnitudes(vec![Some(MAX_MAGNITUDE)]);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(20),
+        resolution_method,
+        Damping::Disabled,
+    );
+    parameters.set_inaccurate_jacobian_policy(policy);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.45]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, explosion_above_validity);
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.kind());
+    (
+        result,
+        rf.get_inaccurate_columns().to_vec(),
+        user_model.get_iteratives(),
+    )
+}
+
+// The perturbed point is retried with a reduced step, as for inaccurate values
+#[test]
+fn exceeded_at_perturbed_point_retried() {
+    let (result, inaccurate_columns, iteratives) = solve_perturbed_explosion(
+        ResolutionMethod::NewtonRaphson,
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 3 },
+    );
+
+    assert_eq!(result, Ok(()));
+    assert!((iteratives[0] - 2.0_f64.sqrt()).abs() < 1e-6);
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_retries(), 1);
+    assert!(inaccurate_columns[0].get_recovered());
+}
+
+// Without previous jacobian, the perturbed point not recovered stops the resolution, whatever the policy
+#[test]
+fn exceeded_at_perturbed_point_without_previous_column() {
+    let (result, inaccurate_columns, _) = solve_perturbed_explosion(
+        ResolutionMethod::NewtonRaphson,
+        InaccurateJacobianPolicy::Accept,
+    );
+
+    assert_eq!(result, Err(SolverErrorKind::ResidualMagnitudeExceeded(0)));
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert!(!inaccurate_columns[0].get_recovered());
+}
+
+// The same applies to the diagonal seeding the limited memory Broyden method
+#[test]
+fn exceeded_at_perturbed_point_of_diagonal() {
+    let (result, _, _) = solve_perturbed_explosion(
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 2 }),
+        InaccurateJacobianPolicy::Accept,
+    );
+
+    assert_eq!(result, Err(SolverErrorKind::ResidualMagnitudeExceeded(0)));
+}
+
+// The perturbed point not recovered keeps the column of the previous jacobian, even with the `Fail` policy
+#[test]
+fn exceeded_at_perturbed_point_keeps_previous_column() {
+    let iterative = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        0.1,
+        1e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_max_magnitudes(vec![Some(MAX_MAGNITUDE)]);
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, explosion_above_validity);
+    let mut jacobian = JacobianMatrix::new();
+
+    // the perturbed point 1.3 is valid
+    user_model.set_iteratives(&nalgebra::DVector::from_vec(vec![1.2]));
+    user_model.evaluate().unwrap();
+    evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        &mut user_model,
+        &iter_params,
+        &res_config,
+        InaccurateJacobianPolicy::Fail,
+    )
+    .unwrap();
+    let previous_column = jacobian.get_jacobian().as_ref().unwrap()[(0, 0)];
+    assert!((previous_column - 2.5).abs() < 1e-10);
+
+    // the perturbed point 1.55 is in the broken regime
+    user_model.set_iteratives(&nalgebra::DVector::from_vec(vec![1.45]));
+    user_model.evaluate().unwrap();
+    evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        &mut user_model,
+        &iter_params,
+        &res_config,
+        InaccurateJacobianPolicy::Fail,
+    )
+    .unwrap();
+
+    assert_eq!(
+        jacobian.get_jacobian().as_ref().unwrap()[(0, 0)],
+        previous_column
+    );
+    let inaccurate_columns = jacobian.get_inaccurate_columns();
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_retries(), 0);
+    assert!(!inaccurate_columns[0].get_recovered());
+}
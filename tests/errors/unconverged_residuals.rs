@@ -0,0 +1,100 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::residuals::{self, ResidualsGroup};
+
+// The second equation has no root: x^2 + 1 >= 1
+fn one_blocking_equation(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 1.0, x[1] * x[1] + 1.0, x[2] - 3.0])
+}
+
+fn solve(
+    groups: Vec<ResidualsGroup>,
+    group_convergence: bool,
+) -> (String, nrf::errors::UnconvergedResiduals, Vec<(usize, f64)>) {
+    let problem_size = 3;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    if !groups.is_empty() {
+        res_config.set_groups(groups);
+        res_config.set_group_convergence(group_convergence);
+    }
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![0.0, 0.5, 0.0]),
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    let mut user_model =
+        nrf::model::UserModelFromFunction::new(problem_size, one_blocking_equation);
+
+    match rf.solve(&mut user_model).unwrap_err() {
+        nrf::errors::SolverError::NonConvergenceError(unconverged_residuals) => (
+            unconverged_residuals.to_string(),
+            unconverged_residuals,
+            rf.get_unconverged_residuals(),
+        ),
+        error => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn blocking_residual_identified() {
+    let (message, unconverged_residuals, residuals) = solve(Vec::new(), false);
+
+    assert_eq!(residuals, unconverged_residuals.get_residuals());
+    assert_eq!(residuals.len(), 1);
+    let (index, error) = residuals[0];
+    assert_eq!(index, 1);
+    assert!(error >= 1.0);
+    assert_eq!(unconverged_residuals.get_worst(), Some((index, error)));
+    assert!(unconverged_residuals.get_groups().is_empty());
+    assert_eq!(
+        message,
+        format!(
+            "1 residual(s) above the tolerance, the largest error being {} for the residual 1",
+            error
+        )
+    );
+}
+
+#[test]
+fn blocking_residual_with_its_group() {
+    let groups = vec![
+        ResidualsGroup::from_range("thermal", 0..2),
+        ResidualsGroup::new("mechanical", vec![2]),
+    ];
+    let (message, unconverged_residuals, residuals) = solve(groups.clone(), false);
+
+    assert_eq!(residuals.len(), 1);
+    assert_eq!(residuals[0].0, 1);
+    assert_eq!(unconverged_residuals.get_groups(), ["thermal"]);
+    assert!(message.ends_with(", groups not converged: thermal"));
+
+    // same outcome with the convergence checked on the groups
+    let (_, unconverged_residuals, residuals) = solve(groups, true);
+    assert_eq!(residuals.len(), 1);
+    assert_eq!(residuals[0].0, 1);
+    assert_eq!(unconverged_residuals.get_groups(), ["thermal"]);
+}
+
+#[test]
+fn residuals_sorted_by_decreasing_error() {
+    let unconverged_residuals = nrf::errors::UnconvergedResiduals::new(
+        vec![(0, 1e-3), (2, f64::INFINITY), (1, 5.0)],
+        Vec::new(),
+    );
+
+    assert_eq!(
+        unconverged_residuals.get_residuals(),
+        [(2, f64::INFINITY), (1, 5.0), (0, 1e-3)]
+    );
+    assert_eq!(
+        unconverged_residuals.to_string(),
+        "3 residual(s) above the tolerance, the largest error being inf for the residual 2"
+    );
+}
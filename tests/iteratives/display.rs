@@ -0,0 +1,77 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives::{IterativeParams, Iteratives};
+
+fn iteratives_params(problem_size: usize) -> Vec<IterativeParams> {
+    (0..problem_size)
+        .map(|i| IterativeParams::new(1.0, 0.5, -(i as f64) - 1.0, i as f64 + 1.0))
+        .collect()
+}
+
+fn displayed_ids(content: &str) -> Vec<usize> {
+    content
+        .lines()
+        .filter_map(|line| line.split('|').nth(1))
+        .filter_map(|id| id.trim().parse().ok())
+        .collect()
+}
+
+#[test]
+fn display_truncated_with_width() {
+    let iteratives_vec = iteratives_params(5);
+    let iteratives = Iteratives::new(&iteratives_vec);
+
+    let expected = "Iteratives parameters
+=====================
+
++-----------+--------------+--------------+--------------+--------------+
+| Iterative | max_step_abs | max_step_rel | min_value    | max_value    |
++-----------+--------------+--------------+--------------+--------------+
+| 0         | 1            | 0.5          | -1           | 1            |
++-----------+--------------+--------------+--------------+--------------+
+| 1         | 1            | 0.5          | -2           | 2            |
++-----------+--------------+--------------+--------------+--------------+
+… (1 more)
++-----------+--------------+--------------+--------------+--------------+
+| 3         | 1            | 0.5          | -4           | 4            |
++-----------+--------------+--------------+--------------+--------------+
+| 4         | 1            | 0.5          | -5           | 5            |
++-----------+--------------+--------------+--------------+--------------+
+
+";
+
+    assert_eq!(format!("{:2}", iteratives), expected);
+    assert_eq!(iteratives.format_with(Some(2)), expected);
+}
+
+#[test]
+fn display_truncated_by_default() {
+    let iteratives_vec = iteratives_params(25);
+    let iteratives = Iteratives::new(&iteratives_vec);
+    let content = iteratives.to_string();
+
+    let expected_ids: Vec<usize> = (0..10).chain(15..25).collect();
+    assert_eq!(displayed_ids(&content), expected_ids);
+    assert!(content.contains("\n… (5 more)\n"));
+}
+
+#[test]
+fn display_small_problem_in_full() {
+    let iteratives_vec = iteratives_params(20);
+    let iteratives = Iteratives::new(&iteratives_vec);
+    let content = iteratives.to_string();
+
+    assert_eq!(displayed_ids(&content), (0..20).collect::<Vec<usize>>());
+    assert!(!content.contains("more)"));
+    assert_eq!(content, iteratives.format_with(None));
+}
+
+#[test]
+fn format_in_full() {
+    let iteratives_vec = iteratives_params(1000);
+    let iteratives = Iteratives::new(&iteratives_vec);
+    let content = iteratives.format_with(None);
+
+    assert_eq!(displayed_ids(&content), (0..1000).collect::<Vec<usize>>());
+    assert!(!content.contains("more)"));
+}
@@ -0,0 +1,4 @@
+mod display;
+mod perturbation;
+mod static_config;
+mod step_limitation;
@@ -0,0 +1,83 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives::{Iterative, IterativeParamsFD, PerturbationMethod, PerturbationSign};
+
+fn iterative(perturbation_method: PerturbationMethod) -> IterativeParamsFD {
+    IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        3e-3,
+        1e-3,
+        perturbation_method,
+    )
+}
+
+#[test]
+fn perturbation_max() {
+    let iterative = iterative(PerturbationMethod::Max);
+    assert_eq!(iterative.compute_perturbation(0.0), 3e-3);
+    assert_eq!(iterative.compute_perturbation(-1.0), 3e-3);
+    assert_eq!(iterative.compute_perturbation(4.0), 4e-3);
+}
+
+#[test]
+fn perturbation_sum() {
+    let iterative = iterative(PerturbationMethod::Sum);
+    assert_eq!(iterative.compute_perturbation(0.0), 3e-3);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(-1.0),
+        4e-3,
+        ulps = 2
+    ));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(4.0),
+        7e-3,
+        ulps = 2
+    ));
+}
+
+#[test]
+fn perturbation_quadrature() {
+    let mut iterative = iterative(PerturbationMethod::Quadrature);
+    assert_eq!(iterative.compute_perturbation(0.0), 3e-3);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(4.0),
+        5e-3,
+        ulps = 2
+    ));
+    // at the crossover of Max, the perturbation is sqrt(2) times the absolute one
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(-3.0),
+        3e-3 * 2.0_f64.sqrt(),
+        ulps = 2
+    ));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(1e6),
+        1e3,
+        epsilon = 1e-6
+    ));
+
+    iterative.set_perturbation_sign(PerturbationSign::Negative);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iterative.compute_perturbation(4.0),
+        -5e-3,
+        ulps = 2
+    ));
+}
+
+#[test]
+fn display_quadrature() {
+    let mut iterative = iterative(PerturbationMethod::Quadrature);
+    iterative.set_perturbation_sign(PerturbationSign::AwayFromNearestBound);
+    assert!(iterative
+        .to_string()
+        .contains("| Quadrature (away) | 0.003        | 0.001        |"));
+}
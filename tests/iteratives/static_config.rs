@@ -0,0 +1,124 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives::{IterativeParams, IterativeParamsFD, Iteratives, PerturbationMethod};
+use nrf::model::Model;
+use nrf::residuals::{NormalizationMethod, ResidualConfig, ResidualsConfig};
+use nrf::solver::{Damping, ResolutionMethod};
+
+static PARAMS_FD: [IterativeParamsFD; 2] = [
+    IterativeParamsFD::new_unchecked_const(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        5e-8,
+        5e-8,
+        PerturbationMethod::Max,
+    ),
+    IterativeParamsFD::new_unchecked_const(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        1e-7,
+        1e-7,
+        PerturbationMethod::Sum,
+    ),
+];
+static ITERATIVES_FD: Iteratives<'static, IterativeParamsFD> = Iteratives::new(&PARAMS_FD);
+static STOPPING_CRITERIAS: [NormalizationMethod; 2] = [NormalizationMethod::Abs; 2];
+static UPDATE_METHODS: [NormalizationMethod; 2] = [NormalizationMethod::Abs; 2];
+
+// array repeat expressions of non-Copy values require a constant
+const ITERATIVE: IterativeParams =
+    IterativeParams::new_unchecked_const(1.0, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY);
+static PARAMS: [IterativeParams; 20] = [ITERATIVE; 20];
+static RESIDUALS: [ResidualConfig; 20] =
+    [ResidualConfig::new(NormalizationMethod::Adapt, NormalizationMethod::Abs); 20];
+
+fn assert_solution(iteratives: &nalgebra::DVector<f64>, solution: &nalgebra::DVector<f64>) {
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn const_constructors_identical_to_checked_ones() {
+    assert_eq!(
+        PARAMS_FD[1],
+        IterativeParamsFD::new(
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            1e-7,
+            1e-7,
+            PerturbationMethod::Sum,
+        )
+    );
+    assert_eq!(
+        ITERATIVE,
+        IterativeParams::new(1.0, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY)
+    );
+    assert_eq!(
+        RESIDUALS[0],
+        ResidualConfig::new(NormalizationMethod::Adapt, NormalizationMethod::Abs)
+    );
+}
+
+#[test]
+fn static_finite_difference_configuration_solve() {
+    let res_config = ResidualsConfig::new(&STOPPING_CRITERIAS, &UPDATE_METHODS);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &ITERATIVES_FD,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    rf.solve(&mut user_model).unwrap();
+    assert_solution(&user_model.get_iteratives(), &solution_broyden1965_case10());
+
+    // identical resolution with the configuration built at runtime
+    let vec_iter_params = PARAMS_FD.to_vec();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let mut runtime_model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    rf.solve(&mut runtime_model).unwrap();
+    assert_eq!(runtime_model.get_iteratives(), user_model.get_iteratives());
+}
+
+#[test]
+fn static_residual_configs_solve() {
+    let iter_params = Iteratives::new(&PARAMS);
+    let (stopping_criterias, update_methods) =
+        ResidualsConfig::convert_into_vecs(RESIDUALS.to_vec());
+    let res_config = ResidualsConfig::new(&stopping_criterias, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        20,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+    rf.solve(&mut user_model).unwrap();
+    assert_solution(&user_model.get_iteratives(), &solution_broyden1965_case8());
+}
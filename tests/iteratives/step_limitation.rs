@@ -0,0 +1,207 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives::{
+    Iterative, IterativeParams, IterativeParamsFD, Iteratives, PerturbationMethod,
+};
+
+// Symmetric limitations large enough not to interfere with the directional ones
+fn iterative_params() -> IterativeParams {
+    IterativeParams::new(10.0, 10.0, f64::NEG_INFINITY, f64::INFINITY)
+}
+
+#[test]
+fn symmetric_limits_by_default() {
+    let iterative = IterativeParams::new(1.0, 0.5, f64::NEG_INFINITY, f64::INFINITY);
+    assert_eq!(iterative.get_max_step_abs_up(), None);
+    assert_eq!(iterative.get_max_step_abs_down(), None);
+    assert_eq!(iterative.get_max_step_rel_up(), None);
+    assert_eq!(iterative.get_max_step_rel_down(), None);
+
+    assert_eq!(iterative.step_limitation(10.0, 5.0), 11.0);
+    assert_eq!(iterative.step_limitation(10.0, -5.0), 9.0);
+    assert_eq!(iterative.step_limitation(1.0, 5.0), 1.5);
+    assert_eq!(iterative.step_limitation(1.0, -5.0), 0.5);
+}
+
+#[test]
+fn max_step_abs_up() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_abs_up(Some(1.0));
+
+    assert_eq!(iterative.step_limitation(5.0, 3.0), 6.0);
+    // the symmetric limit applies to the negative steps
+    assert_eq!(iterative.step_limitation(5.0, -3.0), 2.0);
+    assert_eq!(iterative.step_limitation(5.0, -30.0), -5.0);
+}
+
+#[test]
+fn max_step_abs_down() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_abs_down(Some(1.0));
+
+    assert_eq!(iterative.step_limitation(5.0, -3.0), 4.0);
+    assert_eq!(iterative.step_limitation(5.0, 3.0), 8.0);
+    assert_eq!(iterative.step_limitation(5.0, 30.0), 15.0);
+}
+
+#[test]
+fn max_step_rel_up() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_rel_up(Some(0.5));
+
+    assert_eq!(iterative.step_limitation(2.0, 3.0), 3.0);
+    assert_eq!(iterative.step_limitation(2.0, -3.0), -1.0);
+    assert_eq!(iterative.step_limitation(-2.0, 3.0), -1.0);
+}
+
+#[test]
+fn max_step_rel_down() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_rel_down(Some(0.5));
+
+    assert_eq!(iterative.step_limitation(2.0, -3.0), 1.0);
+    assert_eq!(iterative.step_limitation(2.0, 3.0), 5.0);
+    assert_eq!(iterative.step_limitation(-2.0, -3.0), -3.0);
+}
+
+#[test]
+fn directional_limits_with_scale() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_abs_up(Some(1.0));
+    iterative.set_max_step_abs_down(Some(2.0));
+
+    assert_eq!(iterative.step_limitation_with_scale(5.0, 10.0, 0.5), 5.5);
+    assert_eq!(iterative.step_limitation_with_scale(5.0, -10.0, 0.5), 4.0);
+}
+
+#[test]
+fn directional_limits_reset_to_symmetric() {
+    let mut iterative = iterative_params();
+    iterative.set_max_step_abs_up(Some(1.0));
+    iterative.set_max_step_abs_up(None);
+
+    assert_eq!(iterative.step_limitation(5.0, 30.0), 15.0);
+    assert_eq!(iterative, iterative_params());
+}
+
+#[test]
+fn directional_limits_fd() {
+    let mut iterative =
+        IterativeParamsFD::extend(iterative_params(), 5e-8, 5e-8, PerturbationMethod::Max);
+    iterative.set_max_step_abs_up(Some(1.0));
+    iterative.set_max_step_rel_down(Some(0.1));
+
+    assert_eq!(iterative.get_max_step_abs_up(), Some(1.0));
+    assert_eq!(iterative.get_max_step_rel_down(), Some(0.1));
+    assert_eq!(iterative.step_limitation(5.0, 3.0), 6.0);
+    assert_eq!(iterative.step_limitation(5.0, -3.0), 4.5);
+}
+
+// An iterative bounded to [2, 10], whose step is limited to 1 or 50% of its value
+fn bounded_params() -> IterativeParams {
+    IterativeParams::new(1.0, 0.5, 2.0, 10.0)
+}
+
+#[test]
+fn current_value_below_min_value() {
+    let iterative = bounded_params();
+    assert!(!iterative.is_within_bounds(1.0));
+
+    // the step is limited relatively to the current value and applied from it,
+    // the next value being brought back to the min_value
+    assert_eq!(iterative.step_limitation(1.0, 0.4), 2.0);
+    assert_eq!(iterative.step_limitation(1.8, 0.4), 2.2);
+    assert_eq!(iterative.step_limitation(1.5, 3.0), 2.25);
+
+    // a step pointing further out of the bounds gives the min_value
+    assert_eq!(iterative.step_limitation(-5.0, -0.1), 2.0);
+    assert_eq!(iterative.step_limitation(-5.0, -100.0), 2.0);
+}
+
+#[test]
+fn current_value_above_max_value() {
+    let iterative = bounded_params();
+    assert!(!iterative.is_within_bounds(15.0));
+
+    // the step is limited relatively to the current value and applied from it,
+    // the next value being brought back to the max_value
+    assert_eq!(iterative.step_limitation(15.0, -0.5), 10.0);
+    assert_eq!(iterative.step_limitation(10.5, -0.75), 9.75);
+    assert_eq!(iterative.step_limitation(10.5, -30.0), 9.5);
+
+    // a step pointing further out of the bounds gives the max_value
+    assert_eq!(iterative.step_limitation(15.0, 0.5), 10.0);
+    assert_eq!(iterative.step_limitation(15.0, 100.0), 10.0);
+}
+
+#[test]
+fn current_value_out_of_bounds_with_scale() {
+    let iterative = bounded_params();
+    assert_eq!(iterative.step_limitation_with_scale(1.5, 3.0, 2.0), 3.0);
+    assert_eq!(iterative.step_limitation_with_scale(10.5, -30.0, 2.0), 8.5);
+    assert_eq!(
+        iterative.step_limitation_with_scale(10.5, -30.0, f64::INFINITY),
+        2.0
+    );
+}
+
+#[test]
+fn current_value_out_of_bounds_fd() {
+    let iterative =
+        IterativeParamsFD::extend(bounded_params(), 5e-8, 5e-8, PerturbationMethod::Max);
+    assert_eq!(iterative.step_limitation(1.8, 0.4), 2.2);
+    assert_eq!(iterative.step_limitation(1.8, -0.4), 2.0);
+    assert_eq!(iterative.step_limitation(10.5, -0.75), 9.75);
+    assert_eq!(iterative.step_limitation(10.5, 0.5), 10.0);
+}
+
+#[test]
+fn current_values_out_of_bounds() {
+    let params = vec![bounded_params(); 4];
+    let iteratives = Iteratives::new(&params);
+    let current = nalgebra::DVector::from_vec(vec![1.8, 1.8, 10.5, 10.5]);
+    let raw_step = nalgebra::DVector::from_vec(vec![0.4, -0.4, -0.75, 0.5]);
+    let next = iteratives.step_limitations(&current, &raw_step);
+    assert_eq!(
+        next,
+        nalgebra::DVector::from_vec(vec![2.2, 2.0, 9.75, 10.0])
+    );
+    assert!(next
+        .iter()
+        .all(|value| bounded_params().is_within_bounds(*value)));
+}
+
+#[test]
+fn display_directional_limits() {
+    let mut iterative = IterativeParams::new(1.0, 0.5, -1.0, 1.0);
+    iterative.set_max_step_abs_up(Some(0.25));
+
+    assert_eq!(
+        iterative.to_string(),
+        " +0.25/-1     | 0.5          | -1           | 1            |"
+    );
+}
+
+#[test]
+#[should_panic(expected = "max_step_abs_up must be strictly positive, provided value was 0")]
+fn max_step_abs_up_zero() {
+    iterative_params().set_max_step_abs_up(Some(0.0));
+}
+
+#[test]
+#[should_panic(expected = "max_step_abs_down must be strictly positive, provided value was -1")]
+fn max_step_abs_down_negative() {
+    iterative_params().set_max_step_abs_down(Some(-1.0));
+}
+
+#[test]
+#[should_panic(expected = "max_step_rel_up must be strictly positive, provided value was -0.5")]
+fn max_step_rel_up_negative() {
+    iterative_params().set_max_step_rel_up(Some(-0.5));
+}
+
+#[test]
+#[should_panic(expected = "max_step_rel_down must be strictly positive, provided value was 0")]
+fn max_step_rel_down_zero() {
+    iterative_params().set_max_step_rel_down(Some(0.0));
+}
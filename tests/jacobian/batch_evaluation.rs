@@ -0,0 +1,257 @@
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    evaluate_jacobian_from_finite_difference, Damping, InaccurateJacobianPolicy, JacobianMatrix,
+    ResolutionMethod,
+};
+
+const PROBLEM_SIZE: usize = 3;
+
+#[derive(Debug)]
+struct NoisyBatch;
+
+impl fmt::Display for NoisyBatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Inaccurate batch evaluation")
+    }
+}
+
+impl Error for NoisyBatch {}
+
+/// Function of the external library, with a different dependency on each iterative
+/// so that the columns of the jacobian cannot be swapped unnoticed.
+///
+/// The root is (1, 2, 3).
+fn external_function(x: &[f64], out: &mut [f64]) {
+    out[0] = x[0] * x[0] + x[1] - 3.0;
+    out[1] = x[1] * x[2] - 6.0 + 0.5 * (x[0] - 1.0);
+    out[2] = x[2].powi(3) - 27.0 + x[1] - 2.0;
+}
+
+/// Model calling a function through a costly boundary, such as FFI, counting the crossings
+struct ExternalModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    batch: bool,
+    inaccurate_batch: bool,
+    crossings: usize,
+    batch_inputs: Vec<nalgebra::DVector<f64>>,
+}
+
+impl ExternalModel {
+    fn new(batch: bool) -> Self {
+        ExternalModel {
+            iteratives: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            left: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            batch,
+            inaccurate_batch: false,
+            crossings: 0,
+            batch_inputs: Vec::new(),
+        }
+    }
+
+    fn right() -> nalgebra::DVector<f64> {
+        nalgebra::DVector::zeros(PROBLEM_SIZE)
+    }
+}
+
+impl Model<nalgebra::Dyn> for ExternalModel {
+    type InaccurateValuesError = NoisyBatch;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        PROBLEM_SIZE
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.crossings += 1;
+        external_function(self.iteratives.as_slice(), self.left.as_mut_slice());
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), Self::right())
+    }
+
+    fn provides_batch_evaluation(&self) -> bool {
+        self.batch
+    }
+
+    // All the inputs are sent through the boundary in one crossing, the state of the model is unchanged
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::DVector<f64>],
+    ) -> Result<Vec<residuals::ResidualsValues<nalgebra::Dyn>>, ModelError<Self, nalgebra::Dyn>>
+    {
+        self.crossings += 1;
+        self.batch_inputs.extend(inputs.iter().cloned());
+        if self.inaccurate_batch {
+            return Err(ModelError::InaccurateValuesError(NoisyBatch));
+        }
+
+        let mut outputs = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let mut left = nalgebra::DVector::zeros(PROBLEM_SIZE);
+            external_function(input.as_slice(), left.as_mut_slice());
+            outputs.push(residuals::ResidualsValues::new(left, Self::right()));
+        }
+        Ok(outputs)
+    }
+}
+
+fn jacobian_of(model: &mut ExternalModel) -> nalgebra::DMatrix<f64> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    model.set_iteratives(&nalgebra::DVector::from_vec(vec![0.5, 1.5, 2.5]));
+    model.evaluate().unwrap();
+    model.crossings = 0;
+
+    let mut jacobian = JacobianMatrix::new();
+    evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        model,
+        &iter_params,
+        &res_config,
+        InaccurateJacobianPolicy::Accept,
+    )
+    .unwrap();
+    jacobian.get_jacobian().as_ref().unwrap().clone()
+}
+
+#[test]
+fn batch_jacobian_single_crossing() {
+    let mut model_columns = ExternalModel::new(false);
+    let mut model_batch = ExternalModel::new(true);
+
+    let jacobian_columns = jacobian_of(&mut model_columns);
+    let jacobian_batch = jacobian_of(&mut model_batch);
+
+    assert_eq!(model_columns.crossings, PROBLEM_SIZE);
+    assert_eq!(model_batch.crossings, 1);
+    assert_eq!(jacobian_batch, jacobian_columns);
+}
+
+#[test]
+fn batch_inputs_ordered_by_column() {
+    let mut model = ExternalModel::new(true);
+    jacobian_of(&mut model);
+
+    // The i-th input is the reference point with the i-th iterative perturbed
+    let reference = nalgebra::DVector::from_vec(vec![0.5, 1.5, 2.5]);
+    assert_eq!(model.batch_inputs.len(), PROBLEM_SIZE);
+    for (i, input) in model.batch_inputs.iter().enumerate() {
+        for j in 0..PROBLEM_SIZE {
+            if i == j {
+                assert!(input[j] > reference[j]);
+            } else {
+                assert_eq!(input[j], reference[j]);
+            }
+        }
+    }
+}
+
+#[test]
+fn inaccurate_batch_evaluated_by_column() {
+    let mut model_columns = ExternalModel::new(false);
+    let mut model_batch = ExternalModel::new(true);
+    model_batch.inaccurate_batch = true;
+
+    let jacobian_columns = jacobian_of(&mut model_columns);
+    let jacobian_batch = jacobian_of(&mut model_batch);
+
+    assert_eq!(model_batch.crossings, 1 + PROBLEM_SIZE);
+    assert_eq!(jacobian_batch, jacobian_columns);
+}
+
+fn solve(model: &mut ExternalModel) -> (nalgebra::DVector<f64>, usize, usize) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.5, 2.5, 3.5]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+
+    rf.solve(model).unwrap();
+    (
+        model.get_iteratives(),
+        rf.get_iter(),
+        rf.get_model_evaluations(),
+    )
+}
+
+#[test]
+fn batch_resolution() {
+    let mut model_columns = ExternalModel::new(false);
+    let mut model_batch = ExternalModel::new(true);
+
+    let (solution_columns, iter_columns, evaluations_columns) = solve(&mut model_columns);
+    let (solution_batch, iter_batch, evaluations_batch) = solve(&mut model_batch);
+
+    let root = nalgebra::DVector::from_vec(vec![1.0, 2.0, 3.0]);
+    assert!((&solution_batch - &root).amax() < 1e-6);
+    assert!((&solution_batch - &solution_columns).amax() < 1e-6);
+    assert_eq!(iter_batch, iter_columns);
+
+    // The same points are evaluated, in one crossing per jacobian instead of one per column
+    assert_eq!(evaluations_batch, evaluations_columns);
+    assert_eq!(model_columns.crossings, evaluations_columns);
+    assert_eq!(
+        model_batch.crossings,
+        evaluations_batch - iter_batch * (PROBLEM_SIZE - 1)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn batch_evaluation_through_dyn_model() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut model = ExternalModel::new(true).into_dyn();
+    assert!(model.provides_batch_evaluation());
+    model.set_iteratives(&nalgebra::DVector::from_vec(vec![0.5, 1.5, 2.5]));
+    model.evaluate().unwrap();
+
+    let mut jacobian = JacobianMatrix::new();
+    evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        &mut model,
+        &iter_params,
+        &res_config,
+        InaccurateJacobianPolicy::Accept,
+    )
+    .unwrap();
+
+    let external_model = model.downcast_ref::<ExternalModel>().unwrap();
+    assert_eq!(external_model.crossings, 2);
+    assert_eq!(
+        jacobian.get_jacobian().as_ref().unwrap(),
+        &jacobian_of(&mut ExternalModel::new(false))
+    );
+}
@@ -0,0 +1,182 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+
+use util::test_cases::broyden1965::*;
+
+use crate::common::float_matrix_comparison;
+
+/// Model providing its jacobian column by column only,
+/// the [Model::get_jacobian] method keeping its default implementation
+struct ColumnModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+}
+
+impl ColumnModel {
+    fn new(problem_size: usize) -> Self {
+        ColumnModel {
+            iteratives: nalgebra::DVector::zeros(problem_size),
+            residuals: nalgebra::DVector::zeros(problem_size),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for ColumnModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives = iteratives.clone();
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.residuals.clone(),
+            nalgebra::DVector::zeros(self.len_problem()),
+        )
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.residuals = broyden1965_case5(&self.iteratives);
+        Ok(())
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn provides_jacobian_by_column(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian_column(
+        &mut self,
+        j: usize,
+    ) -> Result<
+        (nalgebra::DVector<f64>, nalgebra::DVector<f64>),
+        nrf::model::ModelError<Self, nalgebra::Dyn>,
+    > {
+        let n = self.len_problem();
+        let left = broyden1965_case5_jac(&self.iteratives)
+            .column(j)
+            .into_owned();
+        Ok((left, nalgebra::DVector::zeros(n)))
+    }
+}
+
+fn evaluate_jacobian<M>(
+    model: &mut M,
+    update_methods: &[residuals::NormalizationMethod],
+) -> nalgebra::DMatrix<f64>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    let stopping_residuals = update_methods.to_vec();
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, update_methods);
+    let mut jacobian = nrf::solver::JacobianMatrix::new();
+    nrf::solver::evaluate_jacobian_from_analytical_function(&mut jacobian, model, &res_config)
+        .unwrap();
+    jacobian.get_jacobian().clone().unwrap()
+}
+
+#[test]
+fn default_jacobian_column_extracts_from_full_jacobian() {
+    let init_guess = init_broyden1965_case5();
+    let problem_size = init_guess.len();
+
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case5,
+        broyden1965_case5_jac,
+    );
+    user_model.set_iteratives(&init_guess);
+    user_model.evaluate().unwrap();
+
+    let jac_ref = broyden1965_case5_jac(&init_guess);
+    for j in 0..problem_size {
+        let (left, right) = user_model.get_jacobian_column(j).unwrap();
+        for i in 0..problem_size {
+            assert!(float_cmp::approx_eq!(
+                f64,
+                left[i],
+                jac_ref[(i, j)],
+                epsilon = 1e-12
+            ));
+        }
+        assert!(right.iter().all(|value| *value == 0.0));
+    }
+}
+
+#[test]
+fn jacobian_by_column_matches_full_jacobian() {
+    let init_guess = init_broyden1965_case5();
+    let problem_size = init_guess.len();
+
+    let mut full_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case5,
+        broyden1965_case5_jac,
+    );
+    full_model.set_iteratives(&init_guess);
+    full_model.evaluate().unwrap();
+
+    let mut column_model = ColumnModel::new(problem_size);
+    column_model.set_iteratives(&init_guess);
+    column_model.evaluate().unwrap();
+
+    for method in [
+        residuals::NormalizationMethod::Abs,
+        residuals::NormalizationMethod::Adapt,
+        residuals::NormalizationMethod::RelToReference(10.0),
+    ] {
+        let update_methods = vec![method; problem_size];
+        let jac_full = evaluate_jacobian(&mut full_model, &update_methods);
+        let jac_column = evaluate_jacobian(&mut column_model, &update_methods);
+        float_matrix_comparison(&jac_column, &jac_full, 1e-12);
+    }
+}
+
+#[test]
+fn solve_with_jacobian_by_column() {
+    let init_guess = init_broyden1965_case5();
+    let problem_size = init_guess.len();
+
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_guess,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+
+    let mut column_model = ColumnModel::new(problem_size);
+    rf.solve(&mut column_model).unwrap();
+
+    let solution = solution_broyden1965_case5();
+    let iteratives = column_model.get_iteratives();
+    for i in 0..problem_size {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+use newton_rootfinder as nrf;
+
+fn jacobian_from(matrix: nalgebra::DMatrix<f64>) -> nrf::solver::JacobianMatrix<nalgebra::Dyn> {
+    let mut jacobian = nrf::solver::JacobianMatrix::new();
+    jacobian.update_jacobian_with_exact_value(matrix).unwrap();
+    jacobian
+}
+
+#[test]
+fn small_matrix_snapshot() {
+    let jacobian = jacobian_from(nalgebra::DMatrix::from_row_slice(
+        2,
+        2,
+        &[2.0, 1.0, 0.0, 4.0],
+    ));
+
+    let expected = "\
+Jacobian matrix
+=================
+
+Jacobian up to date with its inverse
+
+Jacobian:
+
+    |          0          1
+----+----------------------
+  0 |     2.00e0     1.00e0
+  1 |     0.00e0     4.00e0
+
+Inverse of the jacobian Matrix:
+
+    |          0          1
+----+----------------------
+  0 |    5.00e-1   -1.25e-1
+  1 |     0.00e0    2.50e-1
+
+
+";
+
+    assert_eq!(jacobian.format_with(3, 10, 10), expected);
+    assert_eq!(jacobian.to_string(), expected);
+}
+
+#[test]
+fn small_matrix_precision() {
+    let jacobian = jacobian_from(nalgebra::DMatrix::from_row_slice(
+        2,
+        2,
+        &[2.0, 1.0, 0.0, 4.0],
+    ));
+
+    let content = format!("{:.5}", jacobian);
+    assert!(content.contains("    |            0            1\n"));
+    assert!(content.contains("  0 |     2.0000e0     1.0000e0\n"));
+    assert!(content.contains("  0 |    5.0000e-1   -1.2500e-1\n"));
+    assert_eq!(content, jacobian.format_with(5, 10, 10));
+}
+
+#[test]
+fn large_matrix_snapshot() {
+    let mut matrix = nalgebra::DMatrix::identity(12, 12) * 2.0;
+    matrix[(0, 5)] = 8.0;
+    matrix[(3, 7)] = -4.0;
+    let jacobian = jacobian_from(matrix);
+
+    let expected = "\
+Jacobian matrix
+=================
+
+Jacobian up to date with its inverse
+
+Jacobian:
+
+Matrix of size 12x12, only its diagonal and its 2 largest off-diagonal entries are displayed
+
+Diagonal:
+ Id |      Value
+----+-----------
+  0 |     2.00e0
+  1 |     2.00e0
+  2 |     2.00e0
+  3 |     2.00e0
+  4 |     2.00e0
+  5 |     2.00e0
+  6 |     2.00e0
+  7 |     2.00e0
+  8 |     2.00e0
+  9 |     2.00e0
+ 10 |     2.00e0
+ 11 |     2.00e0
+
+Largest off-diagonal entries:
+Row | Col |      Value
+----+-----+-----------
+  0 |   5 |     8.00e0
+  3 |   7 |    -4.00e0
+
+Inverse of the jacobian Matrix:
+
+Matrix of size 12x12, only its diagonal and its 2 largest off-diagonal entries are displayed
+
+Diagonal:
+ Id |      Value
+----+-----------
+  0 |    5.00e-1
+  1 |    5.00e-1
+  2 |    5.00e-1
+  3 |    5.00e-1
+  4 |    5.00e-1
+  5 |    5.00e-1
+  6 |    5.00e-1
+  7 |    5.00e-1
+  8 |    5.00e-1
+  9 |    5.00e-1
+ 10 |    5.00e-1
+ 11 |    5.00e-1
+
+Largest off-diagonal entries:
+Row | Col |      Value
+----+-----+-----------
+  0 |   5 |    -2.00e0
+  3 |   7 |     1.00e0
+
+
+";
+
+    assert_eq!(jacobian.format_with(3, 10, 2), expected);
+}
+
+#[test]
+fn large_matrix_written_in_full_up_to_max_size() {
+    let jacobian = jacobian_from(nalgebra::DMatrix::identity(12, 12));
+
+    let content = jacobian.format_with(3, 12, 2);
+    assert!(!content.contains("Diagonal:"));
+    assert!(content.contains(" 11 |     0.00e0"));
+
+    let content = jacobian.format_with(3, 11, 2);
+    assert!(content.contains("Diagonal:"));
+}
+
+#[test]
+#[should_panic]
+fn zero_significant_digits() {
+    let jacobian = jacobian_from(nalgebra::DMatrix::identity(2, 2));
+    jacobian.format_with(0, 10, 10);
+}
@@ -0,0 +1,155 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{JacobianMatrix, UpdateQuasiNewtonMethod};
+
+use crate::common::float_matrix_comparison;
+
+fn jacobian_example(problem_size: usize) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_fn(problem_size, problem_size, |i, j| {
+        if i == j {
+            4.0 + i as f64
+        } else {
+            1.0 / (1.0 + i as f64 + 2.0 * j as f64)
+        }
+    })
+}
+
+fn steps_example(
+    problem_size: usize,
+) -> (
+    nalgebra::DVector<f64>,
+    nalgebra::DVector<f64>,
+    nalgebra::DVector<f64>,
+) {
+    let s = nalgebra::DVector::from_fn(problem_size, |i, _| 0.1 * (i as f64 + 1.0));
+    let y = nalgebra::DVector::from_fn(problem_size, |i, _| 0.5 - 0.2 * i as f64);
+    let c = nalgebra::DVector::from_fn(problem_size, |i, _| 1.0 + 0.3 * i as f64);
+    (s, y, c)
+}
+
+#[test]
+fn in_place_updates_match_pure_updates() {
+    let problem_size = 4;
+    let jac = jacobian_example(problem_size);
+    let inv_jac = jac.clone().try_inverse().unwrap();
+    let (s, y, c) = steps_example(problem_size);
+    let mut scratch = [
+        nalgebra::DVector::zeros(problem_size),
+        nalgebra::DVector::zeros(problem_size),
+    ];
+
+    let mut jac_in_place = jac.clone();
+    nrf::solver::broyden_first_method_update_jac_in_place(&mut jac_in_place, &s, &y, &mut scratch);
+    let jac_ref = nrf::solver::broyden_first_method_udpate_jac(&jac, &s, &y);
+    float_matrix_comparison(&jac_in_place, &jac_ref, 1e-12);
+
+    let mut jac_in_place = jac.clone();
+    nrf::solver::broyden_second_method_update_jac_in_place(&mut jac_in_place, &s, &y, &mut scratch);
+    let jac_ref = nrf::solver::broyden_second_method_udpate_jac(&jac, &s, &y);
+    float_matrix_comparison(&jac_in_place, &jac_ref, 1e-12);
+
+    let mut jac_in_place = jac.clone();
+    nrf::solver::quasi_method_update_jac_in_place(&mut jac_in_place, &s, &y, &c, &mut scratch);
+    let jac_ref = nrf::solver::quasi_method_update_jac(&jac, &s, &y, &c);
+    float_matrix_comparison(&jac_in_place, &jac_ref, 1e-12);
+
+    let mut jac_in_place = jac.clone();
+    nrf::solver::greenstadt_second_method_update_jac_in_place(
+        &mut jac_in_place,
+        &s,
+        &y,
+        &c,
+        &mut scratch,
+    );
+    let jac_ref = nrf::solver::greenstadt_second_method_udpate_jac(&jac, &s, &y, &c);
+    float_matrix_comparison(&jac_in_place, &jac_ref, 1e-12);
+
+    let mut inv_jac_in_place = inv_jac.clone();
+    nrf::solver::broyden_first_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &s,
+        &y,
+        &mut scratch,
+    );
+    let inv_jac_ref = nrf::solver::broyden_first_method_udpate_inv_jac(&inv_jac, &s, &y);
+    float_matrix_comparison(&inv_jac_in_place, &inv_jac_ref, 1e-12);
+
+    let mut inv_jac_in_place = inv_jac.clone();
+    nrf::solver::broyden_second_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &s,
+        &y,
+        &mut scratch,
+    );
+    let inv_jac_ref = nrf::solver::broyden_second_method_udpate_inv_jac(&inv_jac, &s, &y);
+    float_matrix_comparison(&inv_jac_in_place, &inv_jac_ref, 1e-12);
+
+    let mut inv_jac_in_place = inv_jac.clone();
+    nrf::solver::quasi_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &s,
+        &y,
+        &c,
+        &mut scratch,
+    );
+    let inv_jac_ref = nrf::solver::quasi_method_update_inv_jac(&inv_jac, &s, &y, &c);
+    float_matrix_comparison(&inv_jac_in_place, &inv_jac_ref, 1e-12);
+}
+
+#[test]
+fn jacobian_inverse_matches_lu_inverse() {
+    let jac = jacobian_example(5);
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jac.clone())
+        .unwrap();
+
+    let inv_ref = jac.lu().try_inverse().unwrap();
+    assert_eq!(jacobian.get_inverse().as_ref().unwrap(), &inv_ref);
+}
+
+#[test]
+fn jacobian_reused_across_problem_sizes() {
+    let mut jacobian = JacobianMatrix::new();
+
+    for problem_size in [20, 5, 20] {
+        let jac = jacobian_example(problem_size);
+        let (s, y, c) = steps_example(problem_size);
+
+        jacobian.reset();
+        jacobian
+            .update_jacobian_with_exact_value(jac.clone())
+            .unwrap();
+        nrf::solver::approximate_jacobian(
+            &mut jacobian,
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+            &s,
+            &y,
+            &c,
+        )
+        .unwrap();
+
+        let jac_ref = nrf::solver::broyden_first_method_udpate_jac(&jac, &s, &y);
+        let inv_ref = jac_ref.clone().try_inverse().unwrap();
+        float_matrix_comparison(jacobian.get_jacobian().as_ref().unwrap(), &jac_ref, 1e-12);
+        float_matrix_comparison(jacobian.get_inverse().as_ref().unwrap(), &inv_ref, 1e-12);
+    }
+}
+
+#[test]
+fn non_invertible_jacobian() {
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_example(3))
+        .unwrap();
+
+    let singular =
+        nalgebra::DMatrix::from_vec(3, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0]);
+    assert!(jacobian.update_jacobian_with_exact_value(singular).is_err());
+    assert!(jacobian.get_jacobian().is_none());
+    assert!(jacobian.get_inverse().is_none());
+
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_example(3))
+        .unwrap();
+    assert!(jacobian.get_inverse().is_some());
+}
@@ -0,0 +1,70 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{JacobianMatrix, MatrixFileFormat};
+
+use crate::common::{read_csv_matrix_file, read_matrix_market_file};
+
+fn jacobian_with_zeros() -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_row_slice(
+        3,
+        3,
+        &[2.0, 0.0, 1.0 / 3.0, -1.5e-12, 4.0, 0.0, 0.0, 7.25e8, -0.1],
+    )
+}
+
+#[test]
+fn write_csv_file() {
+    const PATH: &str = "./tests/jacobian/jacobian_write.csv";
+    let matrix = jacobian_with_zeros();
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(matrix.clone())
+        .unwrap();
+
+    jacobian
+        .write_jacobian_file(PATH, MatrixFileFormat::Csv, 3)
+        .unwrap();
+
+    let (iteration, read_matrix) = read_csv_matrix_file(PATH);
+    std::fs::remove_file(PATH).unwrap();
+    assert_eq!(iteration, 3);
+    assert_eq!(read_matrix, matrix);
+}
+
+#[test]
+fn write_matrix_market_file() {
+    const PATH: &str = "./tests/jacobian/jacobian_write.mtx";
+    let matrix = jacobian_with_zeros();
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(matrix.clone())
+        .unwrap();
+
+    jacobian
+        .write_jacobian_file(PATH, MatrixFileFormat::MatrixMarket, 3)
+        .unwrap();
+
+    let content = std::fs::read_to_string(PATH).unwrap();
+    let (iteration, read_matrix) = read_matrix_market_file(PATH);
+    std::fs::remove_file(PATH).unwrap();
+    assert_eq!(iteration, 3);
+    assert_eq!(read_matrix, matrix);
+    // only the nonzero entries are written
+    assert_eq!(content.lines().nth(2), Some("3 3 6"));
+    assert_eq!(content.lines().nth(3), Some("1 1 2e0"));
+}
+
+#[test]
+#[should_panic(expected = "it is not up to date with its inverse")]
+fn write_invalidated_jacobian() {
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jacobian_with_zeros())
+        .unwrap();
+    jacobian.invalidate_jacobian();
+
+    let _ = jacobian.write_jacobian_file(
+        "./tests/jacobian/jacobian_invalidated.csv",
+        MatrixFileFormat::Csv,
+        1,
+    );
+}
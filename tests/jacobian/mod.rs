@@ -1,2 +1,10 @@
+pub mod batch_evaluation;
 pub mod broyden;
+pub mod by_column;
+pub mod display;
+pub mod in_place;
+#[cfg(feature = "std")]
+pub mod matrix_file;
 pub mod non_linear;
+pub mod perturbation_sign;
+pub mod safeguard;
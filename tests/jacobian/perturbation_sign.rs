@@ -0,0 +1,156 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives::{
+    Iterative, IterativeParamsFD, Iteratives, PerturbationMethod, PerturbationSign,
+};
+use nrf::model::Model;
+use nrf::residuals;
+
+const MAX_VALUE: f64 = 1.0;
+
+#[derive(Debug)]
+struct OutOfDomain;
+
+impl fmt::Display for OutOfDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Above the upper bound")
+    }
+}
+
+impl Error for OutOfDomain {}
+
+/// Model of x^2 - 4, that can only be evaluated up to its upper bound
+struct BoundedModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+}
+
+impl BoundedModel {
+    fn new() -> Self {
+        BoundedModel {
+            iteratives: nalgebra::DVector::zeros(1),
+            residuals: nalgebra::DVector::zeros(1),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for BoundedModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = OutOfDomain;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives = iteratives.clone();
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.residuals.clone(), nalgebra::DVector::zeros(1))
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        if self.iteratives[0] > MAX_VALUE {
+            return Err(nrf::model::ModelError::UnusableValuesError(OutOfDomain));
+        }
+        self.residuals[0] = self.iteratives[0].powi(2) - 4.0;
+        Ok(())
+    }
+}
+
+/// Evaluate the jacobian per finite difference on the upper bound of the model
+fn jacobian_on_upper_bound(perturbation_sign: PerturbationSign) -> Result<f64, String> {
+    let mut iterative = IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        MAX_VALUE,
+        1e-6,
+        1e-6,
+        PerturbationMethod::Max,
+    );
+    iterative.set_perturbation_sign(perturbation_sign);
+    let vec_iter_params = vec![iterative];
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut model = BoundedModel::new();
+    model.set_iteratives(&nalgebra::DVector::from_vec(vec![MAX_VALUE]));
+    model.evaluate().unwrap();
+
+    let mut jacobian = nrf::solver::JacobianMatrix::new();
+    nrf::solver::evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        &mut model,
+        &iter_params,
+        &res_config,
+        nrf::solver::InaccurateJacobianPolicy::Fail,
+    )
+    .map_err(|error| error.to_string())?;
+
+    Ok(jacobian.get_jacobian().as_ref().unwrap()[(0, 0)])
+}
+
+#[test]
+fn positive_perturbation_above_the_bound() {
+    assert!(jacobian_on_upper_bound(PerturbationSign::Positive).is_err());
+}
+
+#[test]
+fn negative_perturbation() {
+    let jacobian = jacobian_on_upper_bound(PerturbationSign::Negative).unwrap();
+    assert!(float_cmp::approx_eq!(
+        f64,
+        jacobian,
+        2.0 * MAX_VALUE,
+        epsilon = 1e-5
+    ));
+}
+
+#[test]
+fn perturbation_away_from_nearest_bound() {
+    let jacobian = jacobian_on_upper_bound(PerturbationSign::AwayFromNearestBound).unwrap();
+    assert!(float_cmp::approx_eq!(
+        f64,
+        jacobian,
+        2.0 * MAX_VALUE,
+        epsilon = 1e-5
+    ));
+}
+
+#[test]
+fn perturbation_sign_from_bounds() {
+    let mut iterative = IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        0.0,
+        10.0,
+        1e-3,
+        1e-6,
+        PerturbationMethod::Max,
+    );
+    assert_eq!(
+        iterative.get_perturbation_sign(),
+        PerturbationSign::Positive
+    );
+    iterative.set_perturbation_sign(PerturbationSign::AwayFromNearestBound);
+
+    assert_eq!(iterative.compute_perturbation(1.0), 1e-3);
+    assert_eq!(iterative.compute_perturbation(9.0), -1e-3);
+    assert_eq!(iterative.compute_perturbation(10.0), -1e-3);
+
+    // Without finite bounds, the perturbation is positive
+    let mut unbounded = IterativeParamsFD::default();
+    unbounded.set_perturbation_sign(PerturbationSign::AwayFromNearestBound);
+    assert!(unbounded.compute_perturbation(-1e6) > 0.0);
+}
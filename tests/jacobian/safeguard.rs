@@ -0,0 +1,254 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{JacobianMatrix, QuasiNewtonUpdateStatus, UpdateQuasiNewtonMethod};
+
+const METHODS: [UpdateQuasiNewtonMethod; 4] = [
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    UpdateQuasiNewtonMethod::BroydenSecondMethod,
+    UpdateQuasiNewtonMethod::GreenstadtFirstMethod,
+    UpdateQuasiNewtonMethod::GreenstadtSecondMethod,
+];
+
+fn jacobian_example() -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_vec(2, 2, vec![2.0, 0.5, 1.0, 3.0])
+}
+
+#[test]
+fn in_place_updates_skipped_with_zero_step() {
+    let jac = jacobian_example();
+    let inv_jac = jac.clone().try_inverse().unwrap();
+    let zeros = nalgebra::DVector::zeros(2);
+    let c = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+    let mut scratch = [nalgebra::DVector::zeros(2), nalgebra::DVector::zeros(2)];
+
+    let mut jac_in_place = jac.clone();
+    assert!(!nrf::solver::broyden_first_method_update_jac_in_place(
+        &mut jac_in_place,
+        &zeros,
+        &zeros,
+        &mut scratch
+    ));
+    assert!(!nrf::solver::broyden_second_method_update_jac_in_place(
+        &mut jac_in_place,
+        &zeros,
+        &zeros,
+        &mut scratch
+    ));
+    assert!(!nrf::solver::quasi_method_update_jac_in_place(
+        &mut jac_in_place,
+        &zeros,
+        &zeros,
+        &c,
+        &mut scratch
+    ));
+    assert!(!nrf::solver::greenstadt_second_method_update_jac_in_place(
+        &mut jac_in_place,
+        &zeros,
+        &zeros,
+        &c,
+        &mut scratch
+    ));
+    assert_eq!(jac_in_place, jac);
+
+    let mut inv_jac_in_place = inv_jac.clone();
+    assert!(!nrf::solver::broyden_first_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &zeros,
+        &zeros,
+        &mut scratch
+    ));
+    assert!(!nrf::solver::broyden_second_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &zeros,
+        &zeros,
+        &mut scratch
+    ));
+    assert!(!nrf::solver::quasi_method_update_inv_jac_in_place(
+        &mut inv_jac_in_place,
+        &zeros,
+        &zeros,
+        &c,
+        &mut scratch
+    ));
+    assert_eq!(inv_jac_in_place, inv_jac);
+}
+
+#[test]
+fn in_place_update_skipped_with_tiny_step() {
+    let jac = jacobian_example();
+    let s = nalgebra::DVector::from_vec(vec![1e-200, 0.0]);
+    let y = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+    let mut scratch = [nalgebra::DVector::zeros(2), nalgebra::DVector::zeros(2)];
+
+    // s.norm_squared() underflows to zero
+    let mut jac_in_place = jac.clone();
+    assert!(!nrf::solver::broyden_first_method_update_jac_in_place(
+        &mut jac_in_place,
+        &s,
+        &y,
+        &mut scratch
+    ));
+    assert_eq!(jac_in_place, jac);
+
+    // nearly orthogonal vectors
+    let s = nalgebra::DVector::from_vec(vec![1.0, 0.0]);
+    let c = nalgebra::DVector::from_vec(vec![1e-20, 1.0]);
+    let mut jac_in_place = jac.clone();
+    assert!(!nrf::solver::greenstadt_second_method_update_jac_in_place(
+        &mut jac_in_place,
+        &s,
+        &y,
+        &c,
+        &mut scratch
+    ));
+    assert_eq!(jac_in_place, jac);
+}
+
+#[test]
+fn approximate_jacobian_skipped() {
+    let jac = jacobian_example();
+    let zeros = nalgebra::DVector::zeros(2);
+    let f = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+
+    for method in METHODS {
+        let mut jacobian = JacobianMatrix::new();
+        jacobian
+            .update_jacobian_with_exact_value(jac.clone())
+            .unwrap();
+        let inverse = jacobian.get_inverse().clone();
+
+        let status =
+            nrf::solver::approximate_jacobian(&mut jacobian, method, &zeros, &zeros, &f).unwrap();
+
+        assert_eq!(status, QuasiNewtonUpdateStatus::Skipped);
+        assert_eq!(jacobian.get_jacobian().as_ref().unwrap(), &jac);
+        assert_eq!(jacobian.get_inverse(), &inverse);
+        assert!(!jacobian.compute_jacobian());
+    }
+}
+
+#[test]
+fn approximate_inv_jacobian_skipped() {
+    let jac = jacobian_example();
+    let zeros = nalgebra::DVector::zeros(2);
+    let f = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+
+    for method in METHODS {
+        let mut jacobian = JacobianMatrix::new();
+        jacobian
+            .update_jacobian_with_exact_value(jac.clone())
+            .unwrap();
+        let inverse = jacobian.get_inverse().clone();
+
+        let status =
+            nrf::solver::approximate_inv_jacobian(&mut jacobian, method, &zeros, &zeros, &f);
+
+        assert_eq!(status, QuasiNewtonUpdateStatus::Skipped);
+        assert_eq!(jacobian.get_inverse(), &inverse);
+        assert!(!jacobian.compute_jacobian());
+    }
+}
+
+#[test]
+fn approximate_jacobian_non_finite() {
+    // The norm of the jacobian overflows, hence the update is not considered degenerate,
+    // but the updated jacobian overflows too
+    let jac = nalgebra::DMatrix::from_vec(2, 2, vec![1.0, 0.0, 1e200, 1.0]);
+    let s = nalgebra::DVector::from_vec(vec![1.0, 0.0]);
+    let y = nalgebra::DVector::from_vec(vec![1.0 - 1e150, 0.0]);
+    // c is chosen such that J^T*c = [1e-50, 1e150]
+    let c = nalgebra::DVector::from_vec(vec![1e-50, 0.0]);
+
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(jac.clone())
+        .unwrap();
+    let inverse = jacobian.get_inverse().clone();
+
+    let status = nrf::solver::approximate_jacobian(
+        &mut jacobian,
+        UpdateQuasiNewtonMethod::GreenstadtFirstMethod,
+        &s,
+        &y,
+        &c,
+    )
+    .unwrap();
+
+    assert_eq!(status, QuasiNewtonUpdateStatus::NonFinite);
+    assert!(jacobian.compute_jacobian());
+    // the inverse is not recomputed from the invalid jacobian
+    assert_eq!(jacobian.get_inverse(), &inverse);
+}
+
+#[test]
+fn approximate_inv_jacobian_non_finite() {
+    // The inverse of this matrix is [[1, 1e200], [0, 1]]
+    let jac = nalgebra::DMatrix::from_vec(2, 2, vec![1.0, 0.0, -1e200, 1.0]);
+    let s = nalgebra::DVector::from_vec(vec![1.0 - 1e150, 0.0]);
+    let y = nalgebra::DVector::from_vec(vec![1.0, 0.0]);
+    let c = nalgebra::DVector::from_vec(vec![1e-50, 1e150]);
+
+    let mut jacobian = JacobianMatrix::new();
+    jacobian.update_jacobian_with_exact_value(jac).unwrap();
+
+    let status = nrf::solver::approximate_inv_jacobian(
+        &mut jacobian,
+        UpdateQuasiNewtonMethod::GreenstadtFirstMethod,
+        &s,
+        &y,
+        &c,
+    );
+
+    assert_eq!(status, QuasiNewtonUpdateStatus::NonFinite);
+    assert!(jacobian.compute_jacobian());
+}
+
+#[test]
+fn approximate_inv_jacobian_non_finite_recovery() {
+    // The inverse of this matrix is [[1, 1e200], [0, 1]]:
+    // with these steps, the norms of the degenerate denominators underflow and the updates overflow
+    let jac = nalgebra::DMatrix::from_vec(2, 2, vec![1.0, 0.0, -1e200, 1.0]);
+    let f = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+    let cases = [
+        (
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+            [0.0, 1e-150],
+            [1.0, 1e-150],
+        ),
+        (
+            UpdateQuasiNewtonMethod::BroydenSecondMethod,
+            [0.0, 1.0],
+            [1e-150, 1e-150],
+        ),
+        (
+            UpdateQuasiNewtonMethod::GreenstadtSecondMethod,
+            [0.0, 1.0],
+            [1e-150, 0.0],
+        ),
+    ];
+
+    for (method, s, y) in cases {
+        let s = nalgebra::DVector::from_row_slice(&s);
+        let y = nalgebra::DVector::from_row_slice(&y);
+        let mut jacobian = JacobianMatrix::new();
+        jacobian
+            .update_jacobian_with_exact_value(jac.clone())
+            .unwrap();
+
+        let status = nrf::solver::approximate_inv_jacobian(&mut jacobian, method, &s, &y, &f);
+
+        assert_eq!(status, QuasiNewtonUpdateStatus::NonFinite);
+        assert!(jacobian.compute_jacobian());
+
+        // the invalid inverse is discarded by the computation of the jacobian
+        jacobian
+            .update_jacobian_with_exact_value(jac.clone())
+            .unwrap();
+        assert!(!jacobian.compute_jacobian());
+        assert!(jacobian
+            .get_inverse()
+            .as_ref()
+            .unwrap()
+            .iter()
+            .all(|value| value.is_finite()));
+    }
+}
@@ -1,7 +1,9 @@
 mod advanced_parametrization;
 mod common;
 mod errors;
+mod iteratives;
 mod jacobian;
+#[cfg(feature = "debug_log")]
 mod log;
 mod model;
 #[cfg(feature = "xml_config_file")]
@@ -6,34 +6,25 @@ use std::io::{BufRead, BufReader};
 
 #[test]
 fn broyden_case10_fd() {
-    const FILEPATH: &'static str = "./tests/log/broyden_case10.xml";
-    const LOG_PATH: &'static str = "./tests/log/log.txt";
-    let (solver_parameters, iteratives_vec, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH);
-
-    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
-    let residuals_config =
-        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
-    let problem_size = solver_parameters.get_problem_size();
-
-    let mut rf = nrf::solver::RootFinder::new(
-        solver_parameters,
-        init_broyden1965_case10(),
-        &iteratives,
-        &residuals_config,
-    );
-    rf.activate_debug(&LOG_PATH);
-
+    const FILEPATH: &str = "./tests/log/broyden_case10.xml";
+    const LOG_PATH: &str = "./tests/log/log.txt";
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH);
+    let problem_size = config.get_parameters().get_problem_size().unwrap();
     let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
 
-    rf.solve(&mut user_model).unwrap();
+    config
+        .with_rootfinder(init_broyden1965_case10(), |rf| {
+            rf.activate_debug(LOG_PATH);
+            rf.solve(&mut user_model)
+        })
+        .unwrap();
 
     #[cfg(not(feature = "additional_log_info"))]
     let log_ref = File::open(&"./tests/log/log_ref_without_additional_infos.txt").unwrap();
     #[cfg(feature = "additional_log_info")]
-    let log_ref = File::open(&"./tests/log/log_ref_with_additional_infos.txt").unwrap();
+    let log_ref = File::open("./tests/log/log_ref_with_additional_infos.txt").unwrap();
 
-    let log_new = File::open(&LOG_PATH).unwrap();
+    let log_new = File::open(LOG_PATH).unwrap();
 
     let log_new_reader = BufReader::new(log_new);
     let log_ref_reader = BufReader::new(log_ref);
@@ -0,0 +1,71 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::residuals;
+use nrf::solver::{Damping, DebugWriteMode, MaxIterations, ProblemSize, ResolutionMethod};
+
+/// Solve the case 10 of Broyden with the given convergence options and return the log
+fn solve_log(
+    log_path: &str,
+    error_smoothing: Option<f64>,
+    consecutive_convergences: usize,
+) -> String {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(2);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    parameters.set_error_smoothing(error_smoothing);
+    parameters.set_consecutive_convergences(consecutive_convergences);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug_with_mode(log_path, DebugWriteMode::Buffered);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    log
+}
+
+#[test]
+fn smoothed_errors_logged() {
+    let log = solve_log("./tests/log/log_error_smoothing.txt", Some(0.5), 2);
+
+    assert!(log.contains("Error smoothing: 0.5\n"));
+    assert!(log.contains("Consecutive convergences: 2\n"));
+    let smoothed = log
+        .lines()
+        .filter(|line| line.starts_with("Smoothed maximum error: "))
+        .count();
+    let confirmations: Vec<&str> = log
+        .lines()
+        .filter(|line| line.starts_with("Errors below the tolerance for "))
+        .collect();
+    // one line per iteration, including the initial guess
+    assert_eq!(smoothed, confirmations.len());
+    assert_eq!(
+        confirmations.last(),
+        Some(&"Errors below the tolerance for 2 consecutive iteration(s), 2 required")
+    );
+}
+
+#[test]
+fn nothing_logged_by_default() {
+    let log = solve_log("./tests/log/log_error_smoothing_default.txt", None, 1);
+
+    assert!(!log.contains("Smoothed maximum error"));
+    assert!(!log.contains("Errors below the tolerance for"));
+}
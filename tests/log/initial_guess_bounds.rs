@@ -0,0 +1,51 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives::{IterativeParams, Iteratives};
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+fn linear(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 2.0, x[1] - 1.0])
+}
+
+fn jacobian(_x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::identity(2, 2)
+}
+
+#[test]
+fn projected_initial_guess_logged() {
+    let log_path = "./tests/log/log_initial_guess_bounds.txt";
+    let vec_iter_params = vec![IterativeParams::new(1.0, f64::INFINITY, 0.0, 3.0); 2];
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![-1.0, 1.5]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(log_path);
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(2, linear, jacobian);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    let warnings: Vec<&str> = log
+        .lines()
+        .filter(|line| line.starts_with("Warning: the initial guess"))
+        .collect();
+    assert_eq!(
+        warnings,
+        vec![
+            "Warning: the initial guess of the iterative 0 is out of its bounds, -1 projected to 0"
+        ]
+    );
+}
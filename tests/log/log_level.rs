@@ -0,0 +1,63 @@
+use newton_rootfinder as nrf;
+
+use nrf::solver::{Damping, LogLevel, LogOptions};
+
+const PROBLEM_SIZE: usize = 25;
+
+fn shifted_identity(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_iterator(
+        x.len(),
+        x.iter().enumerate().map(|(i, value)| value - i as f64),
+    )
+}
+
+fn solve_with_log(log_path: &str, log_options: LogOptions) -> String {
+    let init = nalgebra::DVector::zeros(PROBLEM_SIZE);
+    let iteratives_vec = nrf::iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
+    let stopping_criterias = vec![nrf::residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let residuals_config =
+        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iteratives,
+        &residuals_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.set_log_options(log_options);
+    rf.activate_debug(log_path);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, shifted_identity);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    log
+}
+
+#[test]
+fn log_level_summary() {
+    let log = solve_with_log("./tests/log/log_level_summary.txt", LogOptions::default());
+
+    // Iteratives parameters, residuals configuration, perturbations and two iterations
+    assert_eq!(log.matches("\n… (5 more)\n").count(), 5);
+    assert!(!log.contains("| 12 "));
+    assert!(log.contains("| 24 "));
+}
+
+#[test]
+fn log_level_full() {
+    let mut log_options = LogOptions::default();
+    log_options.set_log_level(LogLevel::Full);
+    assert_eq!(log_options.get_log_level(), LogLevel::Full);
+    assert_eq!(log_options.get_jacobian_max_full_size(), usize::MAX);
+
+    let log = solve_with_log("./tests/log/log_level_full.txt", log_options);
+
+    assert!(!log.contains("more)"));
+    assert!(log.contains("| 12 "));
+    assert!(log.contains("| 24 "));
+}
@@ -0,0 +1,100 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{Damping, DebugWriteMode, ResolutionMethod};
+
+fn solve_with_metadata(log_path: &str, write_mode: DebugWriteMode) -> String {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    rf.set_log_metadata("job id", "4242");
+    rf.set_log_metadata("model commit", "3f2a1c9");
+    rf.set_log_metadata("dataset hash", "e3b0c442");
+    rf.activate_debug_with_mode(log_path, write_mode);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    log
+}
+
+/// Lines of the header, before the solver parameters
+fn header(log: &str) -> Vec<&str> {
+    log.lines()
+        .take_while(|line| *line != "Solver parameters")
+        .collect()
+}
+
+fn check_metadata(log: &str) {
+    let header = header(log);
+    let metadata: Vec<&str> = header
+        .iter()
+        .skip_while(|line| !line.starts_with("newton_rootfinder version: "))
+        .skip(1)
+        .take(3)
+        .copied()
+        .collect();
+    assert_eq!(
+        metadata,
+        vec![
+            "job id: 4242",
+            "model commit: 3f2a1c9",
+            "dataset hash: e3b0c442"
+        ]
+    );
+}
+
+#[test]
+fn metadata_in_streaming_header() {
+    let log = solve_with_metadata(
+        "./tests/log/log_metadata_streaming.txt",
+        DebugWriteMode::Streaming,
+    );
+    check_metadata(&log);
+}
+
+#[test]
+fn metadata_in_buffered_header() {
+    let log = solve_with_metadata(
+        "./tests/log/log_metadata_buffered.txt",
+        DebugWriteMode::Buffered,
+    );
+    check_metadata(&log);
+}
+
+#[test]
+#[should_panic(
+    expected = "The metadata \"job id\" must be set before the activation of the debug log"
+)]
+fn metadata_after_activation() {
+    const LOG_PATH: &str = "./tests/log/log_metadata_after_activation.txt";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.activate_debug(LOG_PATH);
+    std::fs::remove_file(LOG_PATH).unwrap();
+    rf.set_log_metadata("job id", "4242");
+}
@@ -0,0 +1,71 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::residuals;
+use nrf::solver::{
+    Damping, DebugWriteMode, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod,
+};
+
+/// Solve the case 5 of Broyden and return the method state lines of the log
+fn method_states(log_path: &str, resolution_method: ResolutionMethod) -> Vec<String> {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(5);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        resolution_method,
+        Damping::Disabled,
+    );
+    rf.activate_debug_with_mode(log_path, DebugWriteMode::Buffered);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    log.lines()
+        .filter(|line| line.starts_with("Method state: "))
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn broyden_first_method() {
+    let states = method_states(
+        "./tests/log/log_method_state_broyden.txt",
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+    );
+
+    assert_eq!(
+        states,
+        [
+            "Method state: Full computation (Finite difference)",
+            "Method state: Update applied (Broyden First Method)",
+            "Method state: Update applied (Broyden First Method)",
+            "Method state: Update applied (Broyden First Method)",
+        ]
+    );
+}
+
+#[test]
+fn stationary_newton() {
+    let states = method_states(
+        "./tests/log/log_method_state_stationary.txt",
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+    );
+
+    assert_eq!(
+        states[0],
+        "Method state: Full computation (Finite difference)"
+    );
+    assert!(states.len() > 1);
+    assert!(states[1..]
+        .iter()
+        .all(|state| state == "Method state: Jacobian reused"));
+}
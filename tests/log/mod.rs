@@ -1,2 +1,13 @@
 #[cfg(feature = "xml_config_file")]
 mod broyden;
+mod error_smoothing;
+mod initial_guess_bounds;
+mod log_level;
+mod metadata;
+mod method_state;
+mod multiple_solves;
+mod perturbations;
+mod residuals_members;
+mod structural_reduction;
+mod termination;
+mod write_mode;
@@ -0,0 +1,120 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{Damping, DebugFilePolicy, DebugWriteMode, ResolutionMethod};
+
+fn solve_twice_broyden_case10(log_path: &str, write_mode: DebugWriteMode, policy: DebugFilePolicy) {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    rf.set_debug_file_policy(policy);
+    rf.activate_debug_with_mode(log_path, write_mode);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+    rf.solve(&mut user_model).unwrap();
+    rf.set_initial_guess(&init_broyden1965_case10());
+    rf.solve(&mut user_model).unwrap();
+}
+
+fn count_lines(content: &str, line: &str) -> usize {
+    content.lines().filter(|&l| l == line).count()
+}
+
+#[test]
+fn append_policy_separates_solves() {
+    for (log_path, write_mode) in [
+        (
+            "./tests/log/log_append_streaming.txt",
+            DebugWriteMode::Streaming,
+        ),
+        (
+            "./tests/log/log_append_buffered.txt",
+            DebugWriteMode::Buffered,
+        ),
+    ] {
+        solve_twice_broyden_case10(log_path, write_mode, DebugFilePolicy::Append);
+        let content = std::fs::read_to_string(log_path).unwrap();
+        std::fs::remove_file(log_path).unwrap();
+
+        assert_eq!(count_lines(&content, "Runner informations"), 1);
+        assert_eq!(count_lines(&content, "Solver parameters"), 2);
+        assert_eq!(count_lines(&content, "Iteration: 1"), 2);
+
+        let first = content.find("=== solve #1 ===").unwrap();
+        let second = content.find("=== solve #2 ===").unwrap();
+        assert!(first < second);
+        // Each resolution starts with its parameters and restarts the iteration numbering
+        for solve in [&content[first..second], &content[second..]] {
+            assert_eq!(count_lines(solve, "Solver parameters"), 1);
+            assert_eq!(count_lines(solve, "Iteration: 0"), 1);
+        }
+        assert_eq!(
+            content[first..second],
+            content[second..].replace("#2", "#1")
+        );
+    }
+}
+
+#[test]
+fn numbered_files_policy_writes_one_file_per_solve() {
+    const LOG_PATH: &str = "./tests/log/log_numbered.txt";
+    const LOG_PATH_1: &str = "./tests/log/log_numbered_001.txt";
+    const LOG_PATH_2: &str = "./tests/log/log_numbered_002.txt";
+
+    solve_twice_broyden_case10(
+        LOG_PATH,
+        DebugWriteMode::Streaming,
+        DebugFilePolicy::NumberedFiles,
+    );
+
+    assert!(!std::path::Path::new(LOG_PATH).exists());
+    let content_1 = std::fs::read_to_string(LOG_PATH_1).unwrap();
+    let content_2 = std::fs::read_to_string(LOG_PATH_2).unwrap();
+    std::fs::remove_file(LOG_PATH_1).unwrap();
+    std::fs::remove_file(LOG_PATH_2).unwrap();
+
+    for content in [&content_1, &content_2] {
+        assert!(content.starts_with("Runner informations"));
+        assert!(!content.contains("=== solve #"));
+        assert_eq!(count_lines(content, "Solver parameters"), 1);
+        assert_eq!(count_lines(content, "Iteration: 0"), 1);
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "The debug file policy must be set before the activation of the debug log"
+)]
+fn file_policy_after_activation() {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.activate_debug_with_mode(
+        "./tests/log/log_policy_after_activation.txt",
+        DebugWriteMode::Buffered,
+    );
+    std::fs::remove_file("./tests/log/log_policy_after_activation.txt").unwrap();
+    rf.set_debug_file_policy(DebugFilePolicy::NumberedFiles);
+}
@@ -0,0 +1,150 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{MaxIterations, ProblemSize};
+
+use nrf::iteratives::{IterativeParamsFD, PerturbationMethod};
+use nrf::residuals::NormalizationMethod;
+
+const DX_ABS: f64 = 1e-3;
+const DX_REL: f64 = 1e-2;
+
+fn linear(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 3.0, x[1] + 0.5])
+}
+
+fn parse_logged_perturbations(log_path: &str) -> (Vec<f64>, Vec<String>) {
+    let log = std::fs::read_to_string(log_path).unwrap();
+    let mut lines = log
+        .lines()
+        .skip_while(|line| *line != "Finite difference perturbations");
+
+    let mut perturbations = Vec::new();
+    let mut warnings = Vec::new();
+    for line in lines.by_ref().skip(1) {
+        if line.starts_with("| Id") || line.starts_with('+') || line.is_empty() {
+            continue;
+        }
+        if line.starts_with("Warning") {
+            warnings.push(line.to_string());
+            continue;
+        }
+        if !line.starts_with('|') {
+            break;
+        }
+        let perturbation = line.split('|').nth(2).unwrap().trim();
+        perturbations.push(perturbation.parse::<f64>().unwrap());
+    }
+
+    (perturbations, warnings)
+}
+
+#[test]
+fn logged_perturbations() {
+    const LOG_PATH: &str = "./tests/log/log_perturbations.txt";
+    let init = nalgebra::DVector::from_vec(vec![10.0, -0.05]);
+
+    let iteratives_vec = [
+        IterativeParamsFD::new(
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            DX_ABS,
+            DX_REL,
+            PerturbationMethod::Max,
+        ),
+        IterativeParamsFD::new(
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            -0.049,
+            DX_ABS,
+            DX_REL,
+            PerturbationMethod::Sum,
+        ),
+    ];
+    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
+    let stopping_criterias = [NormalizationMethod::Abs; 2];
+    let update_methods = [NormalizationMethod::Abs; 2];
+    let residuals_config =
+        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+    let solver_parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters,
+        init.clone(),
+        &iteratives,
+        &residuals_config,
+    );
+    rf.activate_debug(LOG_PATH);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, linear);
+    rf.solve(&mut user_model).unwrap();
+
+    let perturbations_ref = [
+        DX_ABS.max(DX_REL * init[0].abs()), // Max formula
+        DX_ABS + DX_REL * init[1].abs(),    // Sum formula
+    ];
+
+    let (perturbations_logged, warnings) = parse_logged_perturbations(LOG_PATH);
+    std::fs::remove_file(LOG_PATH).unwrap();
+
+    assert_eq!(perturbations_logged.len(), 2);
+    for i in 0..2 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            perturbations_logged[i],
+            perturbations_ref[i],
+            ulps = 2
+        ));
+        assert_eq!(
+            rf.get_perturbations().as_ref().unwrap()[i],
+            perturbations_ref[i]
+        );
+    }
+
+    // The perturbed value of the second iterative is above its max value
+    assert_eq!(rf.get_perturbations_out_of_bounds(), &[1]);
+    assert_eq!(
+        warnings,
+        vec!["Warning: the perturbed values of the iteratives [1] are out of their bounds, the finite difference may be inaccurate"]
+    );
+}
+
+#[test]
+fn logged_perturbation_jitter() {
+    const LOG_PATH: &str = "./tests/log/log_perturbation_jitter.txt";
+    let init = nalgebra::DVector::from_vec(vec![10.0, -0.05]);
+
+    let iteratives_vec = nrf::iteratives::default_vec_iteratives_fd(2);
+    let iteratives = nrf::iteratives::Iteratives::new(&iteratives_vec);
+    let stopping_criterias = [NormalizationMethod::Abs; 2];
+    let update_methods = [NormalizationMethod::Abs; 2];
+    let residuals_config =
+        nrf::residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+    let mut solver_parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+    );
+    solver_parameters.set_perturbation_jitter(Some(0.1));
+
+    let mut rf =
+        nrf::solver::RootFinder::new(solver_parameters, init, &iteratives, &residuals_config);
+    rf.activate_debug(LOG_PATH);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, linear);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+
+    assert!(log.contains("Perturbation jitter: 0.1\n"));
+    assert!(log.contains("Perturbations jittered with the relative amplitude 0.1 (iteration 1)\n"));
+}
@@ -0,0 +1,147 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::residuals::{self, NormalizationMethod};
+use nrf::solver::{Damping, LogLevel, LogOptions};
+
+/// Equations with non-zero right members:
+/// - `x0 = 1`
+/// - `x0 * x1 = 4`
+/// - `x2 * x2 = 9`
+struct MembersModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for MembersModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        3
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        let x = &self.iteratives;
+        self.left = nalgebra::DVector::from_vec(vec![x[0], x[0] * x[1], x[2] * x[2]]);
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.left.clone(),
+            nalgebra::DVector::from_vec(vec![1.0, 4.0, 9.0]),
+        )
+    }
+}
+
+/// Section of the first iteration (with the initial guess) of the debug log
+fn first_iteration_section(log_level: LogLevel, log_path: &str) -> String {
+    let problem_size = 3;
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_criterias = [
+        NormalizationMethod::Abs,
+        NormalizationMethod::Rel,
+        NormalizationMethod::Adapt,
+    ];
+    let update_methods = [
+        NormalizationMethod::Rel,
+        NormalizationMethod::Adapt,
+        NormalizationMethod::Abs,
+    ];
+    let res_config = residuals::ResidualsConfig::new(&stopping_criterias, &update_methods);
+    let init = nalgebra::DVector::from_vec(vec![2.0, 1.0, 2.0]);
+
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        nrf::solver::ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut log_options = LogOptions::default();
+    log_options.set_log_level(log_level);
+    rf.set_log_options(log_options);
+    rf.activate_debug(log_path);
+
+    let mut user_model = MembersModel {
+        iteratives: nalgebra::DVector::zeros(problem_size),
+        left: nalgebra::DVector::zeros(problem_size),
+    };
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+
+    let start = log.find("Iteration: 0").unwrap();
+    let end = start + log[start..].find("Method state").unwrap();
+    log[start..end].to_string()
+}
+
+#[test]
+fn residuals_members_summary() {
+    let section = first_iteration_section(LogLevel::Summary, "./tests/log/log_members_summary.txt");
+
+    let expected = "Iteration: 0
+
+Max error: 1
+
++-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+
+| Id    |           Iteratives          |              Left             |             Right             |        Update residual        |       Stopping residual       |
++-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+
+| 0     | 2                             | 2                             | 1                             | 0.6666666666666666            | 1                             |
++-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+
+| 1     | 1                             | 2                             | 4                             | -0.5                          | 0.6666666666666666            |
++-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+
+| 2     | 2                             | 4                             | 9                             | -5                            | 0.6666666666666666            |
++-------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+-------------------------------+
+
+";
+    assert_eq!(section, expected);
+}
+
+#[test]
+fn residuals_members_full() {
+    let section_full = first_iteration_section(LogLevel::Full, "./tests/log/log_members_full.txt");
+    let section_summary = first_iteration_section(
+        LogLevel::Summary,
+        "./tests/log/log_members_full_summary.txt",
+    );
+
+    assert_eq!(section_full, section_summary);
+}
+
+#[test]
+fn residuals_members_iterations() {
+    let section = first_iteration_section(
+        LogLevel::Iterations,
+        "./tests/log/log_members_iterations.txt",
+    );
+
+    let expected = "Iteration: 0
+
+Max error: 1
+
++-------+-------------------------------+-------------------------------+-------------------------------+
+| Id    |           Iteratives          |        Update residual        |       Stopping residual       |
++-------+-------------------------------+-------------------------------+-------------------------------+
+| 0     | 2                             | 0.6666666666666666            | 1                             |
++-------+-------------------------------+-------------------------------+-------------------------------+
+| 1     | 1                             | -0.5                          | 0.6666666666666666            |
++-------+-------------------------------+-------------------------------+-------------------------------+
+| 2     | 2                             | -5                            | 0.6666666666666666            |
++-------+-------------------------------+-------------------------------+-------------------------------+
+
+";
+    assert_eq!(section, expected);
+}
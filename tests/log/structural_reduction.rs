@@ -0,0 +1,50 @@
+use newton_rootfinder as nrf;
+
+use nrf::residuals;
+use nrf::solver::{
+    Damping, DebugWriteMode, MaxIterations, ProblemSize, ResolutionMethod,
+    StructuralSingularityPolicy,
+};
+
+/// Equation `x^2 = 2` along with a disconnected equation, the iterative `y` affecting no residual
+fn disconnected(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] - 2.0, 3.0])
+}
+
+#[test]
+fn reduction_logged() {
+    let log_path = "./tests/log/log_structural_reduction.txt";
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(2);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_structural_singularity_policy(StructuralSingularityPolicy::Reduce);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.0, 5.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug_with_mode(log_path, DebugWriteMode::Buffered);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, disconnected);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+
+    assert!(log.contains("Structural singularity policy: Reduce\n"));
+    let warnings = log
+        .lines()
+        .filter(|line| line.starts_with("WARNING: the residual 1 depends on no iterative and the iterative 1 affects no residual"))
+        .count();
+    assert_eq!(warnings, 1);
+}
@@ -0,0 +1,226 @@
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, DebugWriteMode, ResolutionMethod};
+
+#[derive(Debug)]
+struct ModelCrash;
+
+impl fmt::Display for ModelCrash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Model crashed")
+    }
+}
+
+impl Error for ModelCrash {}
+
+/// Model of x^2 - 2, crashing once its evaluations exceed a given number
+struct CrashingModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+    evaluations: usize,
+    max_evaluations: usize,
+}
+
+impl CrashingModel {
+    fn new(max_evaluations: usize) -> Self {
+        CrashingModel {
+            iteratives: nalgebra::DVector::zeros(1),
+            residuals: nalgebra::DVector::zeros(1),
+            evaluations: 0,
+            max_evaluations,
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for CrashingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = ModelCrash;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        if self.evaluations > self.max_evaluations {
+            return Err(nrf::model::ModelError::UnusableValuesError(ModelCrash));
+        }
+        self.residuals[0] = self.iteratives[0].powi(2) - 2.0;
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.residuals.clone(), nalgebra::DVector::zeros(1))
+    }
+}
+
+/// Solve the model and return the result, the number of iterations and the termination block of the log
+fn solve_crashing_model(
+    log_path: &str,
+    write_mode: DebugWriteMode,
+    max_evaluations: usize,
+) -> (Result<(), String>, usize, String) {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(1);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.activate_debug_with_mode(log_path, write_mode);
+
+    let mut user_model = CrashingModel::new(max_evaluations);
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+
+    // The log is read before the solver is dropped, the termination must already be written
+    let log = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+    let start = log.find("Termination").unwrap();
+
+    (result, rf.get_iter(), log[start..].to_string())
+}
+
+#[test]
+fn termination_on_model_error() {
+    for (log_path, write_mode) in [
+        (
+            "./tests/log/log_termination_streaming.txt",
+            DebugWriteMode::Streaming,
+        ),
+        (
+            "./tests/log/log_termination_buffered.txt",
+            DebugWriteMode::Buffered,
+        ),
+    ] {
+        // Initial evaluation, jacobian, first update, jacobian, then crash of the second update
+        let (result, iter, termination) = solve_crashing_model(log_path, write_mode, 4);
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Model evaluation failed: UnusableValuesError Error: Model crashed"
+        );
+        assert_eq!(iter, 2);
+        let expected = "Termination
+
+Outcome: Model evaluation failed: UnusableValuesError Error: Model crashed
+Initial max error: 1
+Last iteration: 2
+Last max error: ";
+        assert!(termination.starts_with(expected));
+
+        // Error of the first update x = 1.5, up to the finite difference approximation
+        let (max_error, errors_over_tolerance) =
+            termination[expected.len()..].split_once('\n').unwrap();
+        let max_error: f64 = max_error.parse().unwrap();
+        assert!(float_cmp::approx_eq!(f64, max_error, 0.25, epsilon = 1e-6));
+        // The error of the first update, at the default tolerance of 1e-6
+        let expected = "Residuals above their tolerance: 1\nWorst residual: 0 at ";
+        assert!(errors_over_tolerance.starts_with(expected));
+        let (worst_error, _) = errors_over_tolerance[expected.len()..]
+            .split_once(" times its tolerance\n")
+            .unwrap();
+        let worst_error: f64 = worst_error.parse().unwrap();
+        assert!(float_cmp::approx_eq!(
+            f64,
+            worst_error * 1e-6,
+            max_error,
+            epsilon = 1e-12
+        ));
+    }
+}
+
+#[test]
+fn termination_on_initial_evaluation_error() {
+    let (result, iter, termination) = solve_crashing_model(
+        "./tests/log/log_termination_initial.txt",
+        DebugWriteMode::Buffered,
+        0,
+    );
+
+    assert!(result.is_err());
+    assert_eq!(iter, 0);
+    assert!(termination.starts_with("Termination\n\nOutcome: Initial model evaluation failed: "));
+    assert!(termination.ends_with(
+        "Initial max error: not evaluated\nLast iteration: 0\nLast max error: not evaluated\n\n"
+    ));
+}
+
+#[test]
+fn termination_on_success() {
+    let (result, iter, termination) = solve_crashing_model(
+        "./tests/log/log_termination_success.txt",
+        DebugWriteMode::Streaming,
+        usize::MAX,
+    );
+
+    assert!(result.is_ok());
+    assert!(termination
+        .starts_with("Termination\n\nOutcome: Convergence reached\nInitial max error: 1\n"));
+    assert!(termination.contains(&format!("Last iteration: {}\n", iter)));
+}
+
+#[test]
+fn convergence_rate_on_success() {
+    let (result, iter, termination) = solve_crashing_model(
+        "./tests/log/log_termination_convergence_rate.txt",
+        DebugWriteMode::Buffered,
+        usize::MAX,
+    );
+
+    assert!(result.is_ok());
+    assert!(iter >= nrf::solver::MIN_ITERATIONS_CONVERGENCE_RATE);
+    assert!(termination.contains("\n\nEstimated convergence: order "));
+    assert!(!termination.contains("WARNING"));
+}
+
+#[test]
+fn suspicious_jacobian_warning() {
+    const LOG_PATH: &str = "./tests/log/log_termination_suspicious_jacobian.txt";
+    // derivative of x^2 - 2 off by 50 %
+    fn wrong_derivative(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+        nalgebra::DMatrix::from_element(1, 1, 3.0 * x[0])
+    }
+    fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+        x.map(|x| x * x - 2.0)
+    }
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives(1);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.activate_debug_with_mode(LOG_PATH, DebugWriteMode::Buffered);
+    let mut user_model =
+        nrf::model::UserModelFromFunctionAndJacobian::new(1, square2, wrong_derivative);
+
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(rf.get_suspicious_jacobian());
+    assert!(log.contains(
+        "WARNING: the Newton-Raphson method converged with an order below 1.5, the jacobian of the model may be wrong"
+    ));
+}
@@ -0,0 +1,121 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{Damping, DebugWriteMode, MaxIterations, ProblemSize, ResolutionMethod};
+
+// The simulation start time is not reproducible between two resolutions
+fn read_log_without_time(log_path: &str) -> Vec<String> {
+    let content = std::fs::read_to_string(log_path).unwrap();
+    std::fs::remove_file(log_path).unwrap();
+
+    content
+        .lines()
+        .filter(|line| !line.starts_with("  - UTC:") && !line.starts_with("  - Local:"))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn solve_broyden_case10(
+    log_path: &str,
+    write_mode: DebugWriteMode,
+    max_iter: usize,
+) -> Result<(), String> {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let solver_parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(max_iter),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters,
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug_with_mode(log_path, write_mode);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+
+    rf.solve(&mut user_model).map_err(|error| error.to_string())
+}
+
+#[test]
+fn identical_logs_on_success() {
+    const LOG_STREAMING: &str = "./tests/log/log_streaming_success.txt";
+    const LOG_BUFFERED: &str = "./tests/log/log_buffered_success.txt";
+
+    solve_broyden_case10(LOG_STREAMING, DebugWriteMode::Streaming, 50).unwrap();
+    solve_broyden_case10(LOG_BUFFERED, DebugWriteMode::Buffered, 50).unwrap();
+
+    let log_streaming = read_log_without_time(LOG_STREAMING);
+    let log_buffered = read_log_without_time(LOG_BUFFERED);
+
+    assert!(log_streaming.len() > 100);
+    assert_eq!(log_streaming, log_buffered);
+}
+
+#[test]
+fn identical_logs_on_error() {
+    const LOG_STREAMING: &str = "./tests/log/log_streaming_error.txt";
+    const LOG_BUFFERED: &str = "./tests/log/log_buffered_error.txt";
+
+    let error_streaming = solve_broyden_case10(LOG_STREAMING, DebugWriteMode::Streaming, 2);
+    let error_buffered = solve_broyden_case10(LOG_BUFFERED, DebugWriteMode::Buffered, 2);
+    assert!(error_streaming
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(error_streaming, error_buffered);
+
+    let log_streaming = read_log_without_time(LOG_STREAMING);
+    let log_buffered = read_log_without_time(LOG_BUFFERED);
+
+    assert!(log_streaming.contains(&"Iteration: 2".to_string()));
+    assert_eq!(log_streaming, log_buffered);
+}
+
+fn panicking_model(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    if x[0] < 14.0 {
+        panic!("Model failure");
+    }
+    broyden1965_case10(x)
+}
+
+#[test]
+fn buffered_log_written_on_panic() {
+    const LOG_BUFFERED: &str = "./tests/log/log_buffered_panic.txt";
+
+    let result = std::panic::catch_unwind(|| {
+        let problem_size = 2;
+        let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut rf = nrf::solver::default_with_guess(
+            init_broyden1965_case10(),
+            &iter_params,
+            &res_config,
+            ResolutionMethod::NewtonRaphson,
+            Damping::Disabled,
+        );
+        rf.activate_debug_with_mode(LOG_BUFFERED, DebugWriteMode::Buffered);
+
+        let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, panicking_model);
+        rf.solve(&mut user_model)
+    });
+    assert!(result.is_err());
+
+    // The log has been written when the solver was dropped
+    let log_buffered = read_log_without_time(LOG_BUFFERED);
+    assert!(log_buffered.contains(&"Iteration: 0".to_string()));
+}
@@ -0,0 +1,250 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::model::conformance::{check_model, ConformanceViolation};
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+
+fn sample_points() -> Vec<nalgebra::DVector<f64>> {
+    vec![
+        init_broyden1965_case5(),
+        solution_broyden1965_case5(),
+        nalgebra::DVector::from_element(5, 0.5),
+    ]
+}
+
+fn static_sample_points() -> Vec<nalgebra::SVector<f64, 5>> {
+    sample_points()
+        .iter()
+        .map(|point| nalgebra::SVector::from_column_slice(point.as_slice()))
+        .collect()
+}
+
+#[test]
+fn adapters_from_functions() {
+    let mut model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+
+    let mut model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        5,
+        broyden1965_case5,
+        broyden1965_case5_jac,
+    );
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+}
+
+#[test]
+fn adapters_from_closures() {
+    let closure = |x: &nalgebra::DVector<f64>| broyden1965_case5(x);
+    let jacobian = |x: &nalgebra::DVector<f64>| broyden1965_case5_jac(x);
+
+    let mut model = nrf::model::UserModelFromClosure::new(5, &closure);
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+
+    let mut model = nrf::model::UserModelFromClosureAndJacobian::new(5, &closure, &jacobian);
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+}
+
+#[test]
+fn dimension_adapters() {
+    let mut model = nrf::model::DynToStaticModel::<_, 5>::new(
+        nrf::model::UserModelFromFunctionAndJacobian::new(
+            5,
+            broyden1965_case5,
+            broyden1965_case5_jac,
+        ),
+    );
+    assert_eq!(check_model(&mut model, &static_sample_points()), Ok(()));
+
+    let mut model = nrf::model::StaticToDynModel::new(model);
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dyn_model() {
+    let mut model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5).into_dyn();
+    assert_eq!(check_model(&mut model, &sample_points()), Ok(()));
+}
+
+/// Model of `x - 1` breaking the contract of the trait in the ways selected
+#[derive(Default)]
+struct FaultyModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    evaluations: usize,
+    stale_iteratives: bool,
+    non_deterministic: bool,
+    short_residuals: bool,
+    wrong_jacobian: bool,
+}
+
+impl Model<nalgebra::Dyn> for FaultyModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        2
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        if !self.stale_iteratives || self.iteratives.is_empty() {
+            self.iteratives = iteratives.clone();
+        }
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        self.left = self.iteratives.add_scalar(-1.0);
+        if self.non_deterministic {
+            self.left[0] += self.evaluations as f64;
+        }
+        if self.short_residuals {
+            self.left = self.left.rows(0, 1).into_owned();
+        }
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.left.clone(),
+            nalgebra::DVector::zeros(self.left.len()),
+        )
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.wrong_jacobian
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::identity(3, 3),
+            nalgebra::DMatrix::zeros(3, 3),
+        ))
+    }
+}
+
+fn faulty_sample_points() -> Vec<nalgebra::DVector<f64>> {
+    vec![
+        nalgebra::DVector::from_vec(vec![1.0, 2.0]),
+        nalgebra::DVector::from_vec(vec![3.0, 4.0]),
+    ]
+}
+
+#[test]
+fn stale_iteratives() {
+    let mut model = FaultyModel {
+        stale_iteratives: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        check_model(&mut model, &faulty_sample_points()),
+        Err(vec![ConformanceViolation::IterativesRoundtrip { point: 1 }])
+    );
+}
+
+#[test]
+fn non_deterministic_evaluation() {
+    let mut model = FaultyModel {
+        non_deterministic: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        check_model(&mut model, &faulty_sample_points()),
+        Err(vec![
+            ConformanceViolation::NonDeterministicEvaluation { point: 0 },
+            ConformanceViolation::NonDeterministicEvaluation { point: 1 },
+        ])
+    );
+}
+
+#[test]
+fn residuals_and_jacobian_dimensions() {
+    let mut model = FaultyModel {
+        short_residuals: true,
+        wrong_jacobian: true,
+        ..Default::default()
+    };
+    let sample_points = [
+        nalgebra::DVector::from_vec(vec![1.0, 2.0]),
+        nalgebra::DVector::from_vec(vec![1.0, 2.0, 3.0]),
+    ];
+
+    let violations = check_model(&mut model, &sample_points).unwrap_err();
+    assert_eq!(
+        violations,
+        vec![
+            ConformanceViolation::ResidualsLength {
+                point: 0,
+                length: 1,
+                problem_size: 2
+            },
+            ConformanceViolation::JacobianDimensions {
+                point: 0,
+                rows: 3,
+                columns: 3,
+                problem_size: 2
+            },
+            ConformanceViolation::SamplePointLength {
+                point: 1,
+                length: 3,
+                problem_size: 2
+            },
+        ]
+    );
+    assert_eq!(
+        violations[0].to_string(),
+        "Sample point 0: get_residuals() returned 1 values, the problem size is 2"
+    );
+}
+
+/// Model whose `write_residuals()` leaves the placeholder unchanged
+struct ForgetfulWriter(nrf::model::UserModelFromFunction);
+
+impl Model<nalgebra::Dyn> for ForgetfulWriter {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.0.len_problem()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.0.set_iteratives(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.0.get_iteratives()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.0.evaluate().unwrap();
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        self.0.get_residuals()
+    }
+
+    fn write_residuals(&self, _residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {}
+}
+
+#[test]
+fn write_residuals_mismatch() {
+    let mut model = ForgetfulWriter(nrf::model::UserModelFromFunction::new(5, broyden1965_case5));
+
+    assert_eq!(
+        check_model(&mut model, &sample_points()[..1]),
+        Err(vec![ConformanceViolation::WriteResidualsMismatch {
+            point: 0
+        }])
+    );
+}
@@ -0,0 +1,108 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+const PROBLEM_SIZE: usize = 5;
+
+fn solve_broyden_case5<M, D>(user_model: &mut M, nrows: D) -> nalgebra::DVector<f64>
+where
+    M: Model<D>,
+    D: nalgebra::DimMin<D, Output = D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D>,
+    nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<nalgebra::U1, D>,
+    nalgebra::DefaultAllocator: nalgebra::base::allocator::Allocator<D, D>,
+{
+    let init = nrf::ovector_from_slice_generic(nrows, init_broyden1965_case5().as_slice());
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+        Damping::Disabled,
+    );
+
+    rf.solve(user_model).unwrap();
+
+    nalgebra::DVector::from_column_slice(user_model.get_iteratives().as_slice())
+}
+
+#[test]
+fn broyden_case5_dyn_and_static() {
+    let solution = solution_broyden1965_case5();
+
+    let mut dyn_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5);
+    let dyn_result = solve_broyden_case5(&mut dyn_model, nalgebra::Dyn(PROBLEM_SIZE));
+
+    let mut static_model = nrf::model::DynToStaticModel::<_, PROBLEM_SIZE>::new(
+        nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5),
+    );
+    let static_result = solve_broyden_case5(&mut static_model, nalgebra::Const::<PROBLEM_SIZE>);
+
+    let mut round_trip_model =
+        nrf::model::StaticToDynModel::new(nrf::model::DynToStaticModel::<_, PROBLEM_SIZE>::new(
+            nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5),
+        ));
+    let round_trip_result = solve_broyden_case5(&mut round_trip_model, nalgebra::Dyn(PROBLEM_SIZE));
+
+    for i in 0..PROBLEM_SIZE {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            dyn_result[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+        assert!(float_cmp::approx_eq!(
+            f64,
+            static_result[i],
+            dyn_result[i],
+            epsilon = 1e-12
+        ));
+    }
+    assert_eq!(round_trip_result, dyn_result);
+}
+
+#[test]
+fn static_to_dyn_jacobian() {
+    let init_guess = init_broyden1965_case5();
+
+    let mut user_model =
+        nrf::model::StaticToDynModel::new(nrf::model::DynToStaticModel::<_, PROBLEM_SIZE>::new(
+            nrf::model::UserModelFromFunctionAndJacobian::new(
+                PROBLEM_SIZE,
+                broyden1965_case5,
+                broyden1965_case5_jac,
+            ),
+        ));
+
+    user_model.set_iteratives(&init_guess);
+    user_model.evaluate().unwrap();
+    assert!(user_model.jacobian_provided());
+    let jacobians = user_model.get_jacobian().unwrap();
+    let (left_jac, _right_jac) = jacobians.get_jacobians();
+
+    assert_eq!(left_jac, &broyden1965_case5_jac(&init_guess));
+    assert_eq!(
+        user_model.get_residuals().get_values(0),
+        (broyden1965_case5(&init_guess)[0], 0.0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch between the model and the static dimension 5 != 2")]
+fn dyn_to_static_dimension_mismatch() {
+    let _user_model = nrf::model::DynToStaticModel::<_, 2>::new(
+        nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5),
+    );
+}
@@ -0,0 +1,193 @@
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::{DynModel, Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+/// Model recording the iteratives of each of its evaluations
+struct RecordingModel {
+    model: nrf::model::UserModelFromFunctionAndJacobian,
+    jacobian_provided: bool,
+    evaluations: Vec<nalgebra::DVector<f64>>,
+}
+
+impl RecordingModel {
+    fn new(jacobian_provided: bool) -> Self {
+        RecordingModel {
+            model: nrf::model::UserModelFromFunctionAndJacobian::new(
+                init_broyden1965_case9().len(),
+                broyden1965_case9,
+                broyden1965_case9_jac,
+            ),
+            jacobian_provided,
+            evaluations: Vec::new(),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for RecordingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.model.len_problem()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.model.set_iteratives(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.model.get_iteratives()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations.push(self.model.get_iteratives());
+        self.model.evaluate().unwrap();
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        self.model.get_residuals()
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.jacobian_provided
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        Ok(self.model.get_jacobian().unwrap())
+    }
+}
+
+fn solve<M>(model: &mut M, resolution_method: ResolutionMethod) -> Result<(), String>
+where
+    M: Model<nalgebra::Dyn>,
+{
+    let problem_size = model.len_problem();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case9(),
+        &iter_params,
+        &res_config,
+        resolution_method,
+        Damping::Enabled,
+    );
+
+    rf.solve(model).map_err(|error| error.to_string())
+}
+
+/// Check that the evaluations of the model are the same through a `DynModel`
+fn check_same_iterates(jacobian_provided: bool, resolution_method: ResolutionMethod) {
+    let mut static_model = RecordingModel::new(jacobian_provided);
+    solve(&mut static_model, resolution_method).unwrap();
+
+    let mut dyn_model = RecordingModel::new(jacobian_provided).into_dyn();
+    solve(&mut dyn_model, resolution_method).unwrap();
+    let dyn_evaluations = &dyn_model
+        .downcast_ref::<RecordingModel>()
+        .unwrap()
+        .evaluations;
+
+    assert!(static_model.evaluations.len() > 1);
+    assert_eq!(&static_model.evaluations, dyn_evaluations);
+
+    let solution = solution_broyden1965_case9();
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            dyn_model.get_iteratives()[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn broyden_case9_newton_raphson_fd() {
+    check_same_iterates(false, ResolutionMethod::NewtonRaphson);
+}
+
+#[test]
+fn broyden_case9_newton_raphson_jac() {
+    check_same_iterates(true, ResolutionMethod::NewtonRaphson);
+}
+
+#[test]
+fn broyden_case9_broyden_jac() {
+    check_same_iterates(
+        true,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+    );
+}
+
+#[derive(Debug)]
+struct OutOfDomain;
+
+impl fmt::Display for OutOfDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Out of domain")
+    }
+}
+
+impl Error for OutOfDomain {}
+
+/// Model failing at each evaluation
+struct FailingModel {
+    iteratives: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for FailingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = OutOfDomain;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives = iteratives.clone();
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        Err(ModelError::UnusableValuesError(OutOfDomain))
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.iteratives.clone(), nalgebra::DVector::zeros(1))
+    }
+}
+
+#[test]
+fn erased_error() {
+    let mut dyn_model: DynModel<nalgebra::Dyn> = FailingModel {
+        iteratives: nalgebra::DVector::zeros(1),
+    }
+    .into_dyn();
+
+    match dyn_model.evaluate() {
+        Err(ModelError::UnusableValuesError(error)) => {
+            assert_eq!(error.to_string(), "Out of domain");
+            assert!(error.get_error().downcast_ref::<OutOfDomain>().is_some());
+            assert!(error.source().is_some());
+        }
+        _ => panic!("The error of the model must be kept"),
+    }
+}
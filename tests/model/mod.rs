@@ -1 +1,6 @@
+mod conformance;
+mod dimension_adapters;
+#[cfg(feature = "std")]
+mod dyn_model;
 mod static_model;
+mod write_residuals;
@@ -1,4 +1,5 @@
 use newton_rootfinder as nrf;
+use nrf::solver::{MaxIterations, ProblemSize};
 use std::convert::Infallible;
 
 use nrf::{model::Model, residuals::NormalizationMethod};
@@ -6,8 +7,7 @@ use nrf::{model::Model, residuals::NormalizationMethod};
 /// x**2 - 2 = 0
 /// Root: x = 2.sqrt() approx 1.4142
 pub fn square2(x: &nalgebra::SVector<f64, 1>) -> nalgebra::SVector<f64, 1> {
-    let y = nalgebra::SVector::<f64, 1>::new(x[0] * x[0] - 2.0);
-    y
+    nalgebra::SVector::<f64, 1>::new(x[0] * x[0] - 2.0)
 }
 
 struct UserModel {
@@ -52,11 +52,11 @@ impl Model<nalgebra::Const<1>> for UserModel {
 #[test]
 fn static_types() {
     let solver_parameters = nrf::solver::SolverParameters::new(
-        1,
+        ProblemSize::new(1),
         1e-6,
-        50,
+        MaxIterations::new(50),
         nrf::solver::ResolutionMethod::NewtonRaphson,
-        false,
+        nrf::solver::Damping::Disabled,
     );
 
     let iterative_param = nrf::iteratives::IterativeParamsFD::default();
@@ -0,0 +1,139 @@
+use std::cell::Cell;
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+use util::test_cases::broyden1965::*;
+
+/// Model counting the calls to `get_residuals()`
+///
+/// `write_residuals()` is overriden only if `reuse_residuals` is set,
+/// otherwise the default implementation is used
+struct ResidualsModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    right: nalgebra::DVector<f64>,
+    reuse_residuals: bool,
+    get_residuals_calls: Cell<usize>,
+}
+
+impl ResidualsModel {
+    fn new(problem_size: usize, reuse_residuals: bool) -> Self {
+        ResidualsModel {
+            iteratives: nalgebra::DVector::zeros(problem_size),
+            left: nalgebra::DVector::zeros(problem_size),
+            right: nalgebra::DVector::zeros(problem_size),
+            reuse_residuals,
+            get_residuals_calls: Cell::new(0),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for ResidualsModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives = iteratives.clone();
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.left = broyden1965_case5(&self.iteratives);
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        self.get_residuals_calls
+            .set(self.get_residuals_calls.get() + 1);
+        residuals::ResidualsValues::new(self.left.clone(), self.right.clone())
+    }
+
+    fn write_residuals(&self, residuals: &mut residuals::ResidualsValues<nalgebra::Dyn>) {
+        if self.reuse_residuals {
+            residuals.update_from(&self.left, &self.right);
+        } else {
+            *residuals = self.get_residuals();
+        }
+    }
+}
+
+fn solve(
+    resolution_method: ResolutionMethod,
+    damping: nrf::solver::Damping,
+    reuse_residuals: bool,
+) -> (nalgebra::DVector<f64>, usize, usize) {
+    let problem_size = 5;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        resolution_method,
+        damping,
+    );
+    let mut user_model = ResidualsModel::new(problem_size, reuse_residuals);
+    rf.solve(&mut user_model).unwrap();
+
+    (
+        user_model.get_iteratives(),
+        rf.get_iter(),
+        user_model.get_residuals_calls.get(),
+    )
+}
+
+#[test]
+fn same_resolution_with_reused_residuals() {
+    let resolution_methods = [
+        ResolutionMethod::NewtonRaphson,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenSecondMethod,
+        )),
+    ];
+
+    for resolution_method in resolution_methods {
+        for damping in [
+            nrf::solver::Damping::Disabled,
+            nrf::solver::Damping::Enabled,
+        ] {
+            let (solution_ref, iter_ref, calls_ref) = solve(resolution_method, damping, false);
+            let (solution, iter, calls) = solve(resolution_method, damping, true);
+
+            assert_eq!(solution, solution_ref, "{}", resolution_method);
+            assert_eq!(iter, iter_ref, "{}", resolution_method);
+            assert!(calls < calls_ref, "{}", resolution_method);
+        }
+    }
+}
+
+#[test]
+fn get_residuals_called_once_per_jacobian() {
+    let (_, iter, calls) = solve(
+        ResolutionMethod::NewtonRaphson,
+        nrf::solver::Damping::Disabled,
+        true,
+    );
+
+    // the reference residuals of each finite difference jacobian
+    assert_eq!(calls, iter);
+}
@@ -0,0 +1,124 @@
+use newton_rootfinder as nrf;
+
+use nrf::residuals;
+use nrf::xml_parser::XmlParseError;
+
+const FILEPATH_FD: &str = "./tests/parser/data_fd.xml";
+const FILEPATH_JAC: &str = "./tests/parser/data_jac.xml";
+
+#[test]
+fn load_fd_same_as_tuple() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH_FD);
+    let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
+        nrf::xml_parser::from_xml_finite_diff(FILEPATH_FD);
+
+    // SolverParameters does not implement PartialEq, the parsed values are compared through their Debug output
+    assert_eq!(
+        format!("{:?}", config.get_parameters()),
+        format!("{:?}", solver_parameters)
+    );
+    assert_eq!(config.get_iteratives(), iteratives_parsed.as_slice());
+    assert_eq!(
+        config.get_stopping_criterias(),
+        stopping_criterias.as_slice()
+    );
+    assert_eq!(config.get_update_methods(), update_methods.as_slice());
+}
+
+#[test]
+fn load_jac_same_as_tuple() {
+    let config = nrf::xml_parser::load_xml_jac(FILEPATH_JAC);
+    let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
+        nrf::xml_parser::from_xml_jacobian(FILEPATH_JAC);
+
+    assert_eq!(
+        format!("{:?}", config.get_parameters()),
+        format!("{:?}", solver_parameters)
+    );
+    assert_eq!(config.get_iteratives(), iteratives_parsed.as_slice());
+    assert_eq!(
+        config.get_stopping_criterias(),
+        stopping_criterias.as_slice()
+    );
+    assert_eq!(config.get_update_methods(), update_methods.as_slice());
+}
+
+#[test]
+fn load_str_same_as_file() {
+    assert_eq!(
+        format!(
+            "{:?}",
+            nrf::xml_parser::load_xml_fd_str(include_str!("data_fd.xml")).unwrap()
+        ),
+        format!("{:?}", nrf::xml_parser::load_xml_fd(FILEPATH_FD))
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            nrf::xml_parser::load_xml_jac_str(include_str!("data_jac.xml")).unwrap()
+        ),
+        format!("{:?}", nrf::xml_parser::load_xml_jac(FILEPATH_JAC))
+    );
+}
+
+#[test]
+fn load_str_error() {
+    let content = include_str!("data_fd.xml").replace("problem_size=\"3\"", "problem_size=\"4\"");
+    assert_eq!(
+        nrf::xml_parser::load_xml_fd_str(&content).unwrap_err(),
+        XmlParseError::DimensionMismatch(
+            "Dimension mismatch, got problem_size = 4 and the number of iteratives variables is 3"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unable to read the configuration file ./tests/parser/missing.xml")]
+fn load_file_error() {
+    nrf::xml_parser::load_xml_fd("./tests/parser/missing.xml");
+}
+
+#[test]
+fn built_elements() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH_FD);
+
+    let iteratives = config.build_iteratives();
+    let residuals_config = config.build_residuals_config();
+
+    assert_eq!(iteratives.len(), 3);
+    assert_eq!(residuals_config.len(), 3);
+    assert_eq!(
+        residuals_config.get_update_methods(),
+        &[
+            residuals::NormalizationMethod::Abs,
+            residuals::NormalizationMethod::Abs,
+            residuals::NormalizationMethod::Rel,
+        ]
+    );
+}
+
+#[test]
+fn with_rootfinder() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH_FD);
+
+    let (max_iter, problem_size) = config.with_rootfinder(
+        nalgebra::DVector::from_element(3, 1.0),
+        |rf: &mut nrf::solver::RootFinder<_, nalgebra::Dyn>| {
+            (
+                rf.get_parameters().get_max_iter(),
+                rf.get_parameters().get_problem_size(),
+            )
+        },
+    );
+
+    assert_eq!(max_iter, 60);
+    assert_eq!(problem_size, Some(3));
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n initial_guess.len() = 2 and problem_size = 3")]
+fn with_rootfinder_dimension_mismatch() {
+    let config = nrf::xml_parser::load_xml_fd(FILEPATH_FD);
+    config.with_rootfinder(nalgebra::DVector::from_element(2, 1.0), |_| ());
+}
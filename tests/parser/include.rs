@@ -0,0 +1,135 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+#[test]
+fn included_values_overridden() {
+    const FILEPATH: &str = "./tests/parser/include/problem.xml";
+    let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
+        nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+
+    // values from the included file
+    assert_eq!(solver_parameters.get_problem_size(), Some(2));
+    assert_eq!(solver_parameters.get_tolerance(), 1e-6);
+    assert!(solver_parameters.get_damping().is_enabled());
+    // values overridden by the including file
+    assert_eq!(solver_parameters.get_max_iter(), 30);
+    assert_eq!(
+        solver_parameters.get_resolution_method(),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod
+        ))
+    );
+
+    let iteratives_ref = vec![
+        iteratives::IterativeParamsFD::new(
+            f64::INFINITY,
+            0.5,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            5e-8,
+            5e-8,
+            iteratives::PerturbationMethod::Max,
+        ),
+        iteratives::IterativeParamsFD::new(
+            f64::INFINITY,
+            0.5,
+            0.0,
+            f64::INFINITY,
+            1e-6,
+            5e-8,
+            iteratives::PerturbationMethod::Max,
+        ),
+    ];
+    assert_eq!(iteratives_parsed, iteratives_ref);
+
+    assert_eq!(
+        stopping_criterias,
+        vec![
+            residuals::NormalizationMethod::Abs,
+            residuals::NormalizationMethod::Adapt
+        ]
+    );
+    assert_eq!(update_methods, vec![residuals::NormalizationMethod::Abs; 2]);
+}
+
+#[test]
+fn included_values_with_jacobian_parser() {
+    const FILEPATH: &str = "./tests/parser/include/problem.xml";
+    let (solver_parameters, iteratives_parsed, _stopping_criterias, _update_methods) =
+        nrf::xml_parser::from_xml_jacobian(FILEPATH);
+
+    assert_eq!(solver_parameters.get_max_iter(), 30);
+    assert_eq!(
+        iteratives_parsed[1],
+        iteratives::IterativeParams::new(f64::INFINITY, 0.5, 0.0, f64::INFINITY)
+    );
+}
+
+#[test]
+fn include_relative_to_including_file() {
+    const FILEPATH: &str = "./tests/parser/include/subdir/problem.xml";
+    let (solver_parameters, iteratives_parsed, stopping_criterias, _update_methods) =
+        nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+
+    assert_eq!(solver_parameters.get_max_iter(), 60);
+    assert_eq!(
+        solver_parameters.get_resolution_method(),
+        ResolutionMethod::NewtonRaphson
+    );
+    assert_eq!(
+        iteratives_parsed,
+        vec![nrf::iteratives::IterativeParamsFD::default(); 2]
+    );
+    assert_eq!(
+        stopping_criterias,
+        vec![residuals::NormalizationMethod::Abs; 2]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cyclic include of the configuration file")]
+fn cyclic_include() {
+    const FILEPATH: &str = "./tests/parser/include/cycle_a.xml";
+    nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+}
+
+#[test]
+fn cyclic_include_stack_reported() {
+    const FILEPATH: &str = "./tests/parser/include/cycle_a.xml";
+    let error =
+        std::panic::catch_unwind(|| nrf::xml_parser::from_xml_finite_diff(FILEPATH)).unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+
+    let stack = message.split("include stack: ").nth(1).unwrap();
+    let files: Vec<&str> = stack.split(" -> ").collect();
+    assert_eq!(files.len(), 3);
+    assert!(files[0].ends_with("cycle_a.xml"));
+    assert!(files[1].ends_with("cycle_b.xml"));
+    assert!(files[2].ends_with("cycle_a.xml"));
+}
+
+#[test]
+#[should_panic(expected = "The attribute \"file\" is missing in the include node")]
+fn include_without_file() {
+    const FILEPATH: &str = "./tests/parser/include/missing_file_attribute.xml";
+    nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+}
+
+#[test]
+#[should_panic(expected = "The node 4 below nrf is a duplicate of the node 1, got solver twice")]
+fn duplicate_node() {
+    const FILEPATH: &str = "./tests/parser/include/duplicate_node.xml";
+    nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+}
+
+#[test]
+#[should_panic(
+    expected = "The node 3 below nrf is expected to be \"include\", \"solver\", \"iteratives\" or \"residuals\", got residual"
+)]
+fn unknown_node() {
+    const FILEPATH: &str = "./tests/parser/include/unknown_node.xml";
+    nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+}
@@ -1,2 +1,6 @@
+mod config;
+mod include;
 mod parser_fd;
 mod parser_jac;
+mod solver_options;
+mod validate;
@@ -5,18 +5,18 @@ use nrf::residuals;
 
 #[test]
 fn parse_file() {
-    const FILEPATH: &'static str = "./tests/parser/data_fd.xml";
+    const FILEPATH: &str = "./tests/parser/data_fd.xml";
     let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_finite_diff(&FILEPATH);
+        nrf::xml_parser::from_xml_finite_diff(FILEPATH);
 
-    assert_eq!(solver_parameters.get_problem_size(), 3);
+    assert_eq!(solver_parameters.get_problem_size(), Some(3));
     assert_eq!(solver_parameters.get_max_iter(), 60);
     assert_eq!(solver_parameters.get_tolerance(), 1e-6);
     assert_eq!(
         solver_parameters.get_resolution_method(),
         nrf::solver::ResolutionMethod::NewtonRaphson
     );
-    assert_eq!(solver_parameters.get_damping(), true);
+    assert!(solver_parameters.get_damping().is_enabled());
 
     let iterative1_ref = iteratives::IterativeParamsFD::new(
         100.0,
@@ -5,11 +5,11 @@ use nrf::residuals;
 
 #[test]
 fn parse_file() {
-    const FILEPATH: &'static str = "./tests/parser/data_jac.xml";
+    const FILEPATH: &str = "./tests/parser/data_jac.xml";
     let (solver_parameters, iteratives_parsed, stopping_criterias, update_methods) =
-        nrf::xml_parser::from_xml_jacobian(&FILEPATH);
+        nrf::xml_parser::from_xml_jacobian(FILEPATH);
 
-    assert_eq!(solver_parameters.get_problem_size(), 3);
+    assert_eq!(solver_parameters.get_problem_size(), Some(3));
     assert_eq!(solver_parameters.get_max_iter(), 60);
     assert_eq!(solver_parameters.get_tolerance(), 1e-6);
     assert_eq!(
@@ -18,7 +18,7 @@ fn parse_file() {
             nrf::solver::QuasiNewtonMethod::StationaryNewton
         )
     );
-    assert_eq!(solver_parameters.get_damping(), true);
+    assert!(solver_parameters.get_damping().is_enabled());
 
     let iterative1_ref =
         iteratives::IterativeParams::new(100.0, 0.5, f64::NEG_INFINITY, f64::INFINITY);
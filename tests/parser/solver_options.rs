@@ -0,0 +1,85 @@
+use newton_rootfinder as nrf;
+
+use nrf::solver::{
+    BoundPolicy, CycleBreakingAction, Damping, InaccurateJacobianPolicy, LinearSolver,
+    MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SparsityPattern,
+    StepAcceptanceNorm, StructuralSingularityPolicy,
+};
+
+#[test]
+fn parse_every_solver_option() {
+    const FILEPATH: &str = "./tests/parser/data_solver_options.xml";
+    let (solver_parameters, _, _, _) = nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+
+    assert_eq!(solver_parameters.get_problem_size(), Some(2));
+    assert_eq!(solver_parameters.get_max_iter(), 40);
+    assert_eq!(solver_parameters.get_tolerance(), 1e-16);
+    assert!(solver_parameters.get_extreme_tolerance_allowed());
+    assert!(solver_parameters.get_damping().is_enabled());
+    assert_eq!(
+        solver_parameters.get_resolution_method(),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+    );
+    assert_eq!(solver_parameters.get_newton_warmup_iterations(), 2);
+    assert_eq!(
+        solver_parameters.get_sparsity_pattern(),
+        Some(&SparsityPattern::banded(2, 1, 1))
+    );
+    assert_eq!(solver_parameters.get_max_model_evaluations(), Some(200));
+    assert!(solver_parameters.get_reject_solution_on_bounds());
+    assert_eq!(solver_parameters.get_bound_policy(), BoundPolicy::Fail);
+    assert!(solver_parameters.get_return_best_on_failure());
+    assert_eq!(solver_parameters.get_aitken_acceleration(), Some(3));
+    assert_eq!(
+        solver_parameters.get_first_iteration_step_scale(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        solver_parameters.get_linear_solver(),
+        LinearSolver::Gmres {
+            restart: 10,
+            max_iter: 50,
+            tol: 1e-10
+        }
+    );
+    assert_eq!(
+        solver_parameters.get_inaccurate_jacobian_policy(),
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 4 }
+    );
+    assert_eq!(
+        solver_parameters.get_structural_singularity_policy(),
+        StructuralSingularityPolicy::Reduce
+    );
+    assert_eq!(solver_parameters.get_perturbation_jitter(), Some(0.1));
+    assert_eq!(
+        solver_parameters.get_cycle_detection(),
+        Some(CycleBreakingAction::DampedHalfStep)
+    );
+    assert_eq!(
+        solver_parameters.get_step_acceptance_norm(),
+        StepAcceptanceNorm::Both
+    );
+    assert_eq!(solver_parameters.get_memory_limit_bytes(), Some(1_000_000));
+    assert_eq!(solver_parameters.get_error_smoothing(), Some(0.5));
+    assert_eq!(solver_parameters.get_consecutive_convergences(), 2);
+    assert!(solver_parameters.get_scalar_safeguard());
+    assert_eq!(solver_parameters.get_scalar_safeguard_refinements(), 5);
+}
+
+#[test]
+fn parse_default_solver_options() {
+    const FILEPATH: &str = "./tests/parser/data_fd.xml";
+    let (solver_parameters, _, _, _) = nrf::xml_parser::from_xml_finite_diff(FILEPATH);
+    let reference = nrf::solver::SolverParameters::new(
+        ProblemSize::new(3),
+        1e-6,
+        MaxIterations::new(60),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+
+    assert_eq!(
+        format!("{:?}", solver_parameters),
+        format!("{:?}", reference)
+    );
+}
@@ -0,0 +1,198 @@
+use newton_rootfinder as nrf;
+
+use nrf::solver::{CompatibilityWarning, IterativesKind, QuasiNewtonMethod, ResolutionMethod};
+use nrf::xml_parser::XmlParseError;
+
+const DATA_FD: &str = include_str!("data_fd.xml");
+const DATA_JAC: &str = include_str!("data_jac.xml");
+
+#[test]
+fn validate_finite_diff() {
+    let summary = nrf::xml_parser::validate(DATA_FD, IterativesKind::WithFiniteDifference).unwrap();
+
+    assert_eq!(summary.get_problem_size(), 3);
+    assert_eq!(
+        summary.get_resolution_method(),
+        ResolutionMethod::NewtonRaphson
+    );
+    assert_eq!(summary.get_iteratives_number(), 3);
+    assert_eq!(summary.get_residuals_number(), 3);
+    assert!(summary.get_finite_difference());
+}
+
+#[test]
+fn validate_jacobian() {
+    let summary =
+        nrf::xml_parser::validate(DATA_JAC, IterativesKind::WithoutFiniteDifference).unwrap();
+
+    assert_eq!(summary.get_problem_size(), 3);
+    assert_eq!(
+        summary.get_resolution_method(),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+    );
+    assert!(!summary.get_finite_difference());
+    assert!(summary.get_compatibility_warnings().is_empty());
+}
+
+#[test]
+fn validate_compatibility_warnings() {
+    let content = DATA_FD.replace(
+        "resolution_method=\"NR\"",
+        "resolution_method=\"NR\" newton_warmup=\"2\"",
+    );
+    let summary =
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference).unwrap();
+    assert_eq!(
+        summary.get_compatibility_warnings(),
+        [CompatibilityWarning::UnusedNewtonWarmup(
+            ResolutionMethod::NewtonRaphson
+        )]
+    );
+}
+
+// SolverParameters does not implement PartialEq, the parsed values are compared through their Debug output
+#[test]
+fn parse_str_as_file() {
+    assert_eq!(
+        format!(
+            "{:?}",
+            nrf::xml_parser::from_xml_finite_diff_str(DATA_FD).unwrap()
+        ),
+        format!(
+            "{:?}",
+            nrf::xml_parser::from_xml_finite_diff("./tests/parser/data_fd.xml")
+        )
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            nrf::xml_parser::from_xml_jacobian_str(DATA_JAC).unwrap()
+        ),
+        format!(
+            "{:?}",
+            nrf::xml_parser::from_xml_jacobian("./tests/parser/data_jac.xml")
+        )
+    );
+}
+
+#[test]
+fn validate_dimension_mismatch() {
+    let content = DATA_FD.replace("problem_size=\"3\"", "problem_size=\"4\"");
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::DimensionMismatch(
+            "Dimension mismatch, got problem_size = 4 and the number of iteratives variables is 3"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn validate_invalid_enum_value() {
+    let content = DATA_FD.replace("resolution_method=\"NR\"", "resolution_method=\"BROY3\"");
+    assert!(matches!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::InvalidValue(_))
+    ));
+
+    let content = DATA_FD.replace(
+        "stopping_criteria=\"Rel\"",
+        "stopping_criteria=\"Relative\"",
+    );
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference).unwrap_err().to_string(),
+        "The attribute \"stopping_criteria\" at residual node id = 1 has an improper values, valid values are \"Abs\", \"Rel\", \"Adapt\", \"Adapt:<scale>\" and \"RelToReference\""
+    );
+
+    let content = DATA_FD.replace(
+        "perturbation_method=\"Sum\"",
+        "perturbation_method=\"Mean\"",
+    );
+    assert!(matches!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::InvalidValue(_))
+    ));
+}
+
+#[test]
+fn validate_invalid_value() {
+    let content = DATA_JAC.replace("max_value=\"12\"", "max_value=\"-inf\"");
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithoutFiniteDifference),
+        Err(XmlParseError::InvalidValue(
+            "min_value must be strictly inferior to max_value, provided values are -inf > -inf"
+                .to_string()
+        ))
+    );
+
+    let content = DATA_FD.replace("dx_abs=\"5e-8\"", "dx_abs=\"0\"");
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::InvalidValue(
+            "dx_abs must be strictly positive, provided value was 0".to_string()
+        ))
+    );
+}
+
+#[test]
+fn validate_missing_attribute() {
+    let content = DATA_FD.replace("max_iter=\"60\" ", "");
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::MissingAttribute(
+            "The attribute \"max_iter\" is missing in the solver node".to_string()
+        ))
+    );
+
+    // the finite difference parameters are incomplete
+    let content = DATA_FD.replace("dx_rel=\"5e-9\" ", "");
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::MissingAttribute(
+            "The attribute \"dx_rel\" is missing in the iteratives node".to_string()
+        ))
+    );
+}
+
+#[test]
+fn validate_structure() {
+    let content = DATA_FD.replace("<residual id=\"2\"", "<residual id=\"3\"");
+    assert!(matches!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::Structure(_))
+    ));
+
+    let content = DATA_FD.replace("</nrf>", "<solvr/>\n</nrf>");
+    assert!(matches!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::Structure(_))
+    ));
+}
+
+#[test]
+fn validate_syntax() {
+    let content = DATA_FD.replace("</residuals>", "");
+    assert!(matches!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::Syntax(_))
+    ));
+}
+
+#[test]
+fn validate_explicit_kind() {
+    // without any finite difference attribute, the configuration is still checked as a finite difference one
+    let content = DATA_FD.replace(
+        " dx_abs=\"5e-8\" dx_rel=\"5e-9\" perturbation_method=\"Sum\"",
+        "",
+    );
+    assert_eq!(
+        nrf::xml_parser::validate(&content, IterativesKind::WithFiniteDifference),
+        Err(XmlParseError::MissingAttribute(
+            "The attribute \"dx_abs\" is missing in the iteratives node".to_string()
+        ))
+    );
+
+    let summary =
+        nrf::xml_parser::validate(DATA_FD, IterativesKind::WithoutFiniteDifference).unwrap();
+    assert!(!summary.get_finite_difference());
+}
@@ -0,0 +1,138 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    CompatibilityWarning, Damping, IterativesKind, MaxIterations, ProblemSize, QuasiNewtonMethod,
+    ResolutionMethod, SolverParameters,
+};
+
+const SN: ResolutionMethod = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton);
+
+/// Solve the case 6 of Broyden with the stationary Newton method,
+/// returning the number of iterations and of Aitken extrapolations
+fn solve_case6(aitken_every: Option<usize>) -> (usize, usize) {
+    let problem_size = 5;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-9,
+        MaxIterations::new(200),
+        SN,
+        Damping::Disabled,
+    );
+    parameters.set_aitken_acceleration(aitken_every);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case6(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case6);
+
+    rf.solve(&mut user_model).unwrap();
+
+    let solution = solution_broyden1965_case6();
+    for i in 0..problem_size {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            user_model.get_iteratives()[i],
+            solution[i],
+            epsilon = 1e-5
+        ));
+    }
+    (rf.get_iter(), rf.get_aitken_extrapolations())
+}
+
+#[test]
+fn aitken_reduces_iterations() {
+    let (iter_plain, extrapolations_plain) = solve_case6(None);
+    assert_eq!(iter_plain, 13);
+    assert_eq!(extrapolations_plain, 0);
+    for aitken_every in [1, 2, 3] {
+        let (iter_aitken, extrapolations) = solve_case6(Some(aitken_every));
+        assert!(extrapolations > 0);
+        assert!(iter_aitken + 4 <= iter_plain);
+    }
+}
+
+#[test]
+#[should_panic(expected = "The period of the Aitken acceleration must be strictly positive, got 0")]
+fn aitken_period_zero() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-9,
+        MaxIterations::new(200),
+        SN,
+        Damping::Disabled,
+    );
+    parameters.set_aitken_acceleration(Some(0));
+}
+
+#[test]
+fn aitken_only_with_stationary_newton() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-9,
+        MaxIterations::new(200),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_aitken_acceleration(Some(2));
+    assert_eq!(
+        nrf::solver::check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+        Err(vec![CompatibilityWarning::UnusedAitkenAcceleration(
+            ResolutionMethod::NewtonRaphson
+        )])
+    );
+
+    parameters.set_resolution_method(SN);
+    assert_eq!(
+        nrf::solver::check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+        Ok(())
+    );
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn aitken_extrapolations_in_log() {
+    const LOG_PATH: &str = "./tests/solver/log_aitken.txt";
+    let problem_size = 5;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-9,
+        MaxIterations::new(200),
+        SN,
+        Damping::Disabled,
+    );
+    parameters.set_aitken_acceleration(Some(2));
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case6(),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case6);
+
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("Aitken acceleration every 2 iterations"));
+    assert_eq!(
+        log.matches("Aitken extrapolation of the last three iterates")
+            .count(),
+        rf.get_aitken_extrapolations()
+    );
+}
@@ -0,0 +1,308 @@
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+
+#[derive(Debug)]
+struct NanConstant;
+
+impl fmt::Display for NanConstant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The constant is not a number")
+    }
+}
+
+impl Error for NanConstant {}
+
+/// Equations `x^2 = c` and `y = x + c`
+///
+/// With a negative constant, there is no root and the resolution doesn't converge.
+/// With a NaN constant, the model cannot be evaluated
+#[derive(Debug)]
+struct ShiftedSquare {
+    constant: f64,
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl ShiftedSquare {
+    fn new(constant: f64) -> Self {
+        ShiftedSquare {
+            constant,
+            iteratives: nalgebra::DVector::zeros(2),
+            left: nalgebra::DVector::zeros(2),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for ShiftedSquare {
+    type InaccurateValuesError = NanConstant;
+    type UnusableValuesError = NanConstant;
+
+    fn len_problem(&self) -> usize {
+        2
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        if self.constant.is_nan() {
+            return Err(nrf::model::ModelError::UnusableValuesError(NanConstant));
+        }
+        self.left[0] = self.iteratives[0] * self.iteratives[0] - self.constant;
+        self.left[1] = self.iteratives[1] - self.iteratives[0] - self.constant;
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), nalgebra::DVector::zeros(2))
+    }
+}
+
+fn parameters(resolution_method: ResolutionMethod) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    )
+}
+
+fn broyden() -> ResolutionMethod {
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ))
+}
+
+fn guesses(n: usize) -> Vec<nalgebra::DVector<f64>> {
+    (0..n)
+        .map(|i| nalgebra::DVector::from_vec(vec![2.0 + 0.1 * i as f64, 6.0]))
+        .collect()
+}
+
+/// Solve each model with its own solver, as a user would do without [nrf::solver::solve_batch]
+fn solve_loop(
+    resolution_method: ResolutionMethod,
+    initial_guesses: &[nalgebra::DVector<f64>],
+    models: &mut [ShiftedSquare],
+) -> Vec<Result<(usize, usize), String>> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    initial_guesses
+        .iter()
+        .zip(models.iter_mut())
+        .map(|(initial_guess, model)| {
+            let mut rf = nrf::solver::RootFinder::new(
+                parameters(resolution_method),
+                initial_guess.clone(),
+                &iter_params,
+                &res_config,
+            );
+            rf.solve(model)
+                .map(|()| (rf.get_iter(), rf.get_model_evaluations()))
+                .map_err(|error| error.to_string())
+        })
+        .collect()
+}
+
+fn solve_batch(
+    resolution_method: ResolutionMethod,
+    initial_guesses: &[nalgebra::DVector<f64>],
+    models: &mut [ShiftedSquare],
+) -> Vec<Result<(usize, usize), String>> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    nrf::solver::solve_batch(
+        parameters(resolution_method),
+        &iter_params,
+        &res_config,
+        initial_guesses,
+        models,
+    )
+    .into_iter()
+    .map(|result| {
+        result
+            .map(|report| (report.get_iter(), report.get_model_evaluations()))
+            .map_err(|error| error.to_string())
+    })
+    .collect()
+}
+
+fn assert_same_iteratives(models: &[ShiftedSquare], expected: &[ShiftedSquare]) {
+    for (model, expected) in models.iter().zip(expected) {
+        assert_eq!(model.get_iteratives(), expected.get_iteratives());
+    }
+}
+
+#[test]
+fn batch_same_as_loop() {
+    let constants = [1.2, 1.5, 2.0, 2.5, 3.0];
+    let initial_guesses = guesses(constants.len());
+
+    for resolution_method in [ResolutionMethod::NewtonRaphson, broyden()] {
+        let mut models: Vec<_> = constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+        let mut expected_models: Vec<_> =
+            constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+
+        let results = solve_batch(resolution_method, &initial_guesses, &mut models);
+        let expected = solve_loop(resolution_method, &initial_guesses, &mut expected_models);
+
+        assert_eq!(results, expected);
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert_same_iteratives(&models, &expected_models);
+        for (model, c) in models.iter().zip(constants) {
+            assert!(float_cmp::approx_eq!(
+                f64,
+                model.get_iteratives()[0],
+                c.sqrt(),
+                epsilon = 1e-6
+            ));
+        }
+    }
+}
+
+#[test]
+fn failing_items_do_not_poison_the_next_ones() {
+    let constants = [1.2, -1.0, 2.0, f64::NAN, 3.0];
+    let initial_guesses = guesses(constants.len());
+
+    for resolution_method in [ResolutionMethod::NewtonRaphson, broyden()] {
+        let mut models: Vec<_> = constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+        let mut expected_models: Vec<_> =
+            constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+
+        let results = solve_batch(resolution_method, &initial_guesses, &mut models);
+        let expected = solve_loop(resolution_method, &initial_guesses, &mut expected_models);
+
+        assert!(results[1]
+            .as_ref()
+            .unwrap_err()
+            .starts_with("Convergence not reached: "));
+        assert_eq!(
+            results[3],
+            Err("Initial model evaluation failed: The constant is not a number".to_string())
+        );
+        assert!(results[2].is_ok());
+        assert!(results[4].is_ok());
+        assert_eq!(results, expected);
+        assert_same_iteratives(&models, &expected_models);
+    }
+}
+
+#[test]
+fn empty_batch() {
+    let results = solve_batch(ResolutionMethod::NewtonRaphson, &[], &mut []);
+    assert!(results.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n initial_guesses.len() = 3 and models.len() = 2")]
+fn batch_length_mismatch() {
+    let mut models = vec![ShiftedSquare::new(2.0), ShiftedSquare::new(3.0)];
+    solve_batch(ResolutionMethod::NewtonRaphson, &guesses(3), &mut models);
+}
+
+#[test]
+fn batch_report() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let initial_guesses = guesses(1);
+    let mut models = vec![ShiftedSquare::new(2.0)];
+
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(ResolutionMethod::NewtonRaphson),
+        initial_guesses[0].clone(),
+        &iter_params,
+        &res_config,
+    );
+    rf.solve(&mut ShiftedSquare::new(2.0)).unwrap();
+
+    let results = nrf::solver::solve_batch(
+        parameters(ResolutionMethod::NewtonRaphson),
+        &iter_params,
+        &res_config,
+        &initial_guesses,
+        &mut models,
+    );
+    let report = results[0].as_ref().unwrap();
+
+    assert_eq!(report.get_iter(), rf.get_iter());
+    assert_eq!(report.get_model_evaluations(), rf.get_model_evaluations());
+    assert_eq!(
+        report.get_last_satisfied_criterion(),
+        rf.get_last_satisfied_criterion()
+    );
+    assert_eq!(
+        report.get_active_bound_variables(),
+        rf.get_active_bound_variables()
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_batch_same_as_sequential() {
+    let constants: Vec<f64> = (0..200)
+        .map(|i| if i % 7 == 3 { -1.0 } else { 2.0 + i as f64 })
+        .collect();
+    let initial_guesses = guesses(constants.len());
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    for resolution_method in [ResolutionMethod::NewtonRaphson, broyden()] {
+        let mut models: Vec<_> = constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+        let mut expected_models: Vec<_> =
+            constants.iter().map(|&c| ShiftedSquare::new(c)).collect();
+
+        let results: Vec<_> = nrf::solver::solve_batch_parallel(
+            parameters(resolution_method),
+            &iter_params,
+            &res_config,
+            &initial_guesses,
+            &mut models,
+        )
+        .into_iter()
+        .map(|result| result.map_err(|error| error.to_string()))
+        .collect();
+        let expected: Vec<_> = nrf::solver::solve_batch(
+            parameters(resolution_method),
+            &iter_params,
+            &res_config,
+            &initial_guesses,
+            &mut expected_models,
+        )
+        .into_iter()
+        .map(|result| result.map_err(|error| error.to_string()))
+        .collect();
+
+        assert_eq!(results, expected);
+        assert_same_iteratives(&models, &expected_models);
+    }
+}
@@ -0,0 +1,270 @@
+use newton_rootfinder as nrf;
+use nrf::errors::{SolverError, SolverErrorKind};
+use nrf::iteratives::{IterativeParams, IterativeParamsFD, Iteratives};
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{BoundPolicy, Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+/// Linear problem of roots `(3.0001, 1.0)`
+///
+/// The first root is outside of the bounds `[0, 3]`,
+/// but the residual on the bound is below the tolerance
+fn root_near_bound(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 3.0001, x[1] - 1.0])
+}
+
+/// Linear problem of roots `(5.0, 1.0)`, the first root being far outside of the bounds `[0, 3]`
+fn root_outside_bounds(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 5.0, x[1] - 1.0])
+}
+
+/// Linear problem of roots `(2.0, 1.0)`, within the bounds `[0, 3]`
+fn root_within_bounds(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 2.0, x[1] - 1.0])
+}
+
+fn solve(
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    reject_solution_on_bounds: bool,
+) -> (
+    Result<(), nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>>,
+    Vec<usize>,
+    bool,
+) {
+    let problem_size = 2;
+    let iterative = IterativeParamsFD::extend(
+        IterativeParams::new(f64::INFINITY, f64::INFINITY, 0.0, 3.0),
+        5e-8,
+        5e-8,
+        nrf::iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative; problem_size];
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-3,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_reject_solution_on_bounds(reject_solution_on_bounds);
+    let init = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+    let result = rf.solve(&mut user_model);
+
+    (
+        result,
+        rf.get_active_bound_variables().to_vec(),
+        rf.is_converged_on_boundary(),
+    )
+}
+
+#[test]
+fn converged_on_boundary() {
+    let (result, active_bound_variables, converged_on_boundary) = solve(root_near_bound, false);
+
+    assert!(result.is_ok());
+    assert_eq!(active_bound_variables, vec![0]);
+    assert!(converged_on_boundary);
+}
+
+#[test]
+fn converged_on_boundary_rejected() {
+    let (result, active_bound_variables, converged_on_boundary) = solve(root_near_bound, true);
+
+    assert!(matches!(
+        result,
+        Err(nrf::errors::SolverError::ConvergedOnBoundsError)
+    ));
+    assert_eq!(active_bound_variables, vec![0]);
+    assert!(converged_on_boundary);
+}
+
+#[test]
+fn not_converged_on_boundary() {
+    let (result, active_bound_variables, converged_on_boundary) = solve(root_outside_bounds, true);
+
+    assert!(matches!(
+        result,
+        Err(nrf::errors::SolverError::NonConvergenceError(_))
+    ));
+    assert_eq!(active_bound_variables, vec![0]);
+    assert!(!converged_on_boundary);
+}
+
+#[test]
+fn converged_within_bounds() {
+    let (result, active_bound_variables, converged_on_boundary) = solve(root_within_bounds, true);
+
+    assert!(result.is_ok());
+    assert!(active_bound_variables.is_empty());
+    assert!(!converged_on_boundary);
+}
+
+fn bounded_root_finder<'a>(
+    iter_params: &'a Iteratives<'a, IterativeParamsFD>,
+    res_config: &'a residuals::ResidualsConfig<'a>,
+    init: Vec<f64>,
+    bound_policy: BoundPolicy,
+) -> nrf::solver::RootFinder<'a, IterativeParamsFD, nalgebra::Dyn> {
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_bound_policy(bound_policy);
+    nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(init),
+        iter_params,
+        res_config,
+    )
+}
+
+fn bounded_iteratives() -> Vec<IterativeParamsFD> {
+    vec![
+        IterativeParamsFD::extend(
+            IterativeParams::new(1.0, f64::INFINITY, 0.0, 3.0),
+            5e-8,
+            5e-8,
+            nrf::iteratives::PerturbationMethod::Max,
+        );
+        2
+    ]
+}
+
+#[test]
+fn initial_guess_projected_onto_bounds() {
+    let vec_iter_params = bounded_iteratives();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let mut rf = bounded_root_finder(
+        &iter_params,
+        &res_config,
+        vec![-1.0, 7.0],
+        BoundPolicy::default(),
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, root_within_bounds);
+
+    rf.solve(&mut user_model).unwrap();
+
+    // the model is first evaluated at (0, 3) instead of (-1, 7)
+    assert_eq!(
+        rf.get_initial_errors().unwrap(),
+        &nalgebra::DVector::from_vec(vec![2.0, 2.0])
+    );
+    assert_eq!(
+        rf.get_initial_guess(),
+        &nalgebra::DVector::from_vec(vec![0.0, 3.0])
+    );
+    let solution = user_model.get_iteratives();
+    assert!(float_cmp::approx_eq!(f64, solution[0], 2.0, epsilon = 1e-6));
+    assert!(float_cmp::approx_eq!(f64, solution[1], 1.0, epsilon = 1e-6));
+}
+
+#[test]
+fn initial_guess_out_of_bounds_after_user_modification() {
+    let vec_iter_params = bounded_iteratives();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let mut rf = bounded_root_finder(
+        &iter_params,
+        &res_config,
+        vec![1.0, 1.0],
+        BoundPolicy::Clamp,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, root_within_bounds);
+    rf.solve(&mut user_model).unwrap();
+
+    // new operating point, the first iterative being above its max value
+    let mut operating_point = user_model.get_iteratives();
+    operating_point[0] = 4.5;
+    rf.set_initial_guess(&operating_point);
+    rf.solve(&mut user_model).unwrap();
+
+    // the model is first evaluated at (3, 1) instead of (4.5, 1)
+    assert_eq!(rf.get_initial_errors().unwrap()[0], 1.0);
+    let solution = user_model.get_iteratives();
+    assert!(float_cmp::approx_eq!(f64, solution[0], 2.0, epsilon = 1e-6));
+    assert!(float_cmp::approx_eq!(f64, solution[1], 1.0, epsilon = 1e-6));
+}
+
+#[test]
+fn initial_guess_out_of_bounds_rejected() {
+    let vec_iter_params = bounded_iteratives();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let mut rf = bounded_root_finder(&iter_params, &res_config, vec![1.0, 7.0], BoundPolicy::Fail);
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, root_within_bounds);
+
+    let error = rf.solve(&mut user_model).unwrap_err();
+
+    assert!(matches!(
+        error,
+        SolverError::InitialGuessOutOfBounds {
+            iterative: 1,
+            value,
+            min_value,
+            max_value,
+        } if value == 7.0 && min_value == 0.0 && max_value == 3.0
+    ));
+    assert_eq!(error.kind(), SolverErrorKind::InitialGuessOutOfBounds(1));
+    assert_eq!(
+        error.to_string(),
+        "Initial guess of the iterative 1 out of its bounds: 7 is outside of [0, 3]"
+    );
+    assert_eq!(rf.get_model_evaluations(), 0);
+}
+
+#[test]
+fn initial_guess_within_bounds_accepted() {
+    let vec_iter_params = bounded_iteratives();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let mut rf = bounded_root_finder(&iter_params, &res_config, vec![0.0, 3.0], BoundPolicy::Fail);
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, root_within_bounds);
+
+    rf.solve(&mut user_model).unwrap();
+    assert_eq!(
+        rf.get_initial_errors().unwrap(),
+        &nalgebra::DVector::from_vec(vec![2.0, 2.0])
+    );
+}
+
+#[test]
+fn initial_guess_out_of_bounds_ignored() {
+    let vec_iter_params = bounded_iteratives();
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let residuals_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&residuals_methods, &residuals_methods);
+    let mut rf = bounded_root_finder(
+        &iter_params,
+        &res_config,
+        vec![-1.0, 7.0],
+        BoundPolicy::Ignore,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, root_within_bounds);
+
+    rf.solve(&mut user_model).unwrap();
+
+    // the model is first evaluated at (-1, 7)
+    assert_eq!(
+        rf.get_initial_errors().unwrap(),
+        &nalgebra::DVector::from_vec(vec![3.0, 6.0])
+    );
+    let solution = user_model.get_iteratives();
+    assert!(float_cmp::approx_eq!(f64, solution[0], 2.0, epsilon = 1e-6));
+    assert!(float_cmp::approx_eq!(f64, solution[1], 1.0, epsilon = 1e-6));
+}
@@ -1,14 +1,14 @@
 use newton_rootfinder as nrf;
 use util::test_cases::broyden1965::*;
 
-use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
 
 use crate::common::{run_function_case_fd, run_function_case_jac};
 
 #[test]
 fn broyden_case5_fd() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case5,
@@ -24,7 +24,7 @@ fn broyden_case5_fd() {
 #[test]
 fn broyden_case5_jac() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case5,
@@ -41,7 +41,7 @@ fn broyden_case5_jac() {
 #[test]
 fn broyden_case6_fd() {
     let problem_size = 5;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case6,
@@ -57,7 +57,7 @@ fn broyden_case6_fd() {
 #[test]
 fn broyden_case6_jac() {
     let problem_size = 5;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case6,
@@ -74,7 +74,7 @@ fn broyden_case6_jac() {
 #[test]
 fn broyden_case7_fd() {
     let problem_size = 10;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case7,
@@ -90,7 +90,7 @@ fn broyden_case7_fd() {
 #[test]
 fn broyden_case7_jac() {
     let problem_size = 10;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case7,
@@ -107,7 +107,7 @@ fn broyden_case7_jac() {
 #[test]
 fn broyden_case8_fd() {
     let problem_size = 20;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case8,
@@ -123,7 +123,7 @@ fn broyden_case8_fd() {
 #[test]
 fn broyden_case8_jac() {
     let problem_size = 20;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case8,
@@ -140,7 +140,7 @@ fn broyden_case8_jac() {
 #[test]
 fn broyden_case9_fd() {
     let problem_size = 2;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case9,
@@ -156,7 +156,7 @@ fn broyden_case9_fd() {
 #[test]
 fn broyden_case9_jac() {
     let problem_size = 2;
-    let damping = true;
+    let damping = Damping::Enabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case9,
@@ -0,0 +1,220 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, ExhaustedBudget, MaxIterations, ProblemSize, ResolutionMethod};
+
+/// Model counting its evaluations
+///
+/// The residuals are the cubic roots of the iteratives:
+/// the Newton-Raphson step `x -> -2x` always increases the error,
+/// hence the damping is performed at each iteration if activated
+struct CountingModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+    evaluations: usize,
+    jacobian_provided: bool,
+}
+
+impl CountingModel {
+    fn new(problem_size: usize, jacobian_provided: bool) -> Self {
+        CountingModel {
+            iteratives: nalgebra::DVector::zeros(problem_size),
+            residuals: nalgebra::DVector::zeros(problem_size),
+            evaluations: 0,
+            jacobian_provided,
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for CountingModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives = iteratives.clone();
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.residuals.clone(),
+            nalgebra::DVector::zeros(self.len_problem()),
+        )
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        self.residuals = self.iteratives.map(f64::cbrt);
+        Ok(())
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.jacobian_provided
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, nrf::model::ModelError<Self, nalgebra::Dyn>>
+    {
+        let n = self.len_problem();
+        let derivatives = self.iteratives.map(|x| 1.0 / (3.0 * x.cbrt().powi(2)));
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::from_diagonal(&derivatives),
+            nalgebra::DMatrix::zeros(n, n),
+        ))
+    }
+}
+
+fn solve_counting_model(
+    max_iter: usize,
+    max_model_evaluations: Option<usize>,
+    damping: Damping,
+    jacobian_provided: bool,
+) -> (usize, usize, Option<ExhaustedBudget>) {
+    let problem_size = 2;
+    let init = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut solver_parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(max_iter),
+        ResolutionMethod::NewtonRaphson,
+        damping,
+    );
+    solver_parameters.set_max_model_evaluations(max_model_evaluations);
+    let mut rf = nrf::solver::RootFinder::new(solver_parameters, init, &iter_params, &res_config);
+
+    let mut model = CountingModel::new(problem_size, jacobian_provided);
+    let result = rf.solve(&mut model).unwrap_err();
+    let expected: nrf::errors::SolverError<CountingModel, nalgebra::Dyn> =
+        nrf::errors::SolverError::NonConvergenceError(nrf::errors::UnconvergedResiduals::new(
+            rf.get_unconverged_residuals(),
+            Vec::new(),
+        ));
+    assert_eq!(expected.to_string(), result.to_string());
+
+    assert_eq!(rf.get_model_evaluations(), model.evaluations);
+    (rf.get_iter(), model.evaluations, rf.get_exhausted_budget())
+}
+
+#[test]
+fn max_iter_zero_only_evaluates_initial_guess() {
+    for damping in [Damping::Disabled, Damping::Enabled] {
+        for jacobian_provided in [false, true] {
+            assert_eq!(
+                solve_counting_model(0, None, damping, jacobian_provided),
+                (0, 1, Some(ExhaustedBudget::Iterations))
+            );
+        }
+    }
+}
+
+#[test]
+fn max_iter_evaluations_analytical_jacobian() {
+    for max_iter in [1, 2, 5] {
+        // initial evaluation + one evaluation per iteration
+        assert_eq!(
+            solve_counting_model(max_iter, None, Damping::Disabled, true),
+            (max_iter, 1 + max_iter, Some(ExhaustedBudget::Iterations))
+        );
+        // with one more evaluation for the damping
+        assert_eq!(
+            solve_counting_model(max_iter, None, Damping::Enabled, true),
+            (
+                max_iter,
+                1 + 2 * max_iter,
+                Some(ExhaustedBudget::Iterations)
+            )
+        );
+    }
+}
+
+#[test]
+fn max_iter_evaluations_finite_difference_jacobian() {
+    for max_iter in [1, 2, 5] {
+        // initial evaluation + one evaluation per iteration and per column of the jacobian
+        assert_eq!(
+            solve_counting_model(max_iter, None, Damping::Disabled, false),
+            (
+                max_iter,
+                1 + 3 * max_iter,
+                Some(ExhaustedBudget::Iterations)
+            )
+        );
+        // with one more evaluation for the damping
+        assert_eq!(
+            solve_counting_model(max_iter, None, Damping::Enabled, false),
+            (
+                max_iter,
+                1 + 4 * max_iter,
+                Some(ExhaustedBudget::Iterations)
+            )
+        );
+    }
+}
+
+#[test]
+fn max_model_evaluations_reached() {
+    // 1, 4, 7 evaluations at the beginning of the iterations
+    assert_eq!(
+        solve_counting_model(50, Some(7), Damping::Disabled, false),
+        (2, 7, Some(ExhaustedBudget::ModelEvaluations))
+    );
+    // An iteration is not interrupted: the budget is exceeded by the last iteration
+    assert_eq!(
+        solve_counting_model(50, Some(8), Damping::Disabled, false),
+        (3, 10, Some(ExhaustedBudget::ModelEvaluations))
+    );
+    assert_eq!(
+        solve_counting_model(50, Some(5), Damping::Enabled, true),
+        (2, 5, Some(ExhaustedBudget::ModelEvaluations))
+    );
+    // The maximum number of iterations is reached first
+    assert_eq!(
+        solve_counting_model(2, Some(100), Damping::Disabled, false),
+        (2, 7, Some(ExhaustedBudget::Iterations))
+    );
+}
+
+#[test]
+fn no_exhausted_budget_on_convergence() {
+    let problem_size = 1;
+    let init = nalgebra::DVector::from_vec(vec![1.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut solver_parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    solver_parameters.set_max_model_evaluations(Some(100));
+    let mut rf = nrf::solver::RootFinder::new(solver_parameters, init, &iter_params, &res_config);
+    let mut user_model =
+        nrf::model::UserModelFromFunction::new(problem_size, util::test_cases::polynom::square2);
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert_eq!(rf.get_exhausted_budget(), None);
+    assert_eq!(rf.get_model_evaluations(), 1 + 2 * rf.get_iter());
+}
@@ -0,0 +1,287 @@
+use newton_rootfinder as nrf;
+
+use nrf::solver::{
+    check_compatibility, CompatibilityWarning, Damping, FiniteDifferenceUse, IterativesKind,
+    LinearSolver, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    SolverParameters, SparsityPattern, UpdateQuasiNewtonMethod,
+};
+
+const GAUSS_SEIDEL: ResolutionMethod = ResolutionMethod::NonlinearGaussSeidel {
+    inner_scalar_iterations: 3,
+};
+
+const GMRES: LinearSolver = LinearSolver::Gmres {
+    restart: 10,
+    max_iter: 100,
+    tol: 1e-10,
+};
+
+fn all_methods() -> Vec<ResolutionMethod> {
+    let updates = [
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        UpdateQuasiNewtonMethod::BroydenSecondMethod,
+        UpdateQuasiNewtonMethod::GreenstadtFirstMethod,
+        UpdateQuasiNewtonMethod::GreenstadtSecondMethod,
+    ];
+    let mut methods = vec![
+        ResolutionMethod::NewtonRaphson,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        GAUSS_SEIDEL,
+    ];
+    for update in updates {
+        methods.push(ResolutionMethod::QuasiNewton(
+            QuasiNewtonMethod::JacobianUpdate(update),
+        ));
+        methods.push(ResolutionMethod::QuasiNewton(
+            QuasiNewtonMethod::InverseJacobianUpdate(update),
+        ));
+    }
+    methods
+}
+
+fn parameters(resolution_method: ResolutionMethod) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    )
+}
+
+#[test]
+fn requirements_of_the_methods() {
+    for method in all_methods() {
+        let requirements = method.requirements();
+        let inverse_update = matches!(
+            method,
+            ResolutionMethod::QuasiNewton(
+                QuasiNewtonMethod::InverseJacobianUpdate(_)
+                    | QuasiNewtonMethod::JacobianUpdate(
+                        UpdateQuasiNewtonMethod::GreenstadtSecondMethod
+                    )
+            )
+        );
+        let gauss_seidel = method == GAUSS_SEIDEL;
+
+        assert_eq!(requirements.needs_inverse_storage(), inverse_update);
+        assert_eq!(requirements.needs_jacobian_storage(), !gauss_seidel);
+        assert_eq!(requirements.uses_linear_solver(), !gauss_seidel);
+        assert!(requirements.supports_linear_solver(LinearSolver::DirectInverse));
+        assert_eq!(requirements.supports_linear_solver(GMRES), !inverse_update);
+        assert_eq!(
+            requirements.supports_linear_solver(GMRES),
+            GMRES.is_compatible_with(method)
+        );
+        assert!(requirements.benefits_from_fd_params(false));
+        assert_eq!(requirements.benefits_from_fd_params(true), gauss_seidel);
+        if gauss_seidel {
+            assert_eq!(
+                requirements.get_finite_differences(),
+                FiniteDifferenceUse::Always
+            );
+        }
+    }
+}
+
+#[test]
+fn compatible_configurations() {
+    for method in all_methods() {
+        assert_eq!(
+            check_compatibility(
+                &parameters(method),
+                false,
+                IterativesKind::WithFiniteDifference
+            ),
+            Ok(())
+        );
+        if method != GAUSS_SEIDEL {
+            assert_eq!(
+                check_compatibility(
+                    &parameters(method),
+                    true,
+                    IterativesKind::WithoutFiniteDifference
+                ),
+                Ok(())
+            );
+        }
+    }
+}
+
+#[test]
+fn no_jacobian_computation() {
+    let warnings = check_compatibility(
+        &parameters(ResolutionMethod::NewtonRaphson),
+        false,
+        IterativesKind::WithoutFiniteDifference,
+    )
+    .unwrap_err();
+    assert_eq!(warnings, [CompatibilityWarning::NoJacobianComputation]);
+    assert!(warnings[0].prevents_resolution());
+}
+
+#[test]
+fn gauss_seidel_without_finite_difference() {
+    let warnings = check_compatibility(
+        &parameters(GAUSS_SEIDEL),
+        true,
+        IterativesKind::WithoutFiniteDifference,
+    )
+    .unwrap_err();
+    assert_eq!(
+        warnings,
+        [CompatibilityWarning::GaussSeidelWithoutFiniteDifference]
+    );
+    assert!(warnings[0].prevents_resolution());
+
+    let warnings = check_compatibility(
+        &parameters(GAUSS_SEIDEL),
+        false,
+        IterativesKind::WithoutFiniteDifference,
+    )
+    .unwrap_err();
+    assert_eq!(
+        warnings,
+        [
+            CompatibilityWarning::NoJacobianComputation,
+            CompatibilityWarning::GaussSeidelWithoutFiniteDifference
+        ]
+    );
+}
+
+#[test]
+fn unused_finite_difference_parameters() {
+    let warnings = check_compatibility(
+        &parameters(ResolutionMethod::NewtonRaphson),
+        true,
+        IterativesKind::WithFiniteDifference,
+    )
+    .unwrap_err();
+    assert_eq!(
+        warnings,
+        [CompatibilityWarning::UnusedFiniteDifferenceParameters]
+    );
+    assert!(!warnings[0].prevents_resolution());
+
+    // the nonlinear Gauss-Seidel method always uses them
+    assert_eq!(
+        check_compatibility(
+            &parameters(GAUSS_SEIDEL),
+            true,
+            IterativesKind::WithFiniteDifference
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn unused_newton_warmup() {
+    for method in [ResolutionMethod::NewtonRaphson, GAUSS_SEIDEL] {
+        let mut parameters = parameters(method);
+        parameters.set_newton_warmup_iterations(2);
+        let warnings =
+            check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference)
+                .unwrap_err();
+        assert_eq!(warnings, [CompatibilityWarning::UnusedNewtonWarmup(method)]);
+        assert!(!warnings[0].prevents_resolution());
+    }
+
+    let mut parameters = parameters(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::StationaryNewton,
+    ));
+    parameters.set_newton_warmup_iterations(2);
+    assert_eq!(
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+        Ok(())
+    );
+}
+
+#[test]
+fn unused_linear_solver() {
+    let mut parameters = parameters(GAUSS_SEIDEL);
+    parameters.set_linear_solver(GMRES);
+    let warnings =
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference).unwrap_err();
+    assert_eq!(warnings, [CompatibilityWarning::UnusedLinearSolver(GMRES)]);
+    assert_eq!(
+        warnings[0].to_string(),
+        "The resolution method does not solve linear systems, the linear solver GMRES (restart: 10, max iterations: 100, tolerance: 0.0000000001) is not used"
+    );
+}
+
+#[test]
+fn schubert_sparsity_pattern() {
+    let schubert = UpdateQuasiNewtonMethod::SchubertSparse;
+    let mut parameters = parameters(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::JacobianUpdate(schubert),
+    ));
+    let warnings =
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference).unwrap_err();
+    assert_eq!(
+        warnings,
+        [CompatibilityWarning::SchubertWithoutSparsityPattern]
+    );
+    assert!(warnings[0].prevents_resolution());
+
+    parameters.set_sparsity_pattern(Some(SparsityPattern::banded(2, 1, 1)));
+    assert_eq!(
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+        Ok(())
+    );
+
+    parameters.set_resolution_method(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::InverseJacobianUpdate(schubert),
+    ));
+    let warnings =
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference).unwrap_err();
+    assert_eq!(warnings, [CompatibilityWarning::InverseSchubertUpdate]);
+    assert!(warnings[0].prevents_resolution());
+
+    parameters.set_resolution_method(ResolutionMethod::NewtonRaphson);
+    let warnings =
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference).unwrap_err();
+    assert_eq!(
+        warnings,
+        [CompatibilityWarning::UnusedSparsityPattern(
+            ResolutionMethod::NewtonRaphson
+        )]
+    );
+    assert!(!warnings[0].prevents_resolution());
+}
+
+#[test]
+fn iteratives_kind() {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(2);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    assert_eq!(
+        IterativesKind::from(&iter_params),
+        IterativesKind::WithFiniteDifference
+    );
+
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives(2);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    assert_eq!(
+        IterativesKind::from(&iter_params),
+        IterativesKind::WithoutFiniteDifference
+    );
+}
+
+#[test]
+fn scalar_safeguard_of_larger_problem() {
+    let mut parameters = parameters(ResolutionMethod::NewtonRaphson);
+    parameters.set_scalar_safeguard(true);
+    let warnings =
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference).unwrap_err();
+    assert_eq!(warnings, [CompatibilityWarning::UnusedScalarSafeguard(2)]);
+    assert!(!warnings[0].prevents_resolution());
+    assert_eq!(
+        warnings[0].to_string(),
+        "The scalar safeguard only applies to the problems of size 1, it is ignored for the problem of size 2"
+    );
+
+    parameters.set_problem_size(ProblemSize::new(1));
+    assert_eq!(
+        check_compatibility(&parameters, false, IterativesKind::WithFiniteDifference),
+        Ok(())
+    );
+}
@@ -0,0 +1,173 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+// The residuals are linear with respect to the first iterative:
+// the first column of the jacobian is constant, equal to (2, 1).
+// The root is (1, 1).
+fn half_linear(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![
+        2.0 * x[0] + x[1] * x[1] - 3.0,
+        x[0] + x[1].powi(3) - 2.0,
+    ])
+}
+
+/// Model counting its evaluations
+struct CountingModel {
+    model: nrf::model::UserModelFromFunction,
+    evaluations: usize,
+}
+
+impl CountingModel {
+    fn new() -> Self {
+        CountingModel {
+            model: nrf::model::UserModelFromFunction::new(2, half_linear),
+            evaluations: 0,
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for CountingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.model.len_problem()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.model.set_iteratives(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.model.get_iteratives()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        self.model.evaluate().unwrap();
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        self.model.get_residuals()
+    }
+}
+
+/// Outcome of a resolution: the final iteratives, the iterations, the model evaluations and the saved ones
+type Outcome = (nalgebra::DVector<f64>, usize, usize, usize);
+
+fn solve(constant_columns: &[usize]) -> Outcome {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![2.0, 2.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.declare_constant_jacobian_columns(constant_columns);
+    let mut model = CountingModel::new();
+    rf.solve(&mut model).unwrap();
+
+    assert_eq!(rf.get_model_evaluations(), model.evaluations);
+    (
+        model.get_iteratives(),
+        rf.get_iter(),
+        model.evaluations,
+        rf.get_saved_model_evaluations(),
+    )
+}
+
+#[test]
+fn constant_column_reused() {
+    let (reference_iteratives, reference_iter, reference_evaluations, reference_saved) = solve(&[]);
+    let (iteratives, iter, evaluations, saved) = solve(&[0]);
+
+    assert_eq!(reference_saved, 0);
+    assert_eq!(iter, reference_iter);
+    // the column is evaluated with the first jacobian only
+    assert_eq!(saved, iter - 1);
+    assert_eq!(evaluations + saved, reference_evaluations);
+    for i in 0..2 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            reference_iteratives[i],
+            epsilon = 1e-9
+        ));
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            1.0,
+            epsilon = 1e-9
+        ));
+    }
+}
+
+#[test]
+fn constant_columns_evaluated_again_for_each_resolution() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![2.0, 2.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.declare_constant_jacobian_columns(&[0, 0]);
+    assert_eq!(rf.get_constant_jacobian_columns(), [0]);
+
+    rf.solve(&mut CountingModel::new()).unwrap();
+    let saved = rf.get_saved_model_evaluations();
+    rf.solve(&mut CountingModel::new()).unwrap();
+
+    assert_eq!(rf.get_saved_model_evaluations(), saved);
+    assert_eq!(saved, rf.get_iter() - 1);
+}
+
+#[test]
+#[should_panic(expected = "The constant jacobian column 2 is out of range, the problem size is 2")]
+fn constant_column_out_of_range() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![2.0, 2.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.declare_constant_jacobian_columns(&[0, 2]);
+}
@@ -0,0 +1,422 @@
+use newton_rootfinder as nrf;
+use nrf::errors::RootFinderConstructionError;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    CompatibilityWarning, Damping, MaxIterations, ProblemSize, ResolutionMethod, RootFinder,
+    SolverParameters,
+};
+
+fn parameters(problem_size: usize) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    )
+}
+
+/// Error returned by `RootFinder::try_new` with inputs of the given sizes, for a problem of size 2
+fn construction_error(
+    initial_guess_size: usize,
+    iteratives_size: usize,
+    residuals_size: usize,
+) -> Option<RootFinderConstructionError> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(iteratives_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; residuals_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; residuals_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    RootFinder::try_new(
+        parameters(2),
+        nalgebra::DVector::zeros(initial_guess_size),
+        &iter_params,
+        &res_config,
+    )
+    .err()
+}
+
+#[test]
+fn try_new_matching_sizes() {
+    assert_eq!(construction_error(2, 2, 2), None);
+}
+
+#[test]
+fn try_new_residuals_config_size_mismatch() {
+    assert_eq!(
+        construction_error(2, 2, 3),
+        Some(RootFinderConstructionError::ResidualsConfigSizeMismatch {
+            residuals_config: 3,
+            problem_size: 2
+        })
+    );
+}
+
+#[test]
+fn try_new_initial_guess_size_mismatch() {
+    assert_eq!(
+        construction_error(1, 2, 2),
+        Some(RootFinderConstructionError::InitialGuessSizeMismatch {
+            initial_guess: 1,
+            problem_size: 2
+        })
+    );
+}
+
+#[test]
+fn try_new_iteratives_size_mismatch() {
+    assert_eq!(
+        construction_error(2, 4, 2),
+        Some(RootFinderConstructionError::IterativesSizeMismatch {
+            iteratives: 4,
+            problem_size: 2
+        })
+    );
+}
+
+#[test]
+fn try_default_with_guess_size_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives(3);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let error = nrf::solver::try_default_with_guess(
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    )
+    .err();
+    assert_eq!(
+        error,
+        Some(RootFinderConstructionError::IterativesSizeMismatch {
+            iteratives: 3,
+            problem_size: 2
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n residuals_config.len() = 3 and problem_size = 2")]
+fn new_residuals_config_size_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    RootFinder::new(
+        parameters(2),
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n initial_guess.len() = 1 and problem_size = 2")]
+fn new_initial_guess_size_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    RootFinder::new(
+        parameters(2),
+        nalgebra::DVector::zeros(1),
+        &iter_params,
+        &res_config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n iters_params.len() = 4 and problem_size = 2")]
+fn new_iteratives_size_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(4);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    RootFinder::new(
+        parameters(2),
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch :\n residuals_config.len() = 1 and problem_size = 2")]
+fn default_with_guess_size_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    nrf::solver::default_with_guess(
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+}
+
+#[test]
+fn try_default_with_guess_empty_problem() {
+    let vec_iter_params = iteratives::default_vec_iteratives(0);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 0];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 0];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let error = nrf::solver::try_default_with_guess(
+        nalgebra::DVector::zeros(0),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    )
+    .err();
+    assert_eq!(error, Some(RootFinderConstructionError::EmptyProblem));
+}
+
+#[test]
+fn incompatible_configuration() {
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut gauss_seidel = parameters(2);
+    gauss_seidel.set_resolution_method(ResolutionMethod::NonlinearGaussSeidel {
+        inner_scalar_iterations: 3,
+    });
+
+    // the nonlinear Gauss-Seidel method requires the finite differences, whatever the model
+    let vec_iter_params = iteratives::default_vec_iteratives(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let error = RootFinder::try_new(
+        gauss_seidel.clone(),
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    )
+    .err();
+    assert_eq!(
+        error,
+        Some(RootFinderConstructionError::IncompatibleConfiguration(
+            CompatibilityWarning::GaussSeidelWithoutFiniteDifference
+        ))
+    );
+
+    // without finite differences, the jacobian depends on the model: it is only checked by solve()
+    let newton_raphson = parameters(2);
+    assert!(RootFinder::try_new(
+        newton_raphson,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    )
+    .is_ok());
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    assert!(RootFinder::try_new(
+        gauss_seidel,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    )
+    .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "The problem size must be strictly positive, got 0")]
+fn parameters_empty_problem() {
+    parameters(0);
+}
+
+#[test]
+#[should_panic(expected = "The problem size must be strictly positive, got 0")]
+fn problem_size_zero() {
+    ProblemSize::new(0);
+}
+
+#[test]
+fn sizes_from_integers() {
+    let problem_size = ProblemSize::new(3);
+    let max_iter = MaxIterations::new(60);
+    assert_eq!(problem_size.get(), 3);
+    assert_eq!(max_iter.get(), 60);
+    assert_eq!(format!("{} {}", problem_size, max_iter), "3 60");
+}
+
+#[test]
+#[allow(deprecated)]
+fn parameters_from_usizes() {
+    let parameters =
+        SolverParameters::from_usizes(3, 1e-6, 60, ResolutionMethod::NewtonRaphson, false);
+    assert_eq!(parameters.get_problem_size(), Some(3));
+    assert_eq!(parameters.get_max_iter(), 60);
+    assert_eq!(
+        format!("{:?}", parameters),
+        format!(
+            "{:?}",
+            SolverParameters::new(
+                ProblemSize::new(3),
+                1e-6,
+                MaxIterations::new(60),
+                ResolutionMethod::NewtonRaphson,
+                Damping::Disabled,
+            )
+        )
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn parameters_from_bool_damping() {
+    for (damping, expected) in [(false, Damping::Disabled), (true, Damping::Enabled)] {
+        assert_eq!(Damping::from(damping), expected);
+        assert_eq!(expected.is_enabled(), damping);
+        let parameters = SolverParameters::from_bool_damping(
+            ProblemSize::new(3),
+            1e-6,
+            MaxIterations::new(60),
+            ResolutionMethod::NewtonRaphson,
+            damping,
+        );
+        assert_eq!(parameters.get_damping(), expected);
+        assert_eq!(
+            format!("{:?}", parameters),
+            format!(
+                "{:?}",
+                SolverParameters::new(
+                    ProblemSize::new(3),
+                    1e-6,
+                    MaxIterations::new(60),
+                    ResolutionMethod::NewtonRaphson,
+                    expected,
+                )
+            )
+        );
+    }
+    assert_eq!(Damping::default(), Damping::Disabled);
+}
+
+fn inferred_parameters() -> SolverParameters {
+    SolverParameters::with_inferred_size(
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    )
+}
+
+#[test]
+fn problem_size_inferred_from_initial_guess() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(3);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let parameters = inferred_parameters();
+    assert_eq!(parameters.get_problem_size(), None);
+    assert!(parameters.to_string().contains("| inferred"));
+
+    let rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(3),
+        &iter_params,
+        &res_config,
+    );
+    assert_eq!(rf.get_parameters().get_problem_size(), Some(3));
+}
+
+#[test]
+fn problem_size_inferred_from_iteratives() {
+    let vec_iter_params = iteratives::default_vec_iteratives(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let rf = RootFinder::<_, nalgebra::Dyn>::new_with_default_guess(
+        inferred_parameters(),
+        &iter_params,
+        &res_config,
+    );
+    assert_eq!(rf.get_parameters().get_problem_size(), Some(2));
+}
+
+#[test]
+fn problem_size_inferred_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let error = RootFinder::try_new(
+        inferred_parameters(),
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    )
+    .err();
+    assert_eq!(
+        error,
+        Some(RootFinderConstructionError::ResidualsConfigSizeMismatch {
+            residuals_config: 3,
+            problem_size: 2
+        })
+    );
+}
+
+#[test]
+fn problem_size_inferred_empty_problem() {
+    let vec_iter_params = iteratives::default_vec_iteratives(0);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 0];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 0];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let error = RootFinder::try_new(
+        inferred_parameters(),
+        nalgebra::DVector::zeros(0),
+        &iter_params,
+        &res_config,
+    )
+    .err();
+    assert_eq!(error, Some(RootFinderConstructionError::EmptyProblem));
+}
+
+// The provided problem size is a cross-check of the inferred one
+#[test]
+fn problem_size_provided_mismatch() {
+    assert_eq!(
+        construction_error(3, 3, 3),
+        Some(RootFinderConstructionError::ResidualsConfigSizeMismatch {
+            residuals_config: 3,
+            problem_size: 2
+        })
+    );
+}
+
+// The memory limit is checked once the problem size is inferred
+#[test]
+fn problem_size_not_inferred_yet() {
+    let mut parameters = inferred_parameters();
+    parameters.set_memory_limit_bytes(Some(0));
+
+    assert_eq!(parameters.get_problem_size(), None);
+    assert_eq!(parameters.check_memory_limit(), Ok(()));
+}
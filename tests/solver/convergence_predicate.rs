@@ -0,0 +1,183 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals::{self, NormalizationMethod};
+use nrf::solver::{
+    ConvergenceContext, ConvergenceCriterion, Damping, ExhaustedBudget, MaxIterations,
+    PredicateMode, ProblemSize, ResolutionMethod,
+};
+
+use std::cell::RefCell;
+
+const TOLERANCE: f64 = 1e-6;
+const MAX_ITER: usize = 30;
+
+fn square_root_of_two(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|value| value * value - 2.0)
+}
+
+/// Resolution of `x^2 = 2` from `1.0`, the solver being configured by `configure` before the resolution
+///
+/// Return the solver, the solution and the result of the resolution
+fn solve<'a>(
+    iter_params: &'a iteratives::Iteratives<'a, iteratives::IterativeParamsFD>,
+    res_config: &'a residuals::ResidualsConfig<'a>,
+    configure: impl FnOnce(
+        &mut nrf::solver::RootFinder<'a, iteratives::IterativeParamsFD, nalgebra::Dyn>,
+    ),
+) -> (
+    nrf::solver::RootFinder<'a, iteratives::IterativeParamsFD, nalgebra::Dyn>,
+    f64,
+    Result<(), String>,
+) {
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        TOLERANCE,
+        MaxIterations::new(MAX_ITER),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_element(1, 1.0),
+        iter_params,
+        res_config,
+    );
+    configure(&mut rf);
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, square_root_of_two);
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+    (rf, user_model.get_iteratives()[0], result)
+}
+
+fn strict_predicate(context: &ConvergenceContext<nalgebra::Dyn>) -> bool {
+    context.get_stopping_errors().amax() < context.get_tolerance()
+        && context
+            .get_last_step()
+            .map_or(false, |step| step.amax() < 1e-9)
+}
+
+#[test]
+fn strict_predicate_converges_further() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+
+    let (rf_default, solution_default, result) = solve(&iter_params, &res_config, |_| ());
+    assert!(result.is_ok());
+    assert_eq!(
+        rf_default.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::Residuals)
+    );
+
+    let (rf_strict, solution_strict, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(strict_predicate))
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        rf_strict.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::CustomPredicate)
+    );
+    assert!(rf_strict.get_iter() > rf_default.get_iter());
+    let exact = 2f64.sqrt();
+    assert!((solution_strict - exact).abs() < (solution_default - exact).abs());
+    assert!((solution_strict - exact).abs() < 1e-12);
+}
+
+#[test]
+fn always_false_predicate_exhausts_iterations() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|_| false))
+    });
+    assert!(result.is_err());
+    assert_eq!(rf.get_iter(), MAX_ITER);
+    assert_eq!(rf.get_exhausted_budget(), Some(ExhaustedBudget::Iterations));
+    assert_eq!(rf.get_last_satisfied_criterion(), None);
+}
+
+#[test]
+fn predicate_modes() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+
+    let (rf_default, _, _) = solve(&iter_params, &res_config, |_| ());
+
+    // Combined with the tolerance check, a predicate always false prevents the convergence
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|_| false));
+        rf.set_convergence_predicate_mode(PredicateMode::And);
+    });
+    assert!(result.is_err());
+    assert_eq!(rf.get_exhausted_budget(), Some(ExhaustedBudget::Iterations));
+
+    // As an alternative to the tolerance check, it does not change the resolution
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|_| false));
+        rf.set_convergence_predicate_mode(PredicateMode::Or);
+    });
+    assert!(result.is_ok());
+    assert_eq!(rf.get_iter(), rf_default.get_iter());
+    assert_eq!(
+        rf.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::Residuals)
+    );
+
+    // A predicate always true converges at the initial guess in place of the tolerance check
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|_| true));
+    });
+    assert!(result.is_ok());
+    assert_eq!(rf.get_iter(), 0);
+    assert_eq!(
+        rf.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::CustomPredicate)
+    );
+
+    // Removing the predicate restores the tolerance check
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|_| true));
+        rf.clear_convergence_predicate();
+    });
+    assert!(result.is_ok());
+    assert!(!rf.has_convergence_predicate());
+    assert_eq!(rf.get_iter(), rf_default.get_iter());
+}
+
+#[test]
+fn context_of_the_predicate() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+
+    let calls = RefCell::new(Vec::new());
+    let (rf, _, result) = solve(&iter_params, &res_config, |rf| {
+        rf.set_convergence_predicate(Box::new(|context| {
+            calls.borrow_mut().push((
+                context.get_iter(),
+                context.get_last_step().is_some(),
+                context.get_iteratives()[0],
+                context.get_model_evaluations(),
+            ));
+            context.get_iter() == 2
+        }))
+    });
+    assert!(result.is_ok());
+    assert_eq!(rf.get_iter(), 2);
+    drop(rf);
+
+    let calls = calls.into_inner();
+    assert_eq!(calls.len(), 3);
+    assert_eq!(calls[0], (0, false, 1.0, 1));
+    assert_eq!(calls[1].0, 1);
+    assert!(calls[1].1);
+    assert!((calls[1].2 - 1.5).abs() < 1e-6);
+    assert!(calls[2].3 > calls[1].3);
+}
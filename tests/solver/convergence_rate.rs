@@ -0,0 +1,127 @@
+use newton_rootfinder as nrf;
+use util::test_cases::polynom::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    ConvergenceRate, Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    SolverReport,
+};
+
+type Jacobian = fn(&nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64>;
+
+/// Jacobian of `square2` off by 50 %, the Newton-Raphson method converging linearly
+fn wrong_dsquare(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    dsquare(x) * 1.5
+}
+
+/// Report of the resolution of `square2` from 1, with the jacobian provided if any
+fn solve_square2(resolution_method: ResolutionMethod, jacobian: Option<Jacobian>) -> SolverReport {
+    let init = nalgebra::DVector::from_vec(vec![1.0]);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-9,
+        MaxIterations::new(100),
+        resolution_method,
+        Damping::Disabled,
+    );
+
+    match jacobian {
+        Some(jacobian) => {
+            let vec_iter_params = iteratives::default_vec_iteratives(1);
+            let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+            let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+            let mut user_model =
+                nrf::model::UserModelFromFunctionAndJacobian::new(1, square2, jacobian);
+            rf.solve_with_report(&mut user_model).unwrap()
+        }
+        None => {
+            let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+            let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+            let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+            let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+            rf.solve_with_report(&mut user_model).unwrap()
+        }
+    }
+}
+
+fn order(convergence_rate: Option<ConvergenceRate>) -> f64 {
+    convergence_rate.unwrap().get_order()
+}
+
+#[test]
+fn newton_raphson_quadratic() {
+    let report = solve_square2(ResolutionMethod::NewtonRaphson, Some(dsquare));
+
+    assert!(report.get_iter() >= 4);
+    let order = order(report.get_convergence_rate());
+    assert!((1.8..2.3).contains(&order), "order {}", order);
+    assert!(!report.get_suspicious_jacobian());
+}
+
+#[test]
+fn stationary_newton_linear() {
+    let report = solve_square2(
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Some(dsquare),
+    );
+
+    let convergence_rate = report.get_convergence_rate().unwrap();
+    assert!(
+        (0.8..1.3).contains(&convergence_rate.get_order()),
+        "order {}",
+        convergence_rate.get_order()
+    );
+    // |1 - sqrt(2)|, the derivative of the fixed point function at the root
+    assert!((convergence_rate.get_rate() - 0.414).abs() < 0.05);
+    // the linear convergence is expected from the method
+    assert!(!report.get_suspicious_jacobian());
+}
+
+#[test]
+fn wrong_analytical_jacobian() {
+    let report = solve_square2(ResolutionMethod::NewtonRaphson, Some(wrong_dsquare));
+
+    assert!(order(report.get_convergence_rate()) < 1.5);
+    assert!(report.get_suspicious_jacobian());
+}
+
+#[test]
+fn finite_difference_jacobian() {
+    let report = solve_square2(ResolutionMethod::NewtonRaphson, None);
+
+    assert!(order(report.get_convergence_rate()) > 1.5);
+    assert!(!report.get_suspicious_jacobian());
+}
+
+#[test]
+fn too_few_iterations() {
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.4]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert!(rf.get_iter() < nrf::solver::MIN_ITERATIONS_CONVERGENCE_RATE);
+    assert_eq!(rf.get_convergence_rate(), None);
+    assert!(!rf.get_suspicious_jacobian());
+}
@@ -0,0 +1,156 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    CycleBreakingAction, Damping, ExhaustedBudget, MaxIterations, ProblemSize, ResolutionMethod,
+    SolverParameters,
+};
+
+// From x = 1.5, the Newton step -atan(x) * (1 + x^2) is about -3.2:
+// limited to a step of 3, the iterates bounce between 1.5 and -1.5 with the same error,
+// up to the slow drift due to the finite differences.
+fn arctangent(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(f64::atan)
+}
+
+/// Outcome of a resolution: the result, the final iteratives, the budget exhausted and the number of cycles detected
+type Outcome = (
+    Result<(), String>,
+    nalgebra::DVector<f64>,
+    Option<ExhaustedBudget>,
+    usize,
+);
+
+fn parameters(cycle_detection: Option<CycleBreakingAction>) -> SolverParameters {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_cycle_detection(cycle_detection);
+    parameters
+}
+
+fn solve_arctangent(parameters: SolverParameters) -> Outcome {
+    let iterative = iteratives::IterativeParamsFD::new(
+        3.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        5e-8,
+        5e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.5]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, arctangent);
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+    (
+        result,
+        user_model.get_iteratives(),
+        rf.get_exhausted_budget(),
+        rf.get_cycles_detected(),
+    )
+}
+
+#[test]
+fn cycle_without_detection() {
+    let (result, iteratives, exhausted_budget, cycles_detected) =
+        solve_arctangent(parameters(None));
+
+    assert!(result
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(exhausted_budget, Some(ExhaustedBudget::Iterations));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        iteratives[0].abs(),
+        1.5,
+        epsilon = 1e-4
+    ));
+    assert_eq!(cycles_detected, 0);
+}
+
+#[test]
+fn cycle_broken_with_damped_average() {
+    let (result, iteratives, exhausted_budget, cycles_detected) =
+        solve_arctangent(parameters(Some(CycleBreakingAction::default())));
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(exhausted_budget, None);
+    assert!(iteratives[0].abs() <= 1e-6);
+    assert_eq!(cycles_detected, 1);
+}
+
+#[test]
+fn cycle_broken_with_damped_half_step() {
+    let (result, iteratives, _, cycles_detected) =
+        solve_arctangent(parameters(Some(CycleBreakingAction::DampedHalfStep)));
+
+    assert_eq!(result, Ok(()));
+    assert!(iteratives[0].abs() <= 1e-6);
+    assert_eq!(cycles_detected, 1);
+}
+
+#[test]
+fn recompute_jacobian_with_newton_raphson() {
+    // the jacobian is already computed at each iteration: the cycle is not broken and detected again
+    let (result, _, exhausted_budget, cycles_detected) =
+        solve_arctangent(parameters(Some(CycleBreakingAction::RecomputeJacobian)));
+
+    assert!(result
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(exhausted_budget, Some(ExhaustedBudget::Iterations));
+    assert!(cycles_detected > 1);
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn cycle_detection_in_log() {
+    const LOG_PATH: &str = "./tests/solver/cycle_detection_log.txt";
+    let iterative = iteratives::IterativeParamsFD::new(
+        3.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        5e-8,
+        5e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(Some(CycleBreakingAction::DampedAverage)),
+        nalgebra::DVector::from_vec(vec![1.5]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    rf.solve(&mut nrf::model::UserModelFromFunction::new(1, arctangent))
+        .unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("Cycle detection: Damped average\n"));
+    assert!(log.contains(
+        "Cycle detected, the proposed iterate is the one of two iterations before without improving the error: Damped average\n"
+    ));
+}
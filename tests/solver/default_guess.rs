@@ -0,0 +1,77 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives::{IterativeParams, IterativeParamsFD, Iteratives};
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+fn iterative_with_bounds(min_value: f64, max_value: f64) -> IterativeParamsFD {
+    IterativeParamsFD::extend(
+        IterativeParams::new(f64::INFINITY, f64::INFINITY, min_value, max_value),
+        5e-8,
+        5e-8,
+        nrf::iteratives::PerturbationMethod::Max,
+    )
+}
+
+#[test]
+fn default_guess_for_each_bounds_configuration() {
+    let vec_iter_params = vec![
+        iterative_with_bounds(-2.0, 6.0),
+        iterative_with_bounds(-2.0, f64::INFINITY),
+        iterative_with_bounds(f64::NEG_INFINITY, 6.0),
+        iterative_with_bounds(f64::NEG_INFINITY, f64::INFINITY),
+    ];
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let expected = [2.0, -1.0, 5.0, 1.0];
+
+    let guess = iter_params.default_guess(nalgebra::Dyn(4));
+    assert_eq!(guess.as_slice(), expected);
+
+    let guess = iter_params.default_guess(nalgebra::Const::<4>);
+    assert_eq!(guess.as_slice(), expected);
+}
+
+#[test]
+#[should_panic]
+fn default_guess_dimension_mismatch() {
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(2);
+    let iter_params = Iteratives::new(&vec_iter_params);
+    iter_params.default_guess(nalgebra::Dyn(3));
+}
+
+pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.component_mul(x).add_scalar(-2.0)
+}
+
+#[test]
+fn solve_from_midpoint() {
+    let problem_size = 2;
+    // the bounds exclude the negative root
+    let vec_iter_params = vec![iterative_with_bounds(0.0, 3.0); problem_size];
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf =
+        nrf::solver::RootFinder::new_with_default_guess(parameters, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+
+    rf.solve(&mut user_model).unwrap();
+
+    for value in user_model.get_iteratives().iter() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            *value,
+            2_f64.sqrt(),
+            epsilon = 1e-9
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, ResolutionMethod, RootFinder, SolverParameters,
+};
+
+/// Amplitude of the noise of the model
+const NOISE: f64 = 1e-5;
+
+/// x - 2 = 0, the left member having a pseudo-random noise in `[-NOISE, NOISE)`
+///
+/// The noise is deterministic for a given seed, as a Monte-Carlo computation with a fixed seed:
+/// near the solution, the stopping error is the difference of the noises of two evaluations
+struct NoisyModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: f64,
+    state: u64,
+}
+
+impl NoisyModel {
+    fn new(seed: u64) -> Self {
+        NoisyModel {
+            iteratives: nalgebra::DVector::zeros(1),
+            left: 0.0,
+            state: seed.wrapping_mul(0x9E3779B97F4A7C15) | 1,
+        }
+    }
+
+    fn noise(&mut self) -> f64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        let uniform = (self.state >> 11) as f64 / (1u64 << 53) as f64;
+        NOISE * (2.0 * uniform - 1.0)
+    }
+}
+
+impl Model<nalgebra::Dyn> for NoisyModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = self.iteratives[0] + self.noise();
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            nalgebra::DVector::from_vec(vec![self.left]),
+            nalgebra::DVector::from_vec(vec![2.0]),
+        )
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::identity(1, 1),
+            nalgebra::DMatrix::zeros(1, 1),
+        ))
+    }
+}
+
+/// Number of iterations of the resolution for each seed, `None` if it has not converged
+fn iterations_per_seed(
+    tolerance: f64,
+    error_smoothing: Option<f64>,
+    consecutive_convergences: usize,
+) -> Vec<Option<usize>> {
+    let vec_iter_params = iteratives::default_vec_iteratives(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        tolerance,
+        MaxIterations::new(30),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_error_smoothing(error_smoothing);
+    parameters.set_consecutive_convergences(consecutive_convergences);
+    let mut rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![2.0]),
+        &iter_params,
+        &res_config,
+    );
+
+    (1..=20)
+        .map(|seed| {
+            let mut model = NoisyModel::new(seed);
+            rf.solve(&mut model).ok().map(|()| rf.get_iter())
+        })
+        .collect()
+}
+
+#[test]
+fn defaults() {
+    let parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(30),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    assert_eq!(parameters.get_error_smoothing(), None);
+    assert_eq!(parameters.get_consecutive_convergences(), 1);
+}
+
+#[test]
+fn raw_errors_convergence_is_flaky() {
+    let iterations = iterations_per_seed(1.5 * NOISE, None, 5);
+    assert!(iterations.iter().all(|iterations| iterations.is_some()));
+    assert!(iterations
+        .iter()
+        .any(|seed_iterations| *seed_iterations != iterations[0]));
+
+    // the convergence is randomly declared at the initial guess
+    let iterations = iterations_per_seed(0.5 * NOISE, None, 1);
+    assert!(iterations.contains(&Some(0)));
+    assert!(iterations.iter().any(|iterations| *iterations != Some(0)));
+}
+
+#[test]
+fn smoothed_errors_convergence_is_stable() {
+    // the initial guess counting as the first of the consecutive iterations
+    let iterations = iterations_per_seed(1.5 * NOISE, Some(0.2), 5);
+    assert_eq!(iterations, vec![Some(4); 20]);
+}
+
+#[test]
+fn no_smoothing_with_unit_coefficient() {
+    assert_eq!(
+        iterations_per_seed(0.5 * NOISE, Some(1.0), 1),
+        iterations_per_seed(0.5 * NOISE, None, 1)
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The error smoothing coefficient must be in ]0, 1], provided value was 0"
+)]
+fn zero_smoothing_coefficient() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(30),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_error_smoothing(Some(0.0));
+}
+
+#[test]
+#[should_panic(
+    expected = "The number of consecutive convergences must be strictly positive, got 0"
+)]
+fn zero_consecutive_convergences() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(30),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_consecutive_convergences(0);
+}
@@ -0,0 +1,205 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::residuals::{self, NormalizationMethod, ResidualsGroup};
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, MIN_TOLERANCE};
+
+const PROBLEM_SIZE: usize = 4;
+
+/// Two vector equations of two components each: `x_i^2 = i + 1`
+fn two_vector_equations(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map_with_location(|i, _, value| value * value - (i + 1) as f64)
+}
+
+/// The second group has a tolerance 1000 times larger than the solver one
+fn groups() -> Vec<ResidualsGroup> {
+    let mut velocity = ResidualsGroup::from_range("velocity", 2..4);
+    velocity.set_tolerance(Some(1e-3));
+    vec![ResidualsGroup::from_range("position", 0..2), velocity]
+}
+
+/// Resolution of the two vector equations from `1.0`, `check` being called with the solver and the result
+fn solve(
+    tolerance: f64,
+    max_iter: usize,
+    group_convergence: bool,
+    check: impl Fn(
+        &nrf::solver::RootFinder<iteratives::IterativeParamsFD, nalgebra::Dyn>,
+        Result<(), String>,
+    ),
+) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(groups());
+    res_config.set_group_convergence(group_convergence);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        tolerance,
+        MaxIterations::new(max_iter),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_element(PROBLEM_SIZE, 1.0),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, two_vector_equations);
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+    check(&rf, result);
+}
+
+#[test]
+fn residual_tolerances() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(groups());
+    let stopping_residuals = nalgebra::DVector::from_vec(vec![2.4e-6, 0.0, 2.4e-6, 5e-4]);
+
+    assert_eq!(res_config.residual_tolerances(1e-6), vec![1e-6; 4]);
+    let errors_over_tolerance =
+        res_config.evaluate_errors_over_tolerance(&stopping_residuals, 1e-6);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        errors_over_tolerance[0],
+        2.4,
+        ulps = 2
+    ));
+    assert_eq!(errors_over_tolerance[1], 0.0);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        errors_over_tolerance[3],
+        500.0,
+        ulps = 2
+    ));
+
+    res_config.set_group_convergence(true);
+    assert_eq!(
+        res_config.residual_tolerances(1e-6),
+        vec![1e-6, 1e-6, 1e-3, 1e-3]
+    );
+    let errors_over_tolerance =
+        res_config.evaluate_errors_over_tolerance(&stopping_residuals, 1e-6);
+    assert!(float_cmp::approx_eq!(
+        f64,
+        errors_over_tolerance[2],
+        2.4e-3,
+        ulps = 2
+    ));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        errors_over_tolerance[3],
+        0.5,
+        ulps = 2
+    ));
+}
+
+#[test]
+fn errors_over_tolerance_without_convergence() {
+    solve(1e-6, 3, false, |rf, result| {
+        assert!(result.is_err());
+        let errors_over_tolerance = rf.get_errors_over_tolerance().unwrap();
+        assert_eq!(errors_over_tolerance.len(), PROBLEM_SIZE);
+
+        // with a single tolerance, the worst residual is the one with the largest error
+        let unconverged_residuals = rf.get_unconverged_residuals();
+        assert_eq!(
+            rf.get_residuals_above_tolerance(),
+            unconverged_residuals.len()
+        );
+        let (worst_index, worst_error) = rf.get_worst_residual().unwrap();
+        assert_eq!(worst_index, unconverged_residuals[0].0);
+        assert!(float_cmp::approx_eq!(
+            f64,
+            worst_error * 1e-6,
+            unconverged_residuals[0].1,
+            ulps = 2
+        ));
+        assert!(float_cmp::approx_eq!(
+            f64,
+            worst_error * 1e-6,
+            rf.get_last_max_error().unwrap(),
+            ulps = 2
+        ));
+    });
+}
+
+#[test]
+fn errors_over_tolerance_with_group_tolerance() {
+    solve(1e-6, 3, true, |rf, result| {
+        assert!(result.is_err());
+        let errors_over_tolerance = rf.get_errors_over_tolerance().unwrap();
+        let unconverged_residuals = rf.get_unconverged_residuals();
+        assert_eq!(
+            rf.get_residuals_above_tolerance(),
+            unconverged_residuals.len()
+        );
+
+        // the residual with the largest error is in the velocity group, with the larger tolerance
+        let (largest_index, largest_error) = unconverged_residuals[0];
+        assert_eq!(largest_index, 3);
+        assert!(float_cmp::approx_eq!(
+            f64,
+            errors_over_tolerance[3],
+            largest_error / 1e-3,
+            ulps = 2
+        ));
+        let (worst_index, worst_error) = rf.get_worst_residual().unwrap();
+        assert_eq!(worst_index, 1);
+        assert!(worst_error > errors_over_tolerance[3]);
+    });
+}
+
+#[test]
+fn errors_over_tolerance_in_report() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_element(PROBLEM_SIZE, 1.0),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, two_vector_equations);
+
+    let report = rf.solve_with_report(&mut user_model).unwrap();
+    assert_eq!(report.get_residuals_above_tolerance(), 0);
+    assert_eq!(
+        report.get_errors_over_tolerance(),
+        &(report.get_final_errors() / 1e-6)
+    );
+    let (worst_index, worst_error) = report.get_worst_residual().unwrap();
+    assert_eq!(worst_error * 1e-6, report.get_final_errors()[worst_index]);
+    assert!(worst_error <= 1.0);
+    assert_eq!(
+        report.get_errors_over_tolerance(),
+        rf.get_errors_over_tolerance().unwrap()
+    );
+}
+
+#[test]
+fn errors_over_tolerance_at_tolerance_floor() {
+    solve(MIN_TOLERANCE, 50, false, |rf, result| {
+        let errors_over_tolerance = rf.get_errors_over_tolerance().unwrap();
+        assert!(errors_over_tolerance.iter().all(|error| error.is_finite()));
+        // the residuals above the floor are exactly the unconverged residuals
+        assert_eq!(
+            rf.get_residuals_above_tolerance(),
+            rf.get_unconverged_residuals().len()
+        );
+        assert_eq!(result.is_ok(), rf.get_residuals_above_tolerance() == 0);
+        let (_, worst_error) = rf.get_worst_residual().unwrap();
+        assert!(float_cmp::approx_eq!(
+            f64,
+            worst_error * MIN_TOLERANCE,
+            rf.get_last_max_error().unwrap(),
+            ulps = 2
+        ));
+    });
+}
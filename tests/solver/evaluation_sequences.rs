@@ -0,0 +1,137 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+
+/// Model recording every point at which it is evaluated
+struct RecordingModel {
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    evaluated_points: Vec<nalgebra::DVector<f64>>,
+}
+
+impl RecordingModel {
+    fn new(
+        problem_size: usize,
+        func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    ) -> Self {
+        RecordingModel {
+            func,
+            iteratives: nalgebra::DVector::zeros(problem_size),
+            left: nalgebra::DVector::zeros(problem_size),
+            evaluated_points: Vec::new(),
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for RecordingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = (self.func)(&self.iteratives);
+        self.evaluated_points.push(self.iteratives.clone());
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.left.clone(),
+            nalgebra::DVector::zeros(self.left.len()),
+        )
+    }
+}
+
+/// One evaluated point per line, with the exact representation of the floats
+fn format_points(points: &[nalgebra::DVector<f64>]) -> String {
+    points
+        .iter()
+        .map(|point| {
+            point
+                .iter()
+                .map(|value| format!("{:?}", value))
+                .collect::<Vec<_>>()
+                .join(" ")
+                + "\n"
+        })
+        .collect()
+}
+
+/// The points evaluated by the solver must not change when its internals are reworked,
+/// such as the reuse of the stopping errors of the current iterate
+fn check_sequence(
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    init: nalgebra::DVector<f64>,
+    parameters: SolverParameters,
+    ref_path: &str,
+) {
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = RecordingModel::new(problem_size, func);
+
+    rf.solve(&mut user_model).unwrap();
+
+    let reference = std::fs::read_to_string(ref_path).unwrap();
+    assert_eq!(format_points(&user_model.evaluated_points), reference);
+}
+
+const BROY1: ResolutionMethod = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+));
+
+#[test]
+fn sequence_broyden1_case5() {
+    check_sequence(
+        broyden1965_case5,
+        init_broyden1965_case5(),
+        SolverParameters::new(
+            ProblemSize::new(5),
+            1e-6,
+            MaxIterations::new(50),
+            BROY1,
+            Damping::Disabled,
+        ),
+        "./tests/solver/sequence_ref_broyden1_case5.txt",
+    );
+}
+
+// The damping rejects a step, the jacobian being then computed at the rejected point
+#[test]
+fn sequence_broyden1_case6_damping() {
+    check_sequence(
+        broyden1965_case6,
+        init_broyden1965_case6(),
+        SolverParameters::new(
+            ProblemSize::new(5),
+            1e-6,
+            MaxIterations::new(50),
+            BROY1,
+            Damping::Enabled,
+        ),
+        "./tests/solver/sequence_ref_broyden1_case6_damping.txt",
+    );
+}
@@ -0,0 +1,131 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+fn cannot_converge(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|x| x.cos() + 10.0) // cannot be zero
+}
+
+#[test]
+fn broyden_case10_fallback_on_newton_raphson() {
+    let problem_size = 2;
+    let damping = Damping::Enabled;
+    let broyden_method = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ));
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+        broyden_method,
+        damping,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case10);
+
+    // The first method fails on its own
+    assert!(rf.solve(&mut user_model).is_err());
+
+    let method = rf
+        .solve_with_fallback(&mut user_model, &[ResolutionMethod::NewtonRaphson])
+        .unwrap();
+    assert_eq!(method, ResolutionMethod::NewtonRaphson);
+
+    let solution = solution_broyden1965_case10();
+    for i in 0..problem_size {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            user_model.get_iteratives()[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+
+    // The resolution method of the parameters is restored
+    assert!(rf.solve(&mut user_model).is_err());
+}
+
+#[test]
+fn fallback_not_used_if_first_method_succeeds() {
+    let problem_size = 5;
+    let damping = Damping::Disabled;
+    let broyden_method = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ));
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        broyden_method,
+        damping,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case5);
+
+    let method = rf
+        .solve_with_fallback(&mut user_model, &[ResolutionMethod::NewtonRaphson])
+        .unwrap();
+    assert_eq!(method, broyden_method);
+}
+
+#[test]
+fn fallback_all_methods_fail() {
+    let problem_size = 2;
+    let damping = Damping::Disabled;
+    let stationary_method = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::zeros(problem_size),
+        &iter_params,
+        &res_config,
+        stationary_method,
+        damping,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, cannot_converge);
+
+    let error = rf
+        .solve_with_fallback(&mut user_model, &[ResolutionMethod::NewtonRaphson])
+        .unwrap_err();
+
+    let attempts = error.get_attempts();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].0, stationary_method);
+    assert_eq!(attempts[1].0, ResolutionMethod::NewtonRaphson);
+    for (_, attempt_error) in attempts {
+        match attempt_error {
+            nrf::errors::SolverError::NonConvergenceError(unconverged_residuals) => {
+                assert_eq!(unconverged_residuals.get_residuals().len(), problem_size)
+            }
+            _ => panic!("Unexpected error: {}", attempt_error),
+        }
+    }
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "All 2 resolution methods failed:\n\
+             - attempt 1 with Quasi Newton: Jacobian matrix behavior: Frozen Jacobian matrix: {}\n\
+             - attempt 2 with Newton-Raphson: {}",
+            attempts[0].1, attempts[1].1
+        )
+    );
+}
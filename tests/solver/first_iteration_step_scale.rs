@@ -0,0 +1,157 @@
+use std::cell::RefCell;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+
+// Root: x = 100. From x = 1, the Newton step is 72:
+// with a relative step limitation of 10%, about 45 iterations are required to reach the root.
+fn residual(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|x| x + x.sqrt() - 110.0)
+}
+
+fn jacobian(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_element(1, 1, 1.0 + 0.5 / x[0].sqrt())
+}
+
+/// Outcome of a resolution: the result and the iteratives of each evaluation of the model
+type Outcome = (Result<(), String>, Vec<f64>);
+
+fn solve(first_iteration_step_scale: f64, max_value: f64) -> Outcome {
+    let vec_iter_params = vec![iteratives::IterativeParams::new(
+        f64::INFINITY,
+        0.1,
+        0.0,
+        max_value,
+    )];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-9,
+        MaxIterations::new(10),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_first_iteration_step_scale(first_iteration_step_scale);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+    );
+
+    let trace = RefCell::new(Vec::new());
+    let recording_residual = |x: &nalgebra::DVector<f64>| {
+        trace.borrow_mut().push(x[0]);
+        residual(x)
+    };
+    let mut user_model =
+        nrf::model::UserModelFromClosureAndJacobian::new(1, &recording_residual, &jacobian);
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+    (result, trace.into_inner())
+}
+
+#[test]
+fn limited_first_step() {
+    let (result, trace) = solve(1.0, f64::INFINITY);
+
+    assert!(result
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert!(float_cmp::approx_eq!(f64, trace[1], 1.1, ulps = 2));
+}
+
+#[test]
+fn relaxed_first_step() {
+    let (result, trace) = solve(f64::INFINITY, f64::INFINITY);
+
+    assert_eq!(result, Ok(()));
+    assert!(float_cmp::approx_eq!(f64, trace[1], 73.0, ulps = 2));
+    // the following steps are limited
+    assert!(float_cmp::approx_eq!(f64, trace[2], 80.3, ulps = 2));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        *trace.last().unwrap(),
+        100.0,
+        epsilon = 1e-9
+    ));
+}
+
+#[test]
+fn scaled_first_step() {
+    let (_, trace) = solve(100.0, f64::INFINITY);
+
+    // limited to 100 times 10% of the initial value
+    assert!(float_cmp::approx_eq!(f64, trace[1], 11.0, ulps = 2));
+}
+
+#[test]
+fn bounds_enforced_with_relaxed_first_step() {
+    let (_, trace) = solve(f64::INFINITY, 50.0);
+
+    assert_eq!(trace[1], 50.0);
+}
+
+#[test]
+#[should_panic(
+    expected = "The first iteration step scale must be strictly positive, provided value was 0"
+)]
+fn invalid_first_iteration_step_scale() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-9,
+        MaxIterations::new(10),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_first_iteration_step_scale(0.0);
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn relaxation_in_log() {
+    const LOG_PATH: &str = "./tests/solver/first_iteration_step_scale_log.txt";
+    let vec_iter_params = vec![iteratives::IterativeParams::new(
+        f64::INFINITY,
+        0.1,
+        0.0,
+        f64::INFINITY,
+    )];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-9,
+        MaxIterations::new(10),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_first_iteration_step_scale(f64::INFINITY);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    rf.solve(&mut nrf::model::UserModelFromFunctionAndJacobian::new(
+        1, residual, jacobian,
+    ))
+    .unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("First iteration step scale: inf\n"));
+    assert_eq!(
+        log.matches("Step limitations of the first iteration multiplied by inf\n")
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,229 @@
+use newton_rootfinder as nrf;
+
+use nrf::errors::RootFinderConstructionError;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    UpdateQuasiNewtonMethod,
+};
+
+const FIXED_VALUE: f64 = 1.5;
+
+/// With the third iterative fixed at 1.5 and the third residual ignored, the solution is (2, 1)
+fn three_variables(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![
+        x[0] * x[0] + x[1] + x[2] - 6.5,
+        x[0] + x[1] * x[1] - x[2] - 1.5,
+        x[0] * x[2] - 2.0,
+    ])
+}
+
+fn three_variables_jacobian(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_row_slice(
+        3,
+        3,
+        &[2.0 * x[0], 1.0, 1.0, 1.0, 2.0 * x[1], -1.0, x[2], 0.0, x[0]],
+    )
+}
+
+/// `three_variables` with the third iterative replaced by its fixed value, without the third residual
+fn two_variables(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![
+        x[0] * x[0] + x[1] + FIXED_VALUE - 6.5,
+        x[0] + x[1] * x[1] - FIXED_VALUE - 1.5,
+    ])
+}
+
+fn two_variables_jacobian(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_row_slice(2, 2, &[2.0 * x[0], 1.0, 1.0, 2.0 * x[1]])
+}
+
+fn parameters(
+    problem_size: usize,
+    resolution_method: ResolutionMethod,
+) -> nrf::solver::SolverParameters {
+    nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-10,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    )
+}
+
+/// Solution and number of iterations of the reduced problem, from the first two values of the initial guess
+fn solve_two_variables(resolution_method: ResolutionMethod) -> (nalgebra::DVector<f64>, usize) {
+    let init = nalgebra::DVector::from_vec(vec![1.8, 1.2]);
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(2, resolution_method),
+        init,
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, two_variables);
+    rf.solve(&mut user_model).unwrap();
+
+    (user_model.get_iteratives(), rf.get_iter())
+}
+
+/// Solution and number of iterations of the problem with the third iterative fixed,
+/// the initial guess of the third iterative being overridden by its fixed value
+fn solve_three_variables(resolution_method: ResolutionMethod) -> (nalgebra::DVector<f64>, usize) {
+    let init = nalgebra::DVector::from_vec(vec![1.8, 1.2, 10.0]);
+    let mut vec_iter_params = iteratives::default_vec_iteratives_fd(3);
+    vec_iter_params[2].set_fixed(Some(FIXED_VALUE));
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_ignored_residuals(vec![2]);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(3, resolution_method),
+        init,
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(3, three_variables);
+    rf.solve(&mut user_model).unwrap();
+
+    (user_model.get_iteratives(), rf.get_iter())
+}
+
+fn assert_reduced_solution(resolution_method: ResolutionMethod) {
+    let (reduced, reduced_iter) = solve_two_variables(resolution_method);
+    let (full, full_iter) = solve_three_variables(resolution_method);
+
+    assert_eq!(full[2], FIXED_VALUE);
+    assert!((full[0] - reduced[0]).abs() < 1e-9);
+    assert!((full[1] - reduced[1]).abs() < 1e-9);
+    assert_eq!(full_iter, reduced_iter);
+}
+
+#[test]
+fn newton_raphson() {
+    assert_reduced_solution(ResolutionMethod::NewtonRaphson);
+}
+
+#[test]
+fn broyden_jacobian_update() {
+    assert_reduced_solution(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+    ));
+}
+
+#[test]
+fn broyden_inverse_jacobian_update() {
+    let (reduced, _) = solve_two_variables(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod),
+    ));
+    let (full, _) = solve_three_variables(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod),
+    ));
+
+    assert_eq!(full[2], FIXED_VALUE);
+    assert!((full[0] - reduced[0]).abs() < 1e-9);
+    assert!((full[1] - reduced[1]).abs() < 1e-9);
+}
+
+#[test]
+fn analytical_jacobian() {
+    let init = nalgebra::DVector::from_vec(vec![1.8, 1.2]);
+    let vec_iter_params = iteratives::default_vec_iteratives(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(2, ResolutionMethod::NewtonRaphson),
+        init,
+        &iter_params,
+        &res_config,
+    );
+    let mut reduced_model =
+        nrf::model::UserModelFromFunctionAndJacobian::new(2, two_variables, two_variables_jacobian);
+    rf.solve(&mut reduced_model).unwrap();
+    let reduced_iter = rf.get_iter();
+
+    let init = nalgebra::DVector::from_vec(vec![1.8, 1.2, 1.0]);
+    let mut vec_iter_params = iteratives::default_vec_iteratives(3);
+    vec_iter_params[2].set_fixed(Some(FIXED_VALUE));
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let mut res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    res_config.set_ignored_residuals(vec![2]);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(3, ResolutionMethod::NewtonRaphson),
+        init,
+        &iter_params,
+        &res_config,
+    );
+    let mut full_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        3,
+        three_variables,
+        three_variables_jacobian,
+    );
+    rf.solve(&mut full_model).unwrap();
+
+    let full = full_model.get_iteratives();
+    let reduced = reduced_model.get_iteratives();
+    assert_eq!(full[2], FIXED_VALUE);
+    assert!((full[0] - reduced[0]).abs() < 1e-9);
+    assert!((full[1] - reduced[1]).abs() < 1e-9);
+    assert_eq!(rf.get_iter(), reduced_iter);
+}
+
+#[test]
+fn ignored_residual_not_converged() {
+    let (full, _) = solve_three_variables(ResolutionMethod::NewtonRaphson);
+
+    // the ignored residual is not satisfied by the solution, without preventing the convergence
+    assert!((full[0] - 2.0).abs() < 1e-9);
+    assert!((full[1] - 1.0).abs() < 1e-9);
+    assert!((three_variables(&full)[2] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn count_mismatch() {
+    let init = nalgebra::DVector::from_vec(vec![1.8, 1.2, 1.0]);
+    let mut vec_iter_params = iteratives::default_vec_iteratives_fd(3);
+    vec_iter_params[2].set_fixed(Some(FIXED_VALUE));
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let error = nrf::solver::RootFinder::try_new(
+        parameters(3, ResolutionMethod::NewtonRaphson),
+        init,
+        &iter_params,
+        &res_config,
+    )
+    .err();
+    assert_eq!(
+        error,
+        Some(RootFinderConstructionError::FixedIterativesMismatch {
+            fixed_iteratives: 1,
+            ignored_residuals: 0,
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The fixed iteratives [2] require a resolution method working with the jacobian"
+)]
+fn limited_memory_broyden_panics() {
+    solve_three_variables(ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::LimitedMemoryBroyden { memory: 5 },
+    ));
+}
@@ -2,14 +2,14 @@ use newton_rootfinder as nrf;
 
 use crate::common::{run_function_case_fd, run_function_case_jac};
 
-use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
 
 use util::test_cases::polynom;
 
 #[test]
 fn square() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::square2,
@@ -25,7 +25,7 @@ fn square() {
 #[test]
 fn root_with_high_derivative() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::root_with_high_derivative,
@@ -41,7 +41,7 @@ fn root_with_high_derivative() {
 #[test]
 fn square_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::square2,
@@ -58,7 +58,7 @@ fn square_jac() {
 #[test]
 fn root_with_high_derivative_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::root_with_high_derivative,
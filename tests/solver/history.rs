@@ -0,0 +1,256 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const BROY1: ResolutionMethod = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+));
+
+/// Artificially slowed model, each evaluation lasting at least a millisecond
+fn slow_broyden1965_case5(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    std::thread::sleep(std::time::Duration::from_millis(1));
+    broyden1965_case5(x)
+}
+
+#[test]
+fn durations_and_offsets() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, slow_broyden1965_case5);
+
+    rf.set_history(true);
+    rf.solve(&mut user_model).unwrap();
+
+    let history = rf.get_history();
+    assert_eq!(history.len(), rf.get_iter());
+    let mut previous_offset = std::time::Duration::ZERO;
+    for (i, record) in history.iter().enumerate() {
+        assert_eq!(record.get_iteration(), i + 1);
+        assert!(record.get_duration() >= std::time::Duration::from_millis(1));
+        assert!(record.get_timestamp_offset() >= previous_offset);
+        assert!(record.get_timestamp_offset() >= record.get_duration());
+        previous_offset = record.get_timestamp_offset();
+    }
+    // the initial evaluation precedes the first iteration
+    assert!(history[0].get_timestamp_offset() >= std::time::Duration::from_millis(2));
+    assert_eq!(
+        history.last().unwrap().get_model_evaluations(),
+        rf.get_model_evaluations()
+    );
+    assert!(history.last().unwrap().get_max_error() <= 1e-6);
+
+    // the history of the previous resolution is discarded
+    rf.solve(&mut user_model).unwrap();
+    assert_eq!(rf.get_history().len(), rf.get_iter());
+}
+
+#[test]
+fn deactivated_by_default() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    assert!(!rf.get_history_activated());
+    rf.solve(&mut user_model).unwrap();
+    assert!(rf.get_history().is_empty());
+}
+
+#[test]
+fn csv_file() {
+    const PATH: &str = "./tests/solver/history.csv";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, slow_broyden1965_case5);
+
+    rf.set_history(true);
+    rf.solve(&mut user_model).unwrap();
+    rf.write_history(PATH);
+
+    let content = std::fs::read_to_string(PATH).unwrap();
+    std::fs::remove_file(PATH).unwrap();
+    let mut lines = content.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "iteration,max_error,model_evaluations,duration_s,timestamp_offset_s,secant_residual,update_norm"
+    );
+    let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+    assert_eq!(rows.len(), rf.get_iter());
+    for (row, record) in rows.iter().zip(rf.get_history()) {
+        assert_eq!(row.len(), 7);
+        assert_eq!(row[0].parse::<usize>().unwrap(), record.get_iteration());
+        assert_eq!(
+            row[3].parse::<f64>().unwrap(),
+            record.get_duration().as_secs_f64()
+        );
+        assert_eq!(
+            row[4].parse::<f64>().unwrap(),
+            record.get_timestamp_offset().as_secs_f64()
+        );
+        match record.get_update_metrics() {
+            Some(metrics) => {
+                assert_eq!(
+                    row[5].parse::<f64>().unwrap(),
+                    metrics.get_secant_residual()
+                );
+                assert_eq!(row[6].parse::<f64>().unwrap(), metrics.get_update_norm());
+            }
+            None => assert_eq!((row[5], row[6]), ("", "")),
+        }
+    }
+}
+
+#[test]
+fn broyden_update_metrics() {
+    let broy1_inv = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ));
+    for method in [BROY1, broy1_inv] {
+        let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+        let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let parameters = SolverParameters::new(
+            ProblemSize::new(5),
+            1e-6,
+            MaxIterations::new(50),
+            method,
+            Damping::Disabled,
+        );
+        let mut rf = nrf::solver::RootFinder::new(
+            parameters,
+            init_broyden1965_case5(),
+            &iter_params,
+            &res_config,
+        );
+        let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+        rf.set_history(true);
+        rf.solve(&mut user_model).unwrap();
+
+        let history = rf.get_history();
+        // the jacobian is computed at the first iteration
+        assert_eq!(history[0].get_update_metrics(), None);
+        for record in &history[1..] {
+            let metrics = record.get_update_metrics().unwrap();
+            // the Broyden first method satisfies the secant equation
+            assert!(metrics.get_secant_residual() <= 1e-12);
+            assert!(metrics.get_update_norm() > 0.0);
+        }
+    }
+}
+
+static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Model whose residual alternates between two values at the same iterative
+fn noisy_affine(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let noise = 1e-3 * (EVALUATIONS.fetch_add(1, Ordering::Relaxed) % 2) as f64;
+    x.map(|x| x - 1.0 + noise)
+}
+
+fn unit_jacobian(_x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::identity(1, 1)
+}
+
+#[test]
+fn skipped_update_metrics() {
+    // the iterative is stuck on its upper bound, the quasi-Newton updates being skipped
+    let vec_iter_params = vec![iteratives::IterativeParams::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        0.0,
+        0.5,
+    )];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(3),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![0.5]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model =
+        nrf::model::UserModelFromFunctionAndJacobian::new(1, noisy_affine, unit_jacobian);
+
+    rf.set_history(true);
+    assert!(rf.solve(&mut user_model).is_err());
+
+    let history = rf.get_history();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].get_update_metrics(), None);
+    for record in &history[1..] {
+        let metrics = record.get_update_metrics().unwrap();
+        assert_eq!(metrics.get_update_norm(), 0.0);
+        assert!(float_cmp::approx_eq!(
+            f64,
+            metrics.get_secant_residual(),
+            1e-3,
+            epsilon = 1e-12
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//! Slimmed-down version of the `implicit_euler` example:
+//! the solver is reused for the time steps of an implicit integrator, keeping its jacobian between them
+
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianSource, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    RootFinder,
+};
+
+/// Time step of the implicit Euler method on the Robertson problem: `y - dt f(y) = y_previous`
+struct RobertsonStep {
+    previous_state: nalgebra::DVector<f64>,
+    time_step: f64,
+    state: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for RobertsonStep {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        3
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.state.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.state.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        let y = &self.state;
+        let f = nalgebra::DVector::from_vec(vec![
+            -0.04 * y[0] + 1e4 * y[1] * y[2],
+            0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1] * y[1],
+            3e7 * y[1] * y[1],
+        ]);
+        self.left = y - f * self.time_step;
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), self.previous_state.clone())
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        let y = &self.state;
+        #[rustfmt::skip]
+        let jacobian_f = nalgebra::DMatrix::from_row_slice(3, 3, &[
+            -0.04, 1e4 * y[2], 1e4 * y[1],
+            0.04, -1e4 * y[2] - 6e7 * y[1], -1e4 * y[1],
+            0.0, 6e7 * y[1], 0.0,
+        ]);
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::identity(3, 3) - jacobian_f * self.time_step,
+            nalgebra::DMatrix::zeros(3, 3),
+        ))
+    }
+}
+
+/// Integrate with 10 time steps per value of the time step, doubled at each phase,
+/// returning the final state and the number of jacobian computations
+fn integrate(jacobian_reuse: bool, phases: usize) -> (nalgebra::DVector<f64>, usize) {
+    let initial_state = nalgebra::DVector::from_vec(vec![1.0, 0.0, 0.0]);
+
+    let vec_iter_params = iteratives::default_vec_iteratives(3);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 3];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 3];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(3),
+        1e-12,
+        MaxIterations::new(30),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Damping::Disabled,
+    );
+    let mut rf = RootFinder::new(parameters, initial_state.clone(), &iter_params, &res_config);
+    rf.set_jacobian_reuse(jacobian_reuse);
+    assert_eq!(rf.get_jacobian_reuse(), jacobian_reuse);
+
+    let mut model = RobertsonStep {
+        previous_state: initial_state.clone(),
+        time_step: 1e-4,
+        state: initial_state.clone(),
+        left: initial_state,
+    };
+    let mut jacobian_computations = 0;
+
+    for step in 0..10 * phases {
+        if step > 0 && step % 10 == 0 {
+            model.time_step *= 2.0;
+            rf.invalidate_jacobian();
+        }
+
+        rf.set_initial_guess(&model.previous_state);
+        rf.solve(&mut model).unwrap();
+
+        let sources = rf.get_jacobian_sources();
+        if jacobian_reuse && step % 10 != 0 {
+            assert_eq!(sources[0], JacobianSource::Reused);
+        } else {
+            assert_eq!(sources[0], JacobianSource::AnalyticalModel);
+        }
+        jacobian_computations += sources
+            .iter()
+            .filter(|&&source| source == JacobianSource::AnalyticalModel)
+            .count();
+
+        model.previous_state = model.get_iteratives();
+    }
+
+    (model.previous_state, jacobian_computations)
+}
+
+#[test]
+fn implicit_euler_with_jacobian_reuse() {
+    let phases = 8;
+    let (state, jacobian_computations) = integrate(true, phases);
+
+    // one computation per value of the time step
+    assert_eq!(jacobian_computations, phases);
+
+    // the implicit Euler method conserves the total mass
+    assert!((state.sum() - 1.0).abs() < 1e-10);
+    // state at t = 0.255
+    let expected = [0.9903068, 3.476628e-5, 9.658458e-3];
+    for (value, expected) in state.iter().zip(expected) {
+        assert!(
+            (value - expected).abs() <= 1e-4 * expected,
+            "{} instead of {}",
+            value,
+            expected
+        );
+    }
+}
+
+#[test]
+fn implicit_euler_without_jacobian_reuse() {
+    let phases = 3;
+    let (state_reuse, _) = integrate(true, phases);
+    let (state, jacobian_computations) = integrate(false, phases);
+
+    // one computation per time step at least
+    assert!(jacobian_computations >= 10 * phases);
+    for (value, value_reuse) in state.iter().zip(state_reuse.iter()) {
+        assert!((value - value_reuse).abs() <= 1e-9);
+    }
+}
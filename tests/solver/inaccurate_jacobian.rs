@@ -0,0 +1,347 @@
+use std::error::Error;
+use std::fmt;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, InaccurateJacobianPolicy, MaxIterations, ProblemSize, ResolutionMethod,
+};
+
+const INITIAL_GUESS: f64 = 1.45;
+const VALIDITY_LIMIT: f64 = 1.5;
+
+#[derive(Debug)]
+struct OutOfValidity;
+
+impl fmt::Display for OutOfValidity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Out of the validity range")
+    }
+}
+
+impl Error for OutOfValidity {}
+
+/// Equation `x^2 = 2`, whose values are inaccurate above `VALIDITY_LIMIT`
+///
+/// Starting from `INITIAL_GUESS`, the iterates stay between the root and the limit,
+/// hence only the perturbed inputs are out of the validity range with a perturbation of `0.1`,
+/// whereas they are in this range with a perturbation of `0.05`.
+/// The inaccurate values are offset, giving a slope much too steep.
+/// If `unusable`, the values above the limit are unusable instead of inaccurate
+struct ValidityRangeModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    evaluations: usize,
+    jacobian_provided: bool,
+    unusable: bool,
+}
+
+impl ValidityRangeModel {
+    fn new(jacobian_provided: bool) -> Self {
+        ValidityRangeModel {
+            iteratives: nalgebra::DVector::zeros(1),
+            left: nalgebra::DVector::zeros(1),
+            evaluations: 0,
+            jacobian_provided,
+            unusable: false,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.iteratives[0] <= VALIDITY_LIMIT
+    }
+}
+
+impl Model<nalgebra::Dyn> for ValidityRangeModel {
+    type InaccurateValuesError = OutOfValidity;
+    type UnusableValuesError = OutOfValidity;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        self.left[0] = self.iteratives[0] * self.iteratives[0];
+        if self.is_valid() {
+            Ok(())
+        } else if self.unusable {
+            Err(ModelError::UnusableValuesError(OutOfValidity))
+        } else {
+            self.left[0] += 1.0;
+            Err(ModelError::InaccurateValuesError(OutOfValidity))
+        }
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), nalgebra::DVector::from_vec(vec![2.0]))
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        self.jacobian_provided
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, ModelError<Self, nalgebra::Dyn>> {
+        // the jacobian is out of its validity range for an iterative below the root
+        if self.iteratives[0] < 2.0_f64.sqrt() {
+            return Err(ModelError::InaccurateValuesError(OutOfValidity));
+        }
+        Ok(residuals::JacobianValues::new(
+            nalgebra::DMatrix::from_element(1, 1, 2.0 * self.iteratives[0]),
+            nalgebra::DMatrix::zeros(1, 1),
+        ))
+    }
+}
+
+fn solve(
+    policy: InaccurateJacobianPolicy,
+    user_model: &mut ValidityRangeModel,
+) -> (
+    Result<(), String>,
+    Vec<nrf::solver::InaccurateColumn>,
+    Option<nalgebra::DVector<f64>>,
+    usize,
+) {
+    let iterative = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        0.1,
+        1e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_inaccurate_jacobian_policy(policy);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![INITIAL_GUESS]),
+        &iter_params,
+        &res_config,
+    );
+
+    let result = rf.solve(user_model).map_err(|error| error.to_string());
+    (
+        result,
+        rf.get_inaccurate_columns().to_vec(),
+        rf.get_perturbations().clone(),
+        rf.get_model_evaluations(),
+    )
+}
+
+#[test]
+fn accept_stalls() {
+    let mut user_model = ValidityRangeModel::new(false);
+    let (result, inaccurate_columns, _, evaluations) =
+        solve(InaccurateJacobianPolicy::Accept, &mut user_model);
+
+    assert!(result
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_retries(), 0);
+    assert!(!inaccurate_columns[0].get_recovered());
+    assert_eq!(evaluations, user_model.evaluations);
+}
+
+#[test]
+fn retry_column_with_reduced_step() {
+    let mut user_model = ValidityRangeModel::new(false);
+    let (result, inaccurate_columns, perturbations, evaluations) = solve(
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 3 },
+        &mut user_model,
+    );
+
+    assert_eq!(result, Ok(()));
+    assert!(float_cmp::approx_eq!(
+        f64,
+        user_model.get_iteratives()[0],
+        2.0_f64.sqrt(),
+        epsilon = 1e-6
+    ));
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_column(), 0);
+    assert_eq!(inaccurate_columns[0].get_retries(), 1);
+    assert!(inaccurate_columns[0].get_recovered());
+    assert_eq!(perturbations, Some(nalgebra::DVector::from_vec(vec![0.05])));
+    // the retries are counted as model evaluations
+    assert_eq!(evaluations, user_model.evaluations);
+}
+
+#[test]
+fn retry_exhausted() {
+    let mut user_model = ValidityRangeModel::new(false);
+    let (result, inaccurate_columns, _, _) = solve(
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 0 },
+        &mut user_model,
+    );
+
+    assert_eq!(
+        result,
+        Err(
+            "Jacobian evaluation failed: InaccurateValues Error: Out of the validity range"
+                .to_string()
+        )
+    );
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_retries(), 0);
+    assert!(!inaccurate_columns[0].get_recovered());
+}
+
+#[test]
+fn fail() {
+    let mut user_model = ValidityRangeModel::new(false);
+    let (result, inaccurate_columns, _, _) = solve(InaccurateJacobianPolicy::Fail, &mut user_model);
+
+    assert_eq!(
+        result,
+        Err(
+            "Jacobian evaluation failed: InaccurateValues Error: Out of the validity range"
+                .to_string()
+        )
+    );
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert!(!inaccurate_columns[0].get_recovered());
+}
+
+// The unusable values of a perturbed point are retried as the inaccurate ones
+#[test]
+fn retry_unusable_values() {
+    let mut user_model = ValidityRangeModel::new(false);
+    user_model.unusable = true;
+    let (result, inaccurate_columns, perturbations, _) = solve(
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 3 },
+        &mut user_model,
+    );
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(inaccurate_columns.len(), 1);
+    assert_eq!(inaccurate_columns[0].get_retries(), 1);
+    assert!(inaccurate_columns[0].get_recovered());
+    assert_eq!(perturbations, Some(nalgebra::DVector::from_vec(vec![0.05])));
+}
+
+#[test]
+fn fail_unusable_values() {
+    let mut user_model = ValidityRangeModel::new(false);
+    user_model.unusable = true;
+    let (result, inaccurate_columns, _, _) = solve(InaccurateJacobianPolicy::Fail, &mut user_model);
+
+    assert_eq!(
+        result,
+        Err(
+            "Jacobian evaluation failed: UnusableValuesError Error: Out of the validity range"
+                .to_string()
+        )
+    );
+    assert!(inaccurate_columns.is_empty());
+}
+
+#[test]
+fn analytical_jacobian() {
+    for policy in [
+        InaccurateJacobianPolicy::Fail,
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 3 },
+    ] {
+        let mut user_model = ValidityRangeModel::new(true);
+        user_model.iteratives[0] = 1.0;
+        let iter_params_vec = iteratives::default_vec_iteratives(1);
+        let iter_params = iteratives::Iteratives::new(&iter_params_vec);
+        let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+        let update_methods = vec![residuals::NormalizationMethod::Abs];
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut parameters = nrf::solver::SolverParameters::new(
+            ProblemSize::new(1),
+            1e-6,
+            MaxIterations::new(20),
+            ResolutionMethod::NewtonRaphson,
+            Damping::Disabled,
+        );
+        parameters.set_inaccurate_jacobian_policy(policy);
+        let mut rf = nrf::solver::RootFinder::new(
+            parameters,
+            nalgebra::DVector::from_vec(vec![1.0]),
+            &iter_params,
+            &res_config,
+        );
+
+        let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+        assert_eq!(
+            result,
+            Err(
+                "Jacobian evaluation failed: InaccurateValues Error: Out of the validity range"
+                    .to_string()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn actions_in_log() {
+    const LOG_PATH: &str = "./tests/solver/inaccurate_jacobian_log.txt";
+    let iterative = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        0.1,
+        1e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs];
+    let update_methods = vec![residuals::NormalizationMethod::Abs];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(1),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_inaccurate_jacobian_policy(
+        InaccurateJacobianPolicy::RetryColumnWithReducedStep { max_retries: 3 },
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![INITIAL_GUESS]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    rf.solve(&mut ValidityRangeModel::new(false)).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(
+        log.contains("Inaccurate jacobian policy: Retry column with reduced step (3 retries)\n")
+    );
+    assert!(log.contains(
+        "Inaccurate jacobian column 0 (1 retries): accurate values after reducing the perturbation\n"
+    ));
+}
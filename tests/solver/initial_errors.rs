@@ -0,0 +1,102 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+
+fn circle_and_line(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] + x[1] * x[1] - 4.0, x[0] - x[1] - 0.5])
+}
+
+const STOPPING_RESIDUALS: [residuals::NormalizationMethod; 2] =
+    [residuals::NormalizationMethod::Abs; 2];
+const UPDATE_METHODS: [residuals::NormalizationMethod; 2] =
+    [residuals::NormalizationMethod::Abs; 2];
+
+/// Stopping errors at the guess, evaluated without the solver
+fn independent_errors(
+    res_config: &residuals::ResidualsConfig,
+    guess: &nalgebra::DVector<f64>,
+) -> nalgebra::DVector<f64> {
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, circle_and_line);
+    user_model.set_iteratives(guess);
+    user_model.evaluate().unwrap();
+    res_config.evaluate_stopping_residuals(&user_model.get_residuals())
+}
+
+#[test]
+fn initial_errors_of_the_guess() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let res_config = residuals::ResidualsConfig::new(&STOPPING_RESIDUALS, &UPDATE_METHODS);
+    let guess = nalgebra::DVector::from_vec(vec![3.0, 1.0]);
+    let mut rf = nrf::solver::default_with_guess(
+        guess.clone(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    assert!(rf.get_initial_errors().is_none());
+    assert_eq!(rf.get_initial_max_error(), None);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, circle_and_line);
+    rf.solve(&mut user_model).unwrap();
+
+    let expected = independent_errors(&res_config, &guess);
+    assert_eq!(rf.get_initial_errors(), Some(&expected));
+    assert_eq!(rf.get_initial_max_error(), Some(expected.amax()));
+    assert!(rf.get_last_max_error().unwrap() <= 1e-6);
+
+    // The errors are the ones of the last resolution
+    let guess = nalgebra::DVector::from_vec(vec![1.0, 2.0]);
+    rf.set_initial_guess(&guess);
+    rf.solve(&mut user_model).unwrap();
+    assert_eq!(
+        rf.get_initial_errors(),
+        Some(&independent_errors(&res_config, &guess))
+    );
+}
+
+#[test]
+fn initial_errors_in_batch_reports() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let res_config = residuals::ResidualsConfig::new(&STOPPING_RESIDUALS, &UPDATE_METHODS);
+    let initial_guesses = vec![
+        nalgebra::DVector::from_vec(vec![3.0, 1.0]),
+        nalgebra::DVector::from_vec(vec![10.0, -5.0]),
+    ];
+    let mut models: Vec<_> = (0..initial_guesses.len())
+        .map(|_| nrf::model::UserModelFromFunction::new(2, circle_and_line))
+        .collect();
+    let parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+
+    let reports = nrf::solver::solve_batch(
+        parameters,
+        &iter_params,
+        &res_config,
+        &initial_guesses,
+        &mut models,
+    );
+
+    for (report, guess) in reports.into_iter().zip(&initial_guesses) {
+        let report = report.unwrap();
+        let initial_max_error = independent_errors(&res_config, guess).amax();
+        assert_eq!(report.get_initial_max_error(), initial_max_error);
+        assert!(report.get_final_max_error() <= 1e-9);
+        assert!(float_cmp::approx_eq!(
+            f64,
+            report.get_orders_of_magnitude_gained(),
+            (initial_max_error / report.get_final_max_error()).log10(),
+            epsilon = 1e-12
+        ));
+        assert!(report.get_orders_of_magnitude_gained() > 8.0);
+    }
+}
@@ -0,0 +1,109 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{ConvergenceCriterion, Damping, ResolutionMethod};
+
+fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let mut y = x * x;
+    y[0] -= 2.0;
+    y
+}
+
+/// Model of `square2` whose values are always flagged as inaccurate
+struct InaccurateSquare2 {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+}
+
+impl Model<nalgebra::Dyn> for InaccurateSquare2 {
+    type InaccurateValuesError = std::fmt::Error;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        1
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = square2(&self.iteratives);
+        Err(ModelError::InaccurateValuesError(std::fmt::Error))
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), nalgebra::DVector::zeros(1))
+    }
+}
+
+#[test]
+fn converged_initial_guess() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let guess = nalgebra::DVector::from_vec(vec![std::f64::consts::SQRT_2]);
+    let mut rf = nrf::solver::default_with_guess(
+        guess.clone(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, square2);
+
+    let report = rf.solve_with_report(&mut user_model).unwrap();
+
+    assert_eq!(report.get_iter(), 0);
+    assert_eq!(report.get_model_evaluations(), 1);
+    assert!(report.get_jacobian_source_counts().is_empty());
+    assert_eq!(
+        report.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::InitialGuess)
+    );
+    assert_eq!(report.get_final_iteratives(), &guess);
+    assert_eq!(Some(report.get_final_errors()), rf.get_initial_errors());
+    assert_eq!(report.get_final_max_error(), report.get_initial_max_error());
+}
+
+// The evaluation at the initial guess is the final one: its inaccurate values are not accepted as a solution
+#[test]
+fn converged_initial_guess_with_inaccurate_values() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let guess = nalgebra::DVector::from_vec(vec![std::f64::consts::SQRT_2]);
+    let mut rf = nrf::solver::default_with_guess(
+        guess.clone(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = InaccurateSquare2 {
+        iteratives: nalgebra::DVector::zeros(1),
+        left: nalgebra::DVector::zeros(1),
+    };
+
+    let result = rf.solve(&mut user_model);
+
+    assert!(matches!(
+        result,
+        Err(nrf::errors::SolverError::FinalEvaluationError)
+    ));
+    assert_eq!(rf.get_iter(), 0);
+    assert_eq!(
+        rf.get_last_satisfied_criterion(),
+        Some(ConvergenceCriterion::InitialGuess)
+    );
+    assert_eq!(user_model.get_iteratives(), guess);
+}
@@ -0,0 +1,112 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives::{self, Iterative};
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, ResolutionMethod};
+use util::test_cases::broyden1965::*;
+
+use std::fmt;
+
+const PROBLEM_SIZE: usize = 5;
+
+/// Resolution of the case 5 of Broyden, the jacobian being provided or not by the model
+fn solve_case5<T>(vec_iter_params: &[T], jacobian_provided: bool)
+where
+    T: Iterative + fmt::Display + fmt::Debug,
+{
+    let iter_params = iteratives::Iteratives::new(vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+
+    let solution = if jacobian_provided {
+        let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+            PROBLEM_SIZE,
+            broyden1965_case5,
+            broyden1965_case5_jac,
+        );
+        rf.solve(&mut user_model).unwrap();
+        user_model.get_iteratives()
+    } else {
+        let mut user_model =
+            nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5);
+        rf.solve(&mut user_model).unwrap();
+        user_model.get_iteratives()
+    };
+
+    for i in 0..PROBLEM_SIZE {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            solution[i],
+            solution_broyden1965_case5()[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn finite_difference_with_fd_iteratives() {
+    solve_case5(&iteratives::default_vec_iteratives_fd(PROBLEM_SIZE), false);
+}
+
+#[test]
+#[should_panic(
+    expected = "The model does not provide its jacobian and the iteratives are not working with finite differences"
+)]
+fn finite_difference_without_fd_iteratives() {
+    solve_case5(&iteratives::default_vec_iteratives(PROBLEM_SIZE), false);
+}
+
+#[test]
+fn provided_jacobian_with_fd_iteratives() {
+    solve_case5(&iteratives::default_vec_iteratives_fd(PROBLEM_SIZE), true);
+}
+
+#[test]
+fn provided_jacobian_without_fd_iteratives() {
+    solve_case5(&iteratives::default_vec_iteratives(PROBLEM_SIZE), true);
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn provided_jacobian_with_fd_iteratives_log() {
+    const LOG_PATH: &str = "./tests/solver/jacobian_configuration_log.txt";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        nrf::solver::ProblemSize::new(PROBLEM_SIZE),
+        1e-6,
+        nrf::solver::MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        PROBLEM_SIZE,
+        broyden1965_case5,
+        broyden1965_case5_jac,
+    );
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains(
+        "The jacobian is provided by the model, the finite difference parameters of the iteratives are not used"
+    ));
+    assert!(rf.get_perturbations().is_none());
+}
@@ -0,0 +1,202 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianMatrix, JacobianSource, MatrixFileFormat, MaxIterations, ProblemSize,
+    QuasiNewtonMethod, ResolutionMethod, SolverParameters, UpdateQuasiNewtonMethod,
+};
+
+use crate::common::{read_csv_matrix_file, read_matrix_market_file};
+
+const BROY1: ResolutionMethod = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+));
+
+/// Jacobian at the initial guess, computed with finite differences without the solver
+fn initial_jacobian(
+    iter_params: &iteratives::Iteratives<iteratives::IterativeParamsFD>,
+    res_config: &residuals::ResidualsConfig,
+) -> nalgebra::DMatrix<f64> {
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+    user_model.set_iteratives(&init_broyden1965_case5());
+    user_model.evaluate().unwrap();
+    let mut jacobian = JacobianMatrix::new();
+    nrf::solver::evaluate_jacobian_from_finite_difference(
+        &mut jacobian,
+        &mut user_model,
+        iter_params,
+        res_config,
+        nrf::solver::InaccurateJacobianPolicy::Accept,
+    )
+    .unwrap();
+    jacobian.get_jacobian().clone().unwrap()
+}
+
+#[test]
+fn dump_on_next_computation() {
+    const PATH: &str = "./tests/solver/jacobian_dump_next.csv";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    rf.dump_jacobian_on_next_computation(PATH, MatrixFileFormat::Csv);
+    rf.solve(&mut user_model).unwrap();
+
+    let (iteration, matrix) = read_csv_matrix_file(PATH);
+    std::fs::remove_file(PATH).unwrap();
+    assert_eq!(iteration, 1);
+    assert_eq!(matrix, initial_jacobian(&iter_params, &res_config));
+
+    // the request is discarded once the jacobian written
+    rf.solve(&mut user_model).unwrap();
+    assert!(!std::path::Path::new(PATH).exists());
+}
+
+#[test]
+fn dump_every_computed_jacobian() {
+    const PATH_TEMPLATE: &str = "./tests/solver/jacobian_dump_every_{iter}.mtx";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    rf.dump_every_jacobian(PATH_TEMPLATE, MatrixFileFormat::MatrixMarket);
+    rf.solve(&mut user_model).unwrap();
+
+    assert!(rf.get_iter() > 1);
+    assert!(rf
+        .get_jacobian_sources()
+        .iter()
+        .all(|&source| source == JacobianSource::FiniteDifference));
+    for iter in 1..=rf.get_iter() {
+        let path = PATH_TEMPLATE.replace("{iter}", &iter.to_string());
+        let (iteration, matrix) = read_matrix_market_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(iteration, iter);
+        if iter == 1 {
+            assert_eq!(matrix, initial_jacobian(&iter_params, &res_config));
+        }
+    }
+}
+
+#[test]
+fn approximated_jacobians_not_dumped() {
+    const PATH_TEMPLATE: &str = "./tests/solver/jacobian_dump_approximated_{iter}.csv";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    rf.dump_every_jacobian(PATH_TEMPLATE, MatrixFileFormat::Csv);
+    rf.solve(&mut user_model).unwrap();
+
+    let computed_iterations: Vec<usize> = rf
+        .get_jacobian_sources()
+        .iter()
+        .enumerate()
+        .filter(|(_, &source)| source == JacobianSource::FiniteDifference)
+        .map(|(i, _)| i + 1)
+        .collect();
+    assert_eq!(computed_iterations, vec![1]);
+    for iter in 1..=rf.get_iter() {
+        let path = PATH_TEMPLATE.replace("{iter}", &iter.to_string());
+        let written = std::path::Path::new(&path).exists();
+        assert_eq!(written, computed_iterations.contains(&iter));
+        if written {
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "must contain the {iter} placeholder")]
+fn dump_every_jacobian_without_placeholder() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+
+    rf.dump_every_jacobian("./tests/solver/jacobian.csv", MatrixFileFormat::Csv);
+}
+
+#[test]
+fn dump_into_missing_directory() {
+    const PATH_TEMPLATE: &str = "./tests/solver/missing_directory/jacobian_{iter}.csv";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    rf.dump_every_jacobian(PATH_TEMPLATE, MatrixFileFormat::Csv);
+    rf.solve(&mut user_model).unwrap();
+
+    assert!((&user_model.get_iteratives() - &solution_broyden1965_case5()).amax() < 1e-5);
+    let failures = rf.get_jacobian_dump_failures();
+    assert_eq!(failures.len(), rf.get_iter());
+    for (i, (iteration, failure)) in failures.iter().enumerate() {
+        assert_eq!(*iteration, i + 1);
+        let path = PATH_TEMPLATE.replace("{iter}", &iteration.to_string());
+        assert!(failure.starts_with(&format!("cannot write the jacobian file {}: ", path)));
+    }
+    assert!(!std::path::Path::new("./tests/solver/missing_directory").exists());
+}
@@ -0,0 +1,43 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::broyden1965::*;
+
+use crate::common::{solve_function_fd, Outcome};
+
+const BROYDEN_SECOND_METHOD: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenSecondMethod),
+);
+
+/// Resolution of the case 6 of Broyden with the second method of Broyden,
+/// the damping recomputing the jacobian when a step is refused
+fn solve_case6(damping: Damping) -> Outcome {
+    let parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        1e-6,
+        MaxIterations::new(50),
+        BROYDEN_SECOND_METHOD,
+        damping,
+    );
+    solve_function_fd(broyden1965_case6, init_broyden1965_case6(), parameters)
+}
+
+// The damping refuses the 6th iteration of the Broyden method and forces the recomputation of the jacobian,
+// the 7th iteration converging from the computed jacobian.
+// Without the recomputation, the updated jacobian never leads to the solution.
+#[test]
+fn broyden_case6_recomputed_jacobian() {
+    let (result, iteratives, iter, _) = solve_case6(Damping::Enabled);
+    let (result_without, _, iter_without, _) = solve_case6(Damping::Disabled);
+
+    assert_eq!(result, Ok(()));
+    assert!((&iteratives - &solution_broyden1965_case6()).amax() < 1e-5);
+    assert_eq!(iter, 7);
+
+    assert!(result_without
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(iter_without, 50);
+}
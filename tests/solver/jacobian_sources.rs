@@ -0,0 +1,235 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianSource, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    SolverParameters, UpdateQuasiNewtonMethod,
+};
+
+const BROY1: ResolutionMethod = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+    UpdateQuasiNewtonMethod::BroydenFirstMethod,
+));
+
+const BROY1_UPDATE: JacobianSource = JacobianSource::QuasiNewtonUpdate {
+    method: UpdateQuasiNewtonMethod::BroydenFirstMethod,
+};
+
+type Function = fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>;
+
+fn solve_fd(
+    func: Function,
+    init: nalgebra::DVector<f64>,
+    parameters: SolverParameters,
+) -> Vec<JacobianSource> {
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert_eq!(rf.get_jacobian_sources().len(), rf.get_iter());
+    rf.get_jacobian_sources().to_vec()
+}
+
+#[test]
+fn broyden1_case5_fd() {
+    let init = init_broyden1965_case5();
+    let parameters = SolverParameters::new(
+        ProblemSize::new(init.len()),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    let sources = solve_fd(broyden1965_case5, init, parameters);
+
+    assert_eq!(
+        sources,
+        vec![
+            JacobianSource::FiniteDifference,
+            BROY1_UPDATE,
+            BROY1_UPDATE,
+            BROY1_UPDATE
+        ]
+    );
+}
+
+// The damping rejects a deteriorating step, the last iteration computes the jacobian again
+#[test]
+fn broyden1_case6_fd_damping() {
+    let init = init_broyden1965_case6();
+    let parameters = SolverParameters::new(
+        ProblemSize::new(init.len()),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Enabled,
+    );
+    let sources = solve_fd(broyden1965_case6, init, parameters);
+
+    let mut expected = vec![JacobianSource::FiniteDifference];
+    expected.extend(vec![BROY1_UPDATE; 6]);
+    expected.push(JacobianSource::FiniteDifference);
+    assert_eq!(sources, expected);
+}
+
+// The safeguard of the inverse updates does not trigger any recomputation on the standard cases
+#[test]
+fn inverse_broyden_cases_fd() {
+    let broyden1 = UpdateQuasiNewtonMethod::BroydenFirstMethod;
+    let broyden2 = UpdateQuasiNewtonMethod::BroydenSecondMethod;
+    let cases: [(UpdateQuasiNewtonMethod, Function, nalgebra::DVector<f64>); 4] = [
+        (broyden1, broyden1965_case5, init_broyden1965_case5()),
+        (broyden1, broyden1965_case6, init_broyden1965_case6()),
+        (broyden1, broyden1965_case7, init_broyden1965_case7()),
+        (broyden2, broyden1965_case5, init_broyden1965_case5()),
+    ];
+
+    for (method, func, init) in cases {
+        let resolution_method =
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(method));
+        let parameters = SolverParameters::new(
+            ProblemSize::new(init.len()),
+            1e-6,
+            MaxIterations::new(50),
+            resolution_method,
+            Damping::Disabled,
+        );
+        let sources = solve_fd(func, init, parameters);
+
+        assert_eq!(sources[0], JacobianSource::FiniteDifference);
+        assert!(sources[1..]
+            .iter()
+            .all(|source| *source == JacobianSource::QuasiNewtonUpdate { method }));
+    }
+}
+
+#[test]
+fn broyden1_case5_fd_warmup() {
+    let init = init_broyden1965_case5();
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(init.len()),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Disabled,
+    );
+    parameters.set_newton_warmup_iterations(2);
+    let sources = solve_fd(broyden1965_case5, init, parameters);
+
+    assert_eq!(sources[0], JacobianSource::FiniteDifference);
+    assert_eq!(sources[1], JacobianSource::FiniteDifference);
+    assert!(sources[2..].iter().all(|source| *source == BROY1_UPDATE));
+}
+
+#[test]
+fn stationary_newton_case5_fd() {
+    let init = init_broyden1965_case5();
+    let parameters = SolverParameters::new(
+        ProblemSize::new(init.len()),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+        Damping::Disabled,
+    );
+    let sources = solve_fd(broyden1965_case5, init, parameters);
+
+    assert_eq!(sources[0], JacobianSource::FiniteDifference);
+    assert!(sources.len() > 1);
+    assert!(sources[1..]
+        .iter()
+        .all(|source| *source == JacobianSource::Reused));
+}
+
+#[test]
+fn newton_raphson_case5_analytical() {
+    let init = init_broyden1965_case5();
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        problem_size,
+        broyden1965_case5,
+        broyden1965_case5_jac,
+    );
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert_eq!(
+        rf.get_jacobian_sources(),
+        vec![JacobianSource::AnalyticalModel; rf.get_iter()].as_slice()
+    );
+    assert_eq!(
+        rf.get_jacobian_source_counts(),
+        vec![(JacobianSource::AnalyticalModel, rf.get_iter())]
+    );
+}
+
+#[test]
+fn broyden1_case6_batch_counts() {
+    let init = init_broyden1965_case6();
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        BROY1,
+        Damping::Enabled,
+    );
+    let mut models = vec![nrf::model::UserModelFromFunction::new(
+        problem_size,
+        broyden1965_case6,
+    )];
+
+    let reports =
+        nrf::solver::solve_batch(parameters, &iter_params, &res_config, &[init], &mut models);
+
+    let report = reports[0].as_ref().unwrap();
+    assert_eq!(
+        report.get_jacobian_source_counts(),
+        &[(JacobianSource::FiniteDifference, 2), (BROY1_UPDATE, 6)]
+    );
+}
+
+#[test]
+fn jacobian_source_display() {
+    assert_eq!(
+        JacobianSource::AnalyticalModel.to_string(),
+        "Analytical (model)"
+    );
+    assert_eq!(
+        JacobianSource::FiniteDifference.to_string(),
+        "Finite difference"
+    );
+    assert_eq!(JacobianSource::Reused.to_string(), "Reused");
+    assert_eq!(
+        JacobianSource::ScalarFiniteDifference.to_string(),
+        "Scalar finite difference (Gauss-Seidel)"
+    );
+    assert!(BROY1_UPDATE
+        .to_string()
+        .starts_with("Quasi-Newton update ("));
+}
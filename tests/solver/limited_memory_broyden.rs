@@ -0,0 +1,153 @@
+use newton_rootfinder as nrf;
+
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+
+/// Weakly coupled problem whose jacobian is diagonal at the origin:
+/// the finite difference jacobian computed there is the diagonal seed of the limited memory method
+fn coupled_cubic(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let n = x.len();
+    nalgebra::DVector::from_fn(n, |i, _| {
+        let next = x[(i + 1) % n];
+        2.0 * x[i] + 0.5 * x[i].powi(3) + 0.1 * x[i] * next * next - (0.5 + 0.05 * (i % 10) as f64)
+    })
+}
+
+/// Model recording every point at which it is evaluated
+///
+/// Its memory restores the reference point after each column of the finite differences,
+/// the full jacobian and its diagonal being then evaluated from the same state
+struct RecordingModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    evaluated_points: Vec<nalgebra::DVector<f64>>,
+}
+
+impl Model<nalgebra::Dyn> for RecordingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        self.iteratives.len()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = coupled_cubic(&self.iteratives);
+        self.evaluated_points.push(self.iteratives.clone());
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(
+            self.left.clone(),
+            nalgebra::DVector::zeros(self.left.len()),
+        )
+    }
+
+    fn get_memory(&self) -> nalgebra::DVector<f64> {
+        nalgebra::DVector::from_iterator(
+            2 * self.iteratives.len(),
+            self.iteratives.iter().chain(self.left.iter()).copied(),
+        )
+    }
+
+    fn set_memory(&mut self, memory: &nalgebra::DVector<f64>) {
+        let problem_size = self.iteratives.len();
+        self.iteratives.copy_from(&memory.rows(0, problem_size));
+        self.left
+            .copy_from(&memory.rows(problem_size, problem_size));
+    }
+}
+
+/// Solve from the origin, returning the points evaluated by the model and the final iteratives
+fn solve_recording(
+    problem_size: usize,
+    resolution_method: ResolutionMethod,
+) -> (Vec<nalgebra::DVector<f64>>, nalgebra::DVector<f64>) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    );
+    let init = nalgebra::DVector::zeros(problem_size);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = RecordingModel {
+        iteratives: nalgebra::DVector::zeros(problem_size),
+        left: nalgebra::DVector::zeros(problem_size),
+        evaluated_points: Vec::new(),
+    };
+
+    rf.solve(&mut user_model).unwrap();
+
+    (user_model.evaluated_points, user_model.iteratives)
+}
+
+// The seed being the exact jacobian at the initial guess, the iterates are the ones of the full method
+#[test]
+fn same_iterates_as_broyden2_inv() {
+    let full = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenSecondMethod,
+    ));
+    let limited =
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 50 });
+
+    let (full_points, _) = solve_recording(20, full);
+    let (limited_points, _) = solve_recording(20, limited);
+
+    // the first evaluations are the ones of the finite differences
+    assert!(full_points.len() > 21);
+    assert_eq!(full_points.len(), limited_points.len());
+    for (full_point, limited_point) in full_points.iter().zip(limited_points.iter()) {
+        assert!((full_point - limited_point).amax() < 1e-12);
+    }
+}
+
+// The storage of a jacobian of this size would be 32 MB and its factorization prohibitive in debug
+#[test]
+fn bounded_memory_large_problem() {
+    let problem_size = 2000;
+    let limited =
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 5 });
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let init = nalgebra::DVector::zeros(problem_size);
+    let mut rf = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        limited,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, coupled_cubic);
+
+    let start = std::time::Instant::now();
+    rf.solve(&mut user_model).unwrap();
+    let solution = user_model.get_iteratives();
+
+    assert!(coupled_cubic(&solution).amax() < 1e-6);
+    assert!(start.elapsed() < std::time::Duration::from_secs(30));
+}
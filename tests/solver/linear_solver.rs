@@ -0,0 +1,150 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, LinearSolver, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    RootFinder, SolverParameters, UpdateQuasiNewtonMethod,
+};
+use util::test_cases::broyden1965::*;
+
+const PROBLEM_SIZE: usize = 20;
+
+const GMRES: LinearSolver = LinearSolver::Gmres {
+    restart: 20,
+    max_iter: 100,
+    tol: 1e-12,
+};
+
+/// Resolution with damping of the case 8 of Broyden with the analytical jacobian, returning the final iteratives
+fn solve_case8(
+    max_iter: usize,
+    resolution_method: ResolutionMethod,
+    linear_solver: LinearSolver,
+) -> (Result<(), String>, nalgebra::DVector<f64>) {
+    let vec_iter_params = iteratives::default_vec_iteratives(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-6,
+        MaxIterations::new(max_iter),
+        resolution_method,
+        Damping::Enabled,
+    );
+    parameters.set_linear_solver(linear_solver);
+    let mut rf = RootFinder::new(
+        parameters,
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        PROBLEM_SIZE,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.to_string());
+    (result, user_model.get_iteratives())
+}
+
+fn assert_close(values: &nalgebra::DVector<f64>, expected: &nalgebra::DVector<f64>, epsilon: f64) {
+    for i in 0..PROBLEM_SIZE {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            values[i],
+            expected[i],
+            epsilon = epsilon
+        ));
+    }
+}
+
+#[test]
+fn gmres_step_matches_direct_solve() {
+    let (direct_result, direct_step) = solve_case8(
+        1,
+        ResolutionMethod::NewtonRaphson,
+        LinearSolver::DirectInverse,
+    );
+    let (gmres_result, gmres_step) = solve_case8(1, ResolutionMethod::NewtonRaphson, GMRES);
+
+    assert!(direct_result
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    // same unconverged residuals, with errors equal up to the rounding errors
+    assert_eq!(
+        gmres_result.as_ref().unwrap_err().split(',').next(),
+        direct_result.as_ref().unwrap_err().split(',').next()
+    );
+    assert_close(&gmres_step, &direct_step, 1e-10);
+}
+
+#[test]
+fn gmres_newton_raphson() {
+    let (result, solution) = solve_case8(50, ResolutionMethod::NewtonRaphson, GMRES);
+
+    assert_eq!(result, Ok(()));
+    assert_close(&solution, &solution_broyden1965_case8(), 1e-6);
+}
+
+#[test]
+fn gmres_jacobian_update() {
+    let resolution_method = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ));
+    let (result, solution) = solve_case8(50, resolution_method, GMRES);
+
+    assert_eq!(result, Ok(()));
+    assert_close(&solution, &solution_broyden1965_case8(), 1e-6);
+}
+
+#[test]
+fn gmres_not_converged() {
+    let linear_solver = LinearSolver::Gmres {
+        restart: 1,
+        max_iter: 1,
+        tol: 1e-12,
+    };
+    let (result, _) = solve_case8(50, ResolutionMethod::NewtonRaphson, linear_solver);
+
+    assert_eq!(
+        result,
+        Err("Jacobian error: The linear solver did not reach its tolerance".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot be used with the resolution method")]
+fn gmres_inverse_jacobian_update() {
+    let resolution_method = ResolutionMethod::QuasiNewton(
+        QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+    );
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    );
+    parameters.set_linear_solver(GMRES);
+}
+
+#[test]
+#[should_panic(expected = "cannot be used with the resolution method")]
+fn gmres_greenstadt_second_method() {
+    let resolution_method = ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+        UpdateQuasiNewtonMethod::GreenstadtSecondMethod,
+    ));
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    );
+    parameters.set_linear_solver(GMRES);
+}
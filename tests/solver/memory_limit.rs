@@ -0,0 +1,251 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::errors::RootFinderConstructionError;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    estimate_memory_bytes, Damping, LinearSolver, MaxIterations, ProblemSize, QuasiNewtonMethod,
+    ResolutionMethod, RootFinder, SolverParameters, UpdateQuasiNewtonMethod,
+};
+
+const GMRES: LinearSolver = LinearSolver::Gmres {
+    restart: 30,
+    max_iter: 300,
+    tol: 1e-10,
+};
+
+fn parameters(problem_size: usize, resolution_method: ResolutionMethod) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    )
+}
+
+/// Error returned by `RootFinder::try_new` for a problem of the given size
+fn construction_error(parameters: SolverParameters) -> Option<RootFinderConstructionError> {
+    let problem_size = parameters.get_problem_size().unwrap();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    RootFinder::try_new(
+        parameters,
+        nalgebra::DVector::zeros(problem_size),
+        &iter_params,
+        &res_config,
+    )
+    .err()
+}
+
+#[test]
+fn estimates() {
+    // jacobian, inverse and LU workspace
+    assert_eq!(
+        estimate_memory_bytes(
+            3000,
+            ResolutionMethod::NewtonRaphson,
+            LinearSolver::DirectInverse
+        ),
+        216_000_000
+    );
+    // the inverse updates still compute the jacobian
+    assert_eq!(
+        estimate_memory_bytes(
+            1000,
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+                UpdateQuasiNewtonMethod::BroydenSecondMethod
+            )),
+            LinearSolver::DirectInverse
+        ),
+        24_000_000
+    );
+    // jacobian, LU workspace, 31 Krylov vectors and a 31x30 Hessenberg matrix
+    assert_eq!(
+        estimate_memory_bytes(3000, ResolutionMethod::NewtonRaphson, GMRES),
+        144_000_000 + 31 * 3000 * 8 + 31 * 30 * 8
+    );
+    assert_eq!(
+        estimate_memory_bytes(
+            100,
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton),
+            GMRES
+        ),
+        160_000 + 31 * 130 * 8
+    );
+    // diagonal seed, 10 pairs of steps and 10 corrections
+    assert_eq!(
+        estimate_memory_bytes(
+            3000,
+            ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 10 }),
+            LinearSolver::DirectInverse
+        ),
+        31 * 3000 * 8
+    );
+    assert_eq!(
+        estimate_memory_bytes(
+            3000,
+            ResolutionMethod::NonlinearGaussSeidel {
+                inner_scalar_iterations: 5
+            },
+            LinearSolver::DirectInverse
+        ),
+        0
+    );
+}
+
+#[test]
+fn estimate_of_the_rootfinder() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::with_inferred_size(
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_linear_solver(GMRES);
+    let rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    );
+
+    assert_eq!(
+        rf.estimated_memory_bytes(),
+        estimate_memory_bytes(2, ResolutionMethod::NewtonRaphson, GMRES)
+    );
+}
+
+#[test]
+fn no_limit_by_default() {
+    let parameters = parameters(3000, ResolutionMethod::NewtonRaphson);
+    assert_eq!(parameters.get_memory_limit_bytes(), None);
+    assert_eq!(construction_error(parameters), None);
+}
+
+#[test]
+fn limit_exceeded() {
+    let mut parameters = parameters(3000, ResolutionMethod::NewtonRaphson);
+    parameters.set_memory_limit_bytes(Some(100_000_000));
+
+    let error = construction_error(parameters);
+    assert_eq!(
+        error,
+        Some(RootFinderConstructionError::MemoryLimitExceeded {
+            estimated_bytes: 216_000_000,
+            limit_bytes: 100_000_000,
+        })
+    );
+    assert!(error.unwrap().to_string().contains("LimitedMemoryBroyden"));
+}
+
+#[test]
+fn limit_reached_accepted() {
+    let mut parameters = parameters(3000, ResolutionMethod::NewtonRaphson);
+    parameters.set_memory_limit_bytes(Some(216_000_000));
+    assert_eq!(construction_error(parameters), None);
+}
+
+#[test]
+fn reduced_storage_accepted() {
+    let limited_memory =
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 10 });
+    let mut parameters = parameters(3000, limited_memory);
+    parameters.set_memory_limit_bytes(Some(100_000_000));
+    assert_eq!(construction_error(parameters), None);
+
+    let mut parameters = self::parameters(3000, ResolutionMethod::NewtonRaphson);
+    parameters.set_linear_solver(GMRES);
+    parameters.set_memory_limit_bytes(Some(150_000_000));
+    assert_eq!(construction_error(parameters), None);
+}
+
+#[test]
+fn resolution_within_limit() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = parameters(2, ResolutionMethod::NewtonRaphson);
+    parameters.set_memory_limit_bytes(Some(3 * 2 * 2 * 8));
+    parameters.set_damping(Damping::Enabled);
+
+    let mut rf = RootFinder::new(
+        parameters,
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    rf.solve(&mut user_model).unwrap();
+
+    let solution = solution_broyden1965_case10();
+    for i in 0..2 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            user_model.get_iteratives()[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "above the memory limit")]
+fn resolution_method_exceeding_limit() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(100);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 100];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 100];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = parameters(
+        100,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 10 }),
+    );
+    parameters.set_memory_limit_bytes(Some(100_000));
+    let mut rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(100),
+        &iter_params,
+        &res_config,
+    );
+
+    rf.set_resolution_method(ResolutionMethod::NewtonRaphson);
+}
+
+#[test]
+#[should_panic(expected = "above the memory limit")]
+fn fallback_exceeding_limit() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(100);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 100];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 100];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = parameters(
+        100,
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 10 }),
+    );
+    parameters.set_memory_limit_bytes(Some(100_000));
+    let mut rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(100),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model =
+        nrf::model::UserModelFromFunction::new(100, |x: &nalgebra::DVector<f64>| x.clone());
+
+    let _ = rf.solve_with_fallback(&mut user_model, &[ResolutionMethod::NewtonRaphson]);
+}
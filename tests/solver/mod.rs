@@ -1,10 +1,61 @@
+pub mod aitken_acceleration;
+pub mod batch;
+pub mod bounds;
 pub mod broyden1_inv_jac;
 pub mod broyden1_jac;
 pub mod broyden2_inv_jac;
 pub mod broyden2_jac;
+pub mod budget;
+pub mod compatibility;
+pub mod constant_jacobian_columns;
+pub mod construction;
+pub mod convergence_predicate;
+pub mod convergence_rate;
+pub mod cycle_detection;
+pub mod default_guess;
+pub mod error_smoothing;
+pub mod errors_over_tolerance;
+pub mod evaluation_sequences;
+pub mod fallback;
+pub mod first_iteration_step_scale;
+pub mod fixed_iteratives;
 pub mod greenstadt1_inv_jac;
 pub mod greenstadt1_jac;
 pub mod greenstadt2_inv_jac;
 pub mod greenstadt2_jac;
+#[cfg(feature = "std")]
+pub mod history;
+pub mod implicit_euler;
+pub mod inaccurate_jacobian;
+pub mod initial_errors;
+pub mod initial_guess_converged;
+pub mod jacobian_configuration;
+#[cfg(feature = "std")]
+pub mod jacobian_dump;
+pub mod jacobian_recomputation;
+pub mod jacobian_sources;
+pub mod limited_memory_broyden;
+pub mod linear_solver;
+pub mod memory_limit;
+pub mod multi_start;
 pub mod newton_raphson;
+pub mod newton_step;
+pub mod newton_warmup;
+pub mod nonlinear_gauss_seidel;
+pub mod parameters_update;
+pub mod perturbation_jitter;
+pub mod pipe_flow;
+pub mod polish;
+pub mod presets;
+pub mod quick_solver;
+pub mod residuals_groups;
+pub mod right_overrides;
+pub mod scalar_safeguard;
+pub mod schubert;
+pub mod snapshot;
+pub mod solver_report;
 pub mod stationary_newton;
+pub mod step_acceptance;
+pub mod step_tolerance;
+pub mod structural_singularity;
+pub mod tolerance_floor;
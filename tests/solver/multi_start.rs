@@ -0,0 +1,139 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    distinct_roots, uniform_guesses, Damping, MaxIterations, ProblemSize, ResolutionMethod,
+    SolverParameters,
+};
+
+/// Equations `x^2 = 2` and `y^2 = 3`, with four roots
+fn four_roots(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] - 2.0, x[1] * x[1] - 3.0])
+}
+
+fn bounds() -> (nalgebra::DVector<f64>, nalgebra::DVector<f64>) {
+    (
+        nalgebra::DVector::from_vec(vec![-3.0, -4.0]),
+        nalgebra::DVector::from_vec(vec![3.0, 4.0]),
+    )
+}
+
+type BatchResults = Vec<
+    Result<
+        nrf::solver::SolverReport,
+        nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>,
+    >,
+>;
+
+/// Solve the guesses with `batch`, either `solve_batch()` or `solve_batch_parallel()`
+fn solve(
+    initial_guesses: &[nalgebra::DVector<f64>],
+    batch: impl FnOnce(
+        SolverParameters,
+        &iteratives::Iteratives<iteratives::IterativeParamsFD>,
+        &residuals::ResidualsConfig,
+        &[nalgebra::DVector<f64>],
+        &mut [nrf::model::UserModelFromFunction],
+    ) -> BatchResults,
+) -> Vec<Result<nrf::solver::SolverReport, String>> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut models: Vec<_> = (0..initial_guesses.len())
+        .map(|_| nrf::model::UserModelFromFunction::new(2, four_roots))
+        .collect();
+
+    batch(
+        parameters,
+        &iter_params,
+        &res_config,
+        initial_guesses,
+        &mut models,
+    )
+    .into_iter()
+    .map(|result| result.map_err(|error| error.to_string()))
+    .collect()
+}
+
+#[test]
+fn seeded_guesses() {
+    let lower = nalgebra::DVector::from_vec(vec![-3.0]);
+    let upper = nalgebra::DVector::from_vec(vec![3.0]);
+    let guesses: Vec<f64> = uniform_guesses(&lower, &upper, 3, 42)
+        .iter()
+        .map(|guess| guess[0])
+        .collect();
+
+    assert_eq!(
+        guesses,
+        [1.44938927263094, -2.0405376427384794, -1.328393218469168]
+    );
+}
+
+#[test]
+fn guesses_reproducible() {
+    let (lower, upper) = bounds();
+    let guesses = uniform_guesses(&lower, &upper, 50, 7);
+
+    assert_eq!(guesses, uniform_guesses(&lower, &upper, 50, 7));
+    assert_ne!(guesses, uniform_guesses(&lower, &upper, 50, 8));
+    // the first guesses don't depend on the number of guesses
+    assert_eq!(guesses[..10], uniform_guesses(&lower, &upper, 10, 7)[..]);
+    assert!(guesses
+        .iter()
+        .all(|guess| (0..2).all(|i| lower[i] <= guess[i] && guess[i] < upper[i])));
+}
+
+#[test]
+#[should_panic(expected = "The lower bound 3 of the component 0 is above its upper bound -3")]
+fn guesses_inverted_bounds() {
+    let (lower, upper) = bounds();
+    uniform_guesses(&upper, &lower, 1, 0);
+}
+
+#[test]
+fn roots_in_order_of_the_guesses() {
+    let (lower, upper) = bounds();
+    let initial_guesses = uniform_guesses(&lower, &upper, 40, 3);
+    let results = solve(&initial_guesses, nrf::solver::solve_batch);
+
+    let roots = distinct_roots(&results, 1e-6);
+    assert_eq!(roots.len(), 4);
+    assert!(roots.windows(2).all(|pair| pair[0] < pair[1]));
+    // each root is given by the first guess converging to it
+    for (i, result) in results.iter().enumerate() {
+        let Ok(report) = result else { continue };
+        let first = roots
+            .iter()
+            .find(|&&root| {
+                let root_iteratives = results[root].as_ref().unwrap().get_final_iteratives();
+                (report.get_final_iteratives() - root_iteratives).amax() <= 1e-6
+            })
+            .unwrap();
+        assert!(*first <= i);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_runs_identical() {
+    let (lower, upper) = bounds();
+    let initial_guesses = uniform_guesses(&lower, &upper, 200, 11);
+    let expected = solve(&initial_guesses, nrf::solver::solve_batch);
+    let expected_roots = distinct_roots(&expected, 1e-6);
+
+    for _ in 0..5 {
+        let results = solve(&initial_guesses, nrf::solver::solve_batch_parallel);
+        assert_eq!(results, expected);
+        assert_eq!(distinct_roots(&results, 1e-6), expected_roots);
+    }
+}
@@ -6,7 +6,7 @@ use crate::common::{run_function_case_fd, run_function_case_jac};
 #[test]
 fn broyden_case5_fd() {
     let problem_size = 5;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case5,
@@ -20,7 +20,7 @@ fn broyden_case5_fd() {
 #[test]
 fn broyden_case5_jac() {
     let problem_size = 5;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case5,
@@ -35,7 +35,7 @@ fn broyden_case5_jac() {
 #[test]
 fn broyden_case6_fd() {
     let problem_size = 5;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case6,
@@ -49,7 +49,7 @@ fn broyden_case6_fd() {
 #[test]
 fn broyden_case6_jac() {
     let problem_size = 5;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case6,
@@ -64,7 +64,7 @@ fn broyden_case6_jac() {
 #[test]
 fn broyden_case7_fd() {
     let problem_size = 10;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case7,
@@ -78,7 +78,7 @@ fn broyden_case7_fd() {
 #[test]
 fn broyden_case7_jac() {
     let problem_size = 10;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case7,
@@ -93,7 +93,7 @@ fn broyden_case7_jac() {
 #[test]
 fn broyden_case8_fd() {
     let problem_size = 20;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case8,
@@ -107,7 +107,7 @@ fn broyden_case8_fd() {
 #[test]
 fn broyden_case8_jac() {
     let problem_size = 20;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case8,
@@ -122,7 +122,7 @@ fn broyden_case8_jac() {
 #[test]
 fn broyden_case9_fd() {
     let problem_size = 2;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case9,
@@ -136,7 +136,7 @@ fn broyden_case9_fd() {
 #[test]
 fn broyden_case9_jac() {
     let problem_size = 2;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case9,
@@ -7,7 +7,7 @@ use util::test_cases::polynom;
 #[test]
 fn square() {
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::square2,
@@ -21,7 +21,7 @@ fn square() {
 #[test]
 fn root_with_high_derivative() {
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::root_with_high_derivative,
@@ -35,7 +35,7 @@ fn root_with_high_derivative() {
 #[test]
 fn square_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::square2,
@@ -50,7 +50,7 @@ fn square_jac() {
 #[test]
 fn root_with_high_derivative_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::root_with_high_derivative,
@@ -0,0 +1,192 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::errors::NewtonSystemError;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    BoundPolicy, Damping, JacobianMatrix, LinearSolver, MaxIterations, ProblemSize,
+    ResolutionMethod, SolverParameters,
+};
+
+/// Iteratives of the model after the first iteration of the Newton-Raphson method
+fn first_iteration<M, T>(
+    model: &mut M,
+    init: nalgebra::DVector<f64>,
+    iter_params: &iteratives::Iteratives<T>,
+    res_config: &residuals::ResidualsConfig,
+) -> nalgebra::DVector<f64>
+where
+    M: Model<nalgebra::Dyn>,
+    T: iteratives::Iterative + std::fmt::Display + std::fmt::Debug,
+{
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(init.len()),
+        1e-6,
+        MaxIterations::new(1),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    // the step is computed from the initial guess as given, as by `compute_newton_step()`
+    parameters.set_bound_policy(BoundPolicy::Ignore);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, iter_params, res_config);
+    // the maximum number of iterations is reached
+    assert!(rf.solve(model).is_err());
+    assert_eq!(rf.get_iter(), 1);
+    model.get_iteratives()
+}
+
+#[test]
+fn finite_difference_step_identical_to_rootfinder() {
+    let vec_iter_params = vec![
+        iteratives::IterativeParamsFD::new(
+            5.0,
+            2.0,
+            1.0,
+            15.0,
+            5e-8,
+            5e-8,
+            iteratives::PerturbationMethod::Max,
+        ),
+        iteratives::IterativeParamsFD::new(
+            5.0,
+            f64::INFINITY,
+            3.0,
+            10.0,
+            5e-8,
+            5e-8,
+            iteratives::PerturbationMethod::Max,
+        ),
+    ];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    let expected = first_iteration(
+        &mut model,
+        init_broyden1965_case10(),
+        &iter_params,
+        &res_config,
+    );
+
+    let mut model = nrf::model::UserModelFromFunction::new(2, broyden1965_case10);
+    model.set_iteratives(&init_broyden1965_case10());
+    model.evaluate().unwrap();
+    let mut jacobian = JacobianMatrix::new();
+    let next =
+        nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian)
+            .unwrap();
+
+    assert_eq!(next, expected);
+    assert!(!jacobian.compute_jacobian());
+}
+
+#[test]
+fn analytical_step_identical_to_rootfinder() {
+    let vec_iter_params = iteratives::default_vec_iteratives(20);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 20];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 20];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        20,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+    let expected = first_iteration(
+        &mut model,
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+
+    let mut model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        20,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+    model.set_iteratives(&init_broyden1965_case8());
+    model.evaluate().unwrap();
+    let mut jacobian = JacobianMatrix::new();
+    let next =
+        nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian)
+            .unwrap();
+
+    assert_eq!(next, expected);
+}
+
+#[test]
+fn jacobian_reused_until_forced() {
+    let vec_iter_params = iteratives::default_vec_iteratives(20);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 20];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 20];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        20,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+    model.set_iteratives(&init_broyden1965_case8());
+    model.evaluate().unwrap();
+    let mut jacobian = JacobianMatrix::new();
+
+    let next =
+        nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian)
+            .unwrap();
+    let initial_jacobian = jacobian.get_jacobian().clone();
+    model.set_iteratives(&next);
+    model.evaluate().unwrap();
+
+    // stationary Newton step, with the jacobian of the initial guess
+    nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian).unwrap();
+    assert_eq!(jacobian.get_jacobian(), &initial_jacobian);
+
+    jacobian.force_jacobian_computation();
+    nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian).unwrap();
+    assert_eq!(
+        jacobian.get_jacobian().as_ref().unwrap(),
+        &broyden1965_case8_jac(&next)
+    );
+}
+
+#[test]
+fn missing_inverse() {
+    let vec_iter_params = iteratives::default_vec_iteratives(20);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 20];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 20];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut model = nrf::model::UserModelFromFunctionAndJacobian::new(
+        20,
+        broyden1965_case8,
+        broyden1965_case8_jac,
+    );
+    model.set_iteratives(&init_broyden1965_case8());
+    model.evaluate().unwrap();
+    let mut jacobian = JacobianMatrix::new();
+
+    assert_eq!(
+        nrf::solver::solve_newton_system(
+            &jacobian,
+            &nalgebra::DVector::zeros(20),
+            LinearSolver::DirectInverse
+        ),
+        Err(NewtonSystemError::MissingMatrix)
+    );
+
+    // the step is solved with the inverse of the jacobian
+    jacobian.set_inverse_required(false);
+    let error =
+        nrf::solver::compute_newton_step(&mut model, &iter_params, &res_config, &mut jacobian)
+            .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The jacobian does not hold the matrix required by the linear solver"
+    );
+    assert!(jacobian.get_jacobian().is_some());
+}
@@ -0,0 +1,160 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::broyden1965::*;
+
+use crate::common::{solve_function_fd, Outcome};
+
+const STATIONARY_NEWTON: ResolutionMethod =
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton);
+
+const BROYDEN_FIRST_METHOD: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+);
+
+// From x = 1, the jacobian of the initial guess is 0.5:
+// the stationary Newton iteration x - 2 * atan(x) oscillates around 0 with a slowly decreasing amplitude.
+// The jacobian of the second Newton-Raphson iterate is close to the one of the solution.
+fn arctangent(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(f64::atan)
+}
+
+fn parameters(
+    problem_size: usize,
+    resolution_method: ResolutionMethod,
+    newton_warmup_iterations: usize,
+) -> SolverParameters {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    );
+    parameters.set_newton_warmup_iterations(newton_warmup_iterations);
+    parameters
+}
+
+fn solve_arctangent(newton_warmup_iterations: usize) -> Outcome {
+    solve_function_fd(
+        arctangent,
+        nalgebra::DVector::from_vec(vec![1.0]),
+        parameters(1, STATIONARY_NEWTON, newton_warmup_iterations),
+    )
+}
+
+#[test]
+fn stationary_newton_stalls() {
+    assert!(solve_arctangent(0)
+        .0
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    // the jacobian is still the one of the initial guess
+    assert!(solve_arctangent(1)
+        .0
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+}
+
+#[test]
+fn stationary_newton_with_warmup() {
+    let (result, iteratives, _, _) = solve_arctangent(2);
+
+    assert_eq!(result, Ok(()));
+    assert!(iteratives[0].abs() < 1e-6);
+}
+
+#[test]
+fn no_warmup_unchanged() {
+    for resolution_method in [STATIONARY_NEWTON, BROYDEN_FIRST_METHOD] {
+        let default_parameters = SolverParameters::new(
+            ProblemSize::new(5),
+            1e-6,
+            MaxIterations::new(50),
+            resolution_method,
+            Damping::Disabled,
+        );
+        assert_eq!(
+            solve_function_fd(
+                broyden1965_case6,
+                init_broyden1965_case6(),
+                parameters(5, resolution_method, 0)
+            ),
+            solve_function_fd(
+                broyden1965_case6,
+                init_broyden1965_case6(),
+                default_parameters
+            )
+        );
+    }
+}
+
+// With a warmup covering all the iterations, the resolution is the Newton-Raphson one
+#[test]
+fn warmup_longer_than_resolution() {
+    assert_eq!(
+        solve_function_fd(
+            broyden1965_case6,
+            init_broyden1965_case6(),
+            parameters(5, STATIONARY_NEWTON, 50)
+        ),
+        solve_function_fd(
+            broyden1965_case6,
+            init_broyden1965_case6(),
+            parameters(5, ResolutionMethod::NewtonRaphson, 0)
+        )
+    );
+}
+
+#[test]
+fn broyden_with_warmup() {
+    let (result, iteratives, _, _) = solve_function_fd(
+        broyden1965_case6,
+        init_broyden1965_case6(),
+        parameters(5, BROYDEN_FIRST_METHOD, 2),
+    );
+
+    assert_eq!(result, Ok(()));
+    let solution = solution_broyden1965_case6();
+    for i in 0..5 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn end_of_warmup_in_log() {
+    const LOG_PATH: &str = "./tests/solver/newton_warmup_log.txt";
+    let vec_iter_params = nrf::iteratives::default_vec_iteratives_fd(1);
+    let iter_params = nrf::iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![nrf::residuals::NormalizationMethod::Abs];
+    let update_methods = vec![nrf::residuals::NormalizationMethod::Abs];
+    let res_config = nrf::residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(1, STATIONARY_NEWTON, 2),
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    let mut user_model = nrf::model::UserModelFromFunction::new(1, arctangent);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("Newton warmup iterations: 2\n"));
+    let switch = log
+        .find("End of the Newton warmup after 2 iterations, switching to Quasi Newton: Jacobian matrix behavior: Frozen Jacobian matrix\n")
+        .unwrap();
+    // the switch is written before the third iteration
+    assert!(log[..switch].contains("Iteration: 2\n"));
+    assert!(!log[..switch].contains("Iteration: 3\n"));
+}
@@ -0,0 +1,280 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianSource, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters,
+};
+
+const GAUSS_SEIDEL: ResolutionMethod = ResolutionMethod::NonlinearGaussSeidel {
+    inner_scalar_iterations: 3,
+};
+
+/// Diagonally dominant system: each equation mostly depends on its own iterative
+fn loosely_coupled(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let n = x.len();
+    let mut y = nalgebra::DVector::zeros(n);
+    for i in 0..n {
+        let previous = if i > 0 { x[i - 1] } else { 0.0 };
+        let next = if i + 1 < n { x[i + 1] } else { 0.0 };
+        y[i] = 5.0 * x[i] + x[i].powi(3) + 0.5 * (previous + next) - (i as f64 + 1.0);
+    }
+    y
+}
+
+/// Linear system whose Gauss-Seidel iteration diverges, with the root (1, 1)
+fn strongly_coupled(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] + 2.0 * x[1] - 3.0, 2.0 * x[0] + x[1] - 3.0])
+}
+
+fn solve(
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    init: nalgebra::DVector<f64>,
+    parameters: SolverParameters,
+    iter_params: &[iteratives::IterativeParamsFD],
+) -> (
+    nalgebra::DVector<f64>,
+    usize,
+    Result<(), nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>>,
+) {
+    let problem_size = init.len();
+    let iter_params = iteratives::Iteratives::new(iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+    let result = rf.solve(&mut user_model);
+    (user_model.get_iteratives(), rf.get_iter(), result)
+}
+
+#[test]
+fn gauss_seidel_loosely_coupled() {
+    let problem_size = 5;
+    let init = nalgebra::DVector::zeros(problem_size);
+    let iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(50),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let (solution, iter, result) = solve(loosely_coupled, init.clone(), parameters, &iter_params);
+    result.unwrap();
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let (reference, _, result) = solve(loosely_coupled, init, parameters, &iter_params);
+    result.unwrap();
+
+    assert!(iter < 50);
+    for i in 0..problem_size {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            solution[i],
+            reference[i],
+            epsilon = 1e-7
+        ));
+    }
+    assert!(loosely_coupled(&solution).amax() <= 1e-8);
+}
+
+// Each scalar step is limited to 0.1, the first sweep can then move each iterative by 0.3 at most
+#[test]
+fn gauss_seidel_step_limitation() {
+    let problem_size = 5;
+    let init = nalgebra::DVector::zeros(problem_size);
+    let iter_params = vec![
+        iteratives::IterativeParamsFD::new(
+            0.1,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            5e-8,
+            5e-8,
+            iteratives::PerturbationMethod::Max,
+        );
+        problem_size
+    ];
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(1),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let (first_sweep, iter, result) =
+        solve(loosely_coupled, init.clone(), parameters, &iter_params);
+    assert!(result.is_err());
+    assert_eq!(iter, 1);
+    for i in 0..problem_size {
+        assert!(first_sweep[i].abs() <= 0.3 + 1e-12);
+    }
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(50),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let (solution, _, result) = solve(loosely_coupled, init, parameters, &iter_params);
+    result.unwrap();
+    assert!(loosely_coupled(&solution).amax() <= 1e-8);
+}
+
+// The root of the first equation is above the upper bound of its iterative
+#[test]
+fn gauss_seidel_bounds() {
+    let problem_size = 5;
+    let init = nalgebra::DVector::zeros(problem_size);
+    let mut iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    iter_params[0] = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        0.1,
+        5e-8,
+        5e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(20),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let (solution, _, result) = solve(loosely_coupled, init, parameters, &iter_params);
+
+    assert!(matches!(
+        result,
+        Err(nrf::errors::SolverError::NonConvergenceError(_))
+    ));
+    assert!(solution[0] <= 0.1);
+}
+
+#[test]
+fn gauss_seidel_strongly_coupled() {
+    let problem_size = 2;
+    let init = nalgebra::DVector::from_vec(vec![0.0, 0.0]);
+    let iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let max_iter = 30;
+
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(max_iter),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let (_, iter, result) = solve(strongly_coupled, init, parameters, &iter_params);
+
+    assert_eq!(iter, max_iter);
+    assert_eq!(
+        result.unwrap_err().kind(),
+        nrf::errors::SolverErrorKind::NonConvergence
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The nonlinear Gauss-Seidel method requires at least one scalar iteration per iterative"
+)]
+fn gauss_seidel_without_scalar_iteration() {
+    SolverParameters::new(
+        ProblemSize::new(2),
+        1e-8,
+        MaxIterations::new(50),
+        ResolutionMethod::NonlinearGaussSeidel {
+            inner_scalar_iterations: 0,
+        },
+        Damping::Disabled,
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The nonlinear Gauss-Seidel method computes the derivatives with finite differences"
+)]
+fn gauss_seidel_without_finite_differences() {
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(50),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(problem_size),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model =
+        nrf::model::UserModelFromFunctionAndJacobian::new(problem_size, strongly_coupled, |_| {
+            nalgebra::DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0])
+        });
+
+    rf.solve(&mut user_model).unwrap();
+}
+
+// Each sweep is recorded as an iteration without jacobian,
+// and the initial guess is only evaluated once: the sweep does not evaluate it again before the step
+#[test]
+fn gauss_seidel_sources_and_evaluations() {
+    let problem_size = 5;
+    let init = nalgebra::DVector::zeros(problem_size);
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-8,
+        MaxIterations::new(50),
+        GAUSS_SEIDEL,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(parameters, init.clone(), &iter_params, &res_config);
+    let evaluated_points = std::cell::RefCell::new(Vec::new());
+    let recording_model = |x: &nalgebra::DVector<f64>| {
+        evaluated_points.borrow_mut().push(x.clone());
+        loosely_coupled(x)
+    };
+    let mut user_model = nrf::model::UserModelFromClosure::new(problem_size, &recording_model);
+
+    rf.solve(&mut user_model).unwrap();
+
+    assert_eq!(rf.get_jacobian_sources().len(), rf.get_iter());
+    assert!(rf
+        .get_jacobian_sources()
+        .iter()
+        .all(|source| *source == JacobianSource::ScalarFiniteDifference));
+    let evaluated_points = evaluated_points.borrow();
+    assert_eq!(evaluated_points.len(), rf.get_model_evaluations());
+    assert_eq!(
+        evaluated_points
+            .iter()
+            .filter(|point| **point == init)
+            .count(),
+        1
+    );
+}
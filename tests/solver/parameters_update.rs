@@ -0,0 +1,149 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::broyden1965::*;
+
+use crate::common::{solve_function_fd, solve_outcome, Outcome};
+
+const PROBLEM_SIZE: usize = 5;
+
+const BROYDEN_FIRST_METHOD: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+);
+
+const BROYDEN_FIRST_METHOD_INV: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::InverseJacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+);
+
+type Solver<'a> = nrf::solver::RootFinder<'a, iteratives::IterativeParamsFD, nalgebra::Dyn>;
+
+fn parameters(resolution_method: ResolutionMethod) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-6,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Enabled,
+    )
+}
+
+/// Resolution of the case 5 of Broyden with a new solver and a new model
+fn solve_fresh(parameters: SolverParameters) -> Outcome {
+    solve_function_fd(broyden1965_case5, init_broyden1965_case5(), parameters)
+}
+
+/// Successive resolutions of the case 5 of Broyden with the same solver and the same model,
+/// the parameters being updated before each resolution
+fn solve_successively(updates: &[fn(&mut Solver)]) -> Vec<Outcome> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(ResolutionMethod::NewtonRaphson),
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5);
+
+    updates
+        .iter()
+        .map(|update| {
+            update(&mut rf);
+            solve_outcome(&mut rf, &mut user_model)
+        })
+        .collect()
+}
+
+#[test]
+fn newton_raphson_then_broyden() {
+    let outcomes = solve_successively(&[
+        |_: &mut Solver| (),
+        |rf: &mut Solver| rf.set_resolution_method(BROYDEN_FIRST_METHOD),
+    ]);
+
+    assert_eq!(
+        outcomes[0],
+        solve_fresh(parameters(ResolutionMethod::NewtonRaphson))
+    );
+    assert_eq!(outcomes[1], solve_fresh(parameters(BROYDEN_FIRST_METHOD)));
+    assert_eq!(outcomes[1].0, Ok(()));
+}
+
+#[test]
+fn inverse_update_then_jacobian_update() {
+    let outcomes = solve_successively(&[
+        |rf: &mut Solver| rf.set_resolution_method(BROYDEN_FIRST_METHOD_INV),
+        |rf: &mut Solver| rf.set_resolution_method(BROYDEN_FIRST_METHOD),
+    ]);
+
+    assert_eq!(
+        outcomes[0],
+        solve_fresh(parameters(BROYDEN_FIRST_METHOD_INV))
+    );
+    assert_eq!(outcomes[1], solve_fresh(parameters(BROYDEN_FIRST_METHOD)));
+}
+
+#[test]
+fn tolerance_max_iter_and_damping() {
+    let outcomes = solve_successively(&[
+        |rf: &mut Solver| {
+            rf.set_tolerance(1e-12);
+            rf.set_max_iter(2);
+            rf.set_damping(Damping::Disabled);
+        },
+        |rf: &mut Solver| rf.set_max_iter(50),
+    ]);
+
+    let expected_parameters = |max_iter: usize| {
+        SolverParameters::new(
+            ProblemSize::new(PROBLEM_SIZE),
+            1e-12,
+            MaxIterations::new(max_iter),
+            ResolutionMethod::NewtonRaphson,
+            Damping::Disabled,
+        )
+    };
+    assert_eq!(outcomes[0], solve_fresh(expected_parameters(2)));
+    assert!(outcomes[0]
+        .0
+        .as_ref()
+        .unwrap_err()
+        .starts_with("Convergence not reached: "));
+    assert_eq!(outcomes[1], solve_fresh(expected_parameters(50)));
+    assert_eq!(outcomes[1].0, Ok(()));
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn parameters_in_log() {
+    const LOG_PATH: &str = "./tests/solver/parameters_update_log.txt";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters(ResolutionMethod::NewtonRaphson),
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case5);
+    rf.solve(&mut user_model).unwrap();
+
+    rf.set_resolution_method(BROYDEN_FIRST_METHOD);
+    rf.set_max_iter(42);
+    rf.activate_debug(LOG_PATH);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains(&format!("| {:20}|", BROYDEN_FIRST_METHOD.to_string())));
+    assert!(log.contains("| 42               |"));
+}
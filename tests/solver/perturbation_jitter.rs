@@ -0,0 +1,151 @@
+use newton_rootfinder as nrf;
+use nrf::errors::SolverErrorKind;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    perturbation_jitter_factor, Damping, MaxIterations, ProblemSize, ResolutionMethod, RootFinder,
+    SolverParameters,
+};
+
+const PERTURBATION: f64 = 0.1;
+
+/// Lookup table of slope 1 with a plateau between the breakpoints `1.0` and `1.1`
+fn lookup(x: f64) -> f64 {
+    if x <= 1.0 {
+        x
+    } else if x <= 1.1 {
+        1.0
+    } else {
+        x - 0.1
+    }
+}
+
+/// Equations `x0 = 1` and `lookup(x1) = 1.4`, whose root is `(1.0, 1.5)`
+///
+/// Starting from `x1 = 1.0`, the perturbation of `0.1` lands exactly on the next breakpoint:
+/// the column of `x1` is evaluated on the plateau only and the jacobian is singular
+fn breakpoint_aligned(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 1.0, lookup(x[1]) - 1.4])
+}
+
+fn solve(
+    perturbation_jitter: Option<f64>,
+) -> (
+    Result<(), SolverErrorKind>,
+    Option<nalgebra::DVector<f64>>,
+    nalgebra::DVector<f64>,
+) {
+    let iterative = iteratives::IterativeParamsFD::new(
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        PERTURBATION,
+        1e-8,
+        iteratives::PerturbationMethod::Max,
+    );
+    let vec_iter_params = vec![iterative; 2];
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_perturbation_jitter(perturbation_jitter);
+    let mut rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![0.0, 1.0]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, breakpoint_aligned);
+
+    let result = rf.solve(&mut user_model).map_err(|error| error.kind());
+    (
+        result,
+        rf.get_perturbations().clone(),
+        nrf::model::Model::get_iteratives(&user_model),
+    )
+}
+
+#[test]
+fn breakpoint_aligned_without_jitter() {
+    let (result, perturbations, _) = solve(None);
+
+    assert_eq!(result, Err(SolverErrorKind::StructurallySingularJacobian));
+    assert_eq!(
+        perturbations,
+        Some(nalgebra::DVector::from_vec(vec![PERTURBATION; 2]))
+    );
+}
+
+#[test]
+fn breakpoint_aligned_with_jitter() {
+    let (result, _, iteratives) = solve(Some(0.1));
+
+    assert_eq!(result, Ok(()));
+    assert!((iteratives[0] - 1.0).abs() < 1e-6);
+    assert!((iteratives[1] - 1.5).abs() < 1e-6);
+}
+
+#[test]
+fn jitter_deterministic() {
+    let (first_result, first_perturbations, first_iteratives) = solve(Some(0.1));
+    let (second_result, second_perturbations, second_iteratives) = solve(Some(0.1));
+
+    assert_eq!(first_result, second_result);
+    assert_eq!(first_perturbations, second_perturbations);
+    assert_eq!(first_iteratives, second_iteratives);
+
+    // the perturbations of the last jacobian are the ones of the last iteration
+    let last_perturbations = first_perturbations.unwrap();
+    let last_iteration = (1..=20)
+        .find(|&iteration| {
+            (0..2).all(|index| {
+                last_perturbations[index]
+                    == PERTURBATION * perturbation_jitter_factor(0.1, iteration, index)
+            })
+        })
+        .unwrap();
+    assert!(last_iteration > 1);
+}
+
+#[test]
+fn jitter_factor_sequence() {
+    let factors: Vec<f64> = (0..1000)
+        .map(|index| perturbation_jitter_factor(0.1, 7, index))
+        .collect();
+    let again: Vec<f64> = (0..1000)
+        .map(|index| perturbation_jitter_factor(0.1, 7, index))
+        .collect();
+
+    assert_eq!(factors, again);
+    assert!(factors.iter().all(|&factor| (0.9..1.1).contains(&factor)));
+    // the factors are spread over the interval
+    assert!(factors.iter().any(|&factor| factor < 0.95));
+    assert!(factors.iter().any(|&factor| factor > 1.05));
+    assert_ne!(
+        perturbation_jitter_factor(0.1, 7, 0),
+        perturbation_jitter_factor(0.1, 8, 0)
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The perturbation jitter must be strictly between 0 and 1, provided value was 1"
+)]
+fn jitter_amplitude_too_large() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.set_perturbation_jitter(Some(1.0));
+}
@@ -0,0 +1,148 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals::{self, NormalizationMethod};
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::pipe_flow::*;
+
+use crate::common::{pipe_flow_normalization, PipeFlowModel};
+
+const BROYDEN_FIRST_METHOD: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+);
+
+/// Solve the pipe flow, returning the final iteratives
+fn solve_pipe_flow(
+    normalization: &[NormalizationMethod],
+    tolerance: f64,
+    resolution_method: ResolutionMethod,
+) -> Result<nalgebra::DVector<f64>, String> {
+    let params = parameters_pipe_flow();
+    let problem_size = 4;
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let res_config = residuals::ResidualsConfig::new(normalization, normalization);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        tolerance,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Enabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        init_pipe_flow(&params),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = PipeFlowModel::new(params);
+
+    rf.solve(&mut user_model)
+        .map(|()| user_model.get_iteratives())
+        .map_err(|error| error.to_string())
+}
+
+/// Check the iteratives against the solution with a relative tolerance
+fn assert_solution(iteratives: &nalgebra::DVector<f64>) {
+    let solution = solution_pipe_flow(&parameters_pipe_flow());
+    for i in 0..4 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i] / solution[i],
+            1.0,
+            epsilon = 1e-9
+        ));
+    }
+}
+
+#[test]
+fn pipe_flow_solution() {
+    let params = parameters_pipe_flow();
+    let (left, right) = pipe_flow(&params, &solution_pipe_flow(&params));
+
+    for i in 0..4 {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            left[i],
+            right[i],
+            epsilon = 1e-12 * right[i].abs()
+        ));
+    }
+}
+
+#[test]
+fn pipe_flow_jacobian() {
+    let params = parameters_pipe_flow();
+    let x = init_pipe_flow(&params);
+    let (left_jac, right_jac) = pipe_flow_jac(&params, &x);
+
+    for j in 0..4 {
+        let dx = 1e-6 * x[j].abs();
+        let mut x_plus = x.clone();
+        x_plus[j] += dx;
+        let mut x_minus = x.clone();
+        x_minus[j] -= dx;
+        let (left_plus, right_plus) = pipe_flow(&params, &x_plus);
+        let (left_minus, right_minus) = pipe_flow(&params, &x_minus);
+
+        for i in 0..4 {
+            let left_fd = (left_plus[i] - left_minus[i]) / (2.0 * dx);
+            let right_fd = (right_plus[i] - right_minus[i]) / (2.0 * dx);
+            assert!((left_jac[(i, j)] - left_fd).abs() <= 1e-6 * (1.0 + left_fd.abs()));
+            assert!((right_jac[(i, j)] - right_fd).abs() <= 1e-6 * (1.0 + right_fd.abs()));
+        }
+    }
+}
+
+// With the absolute normalization, the tolerance applies to values of very different magnitudes:
+// it must be tight enough for the mass flow (around 1)
+// while being reachable by the pressures and the enthalpy (around 1e5)
+#[test]
+fn pipe_flow_abs_newton_raphson() {
+    let normalization = vec![NormalizationMethod::Abs; 4];
+    assert_solution(
+        &solve_pipe_flow(&normalization, 1e-9, ResolutionMethod::NewtonRaphson).unwrap(),
+    );
+}
+
+#[test]
+fn pipe_flow_abs_broyden() {
+    let normalization = vec![NormalizationMethod::Abs; 4];
+    assert_solution(&solve_pipe_flow(&normalization, 1e-9, BROYDEN_FIRST_METHOD).unwrap());
+}
+
+// The round-off errors on the pressures and the enthalpy are above 1e-12
+#[test]
+fn pipe_flow_abs_unreachable_tolerance() {
+    let normalization = vec![NormalizationMethod::Abs; 4];
+    assert!(
+        solve_pipe_flow(&normalization, 1e-12, ResolutionMethod::NewtonRaphson)
+            .as_ref()
+            .unwrap_err()
+            .starts_with("Convergence not reached: ")
+    );
+}
+
+// With the normalization suited to each equation,
+// the tolerance has the same meaning for all of them
+#[test]
+fn pipe_flow_normalized_newton_raphson() {
+    assert_solution(
+        &solve_pipe_flow(
+            &pipe_flow_normalization(),
+            1e-12,
+            ResolutionMethod::NewtonRaphson,
+        )
+        .unwrap(),
+    );
+}
+
+#[test]
+fn pipe_flow_normalized_broyden() {
+    assert_solution(
+        &solve_pipe_flow(&pipe_flow_normalization(), 1e-12, BROYDEN_FIRST_METHOD).unwrap(),
+    );
+}
@@ -0,0 +1,171 @@
+use std::convert::Infallible;
+
+use newton_rootfinder as nrf;
+use nrf::iteratives::{self, IterativeParams};
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, RootFinder};
+
+const NOISE: f64 = 1e-8;
+
+/// Two independent equations of roots `(sqrt(2), 2.0)`
+///
+/// The second equation is noisy: its residual alternates between `+NOISE` and `-NOISE` at its root.
+/// The jacobian is provided to keep the noise out of the finite differences
+struct NoisyModel {
+    iteratives: nalgebra::DVector<f64>,
+    residuals: nalgebra::DVector<f64>,
+    evaluations: usize,
+}
+
+impl NoisyModel {
+    fn new() -> Self {
+        NoisyModel {
+            iteratives: nalgebra::DVector::zeros(2),
+            residuals: nalgebra::DVector::zeros(2),
+            evaluations: 0,
+        }
+    }
+}
+
+impl Model<nalgebra::Dyn> for NoisyModel {
+    type InaccurateValuesError = Infallible;
+    type UnusableValuesError = Infallible;
+
+    fn len_problem(&self) -> usize {
+        2
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn evaluate(&mut self) -> Result<(), nrf::model::ModelError<Self, nalgebra::Dyn>> {
+        self.evaluations += 1;
+        let noise = if self.evaluations % 2 == 0 {
+            NOISE
+        } else {
+            -NOISE
+        };
+        self.residuals[0] = self.iteratives[0] * self.iteratives[0] - 2.0;
+        self.residuals[1] = self.iteratives[1] - 2.0 + noise;
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.residuals.clone(), nalgebra::DVector::zeros(2))
+    }
+
+    fn jacobian_provided(&self) -> bool {
+        true
+    }
+
+    fn get_jacobian(
+        &mut self,
+    ) -> Result<residuals::JacobianValues<nalgebra::Dyn>, nrf::model::ModelError<Self, nalgebra::Dyn>>
+    {
+        let left =
+            nalgebra::DMatrix::from_row_slice(2, 2, &[2.0 * self.iteratives[0], 0.0, 0.0, 1.0]);
+        Ok(residuals::JacobianValues::new(
+            left,
+            nalgebra::DMatrix::zeros(2, 2),
+        ))
+    }
+}
+
+fn residual(model: &NoisyModel, i: usize) -> f64 {
+    let (left, right) = model.get_residuals().get_values(i);
+    (left - right).abs()
+}
+
+/// Run `check` on a solver of the given tolerance and a new model
+fn with_root_finder<F>(tolerance: f64, check: F)
+where
+    F: FnOnce(&mut RootFinder<IterativeParams, nalgebra::Dyn>, &mut NoisyModel),
+{
+    let problem_size = 2;
+    let vec_iter_params = iteratives::default_vec_iteratives(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        tolerance,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let init = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+    let mut rf = RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = NoisyModel::new();
+
+    check(&mut rf, &mut user_model);
+}
+
+#[test]
+fn polish_clean_residual() {
+    with_root_finder(1e-6, |rf, user_model| {
+        rf.solve(user_model).unwrap();
+        let iter = rf.get_iter();
+
+        let extra_iterations = rf.polish(user_model, &[0], 1e-15, 10).unwrap();
+
+        assert!(extra_iterations >= 1);
+        assert_eq!(rf.get_iter(), iter + extra_iterations);
+        assert!(residual(user_model, 0) <= 1e-15);
+        // The noisy residual stays below the tolerance of the solver
+        assert!(residual(user_model, 1) <= 1e-6);
+    });
+}
+
+#[test]
+fn polish_already_reached() {
+    with_root_finder(1e-6, |rf, user_model| {
+        rf.solve(user_model).unwrap();
+        let iter = rf.get_iter();
+
+        assert_eq!(rf.polish(user_model, &[0, 1], 1e-6, 10).unwrap(), 0);
+        assert_eq!(rf.get_iter(), iter);
+    });
+}
+
+#[test]
+fn polish_noisy_residual() {
+    with_root_finder(1e-6, |rf, user_model| {
+        rf.solve(user_model).unwrap();
+        let iter = rf.get_iter();
+
+        let result = rf.polish(user_model, &[1], 1e-10, 5);
+
+        assert!(matches!(
+            result,
+            Err(nrf::errors::SolverError::NonConvergenceError(_))
+        ));
+        assert_eq!(rf.get_iter(), iter + 5);
+    });
+}
+
+#[test]
+fn tight_global_tolerance() {
+    // The naive alternative fails because of the noisy residual
+    with_root_finder(1e-10, |rf, user_model| {
+        assert!(matches!(
+            rf.solve(user_model),
+            Err(nrf::errors::SolverError::NonConvergenceError(_))
+        ));
+    });
+}
+
+#[test]
+#[should_panic(expected = "The polished residual index 2 is out of range, the problem size is 2")]
+fn polish_out_of_range() {
+    with_root_finder(1e-6, |rf, user_model| {
+        rf.solve(user_model).unwrap();
+        let _ = rf.polish(user_model, &[2], 1e-10, 5);
+    });
+}
@@ -0,0 +1,235 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::presets::{self, Preset};
+use nrf::solver::{QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+type Case = (
+    fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    fn(&nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64>,
+    nalgebra::DVector<f64>,
+    nalgebra::DVector<f64>,
+);
+
+fn broyden_suite() -> Vec<Case> {
+    vec![
+        (
+            broyden1965_case5,
+            broyden1965_case5_jac,
+            init_broyden1965_case5(),
+            solution_broyden1965_case5(),
+        ),
+        (
+            broyden1965_case6,
+            broyden1965_case6_jac,
+            init_broyden1965_case6(),
+            solution_broyden1965_case6(),
+        ),
+        (
+            broyden1965_case7,
+            broyden1965_case7_jac,
+            init_broyden1965_case7(),
+            solution_broyden1965_case7(),
+        ),
+        (
+            broyden1965_case8,
+            broyden1965_case8_jac,
+            init_broyden1965_case8(),
+            solution_broyden1965_case8(),
+        ),
+        (
+            broyden1965_case9,
+            broyden1965_case9_jac,
+            init_broyden1965_case9(),
+            solution_broyden1965_case9(),
+        ),
+    ]
+}
+
+fn broyden_case10() -> Case {
+    (
+        broyden1965_case10,
+        broyden1965_case10_jac,
+        init_broyden1965_case10(),
+        solution_broyden1965_case10(),
+    )
+}
+
+fn assert_solution(iteratives: &nalgebra::DVector<f64>, solution: &nalgebra::DVector<f64>) {
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-5
+        ));
+    }
+}
+
+fn solve_suite_fd(preset: Preset, cases: Vec<Case>) {
+    for (func, _, init, solution) in cases {
+        let problem_size = init.len();
+        let vec_iter_params = presets::recommended_iteratives_fd(problem_size);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = presets::recommended_stopping_criterias(problem_size);
+        let update_methods = presets::recommended_update_methods(problem_size);
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut rf = nrf::solver::RootFinder::new(
+            preset.parameters(problem_size),
+            init,
+            &iter_params,
+            &res_config,
+        );
+        let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+        rf.solve(&mut user_model).unwrap();
+
+        assert!(rf.get_iter() <= preset.max_iter());
+        assert_solution(&user_model.get_iteratives(), &solution);
+    }
+}
+
+fn solve_suite_jac(preset: Preset, cases: Vec<Case>) {
+    for (func, jac, init, solution) in cases {
+        let problem_size = init.len();
+        let vec_iter_params = presets::recommended_iteratives(problem_size);
+        let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+        let stopping_residuals = presets::recommended_stopping_criterias(problem_size);
+        let update_methods = presets::recommended_update_methods(problem_size);
+        let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+        let mut rf = nrf::solver::RootFinder::new(
+            preset.parameters(problem_size),
+            init,
+            &iter_params,
+            &res_config,
+        );
+        let mut user_model =
+            nrf::model::UserModelFromFunctionAndJacobian::new(problem_size, func, jac);
+
+        rf.solve(&mut user_model).unwrap();
+
+        assert!(rf.get_iter() <= preset.max_iter());
+        assert_solution(&user_model.get_iteratives(), &solution);
+    }
+}
+
+#[test]
+fn robust_broyden_suite_fd() {
+    solve_suite_fd(Preset::Robust, broyden_suite());
+}
+
+#[test]
+fn robust_broyden_suite_jac() {
+    solve_suite_jac(Preset::Robust, broyden_suite());
+}
+
+#[test]
+fn fast_smooth_broyden_suite_fd() {
+    solve_suite_fd(Preset::FastSmooth, broyden_suite());
+}
+
+#[test]
+fn fast_smooth_broyden_suite_jac() {
+    solve_suite_jac(Preset::FastSmooth, broyden_suite());
+}
+
+#[test]
+fn expensive_model_broyden_suite_fd() {
+    solve_suite_fd(Preset::ExpensiveModel, broyden_suite());
+}
+
+#[test]
+fn expensive_model_broyden_suite_jac() {
+    solve_suite_jac(Preset::ExpensiveModel, broyden_suite());
+}
+
+// The ill-conditioned case 10 is only solved by the robust preset
+#[test]
+fn robust_broyden_case10_fd() {
+    solve_suite_fd(Preset::Robust, vec![broyden_case10()]);
+}
+
+#[test]
+fn robust_broyden_case10_jac() {
+    solve_suite_jac(Preset::Robust, vec![broyden_case10()]);
+}
+
+#[test]
+fn presets_parameters() {
+    let problem_size = 3;
+
+    let robust = presets::robust(problem_size);
+    assert_eq!(robust.get_problem_size(), Some(problem_size));
+    assert_eq!(robust.get_tolerance(), presets::PRESET_TOLERANCE);
+    assert_eq!(robust.get_max_iter(), 100);
+    assert_eq!(
+        robust.get_resolution_method(),
+        ResolutionMethod::NewtonRaphson
+    );
+    assert!(robust.get_damping().is_enabled());
+    assert!(robust.get_return_best_on_failure());
+    assert!(robust.get_cycle_detection().is_some());
+
+    let fast_smooth = presets::fast_smooth(problem_size);
+    assert_eq!(fast_smooth.get_max_iter(), 50);
+    assert_eq!(
+        fast_smooth.get_resolution_method(),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenSecondMethod
+        ))
+    );
+    assert!(!fast_smooth.get_damping().is_enabled());
+    assert_eq!(fast_smooth.get_newton_warmup_iterations(), 2);
+
+    let expensive_model = presets::expensive_model(problem_size);
+    assert_eq!(expensive_model.get_max_iter(), 100);
+    assert_eq!(
+        expensive_model.get_resolution_method(),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::StationaryNewton)
+    );
+    assert!(expensive_model.get_damping().is_enabled());
+    assert_eq!(expensive_model.get_newton_warmup_iterations(), 2);
+
+    for preset in [Preset::Robust, Preset::FastSmooth, Preset::ExpensiveModel] {
+        assert_eq!(
+            preset.parameters(problem_size).get_max_iter(),
+            preset.max_iter()
+        );
+    }
+}
+
+#[test]
+fn presets_companion_vectors() {
+    let problem_size = 4;
+    assert_eq!(
+        presets::recommended_iteratives(problem_size).len(),
+        problem_size
+    );
+    assert_eq!(
+        presets::recommended_iteratives_fd(problem_size).len(),
+        problem_size
+    );
+    assert_eq!(
+        presets::recommended_stopping_criterias(problem_size),
+        vec![residuals::NormalizationMethod::Abs; problem_size]
+    );
+    assert_eq!(
+        presets::recommended_update_methods(problem_size),
+        vec![residuals::NormalizationMethod::Abs; problem_size]
+    );
+}
+
+#[test]
+#[should_panic(expected = "The problem size of a preset must be strictly positive, got 0")]
+fn presets_empty_problem() {
+    presets::robust(0);
+}
+
+#[test]
+#[should_panic(expected = "The problem size of a preset must be strictly positive, got 0")]
+fn presets_empty_iteratives() {
+    presets::recommended_iteratives_fd(0);
+}
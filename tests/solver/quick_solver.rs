@@ -0,0 +1,86 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{Damping, QuickStart, ResolutionMethod};
+
+pub fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.component_mul(x).add_scalar(-2.0)
+}
+
+#[test]
+fn same_report_as_default_with_guess() {
+    let problem_size = 2;
+    let init_guess = nalgebra::DVector::from_vec(vec![1.0, 3.0]);
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_guess.clone(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    let report = rf.solve_with_report(&mut user_model).unwrap();
+
+    let mut solver = nrf::solver::quick_solver(
+        init_guess,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let mut quick_model = nrf::model::UserModelFromFunction::new(problem_size, square2);
+    let quick_report = solver.solve(&mut quick_model).unwrap();
+
+    assert_eq!(quick_report, report);
+    assert_eq!(quick_model.get_iteratives(), user_model.get_iteratives());
+    assert_eq!(
+        format!("{:?}", solver.get_parameters()),
+        format!("{:?}", rf.get_parameters())
+    );
+}
+
+#[test]
+fn start_from_problem_size() {
+    let mut solver =
+        nrf::solver::quick_solver(2, ResolutionMethod::NewtonRaphson, Damping::Disabled);
+    assert_eq!(solver.get_initial_guess().as_slice(), [1.0, 1.0]);
+
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, square2);
+    let report = solver.solve(&mut user_model).unwrap();
+    for x in report.get_final_iteratives().iter() {
+        assert!((x - 2.0_f64.sqrt()).abs() < 1e-6);
+    }
+
+    // the next resolution starts from the solution
+    assert_eq!(solver.get_initial_guess(), report.get_final_iteratives());
+    let report = solver.solve(&mut user_model).unwrap();
+    assert_eq!(report.get_iter(), 0);
+}
+
+#[test]
+fn static_initial_guess() {
+    let start = QuickStart::from(nalgebra::SVector::<f64, 1>::new(1.0));
+    let solver =
+        nrf::solver::quick_solver(start, ResolutionMethod::NewtonRaphson, Damping::Disabled);
+    assert_eq!(solver.get_parameters().get_problem_size(), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "The problem size of the quick solver must be strictly positive, got 0")]
+fn empty_problem() {
+    nrf::solver::quick_solver(0, ResolutionMethod::NewtonRaphson, Damping::Disabled);
+}
+
+#[test]
+#[should_panic(
+    expected = "The initial guess has a length of 1, the problem size of the quick solver being 2"
+)]
+fn initial_guess_of_another_size() {
+    let mut solver =
+        nrf::solver::quick_solver(2, ResolutionMethod::NewtonRaphson, Damping::Disabled);
+    solver.set_initial_guess(nalgebra::DVector::from_vec(vec![1.0]));
+}
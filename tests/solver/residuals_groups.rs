@@ -0,0 +1,244 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::residuals::{self, GroupAggregation, NormalizationMethod, ResidualsGroup};
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+use crate::common::solve_outcome;
+
+const PROBLEM_SIZE: usize = 6;
+
+/// Two vector equations of three components each:
+/// - position: `x_i^2 = i + 1` for `i` in `0..3`
+/// - velocity: `x_i^2 = i + 1` for `i` in `3..6`
+fn two_vector_equations(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map_with_location(|i, _, value| value * value - (i + 1) as f64)
+}
+
+fn groups() -> Vec<ResidualsGroup> {
+    vec![
+        ResidualsGroup::from_range("position", 0..3),
+        ResidualsGroup::from_range("velocity", 3..6),
+    ]
+}
+
+/// Resolution of the two vector equations, returning the result and the number of iterations
+fn solve_with_config(
+    configure: fn(&mut residuals::ResidualsConfig),
+) -> (Result<(), String>, usize) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    configure(&mut res_config);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-12,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_element(PROBLEM_SIZE, 1.0),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, two_vector_equations);
+
+    let (result, _, iter, _) = solve_outcome(&mut rf, &mut user_model);
+    (result, iter)
+}
+
+#[test]
+fn group_errors() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(groups());
+    let stopping_residuals = nalgebra::DVector::from_vec(vec![3.0, -4.0, 0.0, 1.0, 0.0, 0.0]);
+
+    assert_eq!(res_config.get_group_aggregation(), GroupAggregation::Max);
+    assert_eq!(
+        res_config.evaluate_group_errors(&stopping_residuals),
+        vec![4.0, 1.0]
+    );
+
+    res_config.set_group_aggregation(GroupAggregation::L2);
+    assert_eq!(
+        res_config.evaluate_group_errors(&stopping_residuals),
+        vec![5.0, 1.0]
+    );
+
+    let non_finite = nalgebra::DVector::from_vec(vec![0.0, 0.0, 0.0, f64::NAN, 0.0, 0.0]);
+    assert_eq!(
+        res_config.evaluate_group_errors(&non_finite),
+        vec![0.0, f64::INFINITY]
+    );
+}
+
+#[test]
+fn group_convergence_decision() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    let mut groups = groups();
+    groups[1].set_tolerance(Some(1e-3));
+    res_config.set_groups(groups);
+    let stopping_residuals = nalgebra::DVector::from_vec(vec![6e-4, 0.0, 0.0, 8e-4, 8e-4, 0.0]);
+
+    assert!(!res_config.are_groups_converged(&stopping_residuals, 1e-4));
+    assert!(res_config.are_groups_converged(&stopping_residuals, 1e-3));
+
+    res_config.set_group_aggregation(GroupAggregation::L2);
+    assert!(!res_config.are_groups_converged(&stopping_residuals, 1e-3));
+}
+
+#[test]
+fn groups_without_group_convergence() {
+    let (reference, reference_iter) = solve_with_config(|_| ());
+    let (result, iter) = solve_with_config(|res_config| {
+        let mut groups = groups();
+        groups[0].set_tolerance(Some(1e-2));
+        res_config.set_groups(groups);
+    });
+
+    assert_eq!(reference, Ok(()));
+    assert_eq!(result, Ok(()));
+    assert_eq!(iter, reference_iter);
+}
+
+#[test]
+fn group_convergence_with_solver_tolerance() {
+    let (_, reference_iter) = solve_with_config(|_| ());
+    let (result, iter) = solve_with_config(|res_config| {
+        res_config.set_groups(groups());
+        res_config.set_group_convergence(true);
+    });
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(iter, reference_iter);
+}
+
+#[test]
+fn group_convergence_with_group_tolerances() {
+    let (_, reference_iter) = solve_with_config(|_| ());
+    let (result, iter) = solve_with_config(|res_config| {
+        let mut groups = groups();
+        groups[0].set_tolerance(Some(1e-2));
+        groups[1].set_tolerance(Some(1e-2));
+        res_config.set_groups(groups);
+        res_config.set_group_convergence(true);
+    });
+
+    assert_eq!(result, Ok(()));
+    assert!(iter < reference_iter);
+}
+
+#[test]
+#[should_panic(expected = "The residual 6 of the group velocity is out of range")]
+fn group_out_of_range() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(vec![
+        ResidualsGroup::from_range("position", 0..3),
+        ResidualsGroup::from_range("velocity", 3..7),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "The residual 2 belongs to both the groups position and velocity")]
+fn groups_overlapping() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(vec![
+        ResidualsGroup::from_range("position", 0..3),
+        ResidualsGroup::from_range("velocity", 2..6),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "The residual 5 does not belong to any group")]
+fn groups_not_covering() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(vec![
+        ResidualsGroup::from_range("position", 0..3),
+        ResidualsGroup::from_range("velocity", 3..5),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "The convergence on the groups of residuals requires groups")]
+fn group_convergence_without_groups() {
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_group_convergence(true);
+}
+
+#[test]
+fn normalization_per_group() {
+    let groups_config = vec![
+        (
+            ResidualsGroup::from_range("position", 0..3),
+            residuals::ResidualConfig::new(NormalizationMethod::Rel, NormalizationMethod::Abs),
+        ),
+        (
+            ResidualsGroup::from_range("velocity", 3..6),
+            residuals::ResidualConfig::new(NormalizationMethod::Adapt, NormalizationMethod::Rel),
+        ),
+    ];
+    let (stopping_criterias, update_methods) =
+        residuals::ResidualsConfig::convert_groups_into_vecs(&groups_config, PROBLEM_SIZE);
+
+    assert_eq!(
+        stopping_criterias,
+        [
+            vec![NormalizationMethod::Rel; 3],
+            vec![NormalizationMethod::Adapt; 3]
+        ]
+        .concat()
+    );
+    assert_eq!(
+        update_methods,
+        [
+            vec![NormalizationMethod::Abs; 3],
+            vec![NormalizationMethod::Rel; 3]
+        ]
+        .concat()
+    );
+}
+
+#[cfg(feature = "debug_log")]
+#[test]
+fn group_errors_in_log() {
+    const LOG_PATH: &str = "./tests/solver/residuals_groups_log.txt";
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let normalization = vec![NormalizationMethod::Abs; PROBLEM_SIZE];
+    let mut res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    res_config.set_groups(groups());
+    res_config.set_group_aggregation(GroupAggregation::L2);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-12,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_element(PROBLEM_SIZE, 1.0),
+        &iter_params,
+        &res_config,
+    );
+    rf.activate_debug(LOG_PATH);
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, two_vector_equations);
+    rf.solve(&mut user_model).unwrap();
+
+    let log = crate::common::read_log(LOG_PATH);
+    assert!(log.contains("Residuals groups"));
+    assert!(log.contains("Aggregation: L2 norm, convergence checked on the groups: false"));
+    assert!(
+        log.contains("| position          | 0-2                      | Solver tolerance         |")
+    );
+    assert!(log.contains(&format!("| {:18}| {:25}|", "position", 5f64.sqrt())));
+    assert!(log.contains(&format!("| {:18}| {:25}|", "velocity", 50f64.sqrt())));
+}
@@ -0,0 +1,215 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::{Model, ModelError};
+use nrf::residuals;
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod, UpdateQuasiNewtonMethod};
+
+const PROBLEM_SIZE: usize = 2;
+
+/// Setpoint tracking: the sum of the iteratives is 3,
+/// and the square of the iterative 1 follows a target depending on the iterative 0
+///
+/// The model only provides the left members, the target being given by an override
+struct TrackingModel {
+    iteratives: nalgebra::DVector<f64>,
+    left: nalgebra::DVector<f64>,
+    batch: bool,
+}
+
+impl TrackingModel {
+    fn new(batch: bool) -> Self {
+        TrackingModel {
+            iteratives: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            left: nalgebra::DVector::zeros(PROBLEM_SIZE),
+            batch,
+        }
+    }
+
+    fn left(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+        nalgebra::DVector::from_vec(vec![x[0] + x[1], x[1] * x[1]])
+    }
+
+    fn right() -> nalgebra::DVector<f64> {
+        nalgebra::DVector::from_vec(vec![3.0, 0.0])
+    }
+}
+
+impl Model<nalgebra::Dyn> for TrackingModel {
+    type InaccurateValuesError = std::convert::Infallible;
+    type UnusableValuesError = std::convert::Infallible;
+
+    fn len_problem(&self) -> usize {
+        PROBLEM_SIZE
+    }
+
+    fn set_iteratives(&mut self, iteratives: &nalgebra::DVector<f64>) {
+        self.iteratives.copy_from(iteratives);
+    }
+
+    fn get_iteratives(&self) -> nalgebra::DVector<f64> {
+        self.iteratives.clone()
+    }
+
+    fn evaluate(&mut self) -> Result<(), ModelError<Self, nalgebra::Dyn>> {
+        self.left = Self::left(&self.iteratives);
+        Ok(())
+    }
+
+    fn get_residuals(&self) -> residuals::ResidualsValues<nalgebra::Dyn> {
+        residuals::ResidualsValues::new(self.left.clone(), Self::right())
+    }
+
+    fn provides_batch_evaluation(&self) -> bool {
+        self.batch
+    }
+
+    fn evaluate_batch(
+        &mut self,
+        inputs: &[nalgebra::DVector<f64>],
+    ) -> Result<Vec<residuals::ResidualsValues<nalgebra::Dyn>>, ModelError<Self, nalgebra::Dyn>>
+    {
+        Ok(inputs
+            .iter()
+            .map(|input| residuals::ResidualsValues::new(Self::left(input), Self::right()))
+            .collect())
+    }
+}
+
+fn target(x: &nalgebra::DVector<f64>) -> f64 {
+    1.0 + 0.5 * x[0]
+}
+
+/// Hand-written equivalent of the tracking model, including the target
+fn tracking_with_target(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] + x[1] - 3.0, x[1] * x[1] - target(x)])
+}
+
+fn solution() -> nalgebra::DVector<f64> {
+    // x1**2 + 0.5 * x1 - 2.5 = 0
+    let x1 = (-0.5 + 10.25f64.sqrt()) / 2.0;
+    nalgebra::DVector::from_vec(vec![3.0 - x1, x1])
+}
+
+fn solve_both(resolution_method: ResolutionMethod, damping: Damping, batch: bool) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let init = nalgebra::DVector::from_vec(vec![1.0, 1.0]);
+
+    let mut rf = nrf::solver::default_with_guess(
+        init.clone(),
+        &iter_params,
+        &res_config,
+        resolution_method,
+        damping,
+    );
+    rf.set_right_overrides(vec![None, Some(Box::new(target))]);
+    assert_eq!(rf.get_overridden_residuals(), vec![1]);
+    let mut overridden_model = TrackingModel::new(batch);
+    rf.solve(&mut overridden_model).unwrap();
+    let overridden_iter = rf.get_iter();
+
+    let mut rf_ref = nrf::solver::default_with_guess(
+        init,
+        &iter_params,
+        &res_config,
+        resolution_method,
+        damping,
+    );
+    let mut reference_model =
+        nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, tracking_with_target);
+    rf_ref.solve(&mut reference_model).unwrap();
+
+    assert_eq!(overridden_iter, rf_ref.get_iter());
+    let overridden = overridden_model.get_iteratives();
+    let reference = reference_model.get_iteratives();
+    let solution = solution();
+    for i in 0..PROBLEM_SIZE {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            overridden[i],
+            reference[i],
+            epsilon = 1e-9
+        ));
+        assert!(float_cmp::approx_eq!(
+            f64,
+            overridden[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn tracking_newton_raphson() {
+    solve_both(ResolutionMethod::NewtonRaphson, Damping::Disabled, false);
+}
+
+#[test]
+fn tracking_newton_raphson_batch() {
+    solve_both(ResolutionMethod::NewtonRaphson, Damping::Disabled, true);
+}
+
+#[test]
+fn tracking_broyden1_damping() {
+    solve_both(
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+            UpdateQuasiNewtonMethod::BroydenFirstMethod,
+        )),
+        Damping::Enabled,
+        false,
+    );
+}
+
+fn square(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.component_mul(x)
+}
+
+fn square_jac(x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_diagonal(&(2.0 * x))
+}
+
+#[test]
+#[should_panic(
+    expected = "The right members of the residuals [0] are overridden, which is not supported with the jacobian provided by the model"
+)]
+fn overrides_with_analytical_jacobian() {
+    let vec_iter_params = iteratives::default_vec_iteratives(1);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 1];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 1];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.set_right_overrides(vec![Some(Box::new(|_| 2.0))]);
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(1, square, square_jac);
+
+    rf.solve(&mut user_model).unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "Dimension mismatch between the residuals and the right members overrides 2 != 1"
+)]
+fn overrides_dimension_mismatch() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        nalgebra::DVector::from_vec(vec![1.0, 1.0]),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    rf.set_right_overrides(vec![None]);
+}
@@ -0,0 +1,79 @@
+use newton_rootfinder as nrf;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, SolverParameters};
+
+use crate::common::{solve_function_fd, Outcome};
+
+// The slope changes of sign every 0.3, a Newton step from a poor guess jumps over the roots
+fn oscillatory(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|x| x.atan() + 0.2 * (10.0 * x).sin() - 0.5)
+}
+
+// Convex and increasing on the right of its root: the Newton steps never overshoot it
+fn square2(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    x.map(|x| x * x - 2.0)
+}
+
+fn solve(
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    init_guess: f64,
+    scalar_safeguard: bool,
+) -> Outcome {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-10,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    parameters.set_scalar_safeguard(scalar_safeguard);
+    solve_function_fd(
+        func,
+        nalgebra::DVector::from_vec(vec![init_guess]),
+        parameters,
+    )
+}
+
+#[test]
+fn oscillatory_function_without_safeguard() {
+    let (result, _, iter, _) = solve(oscillatory, 3.4, false);
+
+    assert!(result.unwrap_err().starts_with("Convergence not reached: "));
+    assert_eq!(iter, 50);
+}
+
+#[test]
+fn oscillatory_function_with_safeguard() {
+    let (result, iteratives, iter, _) = solve(oscillatory, 3.4, true);
+
+    assert_eq!(result, Ok(()));
+    assert!(iter < 50);
+    assert!(oscillatory(&iteratives)[0].abs() <= 1e-10);
+}
+
+#[test]
+fn safeguard_without_overshoot() {
+    let (result_plain, iteratives_plain, iter_plain, evaluations_plain) =
+        solve(square2, 3.0, false);
+    let (result, iteratives, iter, evaluations) = solve(square2, 3.0, true);
+
+    assert_eq!(result_plain, Ok(()));
+    assert_eq!(result, Ok(()));
+    assert_eq!(iteratives, iteratives_plain);
+    assert_eq!(iter, iter_plain);
+    assert_eq!(evaluations, evaluations_plain);
+}
+
+#[test]
+#[should_panic(
+    expected = "The number of refinements of the scalar safeguard must be strictly positive, got 0"
+)]
+fn safeguard_without_refinement() {
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(1),
+        1e-10,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    parameters.set_scalar_safeguard_refinements(0);
+}
@@ -0,0 +1,239 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, JacobianMatrix, MaxIterations, ProblemSize, QuasiNewtonMethod,
+    QuasiNewtonUpdateStatus, ResolutionMethod, SolverParameters, SparsityPattern,
+    UpdateQuasiNewtonMethod,
+};
+
+const SCHUBERT: UpdateQuasiNewtonMethod = UpdateQuasiNewtonMethod::SchubertSparse;
+const BROY1: UpdateQuasiNewtonMethod = UpdateQuasiNewtonMethod::BroydenFirstMethod;
+
+type Function = fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>;
+
+/// Each residual of the cases 5 to 8 depends on the previous iteratives and on the next one
+fn hessenberg_pattern(problem_size: usize) -> SparsityPattern {
+    SparsityPattern::banded(problem_size, problem_size, 1)
+}
+
+fn parameters(
+    problem_size: usize,
+    method: UpdateQuasiNewtonMethod,
+    damping: Damping,
+) -> SolverParameters {
+    SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(method)),
+        damping,
+    )
+}
+
+/// Number of iterations and solution found
+fn solve(
+    func: Function,
+    init: nalgebra::DVector<f64>,
+    method: UpdateQuasiNewtonMethod,
+    sparsity_pattern: Option<SparsityPattern>,
+    damping: Damping,
+) -> (usize, nalgebra::DVector<f64>) {
+    let problem_size = init.len();
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = parameters(problem_size, method, damping);
+    parameters.set_sparsity_pattern(sparsity_pattern);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, func);
+
+    rf.solve(&mut user_model).unwrap();
+
+    (rf.get_iter(), user_model.get_iteratives())
+}
+
+fn assert_solution(iteratives: &nalgebra::DVector<f64>, solution: &nalgebra::DVector<f64>) {
+    for i in 0..solution.len() {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[i],
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+// Iterations of the Schubert method and of the first method of Broyden
+fn compare_with_broyden(
+    func: Function,
+    init: nalgebra::DVector<f64>,
+    solution: nalgebra::DVector<f64>,
+    sparsity_pattern: SparsityPattern,
+    damping: Damping,
+) -> (usize, usize) {
+    let (schubert_iter, schubert_iteratives) = solve(
+        func,
+        init.clone(),
+        SCHUBERT,
+        Some(sparsity_pattern),
+        damping,
+    );
+    let (broyden_iter, broyden_iteratives) = solve(func, init, BROY1, None, damping);
+
+    assert_solution(&schubert_iteratives, &solution);
+    assert_solution(&broyden_iteratives, &solution);
+    (schubert_iter, broyden_iter)
+}
+
+#[test]
+fn broyden_case5() {
+    let iterations = compare_with_broyden(
+        broyden1965_case5,
+        init_broyden1965_case5(),
+        solution_broyden1965_case5(),
+        hessenberg_pattern(5),
+        Damping::Disabled,
+    );
+    assert_eq!(iterations, (4, 4));
+}
+
+#[test]
+fn broyden_case6() {
+    let iterations = compare_with_broyden(
+        broyden1965_case6,
+        init_broyden1965_case6(),
+        solution_broyden1965_case6(),
+        hessenberg_pattern(5),
+        Damping::Disabled,
+    );
+    assert_eq!(iterations, (9, 8));
+}
+
+#[test]
+fn broyden_case7_damping() {
+    let iterations = compare_with_broyden(
+        broyden1965_case7,
+        init_broyden1965_case7(),
+        solution_broyden1965_case7(),
+        hessenberg_pattern(10),
+        Damping::Enabled,
+    );
+    assert_eq!(iterations, (7, 6));
+}
+
+#[test]
+fn broyden_case8_damping() {
+    let iterations = compare_with_broyden(
+        broyden1965_case8,
+        init_broyden1965_case8(),
+        solution_broyden1965_case8(),
+        hessenberg_pattern(20),
+        Damping::Enabled,
+    );
+    assert_eq!(iterations, (6, 6));
+}
+
+#[test]
+fn broyden_case9_damping() {
+    // the second residual does not depend on the second iterative
+    let sparsity_pattern = SparsityPattern::from_entries(2, &[(0, 0), (0, 1), (1, 0)]);
+    let iterations = compare_with_broyden(
+        broyden1965_case9,
+        init_broyden1965_case9(),
+        solution_broyden1965_case9(),
+        sparsity_pattern,
+        Damping::Enabled,
+    );
+    assert_eq!(iterations, (3, 3));
+}
+
+// Without any zero in the pattern, the Schubert method is the first method of Broyden
+#[test]
+fn full_pattern() {
+    let init = init_broyden1965_case6();
+    let full_pattern = SparsityPattern::banded(5, 5, 5);
+
+    let schubert = solve(
+        broyden1965_case6,
+        init.clone(),
+        SCHUBERT,
+        Some(full_pattern),
+        Damping::Disabled,
+    );
+    let broyden = solve(broyden1965_case6, init, BROY1, None, Damping::Disabled);
+
+    assert_eq!(schubert, broyden);
+}
+
+#[test]
+fn declared_zeros_preserved() {
+    let problem_size = 5;
+    let sparsity_pattern = hessenberg_pattern(problem_size);
+    let mut x = init_broyden1965_case5();
+    let mut jacobian = JacobianMatrix::new();
+    jacobian
+        .update_jacobian_with_exact_value(broyden1965_case5_jac(&x))
+        .unwrap();
+    jacobian.set_sparsity_pattern(Some(sparsity_pattern.clone()));
+
+    for step in 1..=5 {
+        let f = broyden1965_case5(&x);
+        let s = nalgebra::DVector::from_fn(problem_size, |i, _| 0.01 * (step + i) as f64);
+        let y = broyden1965_case5(&(&x + &s)) - &f;
+        x += &s;
+
+        let status =
+            nrf::solver::approximate_jacobian(&mut jacobian, SCHUBERT, &s, &y, &f).unwrap();
+
+        assert_eq!(status, QuasiNewtonUpdateStatus::Updated);
+        let jac = jacobian.get_jacobian().as_ref().unwrap();
+        for row in 0..problem_size {
+            for column in 0..problem_size {
+                if !sparsity_pattern.contains(row, column) {
+                    assert_eq!(jac[(row, column)], 0.0);
+                }
+            }
+        }
+        // secant equation
+        assert!((jac * &s - &y).norm() < 1e-12);
+    }
+
+    // the first method of Broyden fills the declared zeros
+    let f = broyden1965_case5(&x);
+    let s = nalgebra::DVector::from_element(problem_size, 0.01);
+    let y = broyden1965_case5(&(&x + &s)) - &f;
+    nrf::solver::approximate_jacobian(&mut jacobian, BROY1, &s, &y, &f).unwrap();
+    assert_ne!(jacobian.get_jacobian().as_ref().unwrap()[(0, 2)], 0.0);
+}
+
+#[test]
+#[should_panic(
+    expected = "The Schubert method preserves the sparsity of the jacobian: declare its sparsity pattern with `SolverParameters::set_sparsity_pattern()`"
+)]
+fn without_sparsity_pattern() {
+    solve(
+        broyden1965_case5,
+        init_broyden1965_case5(),
+        SCHUBERT,
+        None,
+        Damping::Disabled,
+    );
+}
+
+#[test]
+#[should_panic(expected = "The sparsity pattern is of size 4, the problem size is 5")]
+fn sparsity_pattern_size() {
+    solve(
+        broyden1965_case5,
+        init_broyden1965_case5(),
+        SCHUBERT,
+        Some(hessenberg_pattern(4)),
+        Damping::Disabled,
+    );
+}
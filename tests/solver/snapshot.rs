@@ -0,0 +1,253 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::broyden1965::*;
+
+const PROBLEM_SIZE: usize = 20;
+const SNAPSHOT_ITERATION: usize = 3;
+
+const RESOLUTION_METHODS: [ResolutionMethod; 3] = [
+    ResolutionMethod::NewtonRaphson,
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    )),
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::InverseJacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenSecondMethod,
+    )),
+];
+
+// With a memory shorter than the iterations, the oldest steps are dropped
+const LIMITED_MEMORY_BROYDEN: ResolutionMethod =
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::LimitedMemoryBroyden { memory: 2 });
+
+fn solver_parameters(resolution_method: ResolutionMethod) -> nrf::solver::SolverParameters {
+    nrf::solver::SolverParameters::new(
+        ProblemSize::new(PROBLEM_SIZE),
+        1e-9,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Enabled,
+    )
+}
+
+fn broyden_case8_model() -> nrf::model::UserModelFromFunction {
+    nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, broyden1965_case8)
+}
+
+/// Weakly coupled problem whose jacobian is diagonal at the origin,
+/// the diagonal seed of the limited memory method being close to the jacobian
+fn coupled_cubic(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    let n = x.len();
+    nalgebra::DVector::from_fn(n, |i, _| {
+        let next = x[(i + 1) % n];
+        2.0 * x[i] + 0.5 * x[i].powi(3) + 0.1 * x[i] * next * next - (0.5 + 0.05 * (i % 10) as f64)
+    })
+}
+
+/// Iteration and maximum error of each record of the history
+#[cfg(feature = "std")]
+fn history_errors(
+    rf: &nrf::solver::RootFinder<iteratives::IterativeParamsFD, nalgebra::Dyn>,
+) -> Vec<(usize, f64)> {
+    rf.get_history()
+        .iter()
+        .map(|record| (record.get_iteration(), record.get_max_error()))
+        .collect()
+}
+
+/// Compare the resolution resumed from the snapshot taken at `SNAPSHOT_ITERATION`
+/// to the uninterrupted one, stopped at each of the following iterations
+fn assert_resumed_resolution_identical(
+    resolution_method: ResolutionMethod,
+    func: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    init: nalgebra::DVector<f64>,
+) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters(resolution_method),
+        init.clone(),
+        &iter_params,
+        &res_config,
+    );
+    #[cfg(feature = "std")]
+    rf.set_history(true);
+    let mut user_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, func);
+    rf.solve(&mut user_model).unwrap();
+    let total_iter = rf.get_iter();
+    assert!(total_iter > SNAPSHOT_ITERATION);
+
+    rf.set_max_iter(SNAPSHOT_ITERATION);
+    let mut interrupted_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, func);
+    assert!(rf.solve(&mut interrupted_model).is_err());
+    let snapshot = rf.snapshot();
+    assert_eq!(snapshot.get_iter(), SNAPSHOT_ITERATION);
+    let saved_iteratives = interrupted_model.get_iteratives();
+
+    // the iterates of the uninterrupted resolution, stopped at each iteration
+    for max_iter in SNAPSHOT_ITERATION + 1..=total_iter {
+        rf.set_max_iter(max_iter);
+        let mut reference_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, func);
+        let reference_result = rf.solve(&mut reference_model);
+
+        let mut resumed_rf = nrf::solver::RootFinder::new(
+            solver_parameters(resolution_method),
+            init.clone(),
+            &iter_params,
+            &res_config,
+        );
+        resumed_rf.set_max_iter(max_iter);
+        #[cfg(feature = "std")]
+        resumed_rf.set_history(true);
+        let mut resumed_model = nrf::model::UserModelFromFunction::new(PROBLEM_SIZE, func);
+        resumed_model.set_iteratives(&saved_iteratives);
+        let resumed_result = resumed_rf.resume(&snapshot, &mut resumed_model);
+
+        assert_eq!(resumed_result.is_ok(), reference_result.is_ok());
+        assert_eq!(resumed_rf.get_iter(), max_iter);
+        assert_eq!(
+            resumed_model.get_iteratives(),
+            reference_model.get_iteratives()
+        );
+        assert_eq!(resumed_rf.get_jacobian_sources(), rf.get_jacobian_sources());
+        assert_eq!(resumed_rf.get_convergence_rate(), rf.get_convergence_rate());
+        #[cfg(feature = "std")]
+        assert_eq!(history_errors(&resumed_rf), history_errors(&rf));
+        assert_eq!(
+            resumed_rf.get_errors_over_tolerance(),
+            rf.get_errors_over_tolerance()
+        );
+        // the model is evaluated once more to restore its residuals
+        assert_eq!(
+            resumed_rf.get_model_evaluations(),
+            rf.get_model_evaluations() + 1
+        );
+    }
+}
+
+#[test]
+fn resumed_resolution_identical_to_uninterrupted() {
+    for resolution_method in RESOLUTION_METHODS {
+        assert_resumed_resolution_identical(
+            resolution_method,
+            broyden1965_case8,
+            init_broyden1965_case8(),
+        );
+    }
+}
+
+// The steps kept by the limited memory are part of the snapshot,
+// the approximation not being seeded again by the resumed resolution
+#[test]
+fn resumed_limited_memory_broyden_identical_to_uninterrupted() {
+    assert_resumed_resolution_identical(
+        LIMITED_MEMORY_BROYDEN,
+        coupled_cubic,
+        nalgebra::DVector::zeros(PROBLEM_SIZE),
+    );
+}
+
+#[test]
+fn snapshot_of_converged_resolution() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters(ResolutionMethod::NewtonRaphson),
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = broyden_case8_model();
+    rf.solve(&mut user_model).unwrap();
+    let iter = rf.get_iter();
+    let snapshot = rf.snapshot();
+
+    rf.resume(&snapshot, &mut user_model).unwrap();
+    assert_eq!(rf.get_iter(), iter);
+}
+
+#[test]
+#[should_panic(expected = "The snapshot has been taken by a solver with another configuration")]
+fn snapshot_of_another_configuration() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters(ResolutionMethod::NewtonRaphson),
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+    rf.set_max_iter(SNAPSHOT_ITERATION);
+    let mut user_model = broyden_case8_model();
+    assert!(rf.solve(&mut user_model).is_err());
+    let snapshot = rf.snapshot();
+
+    rf.set_tolerance(1e-6);
+    let _ = rf.resume(&snapshot, &mut user_model);
+}
+
+#[test]
+fn configuration_checksum_ignores_budgets() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters(ResolutionMethod::NewtonRaphson),
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+    let checksum = rf.snapshot().get_configuration_checksum();
+
+    rf.set_max_iter(3);
+    assert_eq!(rf.snapshot().get_configuration_checksum(), checksum);
+    rf.set_damping(Damping::Disabled);
+    assert_ne!(rf.snapshot().get_configuration_checksum(), checksum);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialized_snapshot() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(PROBLEM_SIZE);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; PROBLEM_SIZE];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        solver_parameters(LIMITED_MEMORY_BROYDEN),
+        init_broyden1965_case8(),
+        &iter_params,
+        &res_config,
+    );
+    #[cfg(feature = "std")]
+    rf.set_history(true);
+    rf.set_max_iter(SNAPSHOT_ITERATION);
+    let mut user_model = broyden_case8_model();
+    assert!(rf.solve(&mut user_model).is_err());
+    let snapshot = rf.snapshot();
+
+    let bytes = serde_json::to_vec(&snapshot).unwrap();
+    let deserialized: nrf::solver::SolverSnapshot = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(deserialized, snapshot);
+}
@@ -0,0 +1,97 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod,
+    UpdateQuasiNewtonMethod,
+};
+use util::test_cases::pipe_flow::*;
+
+use crate::common::{pipe_flow_normalization, PipeFlowModel};
+
+const BROYDEN_FIRST_METHOD: ResolutionMethod = ResolutionMethod::QuasiNewton(
+    QuasiNewtonMethod::JacobianUpdate(UpdateQuasiNewtonMethod::BroydenFirstMethod),
+);
+
+/// Residuals of a new model evaluated at the iteratives, without the solver
+fn evaluate_pipe_flow(
+    iteratives: &nalgebra::DVector<f64>,
+) -> residuals::ResidualsValues<nalgebra::Dyn> {
+    let mut user_model = PipeFlowModel::new(parameters_pipe_flow());
+    user_model.set_iteratives(iteratives);
+    user_model.evaluate().unwrap();
+    user_model.get_residuals()
+}
+
+#[test]
+fn final_state_in_report() {
+    let normalization = pipe_flow_normalization();
+    let vec_iter_params = iteratives::default_vec_iteratives(4);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+
+    for resolution_method in [ResolutionMethod::NewtonRaphson, BROYDEN_FIRST_METHOD] {
+        let parameters = nrf::solver::SolverParameters::new(
+            ProblemSize::new(4),
+            1e-9,
+            MaxIterations::new(50),
+            resolution_method,
+            Damping::Enabled,
+        );
+        let mut rf = nrf::solver::RootFinder::new(
+            parameters,
+            init_pipe_flow(&parameters_pipe_flow()),
+            &iter_params,
+            &res_config,
+        );
+        let mut user_model = PipeFlowModel::new(parameters_pipe_flow());
+
+        let report = rf.solve_with_report(&mut user_model).unwrap();
+
+        assert_eq!(report.get_final_iteratives(), &user_model.get_iteratives());
+        let expected_residuals = evaluate_pipe_flow(report.get_final_iteratives());
+        assert_eq!(report.get_final_residuals(), &expected_residuals);
+        let expected_errors = res_config.evaluate_stopping_residuals(&expected_residuals);
+        assert_eq!(report.get_final_errors(), &expected_errors);
+        assert_eq!(report.get_final_max_error(), expected_errors.amax());
+        assert!(report.get_final_max_error() <= 1e-9);
+        assert_eq!(report.get_iter(), rf.get_iter());
+    }
+}
+
+#[test]
+fn final_state_in_batch_reports() {
+    let normalization = pipe_flow_normalization();
+    let vec_iter_params = iteratives::default_vec_iteratives(4);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let res_config = residuals::ResidualsConfig::new(&normalization, &normalization);
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(4),
+        1e-9,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    let initial_guesses = vec![init_pipe_flow(&parameters_pipe_flow()); 2];
+    let mut models: Vec<_> = (0..2)
+        .map(|_| PipeFlowModel::new(parameters_pipe_flow()))
+        .collect();
+
+    let reports = nrf::solver::solve_batch(
+        parameters,
+        &iter_params,
+        &res_config,
+        &initial_guesses,
+        &mut models,
+    );
+
+    for (report, model) in reports.into_iter().zip(&models) {
+        let report = report.unwrap();
+        assert_eq!(report.get_final_iteratives(), &model.get_iteratives());
+        assert_eq!(
+            report.get_final_residuals(),
+            &evaluate_pipe_flow(&model.get_iteratives())
+        );
+    }
+}
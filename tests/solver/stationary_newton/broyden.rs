@@ -1,14 +1,14 @@
 use newton_rootfinder as nrf;
 use util::test_cases::broyden1965::*;
 
-use nrf::solver::{QuasiNewtonMethod, ResolutionMethod};
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod};
 
 use crate::common::{run_function_case_fd, run_function_case_jac};
 
 #[test]
 fn broyden_case5_fd() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case5,
@@ -22,7 +22,7 @@ fn broyden_case5_fd() {
 #[test]
 fn broyden_case5_jac() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case5,
@@ -37,7 +37,7 @@ fn broyden_case5_jac() {
 #[test]
 fn broyden_case6_fd() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case6,
@@ -51,7 +51,7 @@ fn broyden_case6_fd() {
 #[test]
 fn broyden_case6_jac() {
     let problem_size = 5;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case6,
@@ -66,7 +66,7 @@ fn broyden_case6_jac() {
 #[test]
 fn broyden_case7_fd() {
     let problem_size = 10;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case7,
@@ -80,7 +80,7 @@ fn broyden_case7_fd() {
 #[test]
 fn broyden_case7_jac() {
     let problem_size = 10;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case7,
@@ -95,7 +95,7 @@ fn broyden_case7_jac() {
 #[test]
 fn broyden_case8_fd() {
     let problem_size = 20;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case8,
@@ -109,7 +109,7 @@ fn broyden_case8_fd() {
 #[test]
 fn broyden_case8_jac() {
     let problem_size = 20;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case8,
@@ -124,7 +124,7 @@ fn broyden_case8_jac() {
 #[test]
 fn broyden_case9_fd() {
     let problem_size = 2;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         broyden1965_case9,
@@ -138,7 +138,7 @@ fn broyden_case9_fd() {
 #[test]
 fn broyden_case9_jac() {
     let problem_size = 2;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         broyden1965_case9,
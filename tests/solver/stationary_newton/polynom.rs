@@ -2,14 +2,14 @@ use newton_rootfinder as nrf;
 
 use crate::common::{run_function_case_fd, run_function_case_jac};
 
-use nrf::solver::{QuasiNewtonMethod, ResolutionMethod};
+use nrf::solver::{Damping, QuasiNewtonMethod, ResolutionMethod};
 
 use util::test_cases::polynom;
 
 #[test]
 fn square() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::square2,
@@ -24,7 +24,7 @@ fn square() {
 #[should_panic]
 fn root_with_high_derivative() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_fd(
         problem_size,
         polynom::root_with_high_derivative,
@@ -38,7 +38,7 @@ fn root_with_high_derivative() {
 #[test]
 fn square_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::square2,
@@ -54,7 +54,7 @@ fn square_jac() {
 #[should_panic]
 fn root_with_high_derivative_jac() {
     let problem_size = 1;
-    let damping = false;
+    let damping = Damping::Disabled;
     run_function_case_jac(
         problem_size,
         polynom::root_with_high_derivative,
@@ -0,0 +1,104 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives;
+use nrf::model::Model;
+use nrf::residuals::NormalizationMethod;
+use nrf::solver::{Damping, MaxIterations, ProblemSize, ResolutionMethod, StepAcceptanceNorm};
+
+// Linear model whose root is (0, 0): from the initial guess (2, -2),
+// the step of the first iterative is limited to 1 and the proposed guess is (1, 0).
+// The absolute residuals go from (2, 0) to (1, 1): their maximum decreases,
+// but the second residual increases, which is amplified by its normalization to a small reference.
+fn coupled(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0], x[0] + x[1]])
+}
+
+fn coupled_jac(_x: &nalgebra::DVector<f64>) -> nalgebra::DMatrix<f64> {
+    nalgebra::DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 1.0, 1.0])
+}
+
+const SCALED: [NormalizationMethod; 2] = [
+    NormalizationMethod::RelToReference(1.0),
+    NormalizationMethod::RelToReference(0.1),
+];
+const ABS: [NormalizationMethod; 2] = [NormalizationMethod::Abs; 2];
+
+/// Perform the first iteration, returning the iteratives and the number of model evaluations
+fn first_iteration(
+    stopping_criterias: &[NormalizationMethod],
+    update_methods: &[NormalizationMethod],
+    step_acceptance_norm: StepAcceptanceNorm,
+) -> (nalgebra::DVector<f64>, usize) {
+    let iteratives_vec = [
+        iteratives::IterativeParams::new(1.0, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY),
+        iteratives::IterativeParams::default(),
+    ];
+    let iter_params = iteratives::Iteratives::new(&iteratives_vec);
+    let res_config = nrf::residuals::ResidualsConfig::new(stopping_criterias, update_methods);
+    let mut parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(1),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Enabled,
+    );
+    parameters.set_step_acceptance_norm(step_acceptance_norm);
+
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(vec![2.0, -2.0]),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunctionAndJacobian::new(2, coupled, coupled_jac);
+    rf.solve(&mut user_model).unwrap_err();
+
+    (user_model.get_iteratives(), rf.get_model_evaluations())
+}
+
+const PROPOSED_GUESS: [f64; 2] = [1.0, 0.0];
+const DAMPED_GUESS: [f64; 2] = [1.5, -1.0];
+
+#[test]
+fn stopping_errors_increase() {
+    // The stopping errors go from 2 to 10, the update errors from 2 to 1
+    let (iteratives, evaluations) = first_iteration(&SCALED, &ABS, StepAcceptanceNorm::Stopping);
+    assert_eq!(iteratives.as_slice(), DAMPED_GUESS);
+    assert_eq!(evaluations, 3);
+
+    let (iteratives, evaluations) = first_iteration(&SCALED, &ABS, StepAcceptanceNorm::Update);
+    assert_eq!(iteratives.as_slice(), PROPOSED_GUESS);
+    assert_eq!(evaluations, 2);
+
+    let (iteratives, evaluations) = first_iteration(&SCALED, &ABS, StepAcceptanceNorm::Both);
+    assert_eq!(iteratives.as_slice(), DAMPED_GUESS);
+    assert_eq!(evaluations, 3);
+}
+
+#[test]
+fn update_errors_increase() {
+    // The stopping errors go from 2 to 1, the update errors from 2 to 10
+    let (iteratives, evaluations) = first_iteration(&ABS, &SCALED, StepAcceptanceNorm::Stopping);
+    assert_eq!(iteratives.as_slice(), PROPOSED_GUESS);
+    assert_eq!(evaluations, 2);
+
+    let (iteratives, evaluations) = first_iteration(&ABS, &SCALED, StepAcceptanceNorm::Update);
+    assert_eq!(iteratives.as_slice(), DAMPED_GUESS);
+    assert_eq!(evaluations, 3);
+
+    let (iteratives, evaluations) = first_iteration(&ABS, &SCALED, StepAcceptanceNorm::Both);
+    assert_eq!(iteratives.as_slice(), DAMPED_GUESS);
+    assert_eq!(evaluations, 3);
+}
+
+#[test]
+fn same_normalization() {
+    // With the same normalization, the decisions are the same
+    for step_acceptance_norm in [
+        StepAcceptanceNorm::Stopping,
+        StepAcceptanceNorm::Update,
+        StepAcceptanceNorm::Both,
+    ] {
+        let (iteratives, _) = first_iteration(&SCALED, &SCALED, step_acceptance_norm);
+        assert_eq!(iteratives.as_slice(), DAMPED_GUESS);
+    }
+}
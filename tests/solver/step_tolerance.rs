@@ -0,0 +1,109 @@
+use newton_rootfinder as nrf;
+use nrf::iteratives::{IterativeParams, IterativeParamsFD, Iteratives};
+use nrf::residuals;
+use nrf::solver::{ConvergenceCriterion, Damping, MaxIterations, ProblemSize, ResolutionMethod};
+
+/// Linear problem of roots `(1.0, 5.0)`
+///
+/// The second iterative is a slack variable:
+/// its residual is below the tolerance long before the variable reaches its root
+fn slack_variable(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] - 1.0, 1e-8 * (x[1] - 5.0)])
+}
+
+type SolverResult =
+    Result<(), nrf::errors::SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>>;
+
+fn solve(
+    step_tolerances: [Option<f64>; 2],
+    max_iter: usize,
+) -> (
+    SolverResult,
+    nalgebra::DVector<f64>,
+    usize,
+    Option<ConvergenceCriterion>,
+) {
+    let problem_size = 2;
+    let mut vec_iter_params: Vec<IterativeParamsFD> = step_tolerances
+        .iter()
+        .map(|_| {
+            IterativeParamsFD::extend(
+                // The slow drift of the slack variable is obtained by limiting its steps
+                IterativeParams::new(1.0, f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY),
+                5e-8,
+                5e-8,
+                nrf::iteratives::PerturbationMethod::Max,
+            )
+        })
+        .collect();
+    for (iterative, step_tolerance) in vec_iter_params.iter_mut().zip(step_tolerances) {
+        iterative.set_convergence_step_tolerance(step_tolerance);
+    }
+    let iter_params = Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+
+    let parameters = nrf::solver::SolverParameters::new(
+        ProblemSize::new(problem_size),
+        1e-6,
+        MaxIterations::new(max_iter),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let init = nalgebra::DVector::from_vec(vec![0.0, 0.0]);
+    let mut rf = nrf::solver::RootFinder::new(parameters, init, &iter_params, &res_config);
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, slack_variable);
+
+    let result = rf.solve(&mut user_model);
+
+    (
+        result,
+        nrf::model::Model::get_iteratives(&user_model),
+        rf.get_iter(),
+        rf.get_last_satisfied_criterion(),
+    )
+}
+
+#[test]
+fn residuals_criterion_only() {
+    let (result, solution, iter, criterion) = solve([None, None], 20);
+
+    assert!(result.is_ok());
+    assert_eq!(iter, 1);
+    assert_eq!(criterion, Some(ConvergenceCriterion::Residuals));
+    // The slack variable has only moved by its maximum step
+    assert!(float_cmp::approx_eq!(f64, solution[1], 1.0, epsilon = 1e-6));
+}
+
+#[test]
+fn step_criterion_delays_convergence() {
+    let (result, solution, iter, criterion) = solve([None, Some(1e-3)], 20);
+
+    assert!(result.is_ok());
+    // 5 iterations to reach the root of the slack variable, one more to have a null step
+    assert_eq!(iter, 6);
+    assert_eq!(criterion, Some(ConvergenceCriterion::Step));
+    assert!(float_cmp::approx_eq!(f64, solution[0], 1.0, epsilon = 1e-6));
+    assert!(float_cmp::approx_eq!(f64, solution[1], 5.0, epsilon = 1e-6));
+}
+
+#[test]
+fn step_criterion_not_reached() {
+    let (result, _, iter, criterion) = solve([None, Some(1e-3)], 3);
+
+    assert!(matches!(
+        result,
+        Err(nrf::errors::SolverError::NonConvergenceError(_))
+    ));
+    assert_eq!(iter, 3);
+    assert_eq!(criterion, None);
+}
+
+#[test]
+#[should_panic(
+    expected = "convergence_step_tolerance must be strictly positive, provided value was -0.001"
+)]
+fn negative_step_tolerance() {
+    let _ = solve([None, Some(-1e-3)], 20);
+}
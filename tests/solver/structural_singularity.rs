@@ -0,0 +1,137 @@
+use newton_rootfinder as nrf;
+use nrf::errors::{SolverError, SolverErrorKind};
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, QuasiNewtonMethod, ResolutionMethod, SolverParameters,
+    StructuralSingularityPolicy, UpdateQuasiNewtonMethod,
+};
+
+const INITIAL_GUESS: [f64; 2] = [1.0, 5.0];
+
+/// Equation `x^2 = 2` along with a disconnected equation, the iterative `y` affecting no residual
+fn disconnected(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] - 2.0, 3.0])
+}
+
+/// Equation `x^2 + y = 2` along with a disconnected equation, each iterative affecting a residual
+fn disconnected_only_equation(x: &nalgebra::DVector<f64>) -> nalgebra::DVector<f64> {
+    nalgebra::DVector::from_vec(vec![x[0] * x[0] + x[1] - 2.0, 3.0])
+}
+
+type Resolution = Result<
+    nrf::solver::SolverReport,
+    SolverError<nrf::model::UserModelFromFunction, nalgebra::Dyn>,
+>;
+
+/// Solve the model and return the structural reductions
+fn solve(
+    function: fn(&nalgebra::DVector<f64>) -> nalgebra::DVector<f64>,
+    resolution_method: ResolutionMethod,
+    policy: StructuralSingularityPolicy,
+) -> (Resolution, Vec<(usize, usize)>) {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-9,
+        MaxIterations::new(50),
+        resolution_method,
+        Damping::Disabled,
+    );
+    parameters.set_structural_singularity_policy(policy);
+    let mut rf = nrf::solver::RootFinder::new(
+        parameters,
+        nalgebra::DVector::from_vec(INITIAL_GUESS.to_vec()),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(2, function);
+
+    let result = rf.solve_with_report(&mut user_model);
+    (result, rf.get_structural_reductions().to_vec())
+}
+
+fn broyden() -> ResolutionMethod {
+    ResolutionMethod::QuasiNewton(QuasiNewtonMethod::JacobianUpdate(
+        UpdateQuasiNewtonMethod::BroydenFirstMethod,
+    ))
+}
+
+#[test]
+fn disconnected_equation_reported() {
+    let (result, reductions) = solve(
+        disconnected,
+        ResolutionMethod::NewtonRaphson,
+        StructuralSingularityPolicy::Fail,
+    );
+
+    let error = result.unwrap_err();
+    assert_eq!(error.kind(), SolverErrorKind::StructurallySingularJacobian);
+    match error {
+        SolverError::StructurallySingularJacobian {
+            zero_rows,
+            zero_cols,
+        } => {
+            assert_eq!(zero_rows, vec![1]);
+            assert_eq!(zero_cols, vec![1]);
+        }
+        error => panic!("Unexpected error {}", error),
+    }
+    assert!(reductions.is_empty());
+}
+
+#[test]
+fn disconnected_equation_reduced() {
+    for resolution_method in [ResolutionMethod::NewtonRaphson, broyden()] {
+        let (result, reductions) = solve(
+            disconnected,
+            resolution_method,
+            StructuralSingularityPolicy::Reduce,
+        );
+
+        let report = result.unwrap();
+        assert_eq!(reductions, vec![(1, 1)]);
+        let iteratives = report.get_final_iteratives();
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[0],
+            2f64.sqrt(),
+            epsilon = 1e-9
+        ));
+        // the removed iterative is not updated by the steps,
+        // the removed residual does not take part in the convergence
+        assert!(float_cmp::approx_eq!(
+            f64,
+            iteratives[1],
+            INITIAL_GUESS[1],
+            epsilon = 1e-5
+        ));
+        assert_eq!(report.get_final_errors()[1], 0.0);
+        assert_eq!(report.get_final_residuals().get_values(1), (3.0, 0.0));
+    }
+}
+
+#[test]
+fn unbalanced_reduction_reported() {
+    let (result, reductions) = solve(
+        disconnected_only_equation,
+        ResolutionMethod::NewtonRaphson,
+        StructuralSingularityPolicy::Reduce,
+    );
+
+    match result.unwrap_err() {
+        SolverError::StructurallySingularJacobian {
+            zero_rows,
+            zero_cols,
+        } => {
+            assert_eq!(zero_rows, vec![1]);
+            assert!(zero_cols.is_empty());
+        }
+        error => panic!("Unexpected error {}", error),
+    }
+    assert!(reductions.is_empty());
+}
@@ -0,0 +1,196 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::errors::RootFinderConstructionError;
+use nrf::iteratives;
+use nrf::residuals;
+use nrf::solver::{
+    Damping, MaxIterations, ProblemSize, ResolutionMethod, RootFinder, SolverParameters,
+    MIN_TOLERANCE,
+};
+
+/// Error returned by `RootFinder::try_new` with the given tolerance
+fn construction_error(
+    tolerance: f64,
+    allow_extreme_tolerance: bool,
+) -> Option<RootFinderConstructionError> {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        tolerance,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    if allow_extreme_tolerance {
+        parameters.allow_extreme_tolerance();
+    }
+
+    RootFinder::try_new(
+        parameters,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    )
+    .err()
+}
+
+#[test]
+fn zero() {
+    assert_eq!(
+        construction_error(0.0, false),
+        Some(RootFinderConstructionError::ToleranceBelowFloor {
+            tolerance: 0.0,
+            floor: MIN_TOLERANCE
+        })
+    );
+}
+
+#[test]
+fn subnormal() {
+    let subnormal = f64::MIN_POSITIVE / 4.0;
+    assert!(subnormal.is_subnormal());
+    assert_eq!(
+        construction_error(subnormal, false),
+        Some(RootFinderConstructionError::ToleranceBelowFloor {
+            tolerance: subnormal,
+            floor: MIN_TOLERANCE
+        })
+    );
+    assert_eq!(
+        construction_error(1e-300, false),
+        Some(RootFinderConstructionError::ToleranceBelowFloor {
+            tolerance: 1e-300,
+            floor: MIN_TOLERANCE
+        })
+    );
+}
+
+#[test]
+fn floor() {
+    assert_eq!(construction_error(MIN_TOLERANCE, false), None);
+}
+
+#[test]
+fn negative() {
+    assert_eq!(
+        construction_error(-1e-6, false),
+        Some(RootFinderConstructionError::InvalidTolerance { tolerance: -1e-6 })
+    );
+    assert_eq!(
+        construction_error(-1e-6, true),
+        Some(RootFinderConstructionError::InvalidTolerance { tolerance: -1e-6 })
+    );
+}
+
+#[test]
+fn not_finite() {
+    assert!(matches!(
+        construction_error(f64::NAN, true),
+        Some(RootFinderConstructionError::InvalidTolerance { tolerance }) if tolerance.is_nan()
+    ));
+    assert_eq!(
+        construction_error(f64::INFINITY, false),
+        Some(RootFinderConstructionError::InvalidTolerance {
+            tolerance: f64::INFINITY
+        })
+    );
+}
+
+#[test]
+fn error_message() {
+    assert_eq!(
+        construction_error(1e-300, false).unwrap().to_string(),
+        "The tolerance 1e-300 is below the floor 1e-15: the residuals would never reach it. \
+         Use `SolverParameters::allow_extreme_tolerance()` to accept it"
+    );
+    assert_eq!(
+        construction_error(f64::NAN, false).unwrap().to_string(),
+        "The tolerance must be finite and non-negative, provided value was NaN"
+    );
+}
+
+// The residuals never reach a zero tolerance: the solver performs all its iterations
+#[test]
+fn allow_extreme_tolerance() {
+    let subnormal = f64::MIN_POSITIVE / 4.0;
+    assert_eq!(construction_error(0.0, true), None);
+    assert_eq!(construction_error(subnormal, true), None);
+
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(5);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 5];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 5];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut parameters = SolverParameters::new(
+        ProblemSize::new(5),
+        0.0,
+        MaxIterations::new(20),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    parameters.allow_extreme_tolerance();
+    let mut rf = RootFinder::new(
+        parameters,
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(5, broyden1965_case5);
+
+    assert!(rf.solve(&mut user_model).is_err());
+    assert_eq!(rf.get_iter(), 20);
+}
+
+#[test]
+#[should_panic(expected = "The tolerance 0e0 is below the floor 1e-15")]
+fn new_panics() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        0.0,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+
+    RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "The tolerance must be finite and non-negative, provided value was -1")]
+fn set_tolerance_panics() {
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(2);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; 2];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; 2];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let parameters = SolverParameters::new(
+        ProblemSize::new(2),
+        1e-6,
+        MaxIterations::new(50),
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut rf = RootFinder::new(
+        parameters,
+        nalgebra::DVector::zeros(2),
+        &iter_params,
+        &res_config,
+    );
+
+    rf.set_tolerance(-1.0);
+}
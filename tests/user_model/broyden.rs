@@ -25,8 +25,8 @@ fn broyden_case5_jac() {
     let jac_ref = broyden1965_case5_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
 
 #[test]
@@ -48,8 +48,8 @@ fn broyden_case6_jac() {
     let jac_ref = broyden1965_case6_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
 
 #[test]
@@ -71,8 +71,8 @@ fn broyden_case7_jac() {
     let jac_ref = broyden1965_case7_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
 
 #[test]
@@ -94,8 +94,8 @@ fn broyden_case8_jac() {
     let jac_ref = broyden1965_case8_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
 
 #[test]
@@ -117,8 +117,8 @@ fn broyden_case9_jac() {
     let jac_ref = broyden1965_case9_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
 
 #[test]
@@ -140,6 +140,6 @@ fn broyden_case10_jac() {
     let jac_ref = broyden1965_case10_jac(&init_guess);
     let zeros = nalgebra::DMatrix::zeros(problem_size, problem_size);
 
-    float_matrix_comparison(&left_jac, &jac_ref, 1e-6);
-    float_matrix_comparison(&right_jac, &zeros, 1e-6);
+    float_matrix_comparison(left_jac, &jac_ref, 1e-6);
+    float_matrix_comparison(right_jac, &zeros, 1e-6);
 }
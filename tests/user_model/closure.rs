@@ -10,7 +10,7 @@ fn solve_with_closure() {
     };
 
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     let init = nalgebra::DVector::from_vec(vec![1.0]);
     let solution = nalgebra::DVector::from_vec(vec![std::f64::consts::SQRT_2]);
 
@@ -38,7 +38,7 @@ fn solve_with_closure_and_jacobian() {
     };
 
     let problem_size = 1;
-    let damping = false;
+    let damping = nrf::solver::Damping::Disabled;
     let init = nalgebra::DVector::from_vec(vec![1.0]);
     let solution = nalgebra::DVector::from_vec(vec![std::f64::consts::SQRT_2]);
 
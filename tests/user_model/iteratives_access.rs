@@ -0,0 +1,83 @@
+use newton_rootfinder as nrf;
+use util::test_cases::broyden1965::*;
+
+use nrf::errors::IterativesAccessError;
+use nrf::iteratives;
+use nrf::model::{IterativesAccess, Model};
+use nrf::residuals;
+use nrf::solver::{Damping, ResolutionMethod};
+
+/// Offset a component of the solution of a model of any adapter
+fn nudge<M: IterativesAccess<nalgebra::Dyn>>(model: &mut M, index: usize, offset: f64) {
+    let value = model.get_iterative(index).unwrap();
+    model.set_iterative(index, value + offset).unwrap();
+}
+
+#[test]
+fn nudge_between_two_solves() {
+    let problem_size = 5;
+    let vec_iter_params = iteratives::default_vec_iteratives_fd(problem_size);
+    let iter_params = iteratives::Iteratives::new(&vec_iter_params);
+    let stopping_residuals = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let update_methods = vec![residuals::NormalizationMethod::Abs; problem_size];
+    let res_config = residuals::ResidualsConfig::new(&stopping_residuals, &update_methods);
+    let mut rf = nrf::solver::default_with_guess(
+        init_broyden1965_case5(),
+        &iter_params,
+        &res_config,
+        ResolutionMethod::NewtonRaphson,
+        Damping::Disabled,
+    );
+    let mut user_model = nrf::model::UserModelFromFunction::new(problem_size, broyden1965_case5);
+
+    rf.solve(&mut user_model).unwrap();
+    let first_iter = rf.get_iter();
+    let solution = user_model.get_iteratives();
+
+    user_model.set_iterative(2, solution[2] + 0.1).unwrap();
+    assert_eq!(user_model.get_iterative(2), Ok(solution[2] + 0.1));
+    assert_eq!(user_model.get_iterative(1), Ok(solution[1]));
+
+    rf.set_initial_guess(&user_model.get_iteratives());
+    rf.solve(&mut user_model).unwrap();
+
+    // starting from the nudged solution is cheaper than from the original initial guess
+    assert!(rf.get_iter() >= 1);
+    assert!(rf.get_iter() < first_iter);
+    for i in 0..problem_size {
+        assert!(float_cmp::approx_eq!(
+            f64,
+            user_model.get_iterative(i).unwrap(),
+            solution[i],
+            epsilon = 1e-6
+        ));
+    }
+}
+
+#[test]
+fn closure_adapter() {
+    let closure = |iteratives: &nalgebra::DVector<f64>| iteratives * 2.0;
+    let mut user_model = nrf::model::UserModelFromClosure::new(2, &closure);
+
+    user_model.set_iteratives_from_slice(&[1.0, 2.0]).unwrap();
+    nudge(&mut user_model, 0, 0.5);
+    user_model.evaluate().unwrap();
+
+    assert_eq!(user_model.get_residuals().get_values(0), (3.0, 0.0));
+    assert_eq!(user_model.get_residuals().get_values(1), (4.0, 0.0));
+    assert_eq!(
+        user_model.set_iteratives_from_slice(&[1.0, 2.0, 3.0]),
+        Err(IterativesAccessError::LengthMismatch {
+            length: 3,
+            problem_size: 2
+        })
+    );
+    assert_eq!(
+        user_model.set_iterative(2, 0.0),
+        Err(IterativesAccessError::IndexOutOfRange {
+            index: 2,
+            problem_size: 2
+        })
+    );
+    assert_eq!(user_model.get_iteratives().as_slice(), &[1.5, 2.0]);
+}
@@ -1,2 +1,3 @@
 pub mod broyden;
 pub mod closure;
+pub mod iteratives_access;
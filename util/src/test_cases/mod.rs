@@ -1,5 +1,6 @@
 //! Test cases taken from the litterature
 
 pub mod broyden1965;
+pub mod pipe_flow;
 pub mod polynom;
 pub mod spedicato1996;
@@ -0,0 +1,130 @@
+//! Flow of a perfect gas in a pipe
+//!
+//! Given the mass flow `W`, the total pressure `Pt` and the total enthalpy `ht` of the flow,
+//! the area `A` of the pipe and the gas constant `R` and heat capacity `cp` of the gas,
+//! the static state of the flow is the solution of:
+//!
+//! |   Equation    |      Left member      |  Right member  |
+//! |---------------|-----------------------|----------------|
+//! |  Mass         | rho * V * A           |       W        |
+//! |  Momentum     | Ps + rho * V^2 / 2    |       Pt       |
+//! |  Energy       | cp * Ts + V^2 / 2     |       ht       |
+//! |  Perfect gas  | Ps                    | rho * R * Ts   |
+//!
+//! The iteratives are, in this order:
+//! the static pressure `Ps`, the static temperature `Ts`, the density `rho` and the velocity `V`.
+//!
+//! The momentum equation is the one of an incompressible flow (Bernoulli),
+//! which is accurate for the low Mach number of the reference parameters.
+//!
+//! The members of the equations have very different magnitudes:
+//! around 1 for the mass flow, 1e5 for the pressures and 3e5 for the enthalpy.
+//! It makes this case representative of the physical problems
+//! where the normalization of the residuals matters.
+//!
+//! ## Solution
+//!
+//! Eliminating `rho = W / (V * A)` and `Ts = (ht - V^2 / 2) / cp`,
+//! the momentum equation gives a second order polynomial in `V`:
+//!
+//! (1 - R / cp) / 2 * V^2 - Pt * A / W * V + R * ht / cp = 0
+//!
+//! The subsonic solution is its smallest root.
+
+/// Boundary conditions of the flow and properties of the pipe and of the gas
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PipeFlowParameters {
+    /// Mass flow `W`, in kg/s
+    pub mass_flow: f64,
+    /// Total pressure `Pt`, in Pa
+    pub total_pressure: f64,
+    /// Total enthalpy `ht`, in J/kg
+    pub total_enthalpy: f64,
+    /// Area `A` of the pipe, in m^2
+    pub area: f64,
+    /// Gas constant `R`, in J/kg/K
+    pub gas_constant: f64,
+    /// Heat capacity at constant pressure `cp`, in J/kg/K
+    pub heat_capacity: f64,
+}
+
+/// Air at 101325 Pa and 300 K flowing at 1 kg/s in a pipe of 0.02 m^2
+pub fn parameters_pipe_flow() -> PipeFlowParameters {
+    let heat_capacity = 1004.5;
+    PipeFlowParameters {
+        mass_flow: 1.0,
+        total_pressure: 101325.0,
+        total_enthalpy: heat_capacity * 300.0,
+        area: 0.02,
+        gas_constant: 287.05,
+        heat_capacity,
+    }
+}
+
+/// Initial guess from the total conditions, as if the flow was at rest
+pub fn init_pipe_flow(params: &PipeFlowParameters) -> nalgebra::DVector<f64> {
+    let pressure = params.total_pressure;
+    let temperature = params.total_enthalpy / params.heat_capacity;
+    let density = pressure / (params.gas_constant * temperature);
+    let velocity = params.mass_flow / (density * params.area);
+    nalgebra::DVector::from_vec(vec![pressure, temperature, density, velocity])
+}
+
+pub fn solution_pipe_flow(params: &PipeFlowParameters) -> nalgebra::DVector<f64> {
+    let a = (1.0 - params.gas_constant / params.heat_capacity) / 2.0;
+    let b = params.total_pressure * params.area / params.mass_flow;
+    let c = params.gas_constant * params.total_enthalpy / params.heat_capacity;
+    // smallest root, written to avoid the cancellation of b - sqrt(b^2 - 4ac)
+    let velocity = 2.0 * c / (b + (b * b - 4.0 * a * c).sqrt());
+
+    let temperature = (params.total_enthalpy - velocity * velocity / 2.0) / params.heat_capacity;
+    let density = params.mass_flow / (velocity * params.area);
+    let pressure = density * params.gas_constant * temperature;
+    nalgebra::DVector::from_vec(vec![pressure, temperature, density, velocity])
+}
+
+/// Left and right members of the equations
+pub fn pipe_flow(
+    params: &PipeFlowParameters,
+    x: &nalgebra::DVector<f64>,
+) -> (nalgebra::DVector<f64>, nalgebra::DVector<f64>) {
+    let (pressure, temperature, density, velocity) = (x[0], x[1], x[2], x[3]);
+
+    let left = nalgebra::DVector::from_vec(vec![
+        density * velocity * params.area,
+        pressure + density * velocity * velocity / 2.0,
+        params.heat_capacity * temperature + velocity * velocity / 2.0,
+        pressure,
+    ]);
+    let right = nalgebra::DVector::from_vec(vec![
+        params.mass_flow,
+        params.total_pressure,
+        params.total_enthalpy,
+        density * params.gas_constant * temperature,
+    ]);
+    (left, right)
+}
+
+/// Jacobians of the left and right members of the equations
+pub fn pipe_flow_jac(
+    params: &PipeFlowParameters,
+    x: &nalgebra::DVector<f64>,
+) -> (nalgebra::DMatrix<f64>, nalgebra::DMatrix<f64>) {
+    let (temperature, density, velocity) = (x[1], x[2], x[3]);
+
+    #[rustfmt::skip]
+    let left = nalgebra::DMatrix::from_row_slice(4, 4, &[
+        0.0, 0.0,                 velocity * params.area,      density * params.area,
+        1.0, 0.0,                 velocity * velocity / 2.0,   density * velocity,
+        0.0, params.heat_capacity, 0.0,                        velocity,
+        1.0, 0.0,                 0.0,                         0.0,
+    ]);
+    #[rustfmt::skip]
+    let right = nalgebra::DMatrix::from_row_slice(4, 4, &[
+        0.0, 0.0,                          0.0,                                0.0,
+        0.0, 0.0,                          0.0,                                0.0,
+        0.0, 0.0,                          0.0,                                0.0,
+        0.0, density * params.gas_constant, params.gas_constant * temperature, 0.0,
+    ]);
+    (left, right)
+}